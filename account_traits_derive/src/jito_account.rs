@@ -0,0 +1,316 @@
+//! Expansion of `#[derive(JitoAccount)]`.
+//!
+//! ```ignore
+//! #[derive(JitoAccount)]
+//! #[discriminator(VaultDiscriminator::VaultNcnTicket)]
+//! #[account_seeds(b"vault_ncn_ticket", vault, ncn)]
+//! pub struct VaultNcnTicket { .. }
+//! ```
+//!
+//! `#[discriminator(Enum::Variant)]` implements `Discriminator`, optionally with a layout version
+//! (`#[discriminator(Enum::Variant, version = 1)]`). Each derive also implements
+//! `DiscriminatorOwner<N>` for the discriminator enum, so two accounts sharing a value fail to
+//! compile with conflicting implementations.
+//!
+//! `#[account_seeds(..)]` starts with the byte string prefix, followed by the remaining seeds:
+//! - `name` is a pubkey seed, passed to `load` as the account it is the key of
+//! - `name: u64` is an integer seed, encoded little-endian and passed to `load` by value
+//! - `self.field` is a pubkey seed read from the stored account, checked against its `bump`
+//!
+//! The attribute isn't named `seeds` because `ShankAccount` already claims and parses that one.
+//! The generated `load` logs failed checks through `jito_jsm_core::diagnostic`, so crates using
+//! `#[account_seeds(..)]` depend on `jito-jsm-core`.
+
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote};
+use syn::{
+    parse::{Parse, ParseStream},
+    punctuated::Punctuated,
+    spanned::Spanned,
+    DeriveInput, Error, Expr, Ident, LitByteStr, Path, Result, Token, Type,
+};
+
+/// `#[discriminator(Enum::Variant, version = N)]`
+struct DiscriminatorAttr {
+    variant: Path,
+    version: Option<Expr>,
+}
+
+impl Parse for DiscriminatorAttr {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let variant: Path = input.parse()?;
+        if variant.segments.len() < 2 {
+            return Err(Error::new(
+                variant.span(),
+                "expected a discriminator enum variant such as `VaultDiscriminator::Vault`",
+            ));
+        }
+        let mut version = None;
+        if input.parse::<Option<Token![,]>>()?.is_some() && !input.is_empty() {
+            let key: Ident = input.parse()?;
+            if key != "version" {
+                return Err(Error::new(key.span(), "expected `version = ..`"));
+            }
+            input.parse::<Token![=]>()?;
+            version = Some(input.parse()?);
+            input.parse::<Option<Token![,]>>()?;
+        }
+        Ok(Self { variant, version })
+    }
+}
+
+enum SeedKind {
+    /// A pubkey passed to `load` as an account
+    Pubkey,
+    /// An integer passed to `load` by value
+    Int(Box<Type>),
+    /// A pubkey read from the stored account
+    Stored,
+}
+
+struct Seed {
+    name: Ident,
+    kind: SeedKind,
+}
+
+impl Parse for Seed {
+    fn parse(input: ParseStream) -> Result<Self> {
+        if input.peek(Token![self]) {
+            input.parse::<Token![self]>()?;
+            input.parse::<Token![.]>()?;
+            return Ok(Self {
+                name: input.parse()?,
+                kind: SeedKind::Stored,
+            });
+        }
+        let name: Ident = input.parse()?;
+        let kind = if input.parse::<Option<Token![:]>>()?.is_some() {
+            SeedKind::Int(Box::new(input.parse()?))
+        } else {
+            SeedKind::Pubkey
+        };
+        Ok(Self { name, kind })
+    }
+}
+
+/// `#[account_seeds(b"prefix", seed, ..)]`
+struct SeedsAttr {
+    prefix: LitByteStr,
+    seeds: Vec<Seed>,
+}
+
+impl Parse for SeedsAttr {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let prefix = input.parse()?;
+        let mut seeds = Vec::new();
+        if input.parse::<Option<Token![,]>>()?.is_some() {
+            seeds = Punctuated::<Seed, Token![,]>::parse_terminated(input)?
+                .into_iter()
+                .collect();
+        }
+        Ok(Self { prefix, seeds })
+    }
+}
+
+pub fn expand(input: &DeriveInput) -> Result<TokenStream> {
+    let name = &input.ident;
+
+    let mut discriminator = None;
+    let mut seeds = None;
+    for attr in &input.attrs {
+        if attr.path().is_ident("discriminator") {
+            discriminator = Some(attr.parse_args::<DiscriminatorAttr>()?);
+        } else if attr.path().is_ident("account_seeds") {
+            seeds = Some(attr.parse_args::<SeedsAttr>()?);
+        }
+    }
+    let discriminator = discriminator.ok_or_else(|| {
+        Error::new(
+            Span::call_site(),
+            "JitoAccount requires a `#[discriminator(..)]` attribute",
+        )
+    })?;
+
+    let mut expanded = expand_discriminator(name, &discriminator);
+    if let Some(seeds) = seeds {
+        expanded.extend(expand_seeds(name, &seeds));
+    }
+    Ok(expanded)
+}
+
+fn expand_discriminator(name: &Ident, attr: &DiscriminatorAttr) -> TokenStream {
+    let variant = &attr.variant;
+    let mut discriminator_enum = variant.clone();
+    discriminator_enum.segments.pop();
+    discriminator_enum.segments.pop_punct();
+    let version = attr
+        .version
+        .as_ref()
+        .map(|version| quote! { const VERSION: u8 = #version; });
+
+    quote! {
+        impl ::jito_bytemuck::Discriminator for #name {
+            const DISCRIMINATOR: u8 = #variant as u8;
+            #version
+        }
+
+        impl ::jito_bytemuck::DiscriminatorOwner<{ #variant as u8 }> for #discriminator_enum {}
+    }
+}
+
+fn expand_seeds(name: &Ident, attr: &SeedsAttr) -> TokenStream {
+    let prefix = &attr.prefix;
+    let seed_names: Vec<&Ident> = attr.seeds.iter().map(|seed| &seed.name).collect();
+    let seed_params: Vec<TokenStream> = attr
+        .seeds
+        .iter()
+        .map(|seed| {
+            let seed_name = &seed.name;
+            match &seed.kind {
+                SeedKind::Pubkey | SeedKind::Stored => {
+                    quote! { #seed_name: &::solana_program::pubkey::Pubkey }
+                }
+                SeedKind::Int(ty) => quote! { #seed_name: #ty },
+            }
+        })
+        .collect();
+    let seed_bytes: Vec<TokenStream> = attr
+        .seeds
+        .iter()
+        .map(|seed| {
+            let seed_name = &seed.name;
+            match &seed.kind {
+                SeedKind::Pubkey | SeedKind::Stored => quote! { #seed_name.to_bytes().to_vec() },
+                SeedKind::Int(_) => quote! { #seed_name.to_le_bytes().to_vec() },
+            }
+        })
+        .collect();
+
+    // Seeds that aren't stored in the account are passed to `load` by the caller
+    let load_params: Vec<TokenStream> = attr
+        .seeds
+        .iter()
+        .filter_map(|seed| {
+            let seed_name = &seed.name;
+            match &seed.kind {
+                SeedKind::Pubkey => {
+                    Some(quote! { #seed_name: &::solana_program::account_info::AccountInfo })
+                }
+                SeedKind::Int(ty) => Some(quote! { #seed_name: #ty }),
+                SeedKind::Stored => None,
+            }
+        })
+        .collect();
+    let stored = format_ident!("stored");
+    let seed_args: Vec<TokenStream> = attr
+        .seeds
+        .iter()
+        .map(|seed| {
+            let seed_name = &seed.name;
+            match &seed.kind {
+                SeedKind::Pubkey => quote! { #seed_name.key },
+                SeedKind::Int(_) => quote! { #seed_name },
+                SeedKind::Stored => quote! { &#stored.#seed_name },
+            }
+        })
+        .collect();
+
+    let pda_check = if attr
+        .seeds
+        .iter()
+        .any(|seed| matches!(seed.kind, SeedKind::Stored))
+    {
+        quote! {
+            let data = account.data.borrow();
+            let #stored =
+                <Self as ::jito_bytemuck::AccountDeserialize>::try_from_slice_unchecked(&data)?;
+            let mut seeds = Self::seeds(#(#seed_args),*);
+            seeds.push(vec![#stored.bump]);
+            let seed_slices: Vec<&[u8]> = seeds.iter().map(|seed| seed.as_slice()).collect();
+            let expected_pubkey =
+                ::solana_program::pubkey::Pubkey::create_program_address(&seed_slices, program_id)?;
+        }
+    } else {
+        quote! {
+            let expected_pubkey = Self::find_program_address(program_id, #(#seed_args),*).0;
+        }
+    };
+
+    let too_many_arguments =
+        (load_params.len() + 3 > 7).then(|| quote! { #[allow(clippy::too_many_arguments)] });
+    let doc_seeds = format!(
+        "Returns the seeds of the [`{}`] PDA, without the bump",
+        name
+    );
+    let doc_find = format!(
+        "Finds the [`{}`] PDA, returning its address, bump and seeds",
+        name
+    );
+    let doc_load = format!(
+        "Loads the account as a [`{}`] account, checking its owner, discriminator and address",
+        name
+    );
+    let invalid_owner = format!("{} account has an invalid owner", name);
+    let data_empty = format!("{} account data is empty", name);
+    let not_writable = format!("{} account is not writable", name);
+    let invalid_discriminator = format!("{} account discriminator is invalid", name);
+    let invalid_pda = format!("{} account is not at the correct PDA", name);
+
+    quote! {
+        impl #name {
+            #[doc = #doc_seeds]
+            pub fn seeds(#(#seed_params),*) -> Vec<Vec<u8>> {
+                Vec::from_iter([#prefix.to_vec(), #(#seed_bytes),*])
+            }
+
+            #[doc = #doc_find]
+            pub fn find_program_address(
+                program_id: &::solana_program::pubkey::Pubkey,
+                #(#seed_params),*
+            ) -> (::solana_program::pubkey::Pubkey, u8, Vec<Vec<u8>>) {
+                let seeds = Self::seeds(#(#seed_names),*);
+                let seeds_iter: Vec<_> = seeds.iter().map(|s| s.as_slice()).collect();
+                let (pda, bump) =
+                    ::solana_program::pubkey::Pubkey::find_program_address(&seeds_iter, program_id);
+                (pda, bump, seeds)
+            }
+
+            #[doc = #doc_load]
+            #too_many_arguments
+            pub fn load(
+                program_id: &::solana_program::pubkey::Pubkey,
+                account: &::solana_program::account_info::AccountInfo,
+                #(#load_params,)*
+                expect_writable: bool,
+            ) -> Result<(), ::solana_program::program_error::ProgramError> {
+                if account.owner.ne(program_id) {
+                    ::solana_program::msg!(#invalid_owner);
+                    ::jito_jsm_core::diagnostic::Check::Owner.log_failure(account.key);
+                    return Err(::solana_program::program_error::ProgramError::InvalidAccountOwner);
+                }
+                if account.data_is_empty() {
+                    ::solana_program::msg!(#data_empty);
+                    ::jito_jsm_core::diagnostic::Check::Initialized.log_failure(account.key);
+                    return Err(::solana_program::program_error::ProgramError::InvalidAccountData);
+                }
+                if expect_writable && !account.is_writable {
+                    ::solana_program::msg!(#not_writable);
+                    ::jito_jsm_core::diagnostic::Check::Writable.log_failure(account.key);
+                    return Err(::solana_program::program_error::ProgramError::InvalidAccountData);
+                }
+                if account.data.borrow()[0].ne(&<Self as ::jito_bytemuck::Discriminator>::DISCRIMINATOR) {
+                    ::solana_program::msg!(#invalid_discriminator);
+                    ::jito_jsm_core::diagnostic::Check::Discriminator.log_failure(account.key);
+                    return Err(::solana_program::program_error::ProgramError::InvalidAccountData);
+                }
+                #pda_check
+                if account.key.ne(&expected_pubkey) {
+                    ::solana_program::msg!(#invalid_pda);
+                    ::jito_jsm_core::diagnostic::Check::Address.log_failure(account.key);
+                    return Err(::solana_program::program_error::ProgramError::InvalidAccountData);
+                }
+                Ok(())
+            }
+        }
+    }
+}
@@ -1,3 +1,5 @@
+mod jito_account;
+
 use proc_macro::TokenStream;
 use quote::quote;
 use syn::{parse_macro_input, DeriveInput};
@@ -13,3 +15,14 @@ pub fn derive_account_deserialize(input: TokenStream) -> TokenStream {
 
     TokenStream::from(expanded)
 }
+
+/// Derives `Discriminator` from `#[discriminator(..)]` and, when `#[account_seeds(..)]` is present,
+/// the `seeds`, `find_program_address` and `load` functions of the account's PDA
+#[proc_macro_derive(JitoAccount, attributes(discriminator, account_seeds))]
+pub fn derive_jito_account(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    jito_account::expand(&input)
+        .unwrap_or_else(|err| err.to_compile_error())
+        .into()
+}
@@ -3,16 +3,92 @@
 pub mod types;
 
 use bytemuck::Pod;
-pub use jito_account_traits_derive::AccountDeserialize;
+pub use jito_account_traits_derive::{AccountDeserialize, JitoAccount};
 use solana_program::{msg, program_error::ProgramError};
 
 pub trait Discriminator {
     const DISCRIMINATOR: u8;
+
+    /// The layout version of the account, stored in the byte after the discriminator.
+    /// Accounts created before versioning have zero there, so the first layout is version 0.
+    const VERSION: u8 = 0;
+}
+
+/// Implemented on a program's discriminator enum once per discriminator value by
+/// `#[derive(JitoAccount)]`, so two accounts sharing a discriminator fail to compile.
+///
+/// ```
+/// use jito_bytemuck::JitoAccount;
+///
+/// pub enum ExampleDiscriminator {
+///     Config = 1,
+///     Ticket = 2,
+/// }
+///
+/// #[derive(JitoAccount)]
+/// #[discriminator(ExampleDiscriminator::Config)]
+/// pub struct Config;
+///
+/// #[derive(JitoAccount)]
+/// #[discriminator(ExampleDiscriminator::Ticket)]
+/// pub struct Ticket;
+/// ```
+///
+/// Reusing `Config`'s discriminator for the ticket conflicts:
+///
+/// ```compile_fail,E0119
+/// use jito_bytemuck::JitoAccount;
+///
+/// pub enum ExampleDiscriminator {
+///     Config = 1,
+///     Ticket = 2,
+/// }
+///
+/// #[derive(JitoAccount)]
+/// #[discriminator(ExampleDiscriminator::Config)]
+/// pub struct Config;
+///
+/// #[derive(JitoAccount)]
+/// #[discriminator(ExampleDiscriminator::Config)]
+/// pub struct Ticket;
+/// ```
+pub trait DiscriminatorOwner<const DISCRIMINATOR: u8> {}
+
+/// Checks the discriminator and version in the account header
+fn check_header<T: Discriminator>(data: &[u8]) -> Result<(), ProgramError> {
+    if data.first() != Some(&T::DISCRIMINATOR) {
+        msg!(
+            "Discriminator is invalid; expected {}, got {}",
+            T::DISCRIMINATOR,
+            data.first().unwrap()
+        );
+        return Err(ProgramError::InvalidAccountData);
+    }
+    match data.get(1) {
+        Some(version) if *version == T::VERSION => Ok(()),
+        Some(version) if *version < T::VERSION => {
+            msg!(
+                "Account is at version {}, expected {}; it must be migrated first",
+                version,
+                T::VERSION
+            );
+            Err(ProgramError::InvalidAccountData)
+        }
+        version => {
+            msg!(
+                "Account version is unknown; expected {}, got {:?}",
+                T::VERSION,
+                version
+            );
+            Err(ProgramError::InvalidAccountData)
+        }
+    }
 }
 
 pub trait AccountDeserialize: Sized + Pod + Discriminator {
     /// Deserialize the account data into a struct.
-    /// It assumes the first byte is the discriminator and the next seven bytes are reserved.
+    /// It assumes the first byte is the discriminator, the second is the version and the next six bytes
+    /// are reserved. Accounts with any other discriminator or version are rejected.
     /// The rest of the data is deserialized into the struct.
     ///
     /// # Arguments
@@ -21,19 +97,13 @@ pub trait AccountDeserialize: Sized + Pod + Discriminator {
     /// # Returns
     /// * `Result<&Self, ProgramError>` - The deserialized struct as a reference or an error
     fn try_from_slice_unchecked(data: &[u8]) -> Result<&Self, ProgramError> {
-        if data.first() != Some(&Self::DISCRIMINATOR) {
-            msg!(
-                "Discriminator is invalid; expected {}, got {}",
-                Self::DISCRIMINATOR,
-                data.first().unwrap()
-            );
-            return Err(ProgramError::InvalidAccountData);
-        }
+        check_header::<Self>(data)?;
         bytemuck::try_from_bytes(&data[8..]).map_err(|_| ProgramError::InvalidAccountData)
     }
 
     /// Deserialize the account data into a mutable struct.
-    /// It assumes the first byte is the discriminator and the next seven bytes are reserved.
+    /// It assumes the first byte is the discriminator, the second is the version and the next six bytes
+    /// are reserved. Accounts with any other discriminator or version are rejected.
     /// The rest of the data is deserialized into the struct.
     ///
     /// # Arguments
@@ -42,14 +112,148 @@ pub trait AccountDeserialize: Sized + Pod + Discriminator {
     /// # Returns
     /// * `Result<&mut Self, ProgramError>` - The deserialized struct as a reference or an error
     fn try_from_slice_unchecked_mut(data: &mut [u8]) -> Result<&mut Self, ProgramError> {
-        if data.first() != Some(&Self::DISCRIMINATOR) {
-            msg!(
-                "Discriminator is invalid; expected {}, got {}",
-                Self::DISCRIMINATOR,
-                data.first().unwrap()
+        check_header::<Self>(data)?;
+        bytemuck::try_from_bytes_mut(&mut data[8..]).map_err(|_| ProgramError::InvalidAccountData)
+    }
+
+    /// Writes the discriminator and version into the header of newly allocated account data
+    ///
+    /// # Arguments
+    /// * `data` - The account data, including the eight byte header
+    ///
+    /// # Returns
+    /// * `Result<(), ProgramError>` - The result of the operation
+    fn write_header(data: &mut [u8]) -> Result<(), ProgramError> {
+        let header = data.get_mut(..2).ok_or(ProgramError::AccountDataTooSmall)?;
+        header.copy_from_slice(&[Self::DISCRIMINATOR, Self::VERSION]);
+        Ok(())
+    }
+}
+
+/// Converts an account written under an older layout version into the current one.
+///
+/// When an account's layout changes, bump its [`Discriminator::VERSION`] and implement
+/// [`Migrate::migrate`] for every version it can be upgraded from. Accounts without a previous
+/// layout keep the default implementation, which rejects every version.
+pub trait Migrate: AccountDeserialize {
+    /// Fills in the current layout from the body of an account at an older version.
+    ///
+    /// # Arguments
+    /// * `version` - The version the account was written with, always below [`Discriminator::VERSION`]
+    /// * `previous` - The account data after the eight byte header, in the old layout
+    /// * `account` - The account in the current layout, zeroed
+    ///
+    /// # Returns
+    /// * `Result<(), ProgramError>` - The result of the operation
+    fn migrate(version: u8, previous: &[u8], account: &mut Self) -> Result<(), ProgramError> {
+        let _ = (previous, account);
+        msg!(
+            "No migration from version {} to version {}",
+            version,
+            Self::VERSION
+        );
+        Err(ProgramError::InvalidAccountData)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bytemuck::Zeroable;
+
+    use super::*;
+
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    #[repr(C)]
+    struct TestAccount {
+        value: u64,
+    }
+
+    unsafe impl Zeroable for TestAccount {}
+    unsafe impl Pod for TestAccount {}
+
+    impl Discriminator for TestAccount {
+        const DISCRIMINATOR: u8 = 3;
+        const VERSION: u8 = 2;
+    }
+
+    impl AccountDeserialize for TestAccount {}
+
+    impl Migrate for TestAccount {
+        fn migrate(version: u8, previous: &[u8], account: &mut Self) -> Result<(), ProgramError> {
+            match version {
+                1 => {
+                    let value = previous
+                        .get(..4)
+                        .and_then(|bytes| bytes.try_into().ok())
+                        .ok_or(ProgramError::InvalidAccountData)?;
+                    account.value = u64::from(u32::from_le_bytes(value));
+                    Ok(())
+                }
+                _ => Err(ProgramError::InvalidAccountData),
+            }
+        }
+    }
+
+    fn account_data(discriminator: u8, version: u8, value: u64) -> Vec<u8> {
+        let mut data = vec![0; 8 + std::mem::size_of::<TestAccount>()];
+        data[0] = discriminator;
+        data[1] = version;
+        data[8..].copy_from_slice(&value.to_le_bytes());
+        data
+    }
+
+    #[test]
+    fn test_write_header() {
+        let mut data = vec![0; 8 + std::mem::size_of::<TestAccount>()];
+        TestAccount::write_header(&mut data).unwrap();
+        assert_eq!(data[..8], [3, 2, 0, 0, 0, 0, 0, 0]);
+        assert_eq!(
+            TestAccount::write_header(&mut [3]),
+            Err(ProgramError::AccountDataTooSmall)
+        );
+    }
+
+    #[test]
+    fn test_deserialize_current_version() {
+        let data = account_data(3, 2, 42);
+        assert_eq!(
+            TestAccount::try_from_slice_unchecked(&data).unwrap().value,
+            42
+        );
+    }
+
+    #[test]
+    fn test_deserialize_rejects_other_versions() {
+        for version in [0, 1, 3, u8::MAX] {
+            let mut data = account_data(3, version, 42);
+            assert_eq!(
+                TestAccount::try_from_slice_unchecked(&data),
+                Err(ProgramError::InvalidAccountData)
+            );
+            assert_eq!(
+                TestAccount::try_from_slice_unchecked_mut(&mut data),
+                Err(ProgramError::InvalidAccountData)
             );
-            return Err(ProgramError::InvalidAccountData);
         }
-        bytemuck::try_from_bytes_mut(&mut data[8..]).map_err(|_| ProgramError::InvalidAccountData)
+    }
+
+    #[test]
+    fn test_deserialize_rejects_wrong_discriminator() {
+        let data = account_data(4, 2, 42);
+        assert_eq!(
+            TestAccount::try_from_slice_unchecked(&data),
+            Err(ProgramError::InvalidAccountData)
+        );
+    }
+
+    #[test]
+    fn test_migrate() {
+        let mut account = TestAccount::zeroed();
+        TestAccount::migrate(1, &7u32.to_le_bytes(), &mut account).unwrap();
+        assert_eq!(account.value, 7);
+        assert_eq!(
+            TestAccount::migrate(0, &[], &mut account),
+            Err(ProgramError::InvalidAccountData)
+        );
     }
 }
@@ -7,6 +7,7 @@ use env_logger::Env;
 use jito_restaking_cli::{
     cli_args::{Cli, ProgramCommand},
     restaking_handler::RestakingCliHandler,
+    transaction::sign_and_send,
     vault_handler::VaultCliHandler,
     CliConfig,
 };
@@ -14,6 +15,7 @@ use jito_restaking_client::programs::JITO_RESTAKING_ID;
 use jito_vault_client::programs::JITO_VAULT_ID;
 use solana_cli_config::Config;
 use solana_program::pubkey::Pubkey;
+use solana_rpc_client::nonblocking::rpc_client::RpcClient;
 use solana_sdk::{commitment_config::CommitmentConfig, signature::read_keypair_file};
 
 pub fn get_cli_config(args: &Cli) -> Result<CliConfig, anyhow::Error> {
@@ -25,6 +27,10 @@ pub fn get_cli_config(args: &Cli) -> Result<CliConfig, anyhow::Error> {
             keypair: Some(
                 read_keypair_file(config.keypair_path).map_err(|e| anyhow!(e.to_string()))?,
             ),
+            output: args.output,
+            print_tx: args.print_tx,
+            signer_pubkey: args.signer_pubkey,
+            vault_transaction: args.vault_transaction,
         }
     } else {
         let config_file = solana_cli_config::CONFIG_FILE
@@ -47,6 +53,10 @@ pub fn get_cli_config(args: &Cli) -> Result<CliConfig, anyhow::Error> {
                 rpc_url: rpc,
                 commitment: CommitmentConfig::from_str(&config.commitment)?,
                 keypair: Some(keypair),
+                output: args.output,
+                print_tx: args.print_tx,
+                signer_pubkey: args.signer_pubkey,
+                vault_transaction: args.vault_transaction,
             }
         } else {
             CliConfig {
@@ -65,6 +75,10 @@ pub fn get_cli_config(args: &Cli) -> Result<CliConfig, anyhow::Error> {
                 } else {
                     None
                 },
+                output: args.output,
+                print_tx: args.print_tx,
+                signer_pubkey: args.signer_pubkey,
+                vault_transaction: args.vault_transaction,
             }
         }
     };
@@ -118,6 +132,24 @@ async fn main() -> Result<(), anyhow::Error> {
                 .handle(action)
                 .await?;
         }
+        ProgramCommand::SignAndSend {
+            transaction,
+            encoding,
+            signer,
+            refresh_blockhash,
+        } => {
+            let rpc_client =
+                RpcClient::new_with_commitment(cli_config.rpc_url.clone(), cli_config.commitment);
+            sign_and_send(
+                &cli_config,
+                &rpc_client,
+                &transaction,
+                encoding,
+                &signer,
+                refresh_blockhash,
+            )
+            .await?;
+        }
     }
 
     Ok(())
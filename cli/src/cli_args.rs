@@ -1,8 +1,13 @@
 use std::path::PathBuf;
 
-use clap::{Parser, Subcommand};
+use clap::{Parser, Subcommand, ValueEnum};
+use jito_jsm_core::pause::PauseCategory;
+use solana_program::pubkey::Pubkey;
 
-use crate::{restaking::RestakingCommands, vault::VaultCommands};
+use crate::{
+    output::OutputFormat, restaking::RestakingCommands, transaction::TxEncoding,
+    vault::VaultCommands,
+};
 
 #[derive(Parser)]
 #[command(author, version, about = "A CLI for managing restaking and vault operations", long_about = None)]
@@ -28,6 +33,39 @@ pub struct Cli {
     #[arg(long, global = true, help = "Keypair")]
     pub keypair: Option<String>,
 
+    #[arg(
+        long,
+        global = true,
+        value_enum,
+        default_value_t = OutputFormat::Table,
+        help = "Output format for fetched accounts"
+    )]
+    pub output: OutputFormat,
+
+    #[arg(
+        long,
+        global = true,
+        value_enum,
+        help = "Print the unsigned transaction message in this encoding instead of signing and sending it"
+    )]
+    pub print_tx: Option<TxEncoding>,
+
+    #[arg(
+        long,
+        global = true,
+        requires = "print_tx",
+        help = "Authority and fee payer to build transactions for, such as a multisig vault, instead of the keypair"
+    )]
+    pub signer_pubkey: Option<Pubkey>,
+
+    #[arg(
+        long,
+        global = true,
+        requires = "print_tx",
+        help = "With --print-tx, print a multisig vault-transaction message payload instead of a legacy message"
+    )]
+    pub vault_transaction: bool,
+
     #[arg(long, global = true, help = "Verbose mode")]
     pub verbose: bool,
 
@@ -47,4 +85,53 @@ pub enum ProgramCommand {
         #[command(subcommand)]
         action: VaultCommands,
     },
+    /// Sign a message or partially signed transaction printed by --print-tx, sending it once all
+    /// signatures are present and printing it for the remaining signers otherwise
+    SignAndSend {
+        /// The encoded message or transaction
+        transaction: String,
+        /// The encoding of the input and of any printed partially signed transaction
+        #[arg(long, value_enum, default_value_t = TxEncoding::Base58)]
+        encoding: TxEncoding,
+        /// Additional keypair files to sign with
+        #[arg(long)]
+        signer: Vec<String>,
+        /// Replace the blockhash of a message nobody has signed yet with a recent one
+        #[arg(long)]
+        refresh_blockhash: bool,
+    },
+}
+
+/// An instruction category that can be paused, see [`PauseCategory`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum PauseCategoryArg {
+    /// Depositing assets, such as minting VRT
+    Deposits,
+    /// Enqueuing, burning and transferring withdrawal tickets
+    Withdrawals,
+    /// Delegating and undelegating assets
+    Delegation,
+    /// Creating, warming up and cooling down tickets
+    TicketChanges,
+    /// Changing fees
+    FeeChanges,
+}
+
+impl From<PauseCategoryArg> for PauseCategory {
+    fn from(category: PauseCategoryArg) -> Self {
+        match category {
+            PauseCategoryArg::Deposits => Self::Deposits,
+            PauseCategoryArg::Withdrawals => Self::Withdrawals,
+            PauseCategoryArg::Delegation => Self::Delegation,
+            PauseCategoryArg::TicketChanges => Self::TicketChanges,
+            PauseCategoryArg::FeeChanges => Self::FeeChanges,
+        }
+    }
+}
+
+/// The paused categories bitmask with exactly the given categories paused
+pub fn paused_categories_mask(categories: &[PauseCategoryArg]) -> u16 {
+    categories.iter().fold(0, |paused_categories, category| {
+        paused_categories | PauseCategory::from(*category).mask()
+    })
 }
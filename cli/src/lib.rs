@@ -1,11 +1,18 @@
+use solana_program::pubkey::Pubkey;
 use solana_sdk::{commitment_config::CommitmentConfig, signature::Keypair};
 
+use crate::{output::OutputFormat, transaction::TxEncoding};
+
 pub mod cli_args;
 pub mod log;
+pub mod output;
 pub mod restaking;
 pub mod restaking_handler;
+pub mod restaking_views;
+pub mod transaction;
 pub mod vault;
 pub mod vault_handler;
+pub mod vault_views;
 
 pub struct CliConfig {
     pub rpc_url: String,
@@ -13,4 +20,15 @@ pub struct CliConfig {
     pub commitment: CommitmentConfig,
 
     pub keypair: Option<Keypair>,
+
+    pub output: OutputFormat,
+
+    /// Print transactions in this encoding instead of signing and sending them
+    pub print_tx: Option<TxEncoding>,
+
+    /// Build transactions for this authority and fee payer instead of the keypair
+    pub signer_pubkey: Option<Pubkey>,
+
+    /// Print multisig vault-transaction payloads instead of legacy messages
+    pub vault_transaction: bool,
 }
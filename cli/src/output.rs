@@ -0,0 +1,146 @@
+use anyhow::Result;
+use clap::ValueEnum;
+use jito_jsm_core::{
+    pause::PauseCategory,
+    slot_toggle::{SlotToggle, SlotToggleState},
+};
+use serde::Serialize;
+use serde_json::Value;
+
+/// The format accounts are printed in
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, ValueEnum)]
+pub enum OutputFormat {
+    /// Pretty-printed JSON
+    Json,
+    /// Aligned key-value rows, nested fields flattened into dotted keys
+    #[default]
+    Table,
+    /// YAML
+    Yaml,
+}
+
+/// The chain state used to decode slot-dependent fields
+#[derive(Clone, Copy, Debug)]
+pub struct ViewContext {
+    /// The current slot
+    pub slot: u64,
+
+    /// The epoch length of the program config the account belongs to
+    pub epoch_length: u64,
+}
+
+/// A raw token amount alongside its UI representation
+#[derive(Debug, Serialize)]
+pub struct TokenAmount {
+    pub amount: u64,
+    pub ui_amount: String,
+}
+
+impl TokenAmount {
+    pub fn new(amount: u64, decimals: u8) -> Self {
+        Self {
+            amount,
+            ui_amount: spl_token::amount_to_ui_amount_string_trimmed(amount, decimals),
+        }
+    }
+}
+
+/// A [`SlotToggle`] decoded at the current slot
+#[derive(Debug, Serialize)]
+pub struct SlotToggleView {
+    pub state: &'static str,
+    pub slot_added: u64,
+    pub slot_removed: u64,
+}
+
+impl SlotToggleView {
+    pub fn new(toggle: &SlotToggle, context: &ViewContext) -> Result<Self> {
+        let state = match toggle.state(context.slot, context.epoch_length)? {
+            SlotToggleState::Inactive => "inactive",
+            SlotToggleState::WarmUp => "warming up",
+            SlotToggleState::Active => "active",
+            SlotToggleState::Cooldown => "cooling down",
+        };
+        Ok(Self {
+            state,
+            slot_added: toggle.slot_added(),
+            slot_removed: toggle.slot_removed(),
+        })
+    }
+}
+
+/// The names of the categories set in a paused categories bitmask
+pub fn paused_category_names(paused_categories: u16) -> Vec<String> {
+    PauseCategory::ALL
+        .iter()
+        .filter(|category| category.is_paused(paused_categories))
+        .map(|category| format!("{:?}", category))
+        .collect()
+}
+
+/// Prints a value to stdout in the requested format. Lists are rendered as one table per entry.
+pub fn print<T: Serialize>(format: OutputFormat, value: &T) -> Result<()> {
+    let rendered = match format {
+        OutputFormat::Json => serde_json::to_string_pretty(value)?,
+        OutputFormat::Yaml => serde_yaml::to_string(value)?,
+        OutputFormat::Table => render_table(&serde_json::to_value(value)?),
+    };
+    println!("{}", rendered.trim_end());
+    Ok(())
+}
+
+fn render_table(value: &Value) -> String {
+    match value {
+        Value::Array(entries) if entries.iter().all(Value::is_object) => entries
+            .iter()
+            .map(render_table)
+            .collect::<Vec<_>>()
+            .join("\n\n"),
+        _ => {
+            let mut rows = Vec::new();
+            flatten("", value, &mut rows);
+            let width = rows.iter().map(|(key, _)| key.len()).max().unwrap_or(0);
+            rows.iter()
+                .map(|(key, value)| format!("{:<width$}  {}", key, value))
+                .collect::<Vec<_>>()
+                .join("\n")
+        }
+    }
+}
+
+fn flatten(prefix: &str, value: &Value, rows: &mut Vec<(String, String)>) {
+    let key = |name: &str| {
+        if prefix.is_empty() {
+            name.to_string()
+        } else {
+            format!("{}.{}", prefix, name)
+        }
+    };
+    match value {
+        Value::Object(fields) => {
+            for (name, field) in fields {
+                flatten(&key(name), field, rows);
+            }
+        }
+        Value::Array(items) if items.iter().any(|item| item.is_object() || item.is_array()) => {
+            for (index, item) in items.iter().enumerate() {
+                flatten(&key(&index.to_string()), item, rows);
+            }
+        }
+        _ => rows.push((prefix.to_string(), render_scalar(value))),
+    }
+}
+
+fn render_scalar(value: &Value) -> String {
+    match value {
+        Value::Null => "-".to_string(),
+        Value::String(s) => s.clone(),
+        Value::Array(items) if items.is_empty() => "-".to_string(),
+        Value::Array(items) => items
+            .iter()
+            .map(render_scalar)
+            .collect::<Vec<_>>()
+            .join(", "),
+        _ => value.to_string(),
+    }
+}
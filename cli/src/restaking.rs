@@ -1,6 +1,8 @@
 use clap::{command, Subcommand};
 use solana_program::pubkey::Pubkey;
 
+use crate::cli_args::PauseCategoryArg;
+
 /// The CLI handler for the restaking program
 #[derive(Subcommand)]
 pub enum RestakingCommands {
@@ -17,6 +19,16 @@ pub enum RestakingCommands {
         #[command(subcommand)]
         action: OperatorActions,
     },
+    /// Decode any restaking program account
+    Account {
+        /// The account's pubkey
+        pubkey: String,
+    },
+    /// Migrate a restaking program account written under an older layout to the current layout
+    MigrateAccount {
+        /// The account's pubkey
+        pubkey: String,
+    },
 }
 
 /// The actions that can be performed on the restaking config
@@ -31,6 +43,11 @@ pub enum ConfigActions {
         /// The new admin's pubkey
         new_admin: Pubkey,
     },
+    /// Set the paused instruction categories, unpausing every category not listed
+    SetPausedCategories {
+        #[arg(value_enum)]
+        paused_categories: Vec<PauseCategoryArg>,
+    },
 }
 
 #[derive(Subcommand)]
@@ -46,12 +63,65 @@ pub enum NcnActions {
     NcnWarmupOperator { ncn: String, operator: String },
     /// NCN Cooldown Operator State
     NcnCooldownOperator { ncn: String, operator: String },
+    /// Jail an operator, immediately excluding it from the NCN
+    JailOperator {
+        ncn: String,
+        operator: String,
+        /// NCN-defined reason code recorded on the NCN operator state
+        reason: u16,
+    },
+    /// Unjail a previously jailed operator
+    UnjailOperator { ncn: String, operator: String },
+    /// List jailed operators, optionally filtered by NCN
+    ListJailedOperators {
+        #[arg(long)]
+        ncn: Option<String>,
+    },
     /// Initialize NCN Vault Ticket
     InitializeNcnVaultTicket { ncn: String, vault: String },
     /// Warmup NCN Vault Ticket
     WarmupNcnVaultTicket { ncn: String, vault: String },
     /// Cooldown NCN Vault Ticket
     CooldownNcnVaultTicket { ncn: String, vault: String },
+    /// Initialize NCN Vault Slasher Ticket
+    InitializeNcnVaultSlasherTicket {
+        ncn: String,
+        vault: String,
+        slasher: String,
+        /// The most the slasher can slash from the vault in an epoch
+        max_slashable_per_epoch: u64,
+    },
+    /// Warmup NCN Vault Slasher Ticket
+    WarmupNcnVaultSlasherTicket {
+        ncn: String,
+        vault: String,
+        slasher: String,
+    },
+    /// Cooldown NCN Vault Slasher Ticket
+    CooldownNcnVaultSlasherTicket {
+        ncn: String,
+        vault: String,
+        slasher: String,
+    },
+    /// NCN Set Secondary Admin
+    NcnSetSecondaryAdmin {
+        ncn: String,
+        new_admin: String,
+        #[arg(long)]
+        set_operator_admin: bool,
+        #[arg(long)]
+        set_vault_admin: bool,
+        #[arg(long)]
+        set_slasher_admin: bool,
+        #[arg(long)]
+        set_delegate_admin: bool,
+        #[arg(long)]
+        set_metadata_admin: bool,
+        #[arg(long)]
+        set_weight_table_admin: bool,
+        #[arg(long)]
+        set_ncn_program_admin: bool,
+    },
     /// NCN Delegate Token Account
     NcnDelegateTokenAccount {
         ncn: String,
@@ -60,6 +130,12 @@ pub enum NcnActions {
         #[arg(long)]
         should_create_token_account: bool,
     },
+    /// NCN Set Paused Categories, unpausing every category not listed
+    NcnSetPausedCategories {
+        ncn: String,
+        #[arg(value_enum)]
+        paused_categories: Vec<PauseCategoryArg>,
+    },
     /// Get NCN
     Get { pubkey: String },
     /// List all NCNs
@@ -108,6 +184,12 @@ pub enum OperatorActions {
         #[arg(long)]
         should_create_token_account: bool,
     },
+    /// Operator Set Paused Categories, unpausing every category not listed
+    OperatorSetPausedCategories {
+        operator: String,
+        #[arg(value_enum)]
+        paused_categories: Vec<PauseCategoryArg>,
+    },
     /// Get operator
     Get { pubkey: String },
     /// List all operators
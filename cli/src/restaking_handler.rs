@@ -2,42 +2,54 @@ use std::str::FromStr;
 
 use anyhow::{anyhow, Result};
 use base64::{engine::general_purpose, Engine};
-use jito_bytemuck::AccountDeserialize;
+use jito_bytemuck::{AccountDeserialize, Discriminator};
+use jito_jsm_core::pause::PauseCategory;
 use jito_restaking_client::{
     instructions::{
-        CooldownNcnVaultTicketBuilder, CooldownOperatorVaultTicketBuilder, InitializeConfigBuilder,
-        InitializeNcnBuilder, InitializeNcnOperatorStateBuilder, InitializeNcnVaultTicketBuilder,
-        InitializeOperatorBuilder, InitializeOperatorVaultTicketBuilder,
-        NcnCooldownOperatorBuilder, NcnDelegateTokenAccountBuilder, NcnWarmupOperatorBuilder,
-        OperatorCooldownNcnBuilder, OperatorDelegateTokenAccountBuilder, OperatorSetFeeBuilder,
-        OperatorSetSecondaryAdminBuilder, OperatorWarmupNcnBuilder, SetConfigAdminBuilder,
-        WarmupNcnVaultTicketBuilder, WarmupOperatorVaultTicketBuilder,
+        CooldownNcnVaultSlasherTicketBuilder, CooldownNcnVaultTicketBuilder,
+        CooldownOperatorVaultTicketBuilder, InitializeConfigBuilder, InitializeNcnBuilder,
+        InitializeNcnOperatorStateBuilder, InitializeNcnVaultSlasherTicketBuilder,
+        InitializeNcnVaultTicketBuilder, InitializeOperatorBuilder,
+        InitializeOperatorVaultTicketBuilder, JailOperatorBuilder, MigrateAccountBuilder,
+        NcnCooldownOperatorBuilder, NcnDelegateTokenAccountBuilder, NcnSetPausedCategoriesBuilder,
+        NcnSetSecondaryAdminBuilder, NcnWarmupOperatorBuilder, OperatorCooldownNcnBuilder,
+        OperatorDelegateTokenAccountBuilder, OperatorSetFeeBuilder,
+        OperatorSetPausedCategoriesBuilder, OperatorSetSecondaryAdminBuilder,
+        OperatorWarmupNcnBuilder, SetConfigAdminBuilder, SetConfigPausedCategoriesBuilder,
+        UnjailOperatorBuilder, WarmupNcnVaultSlasherTicketBuilder, WarmupNcnVaultTicketBuilder,
+        WarmupOperatorVaultTicketBuilder,
     },
-    types::OperatorAdminRole,
+    types::{NcnAdminRole, OperatorAdminRole},
 };
 use jito_restaking_core::{
     config::Config, ncn::Ncn, ncn_operator_state::NcnOperatorState,
-    ncn_vault_ticket::NcnVaultTicket, operator::Operator,
-    operator_vault_ticket::OperatorVaultTicket,
+    ncn_vault_slasher_ticket::NcnVaultSlasherTicket, ncn_vault_ticket::NcnVaultTicket,
+    operator::Operator, operator_vault_ticket::OperatorVaultTicket,
 };
+use jito_vault_core::vault::Vault;
 use log::{debug, info};
 use solana_account_decoder::{UiAccountEncoding, UiDataSliceConfig};
 use solana_program::pubkey::Pubkey;
-use solana_rpc_client::{nonblocking::rpc_client::RpcClient, rpc_client::SerializableTransaction};
+use solana_rpc_client::nonblocking::rpc_client::RpcClient;
 use solana_rpc_client_api::{
     config::{RpcAccountInfoConfig, RpcProgramAccountsConfig},
     filter::{Memcmp, MemcmpEncodedBytes, RpcFilterType},
 };
-use solana_sdk::{
-    signature::{read_keypair_file, Keypair, Signer},
-    transaction::Transaction,
-};
+use solana_sdk::signature::{read_keypair_file, Keypair, Signer};
 use spl_associated_token_account::{
     get_associated_token_address, instruction::create_associated_token_account_idempotent,
 };
 
 use crate::{
+    cli_args::paused_categories_mask,
+    output::{self, ViewContext},
     restaking::{ConfigActions, NcnActions, OperatorActions, RestakingCommands},
+    restaking_views::{
+        ConfigView, NcnOperatorStateView, NcnVaultSlasherTicketView, NcnVaultTicketView, NcnView,
+        OperatorVaultTicketView, OperatorView,
+    },
+    transaction::{process_transaction, signer_pubkey},
+    vault_views::fetch_mint_decimals,
     CliConfig,
 };
 
@@ -105,6 +117,12 @@ impl RestakingCliHandler {
             RestakingCommands::Config {
                 action: ConfigActions::SetAdmin { new_admin },
             } => self.set_config_admin(new_admin).await,
+            RestakingCommands::Config {
+                action: ConfigActions::SetPausedCategories { paused_categories },
+            } => {
+                self.set_config_paused_categories(paused_categories_mask(&paused_categories))
+                    .await
+            }
             RestakingCommands::Ncn {
                 action:
                     NcnActions::Initialize {
@@ -120,6 +138,20 @@ impl RestakingCliHandler {
             RestakingCommands::Ncn {
                 action: NcnActions::NcnCooldownOperator { ncn, operator },
             } => self.ncn_cooldown_operator(ncn, operator).await,
+            RestakingCommands::Ncn {
+                action:
+                    NcnActions::JailOperator {
+                        ncn,
+                        operator,
+                        reason,
+                    },
+            } => self.jail_operator(ncn, operator, reason).await,
+            RestakingCommands::Ncn {
+                action: NcnActions::UnjailOperator { ncn, operator },
+            } => self.unjail_operator(ncn, operator).await,
+            RestakingCommands::Ncn {
+                action: NcnActions::ListJailedOperators { ncn },
+            } => self.list_jailed_operators(ncn).await,
             RestakingCommands::Ncn {
                 action: NcnActions::InitializeNcnVaultTicket { ncn, vault },
             } => self.initialize_ncn_vault_ticket(ncn, vault).await,
@@ -129,6 +161,72 @@ impl RestakingCliHandler {
             RestakingCommands::Ncn {
                 action: NcnActions::CooldownNcnVaultTicket { ncn, vault },
             } => self.cooldown_ncn_vault_ticket(ncn, vault).await,
+            RestakingCommands::Ncn {
+                action:
+                    NcnActions::InitializeNcnVaultSlasherTicket {
+                        ncn,
+                        vault,
+                        slasher,
+                        max_slashable_per_epoch,
+                    },
+            } => {
+                self.initialize_ncn_vault_slasher_ticket(
+                    ncn,
+                    vault,
+                    slasher,
+                    max_slashable_per_epoch,
+                )
+                .await
+            }
+            RestakingCommands::Ncn {
+                action:
+                    NcnActions::WarmupNcnVaultSlasherTicket {
+                        ncn,
+                        vault,
+                        slasher,
+                    },
+            } => {
+                self.warmup_ncn_vault_slasher_ticket(ncn, vault, slasher)
+                    .await
+            }
+            RestakingCommands::Ncn {
+                action:
+                    NcnActions::CooldownNcnVaultSlasherTicket {
+                        ncn,
+                        vault,
+                        slasher,
+                    },
+            } => {
+                self.cooldown_ncn_vault_slasher_ticket(ncn, vault, slasher)
+                    .await
+            }
+            RestakingCommands::Ncn {
+                action:
+                    NcnActions::NcnSetSecondaryAdmin {
+                        ncn,
+                        new_admin,
+                        set_operator_admin,
+                        set_vault_admin,
+                        set_slasher_admin,
+                        set_delegate_admin,
+                        set_metadata_admin,
+                        set_weight_table_admin,
+                        set_ncn_program_admin,
+                    },
+            } => {
+                self.ncn_set_secondary_admin(
+                    ncn,
+                    new_admin,
+                    set_operator_admin,
+                    set_vault_admin,
+                    set_slasher_admin,
+                    set_delegate_admin,
+                    set_metadata_admin,
+                    set_weight_table_admin,
+                    set_ncn_program_admin,
+                )
+                .await
+            }
             RestakingCommands::Ncn {
                 action:
                     NcnActions::NcnDelegateTokenAccount {
@@ -146,6 +244,16 @@ impl RestakingCliHandler {
                 )
                 .await
             }
+            RestakingCommands::Ncn {
+                action:
+                    NcnActions::NcnSetPausedCategories {
+                        ncn,
+                        paused_categories,
+                    },
+            } => {
+                self.ncn_set_paused_categories(ncn, paused_categories_mask(&paused_categories))
+                    .await
+            }
             RestakingCommands::Ncn {
                 action: NcnActions::Get { pubkey },
             } => self.get_ncn(pubkey).await,
@@ -217,21 +325,32 @@ impl RestakingCliHandler {
                 )
                 .await
             }
+            RestakingCommands::Operator {
+                action:
+                    OperatorActions::OperatorSetPausedCategories {
+                        operator,
+                        paused_categories,
+                    },
+            } => {
+                self.operator_set_paused_categories(
+                    operator,
+                    paused_categories_mask(&paused_categories),
+                )
+                .await
+            }
             RestakingCommands::Operator {
                 action: OperatorActions::Get { pubkey },
             } => self.get_operator(pubkey).await,
             RestakingCommands::Operator {
                 action: OperatorActions::List,
             } => self.list_operator().await,
+            RestakingCommands::Account { pubkey } => self.get_account(pubkey).await,
+            RestakingCommands::MigrateAccount { pubkey } => self.migrate_account(pubkey).await,
         }
     }
 
     pub async fn operator_set_fee(&self, operator: String, operator_fee_bps: u16) -> Result<()> {
-        let keypair = self
-            .cli_config
-            .keypair
-            .as_ref()
-            .ok_or_else(|| anyhow!("No keypair"))?;
+        let signer = signer_pubkey(&self.cli_config)?;
         let rpc_client = self.get_rpc_client();
 
         let (restaking_vault_config, _, _) =
@@ -243,23 +362,21 @@ impl RestakingCliHandler {
         ix_builder
             .operator(operator)
             .new_fee_bps(operator_fee_bps)
-            .admin(keypair.pubkey())
+            .admin(signer)
             .config(restaking_vault_config)
             .instruction();
 
-        let blockhash = rpc_client.get_latest_blockhash().await?;
-        let tx = Transaction::new_signed_with_payer(
-            &[ix_builder.instruction()],
-            Some(&keypair.pubkey()),
-            &[keypair],
-            blockhash,
-        );
         info!(
             "Setting fees to {:?} to Operator {}",
             operator_fee_bps, operator,
         );
-        let result = rpc_client.send_and_confirm_transaction(&tx).await?;
-        info!("Transaction confirmed: {:?}", result);
+        process_transaction(
+            &self.cli_config,
+            &rpc_client,
+            &[ix_builder.instruction()],
+            &[],
+        )
+        .await?;
 
         Ok(())
     }
@@ -275,11 +392,7 @@ impl RestakingCliHandler {
         set_delegate_admin: bool,
         set_metadata_admin: bool,
     ) -> Result<()> {
-        let keypair = self
-            .cli_config
-            .keypair
-            .as_ref()
-            .ok_or_else(|| anyhow!("No keypair"))?;
+        let signer = signer_pubkey(&self.cli_config)?;
         let rpc_client = self.get_rpc_client();
 
         let operator = Pubkey::from_str(&operator)?;
@@ -307,23 +420,21 @@ impl RestakingCliHandler {
             ix_builder
                 .new_admin(new_admin)
                 .operator(operator)
-                .admin(keypair.pubkey())
+                .admin(signer)
                 .operator_admin_role(*role)
                 .instruction();
 
-            let blockhash = rpc_client.get_latest_blockhash().await?;
-            let tx = Transaction::new_signed_with_payer(
-                &[ix_builder.instruction()],
-                Some(&keypair.pubkey()),
-                &[keypair],
-                blockhash,
-            );
             info!(
                 "Setting {:?} Admin to {} for Operator {}",
                 role, new_admin, operator
             );
-            let result = rpc_client.send_and_confirm_transaction(&tx).await?;
-            info!("Transaction confirmed: {:?}", result);
+            process_transaction(
+                &self.cli_config,
+                &rpc_client,
+                &[ix_builder.instruction()],
+                &[],
+            )
+            .await?;
         }
 
         Ok(())
@@ -336,11 +447,7 @@ impl RestakingCliHandler {
         token_mint: String,
         should_create_token_account: bool,
     ) -> Result<()> {
-        let keypair = self
-            .cli_config
-            .keypair
-            .as_ref()
-            .ok_or_else(|| anyhow!("No keypair"))?;
+        let signer = signer_pubkey(&self.cli_config)?;
         let rpc_client = self.get_rpc_client();
 
         let operator = Pubkey::from_str(&operator)?;
@@ -353,7 +460,7 @@ impl RestakingCliHandler {
 
         if should_create_token_account {
             let ix = create_associated_token_account_idempotent(
-                &keypair.pubkey(),
+                &signer,
                 &operator,
                 &token_mint,
                 &spl_token::id(),
@@ -365,22 +472,14 @@ impl RestakingCliHandler {
         ix_builder
             .operator(operator)
             .delegate(delegate)
-            .delegate_admin(keypair.pubkey())
+            .delegate_admin(signer)
             .token_account(token_account)
             .token_mint(token_mint);
 
         ixs.push(ix_builder.instruction());
 
-        let blockhash = rpc_client.get_latest_blockhash().await?;
-        let tx = Transaction::new_signed_with_payer(
-            &ixs,
-            Some(&keypair.pubkey()),
-            &[keypair],
-            blockhash,
-        );
         info!("Setting delegate for mint: {} to {}", token_mint, delegate,);
-        let result = rpc_client.send_and_confirm_transaction(&tx).await?;
-        info!("Transaction confirmed: {:?}", result);
+        process_transaction(&self.cli_config, &rpc_client, &ixs, &[]).await?;
 
         Ok(())
     }
@@ -392,11 +491,7 @@ impl RestakingCliHandler {
         token_mint: String,
         should_create_token_account: bool,
     ) -> Result<()> {
-        let keypair = self
-            .cli_config
-            .keypair
-            .as_ref()
-            .ok_or_else(|| anyhow!("No keypair"))?;
+        let signer = signer_pubkey(&self.cli_config)?;
         let rpc_client = self.get_rpc_client();
 
         let ncn = Pubkey::from_str(&ncn)?;
@@ -409,7 +504,7 @@ impl RestakingCliHandler {
 
         if should_create_token_account {
             let ix = create_associated_token_account_idempotent(
-                &keypair.pubkey(),
+                &signer,
                 &ncn,
                 &token_mint,
                 &spl_token::id(),
@@ -421,32 +516,251 @@ impl RestakingCliHandler {
         ix_builder
             .ncn(ncn)
             .delegate(delegate)
-            .delegate_admin(keypair.pubkey())
+            .delegate_admin(signer)
             .token_account(token_account)
             .token_mint(token_mint);
 
         ixs.push(ix_builder.instruction());
 
-        let blockhash = rpc_client.get_latest_blockhash().await?;
-        let tx = Transaction::new_signed_with_payer(
-            &ixs,
-            Some(&keypair.pubkey()),
-            &[keypair],
-            blockhash,
-        );
         info!("Setting delegate for mint: {} to {}", token_mint, delegate,);
-        let result = rpc_client.send_and_confirm_transaction(&tx).await?;
-        info!("Transaction confirmed: {:?}", result);
+        process_transaction(&self.cli_config, &rpc_client, &ixs, &[]).await?;
+
+        Ok(())
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub async fn ncn_set_secondary_admin(
+        &self,
+        ncn: String,
+        new_admin: String,
+        set_operator_admin: bool,
+        set_vault_admin: bool,
+        set_slasher_admin: bool,
+        set_delegate_admin: bool,
+        set_metadata_admin: bool,
+        set_weight_table_admin: bool,
+        set_ncn_program_admin: bool,
+    ) -> Result<()> {
+        let signer = signer_pubkey(&self.cli_config)?;
+        let rpc_client = self.get_rpc_client();
+
+        let ncn = Pubkey::from_str(&ncn)?;
+        let new_admin = Pubkey::from_str(&new_admin)?;
+
+        let ncn_account_raw = rpc_client.get_account(&ncn).await?;
+        let ncn_account = Ncn::try_from_slice_unchecked(&ncn_account_raw.data)?;
+        ncn_account.check_admin(&signer)?;
+
+        let mut roles: Vec<NcnAdminRole> = vec![];
+        if set_operator_admin {
+            roles.push(NcnAdminRole::OperatorAdmin);
+        }
+        if set_vault_admin {
+            roles.push(NcnAdminRole::VaultAdmin);
+        }
+        if set_slasher_admin {
+            roles.push(NcnAdminRole::SlasherAdmin);
+        }
+        if set_delegate_admin {
+            roles.push(NcnAdminRole::DelegateAdmin);
+        }
+        if set_metadata_admin {
+            roles.push(NcnAdminRole::MetadataAdmin);
+        }
+        if set_weight_table_admin {
+            roles.push(NcnAdminRole::WeightTableAdmin);
+        }
+        if set_ncn_program_admin {
+            roles.push(NcnAdminRole::NcnProgramAdmin);
+        }
+
+        for role in roles.iter() {
+            let mut ix_builder = NcnSetSecondaryAdminBuilder::new();
+            ix_builder
+                .ncn(ncn)
+                .admin(signer)
+                .new_admin(new_admin)
+                .ncn_admin_role(*role);
+
+            info!("Setting {:?} to {} for NCN {}", role, new_admin, ncn);
+            process_transaction(
+                &self.cli_config,
+                &rpc_client,
+                &[ix_builder.instruction()],
+                &[],
+            )
+            .await?;
+        }
+
+        Ok(())
+    }
+
+    /// Runs the checks the restaking program applies to NCN slasher ticket changes
+    async fn check_ncn_slasher_ticket_change(
+        &self,
+        rpc_client: &RpcClient,
+        ncn: &Pubkey,
+        slasher_admin: &Pubkey,
+    ) -> Result<()> {
+        let config = Config::find_program_address(&self.restaking_program_id).0;
+        let config_account_raw = rpc_client.get_account(&config).await?;
+        let config_account = Config::try_from_slice_unchecked(&config_account_raw.data)?;
+        config_account.check_is_category_paused(PauseCategory::TicketChanges)?;
+
+        let ncn_account_raw = rpc_client.get_account(ncn).await?;
+        let ncn_account = Ncn::try_from_slice_unchecked(&ncn_account_raw.data)?;
+        ncn_account.check_is_category_paused(PauseCategory::TicketChanges)?;
+        if ncn_account.slasher_admin.ne(slasher_admin) {
+            return Err(anyhow!("{} is not the NCN slasher admin", slasher_admin));
+        }
+
+        Ok(())
+    }
+
+    pub async fn initialize_ncn_vault_slasher_ticket(
+        &self,
+        ncn: String,
+        vault: String,
+        slasher: String,
+        max_slashable_per_epoch: u64,
+    ) -> Result<()> {
+        let signer = signer_pubkey(&self.cli_config)?;
+        let rpc_client = self.get_rpc_client();
+
+        let ncn = Pubkey::from_str(&ncn)?;
+        let vault = Pubkey::from_str(&vault)?;
+        let slasher = Pubkey::from_str(&slasher)?;
+
+        self.check_ncn_slasher_ticket_change(&rpc_client, &ncn, &signer)
+            .await?;
+
+        let (ncn_vault_ticket, _, _) =
+            NcnVaultTicket::find_program_address(&self.restaking_program_id, &ncn, &vault);
+        let (ncn_vault_slasher_ticket, _, _) = NcnVaultSlasherTicket::find_program_address(
+            &self.restaking_program_id,
+            &ncn,
+            &vault,
+            &slasher,
+        );
+
+        let mut ix_builder = InitializeNcnVaultSlasherTicketBuilder::new();
+        ix_builder
+            .config(Config::find_program_address(&self.restaking_program_id).0)
+            .ncn(ncn)
+            .vault(vault)
+            .slasher(slasher)
+            .ncn_vault_ticket(ncn_vault_ticket)
+            .ncn_vault_slasher_ticket(ncn_vault_slasher_ticket)
+            .admin(signer)
+            .payer(signer)
+            .max_slashable_per_epoch(max_slashable_per_epoch);
+
+        info!("Initializing NCN Vault Slasher Ticket");
+        process_transaction(
+            &self.cli_config,
+            &rpc_client,
+            &[ix_builder.instruction()],
+            &[],
+        )
+        .await?;
+        info!("NCN Vault Slasher Ticket: {}", ncn_vault_slasher_ticket);
+
+        Ok(())
+    }
+
+    pub async fn warmup_ncn_vault_slasher_ticket(
+        &self,
+        ncn: String,
+        vault: String,
+        slasher: String,
+    ) -> Result<()> {
+        let signer = signer_pubkey(&self.cli_config)?;
+        let rpc_client = self.get_rpc_client();
+
+        let ncn = Pubkey::from_str(&ncn)?;
+        let vault = Pubkey::from_str(&vault)?;
+        let slasher = Pubkey::from_str(&slasher)?;
+
+        self.check_ncn_slasher_ticket_change(&rpc_client, &ncn, &signer)
+            .await?;
+
+        let (ncn_vault_ticket, _, _) =
+            NcnVaultTicket::find_program_address(&self.restaking_program_id, &ncn, &vault);
+        let (ncn_vault_slasher_ticket, _, _) = NcnVaultSlasherTicket::find_program_address(
+            &self.restaking_program_id,
+            &ncn,
+            &vault,
+            &slasher,
+        );
+
+        let mut ix_builder = WarmupNcnVaultSlasherTicketBuilder::new();
+        ix_builder
+            .config(Config::find_program_address(&self.restaking_program_id).0)
+            .ncn(ncn)
+            .vault(vault)
+            .slasher(slasher)
+            .ncn_vault_ticket(ncn_vault_ticket)
+            .ncn_vault_slasher_ticket(ncn_vault_slasher_ticket)
+            .admin(signer);
+
+        info!("Warming up NCN Vault Slasher Ticket");
+        process_transaction(
+            &self.cli_config,
+            &rpc_client,
+            &[ix_builder.instruction()],
+            &[],
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn cooldown_ncn_vault_slasher_ticket(
+        &self,
+        ncn: String,
+        vault: String,
+        slasher: String,
+    ) -> Result<()> {
+        let signer = signer_pubkey(&self.cli_config)?;
+        let rpc_client = self.get_rpc_client();
+
+        let ncn = Pubkey::from_str(&ncn)?;
+        let vault = Pubkey::from_str(&vault)?;
+        let slasher = Pubkey::from_str(&slasher)?;
+
+        self.check_ncn_slasher_ticket_change(&rpc_client, &ncn, &signer)
+            .await?;
+
+        let (ncn_vault_slasher_ticket, _, _) = NcnVaultSlasherTicket::find_program_address(
+            &self.restaking_program_id,
+            &ncn,
+            &vault,
+            &slasher,
+        );
+
+        let mut ix_builder = CooldownNcnVaultSlasherTicketBuilder::new();
+        ix_builder
+            .config(Config::find_program_address(&self.restaking_program_id).0)
+            .ncn(ncn)
+            .vault(vault)
+            .slasher(slasher)
+            .ncn_vault_slasher_ticket(ncn_vault_slasher_ticket)
+            .admin(signer);
+
+        info!("Cooling down NCN Vault Slasher Ticket");
+        process_transaction(
+            &self.cli_config,
+            &rpc_client,
+            &[ix_builder.instruction()],
+            &[],
+        )
+        .await?;
 
         Ok(())
     }
 
     pub async fn initialize_ncn_vault_ticket(&self, ncn: String, vault: String) -> Result<()> {
-        let keypair = self
-            .cli_config
-            .keypair
-            .as_ref()
-            .ok_or_else(|| anyhow!("No keypair"))?;
+        let signer = signer_pubkey(&self.cli_config)?;
         let rpc_client = self.get_rpc_client();
 
         let ncn = Pubkey::from_str(&ncn)?;
@@ -461,30 +775,24 @@ impl RestakingCliHandler {
             .ncn(ncn)
             .vault(vault)
             .ncn_vault_ticket(ncn_vault_ticket)
-            .admin(keypair.pubkey())
-            .payer(keypair.pubkey())
+            .admin(signer)
+            .payer(signer)
             .instruction();
 
-        let blockhash = rpc_client.get_latest_blockhash().await?;
-        let tx = Transaction::new_signed_with_payer(
-            &[ix_builder.instruction()],
-            Some(&keypair.pubkey()),
-            &[keypair],
-            blockhash,
-        );
         info!("Initializing NCN Vault Ticket");
-        let result = rpc_client.send_and_confirm_transaction(&tx).await?;
-        info!("Transaction confirmed: {:?}", result);
+        process_transaction(
+            &self.cli_config,
+            &rpc_client,
+            &[ix_builder.instruction()],
+            &[],
+        )
+        .await?;
 
         Ok(())
     }
 
     pub async fn warmup_ncn_vault_ticket(&self, ncn: String, vault: String) -> Result<()> {
-        let keypair = self
-            .cli_config
-            .keypair
-            .as_ref()
-            .ok_or_else(|| anyhow!("No keypair"))?;
+        let signer = signer_pubkey(&self.cli_config)?;
         let rpc_client = self.get_rpc_client();
 
         let ncn = Pubkey::from_str(&ncn)?;
@@ -499,29 +807,23 @@ impl RestakingCliHandler {
             .ncn(ncn)
             .vault(vault)
             .ncn_vault_ticket(ncn_vault_ticket)
-            .admin(keypair.pubkey())
+            .admin(signer)
             .instruction();
 
-        let blockhash = rpc_client.get_latest_blockhash().await?;
-        let tx = Transaction::new_signed_with_payer(
-            &[ix_builder.instruction()],
-            Some(&keypair.pubkey()),
-            &[keypair],
-            blockhash,
-        );
         info!("Warmup NCN Vault Ticket");
-        let result = rpc_client.send_and_confirm_transaction(&tx).await?;
-        info!("Transaction confirmed: {:?}", result);
+        process_transaction(
+            &self.cli_config,
+            &rpc_client,
+            &[ix_builder.instruction()],
+            &[],
+        )
+        .await?;
 
         Ok(())
     }
 
     pub async fn cooldown_ncn_vault_ticket(&self, ncn: String, vault: String) -> Result<()> {
-        let keypair = self
-            .cli_config
-            .keypair
-            .as_ref()
-            .ok_or_else(|| anyhow!("No keypair"))?;
+        let signer = signer_pubkey(&self.cli_config)?;
         let rpc_client = self.get_rpc_client();
 
         let ncn = Pubkey::from_str(&ncn)?;
@@ -536,29 +838,23 @@ impl RestakingCliHandler {
             .ncn(ncn)
             .vault(vault)
             .ncn_vault_ticket(ncn_vault_ticket)
-            .admin(keypair.pubkey())
+            .admin(signer)
             .instruction();
 
-        let blockhash = rpc_client.get_latest_blockhash().await?;
-        let tx = Transaction::new_signed_with_payer(
-            &[ix_builder.instruction()],
-            Some(&keypair.pubkey()),
-            &[keypair],
-            blockhash,
-        );
         info!("Cooldown NCN Vault Ticket");
-        let result = rpc_client.send_and_confirm_transaction(&tx).await?;
-        info!("Transaction confirmed: {:?}", result);
+        process_transaction(
+            &self.cli_config,
+            &rpc_client,
+            &[ix_builder.instruction()],
+            &[],
+        )
+        .await?;
 
         Ok(())
     }
 
     pub async fn initialize_ncn_operator_state(&self, ncn: String, operator: String) -> Result<()> {
-        let keypair = self
-            .cli_config
-            .keypair
-            .as_ref()
-            .ok_or_else(|| anyhow!("No keypair"))?;
+        let signer = signer_pubkey(&self.cli_config)?;
         let rpc_client = self.get_rpc_client();
 
         let ncn = Pubkey::from_str(&ncn)?;
@@ -573,30 +869,24 @@ impl RestakingCliHandler {
             .ncn(ncn)
             .operator(operator)
             .ncn_operator_state(ncn_operator_state)
-            .admin(keypair.pubkey())
-            .payer(keypair.pubkey())
+            .admin(signer)
+            .payer(signer)
             .instruction();
 
-        let blockhash = rpc_client.get_latest_blockhash().await?;
-        let tx = Transaction::new_signed_with_payer(
-            &[ix_builder.instruction()],
-            Some(&keypair.pubkey()),
-            &[keypair],
-            blockhash,
-        );
         info!("Initializing NCN Operator State");
-        let result = rpc_client.send_and_confirm_transaction(&tx).await?;
-        info!("Transaction confirmed: {:?}", result);
+        process_transaction(
+            &self.cli_config,
+            &rpc_client,
+            &[ix_builder.instruction()],
+            &[],
+        )
+        .await?;
 
         Ok(())
     }
 
     pub async fn ncn_warmup_operator(&self, ncn: String, operator: String) -> Result<()> {
-        let keypair = self
-            .cli_config
-            .keypair
-            .as_ref()
-            .ok_or_else(|| anyhow!("No keypair"))?;
+        let signer = signer_pubkey(&self.cli_config)?;
         let rpc_client = self.get_rpc_client();
 
         let ncn = Pubkey::from_str(&ncn)?;
@@ -611,29 +901,23 @@ impl RestakingCliHandler {
             .ncn(ncn)
             .operator(operator)
             .ncn_operator_state(ncn_operator_state)
-            .admin(keypair.pubkey())
+            .admin(signer)
             .instruction();
 
-        let blockhash = rpc_client.get_latest_blockhash().await?;
-        let tx = Transaction::new_signed_with_payer(
-            &[ix_builder.instruction()],
-            Some(&keypair.pubkey()),
-            &[keypair],
-            blockhash,
-        );
         info!("NCN Warmup Operator");
-        let result = rpc_client.send_and_confirm_transaction(&tx).await?;
-        info!("Transaction confirmed: {:?}", result);
+        process_transaction(
+            &self.cli_config,
+            &rpc_client,
+            &[ix_builder.instruction()],
+            &[],
+        )
+        .await?;
 
         Ok(())
     }
 
     pub async fn ncn_cooldown_operator(&self, ncn: String, operator: String) -> Result<()> {
-        let keypair = self
-            .cli_config
-            .keypair
-            .as_ref()
-            .ok_or_else(|| anyhow!("No keypair"))?;
+        let signer = signer_pubkey(&self.cli_config)?;
         let rpc_client = self.get_rpc_client();
 
         let ncn = Pubkey::from_str(&ncn)?;
@@ -648,29 +932,84 @@ impl RestakingCliHandler {
             .ncn(ncn)
             .operator(operator)
             .ncn_operator_state(ncn_operator_state)
-            .admin(keypair.pubkey())
+            .admin(signer)
             .instruction();
 
-        let blockhash = rpc_client.get_latest_blockhash().await?;
-        let tx = Transaction::new_signed_with_payer(
-            &[ix_builder.instruction()],
-            Some(&keypair.pubkey()),
-            &[keypair],
-            blockhash,
-        );
         info!("NCN Cooldown Operator");
-        let result = rpc_client.send_and_confirm_transaction(&tx).await?;
-        info!("Transaction confirmed: {:?}", result);
+        process_transaction(
+            &self.cli_config,
+            &rpc_client,
+            &[ix_builder.instruction()],
+            &[],
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn jail_operator(&self, ncn: String, operator: String, reason: u16) -> Result<()> {
+        let signer = signer_pubkey(&self.cli_config)?;
+        let rpc_client = self.get_rpc_client();
+
+        let ncn = Pubkey::from_str(&ncn)?;
+        let operator = Pubkey::from_str(&operator)?;
+
+        let (ncn_operator_state, _, _) =
+            NcnOperatorState::find_program_address(&self.restaking_program_id, &ncn, &operator);
+
+        let mut ix_builder = JailOperatorBuilder::new();
+        ix_builder
+            .config(Config::find_program_address(&self.restaking_program_id).0)
+            .ncn(ncn)
+            .operator(operator)
+            .ncn_operator_state(ncn_operator_state)
+            .admin(signer)
+            .reason(reason);
+
+        info!("Jailing operator {} with reason {}", operator, reason);
+        process_transaction(
+            &self.cli_config,
+            &rpc_client,
+            &[ix_builder.instruction()],
+            &[],
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn unjail_operator(&self, ncn: String, operator: String) -> Result<()> {
+        let signer = signer_pubkey(&self.cli_config)?;
+        let rpc_client = self.get_rpc_client();
+
+        let ncn = Pubkey::from_str(&ncn)?;
+        let operator = Pubkey::from_str(&operator)?;
+
+        let (ncn_operator_state, _, _) =
+            NcnOperatorState::find_program_address(&self.restaking_program_id, &ncn, &operator);
+
+        let mut ix_builder = UnjailOperatorBuilder::new();
+        ix_builder
+            .config(Config::find_program_address(&self.restaking_program_id).0)
+            .ncn(ncn)
+            .operator(operator)
+            .ncn_operator_state(ncn_operator_state)
+            .admin(signer);
+
+        info!("Unjailing operator {}", operator);
+        process_transaction(
+            &self.cli_config,
+            &rpc_client,
+            &[ix_builder.instruction()],
+            &[],
+        )
+        .await?;
 
         Ok(())
     }
 
     pub async fn operator_warmup_ncn(&self, operator: String, ncn: String) -> Result<()> {
-        let keypair = self
-            .cli_config
-            .keypair
-            .as_ref()
-            .ok_or_else(|| anyhow!("No keypair"))?;
+        let signer = signer_pubkey(&self.cli_config)?;
         let rpc_client = self.get_rpc_client();
 
         let operator = Pubkey::from_str(&operator)?;
@@ -685,29 +1024,23 @@ impl RestakingCliHandler {
             .ncn(ncn)
             .operator(operator)
             .ncn_operator_state(ncn_operator_state)
-            .admin(keypair.pubkey())
+            .admin(signer)
             .instruction();
 
-        let blockhash = rpc_client.get_latest_blockhash().await?;
-        let tx = Transaction::new_signed_with_payer(
-            &[ix_builder.instruction()],
-            Some(&keypair.pubkey()),
-            &[keypair],
-            blockhash,
-        );
         info!("Operator Warmup NCN");
-        let result = rpc_client.send_and_confirm_transaction(&tx).await?;
-        info!("Transaction confirmed: {:?}", result);
+        process_transaction(
+            &self.cli_config,
+            &rpc_client,
+            &[ix_builder.instruction()],
+            &[],
+        )
+        .await?;
 
         Ok(())
     }
 
     pub async fn operator_cooldown_ncn(&self, operator: String, ncn: String) -> Result<()> {
-        let keypair = self
-            .cli_config
-            .keypair
-            .as_ref()
-            .ok_or_else(|| anyhow!("No keypair"))?;
+        let signer = signer_pubkey(&self.cli_config)?;
         let rpc_client = self.get_rpc_client();
 
         let operator = Pubkey::from_str(&operator)?;
@@ -722,60 +1055,44 @@ impl RestakingCliHandler {
             .ncn(ncn)
             .operator(operator)
             .ncn_operator_state(ncn_operator_state)
-            .admin(keypair.pubkey())
+            .admin(signer)
             .instruction();
 
-        let blockhash = rpc_client.get_latest_blockhash().await?;
-        let tx = Transaction::new_signed_with_payer(
-            &[ix_builder.instruction()],
-            Some(&keypair.pubkey()),
-            &[keypair],
-            blockhash,
-        );
         info!("Operator Cooldown NCN");
-        let result = rpc_client.send_and_confirm_transaction(&tx).await?;
-        info!("Transaction confirmed: {:?}", result);
+        process_transaction(
+            &self.cli_config,
+            &rpc_client,
+            &[ix_builder.instruction()],
+            &[],
+        )
+        .await?;
 
         Ok(())
     }
 
     async fn initialize_config(&self) -> Result<()> {
-        let keypair = self
-            .cli_config
-            .keypair
-            .as_ref()
-            .ok_or_else(|| anyhow!("No keypair"))?;
+        let signer = signer_pubkey(&self.cli_config)?;
         let rpc_client = self.get_rpc_client();
 
         let config_address = Config::find_program_address(&self.restaking_program_id).0;
         let mut ix_builder = InitializeConfigBuilder::new();
         ix_builder
             .config(config_address)
-            .admin(keypair.pubkey())
+            .admin(signer)
             .vault_program(self.vault_program_id);
-        let blockhash = rpc_client.get_latest_blockhash().await?;
-        let tx = Transaction::new_signed_with_payer(
-            &[ix_builder.instruction()],
-            Some(&keypair.pubkey()),
-            &[keypair],
-            blockhash,
-        );
         info!("Initializing restaking config parameters: {:?}", ix_builder);
-        info!(
-            "Initializing restaking config transaction: {:?}",
-            tx.get_signature()
-        );
-        rpc_client.send_and_confirm_transaction(&tx).await?;
-        info!("Transaction confirmed: {:?}", tx.get_signature());
+        process_transaction(
+            &self.cli_config,
+            &rpc_client,
+            &[ix_builder.instruction()],
+            &[],
+        )
+        .await?;
         Ok(())
     }
 
     pub async fn initialize_ncn(&self, path_to_base_keypair: Option<String>) -> Result<()> {
-        let keypair = self
-            .cli_config
-            .keypair
-            .as_ref()
-            .ok_or_else(|| anyhow!("No keypair"))?;
+        let signer = signer_pubkey(&self.cli_config)?;
         let rpc_client = self.get_rpc_client();
 
         let base =
@@ -786,43 +1103,25 @@ impl RestakingCliHandler {
         ix_builder
             .config(Config::find_program_address(&self.restaking_program_id).0)
             .ncn(ncn)
-            .admin(keypair.pubkey())
+            .admin(signer)
             .base(base.pubkey())
             .instruction();
 
-        let blockhash = rpc_client.get_latest_blockhash().await?;
-        let tx = Transaction::new_signed_with_payer(
-            &[ix_builder.instruction()],
-            Some(&keypair.pubkey()),
-            &[keypair, &base],
-            blockhash,
-        );
         info!("Initializing NCN: {:?}", ncn);
-        info!("Initializing NCN transaction: {:?}", tx.get_signature());
-        let result = rpc_client.send_and_confirm_transaction(&tx).await?;
-        info!("Transaction confirmed: {:?}", result);
-        let statuses = rpc_client
-            .get_signature_statuses(&[*tx.get_signature()])
-            .await?;
-
-        let tx_status = statuses
-            .value
-            .first()
-            .unwrap()
-            .as_ref()
-            .ok_or_else(|| anyhow!("No signature status"))?;
-        info!("Transaction status: {:?}", tx_status);
+        process_transaction(
+            &self.cli_config,
+            &rpc_client,
+            &[ix_builder.instruction()],
+            &[&base],
+        )
+        .await?;
         info!("NCN initialized at address: {:?}", ncn);
 
         Ok(())
     }
 
     pub async fn initialize_operator(&self, operator_fee_bps: u16) -> Result<()> {
-        let keypair = self
-            .cli_config
-            .keypair
-            .as_ref()
-            .ok_or_else(|| anyhow!("No keypair"))?;
+        let signer = signer_pubkey(&self.cli_config)?;
         let rpc_client = self.get_rpc_client();
 
         let base = Keypair::new();
@@ -832,36 +1131,19 @@ impl RestakingCliHandler {
         ix_builder
             .config(Config::find_program_address(&self.restaking_program_id).0)
             .operator(operator)
-            .admin(keypair.pubkey())
+            .admin(signer)
             .base(base.pubkey())
             .operator_fee_bps(operator_fee_bps)
             .instruction();
 
-        let blockhash = rpc_client.get_latest_blockhash().await?;
-        let tx = Transaction::new_signed_with_payer(
-            &[ix_builder.instruction()],
-            Some(&keypair.pubkey()),
-            &[keypair, &base],
-            blockhash,
-        );
         info!("Initializing operator: {:?}", operator);
-        info!(
-            "Initializing operator transaction: {:?}",
-            tx.get_signature()
-        );
-        rpc_client.send_and_confirm_transaction(&tx).await?;
-        info!("Transaction confirmed");
-        let statuses = rpc_client
-            .get_signature_statuses(&[*tx.get_signature()])
-            .await?;
-
-        let tx_status = statuses
-            .value
-            .first()
-            .unwrap()
-            .as_ref()
-            .ok_or_else(|| anyhow!("No signature status"))?;
-        info!("Transaction status: {:?}", tx_status);
+        process_transaction(
+            &self.cli_config,
+            &rpc_client,
+            &[ix_builder.instruction()],
+            &[&base],
+        )
+        .await?;
         info!("Operator initialized at address: {:?}", operator);
 
         Ok(())
@@ -872,11 +1154,7 @@ impl RestakingCliHandler {
         operator: String,
         vault: String,
     ) -> Result<()> {
-        let keypair = self
-            .cli_config
-            .keypair
-            .as_ref()
-            .ok_or_else(|| anyhow!("Keypair not provided"))?;
+        let signer = signer_pubkey(&self.cli_config)?;
         let rpc_client = self.get_rpc_client();
 
         let operator = Pubkey::from_str(&operator)?;
@@ -894,24 +1172,18 @@ impl RestakingCliHandler {
             .config(Config::find_program_address(&self.restaking_program_id).0)
             .operator(operator)
             .vault(vault)
-            .admin(keypair.pubkey())
+            .admin(signer)
             .operator_vault_ticket(operator_vault_ticket)
-            .payer(keypair.pubkey());
+            .payer(signer);
 
-        let blockhash = rpc_client.get_latest_blockhash().await?;
-        let tx = Transaction::new_signed_with_payer(
+        info!("Initializing operator vault ticket");
+        process_transaction(
+            &self.cli_config,
+            &rpc_client,
             &[ix_builder.instruction()],
-            Some(&keypair.pubkey()),
-            &[keypair],
-            blockhash,
-        );
-
-        info!(
-            "Initializing operator vault ticket transaction: {:?}",
-            tx.get_signature()
-        );
-        let result = rpc_client.send_and_confirm_transaction(&tx).await?;
-        info!("Transaction confirmed: {:?}", result);
+            &[],
+        )
+        .await?;
 
         info!("\nCreated Operator Vault Ticket");
         info!("Operator address: {}", operator);
@@ -926,11 +1198,7 @@ impl RestakingCliHandler {
         operator: String,
         vault: String,
     ) -> Result<()> {
-        let keypair = self
-            .cli_config
-            .keypair
-            .as_ref()
-            .ok_or_else(|| anyhow!("Keypair not provided"))?;
+        let signer = signer_pubkey(&self.cli_config)?;
         let rpc_client = self.get_rpc_client();
 
         let operator = Pubkey::from_str(&operator)?;
@@ -949,22 +1217,16 @@ impl RestakingCliHandler {
             .operator(operator)
             .vault(vault)
             .operator_vault_ticket(operator_vault_ticket)
-            .admin(keypair.pubkey());
+            .admin(signer);
 
-        let blockhash = rpc_client.get_latest_blockhash().await?;
-        let tx = Transaction::new_signed_with_payer(
+        info!("Warming up operator vault ticket");
+        process_transaction(
+            &self.cli_config,
+            &rpc_client,
             &[ix_builder.instruction()],
-            Some(&keypair.pubkey()),
-            &[keypair],
-            blockhash,
-        );
-
-        info!(
-            "Warming up operator vault ticket transaction: {:?}",
-            tx.get_signature()
-        );
-        let result = rpc_client.send_and_confirm_transaction(&tx).await?;
-        info!("Transaction confirmed: {:?}", result);
+            &[],
+        )
+        .await?;
 
         Ok(())
     }
@@ -974,11 +1236,7 @@ impl RestakingCliHandler {
         operator: String,
         vault: String,
     ) -> Result<()> {
-        let keypair = self
-            .cli_config
-            .keypair
-            .as_ref()
-            .ok_or_else(|| anyhow!("Keypair not provided"))?;
+        let signer = signer_pubkey(&self.cli_config)?;
         let rpc_client = self.get_rpc_client();
 
         let operator = Pubkey::from_str(&operator)?;
@@ -997,23 +1255,31 @@ impl RestakingCliHandler {
             .operator(operator)
             .vault(vault)
             .operator_vault_ticket(operator_vault_ticket)
-            .admin(keypair.pubkey());
-
-        let blockhash = rpc_client.get_latest_blockhash().await?;
-        let tx = Transaction::new_signed_with_payer(
-            &[ix_builder.instruction()],
-            Some(&keypair.pubkey()),
-            &[keypair],
-            blockhash,
-        );
+            .admin(signer);
 
         info!("Cooldown Operator Vault Ticket");
-        let result = rpc_client.send_and_confirm_transaction(&tx).await?;
-        info!("Transaction confirmed: {:?}", result);
+        process_transaction(
+            &self.cli_config,
+            &rpc_client,
+            &[ix_builder.instruction()],
+            &[],
+        )
+        .await?;
 
         Ok(())
     }
 
+    /// The current slot and restaking epoch length, used to decode slot-dependent fields
+    async fn get_view_context(&self, rpc_client: &RpcClient) -> Result<ViewContext> {
+        let config_address = Config::find_program_address(&self.restaking_program_id).0;
+        let account = rpc_client.get_account(&config_address).await?;
+        let config = Config::try_from_slice_unchecked(&account.data)?;
+        Ok(ViewContext {
+            slot: rpc_client.get_slot().await?,
+            epoch_length: config.epoch_length(),
+        })
+    }
+
     pub async fn get_config(&self) -> Result<()> {
         let rpc_client = self.get_rpc_client();
 
@@ -1025,19 +1291,19 @@ impl RestakingCliHandler {
 
         let account = rpc_client.get_account(&config_address).await?;
         let config = Config::try_from_slice_unchecked(&account.data)?;
-        info!(
-            "Restaking config at address {}: {:?}",
-            config_address, config
-        );
-        Ok(())
+        let context = ViewContext {
+            slot: rpc_client.get_slot().await?,
+            epoch_length: config.epoch_length(),
+        };
+        let view = ConfigView::new(&config_address, config, &context)?;
+        output::print(self.cli_config.output, &view)
     }
 
     pub async fn get_ncn(&self, pubkey: String) -> Result<()> {
         let pubkey = Pubkey::from_str(&pubkey)?;
         let account = self.get_rpc_client().get_account(&pubkey).await?;
         let ncn = Ncn::try_from_slice_unchecked(&account.data)?;
-        info!("NCN at address {}: {:?}", pubkey, ncn);
-        Ok(())
+        output::print(self.cli_config.output, &NcnView::new(&pubkey, ncn))
     }
 
     pub async fn list_ncn(&self) -> Result<()> {
@@ -1047,20 +1313,52 @@ impl RestakingCliHandler {
         let accounts = rpc_client
             .get_program_accounts_with_config(&self.restaking_program_id, config)
             .await?;
-        for (ncn_pubkey, ncn) in accounts {
-            let ncn = Ncn::try_from_slice_unchecked(&ncn.data)?;
-            info!("NCN at address {}: {:?}", ncn_pubkey, ncn);
+        let views = accounts
+            .iter()
+            .map(|(ncn_pubkey, ncn)| {
+                let ncn = Ncn::try_from_slice_unchecked(&ncn.data)?;
+                Ok(NcnView::new(ncn_pubkey, ncn))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        output::print(self.cli_config.output, &views)
+    }
+
+    pub async fn list_jailed_operators(&self, ncn: Option<String>) -> Result<()> {
+        let rpc_client = self.get_rpc_client();
+        let ncn = ncn.map(|ncn| Pubkey::from_str(&ncn)).transpose()?;
+        let context = self.get_view_context(&rpc_client).await?;
+        let config = self.get_rpc_program_accounts_config::<NcnOperatorState>()?;
+
+        let accounts = rpc_client
+            .get_program_accounts_with_config(&self.restaking_program_id, config)
+            .await?;
+        let mut views = Vec::new();
+        for (ncn_operator_state_pubkey, ncn_operator_state) in accounts {
+            let ncn_operator_state =
+                NcnOperatorState::try_from_slice_unchecked(&ncn_operator_state.data)?;
+            if !ncn_operator_state.is_jailed() {
+                continue;
+            }
+            if ncn.is_some_and(|ncn| ncn != ncn_operator_state.ncn) {
+                continue;
+            }
+            views.push(NcnOperatorStateView::new(
+                &ncn_operator_state_pubkey,
+                ncn_operator_state,
+                &context,
+            )?);
         }
-        Ok(())
+        output::print(self.cli_config.output, &views)
     }
 
     pub async fn get_operator(&self, pubkey: String) -> Result<()> {
         let pubkey = Pubkey::from_str(&pubkey)?;
         let account = self.get_rpc_client().get_account(&pubkey).await?;
         let operator = Operator::try_from_slice_unchecked(&account.data)?;
-        info!("Operator at address {}: {:?}", pubkey, operator);
-
-        Ok(())
+        output::print(
+            self.cli_config.output,
+            &OperatorView::new(&pubkey, operator),
+        )
     }
 
     pub async fn list_operator(&self) -> Result<()> {
@@ -1069,45 +1367,226 @@ impl RestakingCliHandler {
         let accounts = rpc_client
             .get_program_accounts_with_config(&self.restaking_program_id, config)
             .await?;
-        for (operator_pubkey, operator) in accounts {
-            let operator = Operator::try_from_slice_unchecked(&operator.data)?;
-            info!("Operator at address {}: {:?}", operator_pubkey, operator);
+        let views = accounts
+            .iter()
+            .map(|(operator_pubkey, operator)| {
+                let operator = Operator::try_from_slice_unchecked(&operator.data)?;
+                Ok(OperatorView::new(operator_pubkey, operator))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        output::print(self.cli_config.output, &views)
+    }
+
+    /// Decodes any restaking program account by its discriminator
+    pub async fn get_account(&self, pubkey: String) -> Result<()> {
+        let pubkey = Pubkey::from_str(&pubkey)?;
+        let rpc_client = self.get_rpc_client();
+        let account = rpc_client.get_account(&pubkey).await?;
+        if account.owner != self.restaking_program_id {
+            return Err(anyhow!(
+                "Account {} is owned by {}, not the restaking program",
+                pubkey,
+                account.owner
+            ));
+        }
+        let context = self.get_view_context(&rpc_client).await?;
+        let format = self.cli_config.output;
+
+        match account.data.first().copied() {
+            Some(Config::DISCRIMINATOR) => {
+                let config = Config::try_from_slice_unchecked(&account.data)?;
+                output::print(format, &ConfigView::new(&pubkey, config, &context)?)
+            }
+            Some(Ncn::DISCRIMINATOR) => {
+                let ncn = Ncn::try_from_slice_unchecked(&account.data)?;
+                output::print(format, &NcnView::new(&pubkey, ncn))
+            }
+            Some(Operator::DISCRIMINATOR) => {
+                let operator = Operator::try_from_slice_unchecked(&account.data)?;
+                output::print(format, &OperatorView::new(&pubkey, operator))
+            }
+            Some(NcnOperatorState::DISCRIMINATOR) => {
+                let state = NcnOperatorState::try_from_slice_unchecked(&account.data)?;
+                output::print(
+                    format,
+                    &NcnOperatorStateView::new(&pubkey, state, &context)?,
+                )
+            }
+            Some(OperatorVaultTicket::DISCRIMINATOR) => {
+                let ticket = OperatorVaultTicket::try_from_slice_unchecked(&account.data)?;
+                output::print(
+                    format,
+                    &OperatorVaultTicketView::new(&pubkey, ticket, &context)?,
+                )
+            }
+            Some(NcnVaultTicket::DISCRIMINATOR) => {
+                let ticket = NcnVaultTicket::try_from_slice_unchecked(&account.data)?;
+                output::print(format, &NcnVaultTicketView::new(&pubkey, ticket, &context)?)
+            }
+            Some(NcnVaultSlasherTicket::DISCRIMINATOR) => {
+                let ticket = NcnVaultSlasherTicket::try_from_slice_unchecked(&account.data)?;
+                let vault_account = rpc_client.get_account(&ticket.vault).await?;
+                let vault = Vault::try_from_slice_unchecked(&vault_account.data)?;
+                let supported_decimals =
+                    fetch_mint_decimals(&rpc_client, &vault.supported_mint).await?;
+                let view =
+                    NcnVaultSlasherTicketView::new(&pubkey, ticket, supported_decimals, &context)?;
+                output::print(format, &view)
+            }
+            discriminator => Err(anyhow!(
+                "Account {} has an unknown discriminator {:?}",
+                pubkey,
+                discriminator
+            )),
         }
-        Ok(())
     }
 
     async fn set_config_admin(&self, new_admin: Pubkey) -> Result<()> {
-        let keypair = self
-            .cli_config
-            .keypair
-            .as_ref()
-            .ok_or_else(|| anyhow!("No keypair"))?;
+        let signer = signer_pubkey(&self.cli_config)?;
         let rpc_client = self.get_rpc_client();
 
         let config_address = Config::find_program_address(&self.restaking_program_id).0;
         let mut ix_builder = SetConfigAdminBuilder::new();
         ix_builder
             .config(config_address)
-            .old_admin(keypair.pubkey())
+            .old_admin(signer)
             .new_admin(new_admin);
 
-        let blockhash = rpc_client.get_latest_blockhash().await?;
-        let tx = Transaction::new_signed_with_payer(
-            &[ix_builder.instruction()],
-            Some(&keypair.pubkey()),
-            &[keypair],
-            blockhash,
-        );
         info!(
             "Setting restaking config admin parameters: {:?}",
             ix_builder
         );
+        process_transaction(
+            &self.cli_config,
+            &rpc_client,
+            &[ix_builder.instruction()],
+            &[],
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn set_config_paused_categories(&self, paused_categories: u16) -> Result<()> {
+        let signer = signer_pubkey(&self.cli_config)?;
+        let rpc_client = self.get_rpc_client();
+
+        let config_address = Config::find_program_address(&self.restaking_program_id).0;
+        let config_account_raw = rpc_client.get_account(&config_address).await?;
+        let config_account = Config::try_from_slice_unchecked(&config_account_raw.data)?;
+        if config_account.admin.ne(&signer) {
+            return Err(anyhow!("Invalid admin for config"));
+        }
+
+        let mut ix_builder = SetConfigPausedCategoriesBuilder::new();
+        ix_builder
+            .config(config_address)
+            .admin(signer)
+            .paused_categories(paused_categories);
+
+        info!(
+            "Setting restaking config paused categories to {:?}",
+            output::paused_category_names(paused_categories)
+        );
+        process_transaction(
+            &self.cli_config,
+            &rpc_client,
+            &[ix_builder.instruction()],
+            &[],
+        )
+        .await?;
+        Ok(())
+    }
+
+    pub async fn ncn_set_paused_categories(
+        &self,
+        ncn: String,
+        paused_categories: u16,
+    ) -> Result<()> {
+        let signer = signer_pubkey(&self.cli_config)?;
+        let rpc_client = self.get_rpc_client();
+
+        let ncn = Pubkey::from_str(&ncn)?;
+        let ncn_account_raw = rpc_client.get_account(&ncn).await?;
+        let ncn_account = Ncn::try_from_slice_unchecked(&ncn_account_raw.data)?;
+        ncn_account.check_admin(&signer)?;
+
+        let mut ix_builder = NcnSetPausedCategoriesBuilder::new();
+        ix_builder
+            .config(Config::find_program_address(&self.restaking_program_id).0)
+            .ncn(ncn)
+            .admin(signer)
+            .paused_categories(paused_categories);
+
         info!(
-            "Setting restaking config admin transaction: {:?}",
-            tx.get_signature()
+            "Setting NCN {} paused categories to {:?}",
+            ncn,
+            output::paused_category_names(paused_categories)
         );
-        rpc_client.send_and_confirm_transaction(&tx).await?;
-        info!("Transaction confirmed: {:?}", tx.get_signature());
+        process_transaction(
+            &self.cli_config,
+            &rpc_client,
+            &[ix_builder.instruction()],
+            &[],
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn operator_set_paused_categories(
+        &self,
+        operator: String,
+        paused_categories: u16,
+    ) -> Result<()> {
+        let signer = signer_pubkey(&self.cli_config)?;
+        let rpc_client = self.get_rpc_client();
+
+        let operator = Pubkey::from_str(&operator)?;
+        let operator_account_raw = rpc_client.get_account(&operator).await?;
+        let operator_account = Operator::try_from_slice_unchecked(&operator_account_raw.data)?;
+        operator_account.check_admin(&signer)?;
+
+        let mut ix_builder = OperatorSetPausedCategoriesBuilder::new();
+        ix_builder
+            .config(Config::find_program_address(&self.restaking_program_id).0)
+            .operator(operator)
+            .admin(signer)
+            .paused_categories(paused_categories);
+
+        info!(
+            "Setting operator {} paused categories to {:?}",
+            operator,
+            output::paused_category_names(paused_categories)
+        );
+        process_transaction(
+            &self.cli_config,
+            &rpc_client,
+            &[ix_builder.instruction()],
+            &[],
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn migrate_account(&self, pubkey: String) -> Result<()> {
+        let signer = signer_pubkey(&self.cli_config)?;
+        let rpc_client = self.get_rpc_client();
+
+        let account = Pubkey::from_str(&pubkey)?;
+
+        let mut ix_builder = MigrateAccountBuilder::new();
+        ix_builder.account(account).payer(signer);
+
+        info!("Migrating account {}", account);
+        process_transaction(
+            &self.cli_config,
+            &rpc_client,
+            &[ix_builder.instruction()],
+            &[],
+        )
+        .await?;
+
         Ok(())
     }
 }
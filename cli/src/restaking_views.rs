@@ -0,0 +1,260 @@
+//! Human-readable views of the restaking program accounts, used by the `--output` formats
+
+use anyhow::Result;
+use jito_jsm_core::get_epoch;
+use jito_restaking_core::{
+    config::Config, ncn::Ncn, ncn_operator_state::NcnOperatorState,
+    ncn_vault_slasher_ticket::NcnVaultSlasherTicket, ncn_vault_ticket::NcnVaultTicket,
+    operator::Operator, operator_vault_ticket::OperatorVaultTicket,
+};
+use serde::Serialize;
+use solana_program::pubkey::Pubkey;
+
+use crate::output::{paused_category_names, SlotToggleView, TokenAmount, ViewContext};
+
+#[derive(Debug, Serialize)]
+pub struct ConfigView {
+    pub address: String,
+    pub admin: String,
+    pub vault_program: String,
+    pub epoch_length: u64,
+    pub current_epoch: u64,
+    pub ncn_count: u64,
+    pub operator_count: u64,
+    pub paused_categories: Vec<String>,
+    pub bump: u8,
+}
+
+impl ConfigView {
+    pub fn new(address: &Pubkey, config: &Config, context: &ViewContext) -> Result<Self> {
+        Ok(Self {
+            address: address.to_string(),
+            admin: config.admin.to_string(),
+            vault_program: config.vault_program.to_string(),
+            epoch_length: config.epoch_length(),
+            current_epoch: get_epoch(context.slot, config.epoch_length())?,
+            ncn_count: config.ncn_count(),
+            operator_count: config.operator_count(),
+            paused_categories: paused_category_names(config.paused_categories()),
+            bump: config.bump,
+        })
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct NcnAdminsView {
+    pub admin: String,
+    pub operator_admin: String,
+    pub vault_admin: String,
+    pub slasher_admin: String,
+    pub delegate_admin: String,
+    pub metadata_admin: String,
+    pub weight_table_admin: String,
+    pub ncn_program_admin: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct NcnView {
+    pub address: String,
+    pub base: String,
+    pub index: u64,
+    pub operator_count: u64,
+    pub vault_count: u64,
+    pub slasher_count: u64,
+    pub paused_categories: Vec<String>,
+    pub admins: NcnAdminsView,
+    pub bump: u8,
+}
+
+impl NcnView {
+    pub fn new(address: &Pubkey, ncn: &Ncn) -> Self {
+        Self {
+            address: address.to_string(),
+            base: ncn.base.to_string(),
+            index: ncn.index(),
+            operator_count: ncn.operator_count(),
+            vault_count: ncn.vault_count(),
+            slasher_count: ncn.slasher_count(),
+            paused_categories: paused_category_names(ncn.paused_categories()),
+            admins: NcnAdminsView {
+                admin: ncn.admin.to_string(),
+                operator_admin: ncn.operator_admin.to_string(),
+                vault_admin: ncn.vault_admin.to_string(),
+                slasher_admin: ncn.slasher_admin.to_string(),
+                delegate_admin: ncn.delegate_admin.to_string(),
+                metadata_admin: ncn.metadata_admin.to_string(),
+                weight_table_admin: ncn.weight_table_admin.to_string(),
+                ncn_program_admin: ncn.ncn_program_admin.to_string(),
+            },
+            bump: ncn.bump,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct OperatorAdminsView {
+    pub admin: String,
+    pub ncn_admin: String,
+    pub vault_admin: String,
+    pub delegate_admin: String,
+    pub metadata_admin: String,
+    pub voter: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct OperatorView {
+    pub address: String,
+    pub base: String,
+    pub index: u64,
+    pub operator_fee_bps: u16,
+    pub ncn_count: u64,
+    pub vault_count: u64,
+    pub jailed_ncn_count: u64,
+    pub paused_categories: Vec<String>,
+    pub admins: OperatorAdminsView,
+    pub bump: u8,
+}
+
+impl OperatorView {
+    pub fn new(address: &Pubkey, operator: &Operator) -> Self {
+        Self {
+            address: address.to_string(),
+            base: operator.base.to_string(),
+            index: operator.index(),
+            operator_fee_bps: operator.operator_fee_bps.into(),
+            ncn_count: operator.ncn_count(),
+            vault_count: operator.vault_count(),
+            jailed_ncn_count: operator.jailed_ncn_count(),
+            paused_categories: paused_category_names(operator.paused_categories()),
+            admins: OperatorAdminsView {
+                admin: operator.admin.to_string(),
+                ncn_admin: operator.ncn_admin.to_string(),
+                vault_admin: operator.vault_admin.to_string(),
+                delegate_admin: operator.delegate_admin.to_string(),
+                metadata_admin: operator.metadata_admin.to_string(),
+                voter: operator.voter.to_string(),
+            },
+            bump: operator.bump,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct NcnOperatorStateView {
+    pub address: String,
+    pub ncn: String,
+    pub operator: String,
+    pub index: u64,
+    pub ncn_opt_in_state: SlotToggleView,
+    pub operator_opt_in_state: SlotToggleView,
+    pub is_active: bool,
+    pub is_jailed: bool,
+    pub jail_reason: Option<u16>,
+    pub jailed_slot: Option<u64>,
+    pub bump: u8,
+}
+
+impl NcnOperatorStateView {
+    pub fn new(address: &Pubkey, state: &NcnOperatorState, context: &ViewContext) -> Result<Self> {
+        let is_jailed = state.is_jailed();
+        Ok(Self {
+            address: address.to_string(),
+            ncn: state.ncn.to_string(),
+            operator: state.operator.to_string(),
+            index: state.index(),
+            ncn_opt_in_state: SlotToggleView::new(&state.ncn_opt_in_state, context)?,
+            operator_opt_in_state: SlotToggleView::new(&state.operator_opt_in_state, context)?,
+            is_active: state.is_active(context.slot, context.epoch_length)?,
+            is_jailed,
+            jail_reason: is_jailed.then(|| state.jail_reason()),
+            jailed_slot: is_jailed.then(|| state.jailed_slot()),
+            bump: state.bump,
+        })
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct NcnVaultTicketView {
+    pub address: String,
+    pub ncn: String,
+    pub vault: String,
+    pub index: u64,
+    pub state: SlotToggleView,
+    pub bump: u8,
+}
+
+impl NcnVaultTicketView {
+    pub fn new(address: &Pubkey, ticket: &NcnVaultTicket, context: &ViewContext) -> Result<Self> {
+        Ok(Self {
+            address: address.to_string(),
+            ncn: ticket.ncn.to_string(),
+            vault: ticket.vault.to_string(),
+            index: ticket.index(),
+            state: SlotToggleView::new(&ticket.state, context)?,
+            bump: ticket.bump,
+        })
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct NcnVaultSlasherTicketView {
+    pub address: String,
+    pub ncn: String,
+    pub vault: String,
+    pub slasher: String,
+    pub index: u64,
+    pub max_slashable_per_epoch: TokenAmount,
+    pub state: SlotToggleView,
+    pub bump: u8,
+}
+
+impl NcnVaultSlasherTicketView {
+    /// `supported_decimals` are the decimals of the vault's supported mint, the token being slashed
+    pub fn new(
+        address: &Pubkey,
+        ticket: &NcnVaultSlasherTicket,
+        supported_decimals: u8,
+        context: &ViewContext,
+    ) -> Result<Self> {
+        Ok(Self {
+            address: address.to_string(),
+            ncn: ticket.ncn.to_string(),
+            vault: ticket.vault.to_string(),
+            slasher: ticket.slasher.to_string(),
+            index: ticket.index(),
+            max_slashable_per_epoch: TokenAmount::new(
+                ticket.max_slashable_per_epoch(),
+                supported_decimals,
+            ),
+            state: SlotToggleView::new(&ticket.state, context)?,
+            bump: ticket.bump,
+        })
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct OperatorVaultTicketView {
+    pub address: String,
+    pub operator: String,
+    pub vault: String,
+    pub index: u64,
+    pub state: SlotToggleView,
+    pub bump: u8,
+}
+
+impl OperatorVaultTicketView {
+    pub fn new(
+        address: &Pubkey,
+        ticket: &OperatorVaultTicket,
+        context: &ViewContext,
+    ) -> Result<Self> {
+        Ok(Self {
+            address: address.to_string(),
+            operator: ticket.operator.to_string(),
+            vault: ticket.vault.to_string(),
+            index: ticket.index(),
+            state: SlotToggleView::new(&ticket.state, context)?,
+            bump: ticket.bump,
+        })
+    }
+}
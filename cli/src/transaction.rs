@@ -0,0 +1,296 @@
+//! Building, exporting and sending transactions.
+//!
+//! By default every command signs with the local keypair and sends the transaction. With
+//! `--print-tx` the exact instructions are instead compiled for `--signer-pubkey` and printed,
+//! either as an unsigned legacy message or as a multisig vault-transaction payload, so they can be
+//! signed elsewhere and later submitted with `sign-and-send`.
+//!
+//! When a sent transaction fails, the error includes the program error and the failed account
+//! check decoded from the preflight logs.
+
+use std::fmt::Write;
+
+use anyhow::{anyhow, Result};
+use base64::{engine::general_purpose, Engine};
+use clap::ValueEnum;
+use jito_jsm_client::diagnostic::diagnose_client_error;
+use log::info;
+use solana_program::{
+    instruction::{AccountMeta, Instruction},
+    message::Message,
+    pubkey::Pubkey,
+};
+use solana_rpc_client::{nonblocking::rpc_client::RpcClient, rpc_client::SerializableTransaction};
+use solana_sdk::{
+    signature::{read_keypair_file, Keypair, Signature, Signer},
+    transaction::Transaction,
+};
+
+use crate::CliConfig;
+
+/// The encoding used for exported messages and transactions
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum TxEncoding {
+    Base58,
+    Base64,
+}
+
+impl TxEncoding {
+    pub fn encode(&self, bytes: &[u8]) -> String {
+        match self {
+            Self::Base58 => bs58::encode(bytes).into_string(),
+            Self::Base64 => general_purpose::STANDARD.encode(bytes),
+        }
+    }
+
+    pub fn decode(&self, encoded: &str) -> Result<Vec<u8>> {
+        let encoded = encoded.trim();
+        Ok(match self {
+            Self::Base58 => bs58::decode(encoded).into_vec()?,
+            Self::Base64 => general_purpose::STANDARD.decode(encoded)?,
+        })
+    }
+}
+
+/// The pubkey transactions are built for: `--signer-pubkey` if set, otherwise the local keypair's
+pub fn signer_pubkey(cli_config: &CliConfig) -> Result<Pubkey> {
+    if let Some(signer_pubkey) = cli_config.signer_pubkey {
+        return Ok(signer_pubkey);
+    }
+    cli_config
+        .keypair
+        .as_ref()
+        .map(|keypair| keypair.pubkey())
+        .ok_or_else(|| anyhow!("Keypair not provided"))
+}
+
+/// Signs the instructions with the local keypair and sends them, or prints them when `--print-tx`
+/// is set. `extra_signers` are keypairs created by the command itself, such as a base account.
+pub async fn process_transaction(
+    cli_config: &CliConfig,
+    rpc_client: &RpcClient,
+    instructions: &[Instruction],
+    extra_signers: &[&Keypair],
+) -> Result<()> {
+    let Some(encoding) = cli_config.print_tx else {
+        let keypair = cli_config
+            .keypair
+            .as_ref()
+            .ok_or_else(|| anyhow!("Keypair not provided"))?;
+        let signers: Vec<&Keypair> = std::iter::once(keypair)
+            .chain(extra_signers.iter().copied())
+            .collect();
+
+        let blockhash = rpc_client.get_latest_blockhash().await?;
+        let tx = Transaction::new_signed_with_payer(
+            instructions,
+            Some(&keypair.pubkey()),
+            &signers,
+            blockhash,
+        );
+        return send_transaction(rpc_client, &tx, instructions).await;
+    };
+
+    let payer = signer_pubkey(cli_config)?;
+
+    if cli_config.vault_transaction {
+        if !extra_signers.is_empty() {
+            return Err(anyhow!(
+                "This command needs additional signers and can't be wrapped in a vault transaction"
+            ));
+        }
+        let message = Message::new(instructions, Some(&payer));
+        info!("Vault transaction payload for {}:", payer);
+        println!("{}", encoding.encode(&vault_transaction_message(&message)?));
+        return Ok(());
+    }
+
+    let blockhash = rpc_client.get_latest_blockhash().await?;
+    let message = Message::new_with_blockhash(instructions, Some(&payer), &blockhash);
+    if extra_signers.is_empty() {
+        info!("Unsigned message for {}:", payer);
+        println!("{}", encoding.encode(&message.serialize()));
+    } else {
+        let mut tx = Transaction::new_unsigned(message);
+        tx.try_partial_sign(extra_signers, blockhash)?;
+        info!(
+            "Transaction partially signed by {}, awaiting {}:",
+            extra_signers
+                .iter()
+                .map(|signer| signer.pubkey().to_string())
+                .collect::<Vec<_>>()
+                .join(", "),
+            payer
+        );
+        println!("{}", encoding.encode(&bincode::serialize(&tx)?));
+    }
+    Ok(())
+}
+
+/// Serializes a message in the compact `TransactionMessage` layout that multisig programs such as
+/// Squads v4 take when creating a vault transaction: the signer/writable counts, then `u8`-length
+/// prefixed account keys and instructions, where instruction data has a `u16` length prefix.
+pub fn vault_transaction_message(message: &Message) -> Result<Vec<u8>> {
+    let header = &message.header;
+    let num_signers = header.num_required_signatures;
+    let num_writable_signers = num_signers
+        .checked_sub(header.num_readonly_signed_accounts)
+        .ok_or_else(|| anyhow!("Invalid message header"))?;
+    let num_writable_non_signers = message
+        .account_keys
+        .len()
+        .checked_sub(num_signers.into())
+        .and_then(|non_signers| {
+            non_signers.checked_sub(header.num_readonly_unsigned_accounts.into())
+        })
+        .ok_or_else(|| anyhow!("Invalid message header"))?;
+
+    let too_large = || anyhow!("Message is too large for a vault transaction");
+    let mut bytes = vec![
+        num_signers,
+        num_writable_signers,
+        u8::try_from(num_writable_non_signers).map_err(|_| too_large())?,
+        u8::try_from(message.account_keys.len()).map_err(|_| too_large())?,
+    ];
+    for key in message.account_keys.iter() {
+        bytes.extend_from_slice(key.as_ref());
+    }
+    bytes.push(u8::try_from(message.instructions.len()).map_err(|_| too_large())?);
+    for instruction in message.instructions.iter() {
+        bytes.push(instruction.program_id_index);
+        bytes.push(u8::try_from(instruction.accounts.len()).map_err(|_| too_large())?);
+        bytes.extend_from_slice(&instruction.accounts);
+        bytes.extend_from_slice(
+            &u16::try_from(instruction.data.len())
+                .map_err(|_| too_large())?
+                .to_le_bytes(),
+        );
+        bytes.extend_from_slice(&instruction.data);
+    }
+    // No address table lookups
+    bytes.push(0);
+
+    Ok(bytes)
+}
+
+/// Sends a signed transaction. If it fails, the error names the program error and the account
+/// check that failed, located in `instructions`.
+async fn send_transaction(
+    rpc_client: &RpcClient,
+    tx: &Transaction,
+    instructions: &[Instruction],
+) -> Result<()> {
+    info!("Sending transaction: {:?}", tx.get_signature());
+    rpc_client
+        .send_and_confirm_transaction(tx)
+        .await
+        .map_err(|e| match diagnose_client_error(instructions, &e) {
+            Some(diagnostic) => {
+                anyhow!(
+                    "Transaction {} failed: {}\n{}",
+                    tx.get_signature(),
+                    e,
+                    diagnostic
+                )
+            }
+            None => anyhow!("Transaction {} failed: {}", tx.get_signature(), e),
+        })?;
+    info!("Transaction confirmed: {:?}", tx.get_signature());
+    Ok(())
+}
+
+/// The instructions of a compiled message. The account metas are only used to locate accounts
+/// in failed transactions, so their signer and writable flags aren't restored.
+fn message_instructions(message: &Message) -> Vec<Instruction> {
+    message
+        .instructions
+        .iter()
+        .map(|instruction| Instruction {
+            program_id: message.account_keys[usize::from(instruction.program_id_index)],
+            accounts: instruction
+                .accounts
+                .iter()
+                .map(|index| {
+                    AccountMeta::new_readonly(message.account_keys[usize::from(*index)], false)
+                })
+                .collect(),
+            data: instruction.data.clone(),
+        })
+        .collect()
+}
+
+/// Decodes a transaction exported by `--print-tx`: either an unsigned message or a transaction
+/// carrying some of its signatures
+fn decode_transaction(bytes: &[u8]) -> Result<Transaction> {
+    if let Ok(tx) = bincode::deserialize::<Transaction>(bytes) {
+        let is_transaction = tx.signatures.len()
+            == usize::from(tx.message.header.num_required_signatures)
+            && bincode::serialized_size(&tx)? == bytes.len() as u64;
+        if is_transaction {
+            return Ok(tx);
+        }
+    }
+    if let Ok(message) = bincode::deserialize::<Message>(bytes) {
+        if message.serialize().len() == bytes.len() {
+            return Ok(Transaction::new_unsigned(message));
+        }
+    }
+    Err(anyhow!("Input is neither a message nor a transaction"))
+}
+
+/// Adds the local signatures to an exported message or partially signed transaction. Sends it
+/// once every signature is present, otherwise prints it for the remaining signers.
+pub async fn sign_and_send(
+    cli_config: &CliConfig,
+    rpc_client: &RpcClient,
+    encoded: &str,
+    encoding: TxEncoding,
+    signer_paths: &[String],
+    refresh_blockhash: bool,
+) -> Result<()> {
+    let mut tx = decode_transaction(&encoding.decode(encoded)?)?;
+
+    let mut keypairs = Vec::new();
+    for path in signer_paths {
+        keypairs.push(read_keypair_file(path).map_err(|e| anyhow!("{}: {}", path, e))?);
+    }
+
+    let required_signers = &tx.message.account_keys[..tx.signatures.len()];
+    let signers: Vec<&Keypair> = cli_config
+        .keypair
+        .iter()
+        .chain(keypairs.iter())
+        .filter(|keypair| required_signers.contains(&keypair.pubkey()))
+        .collect();
+
+    let blockhash = if refresh_blockhash {
+        if tx
+            .signatures
+            .iter()
+            .any(|signature| *signature != Signature::default())
+        {
+            return Err(anyhow!(
+                "Can't refresh the blockhash of a transaction that already has signatures"
+            ));
+        }
+        rpc_client.get_latest_blockhash().await?
+    } else {
+        tx.message.recent_blockhash
+    };
+    tx.try_partial_sign(&signers, blockhash)?;
+
+    if tx.is_signed() {
+        tx.verify()?;
+        return send_transaction(rpc_client, &tx, &message_instructions(&tx.message)).await;
+    }
+
+    let mut missing = String::new();
+    for (signature, key) in tx.signatures.iter().zip(tx.message.account_keys.iter()) {
+        if *signature == Signature::default() {
+            write!(missing, " {}", key)?;
+        }
+    }
+    info!("Transaction still needs signatures from:{}", missing);
+    println!("{}", encoding.encode(&bincode::serialize(&tx)?));
+    Ok(())
+}
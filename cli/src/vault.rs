@@ -1,8 +1,10 @@
 use std::path::PathBuf;
 
-use clap::{command, Subcommand};
+use clap::{command, ArgAction, Subcommand};
 use solana_program::pubkey::Pubkey;
 
+use crate::cli_args::PauseCategoryArg;
+
 #[derive(Subcommand)]
 pub enum VaultCommands {
     Config {
@@ -13,6 +15,16 @@ pub enum VaultCommands {
         #[command(subcommand)]
         action: VaultActions,
     },
+    /// Decodes any vault program account
+    Account {
+        /// The account's pubkey
+        pubkey: String,
+    },
+    /// Migrates a vault program account written under an older layout to the current layout
+    MigrateAccount {
+        /// The account's pubkey
+        pubkey: String,
+    },
 }
 
 #[derive(Subcommand)]
@@ -31,6 +43,16 @@ pub enum ConfigActions {
         /// The new admin's pubkey
         new_admin: Pubkey,
     },
+    /// Set the program fee
+    SetProgramFee {
+        /// The new program fee in basis points
+        new_fee_bps: u16,
+    },
+    /// Set the program fee wallet
+    SetProgramFeeWallet {
+        /// The new program fee wallet pubkey
+        new_fee_wallet: Pubkey,
+    },
 }
 
 /// Vault commands
@@ -194,4 +216,160 @@ pub enum VaultActions {
         /// The new capacity
         amount: u64,
     },
+    /// Sets the deposit, withdrawal and reward fees, only the provided fees are changed
+    SetFees {
+        /// The vault pubkey
+        vault: String,
+        /// The new deposit fee in bips
+        #[arg(long)]
+        deposit_fee_bps: Option<u16>,
+        /// The new withdrawal fee in bips
+        #[arg(long)]
+        withdrawal_fee_bps: Option<u16>,
+        /// The new reward fee in bips
+        #[arg(long)]
+        reward_fee_bps: Option<u16>,
+    },
+    /// Pauses or unpauses the vault
+    SetIsPaused {
+        /// The vault pubkey
+        vault: String,
+        /// Whether the vault should be paused
+        #[arg(action = ArgAction::Set)]
+        is_paused: bool,
+    },
+    /// Sets one or more secondary admins of the vault
+    SetSecondaryAdmin {
+        /// The vault pubkey
+        vault: String,
+        /// The new admin's pubkey
+        new_admin: String,
+        #[arg(long)]
+        set_delegation_admin: bool,
+        #[arg(long)]
+        set_operator_admin: bool,
+        #[arg(long)]
+        set_ncn_admin: bool,
+        #[arg(long)]
+        set_slasher_admin: bool,
+        #[arg(long)]
+        set_capacity_admin: bool,
+        #[arg(long)]
+        set_fee_wallet: bool,
+        #[arg(long)]
+        set_mint_burn_admin: bool,
+        #[arg(long)]
+        set_delegate_asset_admin: bool,
+        #[arg(long)]
+        set_fee_admin: bool,
+        #[arg(long)]
+        set_metadata_admin: bool,
+    },
+    /// Transfers a withdrawal ticket to a new owner
+    ChangeWithdrawalTicketOwner {
+        /// Vault account
+        vault: String,
+        /// The new owner's pubkey
+        new_owner: String,
+        /// The base the ticket was created with, defaults to the keypair
+        #[arg(long)]
+        base: Option<String>,
+    },
+    /// Delegates a token account held by the vault
+    DelegateTokenAccount {
+        /// Vault account
+        vault: String,
+        /// The delegate's pubkey
+        delegate: String,
+        /// The mint of the token account, can't be the vault's supported mint
+        token_mint: String,
+        #[arg(long)]
+        should_create_token_account: bool,
+    },
+    /// Initialize Vault NCN Slasher Ticket
+    InitializeVaultNcnSlasherTicket {
+        /// Vault account
+        vault: String,
+        /// NCN account
+        ncn: String,
+        /// Slasher account
+        slasher: String,
+    },
+    /// Warmup Vault NCN Slasher Ticket
+    WarmupVaultNcnSlasherTicket {
+        /// Vault account
+        vault: String,
+        /// NCN account
+        ncn: String,
+        /// Slasher account
+        slasher: String,
+    },
+    /// Cooldown Vault NCN Slasher Ticket
+    CooldownVaultNcnSlasherTicket {
+        /// Vault account
+        vault: String,
+        /// NCN account
+        ncn: String,
+        /// Slasher account
+        slasher: String,
+    },
+    /// Updates the vault's token balance and collects the reward fee
+    UpdateVaultBalance {
+        /// Vault account
+        vault: String,
+    },
+    /// Initializes a vault with an already-created VRT mint, currently a no-op on-chain
+    InitializeVaultWithMint,
+    /// Nests the vault over a child vault whose VRT is the vault's supported mint
+    SetChildVault {
+        /// Vault account
+        vault: String,
+        /// The child vault, whose VRT mint must be the vault's supported mint
+        child_vault: String,
+    },
+    /// Enters or leaves emergency exit, only the config admin can leave it
+    SetIsEmergencyExit {
+        /// Vault account
+        vault: String,
+        /// Whether the vault should be in emergency exit
+        #[arg(action = ArgAction::Set)]
+        is_emergency_exit: bool,
+    },
+    /// Sets the paused instruction categories, unpausing every category not listed
+    SetPausedCategories {
+        /// Vault account
+        vault: String,
+        /// The categories to pause
+        #[arg(value_enum)]
+        paused_categories: Vec<PauseCategoryArg>,
+    },
+    /// Registers an auto-claim delegate on the keypair's withdrawal ticket
+    SetAutoClaimDelegate {
+        /// Vault account
+        vault: String,
+        /// The delegate's pubkey, the system program removes the delegate
+        auto_claim_delegate: String,
+        /// The largest tip in bips the delegate can take from the withdrawn assets
+        max_tip_bps: u16,
+        /// The base the ticket was created with, defaults to the keypair
+        #[arg(long)]
+        base: Option<String>,
+    },
+    /// Burns a withdrawal ticket that names the keypair as its auto-claim delegate
+    AutoClaimWithdrawalTicket {
+        /// Vault account
+        vault: String,
+        /// The base the ticket was created with, the staker for tickets enqueued with this CLI
+        base: String,
+        /// The tip in bips taken from the withdrawn assets
+        tip_bps: u16,
+    },
+    /// Cranks the vault update state tracker for several operators in one instruction
+    CrankVaultUpdateStateTrackerMany {
+        /// Vault account
+        vault: String,
+        /// Operator accounts, in the order they're cranked
+        #[arg(required = true)]
+        operators: Vec<String>,
+    },
 }
@@ -2,25 +2,37 @@ use std::{path::PathBuf, str::FromStr};
 
 use anyhow::{anyhow, Result};
 use base64::{engine::general_purpose, Engine};
-use jito_bytemuck::AccountDeserialize;
-use jito_jsm_core::get_epoch;
+use jito_bytemuck::{AccountDeserialize, Discriminator};
+use jito_jsm_core::{get_epoch, pause::PauseCategory};
 use jito_restaking_core::{
-    ncn_vault_ticket::NcnVaultTicket, operator_vault_ticket::OperatorVaultTicket,
+    ncn_operator_state::NcnOperatorState, ncn_vault_slasher_ticket::NcnVaultSlasherTicket,
+    ncn_vault_ticket::NcnVaultTicket, operator::Operator,
+    operator_vault_ticket::OperatorVaultTicket,
 };
 use jito_vault_client::{
     instructions::{
-        AddDelegationBuilder, BurnWithdrawalTicketBuilder, CloseVaultUpdateStateTrackerBuilder,
-        CooldownDelegationBuilder, CooldownVaultNcnTicketBuilder,
-        CrankVaultUpdateStateTrackerBuilder, CreateTokenMetadataBuilder, EnqueueWithdrawalBuilder,
-        InitializeConfigBuilder, InitializeVaultBuilder, InitializeVaultNcnTicketBuilder,
-        InitializeVaultOperatorDelegationBuilder, InitializeVaultUpdateStateTrackerBuilder,
-        MintToBuilder, SetConfigAdminBuilder, SetDepositCapacityBuilder,
-        UpdateTokenMetadataBuilder, WarmupVaultNcnTicketBuilder,
+        AddDelegationBuilder, AutoClaimWithdrawalTicketBuilder, BurnWithdrawalTicketBuilder,
+        ChangeWithdrawalTicketOwnerBuilder, CloseVaultUpdateStateTrackerBuilder,
+        CooldownDelegationBuilder, CooldownVaultNcnSlasherTicketBuilder,
+        CooldownVaultNcnTicketBuilder, CrankVaultUpdateStateTrackerBuilder,
+        CrankVaultUpdateStateTrackerManyBuilder, CreateTokenMetadataBuilder,
+        DelegateTokenAccountBuilder, EnqueueWithdrawalBuilder, InitializeConfigBuilder,
+        InitializeVaultBuilder, InitializeVaultNcnSlasherTicketBuilder,
+        InitializeVaultNcnTicketBuilder, InitializeVaultOperatorDelegationBuilder,
+        InitializeVaultUpdateStateTrackerBuilder, InitializeVaultWithMintBuilder,
+        MigrateAccountBuilder, MintToBuilder, SetAutoClaimDelegateBuilder, SetChildVaultBuilder,
+        SetConfigAdminBuilder, SetDepositCapacityBuilder, SetFeesBuilder,
+        SetIsEmergencyExitBuilder, SetIsPausedBuilder, SetPausedCategoriesBuilder,
+        SetProgramFeeBuilder, SetProgramFeeWalletBuilder, SetSecondaryAdminBuilder,
+        UpdateTokenMetadataBuilder, UpdateVaultBalanceBuilder, WarmupVaultNcnSlasherTicketBuilder,
+        WarmupVaultNcnTicketBuilder,
     },
-    types::WithdrawalAllocationMethod,
+    types::{VaultAdminRole, WithdrawalAllocationMethod},
 };
 use jito_vault_core::{
-    burn_vault::BurnVault, config::Config, vault::Vault, vault_ncn_ticket::VaultNcnTicket,
+    burn_vault::BurnVault, config::Config, vault::Vault,
+    vault_ncn_slasher_operator_ticket::VaultNcnSlasherOperatorTicket,
+    vault_ncn_slasher_ticket::VaultNcnSlasherTicket, vault_ncn_ticket::VaultNcnTicket,
     vault_operator_delegation::VaultOperatorDelegation,
     vault_staker_withdrawal_ticket::VaultStakerWithdrawalTicket,
     vault_update_state_tracker::VaultUpdateStateTracker,
@@ -28,22 +40,27 @@ use jito_vault_core::{
 use jito_vault_sdk::inline_mpl_token_metadata;
 use log::{debug, info};
 use solana_account_decoder::{UiAccountEncoding, UiDataSliceConfig};
-use solana_program::pubkey::Pubkey;
-use solana_rpc_client::{nonblocking::rpc_client::RpcClient, rpc_client::SerializableTransaction};
+use solana_program::{instruction::AccountMeta, pubkey::Pubkey};
+use solana_rpc_client::nonblocking::rpc_client::RpcClient;
 use solana_rpc_client_api::{
     config::{RpcAccountInfoConfig, RpcProgramAccountsConfig},
     filter::{Memcmp, MemcmpEncodedBytes, RpcFilterType},
 };
-use solana_sdk::{
-    signature::{read_keypair_file, Keypair, Signer},
-    transaction::Transaction,
-};
+use solana_sdk::signature::{read_keypair_file, Keypair, Signer};
 use spl_associated_token_account::{
     get_associated_token_address, instruction::create_associated_token_account_idempotent,
 };
 
 use crate::{
+    cli_args::paused_categories_mask,
+    output::{self, ViewContext},
+    transaction::{process_transaction, signer_pubkey},
     vault::{ConfigActions, VaultActions, VaultCommands},
+    vault_views::{
+        ConfigView, VaultDecimals, VaultNcnSlasherOperatorTicketView, VaultNcnSlasherTicketView,
+        VaultNcnTicketView, VaultOperatorDelegationView, VaultStakerWithdrawalTicketView,
+        VaultUpdateStateTrackerView, VaultView,
+    },
     CliConfig,
 };
 
@@ -118,6 +135,12 @@ impl VaultCliHandler {
             VaultCommands::Config {
                 action: ConfigActions::SetAdmin { new_admin },
             } => self.set_config_admin(new_admin).await,
+            VaultCommands::Config {
+                action: ConfigActions::SetProgramFee { new_fee_bps },
+            } => self.set_program_fee(new_fee_bps).await,
+            VaultCommands::Config {
+                action: ConfigActions::SetProgramFeeWallet { new_fee_wallet },
+            } => self.set_program_fee_wallet(new_fee_wallet).await,
             VaultCommands::Vault {
                 action:
                     VaultActions::Initialize {
@@ -238,6 +261,172 @@ impl VaultCliHandler {
             VaultCommands::Vault {
                 action: VaultActions::SetCapacity { vault, amount },
             } => self.set_capacity(vault, amount).await,
+            VaultCommands::Vault {
+                action:
+                    VaultActions::SetFees {
+                        vault,
+                        deposit_fee_bps,
+                        withdrawal_fee_bps,
+                        reward_fee_bps,
+                    },
+            } => {
+                self.set_fees(vault, deposit_fee_bps, withdrawal_fee_bps, reward_fee_bps)
+                    .await
+            }
+            VaultCommands::Vault {
+                action: VaultActions::SetIsPaused { vault, is_paused },
+            } => self.set_is_paused(vault, is_paused).await,
+            VaultCommands::Vault {
+                action:
+                    VaultActions::SetSecondaryAdmin {
+                        vault,
+                        new_admin,
+                        set_delegation_admin,
+                        set_operator_admin,
+                        set_ncn_admin,
+                        set_slasher_admin,
+                        set_capacity_admin,
+                        set_fee_wallet,
+                        set_mint_burn_admin,
+                        set_delegate_asset_admin,
+                        set_fee_admin,
+                        set_metadata_admin,
+                    },
+            } => {
+                self.set_secondary_admin(
+                    vault,
+                    new_admin,
+                    set_delegation_admin,
+                    set_operator_admin,
+                    set_ncn_admin,
+                    set_slasher_admin,
+                    set_capacity_admin,
+                    set_fee_wallet,
+                    set_mint_burn_admin,
+                    set_delegate_asset_admin,
+                    set_fee_admin,
+                    set_metadata_admin,
+                )
+                .await
+            }
+            VaultCommands::Vault {
+                action:
+                    VaultActions::ChangeWithdrawalTicketOwner {
+                        vault,
+                        new_owner,
+                        base,
+                    },
+            } => {
+                self.change_withdrawal_ticket_owner(vault, new_owner, base)
+                    .await
+            }
+            VaultCommands::Vault {
+                action:
+                    VaultActions::DelegateTokenAccount {
+                        vault,
+                        delegate,
+                        token_mint,
+                        should_create_token_account,
+                    },
+            } => {
+                self.delegate_token_account(
+                    vault,
+                    delegate,
+                    token_mint,
+                    should_create_token_account,
+                )
+                .await
+            }
+            VaultCommands::Vault {
+                action:
+                    VaultActions::InitializeVaultNcnSlasherTicket {
+                        vault,
+                        ncn,
+                        slasher,
+                    },
+            } => {
+                self.initialize_vault_ncn_slasher_ticket(vault, ncn, slasher)
+                    .await
+            }
+            VaultCommands::Vault {
+                action:
+                    VaultActions::WarmupVaultNcnSlasherTicket {
+                        vault,
+                        ncn,
+                        slasher,
+                    },
+            } => {
+                self.warmup_vault_ncn_slasher_ticket(vault, ncn, slasher)
+                    .await
+            }
+            VaultCommands::Vault {
+                action:
+                    VaultActions::CooldownVaultNcnSlasherTicket {
+                        vault,
+                        ncn,
+                        slasher,
+                    },
+            } => {
+                self.cooldown_vault_ncn_slasher_ticket(vault, ncn, slasher)
+                    .await
+            }
+            VaultCommands::Vault {
+                action: VaultActions::UpdateVaultBalance { vault },
+            } => self.update_vault_balance(vault).await,
+            VaultCommands::Vault {
+                action: VaultActions::InitializeVaultWithMint,
+            } => self.initialize_vault_with_mint().await,
+            VaultCommands::Vault {
+                action: VaultActions::SetChildVault { vault, child_vault },
+            } => self.set_child_vault(vault, child_vault).await,
+            VaultCommands::Vault {
+                action:
+                    VaultActions::SetIsEmergencyExit {
+                        vault,
+                        is_emergency_exit,
+                    },
+            } => self.set_is_emergency_exit(vault, is_emergency_exit).await,
+            VaultCommands::Vault {
+                action:
+                    VaultActions::SetPausedCategories {
+                        vault,
+                        paused_categories,
+                    },
+            } => {
+                self.set_paused_categories(vault, paused_categories_mask(&paused_categories))
+                    .await
+            }
+            VaultCommands::Vault {
+                action:
+                    VaultActions::SetAutoClaimDelegate {
+                        vault,
+                        auto_claim_delegate,
+                        max_tip_bps,
+                        base,
+                    },
+            } => {
+                self.set_auto_claim_delegate(vault, auto_claim_delegate, max_tip_bps, base)
+                    .await
+            }
+            VaultCommands::Vault {
+                action:
+                    VaultActions::AutoClaimWithdrawalTicket {
+                        vault,
+                        base,
+                        tip_bps,
+                    },
+            } => {
+                self.auto_claim_withdrawal_ticket(vault, base, tip_bps)
+                    .await
+            }
+            VaultCommands::Vault {
+                action: VaultActions::CrankVaultUpdateStateTrackerMany { vault, operators },
+            } => {
+                self.crank_vault_update_state_tracker_many(vault, operators)
+                    .await
+            }
+            VaultCommands::Account { pubkey } => self.get_account(pubkey).await,
+            VaultCommands::MigrateAccount { pubkey } => self.migrate_account(pubkey).await,
         }
     }
 
@@ -246,36 +435,26 @@ impl VaultCliHandler {
         program_fee_bps: u16,
         program_fee_wallet: Pubkey,
     ) -> Result<()> {
-        let keypair = self
-            .cli_config
-            .keypair
-            .as_ref()
-            .ok_or_else(|| anyhow!("Keypair not provided"))?;
+        let signer = signer_pubkey(&self.cli_config)?;
         let rpc_client = self.get_rpc_client();
 
         let mut ix_builder = InitializeConfigBuilder::new();
         let config_address = Config::find_program_address(&self.vault_program_id).0;
         let ix_builder = ix_builder
             .config(config_address)
-            .admin(keypair.pubkey())
+            .admin(signer)
             .restaking_program(self.restaking_program_id)
             .program_fee_wallet(program_fee_wallet)
             .program_fee_bps(program_fee_bps);
 
-        let blockhash = rpc_client.get_latest_blockhash().await?;
-        let tx = Transaction::new_signed_with_payer(
-            &[ix_builder.instruction()],
-            Some(&keypair.pubkey()),
-            &[keypair],
-            blockhash,
-        );
         info!("Initializing vault config parameters: {:?}", ix_builder);
-        info!(
-            "Initializing vault config transaction: {:?}",
-            tx.get_signature()
-        );
-        rpc_client.send_and_confirm_transaction(&tx).await?;
-        info!("Transaction confirmed: {:?}", tx.get_signature());
+        process_transaction(
+            &self.cli_config,
+            &rpc_client,
+            &[ix_builder.instruction()],
+            &[],
+        )
+        .await?;
         info!("Vault config initialized at address: {}", config_address);
         Ok(())
     }
@@ -292,24 +471,20 @@ impl VaultCliHandler {
         vrt_mint_address_file_path: Option<PathBuf>,
     ) -> Result<()> {
         let token_mint = Pubkey::from_str(&token_mint)?;
-        let keypair = self
-            .cli_config
-            .keypair
-            .as_ref()
-            .ok_or_else(|| anyhow!("Keypair not provided"))?;
+        let signer = signer_pubkey(&self.cli_config)?;
         let rpc_client = self.get_rpc_client();
 
         let base = Keypair::new();
         let vault = Vault::find_program_address(&self.vault_program_id, &base.pubkey()).0;
 
-        let admin = keypair.pubkey();
+        let admin = signer;
 
         let vrt_mint = match vrt_mint_address_file_path {
             Some(file_path) => {
-                let keypair = read_keypair_file(file_path)
+                let vrt_mint = read_keypair_file(file_path)
                     .map_err(|e| anyhow!("Could not read VRT mint address file path: {e}"))?;
-                info!("Found VRT mint address: {}", keypair.pubkey());
-                keypair
+                info!("Found VRT mint address: {}", vrt_mint.pubkey());
+                vrt_mint
             }
             None => Keypair::new(),
         };
@@ -348,26 +523,18 @@ impl VaultCliHandler {
         let vault_st_token_account_ix =
             create_associated_token_account_idempotent(&admin, &vault, &token_mint, &spl_token::ID);
 
-        let blockhash = rpc_client.get_latest_blockhash().await?;
-        let tx = Transaction::new_signed_with_payer(
+        info!("Initializing vault");
+        process_transaction(
+            &self.cli_config,
+            &rpc_client,
             &[
                 admin_st_token_account_ix,
                 vault_st_token_account_ix,
                 ix_builder.instruction(),
             ],
-            Some(&keypair.pubkey()),
-            &[keypair, &base, &vrt_mint],
-            blockhash,
-        );
-        info!("Initializing vault transaction: {:?}", tx.get_signature());
-        let result = rpc_client.send_and_confirm_transaction(&tx).await;
-
-        if result.is_err() {
-            info!("Transaction failed: {:?}", result.err());
-            return Err(anyhow::anyhow!("Transaction failed"));
-        }
-
-        info!("Transaction confirmed: {:?}", tx.get_signature());
+            &[&base, &vrt_mint],
+        )
+        .await?;
         info!("\nCreated new vault");
         info!("Vault address: {}", vault);
         info!("Base address: {}", base.pubkey());
@@ -384,11 +551,7 @@ impl VaultCliHandler {
         symbol: String,
         uri: String,
     ) -> Result<()> {
-        let keypair = self
-            .cli_config
-            .keypair
-            .as_ref()
-            .ok_or_else(|| anyhow!("Keypair not provided"))?;
+        let signer = signer_pubkey(&self.cli_config)?;
         let vault_pubkey = Pubkey::from_str(&vault)?;
 
         let rpc_client = self.get_rpc_client();
@@ -407,32 +570,17 @@ impl VaultCliHandler {
 
         let ix = CreateTokenMetadataBuilder::new()
             .vault(vault_pubkey)
-            .admin(keypair.pubkey())
+            .admin(signer)
             .vrt_mint(vault.vrt_mint)
-            .payer(keypair.pubkey())
+            .payer(signer)
             .metadata(metadata)
             .name(name)
             .symbol(symbol)
             .uri(uri)
             .instruction();
 
-        let recent_blockhash = rpc_client.get_latest_blockhash().await?;
-        let tx = Transaction::new_signed_with_payer(
-            &[ix],
-            Some(&keypair.pubkey()),
-            &[keypair],
-            recent_blockhash,
-        );
-
-        info!(
-            "Creating token metadata transaction: {:?}",
-            tx.get_signature()
-        );
-        rpc_client
-            .send_and_confirm_transaction(&tx)
-            .await
-            .map_err(|e| anyhow!(e.to_string()))?;
-        info!("Transaction confirmed: {:?}", tx.get_signature());
+        info!("Creating token metadata");
+        process_transaction(&self.cli_config, &rpc_client, &[ix], &[]).await?;
 
         Ok(())
     }
@@ -444,11 +592,7 @@ impl VaultCliHandler {
         symbol: String,
         uri: String,
     ) -> Result<()> {
-        let keypair = self
-            .cli_config
-            .keypair
-            .as_ref()
-            .ok_or_else(|| anyhow!("Keypair not provided"))?;
+        let signer = signer_pubkey(&self.cli_config)?;
         let vault_pubkey = Pubkey::from_str(&vault)?;
 
         let rpc_client = self.get_rpc_client();
@@ -467,7 +611,7 @@ impl VaultCliHandler {
 
         let ix = UpdateTokenMetadataBuilder::new()
             .vault(vault_pubkey)
-            .admin(keypair.pubkey())
+            .admin(signer)
             .vrt_mint(vault.vrt_mint)
             .metadata(metadata)
             .name(name)
@@ -475,23 +619,8 @@ impl VaultCliHandler {
             .uri(uri)
             .instruction();
 
-        let recent_blockhash = rpc_client.get_latest_blockhash().await?;
-        let tx = Transaction::new_signed_with_payer(
-            &[ix],
-            Some(&keypair.pubkey()),
-            &[keypair],
-            recent_blockhash,
-        );
-
-        info!(
-            "Updating token metadata transaction: {:?}",
-            tx.get_signature()
-        );
-        rpc_client
-            .send_and_confirm_transaction(&tx)
-            .await
-            .map_err(|e| anyhow!(e.to_string()))?;
-        info!("Transaction confirmed: {:?}", tx.get_signature());
+        info!("Updating token metadata");
+        process_transaction(&self.cli_config, &rpc_client, &[ix], &[]).await?;
 
         Ok(())
     }
@@ -499,11 +628,7 @@ impl VaultCliHandler {
     // ---------- UPDATE ------------
 
     pub async fn initialize_vault_update_state_tracker(&self, vault: String) -> Result<()> {
-        let keypair = self
-            .cli_config
-            .keypair
-            .as_ref()
-            .ok_or_else(|| anyhow!("Keypair not provided"))?;
+        let signer = signer_pubkey(&self.cli_config)?;
         let rpc_client = self.get_rpc_client();
 
         let config = Config::find_program_address(&self.vault_program_id).0;
@@ -528,28 +653,17 @@ impl VaultCliHandler {
             .config(Config::find_program_address(&self.vault_program_id).0)
             .vault(vault)
             .vault_update_state_tracker(vault_update_state_tracker)
-            .payer(keypair.pubkey())
+            .payer(signer)
             .withdrawal_allocation_method(WithdrawalAllocationMethod::Greedy); // Only withdrawal allocation method supported for now
 
-        let blockhash = rpc_client.get_latest_blockhash().await?;
-        let tx = Transaction::new_signed_with_payer(
+        info!("Initializing vault update state tracker");
+        process_transaction(
+            &self.cli_config,
+            &rpc_client,
             &[ix_builder.instruction()],
-            Some(&keypair.pubkey()),
-            &[keypair],
-            blockhash,
-        );
-        info!(
-            "Initializing vault update state tracker transaction: {:?}",
-            tx.get_signature()
-        );
-
-        let result = rpc_client.send_and_confirm_transaction(&tx).await;
-
-        if result.is_err() {
-            return Err(anyhow::anyhow!("Transaction failed: {:?}", result.err()));
-        }
-
-        info!("Transaction confirmed: {:?}", tx.get_signature());
+            &[],
+        )
+        .await?;
 
         info!("\nCreated Update State Tracker");
         info!("Vault address: {}", vault);
@@ -569,11 +683,6 @@ impl VaultCliHandler {
     ) -> Result<()> {
         //TODO V2: Make it so the operator needed is automatically fetched from the vault
 
-        let keypair = self
-            .cli_config
-            .keypair
-            .as_ref()
-            .ok_or_else(|| anyhow!("Keypair not provided"))?;
         let rpc_client = self.get_rpc_client();
 
         let config = Config::find_program_address(&self.vault_program_id).0;
@@ -611,24 +720,73 @@ impl VaultCliHandler {
             .vault_operator_delegation(vault_operator_delegation)
             .vault_update_state_tracker(vault_update_state_tracker);
 
-        let blockhash = rpc_client.get_latest_blockhash().await?;
-        let tx = Transaction::new_signed_with_payer(
+        info!("Cranking vault update state tracker");
+        process_transaction(
+            &self.cli_config,
+            &rpc_client,
             &[ix_builder.instruction()],
-            Some(&keypair.pubkey()),
-            &[keypair],
-            blockhash,
-        );
-        info!(
-            "Cranking vault update state tracker: {:?}",
-            tx.get_signature()
-        );
-        let result = rpc_client.send_and_confirm_transaction(&tx).await;
+            &[],
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn crank_vault_update_state_tracker_many(
+        &self,
+        vault: String,
+        operators: Vec<String>,
+    ) -> Result<()> {
+        let rpc_client = self.get_rpc_client();
+
+        let config = Config::find_program_address(&self.vault_program_id).0;
+
+        let vault = Pubkey::from_str(&vault)?;
+
+        let ncn_epoch = {
+            let config_account_raw = rpc_client.get_account(&config).await?;
+            let config_account = Config::try_from_slice_unchecked(&config_account_raw.data)?;
+
+            let current_slot = rpc_client.get_slot().await?;
+            get_epoch(current_slot, config_account.epoch_length()).unwrap()
+        };
 
-        if result.is_err() {
-            return Err(anyhow::anyhow!("Transaction failed: {:?}", result.err()));
+        let vault_update_state_tracker = VaultUpdateStateTracker::find_program_address(
+            &self.vault_program_id,
+            &vault,
+            ncn_epoch,
+        )
+        .0;
+
+        let mut ix_builder = CrankVaultUpdateStateTrackerManyBuilder::new();
+        ix_builder
+            .config(config)
+            .vault(vault)
+            .vault_update_state_tracker(vault_update_state_tracker);
+        for operator in operators.iter() {
+            let operator = Pubkey::from_str(operator)?;
+            let vault_operator_delegation = VaultOperatorDelegation::find_program_address(
+                &self.vault_program_id,
+                &vault,
+                &operator,
+            )
+            .0;
+            ix_builder
+                .add_remaining_account(AccountMeta::new_readonly(operator, false))
+                .add_remaining_account(AccountMeta::new(vault_operator_delegation, false));
         }
 
-        info!("Transaction confirmed: {:?}", tx.get_signature());
+        info!(
+            "Cranking vault update state tracker for {} operators",
+            operators.len()
+        );
+        process_transaction(
+            &self.cli_config,
+            &rpc_client,
+            &[ix_builder.instruction()],
+            &[],
+        )
+        .await?;
 
         Ok(())
     }
@@ -638,11 +796,7 @@ impl VaultCliHandler {
         vault: String,
         ncn_epoch: Option<u64>,
     ) -> Result<()> {
-        let keypair = self
-            .cli_config
-            .keypair
-            .as_ref()
-            .ok_or_else(|| anyhow!("Keypair not provided"))?;
+        let signer = signer_pubkey(&self.cli_config)?;
         let rpc_client = self.get_rpc_client();
 
         let config = Config::find_program_address(&self.vault_program_id).0;
@@ -672,25 +826,16 @@ impl VaultCliHandler {
             .vault(vault)
             .vault_update_state_tracker(vault_update_state_tracker)
             .ncn_epoch(ncn_epoch)
-            .payer(keypair.pubkey());
+            .payer(signer);
 
-        let blockhash = rpc_client.get_latest_blockhash().await?;
-        let tx = Transaction::new_signed_with_payer(
+        info!("Closing vault update state tracker");
+        process_transaction(
+            &self.cli_config,
+            &rpc_client,
             &[ix_builder.instruction()],
-            Some(&keypair.pubkey()),
-            &[keypair],
-            blockhash,
-        );
-        info!(
-            "Closing vault update state tracker transaction: {:?}",
-            tx.get_signature()
-        );
-        let result = rpc_client.send_and_confirm_transaction(&tx).await;
-
-        if result.is_err() {
-            return Err(anyhow::anyhow!("Transaction failed: {:?}", result.err()));
-        }
-        info!("Transaction confirmed: {:?}", tx.get_signature());
+            &[],
+        )
+        .await?;
 
         info!("\nClose Update State Tracker");
         Ok(())
@@ -698,11 +843,7 @@ impl VaultCliHandler {
 
     // ---------- FUNCTIONS --------------
     pub async fn mint_vrt(&self, vault: String, amount_in: u64, min_amount_out: u64) -> Result<()> {
-        let keypair = self
-            .cli_config
-            .keypair
-            .as_ref()
-            .ok_or_else(|| anyhow!("Keypair not provided"))?;
+        let signer = signer_pubkey(&self.cli_config)?;
         let rpc_client = self.get_rpc_client();
 
         let vault = Pubkey::from_str(&vault)?;
@@ -710,7 +851,7 @@ impl VaultCliHandler {
         let vault_account_raw = rpc_client.get_account(&vault).await?;
         let vault_account = Vault::try_from_slice_unchecked(&vault_account_raw.data)?;
 
-        let depositor = keypair.pubkey();
+        let depositor = signer;
         let depositor_token_account =
             get_associated_token_address(&depositor, &vault_account.supported_mint);
         let depositor_vrt_token_account =
@@ -760,8 +901,10 @@ impl VaultCliHandler {
             .min_amount_out(min_amount_out)
             .vault(vault);
 
-        let blockhash = rpc_client.get_latest_blockhash().await?;
-        let tx = Transaction::new_signed_with_payer(
+        info!("Minting VRT");
+        process_transaction(
+            &self.cli_config,
+            &rpc_client,
             &[
                 depositor_ata_ix,
                 depositor_vrt_ata_ix,
@@ -769,18 +912,9 @@ impl VaultCliHandler {
                 vault_fee_ata_ix,
                 ix_builder.instruction(),
             ],
-            Some(&keypair.pubkey()),
-            &[keypair],
-            blockhash,
-        );
-        info!("Mint to transaction: {:?}", tx.get_signature());
-        let result = rpc_client.send_and_confirm_transaction(&tx).await;
-
-        if result.is_err() {
-            return Err(anyhow::anyhow!("Transaction failed: {:?}", result.err()));
-        }
-
-        info!("Transaction confirmed: {:?}", tx.get_signature());
+            &[],
+        )
+        .await?;
 
         info!("\nMinted VRT");
 
@@ -788,11 +922,7 @@ impl VaultCliHandler {
     }
 
     pub async fn initialize_vault_ncn_ticket(&self, vault: String, ncn: String) -> Result<()> {
-        let keypair = self
-            .cli_config
-            .keypair
-            .as_ref()
-            .ok_or_else(|| anyhow!("Keypair not provided"))?;
+        let signer = signer_pubkey(&self.cli_config)?;
         let rpc_client = self.get_rpc_client();
 
         let vault = Pubkey::from_str(&vault)?;
@@ -811,36 +941,23 @@ impl VaultCliHandler {
             .ncn(ncn)
             .vault_ncn_ticket(vault_ncn_ticket)
             .ncn_vault_ticket(ncn_vault_ticket)
-            .payer(keypair.pubkey())
-            .admin(keypair.pubkey());
-
-        let blockhash = rpc_client.get_latest_blockhash().await?;
-        let tx = Transaction::new_signed_with_payer(
-            &[ix_builder.instruction()],
-            Some(&keypair.pubkey()),
-            &[keypair],
-            blockhash,
-        );
+            .payer(signer)
+            .admin(signer);
 
         info!("Initialize Vault NCN Ticket");
-        let result = rpc_client.send_and_confirm_transaction(&tx).await;
-
-        if result.is_err() {
-            println!("Transaction failed: {:?}", result.err());
-            return Err(anyhow::anyhow!("Transaction failed"));
-        }
-
-        info!("Transaction confirmed: {:?}", result.unwrap());
+        process_transaction(
+            &self.cli_config,
+            &rpc_client,
+            &[ix_builder.instruction()],
+            &[],
+        )
+        .await?;
 
         Ok(())
     }
 
     pub async fn warmup_vault_ncn_ticket(&self, vault: String, ncn: String) -> Result<()> {
-        let keypair = self
-            .cli_config
-            .keypair
-            .as_ref()
-            .ok_or_else(|| anyhow!("Keypair not provided"))?;
+        let signer = signer_pubkey(&self.cli_config)?;
         let rpc_client = self.get_rpc_client();
 
         let vault = Pubkey::from_str(&vault)?;
@@ -855,29 +972,22 @@ impl VaultCliHandler {
             .vault(vault)
             .ncn(ncn)
             .vault_ncn_ticket(vault_ncn_ticket)
-            .admin(keypair.pubkey());
-
-        let blockhash = rpc_client.get_latest_blockhash().await?;
-        let tx = Transaction::new_signed_with_payer(
-            &[ix_builder.instruction()],
-            Some(&keypair.pubkey()),
-            &[keypair],
-            blockhash,
-        );
+            .admin(signer);
 
         info!("Warmup Vault NCN Ticket");
-        let result = rpc_client.send_and_confirm_transaction(&tx).await?;
-        info!("Transaction confirmed: {:?}", result);
+        process_transaction(
+            &self.cli_config,
+            &rpc_client,
+            &[ix_builder.instruction()],
+            &[],
+        )
+        .await?;
 
         Ok(())
     }
 
     pub async fn cooldown_vault_ncn_ticket(&self, vault: String, ncn: String) -> Result<()> {
-        let keypair = self
-            .cli_config
-            .keypair
-            .as_ref()
-            .ok_or_else(|| anyhow!("Keypair not provided"))?;
+        let signer = signer_pubkey(&self.cli_config)?;
         let rpc_client = self.get_rpc_client();
 
         let vault = Pubkey::from_str(&vault)?;
@@ -892,19 +1002,16 @@ impl VaultCliHandler {
             .vault(vault)
             .ncn(ncn)
             .vault_ncn_ticket(vault_ncn_ticket)
-            .admin(keypair.pubkey());
-
-        let blockhash = rpc_client.get_latest_blockhash().await?;
-        let tx = Transaction::new_signed_with_payer(
-            &[ix_builder.instruction()],
-            Some(&keypair.pubkey()),
-            &[keypair],
-            blockhash,
-        );
+            .admin(signer);
 
         info!("Cooldown Vault NCN Ticket");
-        let result = rpc_client.send_and_confirm_transaction(&tx).await?;
-        info!("Transaction confirmed: {:?}", result);
+        process_transaction(
+            &self.cli_config,
+            &rpc_client,
+            &[ix_builder.instruction()],
+            &[],
+        )
+        .await?;
 
         Ok(())
     }
@@ -914,11 +1021,7 @@ impl VaultCliHandler {
         vault: String,
         operator: String,
     ) -> Result<()> {
-        let keypair = self
-            .cli_config
-            .keypair
-            .as_ref()
-            .ok_or_else(|| anyhow!("Keypair not provided"))?;
+        let signer = signer_pubkey(&self.cli_config)?;
         let rpc_client = self.get_rpc_client();
 
         let vault = Pubkey::from_str(&vault)?;
@@ -945,27 +1048,17 @@ impl VaultCliHandler {
             .operator(operator)
             .operator_vault_ticket(operator_vault_ticket)
             .vault_operator_delegation(vault_operator_delegation)
-            .payer(keypair.pubkey())
-            .admin(keypair.pubkey());
+            .payer(signer)
+            .admin(signer);
 
-        let blockhash = rpc_client.get_latest_blockhash().await?;
-        let tx = Transaction::new_signed_with_payer(
+        info!("Initializing vault operator delegation");
+        process_transaction(
+            &self.cli_config,
+            &rpc_client,
             &[ix_builder.instruction()],
-            Some(&keypair.pubkey()),
-            &[keypair],
-            blockhash,
-        );
-        info!(
-            "Initializing vault operator delegation transaction: {:?}",
-            tx.get_signature()
-        );
-        let result = rpc_client.send_and_confirm_transaction(&tx).await;
-
-        if result.is_err() {
-            return Err(anyhow::anyhow!("Transaction failed: {:?}", result.err()));
-        }
-
-        info!("Transaction confirmed: {:?}", tx.get_signature());
+            &[],
+        )
+        .await?;
 
         Ok(())
     }
@@ -976,11 +1069,7 @@ impl VaultCliHandler {
         operator: String,
         amount: u64,
     ) -> Result<()> {
-        let keypair = self
-            .cli_config
-            .keypair
-            .as_ref()
-            .ok_or_else(|| anyhow!("Keypair not provided"))?;
+        let signer = signer_pubkey(&self.cli_config)?;
         let rpc_client = self.get_rpc_client();
 
         let vault = Pubkey::from_str(&vault)?;
@@ -999,40 +1088,82 @@ impl VaultCliHandler {
             .vault(vault)
             .operator(operator)
             .vault_operator_delegation(vault_operator_delegation)
-            .admin(keypair.pubkey())
+            .admin(signer)
             .amount(amount);
 
-        let blockhash = rpc_client.get_latest_blockhash().await?;
-        let tx = Transaction::new_signed_with_payer(
-            &[ix_builder.instruction()],
-            Some(&keypair.pubkey()),
-            &[keypair],
-            blockhash,
-        );
-        info!("Delegating to operator: {:?}", tx.get_signature());
-        let result = rpc_client.send_and_confirm_transaction(&tx).await;
-
-        if result.is_err() {
-            return Err(anyhow::anyhow!("Transaction failed: {:?}", result.err()));
+        let operator_account = rpc_client.get_account(&operator).await?;
+        let operator_account = Operator::try_from_slice_unchecked(&operator_account.data)?;
+        if operator_account.jailed_ncn_count() > 0 {
+            let remaining_accounts = self
+                .get_operator_jail_check_accounts(&rpc_client, &vault, &operator)
+                .await?;
+            ix_builder.add_remaining_accounts(&remaining_accounts);
         }
 
-        info!("Transaction confirmed: {:?}", tx.get_signature());
+        info!("Delegating to operator");
+        process_transaction(
+            &self.cli_config,
+            &rpc_client,
+            &[ix_builder.instruction()],
+            &[],
+        )
+        .await?;
         info!("Delegated {} tokens to {}", amount, operator);
 
         Ok(())
     }
 
+    /// Returns the (ncn, vault_ncn_ticket, ncn_operator_state) triples, ordered by ticket index,
+    /// that AddDelegation needs to check an operator that is jailed by at least one NCN.
+    async fn get_operator_jail_check_accounts(
+        &self,
+        rpc_client: &RpcClient,
+        vault: &Pubkey,
+        operator: &Pubkey,
+    ) -> Result<Vec<AccountMeta>> {
+        let mut config = self.get_rpc_program_accounts_config::<VaultNcnTicket>()?;
+        if let Some(filters) = config.filters.as_mut() {
+            filters.push(RpcFilterType::Memcmp(Memcmp::new(
+                8,
+                MemcmpEncodedBytes::Bytes(vault.to_bytes().to_vec()),
+            )));
+        }
+        let accounts = rpc_client
+            .get_program_accounts_with_config(&self.vault_program_id, config)
+            .await?;
+
+        let mut vault_ncn_tickets = accounts
+            .into_iter()
+            .map(|(pubkey, account)| {
+                let ticket = *VaultNcnTicket::try_from_slice_unchecked(&account.data)?;
+                Ok((pubkey, ticket))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        vault_ncn_tickets.sort_by_key(|(_, ticket)| ticket.index());
+
+        let mut remaining_accounts = Vec::with_capacity(vault_ncn_tickets.len() * 3);
+        for (vault_ncn_ticket, ticket) in vault_ncn_tickets {
+            let ncn_operator_state = NcnOperatorState::find_program_address(
+                &self.restaking_program_id,
+                &ticket.ncn,
+                operator,
+            )
+            .0;
+            remaining_accounts.push(AccountMeta::new_readonly(ticket.ncn, false));
+            remaining_accounts.push(AccountMeta::new_readonly(vault_ncn_ticket, false));
+            remaining_accounts.push(AccountMeta::new_readonly(ncn_operator_state, false));
+        }
+
+        Ok(remaining_accounts)
+    }
+
     pub async fn cooldown_operator_delegation(
         &self,
         vault: String,
         operator: String,
         amount: u64,
     ) -> Result<()> {
-        let keypair = self
-            .cli_config
-            .keypair
-            .as_ref()
-            .ok_or_else(|| anyhow!("Keypair not provided"))?;
+        let signer = signer_pubkey(&self.cli_config)?;
         let rpc_client = self.get_rpc_client();
 
         let vault = Pubkey::from_str(&vault)?;
@@ -1051,35 +1182,24 @@ impl VaultCliHandler {
             .vault(vault)
             .operator(operator)
             .vault_operator_delegation(vault_operator_delegation)
-            .admin(keypair.pubkey())
+            .admin(signer)
             .amount(amount);
 
-        let blockhash = rpc_client.get_latest_blockhash().await?;
-        let tx = Transaction::new_signed_with_payer(
+        info!("Cooling down delegation");
+        process_transaction(
+            &self.cli_config,
+            &rpc_client,
             &[ix_builder.instruction()],
-            Some(&keypair.pubkey()),
-            &[keypair],
-            blockhash,
-        );
-        info!("Cooling down delegation: {:?}", tx.get_signature());
-        let result = rpc_client.send_and_confirm_transaction(&tx).await;
-
-        if result.is_err() {
-            return Err(anyhow::anyhow!("Transaction failed: {:?}", result.err()));
-        }
-
-        info!("Transaction confirmed: {:?}", tx.get_signature());
+            &[],
+        )
+        .await?;
         info!("Cooldown {} tokens for {}", amount, operator);
 
         Ok(())
     }
 
     pub async fn enqueue_withdrawal(&self, vault: String, amount: u64) -> Result<()> {
-        let keypair = self
-            .cli_config
-            .keypair
-            .as_ref()
-            .ok_or_else(|| anyhow!("Keypair not provided"))?;
+        let signer = signer_pubkey(&self.cli_config)?;
         let rpc_client = self.get_rpc_client();
 
         let vault = Pubkey::from_str(&vault)?;
@@ -1089,7 +1209,7 @@ impl VaultCliHandler {
         let vault_staker_withdrawal_ticket = VaultStakerWithdrawalTicket::find_program_address(
             &self.vault_program_id,
             &vault,
-            &keypair.pubkey(),
+            &signer,
         )
         .0;
 
@@ -1097,10 +1217,10 @@ impl VaultCliHandler {
             get_associated_token_address(&vault_staker_withdrawal_ticket, &vault_account.vrt_mint);
 
         let staker_vrt_token_account =
-            get_associated_token_address(&keypair.pubkey(), &vault_account.vrt_mint);
+            get_associated_token_address(&signer, &vault_account.vrt_mint);
 
         let vault_staker_withdrawal_ticket_ata_ix = create_associated_token_account_idempotent(
-            &keypair.pubkey(),
+            &signer,
             &vault_staker_withdrawal_ticket,
             &vault_account.vrt_mint,
             &spl_token::ID,
@@ -1114,42 +1234,28 @@ impl VaultCliHandler {
             .vault_staker_withdrawal_ticket_token_account(
                 vault_staker_withdrawal_ticket_token_account,
             )
-            .staker(keypair.pubkey())
+            .staker(signer)
             .staker_vrt_token_account(staker_vrt_token_account)
-            .base(keypair.pubkey())
+            .base(signer)
             .amount(amount);
 
-        let blockhash = rpc_client.get_latest_blockhash().await?;
-        let tx = Transaction::new_signed_with_payer(
+        info!("Enqueuing withdrawal");
+        process_transaction(
+            &self.cli_config,
+            &rpc_client,
             &[
                 vault_staker_withdrawal_ticket_ata_ix,
                 ix_builder.instruction(),
             ],
-            Some(&keypair.pubkey()),
-            &[keypair],
-            blockhash,
-        );
-        info!(
-            "Initializing vault operator delegation transaction: {:?}",
-            tx.get_signature()
-        );
-        let result = rpc_client.send_and_confirm_transaction(&tx).await;
-
-        if result.is_err() {
-            return Err(anyhow::anyhow!("Transaction failed: {:?}", result.err()));
-        }
-
-        info!("Transaction confirmed: {:?}", tx.get_signature());
+            &[],
+        )
+        .await?;
 
         Ok(())
     }
 
     pub async fn burn_withdrawal_ticket(&self, vault: String) -> Result<()> {
-        let keypair = self
-            .cli_config
-            .keypair
-            .as_ref()
-            .ok_or_else(|| anyhow!("Keypair not provided"))?;
+        let signer = signer_pubkey(&self.cli_config)?;
         let rpc_client = self.get_rpc_client();
 
         let vault = Pubkey::from_str(&vault)?;
@@ -1159,11 +1265,11 @@ impl VaultCliHandler {
         let vault_staker_withdrawal_ticket = VaultStakerWithdrawalTicket::find_program_address(
             &self.vault_program_id,
             &vault,
-            &keypair.pubkey(),
+            &signer,
         )
         .0;
 
-        let staker = keypair.pubkey();
+        let staker = signer;
         let staker_token_account =
             get_associated_token_address(&staker, &vault_account.supported_mint);
 
@@ -1181,7 +1287,7 @@ impl VaultCliHandler {
         let config_account = Config::try_from_slice_unchecked(&config_account_raw.data)?;
 
         let program_fee_ata = create_associated_token_account_idempotent(
-            &keypair.pubkey(),
+            &signer,
             &config_account.program_fee_wallet,
             &vault_account.vrt_mint,
             &spl_token::ID,
@@ -1207,51 +1313,70 @@ impl VaultCliHandler {
             .vault_token_account(vault_token_account)
             .staker(staker);
 
-        let blockhash = rpc_client.get_latest_blockhash().await?;
-        let tx = Transaction::new_signed_with_payer(
+        info!("Burning withdrawal ticket");
+        process_transaction(
+            &self.cli_config,
+            &rpc_client,
             &[program_fee_ata, ix_builder.instruction()],
-            Some(&keypair.pubkey()),
-            &[keypair],
-            blockhash,
-        );
-        info!(
-            "Initializing vault operator delegation transaction: {:?}",
-            tx.get_signature()
-        );
-        let result = rpc_client.send_and_confirm_transaction(&tx).await;
-
-        if result.is_err() {
-            return Err(anyhow::anyhow!("Transaction failed: {:?}", result.err()));
-        }
-
-        info!("Transaction confirmed: {:?}", tx.get_signature());
+            &[],
+        )
+        .await?;
 
         Ok(())
     }
 
     // ------- GET ACCOUNTS --------------------
+    /// The current slot and vault epoch length, used to decode slot-dependent fields
+    async fn get_view_context(&self, rpc_client: &RpcClient) -> Result<ViewContext> {
+        let config_address = Config::find_program_address(&self.vault_program_id).0;
+        let account = rpc_client.get_account(&config_address).await?;
+        let config = Config::try_from_slice_unchecked(&account.data)?;
+        Ok(ViewContext {
+            slot: rpc_client.get_slot().await?,
+            epoch_length: config.epoch_length(),
+        })
+    }
+
+    async fn get_vault_with_decimals(
+        &self,
+        rpc_client: &RpcClient,
+        vault: &Pubkey,
+    ) -> Result<(Vault, VaultDecimals)> {
+        let account = rpc_client.get_account(vault).await?;
+        let vault = *Vault::try_from_slice_unchecked(&account.data)?;
+        let decimals = VaultDecimals::fetch(rpc_client, &vault).await?;
+        Ok((vault, decimals))
+    }
+
     pub async fn get_vault(&self, pubkey: String) -> Result<()> {
         let pubkey = Pubkey::from_str(&pubkey)?;
         let rpc_client = self.get_rpc_client();
-        let account = rpc_client.get_account(&pubkey).await?;
-        let vault = Vault::try_from_slice_unchecked(&account.data)?;
-        info!("vault at address {}: {:?}", pubkey, vault);
-        Ok(())
+        let context = self.get_view_context(&rpc_client).await?;
+        let (vault, decimals) = self.get_vault_with_decimals(&rpc_client, &pubkey).await?;
+        let view = VaultView::new(&self.vault_program_id, &pubkey, &vault, decimals, &context)?;
+        output::print(self.cli_config.output, &view)
     }
 
     pub async fn list_vaults(&self) -> Result<()> {
         let rpc_client = self.get_rpc_client();
+        let context = self.get_view_context(&rpc_client).await?;
         let config = self.get_rpc_program_accounts_config::<Vault>()?;
         let accounts = rpc_client
             .get_program_accounts_with_config(&self.vault_program_id, config)
-            .await
-            .unwrap();
-        log::info!("{:?}", accounts);
+            .await?;
+        let mut views = Vec::with_capacity(accounts.len());
         for (vault_pubkey, vault) in accounts {
             let vault = Vault::try_from_slice_unchecked(&vault.data)?;
-            info!("vault at address {}: {:?}", vault_pubkey, vault);
+            let decimals = VaultDecimals::fetch(&rpc_client, vault).await?;
+            views.push(VaultView::new(
+                &self.vault_program_id,
+                &vault_pubkey,
+                vault,
+                decimals,
+                &context,
+            )?);
         }
-        Ok(())
+        output::print(self.cli_config.output, &views)
     }
 
     async fn get_config(&self) -> Result<()> {
@@ -1265,8 +1390,12 @@ impl VaultCliHandler {
 
         let account = rpc_client.get_account(&config_address).await?;
         let config = Config::try_from_slice_unchecked(&account.data)?;
-        info!("Vault config at address {} : {:?}", config_address, config);
-        Ok(())
+        let context = ViewContext {
+            slot: rpc_client.get_slot().await?,
+            epoch_length: config.epoch_length(),
+        };
+        let view = ConfigView::new(&config_address, config, &context)?;
+        output::print(self.cli_config.output, &view)
     }
 
     pub async fn get_vault_update_state_tracker(
@@ -1284,8 +1413,10 @@ impl VaultCliHandler {
         .0;
         let account = rpc_client.get_account(&vault_update_state_tracker).await?;
         let state_tracker = VaultUpdateStateTracker::try_from_slice_unchecked(&account.data)?;
-        info!("{:?}", state_tracker);
-        Ok(())
+        let (_, decimals) = self.get_vault_with_decimals(&rpc_client, &vault).await?;
+        let view =
+            VaultUpdateStateTrackerView::new(&vault_update_state_tracker, state_tracker, decimals)?;
+        output::print(self.cli_config.output, &view)
     }
 
     pub async fn get_vault_operator_delegation(
@@ -1304,8 +1435,10 @@ impl VaultCliHandler {
         .0;
         let account = rpc_client.get_account(&vault_operator_delegation).await?;
         let delegation = VaultOperatorDelegation::try_from_slice_unchecked(&account.data)?;
-        info!("{:?}", delegation);
-        Ok(())
+        let (_, decimals) = self.get_vault_with_decimals(&rpc_client, &vault).await?;
+        let view =
+            VaultOperatorDelegationView::new(&vault_operator_delegation, delegation, decimals)?;
+        output::print(self.cli_config.output, &view)
     }
 
     pub async fn get_withdrawal_ticket(&self, vault: String, staker: Option<String>) -> Result<()> {
@@ -1314,12 +1447,7 @@ impl VaultCliHandler {
         let staker = if let Some(staker) = staker {
             Pubkey::from_str(&staker)?
         } else {
-            let keypair = self
-                .cli_config
-                .keypair
-                .as_ref()
-                .ok_or_else(|| anyhow!("Keypair not provided"))?;
-            keypair.pubkey()
+            signer_pubkey(&self.cli_config)?
         };
         let vault_staker_withdrawal_ticket = VaultStakerWithdrawalTicket::find_program_address(
             &self.vault_program_id,
@@ -1331,78 +1459,1017 @@ impl VaultCliHandler {
             .get_account(&vault_staker_withdrawal_ticket)
             .await?;
         let ticket = VaultStakerWithdrawalTicket::try_from_slice_unchecked(&account.data)?;
-        info!("{:?}", ticket);
-
-        Ok(())
+        let context = self.get_view_context(&rpc_client).await?;
+        let (vault, decimals) = self.get_vault_with_decimals(&rpc_client, &vault).await?;
+        let view = VaultStakerWithdrawalTicketView::new(
+            &vault_staker_withdrawal_ticket,
+            ticket,
+            &vault,
+            decimals,
+            &context,
+        )?;
+        output::print(self.cli_config.output, &view)
     }
 
-    pub async fn set_capacity(&self, vault: String, amount: u64) -> Result<()> {
-        let keypair = self
-            .cli_config
-            .keypair
-            .as_ref()
-            .ok_or_else(|| anyhow!("Keypair not provided"))?;
-        let vault_pubkey = Pubkey::from_str(&vault)?;
+    /// Decodes any vault program account by its discriminator
+    pub async fn get_account(&self, pubkey: String) -> Result<()> {
+        let pubkey = Pubkey::from_str(&pubkey)?;
+        let rpc_client = self.get_rpc_client();
+        let account = rpc_client.get_account(&pubkey).await?;
+        if account.owner != self.vault_program_id {
+            return Err(anyhow!(
+                "Account {} is owned by {}, not the vault program",
+                pubkey,
+                account.owner
+            ));
+        }
+        let context = self.get_view_context(&rpc_client).await?;
+        let format = self.cli_config.output;
+
+        match account.data.first().copied() {
+            Some(Config::DISCRIMINATOR) => {
+                let config = Config::try_from_slice_unchecked(&account.data)?;
+                output::print(format, &ConfigView::new(&pubkey, config, &context)?)
+            }
+            Some(Vault::DISCRIMINATOR) => {
+                let (vault, decimals) = self.get_vault_with_decimals(&rpc_client, &pubkey).await?;
+                let view =
+                    VaultView::new(&self.vault_program_id, &pubkey, &vault, decimals, &context)?;
+                output::print(format, &view)
+            }
+            Some(VaultNcnTicket::DISCRIMINATOR) => {
+                let ticket = VaultNcnTicket::try_from_slice_unchecked(&account.data)?;
+                output::print(format, &VaultNcnTicketView::new(&pubkey, ticket, &context)?)
+            }
+            Some(VaultOperatorDelegation::DISCRIMINATOR) => {
+                let delegation = VaultOperatorDelegation::try_from_slice_unchecked(&account.data)?;
+                let (_, decimals) = self
+                    .get_vault_with_decimals(&rpc_client, &delegation.vault)
+                    .await?;
+                let view = VaultOperatorDelegationView::new(&pubkey, delegation, decimals)?;
+                output::print(format, &view)
+            }
+            Some(VaultNcnSlasherTicket::DISCRIMINATOR) => {
+                let ticket = VaultNcnSlasherTicket::try_from_slice_unchecked(&account.data)?;
+                let (_, decimals) = self
+                    .get_vault_with_decimals(&rpc_client, &ticket.vault)
+                    .await?;
+                let view = VaultNcnSlasherTicketView::new(&pubkey, ticket, decimals, &context)?;
+                output::print(format, &view)
+            }
+            Some(VaultNcnSlasherOperatorTicket::DISCRIMINATOR) => {
+                let ticket =
+                    VaultNcnSlasherOperatorTicket::try_from_slice_unchecked(&account.data)?;
+                let (_, decimals) = self
+                    .get_vault_with_decimals(&rpc_client, &ticket.vault)
+                    .await?;
+                let view = VaultNcnSlasherOperatorTicketView::new(&pubkey, ticket, decimals);
+                output::print(format, &view)
+            }
+            Some(VaultStakerWithdrawalTicket::DISCRIMINATOR) => {
+                let ticket = VaultStakerWithdrawalTicket::try_from_slice_unchecked(&account.data)?;
+                let (vault, decimals) = self
+                    .get_vault_with_decimals(&rpc_client, &ticket.vault)
+                    .await?;
+                let view = VaultStakerWithdrawalTicketView::new(
+                    &pubkey, ticket, &vault, decimals, &context,
+                )?;
+                output::print(format, &view)
+            }
+            Some(VaultUpdateStateTracker::DISCRIMINATOR) => {
+                let tracker = VaultUpdateStateTracker::try_from_slice_unchecked(&account.data)?;
+                let (_, decimals) = self
+                    .get_vault_with_decimals(&rpc_client, &tracker.vault)
+                    .await?;
+                let view = VaultUpdateStateTrackerView::new(&pubkey, tracker, decimals)?;
+                output::print(format, &view)
+            }
+            discriminator => Err(anyhow!(
+                "Account {} has an unknown discriminator {:?}",
+                pubkey,
+                discriminator
+            )),
+        }
+    }
+
+    pub async fn set_capacity(&self, vault: String, amount: u64) -> Result<()> {
+        let signer = signer_pubkey(&self.cli_config)?;
+        let vault_pubkey = Pubkey::from_str(&vault)?;
         let rpc_client = self.get_rpc_client();
 
         let mut builder = SetDepositCapacityBuilder::new();
         builder
             .config(Config::find_program_address(&self.vault_program_id).0)
             .vault(vault_pubkey)
-            .admin(keypair.pubkey())
+            .admin(signer)
             .amount(amount);
 
-        let recent_blockhash = rpc_client.get_latest_blockhash().await?;
-        let tx = Transaction::new_signed_with_payer(
-            &[builder.instruction()],
-            Some(&keypair.pubkey()),
-            &[keypair],
-            recent_blockhash,
-        );
-
         info!("Vault capacity instruction: {:?}", builder);
-        info!(
-            "Vault capacity transaction signature: {:?}",
-            tx.get_signature()
-        );
-        rpc_client
-            .send_and_confirm_transaction(&tx)
-            .await
-            .map_err(|e| anyhow!(e.to_string()))?;
-        info!("Transaction confirmed: {:?}", tx.get_signature());
+        process_transaction(&self.cli_config, &rpc_client, &[builder.instruction()], &[]).await?;
 
         Ok(())
     }
 
     async fn set_config_admin(&self, new_admin: Pubkey) -> Result<()> {
-        let keypair = self
-            .cli_config
-            .keypair
-            .as_ref()
-            .ok_or_else(|| anyhow!("Keypair not provided"))?;
+        let signer = signer_pubkey(&self.cli_config)?;
         let rpc_client = self.get_rpc_client();
 
         let config_address = Config::find_program_address(&self.vault_program_id).0;
         let mut ix_builder = SetConfigAdminBuilder::new();
         ix_builder
             .config(config_address)
-            .old_admin(keypair.pubkey())
+            .old_admin(signer)
             .new_admin(new_admin);
 
-        let blockhash = rpc_client.get_latest_blockhash().await?;
-        let tx = Transaction::new_signed_with_payer(
+        info!("Setting vault config admin parameters: {:?}", ix_builder);
+        process_transaction(
+            &self.cli_config,
+            &rpc_client,
+            &[ix_builder.instruction()],
+            &[],
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn set_program_fee(&self, new_fee_bps: u16) -> Result<()> {
+        let signer = signer_pubkey(&self.cli_config)?;
+        let rpc_client = self.get_rpc_client();
+
+        let config_address = Config::find_program_address(&self.vault_program_id).0;
+        let config_account_raw = rpc_client.get_account(&config_address).await?;
+        let mut config_account = *Config::try_from_slice_unchecked(&config_account_raw.data)?;
+        if config_account.admin.ne(&signer) {
+            return Err(anyhow!("{} is not the vault config admin", signer));
+        }
+        config_account.set_program_fee_bps(new_fee_bps)?;
+
+        let mut ix_builder = SetProgramFeeBuilder::new();
+        ix_builder
+            .config(config_address)
+            .admin(signer)
+            .new_fee_bps(new_fee_bps);
+
+        info!("Setting program fee to {} bps", new_fee_bps);
+        process_transaction(
+            &self.cli_config,
+            &rpc_client,
             &[ix_builder.instruction()],
-            Some(&keypair.pubkey()),
-            &[keypair],
-            blockhash,
+            &[],
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn set_program_fee_wallet(&self, new_fee_wallet: Pubkey) -> Result<()> {
+        let signer = signer_pubkey(&self.cli_config)?;
+        let rpc_client = self.get_rpc_client();
+
+        let config_address = Config::find_program_address(&self.vault_program_id).0;
+        let config_account_raw = rpc_client.get_account(&config_address).await?;
+        let config_account = Config::try_from_slice_unchecked(&config_account_raw.data)?;
+        if config_account.fee_admin.ne(&signer) {
+            return Err(anyhow!("{} is not the vault config fee admin", signer));
+        }
+
+        let mut ix_builder = SetProgramFeeWalletBuilder::new();
+        ix_builder
+            .config(config_address)
+            .program_fee_admin(signer)
+            .new_fee_wallet(new_fee_wallet);
+
+        info!("Setting program fee wallet to {}", new_fee_wallet);
+        process_transaction(
+            &self.cli_config,
+            &rpc_client,
+            &[ix_builder.instruction()],
+            &[],
+        )
+        .await?;
+        Ok(())
+    }
+
+    pub async fn set_fees(
+        &self,
+        vault: String,
+        deposit_fee_bps: Option<u16>,
+        withdrawal_fee_bps: Option<u16>,
+        reward_fee_bps: Option<u16>,
+    ) -> Result<()> {
+        let signer = signer_pubkey(&self.cli_config)?;
+        let rpc_client = self.get_rpc_client();
+
+        if deposit_fee_bps.is_none() && withdrawal_fee_bps.is_none() && reward_fee_bps.is_none() {
+            return Err(anyhow!("No fees provided for update"));
+        }
+
+        let config = Config::find_program_address(&self.vault_program_id).0;
+        let config_account_raw = rpc_client.get_account(&config).await?;
+        let config_account = Config::try_from_slice_unchecked(&config_account_raw.data)?;
+
+        let vault = Pubkey::from_str(&vault)?;
+        let vault_account_raw = rpc_client.get_account(&vault).await?;
+        let mut vault_account = *Vault::try_from_slice_unchecked(&vault_account_raw.data)?;
+
+        // Run the program's checks against a copy of the vault so bad fees fail before sending
+        let slot = rpc_client.get_slot().await?;
+        vault_account.check_fee_admin(&signer)?;
+        vault_account.check_can_modify_fees(slot, config_account.epoch_length())?;
+        vault_account.check_is_category_paused(PauseCategory::FeeChanges)?;
+        if let Some(deposit_fee_bps) = deposit_fee_bps {
+            vault_account.set_deposit_fee_bps(
+                deposit_fee_bps,
+                config_account.deposit_withdrawal_fee_cap_bps(),
+                config_account.fee_bump_bps(),
+                config_account.fee_rate_of_change_bps(),
+            )?;
+        }
+        if let Some(withdrawal_fee_bps) = withdrawal_fee_bps {
+            vault_account.set_next_withdrawal_fee_bps(
+                withdrawal_fee_bps,
+                config_account.deposit_withdrawal_fee_cap_bps(),
+                config_account.fee_bump_bps(),
+                config_account.fee_rate_of_change_bps(),
+            )?;
+        }
+        if let Some(reward_fee_bps) = reward_fee_bps {
+            vault_account.set_reward_fee_bps(reward_fee_bps)?;
+        }
+
+        let mut ix_builder = SetFeesBuilder::new();
+        ix_builder.config(config).vault(vault).admin(signer);
+        if let Some(deposit_fee_bps) = deposit_fee_bps {
+            ix_builder.deposit_fee_bps(deposit_fee_bps);
+        }
+        if let Some(withdrawal_fee_bps) = withdrawal_fee_bps {
+            ix_builder.withdrawal_fee_bps(withdrawal_fee_bps);
+        }
+        if let Some(reward_fee_bps) = reward_fee_bps {
+            ix_builder.reward_fee_bps(reward_fee_bps);
+        }
+
+        info!("Setting vault fees");
+        process_transaction(
+            &self.cli_config,
+            &rpc_client,
+            &[ix_builder.instruction()],
+            &[],
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn set_is_paused(&self, vault: String, is_paused: bool) -> Result<()> {
+        let signer = signer_pubkey(&self.cli_config)?;
+        let rpc_client = self.get_rpc_client();
+
+        let vault = Pubkey::from_str(&vault)?;
+        let vault_account_raw = rpc_client.get_account(&vault).await?;
+        let vault_account = Vault::try_from_slice_unchecked(&vault_account_raw.data)?;
+        vault_account.check_admin(&signer)?;
+
+        let mut ix_builder = SetIsPausedBuilder::new();
+        ix_builder
+            .config(Config::find_program_address(&self.vault_program_id).0)
+            .vault(vault)
+            .admin(signer)
+            .is_paused(is_paused);
+
+        info!("Setting vault {} is_paused to {}", vault, is_paused);
+        process_transaction(
+            &self.cli_config,
+            &rpc_client,
+            &[ix_builder.instruction()],
+            &[],
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub async fn set_secondary_admin(
+        &self,
+        vault: String,
+        new_admin: String,
+        set_delegation_admin: bool,
+        set_operator_admin: bool,
+        set_ncn_admin: bool,
+        set_slasher_admin: bool,
+        set_capacity_admin: bool,
+        set_fee_wallet: bool,
+        set_mint_burn_admin: bool,
+        set_delegate_asset_admin: bool,
+        set_fee_admin: bool,
+        set_metadata_admin: bool,
+    ) -> Result<()> {
+        let signer = signer_pubkey(&self.cli_config)?;
+        let rpc_client = self.get_rpc_client();
+
+        let vault = Pubkey::from_str(&vault)?;
+        let new_admin = Pubkey::from_str(&new_admin)?;
+
+        let vault_account_raw = rpc_client.get_account(&vault).await?;
+        let vault_account = Vault::try_from_slice_unchecked(&vault_account_raw.data)?;
+        vault_account.check_admin(&signer)?;
+
+        let mut roles: Vec<VaultAdminRole> = vec![];
+        if set_delegation_admin {
+            roles.push(VaultAdminRole::DelegationAdmin);
+        }
+        if set_operator_admin {
+            roles.push(VaultAdminRole::OperatorAdmin);
+        }
+        if set_ncn_admin {
+            roles.push(VaultAdminRole::NcnAdmin);
+        }
+        if set_slasher_admin {
+            roles.push(VaultAdminRole::SlasherAdmin);
+        }
+        if set_capacity_admin {
+            roles.push(VaultAdminRole::CapacityAdmin);
+        }
+        if set_fee_wallet {
+            roles.push(VaultAdminRole::FeeWallet);
+        }
+        if set_mint_burn_admin {
+            roles.push(VaultAdminRole::MintBurnAdmin);
+        }
+        if set_delegate_asset_admin {
+            roles.push(VaultAdminRole::DelegateAssetAdmin);
+        }
+        if set_fee_admin {
+            roles.push(VaultAdminRole::FeeAdmin);
+        }
+        if set_metadata_admin {
+            roles.push(VaultAdminRole::MetadataAdmin);
+        }
+
+        for role in roles.iter() {
+            let mut ix_builder = SetSecondaryAdminBuilder::new();
+            ix_builder
+                .config(Config::find_program_address(&self.vault_program_id).0)
+                .vault(vault)
+                .admin(signer)
+                .new_admin(new_admin)
+                .vault_admin_role(*role);
+
+            info!("Setting {:?} to {} for Vault {}", role, new_admin, vault);
+            process_transaction(
+                &self.cli_config,
+                &rpc_client,
+                &[ix_builder.instruction()],
+                &[],
+            )
+            .await?;
+        }
+
+        Ok(())
+    }
+
+    pub async fn change_withdrawal_ticket_owner(
+        &self,
+        vault: String,
+        new_owner: String,
+        base: Option<String>,
+    ) -> Result<()> {
+        let signer = signer_pubkey(&self.cli_config)?;
+        let rpc_client = self.get_rpc_client();
+
+        let vault = Pubkey::from_str(&vault)?;
+        let new_owner = Pubkey::from_str(&new_owner)?;
+        let base = match base {
+            Some(base) => Pubkey::from_str(&base)?,
+            None => signer,
+        };
+
+        let vault_staker_withdrawal_ticket = VaultStakerWithdrawalTicket::find_program_address(
+            &self.vault_program_id,
+            &vault,
+            &base,
+        )
+        .0;
+
+        let vault_account_raw = rpc_client.get_account(&vault).await?;
+        let vault_account = Vault::try_from_slice_unchecked(&vault_account_raw.data)?;
+        vault_account.check_is_paused()?;
+        vault_account.check_is_category_paused(PauseCategory::Withdrawals)?;
+
+        let ticket_account_raw = rpc_client
+            .get_account(&vault_staker_withdrawal_ticket)
+            .await?;
+        let ticket_account =
+            VaultStakerWithdrawalTicket::try_from_slice_unchecked(&ticket_account_raw.data)?;
+        ticket_account.check_staker(&signer)?;
+
+        let mut ix_builder = ChangeWithdrawalTicketOwnerBuilder::new();
+        ix_builder
+            .config(Config::find_program_address(&self.vault_program_id).0)
+            .vault(vault)
+            .vault_staker_withdrawal_ticket(vault_staker_withdrawal_ticket)
+            .old_owner(signer)
+            .new_owner(new_owner);
+
+        info!(
+            "Changing withdrawal ticket {} owner to {}",
+            vault_staker_withdrawal_ticket, new_owner
         );
-        info!("Setting vault config admin parameters: {:?}", ix_builder);
+        process_transaction(
+            &self.cli_config,
+            &rpc_client,
+            &[ix_builder.instruction()],
+            &[],
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn delegate_token_account(
+        &self,
+        vault: String,
+        delegate: String,
+        token_mint: String,
+        should_create_token_account: bool,
+    ) -> Result<()> {
+        let signer = signer_pubkey(&self.cli_config)?;
+        let rpc_client = self.get_rpc_client();
+
+        let vault = Pubkey::from_str(&vault)?;
+        let delegate = Pubkey::from_str(&delegate)?;
+        let token_mint = Pubkey::from_str(&token_mint)?;
+
+        let vault_account_raw = rpc_client.get_account(&vault).await?;
+        let vault_account = Vault::try_from_slice_unchecked(&vault_account_raw.data)?;
+        vault_account.check_delegate_asset_admin(&signer)?;
+        if vault_account.supported_mint.eq(&token_mint) {
+            return Err(anyhow!(
+                "Cannot delegate away the supported mint for a vault"
+            ));
+        }
+
+        let token_account = get_associated_token_address(&vault, &token_mint);
+
+        let mut ixs = vec![];
+
+        if should_create_token_account {
+            ixs.push(create_associated_token_account_idempotent(
+                &signer,
+                &vault,
+                &token_mint,
+                &spl_token::id(),
+            ));
+        }
+
+        let mut ix_builder = DelegateTokenAccountBuilder::new();
+        ix_builder
+            .config(Config::find_program_address(&self.vault_program_id).0)
+            .vault(vault)
+            .delegate_asset_admin(signer)
+            .token_mint(token_mint)
+            .token_account(token_account)
+            .delegate(delegate);
+
+        ixs.push(ix_builder.instruction());
+
+        info!("Setting delegate for mint: {} to {}", token_mint, delegate);
+        process_transaction(&self.cli_config, &rpc_client, &ixs, &[]).await?;
+
+        Ok(())
+    }
+
+    pub async fn initialize_vault_ncn_slasher_ticket(
+        &self,
+        vault: String,
+        ncn: String,
+        slasher: String,
+    ) -> Result<()> {
+        let signer = signer_pubkey(&self.cli_config)?;
+        let rpc_client = self.get_rpc_client();
+
+        let vault = Pubkey::from_str(&vault)?;
+        let ncn = Pubkey::from_str(&ncn)?;
+        let slasher = Pubkey::from_str(&slasher)?;
+
+        let vault_account_raw = rpc_client.get_account(&vault).await?;
+        let vault_account = Vault::try_from_slice_unchecked(&vault_account_raw.data)?;
+        vault_account.check_slasher_admin(&signer)?;
+        vault_account.check_is_paused()?;
+        vault_account.check_is_category_paused(PauseCategory::TicketChanges)?;
+
+        let ncn_vault_slasher_ticket = NcnVaultSlasherTicket::find_program_address(
+            &self.restaking_program_id,
+            &ncn,
+            &vault,
+            &slasher,
+        )
+        .0;
+        let vault_ncn_slasher_ticket = VaultNcnSlasherTicket::find_program_address(
+            &self.vault_program_id,
+            &vault,
+            &ncn,
+            &slasher,
+        )
+        .0;
+
+        let mut ix_builder = InitializeVaultNcnSlasherTicketBuilder::new();
+        ix_builder
+            .config(Config::find_program_address(&self.vault_program_id).0)
+            .vault(vault)
+            .ncn(ncn)
+            .slasher(slasher)
+            .ncn_slasher_ticket(ncn_vault_slasher_ticket)
+            .vault_slasher_ticket(vault_ncn_slasher_ticket)
+            .admin(signer)
+            .payer(signer);
+
+        info!("Initializing vault NCN slasher ticket");
+        process_transaction(
+            &self.cli_config,
+            &rpc_client,
+            &[ix_builder.instruction()],
+            &[],
+        )
+        .await?;
+        info!("Vault NCN slasher ticket: {}", vault_ncn_slasher_ticket);
+
+        Ok(())
+    }
+
+    pub async fn warmup_vault_ncn_slasher_ticket(
+        &self,
+        vault: String,
+        ncn: String,
+        slasher: String,
+    ) -> Result<()> {
+        let signer = signer_pubkey(&self.cli_config)?;
+        let rpc_client = self.get_rpc_client();
+
+        let vault = Pubkey::from_str(&vault)?;
+        let ncn = Pubkey::from_str(&ncn)?;
+        let slasher = Pubkey::from_str(&slasher)?;
+
+        let vault_account_raw = rpc_client.get_account(&vault).await?;
+        let vault_account = Vault::try_from_slice_unchecked(&vault_account_raw.data)?;
+        vault_account.check_slasher_admin(&signer)?;
+        vault_account.check_is_paused()?;
+        vault_account.check_is_category_paused(PauseCategory::TicketChanges)?;
+
+        let vault_ncn_slasher_ticket = VaultNcnSlasherTicket::find_program_address(
+            &self.vault_program_id,
+            &vault,
+            &ncn,
+            &slasher,
+        )
+        .0;
+
+        let mut ix_builder = WarmupVaultNcnSlasherTicketBuilder::new();
+        ix_builder
+            .config(Config::find_program_address(&self.vault_program_id).0)
+            .vault(vault)
+            .ncn(ncn)
+            .slasher(slasher)
+            .vault_slasher_ticket(vault_ncn_slasher_ticket)
+            .admin(signer);
+
+        info!("Warming up vault NCN slasher ticket");
+        process_transaction(
+            &self.cli_config,
+            &rpc_client,
+            &[ix_builder.instruction()],
+            &[],
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn cooldown_vault_ncn_slasher_ticket(
+        &self,
+        vault: String,
+        ncn: String,
+        slasher: String,
+    ) -> Result<()> {
+        let signer = signer_pubkey(&self.cli_config)?;
+        let rpc_client = self.get_rpc_client();
+
+        let vault = Pubkey::from_str(&vault)?;
+        let ncn = Pubkey::from_str(&ncn)?;
+        let slasher = Pubkey::from_str(&slasher)?;
+
+        let vault_account_raw = rpc_client.get_account(&vault).await?;
+        let vault_account = Vault::try_from_slice_unchecked(&vault_account_raw.data)?;
+        vault_account.check_slasher_admin(&signer)?;
+        vault_account.check_is_paused()?;
+        vault_account.check_is_category_paused(PauseCategory::TicketChanges)?;
+
+        let vault_ncn_slasher_ticket = VaultNcnSlasherTicket::find_program_address(
+            &self.vault_program_id,
+            &vault,
+            &ncn,
+            &slasher,
+        )
+        .0;
+
+        let mut ix_builder = CooldownVaultNcnSlasherTicketBuilder::new();
+        ix_builder
+            .config(Config::find_program_address(&self.vault_program_id).0)
+            .vault(vault)
+            .ncn(ncn)
+            .slasher(slasher)
+            .vault_ncn_slasher_ticket(vault_ncn_slasher_ticket)
+            .admin(signer);
+
+        info!("Cooling down vault NCN slasher ticket");
+        process_transaction(
+            &self.cli_config,
+            &rpc_client,
+            &[ix_builder.instruction()],
+            &[],
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn update_vault_balance(&self, vault: String) -> Result<()> {
+        let signer = signer_pubkey(&self.cli_config)?;
+        let rpc_client = self.get_rpc_client();
+
+        let config = Config::find_program_address(&self.vault_program_id).0;
+        let config_account_raw = rpc_client.get_account(&config).await?;
+        let config_account = Config::try_from_slice_unchecked(&config_account_raw.data)?;
+
+        let vault = Pubkey::from_str(&vault)?;
+        let vault_account_raw = rpc_client.get_account(&vault).await?;
+        let vault_account = Vault::try_from_slice_unchecked(&vault_account_raw.data)?;
+
+        let slot = rpc_client.get_slot().await?;
+        vault_account.check_update_state_ok(slot, config_account.epoch_length())?;
+        vault_account.check_is_paused()?;
+
+        let vault_token_account =
+            get_associated_token_address(&vault, &vault_account.supported_mint);
+        let vault_fee_token_account =
+            get_associated_token_address(&vault_account.fee_wallet, &vault_account.vrt_mint);
+
+        let vault_fee_ata_ix = create_associated_token_account_idempotent(
+            &signer,
+            &vault_account.fee_wallet,
+            &vault_account.vrt_mint,
+            &spl_token::ID,
+        );
+
+        let mut ix_builder = UpdateVaultBalanceBuilder::new();
+        ix_builder
+            .config(config)
+            .vault(vault)
+            .vault_token_account(vault_token_account)
+            .vrt_mint(vault_account.vrt_mint)
+            .vault_fee_token_account(vault_fee_token_account);
+        if vault_account.is_nested() {
+            ix_builder.child_vault(Some(vault_account.child_vault));
+        }
+
+        info!("Updating vault balance");
+        process_transaction(
+            &self.cli_config,
+            &rpc_client,
+            &[vault_fee_ata_ix, ix_builder.instruction()],
+            &[],
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn initialize_vault_with_mint(&self) -> Result<()> {
+        let rpc_client = self.get_rpc_client();
+
+        let ix_builder = InitializeVaultWithMintBuilder::new();
+
+        info!("Initializing vault with mint");
+        process_transaction(
+            &self.cli_config,
+            &rpc_client,
+            &[ix_builder.instruction()],
+            &[],
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn set_child_vault(&self, vault: String, child_vault: String) -> Result<()> {
+        let signer = signer_pubkey(&self.cli_config)?;
+        let rpc_client = self.get_rpc_client();
+
+        let vault = Pubkey::from_str(&vault)?;
+        let child_vault = Pubkey::from_str(&child_vault)?;
+
+        let vault_account_raw = rpc_client.get_account(&vault).await?;
+        let vault_account = Vault::try_from_slice_unchecked(&vault_account_raw.data)?;
+        vault_account.check_admin(&signer)?;
+        if vault_account.is_nested() {
+            return Err(anyhow!(
+                "Vault {} is already nested over {}",
+                vault,
+                vault_account.child_vault
+            ));
+        }
+
+        let child_vault_account_raw = rpc_client.get_account(&child_vault).await?;
+        let child_vault_account = Vault::try_from_slice_unchecked(&child_vault_account_raw.data)?;
+        if child_vault_account
+            .vrt_mint
+            .ne(&vault_account.supported_mint)
+        {
+            return Err(anyhow!(
+                "The child vault's VRT mint {} isn't the vault's supported mint {}",
+                child_vault_account.vrt_mint,
+                vault_account.supported_mint
+            ));
+        }
+
+        let mut ix_builder = SetChildVaultBuilder::new();
+        ix_builder
+            .config(Config::find_program_address(&self.vault_program_id).0)
+            .vault(vault)
+            .child_vault(child_vault)
+            .admin(signer);
+
+        info!("Nesting vault {} over child vault {}", vault, child_vault);
+        process_transaction(
+            &self.cli_config,
+            &rpc_client,
+            &[ix_builder.instruction()],
+            &[],
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn set_is_emergency_exit(
+        &self,
+        vault: String,
+        is_emergency_exit: bool,
+    ) -> Result<()> {
+        let signer = signer_pubkey(&self.cli_config)?;
+        let rpc_client = self.get_rpc_client();
+
+        let config = Config::find_program_address(&self.vault_program_id).0;
+        let config_account_raw = rpc_client.get_account(&config).await?;
+        let config_account = Config::try_from_slice_unchecked(&config_account_raw.data)?;
+
+        let vault = Pubkey::from_str(&vault)?;
+        let vault_account_raw = rpc_client.get_account(&vault).await?;
+        let vault_account = Vault::try_from_slice_unchecked(&vault_account_raw.data)?;
+
+        // The vault admin can enter emergency exit, only the config admin can leave it
+        if config_account.admin.ne(&signer) {
+            if !is_emergency_exit {
+                return Err(anyhow!("Only the config admin can leave emergency exit"));
+            }
+            vault_account.check_admin(&signer)?;
+        }
+
+        let mut ix_builder = SetIsEmergencyExitBuilder::new();
+        ix_builder
+            .config(config)
+            .vault(vault)
+            .admin(signer)
+            .is_emergency_exit(is_emergency_exit);
+
+        info!(
+            "Setting vault {} is_emergency_exit to {}",
+            vault, is_emergency_exit
+        );
+        process_transaction(
+            &self.cli_config,
+            &rpc_client,
+            &[ix_builder.instruction()],
+            &[],
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn set_paused_categories(&self, vault: String, paused_categories: u16) -> Result<()> {
+        let signer = signer_pubkey(&self.cli_config)?;
+        let rpc_client = self.get_rpc_client();
+
+        let vault = Pubkey::from_str(&vault)?;
+        let vault_account_raw = rpc_client.get_account(&vault).await?;
+        let vault_account = Vault::try_from_slice_unchecked(&vault_account_raw.data)?;
+        vault_account.check_admin(&signer)?;
+        if vault_account.is_emergency_exit()
+            && PauseCategory::Withdrawals.is_paused(paused_categories)
+        {
+            return Err(anyhow!("Withdrawals can't be paused in emergency exit"));
+        }
+
+        let mut ix_builder = SetPausedCategoriesBuilder::new();
+        ix_builder
+            .config(Config::find_program_address(&self.vault_program_id).0)
+            .vault(vault)
+            .admin(signer)
+            .paused_categories(paused_categories);
+
+        info!(
+            "Setting vault {} paused categories to {:?}",
+            vault,
+            output::paused_category_names(paused_categories)
+        );
+        process_transaction(
+            &self.cli_config,
+            &rpc_client,
+            &[ix_builder.instruction()],
+            &[],
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn set_auto_claim_delegate(
+        &self,
+        vault: String,
+        auto_claim_delegate: String,
+        max_tip_bps: u16,
+        base: Option<String>,
+    ) -> Result<()> {
+        let signer = signer_pubkey(&self.cli_config)?;
+        let rpc_client = self.get_rpc_client();
+
+        let vault = Pubkey::from_str(&vault)?;
+        let auto_claim_delegate = Pubkey::from_str(&auto_claim_delegate)?;
+        let base = match base {
+            Some(base) => Pubkey::from_str(&base)?,
+            None => signer,
+        };
+
+        let vault_staker_withdrawal_ticket = VaultStakerWithdrawalTicket::find_program_address(
+            &self.vault_program_id,
+            &vault,
+            &base,
+        )
+        .0;
+
+        let vault_account_raw = rpc_client.get_account(&vault).await?;
+        let vault_account = Vault::try_from_slice_unchecked(&vault_account_raw.data)?;
+        vault_account.check_is_paused()?;
+        vault_account.check_is_category_paused(PauseCategory::Withdrawals)?;
+
+        let ticket_account_raw = rpc_client
+            .get_account(&vault_staker_withdrawal_ticket)
+            .await?;
+        let ticket_account =
+            VaultStakerWithdrawalTicket::try_from_slice_unchecked(&ticket_account_raw.data)?;
+        ticket_account.check_staker(&signer)?;
+
+        let mut ix_builder = SetAutoClaimDelegateBuilder::new();
+        ix_builder
+            .config(Config::find_program_address(&self.vault_program_id).0)
+            .vault(vault)
+            .vault_staker_withdrawal_ticket(vault_staker_withdrawal_ticket)
+            .staker(signer)
+            .auto_claim_delegate(auto_claim_delegate)
+            .max_tip_bps(max_tip_bps);
+
+        info!(
+            "Setting withdrawal ticket {} auto-claim delegate to {} with a max tip of {} bps",
+            vault_staker_withdrawal_ticket, auto_claim_delegate, max_tip_bps
+        );
+        process_transaction(
+            &self.cli_config,
+            &rpc_client,
+            &[ix_builder.instruction()],
+            &[],
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn auto_claim_withdrawal_ticket(
+        &self,
+        vault: String,
+        base: String,
+        tip_bps: u16,
+    ) -> Result<()> {
+        let signer = signer_pubkey(&self.cli_config)?;
+        let rpc_client = self.get_rpc_client();
+
+        let vault = Pubkey::from_str(&vault)?;
+        let base = Pubkey::from_str(&base)?;
+        let vault_account_raw = rpc_client.get_account(&vault).await?;
+        let vault_account = Vault::try_from_slice_unchecked(&vault_account_raw.data)?;
+
+        let vault_staker_withdrawal_ticket = VaultStakerWithdrawalTicket::find_program_address(
+            &self.vault_program_id,
+            &vault,
+            &base,
+        )
+        .0;
+        let ticket_account_raw = rpc_client
+            .get_account(&vault_staker_withdrawal_ticket)
+            .await?;
+        let ticket_account =
+            VaultStakerWithdrawalTicket::try_from_slice_unchecked(&ticket_account_raw.data)?;
+        ticket_account.check_auto_claim(&signer, tip_bps)?;
+        let staker = ticket_account.staker;
+
+        let config = Config::find_program_address(&self.vault_program_id).0;
+        let config_account_raw = rpc_client.get_account(&config).await?;
+        let config_account = Config::try_from_slice_unchecked(&config_account_raw.data)?;
+
+        let program_fee_ata_ix = create_associated_token_account_idempotent(
+            &signer,
+            &config_account.program_fee_wallet,
+            &vault_account.vrt_mint,
+            &spl_token::ID,
+        );
+        let auto_claim_delegate_ata_ix = create_associated_token_account_idempotent(
+            &signer,
+            &signer,
+            &vault_account.supported_mint,
+            &spl_token::ID,
+        );
+
+        let mut ix_builder = AutoClaimWithdrawalTicketBuilder::new();
+        ix_builder
+            .config(config)
+            .vault(vault)
+            .vault_token_account(get_associated_token_address(
+                &vault,
+                &vault_account.supported_mint,
+            ))
+            .vrt_mint(vault_account.vrt_mint)
+            .staker(staker)
+            .staker_token_account(get_associated_token_address(
+                &staker,
+                &vault_account.supported_mint,
+            ))
+            .vault_staker_withdrawal_ticket(vault_staker_withdrawal_ticket)
+            .vault_staker_withdrawal_ticket_token_account(get_associated_token_address(
+                &vault_staker_withdrawal_ticket,
+                &vault_account.vrt_mint,
+            ))
+            .vault_fee_token_account(get_associated_token_address(
+                &vault_account.fee_wallet,
+                &vault_account.vrt_mint,
+            ))
+            .program_fee_token_account(get_associated_token_address(
+                &config_account.program_fee_wallet,
+                &vault_account.vrt_mint,
+            ))
+            .auto_claim_delegate(signer)
+            .auto_claim_delegate_token_account(get_associated_token_address(
+                &signer,
+                &vault_account.supported_mint,
+            ))
+            .tip_bps(tip_bps);
+
         info!(
-            "Setting vault config admin transaction: {:?}",
-            tx.get_signature()
+            "Auto-claiming withdrawal ticket {} for staker {}",
+            vault_staker_withdrawal_ticket, staker
         );
-        rpc_client.send_and_confirm_transaction(&tx).await?;
-        info!("Transaction confirmed: {:?}", tx.get_signature());
+        process_transaction(
+            &self.cli_config,
+            &rpc_client,
+            &[
+                program_fee_ata_ix,
+                auto_claim_delegate_ata_ix,
+                ix_builder.instruction(),
+            ],
+            &[],
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn migrate_account(&self, pubkey: String) -> Result<()> {
+        let signer = signer_pubkey(&self.cli_config)?;
+        let rpc_client = self.get_rpc_client();
+
+        let account = Pubkey::from_str(&pubkey)?;
+
+        let mut ix_builder = MigrateAccountBuilder::new();
+        ix_builder.account(account).payer(signer);
+
+        info!("Migrating account {}", account);
+        process_transaction(
+            &self.cli_config,
+            &rpc_client,
+            &[ix_builder.instruction()],
+            &[],
+        )
+        .await?;
+
         Ok(())
     }
 }
@@ -0,0 +1,479 @@
+//! Human-readable views of the vault program accounts, used by the `--output` formats
+
+use anyhow::{anyhow, Result};
+use jito_jsm_core::get_epoch;
+use jito_vault_core::{
+    burn_vault::BurnVault, config::Config, delegation_state::DelegationState, vault::Vault,
+    vault_ncn_slasher_operator_ticket::VaultNcnSlasherOperatorTicket,
+    vault_ncn_slasher_ticket::VaultNcnSlasherTicket, vault_ncn_ticket::VaultNcnTicket,
+    vault_operator_delegation::VaultOperatorDelegation,
+    vault_staker_withdrawal_ticket::VaultStakerWithdrawalTicket,
+    vault_update_state_tracker::VaultUpdateStateTracker,
+};
+use jito_vault_sdk::instruction::WithdrawalAllocationMethod;
+use serde::Serialize;
+use solana_program::{program_pack::Pack, pubkey::Pubkey};
+use solana_rpc_client::nonblocking::rpc_client::RpcClient;
+use spl_associated_token_account::get_associated_token_address;
+use spl_token::state::Mint;
+
+use crate::output::{paused_category_names, SlotToggleView, TokenAmount, ViewContext};
+
+/// Reads the decimals of a mint owned by either token program
+pub async fn fetch_mint_decimals(rpc_client: &RpcClient, mint: &Pubkey) -> Result<u8> {
+    let account = rpc_client.get_account(mint).await?;
+    let data = account
+        .data
+        .get(..Mint::LEN)
+        .ok_or_else(|| anyhow!("Account {} is not a mint", mint))?;
+    Ok(Mint::unpack_from_slice(data)?.decimals)
+}
+
+/// The decimals of a vault's supported token and VRT
+#[derive(Clone, Copy, Debug)]
+pub struct VaultDecimals {
+    pub supported: u8,
+    pub vrt: u8,
+}
+
+impl VaultDecimals {
+    pub async fn fetch(rpc_client: &RpcClient, vault: &Vault) -> Result<Self> {
+        Ok(Self {
+            supported: fetch_mint_decimals(rpc_client, &vault.supported_mint).await?,
+            vrt: fetch_mint_decimals(rpc_client, &vault.vrt_mint).await?,
+        })
+    }
+}
+
+fn optional_pubkey(pubkey: &Pubkey) -> Option<String> {
+    (*pubkey != Pubkey::default()).then(|| pubkey.to_string())
+}
+
+#[derive(Debug, Serialize)]
+pub struct ConfigView {
+    pub address: String,
+    pub admin: String,
+    pub fee_admin: String,
+    pub restaking_program: String,
+    pub epoch_length: u64,
+    pub current_epoch: u64,
+    pub num_vaults: u64,
+    pub program_fee_bps: u16,
+    pub program_fee_wallet: String,
+    pub deposit_withdrawal_fee_cap_bps: u16,
+    pub fee_rate_of_change_bps: u16,
+    pub fee_bump_bps: u16,
+    pub bump: u8,
+}
+
+impl ConfigView {
+    pub fn new(address: &Pubkey, config: &Config, context: &ViewContext) -> Result<Self> {
+        Ok(Self {
+            address: address.to_string(),
+            admin: config.admin.to_string(),
+            fee_admin: config.fee_admin.to_string(),
+            restaking_program: config.restaking_program.to_string(),
+            epoch_length: config.epoch_length(),
+            current_epoch: get_epoch(context.slot, config.epoch_length())?,
+            num_vaults: config.num_vaults(),
+            program_fee_bps: config.program_fee_bps(),
+            program_fee_wallet: config.program_fee_wallet.to_string(),
+            deposit_withdrawal_fee_cap_bps: config.deposit_withdrawal_fee_cap_bps(),
+            fee_rate_of_change_bps: config.fee_rate_of_change_bps(),
+            fee_bump_bps: config.fee_bump_bps(),
+            bump: config.bump,
+        })
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct DelegationStateView {
+    pub staked_amount: TokenAmount,
+    pub enqueued_for_cooldown_amount: TokenAmount,
+    pub cooling_down_amount: TokenAmount,
+    pub total_security: TokenAmount,
+}
+
+impl DelegationStateView {
+    pub fn new(delegation_state: &DelegationState, decimals: u8) -> Result<Self> {
+        Ok(Self {
+            staked_amount: TokenAmount::new(delegation_state.staked_amount(), decimals),
+            enqueued_for_cooldown_amount: TokenAmount::new(
+                delegation_state.enqueued_for_cooldown_amount(),
+                decimals,
+            ),
+            cooling_down_amount: TokenAmount::new(delegation_state.cooling_down_amount(), decimals),
+            total_security: TokenAmount::new(delegation_state.total_security()?, decimals),
+        })
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct VaultFeesView {
+    pub deposit_fee_bps: u16,
+    pub withdrawal_fee_bps: u16,
+    pub next_withdrawal_fee_bps: u16,
+    pub reward_fee_bps: u16,
+    pub program_fee_bps: u16,
+    pub last_fee_change_slot: u64,
+    pub fee_wallet: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct VaultAdminsView {
+    pub admin: String,
+    pub delegation_admin: String,
+    pub operator_admin: String,
+    pub ncn_admin: String,
+    pub slasher_admin: String,
+    pub capacity_admin: String,
+    pub fee_admin: String,
+    pub delegate_asset_admin: String,
+    pub mint_burn_admin: String,
+    pub metadata_admin: String,
+}
+
+/// Accounts derived from a vault that other instructions need
+#[derive(Debug, Serialize)]
+pub struct VaultDerivedView {
+    pub config: String,
+    pub burn_vault: String,
+    pub vault_supported_token_account: String,
+    pub fee_wallet_vrt_account: String,
+    pub burn_vault_vrt_account: String,
+    pub current_update_state_tracker: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct VaultView {
+    pub address: String,
+    pub base: String,
+    pub vault_index: u64,
+    pub vrt_mint: String,
+    pub supported_mint: String,
+    pub vrt_supply: TokenAmount,
+    pub tokens_deposited: TokenAmount,
+    pub deposit_capacity: TokenAmount,
+    /// Supported tokens backing one VRT, or none while no VRT is minted
+    pub exchange_rate: Option<f64>,
+    pub delegation_state: DelegationStateView,
+    pub additional_assets_need_unstaking: TokenAmount,
+    pub vrt_enqueued_for_cooldown_amount: TokenAmount,
+    pub vrt_cooling_down_amount: TokenAmount,
+    pub vrt_ready_to_claim_amount: TokenAmount,
+    pub ncn_count: u64,
+    pub operator_count: u64,
+    pub slasher_count: u64,
+    pub fees: VaultFeesView,
+    pub is_paused: bool,
+    pub paused_categories: Vec<String>,
+    pub is_emergency_exit: bool,
+    pub last_start_state_update_slot: u64,
+    pub last_full_state_update_slot: u64,
+    pub is_update_needed: bool,
+    pub child_vault: Option<String>,
+    pub nesting_depth: u8,
+    pub admins: VaultAdminsView,
+    pub derived: VaultDerivedView,
+    pub bump: u8,
+}
+
+impl VaultView {
+    pub fn new(
+        program_id: &Pubkey,
+        address: &Pubkey,
+        vault: &Vault,
+        decimals: VaultDecimals,
+        context: &ViewContext,
+    ) -> Result<Self> {
+        let exchange_rate = (vault.vrt_supply() > 0).then(|| {
+            let tokens = vault.tokens_deposited() as f64 / 10f64.powi(decimals.supported.into());
+            let vrt = vault.vrt_supply() as f64 / 10f64.powi(decimals.vrt.into());
+            tokens / vrt
+        });
+        let burn_vault = BurnVault::find_program_address(program_id, &vault.base).0;
+        let ncn_epoch = get_epoch(context.slot, context.epoch_length)?;
+
+        Ok(Self {
+            address: address.to_string(),
+            base: vault.base.to_string(),
+            vault_index: vault.vault_index(),
+            vrt_mint: vault.vrt_mint.to_string(),
+            supported_mint: vault.supported_mint.to_string(),
+            vrt_supply: TokenAmount::new(vault.vrt_supply(), decimals.vrt),
+            tokens_deposited: TokenAmount::new(vault.tokens_deposited(), decimals.supported),
+            deposit_capacity: TokenAmount::new(vault.deposit_capacity(), decimals.supported),
+            exchange_rate,
+            delegation_state: DelegationStateView::new(
+                &vault.delegation_state,
+                decimals.supported,
+            )?,
+            additional_assets_need_unstaking: TokenAmount::new(
+                vault.additional_assets_need_unstaking(),
+                decimals.supported,
+            ),
+            vrt_enqueued_for_cooldown_amount: TokenAmount::new(
+                vault.vrt_enqueued_for_cooldown_amount(),
+                decimals.vrt,
+            ),
+            vrt_cooling_down_amount: TokenAmount::new(
+                vault.vrt_cooling_down_amount(),
+                decimals.vrt,
+            ),
+            vrt_ready_to_claim_amount: TokenAmount::new(
+                vault.vrt_ready_to_claim_amount(),
+                decimals.vrt,
+            ),
+            ncn_count: vault.ncn_count(),
+            operator_count: vault.operator_count(),
+            slasher_count: vault.slasher_count(),
+            fees: VaultFeesView {
+                deposit_fee_bps: vault.deposit_fee_bps(),
+                withdrawal_fee_bps: vault.withdrawal_fee_bps(),
+                next_withdrawal_fee_bps: vault.next_withdrawal_fee_bps(),
+                reward_fee_bps: vault.reward_fee_bps(),
+                program_fee_bps: vault.program_fee_bps(),
+                last_fee_change_slot: vault.last_fee_change_slot(),
+                fee_wallet: vault.fee_wallet.to_string(),
+            },
+            is_paused: vault.is_paused(),
+            paused_categories: paused_category_names(vault.paused_categories()),
+            is_emergency_exit: vault.is_emergency_exit(),
+            last_start_state_update_slot: vault.last_start_state_update_slot(),
+            last_full_state_update_slot: vault.last_full_state_update_slot(),
+            is_update_needed: vault.is_update_needed(context.slot, context.epoch_length)?,
+            child_vault: optional_pubkey(&vault.child_vault),
+            nesting_depth: vault.nesting_depth(),
+            admins: VaultAdminsView {
+                admin: vault.admin.to_string(),
+                delegation_admin: vault.delegation_admin.to_string(),
+                operator_admin: vault.operator_admin.to_string(),
+                ncn_admin: vault.ncn_admin.to_string(),
+                slasher_admin: vault.slasher_admin.to_string(),
+                capacity_admin: vault.capacity_admin.to_string(),
+                fee_admin: vault.fee_admin.to_string(),
+                delegate_asset_admin: vault.delegate_asset_admin.to_string(),
+                mint_burn_admin: vault.mint_burn_admin.to_string(),
+                metadata_admin: vault.metadata_admin.to_string(),
+            },
+            derived: VaultDerivedView {
+                config: Config::find_program_address(program_id).0.to_string(),
+                burn_vault: burn_vault.to_string(),
+                vault_supported_token_account: get_associated_token_address(
+                    address,
+                    &vault.supported_mint,
+                )
+                .to_string(),
+                fee_wallet_vrt_account: get_associated_token_address(
+                    &vault.fee_wallet,
+                    &vault.vrt_mint,
+                )
+                .to_string(),
+                burn_vault_vrt_account: get_associated_token_address(&burn_vault, &vault.vrt_mint)
+                    .to_string(),
+                current_update_state_tracker: VaultUpdateStateTracker::find_program_address(
+                    program_id, address, ncn_epoch,
+                )
+                .0
+                .to_string(),
+            },
+            bump: vault.bump,
+        })
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct VaultNcnTicketView {
+    pub address: String,
+    pub vault: String,
+    pub ncn: String,
+    pub index: u64,
+    pub state: SlotToggleView,
+    pub bump: u8,
+}
+
+impl VaultNcnTicketView {
+    pub fn new(address: &Pubkey, ticket: &VaultNcnTicket, context: &ViewContext) -> Result<Self> {
+        Ok(Self {
+            address: address.to_string(),
+            vault: ticket.vault.to_string(),
+            ncn: ticket.ncn.to_string(),
+            index: ticket.index(),
+            state: SlotToggleView::new(&ticket.state, context)?,
+            bump: ticket.bump,
+        })
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct VaultNcnSlasherTicketView {
+    pub address: String,
+    pub vault: String,
+    pub ncn: String,
+    pub slasher: String,
+    pub index: u64,
+    pub max_slashable_per_epoch: TokenAmount,
+    pub state: SlotToggleView,
+    pub bump: u8,
+}
+
+impl VaultNcnSlasherTicketView {
+    pub fn new(
+        address: &Pubkey,
+        ticket: &VaultNcnSlasherTicket,
+        decimals: VaultDecimals,
+        context: &ViewContext,
+    ) -> Result<Self> {
+        Ok(Self {
+            address: address.to_string(),
+            vault: ticket.vault.to_string(),
+            ncn: ticket.ncn.to_string(),
+            slasher: ticket.slasher.to_string(),
+            index: ticket.index(),
+            max_slashable_per_epoch: TokenAmount::new(
+                ticket.max_slashable_per_epoch(),
+                decimals.supported,
+            ),
+            state: SlotToggleView::new(&ticket.state, context)?,
+            bump: ticket.bump,
+        })
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct VaultNcnSlasherOperatorTicketView {
+    pub address: String,
+    pub vault: String,
+    pub ncn: String,
+    pub slasher: String,
+    pub operator: String,
+    pub epoch: u64,
+    pub slashed: TokenAmount,
+    pub bump: u8,
+}
+
+impl VaultNcnSlasherOperatorTicketView {
+    pub fn new(
+        address: &Pubkey,
+        ticket: &VaultNcnSlasherOperatorTicket,
+        decimals: VaultDecimals,
+    ) -> Self {
+        Self {
+            address: address.to_string(),
+            vault: ticket.vault.to_string(),
+            ncn: ticket.ncn.to_string(),
+            slasher: ticket.slasher.to_string(),
+            operator: ticket.operator.to_string(),
+            epoch: ticket.epoch(),
+            slashed: TokenAmount::new(ticket.slashed(), decimals.supported),
+            bump: ticket.bump,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct VaultOperatorDelegationView {
+    pub address: String,
+    pub vault: String,
+    pub operator: String,
+    pub index: u64,
+    pub last_update_slot: u64,
+    pub delegation_state: DelegationStateView,
+    pub bump: u8,
+}
+
+impl VaultOperatorDelegationView {
+    pub fn new(
+        address: &Pubkey,
+        delegation: &VaultOperatorDelegation,
+        decimals: VaultDecimals,
+    ) -> Result<Self> {
+        Ok(Self {
+            address: address.to_string(),
+            vault: delegation.vault.to_string(),
+            operator: delegation.operator.to_string(),
+            index: delegation.index(),
+            last_update_slot: delegation.last_update_slot(),
+            delegation_state: DelegationStateView::new(
+                &delegation.delegation_state,
+                decimals.supported,
+            )?,
+            bump: delegation.bump,
+        })
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct VaultStakerWithdrawalTicketView {
+    pub address: String,
+    pub vault: String,
+    pub staker: String,
+    pub base: String,
+    pub vrt_amount: TokenAmount,
+    pub slot_unstaked: u64,
+    pub is_withdrawable: bool,
+    pub auto_claim_delegate: Option<String>,
+    pub auto_claim_max_tip_bps: u16,
+    pub ticket_vrt_account: String,
+    pub bump: u8,
+}
+
+impl VaultStakerWithdrawalTicketView {
+    pub fn new(
+        address: &Pubkey,
+        ticket: &VaultStakerWithdrawalTicket,
+        vault: &Vault,
+        decimals: VaultDecimals,
+        context: &ViewContext,
+    ) -> Result<Self> {
+        Ok(Self {
+            address: address.to_string(),
+            vault: ticket.vault.to_string(),
+            staker: ticket.staker.to_string(),
+            base: ticket.base.to_string(),
+            vrt_amount: TokenAmount::new(ticket.vrt_amount(), decimals.vrt),
+            slot_unstaked: ticket.slot_unstaked(),
+            is_withdrawable: ticket.is_withdrawable(context.slot, context.epoch_length)?,
+            auto_claim_delegate: optional_pubkey(&ticket.auto_claim_delegate),
+            auto_claim_max_tip_bps: ticket.auto_claim_max_tip_bps(),
+            ticket_vrt_account: get_associated_token_address(address, &vault.vrt_mint).to_string(),
+            bump: ticket.bump,
+        })
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct VaultUpdateStateTrackerView {
+    pub address: String,
+    pub vault: String,
+    pub ncn_epoch: u64,
+    pub last_updated_index: Option<u64>,
+    pub delegation_state: DelegationStateView,
+    pub withdrawal_allocation_method: String,
+}
+
+impl VaultUpdateStateTrackerView {
+    pub fn new(
+        address: &Pubkey,
+        tracker: &VaultUpdateStateTracker,
+        decimals: VaultDecimals,
+    ) -> Result<Self> {
+        let withdrawal_allocation_method =
+            match WithdrawalAllocationMethod::try_from(tracker.withdrawal_allocation_method) {
+                Ok(method) => format!("{:?}", method),
+                Err(_) => format!("unknown ({})", tracker.withdrawal_allocation_method),
+            };
+        Ok(Self {
+            address: address.to_string(),
+            vault: tracker.vault.to_string(),
+            ncn_epoch: tracker.ncn_epoch(),
+            last_updated_index: (tracker.last_updated_index() != u64::MAX)
+                .then(|| tracker.last_updated_index()),
+            delegation_state: DelegationStateView::new(
+                &tracker.delegation_state,
+                decimals.supported,
+            )?,
+            withdrawal_allocation_method,
+        })
+    }
+}
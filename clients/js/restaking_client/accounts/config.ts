@@ -19,6 +19,8 @@ import {
   getArrayEncoder,
   getStructDecoder,
   getStructEncoder,
+  getU16Decoder,
+  getU16Encoder,
   getU64Decoder,
   getU64Encoder,
   getU8Decoder,
@@ -43,6 +45,7 @@ export type Config = {
   operatorCount: bigint;
   epochLength: bigint;
   bump: number;
+  pausedCategories: number;
   reserved: Array<number>;
 };
 
@@ -54,6 +57,7 @@ export type ConfigArgs = {
   operatorCount: number | bigint;
   epochLength: number | bigint;
   bump: number;
+  pausedCategories: number;
   reserved: Array<number>;
 };
 
@@ -66,7 +70,8 @@ export function getConfigEncoder(): Encoder<ConfigArgs> {
     ['operatorCount', getU64Encoder()],
     ['epochLength', getU64Encoder()],
     ['bump', getU8Encoder()],
-    ['reserved', getArrayEncoder(getU8Encoder(), { size: 263 })],
+    ['pausedCategories', getU16Encoder()],
+    ['reserved', getArrayEncoder(getU8Encoder(), { size: 261 })],
   ]);
 }
 
@@ -79,7 +84,8 @@ export function getConfigDecoder(): Decoder<Config> {
     ['operatorCount', getU64Decoder()],
     ['epochLength', getU64Decoder()],
     ['bump', getU8Decoder()],
-    ['reserved', getArrayDecoder(getU8Decoder(), { size: 263 })],
+    ['pausedCategories', getU16Decoder()],
+    ['reserved', getArrayDecoder(getU8Decoder(), { size: 261 })],
   ]);
 }
 
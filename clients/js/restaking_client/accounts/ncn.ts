@@ -19,6 +19,8 @@ import {
   getArrayEncoder,
   getStructDecoder,
   getStructEncoder,
+  getU16Decoder,
+  getU16Encoder,
   getU64Decoder,
   getU64Encoder,
   getU8Decoder,
@@ -51,6 +53,7 @@ export type Ncn = {
   vaultCount: bigint;
   slasherCount: bigint;
   bump: number;
+  pausedCategories: number;
   reserved: Array<number>;
 };
 
@@ -70,6 +73,7 @@ export type NcnArgs = {
   vaultCount: number | bigint;
   slasherCount: number | bigint;
   bump: number;
+  pausedCategories: number;
   reserved: Array<number>;
 };
 
@@ -90,7 +94,8 @@ export function getNcnEncoder(): Encoder<NcnArgs> {
     ['vaultCount', getU64Encoder()],
     ['slasherCount', getU64Encoder()],
     ['bump', getU8Encoder()],
-    ['reserved', getArrayEncoder(getU8Encoder(), { size: 263 })],
+    ['pausedCategories', getU16Encoder()],
+    ['reserved', getArrayEncoder(getU8Encoder(), { size: 261 })],
   ]);
 }
 
@@ -111,7 +116,8 @@ export function getNcnDecoder(): Decoder<Ncn> {
     ['vaultCount', getU64Decoder()],
     ['slasherCount', getU64Decoder()],
     ['bump', getU8Decoder()],
-    ['reserved', getArrayDecoder(getU8Decoder(), { size: 263 })],
+    ['pausedCategories', getU16Decoder()],
+    ['reserved', getArrayDecoder(getU8Decoder(), { size: 261 })],
   ]);
 }
 
@@ -17,8 +17,12 @@ import {
   getAddressEncoder,
   getArrayDecoder,
   getArrayEncoder,
+  getBooleanDecoder,
+  getBooleanEncoder,
   getStructDecoder,
   getStructEncoder,
+  getU16Decoder,
+  getU16Encoder,
   getU64Decoder,
   getU64Encoder,
   getU8Decoder,
@@ -49,6 +53,9 @@ export type NcnOperatorState = {
   ncnOptInState: SlotToggle;
   operatorOptInState: SlotToggle;
   bump: number;
+  isJailed: boolean;
+  jailReason: number;
+  jailedSlot: bigint;
   reserved: Array<number>;
 };
 
@@ -60,6 +67,9 @@ export type NcnOperatorStateArgs = {
   ncnOptInState: SlotToggleArgs;
   operatorOptInState: SlotToggleArgs;
   bump: number;
+  isJailed: boolean;
+  jailReason: number;
+  jailedSlot: number | bigint;
   reserved: Array<number>;
 };
 
@@ -72,7 +82,10 @@ export function getNcnOperatorStateEncoder(): Encoder<NcnOperatorStateArgs> {
     ['ncnOptInState', getSlotToggleEncoder()],
     ['operatorOptInState', getSlotToggleEncoder()],
     ['bump', getU8Encoder()],
-    ['reserved', getArrayEncoder(getU8Encoder(), { size: 263 })],
+    ['isJailed', getBooleanEncoder()],
+    ['jailReason', getU16Encoder()],
+    ['jailedSlot', getU64Encoder()],
+    ['reserved', getArrayEncoder(getU8Encoder(), { size: 252 })],
   ]);
 }
 
@@ -85,7 +98,10 @@ export function getNcnOperatorStateDecoder(): Decoder<NcnOperatorState> {
     ['ncnOptInState', getSlotToggleDecoder()],
     ['operatorOptInState', getSlotToggleDecoder()],
     ['bump', getU8Decoder()],
-    ['reserved', getArrayDecoder(getU8Decoder(), { size: 263 })],
+    ['isJailed', getBooleanDecoder()],
+    ['jailReason', getU16Decoder()],
+    ['jailedSlot', getU64Decoder()],
+    ['reserved', getArrayDecoder(getU8Decoder(), { size: 252 })],
   ]);
 }
 
@@ -51,6 +51,8 @@ export type Operator = {
   vaultCount: bigint;
   operatorFeeBps: number;
   bump: number;
+  jailedNcnCount: bigint;
+  pausedCategories: number;
   reservedSpace: Array<number>;
 };
 
@@ -68,6 +70,8 @@ export type OperatorArgs = {
   vaultCount: number | bigint;
   operatorFeeBps: number;
   bump: number;
+  jailedNcnCount: number | bigint;
+  pausedCategories: number;
   reservedSpace: Array<number>;
 };
 
@@ -86,7 +90,9 @@ export function getOperatorEncoder(): Encoder<OperatorArgs> {
     ['vaultCount', getU64Encoder()],
     ['operatorFeeBps', getU16Encoder()],
     ['bump', getU8Encoder()],
-    ['reservedSpace', getArrayEncoder(getU8Encoder(), { size: 261 })],
+    ['jailedNcnCount', getU64Encoder()],
+    ['pausedCategories', getU16Encoder()],
+    ['reservedSpace', getArrayEncoder(getU8Encoder(), { size: 251 })],
   ]);
 }
 
@@ -105,7 +111,9 @@ export function getOperatorDecoder(): Decoder<Operator> {
     ['vaultCount', getU64Decoder()],
     ['operatorFeeBps', getU16Decoder()],
     ['bump', getU8Decoder()],
-    ['reservedSpace', getArrayDecoder(getU8Decoder(), { size: 261 })],
+    ['jailedNcnCount', getU64Decoder()],
+    ['pausedCategories', getU16Decoder()],
+    ['reservedSpace', getArrayDecoder(getU8Decoder(), { size: 251 })],
   ]);
 }
 
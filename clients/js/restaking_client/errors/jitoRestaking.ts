@@ -38,6 +38,12 @@ export const JITO_RESTAKING_ERROR__NCN_WARMUP_OPERATOR_FAILED = 0x3f0; // 1008
 export const JITO_RESTAKING_ERROR__NCN_VAULT_SLASHER_TICKET_FAILED_WARMUP = 0x3f1; // 1009
 /** NcnVaultTicketFailedWarmup: NcnVaultTicketFailedWarmup */
 export const JITO_RESTAKING_ERROR__NCN_VAULT_TICKET_FAILED_WARMUP = 0x3f2; // 1010
+/** NcnOperatorAlreadyJailed: NcnOperatorAlreadyJailed */
+export const JITO_RESTAKING_ERROR__NCN_OPERATOR_ALREADY_JAILED = 0x3f3; // 1011
+/** NcnOperatorNotJailed: NcnOperatorNotJailed */
+export const JITO_RESTAKING_ERROR__NCN_OPERATOR_NOT_JAILED = 0x3f4; // 1012
+/** NcnPaused: NcnPaused */
+export const JITO_RESTAKING_ERROR__NCN_PAUSED = 0x3f5; // 1013
 /** OperatorNcnAdminInvalid: OperatorNcnAdminInvalid */
 export const JITO_RESTAKING_ERROR__OPERATOR_NCN_ADMIN_INVALID = 0x7d0; // 2000
 /** OperatorVaultAdminInvalid: OperatorVaultAdminInvalid */
@@ -68,6 +74,10 @@ export const JITO_RESTAKING_ERROR__SLASHER_OVERFLOW = 0x7dc; // 2012
 export const JITO_RESTAKING_ERROR__INVALID_EPOCH_LENGTH = 0x7dd; // 2013
 /** ConfigAdminInvalid: ConfigAdminInvalid */
 export const JITO_RESTAKING_ERROR__CONFIG_ADMIN_INVALID = 0x7de; // 2014
+/** OperatorPaused: OperatorPaused */
+export const JITO_RESTAKING_ERROR__OPERATOR_PAUSED = 0x7df; // 2015
+/** ConfigPaused: ConfigPaused */
+export const JITO_RESTAKING_ERROR__CONFIG_PAUSED = 0x7e0; // 2016
 /** ArithmeticOverflow: ArithmeticOverflow */
 export const JITO_RESTAKING_ERROR__ARITHMETIC_OVERFLOW = 0xbb8; // 3000
 /** ArithmeticUnderflow: ArithmeticUnderflow */
@@ -80,13 +90,17 @@ export type JitoRestakingError =
   | typeof JITO_RESTAKING_ERROR__ARITHMETIC_UNDERFLOW
   | typeof JITO_RESTAKING_ERROR__BAD_EPOCH_LENGTH
   | typeof JITO_RESTAKING_ERROR__CONFIG_ADMIN_INVALID
+  | typeof JITO_RESTAKING_ERROR__CONFIG_PAUSED
   | typeof JITO_RESTAKING_ERROR__DIVISION_BY_ZERO
   | typeof JITO_RESTAKING_ERROR__INVALID_EPOCH_LENGTH
   | typeof JITO_RESTAKING_ERROR__NCN_ADMIN_INVALID
   | typeof JITO_RESTAKING_ERROR__NCN_COOLDOWN_OPERATOR_FAILED
   | typeof JITO_RESTAKING_ERROR__NCN_DELEGATE_ADMIN_INVALID
   | typeof JITO_RESTAKING_ERROR__NCN_OPERATOR_ADMIN_INVALID
+  | typeof JITO_RESTAKING_ERROR__NCN_OPERATOR_ALREADY_JAILED
+  | typeof JITO_RESTAKING_ERROR__NCN_OPERATOR_NOT_JAILED
   | typeof JITO_RESTAKING_ERROR__NCN_OVERFLOW
+  | typeof JITO_RESTAKING_ERROR__NCN_PAUSED
   | typeof JITO_RESTAKING_ERROR__NCN_SLASHER_ADMIN_INVALID
   | typeof JITO_RESTAKING_ERROR__NCN_VAULT_ADMIN_INVALID
   | typeof JITO_RESTAKING_ERROR__NCN_VAULT_SLASHER_TICKET_FAILED_COOLDOWN
@@ -100,6 +114,7 @@ export type JitoRestakingError =
   | typeof JITO_RESTAKING_ERROR__OPERATOR_FEE_CAP_EXCEEDED
   | typeof JITO_RESTAKING_ERROR__OPERATOR_NCN_ADMIN_INVALID
   | typeof JITO_RESTAKING_ERROR__OPERATOR_OVERFLOW
+  | typeof JITO_RESTAKING_ERROR__OPERATOR_PAUSED
   | typeof JITO_RESTAKING_ERROR__OPERATOR_VAULT_ADMIN_INVALID
   | typeof JITO_RESTAKING_ERROR__OPERATOR_VAULT_TICKET_FAILED_COOLDOWN
   | typeof JITO_RESTAKING_ERROR__OPERATOR_VAULT_TICKET_FAILED_WARMUP
@@ -114,13 +129,17 @@ if (process.env.NODE_ENV !== 'production') {
     [JITO_RESTAKING_ERROR__ARITHMETIC_UNDERFLOW]: `ArithmeticUnderflow`,
     [JITO_RESTAKING_ERROR__BAD_EPOCH_LENGTH]: `Bad epoch length`,
     [JITO_RESTAKING_ERROR__CONFIG_ADMIN_INVALID]: `ConfigAdminInvalid`,
+    [JITO_RESTAKING_ERROR__CONFIG_PAUSED]: `ConfigPaused`,
     [JITO_RESTAKING_ERROR__DIVISION_BY_ZERO]: `DivisionByZero`,
     [JITO_RESTAKING_ERROR__INVALID_EPOCH_LENGTH]: `InvalidEpochLength`,
     [JITO_RESTAKING_ERROR__NCN_ADMIN_INVALID]: `NcnAdminInvalid`,
     [JITO_RESTAKING_ERROR__NCN_COOLDOWN_OPERATOR_FAILED]: `NcnCooldownOperatorFailed`,
     [JITO_RESTAKING_ERROR__NCN_DELEGATE_ADMIN_INVALID]: `NcnDelegateAdminInvalid`,
     [JITO_RESTAKING_ERROR__NCN_OPERATOR_ADMIN_INVALID]: `NcnOperatorAdminInvalid`,
+    [JITO_RESTAKING_ERROR__NCN_OPERATOR_ALREADY_JAILED]: `NcnOperatorAlreadyJailed`,
+    [JITO_RESTAKING_ERROR__NCN_OPERATOR_NOT_JAILED]: `NcnOperatorNotJailed`,
     [JITO_RESTAKING_ERROR__NCN_OVERFLOW]: `NcnOverflow`,
+    [JITO_RESTAKING_ERROR__NCN_PAUSED]: `NcnPaused`,
     [JITO_RESTAKING_ERROR__NCN_SLASHER_ADMIN_INVALID]: `NcnSlasherAdminInvalid`,
     [JITO_RESTAKING_ERROR__NCN_VAULT_ADMIN_INVALID]: `NcnVaultAdminInvalid`,
     [JITO_RESTAKING_ERROR__NCN_VAULT_SLASHER_TICKET_FAILED_COOLDOWN]: `NcnVaultSlasherTicketFailedCooldown`,
@@ -134,6 +153,7 @@ if (process.env.NODE_ENV !== 'production') {
     [JITO_RESTAKING_ERROR__OPERATOR_FEE_CAP_EXCEEDED]: `OperatorFeeCapExceeded`,
     [JITO_RESTAKING_ERROR__OPERATOR_NCN_ADMIN_INVALID]: `OperatorNcnAdminInvalid`,
     [JITO_RESTAKING_ERROR__OPERATOR_OVERFLOW]: `OperatorOverflow`,
+    [JITO_RESTAKING_ERROR__OPERATOR_PAUSED]: `OperatorPaused`,
     [JITO_RESTAKING_ERROR__OPERATOR_VAULT_ADMIN_INVALID]: `OperatorVaultAdminInvalid`,
     [JITO_RESTAKING_ERROR__OPERATOR_VAULT_TICKET_FAILED_COOLDOWN]: `OperatorVaultTicketFailedCooldown`,
     [JITO_RESTAKING_ERROR__OPERATOR_VAULT_TICKET_FAILED_WARMUP]: `OperatorVaultTicketFailedWarmup`,
@@ -16,18 +16,24 @@ export * from './initializeNcnVaultSlasherTicket';
 export * from './initializeNcnVaultTicket';
 export * from './initializeOperator';
 export * from './initializeOperatorVaultTicket';
+export * from './jailOperator';
+export * from './migrateAccount';
 export * from './ncnCooldownOperator';
 export * from './ncnDelegateTokenAccount';
 export * from './ncnSetAdmin';
+export * from './ncnSetPausedCategories';
 export * from './ncnSetSecondaryAdmin';
 export * from './ncnWarmupOperator';
 export * from './operatorCooldownNcn';
 export * from './operatorDelegateTokenAccount';
 export * from './operatorSetAdmin';
 export * from './operatorSetFee';
+export * from './operatorSetPausedCategories';
 export * from './operatorSetSecondaryAdmin';
 export * from './operatorWarmupNcn';
 export * from './setConfigAdmin';
+export * from './setConfigPausedCategories';
+export * from './unjailOperator';
 export * from './warmupNcnVaultSlasherTicket';
 export * from './warmupNcnVaultTicket';
 export * from './warmupOperatorVaultTicket';
@@ -0,0 +1,214 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU16Decoder,
+  getU16Encoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IAccountSignerMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+  type ReadonlySignerAccount,
+  type TransactionSigner,
+  type WritableAccount,
+} from '@solana/web3.js';
+import { JITO_RESTAKING_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export const NCN_SET_PAUSED_CATEGORIES_DISCRIMINATOR = 28;
+
+export function getNcnSetPausedCategoriesDiscriminatorBytes() {
+  return getU8Encoder().encode(NCN_SET_PAUSED_CATEGORIES_DISCRIMINATOR);
+}
+
+export type NcnSetPausedCategoriesInstruction<
+  TProgram extends string = typeof JITO_RESTAKING_PROGRAM_ADDRESS,
+  TAccountConfig extends string | IAccountMeta<string> = string,
+  TAccountNcn extends string | IAccountMeta<string> = string,
+  TAccountAdmin extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountConfig extends string
+        ? ReadonlyAccount<TAccountConfig>
+        : TAccountConfig,
+      TAccountNcn extends string
+        ? WritableAccount<TAccountNcn>
+        : TAccountNcn,
+      TAccountAdmin extends string
+        ? ReadonlySignerAccount<TAccountAdmin> &
+            IAccountSignerMeta<TAccountAdmin>
+        : TAccountAdmin,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type NcnSetPausedCategoriesInstructionData = {
+  discriminator: number;
+  pausedCategories: number;
+};
+
+export type NcnSetPausedCategoriesInstructionDataArgs = {
+  pausedCategories: number;
+};
+
+export function getNcnSetPausedCategoriesInstructionDataEncoder(): Encoder<NcnSetPausedCategoriesInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([
+      ['discriminator', getU8Encoder()],
+      ['pausedCategories', getU16Encoder()],
+    ]),
+    (value) => ({
+      ...value,
+      discriminator: NCN_SET_PAUSED_CATEGORIES_DISCRIMINATOR,
+    })
+  );
+}
+
+export function getNcnSetPausedCategoriesInstructionDataDecoder(): Decoder<NcnSetPausedCategoriesInstructionData> {
+  return getStructDecoder([
+    ['discriminator', getU8Decoder()],
+    ['pausedCategories', getU16Decoder()],
+  ]);
+}
+
+export function getNcnSetPausedCategoriesInstructionDataCodec(): Codec<
+  NcnSetPausedCategoriesInstructionDataArgs,
+  NcnSetPausedCategoriesInstructionData
+> {
+  return combineCodec(
+    getNcnSetPausedCategoriesInstructionDataEncoder(),
+    getNcnSetPausedCategoriesInstructionDataDecoder()
+  );
+}
+
+export type NcnSetPausedCategoriesInput<
+  TAccountConfig extends string = string,
+  TAccountNcn extends string = string,
+  TAccountAdmin extends string = string,
+> = {
+  config: Address<TAccountConfig>;
+  ncn: Address<TAccountNcn>;
+  admin: TransactionSigner<TAccountAdmin>;
+  pausedCategories: NcnSetPausedCategoriesInstructionDataArgs['pausedCategories'];
+};
+
+export function getNcnSetPausedCategoriesInstruction<
+  TAccountConfig extends string,
+  TAccountNcn extends string,
+  TAccountAdmin extends string,
+  TProgramAddress extends Address = typeof JITO_RESTAKING_PROGRAM_ADDRESS,
+>(
+  input: NcnSetPausedCategoriesInput<
+    TAccountConfig,
+    TAccountNcn,
+    TAccountAdmin
+  >,
+  config?: { programAddress?: TProgramAddress }
+): NcnSetPausedCategoriesInstruction<
+  TProgramAddress,
+  TAccountConfig,
+  TAccountNcn,
+  TAccountAdmin
+> {
+  // Program address.
+  const programAddress =
+    config?.programAddress ?? JITO_RESTAKING_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: false },
+    ncn: { value: input.ncn ?? null, isWritable: true },
+    admin: { value: input.admin ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.ncn),
+      getAccountMeta(accounts.admin),
+    ],
+    programAddress,
+    data: getNcnSetPausedCategoriesInstructionDataEncoder().encode(
+      args as NcnSetPausedCategoriesInstructionDataArgs
+    ),
+  } as NcnSetPausedCategoriesInstruction<
+    TProgramAddress,
+    TAccountConfig,
+    TAccountNcn,
+    TAccountAdmin
+  >;
+
+  return instruction;
+}
+
+export type ParsedNcnSetPausedCategoriesInstruction<
+  TProgram extends string = typeof JITO_RESTAKING_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    config: TAccountMetas[0];
+    ncn: TAccountMetas[1];
+    admin: TAccountMetas[2];
+  };
+  data: NcnSetPausedCategoriesInstructionData;
+};
+
+export function parseNcnSetPausedCategoriesInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedNcnSetPausedCategoriesInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 3) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      config: getNextAccount(),
+      ncn: getNextAccount(),
+      admin: getNextAccount(),
+    },
+    data: getNcnSetPausedCategoriesInstructionDataDecoder().decode(
+      instruction.data
+    ),
+  };
+}
@@ -0,0 +1,196 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU16Decoder,
+  getU16Encoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IAccountSignerMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlySignerAccount,
+  type TransactionSigner,
+  type WritableAccount,
+} from '@solana/web3.js';
+import { JITO_RESTAKING_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export const SET_CONFIG_PAUSED_CATEGORIES_DISCRIMINATOR = 27;
+
+export function getSetConfigPausedCategoriesDiscriminatorBytes() {
+  return getU8Encoder().encode(SET_CONFIG_PAUSED_CATEGORIES_DISCRIMINATOR);
+}
+
+export type SetConfigPausedCategoriesInstruction<
+  TProgram extends string = typeof JITO_RESTAKING_PROGRAM_ADDRESS,
+  TAccountConfig extends string | IAccountMeta<string> = string,
+  TAccountAdmin extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountConfig extends string
+        ? WritableAccount<TAccountConfig>
+        : TAccountConfig,
+      TAccountAdmin extends string
+        ? ReadonlySignerAccount<TAccountAdmin> &
+            IAccountSignerMeta<TAccountAdmin>
+        : TAccountAdmin,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type SetConfigPausedCategoriesInstructionData = {
+  discriminator: number;
+  pausedCategories: number;
+};
+
+export type SetConfigPausedCategoriesInstructionDataArgs = {
+  pausedCategories: number;
+};
+
+export function getSetConfigPausedCategoriesInstructionDataEncoder(): Encoder<SetConfigPausedCategoriesInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([
+      ['discriminator', getU8Encoder()],
+      ['pausedCategories', getU16Encoder()],
+    ]),
+    (value) => ({
+      ...value,
+      discriminator: SET_CONFIG_PAUSED_CATEGORIES_DISCRIMINATOR,
+    })
+  );
+}
+
+export function getSetConfigPausedCategoriesInstructionDataDecoder(): Decoder<SetConfigPausedCategoriesInstructionData> {
+  return getStructDecoder([
+    ['discriminator', getU8Decoder()],
+    ['pausedCategories', getU16Decoder()],
+  ]);
+}
+
+export function getSetConfigPausedCategoriesInstructionDataCodec(): Codec<
+  SetConfigPausedCategoriesInstructionDataArgs,
+  SetConfigPausedCategoriesInstructionData
+> {
+  return combineCodec(
+    getSetConfigPausedCategoriesInstructionDataEncoder(),
+    getSetConfigPausedCategoriesInstructionDataDecoder()
+  );
+}
+
+export type SetConfigPausedCategoriesInput<
+  TAccountConfig extends string = string,
+  TAccountAdmin extends string = string,
+> = {
+  config: Address<TAccountConfig>;
+  admin: TransactionSigner<TAccountAdmin>;
+  pausedCategories: SetConfigPausedCategoriesInstructionDataArgs['pausedCategories'];
+};
+
+export function getSetConfigPausedCategoriesInstruction<
+  TAccountConfig extends string,
+  TAccountAdmin extends string,
+  TProgramAddress extends Address = typeof JITO_RESTAKING_PROGRAM_ADDRESS,
+>(
+  input: SetConfigPausedCategoriesInput<TAccountConfig, TAccountAdmin>,
+  config?: { programAddress?: TProgramAddress }
+): SetConfigPausedCategoriesInstruction<
+  TProgramAddress,
+  TAccountConfig,
+  TAccountAdmin
+> {
+  // Program address.
+  const programAddress =
+    config?.programAddress ?? JITO_RESTAKING_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: true },
+    admin: { value: input.admin ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.admin),
+    ],
+    programAddress,
+    data: getSetConfigPausedCategoriesInstructionDataEncoder().encode(
+      args as SetConfigPausedCategoriesInstructionDataArgs
+    ),
+  } as SetConfigPausedCategoriesInstruction<
+    TProgramAddress,
+    TAccountConfig,
+    TAccountAdmin
+  >;
+
+  return instruction;
+}
+
+export type ParsedSetConfigPausedCategoriesInstruction<
+  TProgram extends string = typeof JITO_RESTAKING_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    config: TAccountMetas[0];
+    admin: TAccountMetas[1];
+  };
+  data: SetConfigPausedCategoriesInstructionData;
+};
+
+export function parseSetConfigPausedCategoriesInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedSetConfigPausedCategoriesInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 2) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      config: getNextAccount(),
+      admin: getNextAccount(),
+    },
+    data: getSetConfigPausedCategoriesInstructionDataDecoder().decode(
+      instruction.data
+    ),
+  };
+}
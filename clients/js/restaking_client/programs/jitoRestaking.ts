@@ -23,18 +23,24 @@ import {
   type ParsedInitializeNcnVaultTicketInstruction,
   type ParsedInitializeOperatorInstruction,
   type ParsedInitializeOperatorVaultTicketInstruction,
+  type ParsedJailOperatorInstruction,
+  type ParsedMigrateAccountInstruction,
   type ParsedNcnCooldownOperatorInstruction,
   type ParsedNcnDelegateTokenAccountInstruction,
   type ParsedNcnSetAdminInstruction,
+  type ParsedNcnSetPausedCategoriesInstruction,
   type ParsedNcnSetSecondaryAdminInstruction,
   type ParsedNcnWarmupOperatorInstruction,
   type ParsedOperatorCooldownNcnInstruction,
   type ParsedOperatorDelegateTokenAccountInstruction,
   type ParsedOperatorSetAdminInstruction,
   type ParsedOperatorSetFeeInstruction,
+  type ParsedOperatorSetPausedCategoriesInstruction,
   type ParsedOperatorSetSecondaryAdminInstruction,
   type ParsedOperatorWarmupNcnInstruction,
   type ParsedSetConfigAdminInstruction,
+  type ParsedSetConfigPausedCategoriesInstruction,
+  type ParsedUnjailOperatorInstruction,
   type ParsedWarmupNcnVaultSlasherTicketInstruction,
   type ParsedWarmupNcnVaultTicketInstruction,
   type ParsedWarmupOperatorVaultTicketInstruction,
@@ -79,6 +85,12 @@ export enum JitoRestakingInstruction {
   NcnDelegateTokenAccount,
   OperatorDelegateTokenAccount,
   SetConfigAdmin,
+  JailOperator,
+  UnjailOperator,
+  SetConfigPausedCategories,
+  NcnSetPausedCategories,
+  OperatorSetPausedCategories,
+  MigrateAccount,
 }
 
 export function identifyJitoRestakingInstruction(
@@ -160,6 +172,24 @@ export function identifyJitoRestakingInstruction(
   if (containsBytes(data, getU8Encoder().encode(24), 0)) {
     return JitoRestakingInstruction.SetConfigAdmin;
   }
+  if (containsBytes(data, getU8Encoder().encode(25), 0)) {
+    return JitoRestakingInstruction.JailOperator;
+  }
+  if (containsBytes(data, getU8Encoder().encode(26), 0)) {
+    return JitoRestakingInstruction.UnjailOperator;
+  }
+  if (containsBytes(data, getU8Encoder().encode(27), 0)) {
+    return JitoRestakingInstruction.SetConfigPausedCategories;
+  }
+  if (containsBytes(data, getU8Encoder().encode(28), 0)) {
+    return JitoRestakingInstruction.NcnSetPausedCategories;
+  }
+  if (containsBytes(data, getU8Encoder().encode(29), 0)) {
+    return JitoRestakingInstruction.OperatorSetPausedCategories;
+  }
+  if (containsBytes(data, getU8Encoder().encode(30), 0)) {
+    return JitoRestakingInstruction.MigrateAccount;
+  }
   throw new Error(
     'The provided instruction could not be identified as a jitoRestaking instruction.'
   );
@@ -242,4 +272,22 @@ export type ParsedJitoRestakingInstruction<
     } & ParsedOperatorDelegateTokenAccountInstruction<TProgram>)
   | ({
       instructionType: JitoRestakingInstruction.SetConfigAdmin;
-    } & ParsedSetConfigAdminInstruction<TProgram>);
+    } & ParsedSetConfigAdminInstruction<TProgram>)
+  | ({
+      instructionType: JitoRestakingInstruction.JailOperator;
+    } & ParsedJailOperatorInstruction<TProgram>)
+  | ({
+      instructionType: JitoRestakingInstruction.UnjailOperator;
+    } & ParsedUnjailOperatorInstruction<TProgram>)
+  | ({
+      instructionType: JitoRestakingInstruction.SetConfigPausedCategories;
+    } & ParsedSetConfigPausedCategoriesInstruction<TProgram>)
+  | ({
+      instructionType: JitoRestakingInstruction.NcnSetPausedCategories;
+    } & ParsedNcnSetPausedCategoriesInstruction<TProgram>)
+  | ({
+      instructionType: JitoRestakingInstruction.OperatorSetPausedCategories;
+    } & ParsedOperatorSetPausedCategoriesInstruction<TProgram>)
+  | ({
+      instructionType: JitoRestakingInstruction.MigrateAccount;
+    } & ParsedMigrateAccountInstruction<TProgram>);
@@ -83,6 +83,11 @@ export type Vault = {
   bump: number;
   isPaused: boolean;
   lastStartStateUpdateSlot: bigint;
+  childVault: Address;
+  nestedUnderlyingValue: bigint;
+  nestingDepth: number;
+  isEmergencyExit: boolean;
+  pausedCategories: number;
   reserved: Array<number>;
 };
 
@@ -124,6 +129,11 @@ export type VaultArgs = {
   bump: number;
   isPaused: boolean;
   lastStartStateUpdateSlot: number | bigint;
+  childVault: Address;
+  nestedUnderlyingValue: number | bigint;
+  nestingDepth: number;
+  isEmergencyExit: boolean;
+  pausedCategories: number;
   reserved: Array<number>;
 };
 
@@ -166,7 +176,12 @@ export function getVaultEncoder(): Encoder<VaultArgs> {
     ['bump', getU8Encoder()],
     ['isPaused', getBooleanEncoder()],
     ['lastStartStateUpdateSlot', getU64Encoder()],
-    ['reserved', getArrayEncoder(getU8Encoder(), { size: 251 })],
+    ['childVault', getAddressEncoder()],
+    ['nestedUnderlyingValue', getU64Encoder()],
+    ['nestingDepth', getU8Encoder()],
+    ['isEmergencyExit', getBooleanEncoder()],
+    ['pausedCategories', getU16Encoder()],
+    ['reserved', getArrayEncoder(getU8Encoder(), { size: 207 })],
   ]);
 }
 
@@ -209,7 +224,12 @@ export function getVaultDecoder(): Decoder<Vault> {
     ['bump', getU8Decoder()],
     ['isPaused', getBooleanDecoder()],
     ['lastStartStateUpdateSlot', getU64Decoder()],
-    ['reserved', getArrayDecoder(getU8Decoder(), { size: 251 })],
+    ['childVault', getAddressDecoder()],
+    ['nestedUnderlyingValue', getU64Decoder()],
+    ['nestingDepth', getU8Decoder()],
+    ['isEmergencyExit', getBooleanDecoder()],
+    ['pausedCategories', getU16Decoder()],
+    ['reserved', getArrayDecoder(getU8Decoder(), { size: 207 })],
   ]);
 }
 
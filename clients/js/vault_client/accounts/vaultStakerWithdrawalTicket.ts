@@ -19,6 +19,8 @@ import {
   getArrayEncoder,
   getStructDecoder,
   getStructEncoder,
+  getU16Decoder,
+  getU16Encoder,
   getU64Decoder,
   getU64Encoder,
   getU8Decoder,
@@ -43,6 +45,8 @@ export type VaultStakerWithdrawalTicket = {
   vrtAmount: bigint;
   slotUnstaked: bigint;
   bump: number;
+  autoClaimDelegate: Address;
+  autoClaimMaxTipBps: number;
   reserved: Array<number>;
 };
 
@@ -54,6 +58,8 @@ export type VaultStakerWithdrawalTicketArgs = {
   vrtAmount: number | bigint;
   slotUnstaked: number | bigint;
   bump: number;
+  autoClaimDelegate: Address;
+  autoClaimMaxTipBps: number;
   reserved: Array<number>;
 };
 
@@ -66,7 +72,9 @@ export function getVaultStakerWithdrawalTicketEncoder(): Encoder<VaultStakerWith
     ['vrtAmount', getU64Encoder()],
     ['slotUnstaked', getU64Encoder()],
     ['bump', getU8Encoder()],
-    ['reserved', getArrayEncoder(getU8Encoder(), { size: 263 })],
+    ['autoClaimDelegate', getAddressEncoder()],
+    ['autoClaimMaxTipBps', getU16Encoder()],
+    ['reserved', getArrayEncoder(getU8Encoder(), { size: 229 })],
   ]);
 }
 
@@ -79,7 +87,9 @@ export function getVaultStakerWithdrawalTicketDecoder(): Decoder<VaultStakerWith
     ['vrtAmount', getU64Decoder()],
     ['slotUnstaked', getU64Decoder()],
     ['bump', getU8Decoder()],
-    ['reserved', getArrayDecoder(getU8Decoder(), { size: 263 })],
+    ['autoClaimDelegate', getAddressDecoder()],
+    ['autoClaimMaxTipBps', getU16Decoder()],
+    ['reserved', getArrayDecoder(getU8Decoder(), { size: 229 })],
   ]);
 }
 
@@ -136,6 +136,26 @@ export const JITO_VAULT_ERROR__VAULT_REWARD_FEE_IS_ZERO = 0x421; // 1057
 export const JITO_VAULT_ERROR__VRT_OUT_CANNOT_BE_ZERO = 0x422; // 1058
 /** NonZeroAdditionalAssetsNeededForWithdrawalAtEndOfUpdate: NonZeroAdditionalAssetsNeededForWithdrawalAtEndOfUpdate */
 export const JITO_VAULT_ERROR__NON_ZERO_ADDITIONAL_ASSETS_NEEDED_FOR_WITHDRAWAL_AT_END_OF_UPDATE = 0x423; // 1059
+/** OperatorJailed: OperatorJailed */
+export const JITO_VAULT_ERROR__OPERATOR_JAILED = 0x424; // 1060
+/** VaultAlreadyNested: VaultAlreadyNested */
+export const JITO_VAULT_ERROR__VAULT_ALREADY_NESTED = 0x425; // 1061
+/** VaultNestingCycle: VaultNestingCycle */
+export const JITO_VAULT_ERROR__VAULT_NESTING_CYCLE = 0x426; // 1062
+/** VaultNestingTooDeep: VaultNestingTooDeep */
+export const JITO_VAULT_ERROR__VAULT_NESTING_TOO_DEEP = 0x427; // 1063
+/** VaultChildVaultInvalid: VaultChildVaultInvalid */
+export const JITO_VAULT_ERROR__VAULT_CHILD_VAULT_INVALID = 0x428; // 1064
+/** VaultInEmergencyExit: VaultInEmergencyExit */
+export const JITO_VAULT_ERROR__VAULT_IN_EMERGENCY_EXIT = 0x429; // 1065
+/** VaultCategoryPaused: VaultCategoryPaused */
+export const JITO_VAULT_ERROR__VAULT_CATEGORY_PAUSED = 0x42a; // 1066
+/** VaultStakerWithdrawalTicketInvalidAutoClaimDelegate: VaultStakerWithdrawalTicketInvalidAutoClaimDelegate */
+export const JITO_VAULT_ERROR__VAULT_STAKER_WITHDRAWAL_TICKET_INVALID_AUTO_CLAIM_DELEGATE = 0x42b; // 1067
+/** VaultStakerWithdrawalTicketAutoClaimTipTooHigh: VaultStakerWithdrawalTicketAutoClaimTipTooHigh */
+export const JITO_VAULT_ERROR__VAULT_STAKER_WITHDRAWAL_TICKET_AUTO_CLAIM_TIP_TOO_HIGH = 0x42c; // 1068
+/** VaultStakerWithdrawalTicketInvalidVault: VaultStakerWithdrawalTicketInvalidVault */
+export const JITO_VAULT_ERROR__VAULT_STAKER_WITHDRAWAL_TICKET_INVALID_VAULT = 0x42d; // 1069
 /** ArithmeticOverflow: ArithmeticOverflow */
 export const JITO_VAULT_ERROR__ARITHMETIC_OVERFLOW = 0xbb8; // 3000
 /** ArithmeticUnderflow: ArithmeticUnderflow */
@@ -159,14 +179,18 @@ export type JitoVaultError =
   | typeof JITO_VAULT_ERROR__NCN_VAULT_TICKET_UNSLASHABLE
   | typeof JITO_VAULT_ERROR__NON_ZERO_ADDITIONAL_ASSETS_NEEDED_FOR_WITHDRAWAL_AT_END_OF_UPDATE
   | typeof JITO_VAULT_ERROR__NO_SUPPORTED_MINT_BALANCE_CHANGE
+  | typeof JITO_VAULT_ERROR__OPERATOR_JAILED
   | typeof JITO_VAULT_ERROR__OPERATOR_OVERFLOW
   | typeof JITO_VAULT_ERROR__OPERATOR_VAULT_TICKET_UNSLASHABLE
   | typeof JITO_VAULT_ERROR__SLASHER_OVERFLOW
   | typeof JITO_VAULT_ERROR__SLIPPAGE_ERROR
   | typeof JITO_VAULT_ERROR__VAULT_ADMIN_INVALID
+  | typeof JITO_VAULT_ERROR__VAULT_ALREADY_NESTED
   | typeof JITO_VAULT_ERROR__VAULT_BURN_ZERO
   | typeof JITO_VAULT_ERROR__VAULT_CAPACITY_ADMIN_INVALID
   | typeof JITO_VAULT_ERROR__VAULT_CAPACITY_EXCEEDED
+  | typeof JITO_VAULT_ERROR__VAULT_CATEGORY_PAUSED
+  | typeof JITO_VAULT_ERROR__VAULT_CHILD_VAULT_INVALID
   | typeof JITO_VAULT_ERROR__VAULT_COOLDOWN_ZERO
   | typeof JITO_VAULT_ERROR__VAULT_DELEGATE_ASSET_ADMIN_INVALID
   | typeof JITO_VAULT_ERROR__VAULT_DELEGATION_ADMIN_INVALID
@@ -178,6 +202,7 @@ export type JitoVaultError =
   | typeof JITO_VAULT_ERROR__VAULT_FEE_CHANGE_TOO_SOON
   | typeof JITO_VAULT_ERROR__VAULT_INITIAL_AMOUNT_FAILED
   | typeof JITO_VAULT_ERROR__VAULT_INSUFFICIENT_FUNDS
+  | typeof JITO_VAULT_ERROR__VAULT_IN_EMERGENCY_EXIT
   | typeof JITO_VAULT_ERROR__VAULT_IS_PAUSED
   | typeof JITO_VAULT_ERROR__VAULT_IS_UPDATED
   | typeof JITO_VAULT_ERROR__VAULT_MAX_SLASHED_PER_OPERATOR_EXCEEDED
@@ -190,6 +215,8 @@ export type JitoVaultError =
   | typeof JITO_VAULT_ERROR__VAULT_NCN_TICKET_FAILED_COOLDOWN
   | typeof JITO_VAULT_ERROR__VAULT_NCN_TICKET_FAILED_WARMUP
   | typeof JITO_VAULT_ERROR__VAULT_NCN_TICKET_UNSLASHABLE
+  | typeof JITO_VAULT_ERROR__VAULT_NESTING_CYCLE
+  | typeof JITO_VAULT_ERROR__VAULT_NESTING_TOO_DEEP
   | typeof JITO_VAULT_ERROR__VAULT_OPERATOR_ADMIN_INVALID
   | typeof JITO_VAULT_ERROR__VAULT_OPERATOR_DELEGATION_IS_UPDATED
   | typeof JITO_VAULT_ERROR__VAULT_OPERATOR_DELEGATION_UPDATE_NEEDED
@@ -201,7 +228,10 @@ export type JitoVaultError =
   | typeof JITO_VAULT_ERROR__VAULT_SLASHER_ADMIN_INVALID
   | typeof JITO_VAULT_ERROR__VAULT_SLASH_INCOMPLETE
   | typeof JITO_VAULT_ERROR__VAULT_SLASH_UNDERFLOW
+  | typeof JITO_VAULT_ERROR__VAULT_STAKER_WITHDRAWAL_TICKET_AUTO_CLAIM_TIP_TOO_HIGH
+  | typeof JITO_VAULT_ERROR__VAULT_STAKER_WITHDRAWAL_TICKET_INVALID_AUTO_CLAIM_DELEGATE
   | typeof JITO_VAULT_ERROR__VAULT_STAKER_WITHDRAWAL_TICKET_INVALID_STAKER
+  | typeof JITO_VAULT_ERROR__VAULT_STAKER_WITHDRAWAL_TICKET_INVALID_VAULT
   | typeof JITO_VAULT_ERROR__VAULT_STAKER_WITHDRAWAL_TICKET_NOT_WITHDRAWABLE
   | typeof JITO_VAULT_ERROR__VAULT_UNDERFLOW
   | typeof JITO_VAULT_ERROR__VAULT_UPDATE_INCORRECT_INDEX
@@ -227,14 +257,18 @@ if (process.env.NODE_ENV !== 'production') {
     [JITO_VAULT_ERROR__NCN_VAULT_TICKET_UNSLASHABLE]: `NcnVaultTicketUnslashable`,
     [JITO_VAULT_ERROR__NON_ZERO_ADDITIONAL_ASSETS_NEEDED_FOR_WITHDRAWAL_AT_END_OF_UPDATE]: `NonZeroAdditionalAssetsNeededForWithdrawalAtEndOfUpdate`,
     [JITO_VAULT_ERROR__NO_SUPPORTED_MINT_BALANCE_CHANGE]: `NoSupportedMintBalanceChange`,
+    [JITO_VAULT_ERROR__OPERATOR_JAILED]: `OperatorJailed`,
     [JITO_VAULT_ERROR__OPERATOR_OVERFLOW]: `OperatorOverflow`,
     [JITO_VAULT_ERROR__OPERATOR_VAULT_TICKET_UNSLASHABLE]: `OperatorVaultTicketUnslashable`,
     [JITO_VAULT_ERROR__SLASHER_OVERFLOW]: `SlasherOverflow`,
     [JITO_VAULT_ERROR__SLIPPAGE_ERROR]: `SlippageError`,
     [JITO_VAULT_ERROR__VAULT_ADMIN_INVALID]: `VaultAdminInvalid`,
+    [JITO_VAULT_ERROR__VAULT_ALREADY_NESTED]: `VaultAlreadyNested`,
     [JITO_VAULT_ERROR__VAULT_BURN_ZERO]: `VaultBurnZero`,
     [JITO_VAULT_ERROR__VAULT_CAPACITY_ADMIN_INVALID]: `VaultCapacityAdminInvalid`,
     [JITO_VAULT_ERROR__VAULT_CAPACITY_EXCEEDED]: `VaultCapacityExceeded`,
+    [JITO_VAULT_ERROR__VAULT_CATEGORY_PAUSED]: `VaultCategoryPaused`,
+    [JITO_VAULT_ERROR__VAULT_CHILD_VAULT_INVALID]: `VaultChildVaultInvalid`,
     [JITO_VAULT_ERROR__VAULT_COOLDOWN_ZERO]: `VaultCooldownZero`,
     [JITO_VAULT_ERROR__VAULT_DELEGATE_ASSET_ADMIN_INVALID]: `VaultDelegateAssetAdminInvalid`,
     [JITO_VAULT_ERROR__VAULT_DELEGATION_ADMIN_INVALID]: `VaultDelegationAdminInvalid`,
@@ -246,6 +280,7 @@ if (process.env.NODE_ENV !== 'production') {
     [JITO_VAULT_ERROR__VAULT_FEE_CHANGE_TOO_SOON]: `VaultFeeChangeTooSoon`,
     [JITO_VAULT_ERROR__VAULT_INITIAL_AMOUNT_FAILED]: `VaultInitialAmountFailed`,
     [JITO_VAULT_ERROR__VAULT_INSUFFICIENT_FUNDS]: `VaultInsufficientFunds`,
+    [JITO_VAULT_ERROR__VAULT_IN_EMERGENCY_EXIT]: `VaultInEmergencyExit`,
     [JITO_VAULT_ERROR__VAULT_IS_PAUSED]: `VaultIsPaused`,
     [JITO_VAULT_ERROR__VAULT_IS_UPDATED]: `VaultIsUpdated`,
     [JITO_VAULT_ERROR__VAULT_MAX_SLASHED_PER_OPERATOR_EXCEEDED]: `VaultMaxSlashedPerOperatorExceeded`,
@@ -258,6 +293,8 @@ if (process.env.NODE_ENV !== 'production') {
     [JITO_VAULT_ERROR__VAULT_NCN_TICKET_FAILED_COOLDOWN]: `VaultNcnTicketFailedCooldown`,
     [JITO_VAULT_ERROR__VAULT_NCN_TICKET_FAILED_WARMUP]: `VaultNcnTicketFailedWarmup`,
     [JITO_VAULT_ERROR__VAULT_NCN_TICKET_UNSLASHABLE]: `VaultNcnTicketUnslashable`,
+    [JITO_VAULT_ERROR__VAULT_NESTING_CYCLE]: `VaultNestingCycle`,
+    [JITO_VAULT_ERROR__VAULT_NESTING_TOO_DEEP]: `VaultNestingTooDeep`,
     [JITO_VAULT_ERROR__VAULT_OPERATOR_ADMIN_INVALID]: `VaultOperatorAdminInvalid`,
     [JITO_VAULT_ERROR__VAULT_OPERATOR_DELEGATION_IS_UPDATED]: `VaultOperatorDelegationIsUpdated`,
     [JITO_VAULT_ERROR__VAULT_OPERATOR_DELEGATION_UPDATE_NEEDED]: `VaultOperatorDelegationUpdateNeeded`,
@@ -269,7 +306,10 @@ if (process.env.NODE_ENV !== 'production') {
     [JITO_VAULT_ERROR__VAULT_SLASHER_ADMIN_INVALID]: `VaultSlasherAdminInvalid`,
     [JITO_VAULT_ERROR__VAULT_SLASH_INCOMPLETE]: `VaultSlashIncomplete`,
     [JITO_VAULT_ERROR__VAULT_SLASH_UNDERFLOW]: `VaultSlashUnderflow`,
+    [JITO_VAULT_ERROR__VAULT_STAKER_WITHDRAWAL_TICKET_AUTO_CLAIM_TIP_TOO_HIGH]: `VaultStakerWithdrawalTicketAutoClaimTipTooHigh`,
+    [JITO_VAULT_ERROR__VAULT_STAKER_WITHDRAWAL_TICKET_INVALID_AUTO_CLAIM_DELEGATE]: `VaultStakerWithdrawalTicketInvalidAutoClaimDelegate`,
     [JITO_VAULT_ERROR__VAULT_STAKER_WITHDRAWAL_TICKET_INVALID_STAKER]: `VaultStakerWithdrawalTicketInvalidStaker`,
+    [JITO_VAULT_ERROR__VAULT_STAKER_WITHDRAWAL_TICKET_INVALID_VAULT]: `VaultStakerWithdrawalTicketInvalidVault`,
     [JITO_VAULT_ERROR__VAULT_STAKER_WITHDRAWAL_TICKET_NOT_WITHDRAWABLE]: `VaultStakerWithdrawalTicketNotWithdrawable`,
     [JITO_VAULT_ERROR__VAULT_UNDERFLOW]: `VaultUnderflow`,
     [JITO_VAULT_ERROR__VAULT_UPDATE_INCORRECT_INDEX]: `VaultUpdateIncorrectIndex`,
@@ -0,0 +1,201 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+  type WritableAccount,
+} from '@solana/web3.js';
+import { JITO_VAULT_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export const CRANK_VAULT_UPDATE_STATE_TRACKER_MANY_DISCRIMINATOR = 35;
+
+export function getCrankVaultUpdateStateTrackerManyDiscriminatorBytes() {
+  return getU8Encoder().encode(
+    CRANK_VAULT_UPDATE_STATE_TRACKER_MANY_DISCRIMINATOR
+  );
+}
+
+export type CrankVaultUpdateStateTrackerManyInstruction<
+  TProgram extends string = typeof JITO_VAULT_PROGRAM_ADDRESS,
+  TAccountConfig extends string | IAccountMeta<string> = string,
+  TAccountVault extends string | IAccountMeta<string> = string,
+  TAccountVaultUpdateStateTracker extends
+    | string
+    | IAccountMeta<string> = string,
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountConfig extends string
+        ? ReadonlyAccount<TAccountConfig>
+        : TAccountConfig,
+      TAccountVault extends string
+        ? WritableAccount<TAccountVault>
+        : TAccountVault,
+      TAccountVaultUpdateStateTracker extends string
+        ? WritableAccount<TAccountVaultUpdateStateTracker>
+        : TAccountVaultUpdateStateTracker,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type CrankVaultUpdateStateTrackerManyInstructionData = {
+  discriminator: number;
+};
+
+export type CrankVaultUpdateStateTrackerManyInstructionDataArgs = {};
+
+export function getCrankVaultUpdateStateTrackerManyInstructionDataEncoder(): Encoder<CrankVaultUpdateStateTrackerManyInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([['discriminator', getU8Encoder()]]),
+    (value) => ({
+      ...value,
+      discriminator: CRANK_VAULT_UPDATE_STATE_TRACKER_MANY_DISCRIMINATOR,
+    })
+  );
+}
+
+export function getCrankVaultUpdateStateTrackerManyInstructionDataDecoder(): Decoder<CrankVaultUpdateStateTrackerManyInstructionData> {
+  return getStructDecoder([['discriminator', getU8Decoder()]]);
+}
+
+export function getCrankVaultUpdateStateTrackerManyInstructionDataCodec(): Codec<
+  CrankVaultUpdateStateTrackerManyInstructionDataArgs,
+  CrankVaultUpdateStateTrackerManyInstructionData
+> {
+  return combineCodec(
+    getCrankVaultUpdateStateTrackerManyInstructionDataEncoder(),
+    getCrankVaultUpdateStateTrackerManyInstructionDataDecoder()
+  );
+}
+
+export type CrankVaultUpdateStateTrackerManyInput<
+  TAccountConfig extends string = string,
+  TAccountVault extends string = string,
+  TAccountVaultUpdateStateTracker extends string = string,
+> = {
+  config: Address<TAccountConfig>;
+  vault: Address<TAccountVault>;
+  vaultUpdateStateTracker: Address<TAccountVaultUpdateStateTracker>;
+};
+
+export function getCrankVaultUpdateStateTrackerManyInstruction<
+  TAccountConfig extends string,
+  TAccountVault extends string,
+  TAccountVaultUpdateStateTracker extends string,
+  TProgramAddress extends Address = typeof JITO_VAULT_PROGRAM_ADDRESS,
+>(
+  input: CrankVaultUpdateStateTrackerManyInput<
+    TAccountConfig,
+    TAccountVault,
+    TAccountVaultUpdateStateTracker
+  >,
+  config?: { programAddress?: TProgramAddress }
+): CrankVaultUpdateStateTrackerManyInstruction<
+  TProgramAddress,
+  TAccountConfig,
+  TAccountVault,
+  TAccountVaultUpdateStateTracker
+> {
+  // Program address.
+  const programAddress = config?.programAddress ?? JITO_VAULT_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: false },
+    vault: { value: input.vault ?? null, isWritable: true },
+    vaultUpdateStateTracker: {
+      value: input.vaultUpdateStateTracker ?? null,
+      isWritable: true,
+    },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.vault),
+      getAccountMeta(accounts.vaultUpdateStateTracker),
+    ],
+    programAddress,
+    data: getCrankVaultUpdateStateTrackerManyInstructionDataEncoder().encode(
+      {}
+    ),
+  } as CrankVaultUpdateStateTrackerManyInstruction<
+    TProgramAddress,
+    TAccountConfig,
+    TAccountVault,
+    TAccountVaultUpdateStateTracker
+  >;
+
+  return instruction;
+}
+
+export type ParsedCrankVaultUpdateStateTrackerManyInstruction<
+  TProgram extends string = typeof JITO_VAULT_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    config: TAccountMetas[0];
+    vault: TAccountMetas[1];
+    vaultUpdateStateTracker: TAccountMetas[2];
+  };
+  data: CrankVaultUpdateStateTrackerManyInstructionData;
+};
+
+export function parseCrankVaultUpdateStateTrackerManyInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedCrankVaultUpdateStateTrackerManyInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 3) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      config: getNextAccount(),
+      vault: getNextAccount(),
+      vaultUpdateStateTracker: getNextAccount(),
+    },
+    data: getCrankVaultUpdateStateTrackerManyInstructionDataDecoder().decode(
+      instruction.data
+    ),
+  };
+}
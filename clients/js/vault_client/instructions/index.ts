@@ -7,6 +7,7 @@
  */
 
 export * from './addDelegation';
+export * from './autoClaimWithdrawalTicket';
 export * from './burnWithdrawalTicket';
 export * from './changeWithdrawalTicketOwner';
 export * from './closeVaultUpdateStateTracker';
@@ -14,6 +15,7 @@ export * from './cooldownDelegation';
 export * from './cooldownVaultNcnSlasherTicket';
 export * from './cooldownVaultNcnTicket';
 export * from './crankVaultUpdateStateTracker';
+export * from './crankVaultUpdateStateTrackerMany';
 export * from './createTokenMetadata';
 export * from './delegateTokenAccount';
 export * from './enqueueWithdrawal';
@@ -25,12 +27,17 @@ export * from './initializeVaultNcnTicket';
 export * from './initializeVaultOperatorDelegation';
 export * from './initializeVaultUpdateStateTracker';
 export * from './initializeVaultWithMint';
+export * from './migrateAccount';
 export * from './mintTo';
 export * from './setAdmin';
+export * from './setAutoClaimDelegate';
+export * from './setChildVault';
 export * from './setConfigAdmin';
 export * from './setDepositCapacity';
 export * from './setFees';
+export * from './setIsEmergencyExit';
 export * from './setIsPaused';
+export * from './setPausedCategories';
 export * from './setProgramFee';
 export * from './setProgramFeeWallet';
 export * from './setSecondaryAdmin';
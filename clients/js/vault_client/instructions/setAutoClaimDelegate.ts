@@ -0,0 +1,247 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU16Decoder,
+  getU16Encoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IAccountSignerMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+  type ReadonlySignerAccount,
+  type TransactionSigner,
+  type WritableAccount,
+} from '@solana/web3.js';
+import { JITO_VAULT_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export const SET_AUTO_CLAIM_DELEGATE_DISCRIMINATOR = 36;
+
+export function getSetAutoClaimDelegateDiscriminatorBytes() {
+  return getU8Encoder().encode(SET_AUTO_CLAIM_DELEGATE_DISCRIMINATOR);
+}
+
+export type SetAutoClaimDelegateInstruction<
+  TProgram extends string = typeof JITO_VAULT_PROGRAM_ADDRESS,
+  TAccountConfig extends string | IAccountMeta<string> = string,
+  TAccountVault extends string | IAccountMeta<string> = string,
+  TAccountVaultStakerWithdrawalTicket extends
+    | string
+    | IAccountMeta<string> = string,
+  TAccountStaker extends string | IAccountMeta<string> = string,
+  TAccountAutoClaimDelegate extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountConfig extends string
+        ? ReadonlyAccount<TAccountConfig>
+        : TAccountConfig,
+      TAccountVault extends string
+        ? ReadonlyAccount<TAccountVault>
+        : TAccountVault,
+      TAccountVaultStakerWithdrawalTicket extends string
+        ? WritableAccount<TAccountVaultStakerWithdrawalTicket>
+        : TAccountVaultStakerWithdrawalTicket,
+      TAccountStaker extends string
+        ? ReadonlySignerAccount<TAccountStaker> &
+            IAccountSignerMeta<TAccountStaker>
+        : TAccountStaker,
+      TAccountAutoClaimDelegate extends string
+        ? ReadonlyAccount<TAccountAutoClaimDelegate>
+        : TAccountAutoClaimDelegate,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type SetAutoClaimDelegateInstructionData = {
+  discriminator: number;
+  maxTipBps: number;
+};
+
+export type SetAutoClaimDelegateInstructionDataArgs = { maxTipBps: number };
+
+export function getSetAutoClaimDelegateInstructionDataEncoder(): Encoder<SetAutoClaimDelegateInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([
+      ['discriminator', getU8Encoder()],
+      ['maxTipBps', getU16Encoder()],
+    ]),
+    (value) => ({
+      ...value,
+      discriminator: SET_AUTO_CLAIM_DELEGATE_DISCRIMINATOR,
+    })
+  );
+}
+
+export function getSetAutoClaimDelegateInstructionDataDecoder(): Decoder<SetAutoClaimDelegateInstructionData> {
+  return getStructDecoder([
+    ['discriminator', getU8Decoder()],
+    ['maxTipBps', getU16Decoder()],
+  ]);
+}
+
+export function getSetAutoClaimDelegateInstructionDataCodec(): Codec<
+  SetAutoClaimDelegateInstructionDataArgs,
+  SetAutoClaimDelegateInstructionData
+> {
+  return combineCodec(
+    getSetAutoClaimDelegateInstructionDataEncoder(),
+    getSetAutoClaimDelegateInstructionDataDecoder()
+  );
+}
+
+export type SetAutoClaimDelegateInput<
+  TAccountConfig extends string = string,
+  TAccountVault extends string = string,
+  TAccountVaultStakerWithdrawalTicket extends string = string,
+  TAccountStaker extends string = string,
+  TAccountAutoClaimDelegate extends string = string,
+> = {
+  config: Address<TAccountConfig>;
+  vault: Address<TAccountVault>;
+  vaultStakerWithdrawalTicket: Address<TAccountVaultStakerWithdrawalTicket>;
+  staker: TransactionSigner<TAccountStaker>;
+  autoClaimDelegate: Address<TAccountAutoClaimDelegate>;
+  maxTipBps: SetAutoClaimDelegateInstructionDataArgs['maxTipBps'];
+};
+
+export function getSetAutoClaimDelegateInstruction<
+  TAccountConfig extends string,
+  TAccountVault extends string,
+  TAccountVaultStakerWithdrawalTicket extends string,
+  TAccountStaker extends string,
+  TAccountAutoClaimDelegate extends string,
+  TProgramAddress extends Address = typeof JITO_VAULT_PROGRAM_ADDRESS,
+>(
+  input: SetAutoClaimDelegateInput<
+    TAccountConfig,
+    TAccountVault,
+    TAccountVaultStakerWithdrawalTicket,
+    TAccountStaker,
+    TAccountAutoClaimDelegate
+  >,
+  config?: { programAddress?: TProgramAddress }
+): SetAutoClaimDelegateInstruction<
+  TProgramAddress,
+  TAccountConfig,
+  TAccountVault,
+  TAccountVaultStakerWithdrawalTicket,
+  TAccountStaker,
+  TAccountAutoClaimDelegate
+> {
+  // Program address.
+  const programAddress = config?.programAddress ?? JITO_VAULT_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: false },
+    vault: { value: input.vault ?? null, isWritable: false },
+    vaultStakerWithdrawalTicket: {
+      value: input.vaultStakerWithdrawalTicket ?? null,
+      isWritable: true,
+    },
+    staker: { value: input.staker ?? null, isWritable: false },
+    autoClaimDelegate: {
+      value: input.autoClaimDelegate ?? null,
+      isWritable: false,
+    },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.vault),
+      getAccountMeta(accounts.vaultStakerWithdrawalTicket),
+      getAccountMeta(accounts.staker),
+      getAccountMeta(accounts.autoClaimDelegate),
+    ],
+    programAddress,
+    data: getSetAutoClaimDelegateInstructionDataEncoder().encode(
+      args as SetAutoClaimDelegateInstructionDataArgs
+    ),
+  } as SetAutoClaimDelegateInstruction<
+    TProgramAddress,
+    TAccountConfig,
+    TAccountVault,
+    TAccountVaultStakerWithdrawalTicket,
+    TAccountStaker,
+    TAccountAutoClaimDelegate
+  >;
+
+  return instruction;
+}
+
+export type ParsedSetAutoClaimDelegateInstruction<
+  TProgram extends string = typeof JITO_VAULT_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    config: TAccountMetas[0];
+    vault: TAccountMetas[1];
+    vaultStakerWithdrawalTicket: TAccountMetas[2];
+    staker: TAccountMetas[3];
+    autoClaimDelegate: TAccountMetas[4];
+  };
+  data: SetAutoClaimDelegateInstructionData;
+};
+
+export function parseSetAutoClaimDelegateInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedSetAutoClaimDelegateInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 5) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      config: getNextAccount(),
+      vault: getNextAccount(),
+      vaultStakerWithdrawalTicket: getNextAccount(),
+      staker: getNextAccount(),
+      autoClaimDelegate: getNextAccount(),
+    },
+    data: getSetAutoClaimDelegateInstructionDataDecoder().decode(
+      instruction.data
+    ),
+  };
+}
@@ -0,0 +1,203 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IAccountSignerMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+  type ReadonlySignerAccount,
+  type TransactionSigner,
+  type WritableAccount,
+} from '@solana/web3.js';
+import { JITO_VAULT_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export const SET_CHILD_VAULT_DISCRIMINATOR = 32;
+
+export function getSetChildVaultDiscriminatorBytes() {
+  return getU8Encoder().encode(SET_CHILD_VAULT_DISCRIMINATOR);
+}
+
+export type SetChildVaultInstruction<
+  TProgram extends string = typeof JITO_VAULT_PROGRAM_ADDRESS,
+  TAccountConfig extends string | IAccountMeta<string> = string,
+  TAccountVault extends string | IAccountMeta<string> = string,
+  TAccountChildVault extends string | IAccountMeta<string> = string,
+  TAccountAdmin extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountConfig extends string
+        ? ReadonlyAccount<TAccountConfig>
+        : TAccountConfig,
+      TAccountVault extends string
+        ? WritableAccount<TAccountVault>
+        : TAccountVault,
+      TAccountChildVault extends string
+        ? ReadonlyAccount<TAccountChildVault>
+        : TAccountChildVault,
+      TAccountAdmin extends string
+        ? ReadonlySignerAccount<TAccountAdmin> &
+            IAccountSignerMeta<TAccountAdmin>
+        : TAccountAdmin,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type SetChildVaultInstructionData = { discriminator: number };
+
+export type SetChildVaultInstructionDataArgs = {};
+
+export function getSetChildVaultInstructionDataEncoder(): Encoder<SetChildVaultInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([['discriminator', getU8Encoder()]]),
+    (value) => ({ ...value, discriminator: SET_CHILD_VAULT_DISCRIMINATOR })
+  );
+}
+
+export function getSetChildVaultInstructionDataDecoder(): Decoder<SetChildVaultInstructionData> {
+  return getStructDecoder([['discriminator', getU8Decoder()]]);
+}
+
+export function getSetChildVaultInstructionDataCodec(): Codec<
+  SetChildVaultInstructionDataArgs,
+  SetChildVaultInstructionData
+> {
+  return combineCodec(
+    getSetChildVaultInstructionDataEncoder(),
+    getSetChildVaultInstructionDataDecoder()
+  );
+}
+
+export type SetChildVaultInput<
+  TAccountConfig extends string = string,
+  TAccountVault extends string = string,
+  TAccountChildVault extends string = string,
+  TAccountAdmin extends string = string,
+> = {
+  config: Address<TAccountConfig>;
+  vault: Address<TAccountVault>;
+  childVault: Address<TAccountChildVault>;
+  admin: TransactionSigner<TAccountAdmin>;
+};
+
+export function getSetChildVaultInstruction<
+  TAccountConfig extends string,
+  TAccountVault extends string,
+  TAccountChildVault extends string,
+  TAccountAdmin extends string,
+  TProgramAddress extends Address = typeof JITO_VAULT_PROGRAM_ADDRESS,
+>(
+  input: SetChildVaultInput<
+    TAccountConfig,
+    TAccountVault,
+    TAccountChildVault,
+    TAccountAdmin
+  >,
+  config?: { programAddress?: TProgramAddress }
+): SetChildVaultInstruction<
+  TProgramAddress,
+  TAccountConfig,
+  TAccountVault,
+  TAccountChildVault,
+  TAccountAdmin
+> {
+  // Program address.
+  const programAddress = config?.programAddress ?? JITO_VAULT_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: false },
+    vault: { value: input.vault ?? null, isWritable: true },
+    childVault: { value: input.childVault ?? null, isWritable: false },
+    admin: { value: input.admin ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.vault),
+      getAccountMeta(accounts.childVault),
+      getAccountMeta(accounts.admin),
+    ],
+    programAddress,
+    data: getSetChildVaultInstructionDataEncoder().encode({}),
+  } as SetChildVaultInstruction<
+    TProgramAddress,
+    TAccountConfig,
+    TAccountVault,
+    TAccountChildVault,
+    TAccountAdmin
+  >;
+
+  return instruction;
+}
+
+export type ParsedSetChildVaultInstruction<
+  TProgram extends string = typeof JITO_VAULT_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    config: TAccountMetas[0];
+    vault: TAccountMetas[1];
+    childVault: TAccountMetas[2];
+    admin: TAccountMetas[3];
+  };
+  data: SetChildVaultInstructionData;
+};
+
+export function parseSetChildVaultInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedSetChildVaultInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 4) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      config: getNextAccount(),
+      vault: getNextAccount(),
+      childVault: getNextAccount(),
+      admin: getNextAccount(),
+    },
+    data: getSetChildVaultInstructionDataDecoder().decode(instruction.data),
+  };
+}
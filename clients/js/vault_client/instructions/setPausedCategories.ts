@@ -0,0 +1,209 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU16Decoder,
+  getU16Encoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IAccountSignerMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+  type ReadonlySignerAccount,
+  type TransactionSigner,
+  type WritableAccount,
+} from '@solana/web3.js';
+import { JITO_VAULT_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export const SET_PAUSED_CATEGORIES_DISCRIMINATOR = 34;
+
+export function getSetPausedCategoriesDiscriminatorBytes() {
+  return getU8Encoder().encode(SET_PAUSED_CATEGORIES_DISCRIMINATOR);
+}
+
+export type SetPausedCategoriesInstruction<
+  TProgram extends string = typeof JITO_VAULT_PROGRAM_ADDRESS,
+  TAccountConfig extends string | IAccountMeta<string> = string,
+  TAccountVault extends string | IAccountMeta<string> = string,
+  TAccountAdmin extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountConfig extends string
+        ? ReadonlyAccount<TAccountConfig>
+        : TAccountConfig,
+      TAccountVault extends string
+        ? WritableAccount<TAccountVault>
+        : TAccountVault,
+      TAccountAdmin extends string
+        ? ReadonlySignerAccount<TAccountAdmin> &
+            IAccountSignerMeta<TAccountAdmin>
+        : TAccountAdmin,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type SetPausedCategoriesInstructionData = {
+  discriminator: number;
+  pausedCategories: number;
+};
+
+export type SetPausedCategoriesInstructionDataArgs = {
+  pausedCategories: number;
+};
+
+export function getSetPausedCategoriesInstructionDataEncoder(): Encoder<SetPausedCategoriesInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([
+      ['discriminator', getU8Encoder()],
+      ['pausedCategories', getU16Encoder()],
+    ]),
+    (value) => ({
+      ...value,
+      discriminator: SET_PAUSED_CATEGORIES_DISCRIMINATOR,
+    })
+  );
+}
+
+export function getSetPausedCategoriesInstructionDataDecoder(): Decoder<SetPausedCategoriesInstructionData> {
+  return getStructDecoder([
+    ['discriminator', getU8Decoder()],
+    ['pausedCategories', getU16Decoder()],
+  ]);
+}
+
+export function getSetPausedCategoriesInstructionDataCodec(): Codec<
+  SetPausedCategoriesInstructionDataArgs,
+  SetPausedCategoriesInstructionData
+> {
+  return combineCodec(
+    getSetPausedCategoriesInstructionDataEncoder(),
+    getSetPausedCategoriesInstructionDataDecoder()
+  );
+}
+
+export type SetPausedCategoriesInput<
+  TAccountConfig extends string = string,
+  TAccountVault extends string = string,
+  TAccountAdmin extends string = string,
+> = {
+  config: Address<TAccountConfig>;
+  vault: Address<TAccountVault>;
+  admin: TransactionSigner<TAccountAdmin>;
+  pausedCategories: SetPausedCategoriesInstructionDataArgs['pausedCategories'];
+};
+
+export function getSetPausedCategoriesInstruction<
+  TAccountConfig extends string,
+  TAccountVault extends string,
+  TAccountAdmin extends string,
+  TProgramAddress extends Address = typeof JITO_VAULT_PROGRAM_ADDRESS,
+>(
+  input: SetPausedCategoriesInput<TAccountConfig, TAccountVault, TAccountAdmin>,
+  config?: { programAddress?: TProgramAddress }
+): SetPausedCategoriesInstruction<
+  TProgramAddress,
+  TAccountConfig,
+  TAccountVault,
+  TAccountAdmin
+> {
+  // Program address.
+  const programAddress = config?.programAddress ?? JITO_VAULT_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: false },
+    vault: { value: input.vault ?? null, isWritable: true },
+    admin: { value: input.admin ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.vault),
+      getAccountMeta(accounts.admin),
+    ],
+    programAddress,
+    data: getSetPausedCategoriesInstructionDataEncoder().encode(
+      args as SetPausedCategoriesInstructionDataArgs
+    ),
+  } as SetPausedCategoriesInstruction<
+    TProgramAddress,
+    TAccountConfig,
+    TAccountVault,
+    TAccountAdmin
+  >;
+
+  return instruction;
+}
+
+export type ParsedSetPausedCategoriesInstruction<
+  TProgram extends string = typeof JITO_VAULT_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    config: TAccountMetas[0];
+    vault: TAccountMetas[1];
+    admin: TAccountMetas[2];
+  };
+  data: SetPausedCategoriesInstructionData;
+};
+
+export function parseSetPausedCategoriesInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedSetPausedCategoriesInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 3) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      config: getNextAccount(),
+      vault: getNextAccount(),
+      admin: getNextAccount(),
+    },
+    data: getSetPausedCategoriesInstructionDataDecoder().decode(
+      instruction.data
+    ),
+  };
+}
@@ -43,6 +43,7 @@ export type UpdateVaultBalanceInstruction<
   TAccountTokenProgram extends
     | string
     | IAccountMeta<string> = 'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA',
+  TAccountChildVault extends string | IAccountMeta<string> = string,
   TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
 > = IInstruction<TProgram> &
   IInstructionWithData<Uint8Array> &
@@ -66,6 +67,9 @@ export type UpdateVaultBalanceInstruction<
       TAccountTokenProgram extends string
         ? ReadonlyAccount<TAccountTokenProgram>
         : TAccountTokenProgram,
+      TAccountChildVault extends string
+        ? ReadonlyAccount<TAccountChildVault>
+        : TAccountChildVault,
       ...TRemainingAccounts,
     ]
   >;
@@ -102,6 +106,7 @@ export type UpdateVaultBalanceInput<
   TAccountVrtMint extends string = string,
   TAccountVaultFeeTokenAccount extends string = string,
   TAccountTokenProgram extends string = string,
+  TAccountChildVault extends string = string,
 > = {
   config: Address<TAccountConfig>;
   vault: Address<TAccountVault>;
@@ -109,6 +114,8 @@ export type UpdateVaultBalanceInput<
   vrtMint: Address<TAccountVrtMint>;
   vaultFeeTokenAccount: Address<TAccountVaultFeeTokenAccount>;
   tokenProgram?: Address<TAccountTokenProgram>;
+  /** Required for nested vaults */
+  childVault?: Address<TAccountChildVault>;
 };
 
 export function getUpdateVaultBalanceInstruction<
@@ -118,6 +125,7 @@ export function getUpdateVaultBalanceInstruction<
   TAccountVrtMint extends string,
   TAccountVaultFeeTokenAccount extends string,
   TAccountTokenProgram extends string,
+  TAccountChildVault extends string,
   TProgramAddress extends Address = typeof JITO_VAULT_PROGRAM_ADDRESS,
 >(
   input: UpdateVaultBalanceInput<
@@ -126,7 +134,8 @@ export function getUpdateVaultBalanceInstruction<
     TAccountVaultTokenAccount,
     TAccountVrtMint,
     TAccountVaultFeeTokenAccount,
-    TAccountTokenProgram
+    TAccountTokenProgram,
+    TAccountChildVault
   >,
   config?: { programAddress?: TProgramAddress }
 ): UpdateVaultBalanceInstruction<
@@ -136,7 +145,8 @@ export function getUpdateVaultBalanceInstruction<
   TAccountVaultTokenAccount,
   TAccountVrtMint,
   TAccountVaultFeeTokenAccount,
-  TAccountTokenProgram
+  TAccountTokenProgram,
+  TAccountChildVault
 > {
   // Program address.
   const programAddress = config?.programAddress ?? JITO_VAULT_PROGRAM_ADDRESS;
@@ -155,6 +165,7 @@ export function getUpdateVaultBalanceInstruction<
       isWritable: true,
     },
     tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
+    childVault: { value: input.childVault ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -176,6 +187,7 @@ export function getUpdateVaultBalanceInstruction<
       getAccountMeta(accounts.vrtMint),
       getAccountMeta(accounts.vaultFeeTokenAccount),
       getAccountMeta(accounts.tokenProgram),
+      getAccountMeta(accounts.childVault),
     ],
     programAddress,
     data: getUpdateVaultBalanceInstructionDataEncoder().encode({}),
@@ -186,7 +198,8 @@ export function getUpdateVaultBalanceInstruction<
     TAccountVaultTokenAccount,
     TAccountVrtMint,
     TAccountVaultFeeTokenAccount,
-    TAccountTokenProgram
+    TAccountTokenProgram,
+    TAccountChildVault
   >;
 
   return instruction;
@@ -204,6 +217,8 @@ export type ParsedUpdateVaultBalanceInstruction<
     vrtMint: TAccountMetas[3];
     vaultFeeTokenAccount: TAccountMetas[4];
     tokenProgram: TAccountMetas[5];
+    /** Required for nested vaults */
+    childVault?: TAccountMetas[6] | undefined;
   };
   data: UpdateVaultBalanceInstructionData;
 };
@@ -216,7 +231,7 @@ export function parseUpdateVaultBalanceInstruction<
     IInstructionWithAccounts<TAccountMetas> &
     IInstructionWithData<Uint8Array>
 ): ParsedUpdateVaultBalanceInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 6) {
+  if (instruction.accounts.length < 7) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -226,6 +241,12 @@ export function parseUpdateVaultBalanceInstruction<
     accountIndex += 1;
     return accountMeta;
   };
+  const getNextOptionalAccount = () => {
+    const accountMeta = getNextAccount();
+    return accountMeta.address === JITO_VAULT_PROGRAM_ADDRESS
+      ? undefined
+      : accountMeta;
+  };
   return {
     programAddress: instruction.programAddress,
     accounts: {
@@ -235,6 +256,7 @@ export function parseUpdateVaultBalanceInstruction<
       vrtMint: getNextAccount(),
       vaultFeeTokenAccount: getNextAccount(),
       tokenProgram: getNextAccount(),
+      childVault: getNextOptionalAccount(),
     },
     data: getUpdateVaultBalanceInstructionDataDecoder().decode(
       instruction.data
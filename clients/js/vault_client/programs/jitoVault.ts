@@ -14,6 +14,7 @@ import {
 } from '@solana/web3.js';
 import {
   type ParsedAddDelegationInstruction,
+  type ParsedAutoClaimWithdrawalTicketInstruction,
   type ParsedBurnWithdrawalTicketInstruction,
   type ParsedChangeWithdrawalTicketOwnerInstruction,
   type ParsedCloseVaultUpdateStateTrackerInstruction,
@@ -21,6 +22,7 @@ import {
   type ParsedCooldownVaultNcnSlasherTicketInstruction,
   type ParsedCooldownVaultNcnTicketInstruction,
   type ParsedCrankVaultUpdateStateTrackerInstruction,
+  type ParsedCrankVaultUpdateStateTrackerManyInstruction,
   type ParsedCreateTokenMetadataInstruction,
   type ParsedDelegateTokenAccountInstruction,
   type ParsedEnqueueWithdrawalInstruction,
@@ -32,12 +34,17 @@ import {
   type ParsedInitializeVaultOperatorDelegationInstruction,
   type ParsedInitializeVaultUpdateStateTrackerInstruction,
   type ParsedInitializeVaultWithMintInstruction,
+  type ParsedMigrateAccountInstruction,
   type ParsedMintToInstruction,
   type ParsedSetAdminInstruction,
+  type ParsedSetAutoClaimDelegateInstruction,
+  type ParsedSetChildVaultInstruction,
   type ParsedSetConfigAdminInstruction,
   type ParsedSetDepositCapacityInstruction,
   type ParsedSetFeesInstruction,
+  type ParsedSetIsEmergencyExitInstruction,
   type ParsedSetIsPausedInstruction,
+  type ParsedSetPausedCategoriesInstruction,
   type ParsedSetProgramFeeInstruction,
   type ParsedSetProgramFeeWalletInstruction,
   type ParsedSetSecondaryAdminInstruction,
@@ -94,6 +101,13 @@ export enum JitoVaultInstruction {
   CreateTokenMetadata,
   UpdateTokenMetadata,
   SetConfigAdmin,
+  SetChildVault,
+  SetIsEmergencyExit,
+  SetPausedCategories,
+  CrankVaultUpdateStateTrackerMany,
+  SetAutoClaimDelegate,
+  AutoClaimWithdrawalTicket,
+  MigrateAccount,
 }
 
 export function identifyJitoVaultInstruction(
@@ -196,6 +210,27 @@ export function identifyJitoVaultInstruction(
   if (containsBytes(data, getU8Encoder().encode(31), 0)) {
     return JitoVaultInstruction.SetConfigAdmin;
   }
+  if (containsBytes(data, getU8Encoder().encode(32), 0)) {
+    return JitoVaultInstruction.SetChildVault;
+  }
+  if (containsBytes(data, getU8Encoder().encode(33), 0)) {
+    return JitoVaultInstruction.SetIsEmergencyExit;
+  }
+  if (containsBytes(data, getU8Encoder().encode(34), 0)) {
+    return JitoVaultInstruction.SetPausedCategories;
+  }
+  if (containsBytes(data, getU8Encoder().encode(35), 0)) {
+    return JitoVaultInstruction.CrankVaultUpdateStateTrackerMany;
+  }
+  if (containsBytes(data, getU8Encoder().encode(36), 0)) {
+    return JitoVaultInstruction.SetAutoClaimDelegate;
+  }
+  if (containsBytes(data, getU8Encoder().encode(37), 0)) {
+    return JitoVaultInstruction.AutoClaimWithdrawalTicket;
+  }
+  if (containsBytes(data, getU8Encoder().encode(38), 0)) {
+    return JitoVaultInstruction.MigrateAccount;
+  }
   throw new Error(
     'The provided instruction could not be identified as a jitoVault instruction.'
   );
@@ -299,4 +334,25 @@ export type ParsedJitoVaultInstruction<
     } & ParsedUpdateTokenMetadataInstruction<TProgram>)
   | ({
       instructionType: JitoVaultInstruction.SetConfigAdmin;
-    } & ParsedSetConfigAdminInstruction<TProgram>);
+    } & ParsedSetConfigAdminInstruction<TProgram>)
+  | ({
+      instructionType: JitoVaultInstruction.SetChildVault;
+    } & ParsedSetChildVaultInstruction<TProgram>)
+  | ({
+      instructionType: JitoVaultInstruction.SetIsEmergencyExit;
+    } & ParsedSetIsEmergencyExitInstruction<TProgram>)
+  | ({
+      instructionType: JitoVaultInstruction.SetPausedCategories;
+    } & ParsedSetPausedCategoriesInstruction<TProgram>)
+  | ({
+      instructionType: JitoVaultInstruction.CrankVaultUpdateStateTrackerMany;
+    } & ParsedCrankVaultUpdateStateTrackerManyInstruction<TProgram>)
+  | ({
+      instructionType: JitoVaultInstruction.SetAutoClaimDelegate;
+    } & ParsedSetAutoClaimDelegateInstruction<TProgram>)
+  | ({
+      instructionType: JitoVaultInstruction.AutoClaimWithdrawalTicket;
+    } & ParsedAutoClaimWithdrawalTicketInstruction<TProgram>)
+  | ({
+      instructionType: JitoVaultInstruction.MigrateAccount;
+    } & ParsedMigrateAccountInstruction<TProgram>);
@@ -0,0 +1,316 @@
+//! Decodes failed transactions into readable diagnostics.
+//!
+//! A failed instruction only returns an error code. The programs also log which account check
+//! failed (see [`jito_jsm_core::diagnostic`]); [`diagnose`] combines both with the instructions
+//! that were sent to name the program error, the check and the index of the offending account.
+
+use std::fmt;
+
+use jito_jsm_core::diagnostic::{Check, FailedCheck};
+use jito_restaking_client::{errors::JitoRestakingError, programs::JITO_RESTAKING_ID};
+use jito_vault_client::{errors::JitoVaultError, programs::JITO_VAULT_ID};
+use num_traits::FromPrimitive;
+use solana_program::{
+    instruction::{Instruction, InstructionError},
+    pubkey::Pubkey,
+};
+use solana_rpc_client_api::{
+    client_error::{Error as ClientError, ErrorKind},
+    request::{RpcError, RpcResponseErrorData},
+};
+use solana_sdk::transaction::TransactionError;
+
+use crate::JsmClientError;
+
+/// Why an instruction of a transaction failed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostic {
+    /// Index of the failed instruction in the transaction
+    pub instruction_index: u8,
+    /// The program the failed instruction called
+    pub program_id: Option<Pubkey>,
+    pub error: InstructionError,
+    /// The name of the program error for custom error codes, e.g. `VaultAdminInvalid`
+    pub error_name: Option<String>,
+    /// The account checks the program logged as failed, in order
+    pub failed_checks: Vec<AccountCheck>,
+}
+
+/// An account check that failed, located in the failed instruction
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AccountCheck {
+    pub check: Check,
+    /// The account that failed the check, `None` if it wasn't passed
+    pub account: Option<Pubkey>,
+    /// The index of the account in the failed instruction's accounts
+    pub account_index: Option<usize>,
+}
+
+/// Decodes a transaction error and its logs, given the instructions of the transaction.
+/// Returns `None` if the transaction didn't fail in an instruction, e.g. because a signature or
+/// the blockhash was invalid.
+pub fn diagnose(
+    instructions: &[Instruction],
+    error: &TransactionError,
+    logs: &[String],
+) -> Option<Diagnostic> {
+    let TransactionError::InstructionError(instruction_index, error) = error else {
+        return None;
+    };
+    let instruction = instructions.get(*instruction_index as usize);
+    let program_id = instruction.map(|instruction| instruction.program_id);
+
+    let error_name = match (error, program_id) {
+        (InstructionError::Custom(code), Some(program_id)) if program_id == JITO_VAULT_ID => {
+            JitoVaultError::from_u32(*code).map(|error| error.to_string())
+        }
+        (InstructionError::Custom(code), Some(program_id)) if program_id == JITO_RESTAKING_ID => {
+            JitoRestakingError::from_u32(*code).map(|error| error.to_string())
+        }
+        _ => None,
+    };
+
+    let failed_checks = instruction_logs(logs, *instruction_index)
+        .filter_map(FailedCheck::parse_log)
+        .map(|failed| AccountCheck {
+            check: failed.check,
+            account: failed.account,
+            account_index: failed.account.and_then(|account| {
+                instruction?
+                    .accounts
+                    .iter()
+                    .position(|meta| meta.pubkey == account)
+            }),
+        })
+        .collect();
+
+    Some(Diagnostic {
+        instruction_index: *instruction_index,
+        program_id,
+        error: error.clone(),
+        error_name,
+        failed_checks,
+    })
+}
+
+/// Decodes a failed RPC send. Logs are only available when the preflight simulation failed.
+pub fn diagnose_client_error(
+    instructions: &[Instruction],
+    error: &ClientError,
+) -> Option<Diagnostic> {
+    if let ErrorKind::RpcError(RpcError::RpcResponseError {
+        data: RpcResponseErrorData::SendTransactionPreflightFailure(result),
+        ..
+    }) = error.kind()
+    {
+        let logs = result.logs.clone().unwrap_or_default();
+        return diagnose(instructions, result.err.as_ref()?, &logs);
+    }
+    diagnose(instructions, &error.get_transaction_error()?, &[])
+}
+
+impl JsmClientError {
+    /// Decodes the error of a failed transaction built from `instructions`
+    pub fn diagnose(&self, instructions: &[Instruction]) -> Option<Diagnostic> {
+        match self {
+            Self::RpcClient(error) => diagnose_client_error(instructions, error),
+            #[cfg(feature = "banks-client")]
+            Self::BanksClient(error) => match error.as_ref() {
+                solana_banks_client::BanksClientError::SimulationError { err, logs, .. } => {
+                    diagnose(instructions, err, logs)
+                }
+                solana_banks_client::BanksClientError::TransactionError(err) => {
+                    diagnose(instructions, err, &[])
+                }
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+}
+
+/// The log lines of a top-level instruction, including those of the programs it invoked
+fn instruction_logs(logs: &[String], instruction_index: u8) -> impl Iterator<Item = &str> {
+    let mut current = None;
+    logs.iter().filter_map(move |line| {
+        if line.starts_with("Program ") && line.ends_with(" invoke [1]") {
+            current = Some(current.map_or(0, |index: u8| index.saturating_add(1)));
+        }
+        (current == Some(instruction_index)).then_some(line.as_str())
+    })
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Instruction {} failed: ", self.instruction_index)?;
+        match (&self.error_name, &self.error) {
+            (Some(name), InstructionError::Custom(code)) => write!(f, "{} ({:#x})", name, code)?,
+            (_, error) => write!(f, "{}", error)?,
+        }
+        for failed in self.failed_checks.iter() {
+            write!(f, "\n  {}", failed)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for AccountCheck {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Some(account) = self.account else {
+            return match self.check.field() {
+                Some(field) => write!(f, "the `{}` account was not passed", field),
+                None => write!(f, "a required account was not passed"),
+            };
+        };
+        match self.account_index {
+            Some(index) => write!(f, "account {} ({}) ", index, account)?,
+            None => write!(f, "account {} ", account)?,
+        }
+        match self.check.field() {
+            Some(field) => write!(f, "does not match the stored `{}`", field),
+            None => write!(f, "{}", self.check.requirement()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use jito_jsm_core::diagnostic::{Check, LOG_PREFIX};
+    use jito_restaking_client::programs::JITO_RESTAKING_ID;
+    use jito_vault_client::programs::JITO_VAULT_ID;
+    use solana_program::{
+        instruction::{AccountMeta, Instruction, InstructionError},
+        pubkey::Pubkey,
+    };
+    use solana_sdk::transaction::TransactionError;
+
+    use super::{diagnose, AccountCheck};
+
+    fn instruction(program_id: Pubkey, accounts: &[Pubkey]) -> Instruction {
+        Instruction {
+            program_id,
+            accounts: accounts
+                .iter()
+                .map(|account| AccountMeta::new(*account, false))
+                .collect(),
+            data: vec![],
+        }
+    }
+
+    fn invoke(program_id: &Pubkey) -> String {
+        format!("Program {} invoke [1]", program_id)
+    }
+
+    #[test]
+    fn test_diagnose_locates_failed_check_in_failed_instruction() {
+        let accounts = [Pubkey::new_unique(), Pubkey::new_unique()];
+        let instructions = [
+            instruction(JITO_RESTAKING_ID, &accounts),
+            instruction(JITO_VAULT_ID, &accounts),
+        ];
+        let logs = vec![
+            invoke(&JITO_RESTAKING_ID),
+            format!("Program {} success", JITO_RESTAKING_ID),
+            invoke(&JITO_VAULT_ID),
+            "Program log: Vault admin does not match the provided admin".to_string(),
+            format!("Program log: {}20:{}", LOG_PREFIX, accounts[1]),
+            format!(
+                "Program {} failed: custom program error: 0x3ed",
+                JITO_VAULT_ID
+            ),
+        ];
+
+        let diagnostic = diagnose(
+            &instructions,
+            &TransactionError::InstructionError(1, InstructionError::Custom(1005)),
+            &logs,
+        )
+        .unwrap();
+
+        assert_eq!(diagnostic.instruction_index, 1);
+        assert_eq!(diagnostic.program_id, Some(JITO_VAULT_ID));
+        assert_eq!(diagnostic.error_name.as_deref(), Some("VaultAdminInvalid"));
+        assert_eq!(
+            diagnostic.failed_checks,
+            vec![AccountCheck {
+                check: Check::Admin,
+                account: Some(accounts[1]),
+                account_index: Some(1),
+            }]
+        );
+        assert_eq!(
+            diagnostic.to_string(),
+            format!(
+                "Instruction 1 failed: VaultAdminInvalid (0x3ed)\n  account 1 ({}) does not match the stored `admin`",
+                accounts[1]
+            )
+        );
+    }
+
+    #[test]
+    fn test_diagnose_ignores_checks_logged_by_other_instructions() {
+        let account = Pubkey::new_unique();
+        let instructions = [
+            instruction(JITO_VAULT_ID, &[account]),
+            instruction(JITO_VAULT_ID, &[account]),
+        ];
+        let logs = vec![
+            invoke(&JITO_VAULT_ID),
+            format!("Program log: {}02:{}", LOG_PREFIX, account),
+            invoke(&JITO_VAULT_ID),
+            format!("Program log: {}03:{}", LOG_PREFIX, account),
+        ];
+
+        let diagnostic = diagnose(
+            &instructions,
+            &TransactionError::InstructionError(1, InstructionError::InvalidAccountOwner),
+            &logs,
+        )
+        .unwrap();
+
+        assert_eq!(diagnostic.error_name, None);
+        assert_eq!(diagnostic.failed_checks.len(), 1);
+        assert_eq!(diagnostic.failed_checks[0].check, Check::Owner);
+        assert_eq!(
+            diagnostic.to_string(),
+            format!(
+                "Instruction 1 failed: {}\n  account 0 ({}) is owned by the wrong program",
+                InstructionError::InvalidAccountOwner,
+                account
+            )
+        );
+    }
+
+    #[test]
+    fn test_diagnose_missing_account() {
+        let instructions = [instruction(JITO_VAULT_ID, &[])];
+        let logs = vec![
+            invoke(&JITO_VAULT_ID),
+            format!("Program log: {}28", LOG_PREFIX),
+        ];
+
+        let diagnostic = diagnose(
+            &instructions,
+            &TransactionError::InstructionError(0, InstructionError::Custom(1007)),
+            &logs,
+        )
+        .unwrap();
+
+        assert_eq!(
+            diagnostic.error_name.as_deref(),
+            Some("VaultMintBurnAdminInvalid")
+        );
+        assert_eq!(
+            diagnostic.failed_checks[0].to_string(),
+            "the `mint_burn_admin` account was not passed"
+        );
+    }
+
+    #[test]
+    fn test_diagnose_non_instruction_error() {
+        assert_eq!(
+            diagnose(&[], &TransactionError::BlockhashNotFound, &[]),
+            None
+        );
+    }
+}
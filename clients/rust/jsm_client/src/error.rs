@@ -0,0 +1,36 @@
+use solana_program::{program_error::ProgramError, pubkey::Pubkey};
+use solana_rpc_client_api::client_error::Error as ClientError;
+use solana_sdk::signer::SignerError;
+use thiserror::Error;
+
+pub type JsmClientResult<T> = Result<T, JsmClientError>;
+
+#[derive(Debug, Error)]
+pub enum JsmClientError {
+    #[error(transparent)]
+    RpcClient(Box<ClientError>),
+    #[cfg(feature = "banks-client")]
+    #[error(transparent)]
+    BanksClient(Box<solana_banks_client::BanksClientError>),
+    #[error("Account {0} not found")]
+    AccountNotFound(Pubkey),
+    #[error("Account {0} is invalid: {1}")]
+    InvalidAccount(Pubkey, ProgramError),
+    #[error("Ticket {0} is cooling down and can't be warmed up until it's inactive")]
+    TicketCoolingDown(Pubkey),
+    #[error(transparent)]
+    Signer(#[from] SignerError),
+}
+
+impl From<ClientError> for JsmClientError {
+    fn from(e: ClientError) -> Self {
+        Self::RpcClient(Box::new(e))
+    }
+}
+
+#[cfg(feature = "banks-client")]
+impl From<solana_banks_client::BanksClientError> for JsmClientError {
+    fn from(e: solana_banks_client::BanksClientError) -> Self {
+        Self::BanksClient(Box::new(e))
+    }
+}
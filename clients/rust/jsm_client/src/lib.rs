@@ -0,0 +1,20 @@
+//! High-level async client for the vault and restaking programs.
+//!
+//! [`VaultClient`] and [`RestakingClient`] wrap the instruction builders in `jito-vault-sdk` and
+//! `jito-restaking-sdk`, fetching the accounts each instruction needs and deriving its PDAs and
+//! associated token accounts. Both run over any [`JsmRpc`], which is implemented for the
+//! nonblocking `RpcClient` and, with the `banks-client` feature, for `BanksClient`.
+//!
+//! When a transaction fails, [`JsmClientError::diagnose`] decodes the program error and the
+//! account check the program logged into a [`Diagnostic`].
+pub mod diagnostic;
+pub mod error;
+pub mod restaking;
+pub mod rpc;
+pub mod vault;
+
+pub use diagnostic::Diagnostic;
+pub use error::{JsmClientError, JsmClientResult};
+pub use restaking::RestakingClient;
+pub use rpc::JsmRpc;
+pub use vault::VaultClient;
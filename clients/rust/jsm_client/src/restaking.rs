@@ -0,0 +1,341 @@
+use jito_restaking_client::programs::JITO_RESTAKING_ID;
+use jito_restaking_core::{
+    config::Config, ncn::Ncn, ncn_operator_state::NcnOperatorState,
+    ncn_vault_ticket::NcnVaultTicket, operator::Operator,
+    operator_vault_ticket::OperatorVaultTicket,
+};
+use jito_restaking_sdk::sdk::{
+    initialize_ncn_operator_state, initialize_ncn_vault_ticket, initialize_operator_vault_ticket,
+    ncn_warmup_operator, operator_warmup_ncn, warmup_ncn_vault_ticket,
+    warmup_operator_vault_ticket,
+};
+use solana_program::{instruction::Instruction, pubkey::Pubkey};
+use solana_sdk::signature::{Keypair, Signature, Signer};
+
+use crate::{
+    error::JsmClientResult,
+    rpc::{check_not_cooling_down, get_program_account, try_get_program_account, JsmRpc},
+};
+
+/// Intent-level calls into the restaking program.
+///
+/// Connecting an NCN, operator or vault takes two calls. A ticket can't be warmed up in the slot
+/// it was initialized in, so the first call initializes the ticket and a call in a later slot
+/// warms it up. Further calls return no instructions while the ticket is warming up or active, and
+/// fail with [`crate::JsmClientError::TicketCoolingDown`] while a deactivated ticket is cooling
+/// down. The `*_instructions` variants return the instructions without sending them.
+pub struct RestakingClient<R> {
+    rpc: R,
+    restaking_program_id: Pubkey,
+}
+
+impl<R: JsmRpc> RestakingClient<R> {
+    pub const fn new(rpc: R) -> Self {
+        Self {
+            rpc,
+            restaking_program_id: JITO_RESTAKING_ID,
+        }
+    }
+
+    /// Points the client at a different deployment of the restaking program
+    pub const fn with_program_id(rpc: R, restaking_program_id: Pubkey) -> Self {
+        Self {
+            rpc,
+            restaking_program_id,
+        }
+    }
+
+    pub const fn rpc(&self) -> &R {
+        &self.rpc
+    }
+
+    pub const fn restaking_program_id(&self) -> &Pubkey {
+        &self.restaking_program_id
+    }
+
+    pub fn config_address(&self) -> Pubkey {
+        Config::find_program_address(&self.restaking_program_id).0
+    }
+
+    pub fn ncn_operator_state_address(&self, ncn: &Pubkey, operator: &Pubkey) -> Pubkey {
+        NcnOperatorState::find_program_address(&self.restaking_program_id, ncn, operator).0
+    }
+
+    pub fn ncn_vault_ticket_address(&self, ncn: &Pubkey, vault: &Pubkey) -> Pubkey {
+        NcnVaultTicket::find_program_address(&self.restaking_program_id, ncn, vault).0
+    }
+
+    pub fn operator_vault_ticket_address(&self, operator: &Pubkey, vault: &Pubkey) -> Pubkey {
+        OperatorVaultTicket::find_program_address(&self.restaking_program_id, operator, vault).0
+    }
+
+    pub async fn get_config(&self) -> JsmClientResult<Config> {
+        get_program_account(&self.rpc, &self.config_address()).await
+    }
+
+    pub async fn get_ncn(&self, ncn: &Pubkey) -> JsmClientResult<Ncn> {
+        get_program_account(&self.rpc, ncn).await
+    }
+
+    pub async fn get_operator(&self, operator: &Pubkey) -> JsmClientResult<Operator> {
+        get_program_account(&self.rpc, operator).await
+    }
+
+    pub async fn get_ncn_operator_state(
+        &self,
+        ncn: &Pubkey,
+        operator: &Pubkey,
+    ) -> JsmClientResult<NcnOperatorState> {
+        get_program_account(&self.rpc, &self.ncn_operator_state_address(ncn, operator)).await
+    }
+
+    pub async fn get_ncn_vault_ticket(
+        &self,
+        ncn: &Pubkey,
+        vault: &Pubkey,
+    ) -> JsmClientResult<NcnVaultTicket> {
+        get_program_account(&self.rpc, &self.ncn_vault_ticket_address(ncn, vault)).await
+    }
+
+    pub async fn get_operator_vault_ticket(
+        &self,
+        operator: &Pubkey,
+        vault: &Pubkey,
+    ) -> JsmClientResult<OperatorVaultTicket> {
+        get_program_account(
+            &self.rpc,
+            &self.operator_vault_ticket_address(operator, vault),
+        )
+        .await
+    }
+
+    /// Has the NCN opt into a vault, initializing the ticket if it doesn't exist and warming it up
+    /// otherwise. Returns no instructions if the ticket is already warming up or active.
+    /// `ncn_vault_admin` must sign.
+    pub async fn connect_ncn_vault_instructions(
+        &self,
+        payer: &Pubkey,
+        ncn_vault_admin: &Pubkey,
+        ncn: &Pubkey,
+        vault: &Pubkey,
+    ) -> JsmClientResult<Vec<Instruction>> {
+        let config = self.config_address();
+        let ticket = self.ncn_vault_ticket_address(ncn, vault);
+
+        match try_get_program_account::<NcnVaultTicket>(&self.rpc, &ticket).await? {
+            None => Ok(vec![initialize_ncn_vault_ticket(
+                &self.restaking_program_id,
+                &config,
+                ncn,
+                vault,
+                &ticket,
+                ncn_vault_admin,
+                payer,
+            )]),
+            Some(ticket_account) if ticket_account.state.is_activated() => Ok(vec![]),
+            Some(ticket_account) => {
+                let epoch_length = self.get_config().await?.epoch_length();
+                check_not_cooling_down(&self.rpc, &ticket, &ticket_account.state, epoch_length)
+                    .await?;
+                Ok(vec![warmup_ncn_vault_ticket(
+                    &self.restaking_program_id,
+                    &config,
+                    ncn,
+                    vault,
+                    &ticket,
+                    ncn_vault_admin,
+                )])
+            }
+        }
+    }
+
+    /// Returns `None` if there was nothing to send
+    pub async fn connect_ncn_vault(
+        &self,
+        ncn_vault_admin: &Keypair,
+        ncn: &Pubkey,
+        vault: &Pubkey,
+    ) -> JsmClientResult<Option<Signature>> {
+        let instructions = self
+            .connect_ncn_vault_instructions(
+                &ncn_vault_admin.pubkey(),
+                &ncn_vault_admin.pubkey(),
+                ncn,
+                vault,
+            )
+            .await?;
+        if instructions.is_empty() {
+            return Ok(None);
+        }
+        self.rpc
+            .process_instructions(&instructions, ncn_vault_admin, &[])
+            .await
+            .map(Some)
+    }
+
+    /// Has the operator opt into a vault, initializing the ticket if it doesn't exist and warming it
+    /// up otherwise. Returns no instructions if the ticket is already warming up or active.
+    /// `operator_vault_admin` must sign.
+    pub async fn connect_operator_vault_instructions(
+        &self,
+        payer: &Pubkey,
+        operator_vault_admin: &Pubkey,
+        operator: &Pubkey,
+        vault: &Pubkey,
+    ) -> JsmClientResult<Vec<Instruction>> {
+        let config = self.config_address();
+        let ticket = self.operator_vault_ticket_address(operator, vault);
+
+        match try_get_program_account::<OperatorVaultTicket>(&self.rpc, &ticket).await? {
+            None => Ok(vec![initialize_operator_vault_ticket(
+                &self.restaking_program_id,
+                &config,
+                operator,
+                vault,
+                &ticket,
+                operator_vault_admin,
+                payer,
+            )]),
+            Some(ticket_account) if ticket_account.state.is_activated() => Ok(vec![]),
+            Some(ticket_account) => {
+                let epoch_length = self.get_config().await?.epoch_length();
+                check_not_cooling_down(&self.rpc, &ticket, &ticket_account.state, epoch_length)
+                    .await?;
+                Ok(vec![warmup_operator_vault_ticket(
+                    &self.restaking_program_id,
+                    &config,
+                    operator,
+                    vault,
+                    &ticket,
+                    operator_vault_admin,
+                )])
+            }
+        }
+    }
+
+    /// Returns `None` if there was nothing to send
+    pub async fn connect_operator_vault(
+        &self,
+        operator_vault_admin: &Keypair,
+        operator: &Pubkey,
+        vault: &Pubkey,
+    ) -> JsmClientResult<Option<Signature>> {
+        let instructions = self
+            .connect_operator_vault_instructions(
+                &operator_vault_admin.pubkey(),
+                &operator_vault_admin.pubkey(),
+                operator,
+                vault,
+            )
+            .await?;
+        if instructions.is_empty() {
+            return Ok(None);
+        }
+        self.rpc
+            .process_instructions(&instructions, operator_vault_admin, &[])
+            .await
+            .map(Some)
+    }
+
+    /// Has the NCN opt into an operator, initializing the NCN operator state if it doesn't exist and
+    /// warming it up otherwise. Returns no instructions if the NCN's opt-in is already warming up or
+    /// active. `ncn_operator_admin` must sign.
+    ///
+    /// The operator opts back in separately with [`Self::operator_opt_in_ncn_instructions`].
+    pub async fn connect_ncn_operator_instructions(
+        &self,
+        payer: &Pubkey,
+        ncn_operator_admin: &Pubkey,
+        ncn: &Pubkey,
+        operator: &Pubkey,
+    ) -> JsmClientResult<Vec<Instruction>> {
+        let config = self.config_address();
+        let ncn_operator_state = self.ncn_operator_state_address(ncn, operator);
+
+        match try_get_program_account::<NcnOperatorState>(&self.rpc, &ncn_operator_state).await? {
+            None => Ok(vec![initialize_ncn_operator_state(
+                &self.restaking_program_id,
+                &config,
+                ncn,
+                operator,
+                &ncn_operator_state,
+                ncn_operator_admin,
+                payer,
+            )]),
+            Some(state) if state.ncn_opt_in_state.is_activated() => Ok(vec![]),
+            Some(state) => {
+                let epoch_length = self.get_config().await?.epoch_length();
+                check_not_cooling_down(
+                    &self.rpc,
+                    &ncn_operator_state,
+                    &state.ncn_opt_in_state,
+                    epoch_length,
+                )
+                .await?;
+                Ok(vec![ncn_warmup_operator(
+                    &self.restaking_program_id,
+                    &config,
+                    ncn,
+                    operator,
+                    &ncn_operator_state,
+                    ncn_operator_admin,
+                )])
+            }
+        }
+    }
+
+    /// Returns `None` if there was nothing to send
+    pub async fn connect_ncn_operator(
+        &self,
+        ncn_operator_admin: &Keypair,
+        ncn: &Pubkey,
+        operator: &Pubkey,
+    ) -> JsmClientResult<Option<Signature>> {
+        let instructions = self
+            .connect_ncn_operator_instructions(
+                &ncn_operator_admin.pubkey(),
+                &ncn_operator_admin.pubkey(),
+                ncn,
+                operator,
+            )
+            .await?;
+        if instructions.is_empty() {
+            return Ok(None);
+        }
+        self.rpc
+            .process_instructions(&instructions, ncn_operator_admin, &[])
+            .await
+            .map(Some)
+    }
+
+    /// Has the operator opt into an NCN that has already connected to it. `operator_ncn_admin`
+    /// must sign.
+    pub fn operator_opt_in_ncn_instructions(
+        &self,
+        operator_ncn_admin: &Pubkey,
+        ncn: &Pubkey,
+        operator: &Pubkey,
+    ) -> Vec<Instruction> {
+        vec![operator_warmup_ncn(
+            &self.restaking_program_id,
+            &self.config_address(),
+            ncn,
+            operator,
+            &self.ncn_operator_state_address(ncn, operator),
+            operator_ncn_admin,
+        )]
+    }
+
+    pub async fn operator_opt_in_ncn(
+        &self,
+        operator_ncn_admin: &Keypair,
+        ncn: &Pubkey,
+        operator: &Pubkey,
+    ) -> JsmClientResult<Signature> {
+        let instructions =
+            self.operator_opt_in_ncn_instructions(&operator_ncn_admin.pubkey(), ncn, operator);
+        self.rpc
+            .process_instructions(&instructions, operator_ncn_admin, &[])
+            .await
+    }
+}
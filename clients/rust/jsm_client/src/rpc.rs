@@ -0,0 +1,137 @@
+use async_trait::async_trait;
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::slot_toggle::{SlotToggle, SlotToggleState};
+use solana_program::{hash::Hash, instruction::Instruction, pubkey::Pubkey};
+use solana_rpc_client::nonblocking::rpc_client::RpcClient;
+use solana_sdk::{
+    account::Account,
+    signature::{Keypair, Signature, Signer},
+    transaction::Transaction,
+};
+
+use crate::error::{JsmClientError, JsmClientResult};
+
+/// The RPC calls [`crate::VaultClient`] and [`crate::RestakingClient`] make.
+///
+/// It's implemented for the nonblocking [`RpcClient`] and, with the `banks-client` feature, for
+/// `BanksClient` so the clients can be driven from `solana-program-test`.
+#[async_trait]
+pub trait JsmRpc: Send + Sync {
+    /// Fetches an account, returning `None` if it doesn't exist
+    async fn get_account(&self, pubkey: &Pubkey) -> JsmClientResult<Option<Account>>;
+
+    async fn get_latest_blockhash(&self) -> JsmClientResult<Hash>;
+
+    async fn get_slot(&self) -> JsmClientResult<u64>;
+
+    /// Sends a signed transaction and waits for it to be confirmed
+    async fn send_transaction(&self, transaction: &Transaction) -> JsmClientResult<Signature>;
+
+    /// Signs the instructions with the payer and signers and sends them in a single transaction
+    async fn process_instructions(
+        &self,
+        instructions: &[Instruction],
+        payer: &Keypair,
+        signers: &[&Keypair],
+    ) -> JsmClientResult<Signature> {
+        let blockhash = self.get_latest_blockhash().await?;
+        let mut transaction = Transaction::new_with_payer(instructions, Some(&payer.pubkey()));
+        let mut all_signers = vec![payer];
+        all_signers.extend(
+            signers
+                .iter()
+                .filter(|signer| signer.pubkey() != payer.pubkey()),
+        );
+        transaction.try_sign(&all_signers, blockhash)?;
+        self.send_transaction(&transaction).await
+    }
+}
+
+/// Fetches and deserializes a program account
+pub async fn get_program_account<T: AccountDeserialize + Copy>(
+    rpc: &(impl JsmRpc + ?Sized),
+    pubkey: &Pubkey,
+) -> JsmClientResult<T> {
+    try_get_program_account(rpc, pubkey)
+        .await?
+        .ok_or(JsmClientError::AccountNotFound(*pubkey))
+}
+
+/// Fetches and deserializes a program account, returning `None` if it doesn't exist
+pub async fn try_get_program_account<T: AccountDeserialize + Copy>(
+    rpc: &(impl JsmRpc + ?Sized),
+    pubkey: &Pubkey,
+) -> JsmClientResult<Option<T>> {
+    let Some(account) = rpc.get_account(pubkey).await? else {
+        return Ok(None);
+    };
+    let account = T::try_from_slice_unchecked(&account.data)
+        .map_err(|e| JsmClientError::InvalidAccount(*pubkey, e))?;
+    Ok(Some(*account))
+}
+
+/// Fails if the ticket's state is cooling down at the current slot. A deactivated ticket can only
+/// be warmed up again once it's inactive.
+pub async fn check_not_cooling_down(
+    rpc: &(impl JsmRpc + ?Sized),
+    ticket: &Pubkey,
+    state: &SlotToggle,
+    epoch_length: u64,
+) -> JsmClientResult<()> {
+    let slot = rpc.get_slot().await?;
+    let state = state
+        .state(slot, epoch_length)
+        .map_err(|e| JsmClientError::InvalidAccount(*ticket, e))?;
+    if state == SlotToggleState::Cooldown {
+        return Err(JsmClientError::TicketCoolingDown(*ticket));
+    }
+    Ok(())
+}
+
+#[async_trait]
+impl JsmRpc for RpcClient {
+    async fn get_account(&self, pubkey: &Pubkey) -> JsmClientResult<Option<Account>> {
+        Ok(self
+            .get_account_with_commitment(pubkey, self.commitment())
+            .await?
+            .value)
+    }
+
+    async fn get_latest_blockhash(&self) -> JsmClientResult<Hash> {
+        Ok(self.get_latest_blockhash().await?)
+    }
+
+    async fn get_slot(&self) -> JsmClientResult<u64> {
+        Ok(self.get_slot_with_commitment(self.commitment()).await?)
+    }
+
+    async fn send_transaction(&self, transaction: &Transaction) -> JsmClientResult<Signature> {
+        Ok(self.send_and_confirm_transaction(transaction).await?)
+    }
+}
+
+#[cfg(feature = "banks-client")]
+#[async_trait]
+impl JsmRpc for solana_banks_client::BanksClient {
+    async fn get_account(&self, pubkey: &Pubkey) -> JsmClientResult<Option<Account>> {
+        // Called through the type since `JsmRpc` shadows the `&mut self` inherent methods
+        Ok(solana_banks_client::BanksClient::get_account(&mut self.clone(), *pubkey).await?)
+    }
+
+    async fn get_latest_blockhash(&self) -> JsmClientResult<Hash> {
+        Ok(solana_banks_client::BanksClient::get_latest_blockhash(&mut self.clone()).await?)
+    }
+
+    async fn get_slot(&self) -> JsmClientResult<u64> {
+        // The bank's clock, which follows warps in program-test
+        let clock: solana_program::clock::Clock = self.clone().get_sysvar().await?;
+        Ok(clock.slot)
+    }
+
+    async fn send_transaction(&self, transaction: &Transaction) -> JsmClientResult<Signature> {
+        self.clone()
+            .process_transaction(transaction.clone())
+            .await?;
+        Ok(transaction.signatures[0])
+    }
+}
@@ -0,0 +1,815 @@
+use jito_restaking_client::programs::JITO_RESTAKING_ID;
+use jito_restaking_core::{
+    ncn_vault_ticket::NcnVaultTicket, operator_vault_ticket::OperatorVaultTicket,
+};
+use jito_vault_client::programs::JITO_VAULT_ID;
+use jito_vault_core::{
+    config::Config, vault::Vault, vault_ncn_ticket::VaultNcnTicket,
+    vault_operator_delegation::VaultOperatorDelegation,
+    vault_staker_withdrawal_ticket::VaultStakerWithdrawalTicket,
+};
+use jito_vault_sdk::sdk::{
+    add_delegation, burn_withdrawal_ticket, cooldown_delegation, enqueue_withdrawal,
+    initialize_vault_ncn_ticket, initialize_vault_operator_delegation, mint_to,
+    warmup_vault_ncn_ticket,
+};
+use solana_program::{
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+};
+use solana_sdk::signature::{Keypair, Signature, Signer};
+use spl_associated_token_account::{
+    get_associated_token_address, instruction::create_associated_token_account_idempotent,
+};
+
+use crate::{
+    error::JsmClientResult,
+    rpc::{check_not_cooling_down, get_program_account, try_get_program_account, JsmRpc},
+};
+
+/// Intent-level calls into the vault program.
+///
+/// Each call fetches the accounts it needs and derives every PDA and associated token account, so
+/// callers only pass the vault and the amounts involved. The `*_instructions` variants return the
+/// instructions without sending them, for callers that sign elsewhere.
+pub struct VaultClient<R> {
+    rpc: R,
+    vault_program_id: Pubkey,
+    restaking_program_id: Pubkey,
+}
+
+impl<R: JsmRpc> VaultClient<R> {
+    pub const fn new(rpc: R) -> Self {
+        Self {
+            rpc,
+            vault_program_id: JITO_VAULT_ID,
+            restaking_program_id: JITO_RESTAKING_ID,
+        }
+    }
+
+    /// Points the client at a different deployment of the vault and restaking programs
+    pub const fn with_program_ids(
+        rpc: R,
+        vault_program_id: Pubkey,
+        restaking_program_id: Pubkey,
+    ) -> Self {
+        Self {
+            rpc,
+            vault_program_id,
+            restaking_program_id,
+        }
+    }
+
+    pub const fn rpc(&self) -> &R {
+        &self.rpc
+    }
+
+    pub const fn vault_program_id(&self) -> &Pubkey {
+        &self.vault_program_id
+    }
+
+    pub fn config_address(&self) -> Pubkey {
+        Config::find_program_address(&self.vault_program_id).0
+    }
+
+    pub fn withdrawal_ticket_address(&self, vault: &Pubkey, base: &Pubkey) -> Pubkey {
+        VaultStakerWithdrawalTicket::find_program_address(&self.vault_program_id, vault, base).0
+    }
+
+    pub fn vault_ncn_ticket_address(&self, vault: &Pubkey, ncn: &Pubkey) -> Pubkey {
+        VaultNcnTicket::find_program_address(&self.vault_program_id, vault, ncn).0
+    }
+
+    pub fn vault_operator_delegation_address(&self, vault: &Pubkey, operator: &Pubkey) -> Pubkey {
+        VaultOperatorDelegation::find_program_address(&self.vault_program_id, vault, operator).0
+    }
+
+    pub async fn get_config(&self) -> JsmClientResult<Config> {
+        get_program_account(&self.rpc, &self.config_address()).await
+    }
+
+    pub async fn get_vault(&self, vault: &Pubkey) -> JsmClientResult<Vault> {
+        get_program_account(&self.rpc, vault).await
+    }
+
+    pub async fn get_withdrawal_ticket(
+        &self,
+        ticket: &Pubkey,
+    ) -> JsmClientResult<VaultStakerWithdrawalTicket> {
+        get_program_account(&self.rpc, ticket).await
+    }
+
+    pub async fn get_vault_ncn_ticket(
+        &self,
+        vault: &Pubkey,
+        ncn: &Pubkey,
+    ) -> JsmClientResult<VaultNcnTicket> {
+        get_program_account(&self.rpc, &self.vault_ncn_ticket_address(vault, ncn)).await
+    }
+
+    pub async fn get_vault_operator_delegation(
+        &self,
+        vault: &Pubkey,
+        operator: &Pubkey,
+    ) -> JsmClientResult<VaultOperatorDelegation> {
+        get_program_account(
+            &self.rpc,
+            &self.vault_operator_delegation_address(vault, operator),
+        )
+        .await
+    }
+
+    /// Deposits `amount` of the vault's supported token, minting VRT to the depositor.
+    ///
+    /// The depositor's VRT token account is created if it doesn't exist. If the vault has a mint
+    /// burn admin, it must co-sign the transaction.
+    pub async fn deposit_instructions(
+        &self,
+        depositor: &Pubkey,
+        vault: &Pubkey,
+        amount: u64,
+        min_amount_out: u64,
+    ) -> JsmClientResult<Vec<Instruction>> {
+        let vault_account = self.get_vault(vault).await?;
+
+        Ok(vec![
+            create_associated_token_account_idempotent(
+                depositor,
+                depositor,
+                &vault_account.vrt_mint,
+                &spl_token::id(),
+            ),
+            mint_to(
+                &self.vault_program_id,
+                &self.config_address(),
+                vault,
+                &vault_account.vrt_mint,
+                depositor,
+                &get_associated_token_address(depositor, &vault_account.supported_mint),
+                &get_associated_token_address(vault, &vault_account.supported_mint),
+                &get_associated_token_address(depositor, &vault_account.vrt_mint),
+                &get_associated_token_address(&vault_account.fee_wallet, &vault_account.vrt_mint),
+                mint_burn_admin(&vault_account).as_ref(),
+                amount,
+                min_amount_out,
+            ),
+        ])
+    }
+
+    pub async fn deposit(
+        &self,
+        depositor: &Keypair,
+        vault: &Pubkey,
+        amount: u64,
+        min_amount_out: u64,
+    ) -> JsmClientResult<Signature> {
+        let instructions = self
+            .deposit_instructions(&depositor.pubkey(), vault, amount, min_amount_out)
+            .await?;
+        self.rpc
+            .process_instructions(&instructions, depositor, &[])
+            .await
+    }
+
+    /// Enqueues a withdrawal of `amount` VRT into a new withdrawal ticket derived from `base`.
+    ///
+    /// Returns the withdrawal ticket address with the instructions. `base` must sign.
+    pub async fn request_withdrawal_instructions(
+        &self,
+        staker: &Pubkey,
+        vault: &Pubkey,
+        base: &Pubkey,
+        amount: u64,
+    ) -> JsmClientResult<(Pubkey, Vec<Instruction>)> {
+        let vault_account = self.get_vault(vault).await?;
+        let ticket = self.withdrawal_ticket_address(vault, base);
+
+        let instructions = vec![
+            create_associated_token_account_idempotent(
+                staker,
+                &ticket,
+                &vault_account.vrt_mint,
+                &spl_token::id(),
+            ),
+            enqueue_withdrawal(
+                &self.vault_program_id,
+                &self.config_address(),
+                vault,
+                &ticket,
+                &get_associated_token_address(&ticket, &vault_account.vrt_mint),
+                staker,
+                &get_associated_token_address(staker, &vault_account.vrt_mint),
+                base,
+                amount,
+            ),
+        ];
+        Ok((ticket, instructions))
+    }
+
+    /// Enqueues a withdrawal of `amount` VRT, returning the new withdrawal ticket address
+    pub async fn request_withdrawal(
+        &self,
+        staker: &Keypair,
+        vault: &Pubkey,
+        amount: u64,
+    ) -> JsmClientResult<(Pubkey, Signature)> {
+        let base = Keypair::new();
+        let (ticket, instructions) = self
+            .request_withdrawal_instructions(&staker.pubkey(), vault, &base.pubkey(), amount)
+            .await?;
+        let signature = self
+            .rpc
+            .process_instructions(&instructions, staker, &[&base])
+            .await?;
+        Ok((ticket, signature))
+    }
+
+    /// Burns a withdrawable ticket, sending the supported token to its staker.
+    ///
+    /// Anyone can pay for the claim; the staker's supported token account and the program fee
+    /// wallet's VRT token account are created if they don't exist. If the vault has a mint burn
+    /// admin and isn't in emergency exit, it must co-sign the transaction.
+    pub async fn claim_instructions(
+        &self,
+        payer: &Pubkey,
+        ticket: &Pubkey,
+    ) -> JsmClientResult<Vec<Instruction>> {
+        let ticket_account = self.get_withdrawal_ticket(ticket).await?;
+        let vault = ticket_account.vault;
+        let vault_account = self.get_vault(&vault).await?;
+        let config = self.get_config().await?;
+
+        let mut burn_ix = burn_withdrawal_ticket(
+            &self.vault_program_id,
+            &self.config_address(),
+            &vault,
+            &get_associated_token_address(&vault, &vault_account.supported_mint),
+            &vault_account.vrt_mint,
+            &ticket_account.staker,
+            &get_associated_token_address(&ticket_account.staker, &vault_account.supported_mint),
+            ticket,
+            &get_associated_token_address(ticket, &vault_account.vrt_mint),
+            &get_associated_token_address(&vault_account.fee_wallet, &vault_account.vrt_mint),
+            &get_associated_token_address(&config.program_fee_wallet, &vault_account.vrt_mint),
+        );
+        if !vault_account.is_emergency_exit() {
+            if let Some(mint_burn_admin) = mint_burn_admin(&vault_account) {
+                burn_ix
+                    .accounts
+                    .push(AccountMeta::new_readonly(mint_burn_admin, true));
+            }
+        }
+
+        Ok(vec![
+            create_associated_token_account_idempotent(
+                payer,
+                &ticket_account.staker,
+                &vault_account.supported_mint,
+                &spl_token::id(),
+            ),
+            create_associated_token_account_idempotent(
+                payer,
+                &config.program_fee_wallet,
+                &vault_account.vrt_mint,
+                &spl_token::id(),
+            ),
+            burn_ix,
+        ])
+    }
+
+    pub async fn claim(&self, payer: &Keypair, ticket: &Pubkey) -> JsmClientResult<Signature> {
+        let instructions = self.claim_instructions(&payer.pubkey(), ticket).await?;
+        self.rpc
+            .process_instructions(&instructions, payer, &[])
+            .await
+    }
+
+    /// Has the vault opt into an NCN that has opted into it, initializing the vault NCN ticket if
+    /// it doesn't exist and warming it up otherwise. Returns no instructions if the ticket is
+    /// already warming up or active, and fails with [`crate::JsmClientError::TicketCoolingDown`]
+    /// while it's cooling down. The vault's NCN admin must sign.
+    ///
+    /// Like the restaking tickets, a new ticket can only be warmed up in a later slot.
+    pub async fn connect_ncn_instructions(
+        &self,
+        payer: &Pubkey,
+        vault: &Pubkey,
+        ncn: &Pubkey,
+    ) -> JsmClientResult<Vec<Instruction>> {
+        let config = self.config_address();
+        let vault_ncn_ticket = self.vault_ncn_ticket_address(vault, ncn);
+        let vault_account = self.get_vault(vault).await?;
+
+        match try_get_program_account::<VaultNcnTicket>(&self.rpc, &vault_ncn_ticket).await? {
+            None => Ok(vec![initialize_vault_ncn_ticket(
+                &self.vault_program_id,
+                &config,
+                vault,
+                ncn,
+                &NcnVaultTicket::find_program_address(&self.restaking_program_id, ncn, vault).0,
+                &vault_ncn_ticket,
+                &vault_account.ncn_admin,
+                payer,
+            )]),
+            Some(ticket) if ticket.state.is_activated() => Ok(vec![]),
+            Some(ticket) => {
+                let epoch_length = self.get_config().await?.epoch_length();
+                check_not_cooling_down(&self.rpc, &vault_ncn_ticket, &ticket.state, epoch_length)
+                    .await?;
+                Ok(vec![warmup_vault_ncn_ticket(
+                    &self.vault_program_id,
+                    &config,
+                    vault,
+                    ncn,
+                    &vault_ncn_ticket,
+                    &vault_account.ncn_admin,
+                )])
+            }
+        }
+    }
+
+    /// Returns `None` if there was nothing to send
+    pub async fn connect_ncn(
+        &self,
+        ncn_admin: &Keypair,
+        vault: &Pubkey,
+        ncn: &Pubkey,
+    ) -> JsmClientResult<Option<Signature>> {
+        let instructions = self
+            .connect_ncn_instructions(&ncn_admin.pubkey(), vault, ncn)
+            .await?;
+        if instructions.is_empty() {
+            return Ok(None);
+        }
+        self.rpc
+            .process_instructions(&instructions, ncn_admin, &[])
+            .await
+            .map(Some)
+    }
+
+    /// Initializes the vault operator delegation, which requires the operator to have opted into
+    /// the vault. Returns no instructions if it already exists. The vault's operator admin must
+    /// sign.
+    pub async fn connect_operator_instructions(
+        &self,
+        payer: &Pubkey,
+        vault: &Pubkey,
+        operator: &Pubkey,
+    ) -> JsmClientResult<Vec<Instruction>> {
+        let vault_operator_delegation = self.vault_operator_delegation_address(vault, operator);
+        if try_get_program_account::<VaultOperatorDelegation>(&self.rpc, &vault_operator_delegation)
+            .await?
+            .is_some()
+        {
+            return Ok(vec![]);
+        }
+
+        let vault_account = self.get_vault(vault).await?;
+        Ok(vec![initialize_vault_operator_delegation(
+            &self.vault_program_id,
+            &self.config_address(),
+            vault,
+            operator,
+            &OperatorVaultTicket::find_program_address(&self.restaking_program_id, operator, vault)
+                .0,
+            &vault_operator_delegation,
+            &vault_account.operator_admin,
+            payer,
+        )])
+    }
+
+    /// Returns `None` if the vault was already connected to the operator
+    pub async fn connect_operator(
+        &self,
+        operator_admin: &Keypair,
+        vault: &Pubkey,
+        operator: &Pubkey,
+    ) -> JsmClientResult<Option<Signature>> {
+        let instructions = self
+            .connect_operator_instructions(&operator_admin.pubkey(), vault, operator)
+            .await?;
+        if instructions.is_empty() {
+            return Ok(None);
+        }
+        self.rpc
+            .process_instructions(&instructions, operator_admin, &[])
+            .await
+            .map(Some)
+    }
+
+    /// Delegates `amount` of the vault's assets to an operator.
+    ///
+    /// The vault operator delegation is initialized first if it doesn't exist, see
+    /// [`Self::connect_operator_instructions`]. `delegation_admin` must sign.
+    pub async fn delegate_instructions(
+        &self,
+        payer: &Pubkey,
+        delegation_admin: &Pubkey,
+        vault: &Pubkey,
+        operator: &Pubkey,
+        amount: u64,
+    ) -> JsmClientResult<Vec<Instruction>> {
+        let mut instructions = self
+            .connect_operator_instructions(payer, vault, operator)
+            .await?;
+        instructions.push(add_delegation(
+            &self.vault_program_id,
+            &self.config_address(),
+            vault,
+            operator,
+            &self.vault_operator_delegation_address(vault, operator),
+            delegation_admin,
+            amount,
+        ));
+        Ok(instructions)
+    }
+
+    /// Delegates `amount` to an operator, paid for by the delegation admin.
+    ///
+    /// If the vault operator delegation has to be initialized, the vault's operator admin must be
+    /// the same key as its delegation admin.
+    pub async fn delegate(
+        &self,
+        delegation_admin: &Keypair,
+        vault: &Pubkey,
+        operator: &Pubkey,
+        amount: u64,
+    ) -> JsmClientResult<Signature> {
+        let instructions = self
+            .delegate_instructions(
+                &delegation_admin.pubkey(),
+                &delegation_admin.pubkey(),
+                vault,
+                operator,
+                amount,
+            )
+            .await?;
+        self.rpc
+            .process_instructions(&instructions, delegation_admin, &[])
+            .await
+    }
+
+    /// Starts cooling down `amount` of an operator's delegation. `delegation_admin` must sign.
+    pub fn undelegate_instructions(
+        &self,
+        delegation_admin: &Pubkey,
+        vault: &Pubkey,
+        operator: &Pubkey,
+        amount: u64,
+    ) -> Vec<Instruction> {
+        vec![cooldown_delegation(
+            &self.vault_program_id,
+            &self.config_address(),
+            vault,
+            operator,
+            &self.vault_operator_delegation_address(vault, operator),
+            delegation_admin,
+            amount,
+        )]
+    }
+
+    pub async fn undelegate(
+        &self,
+        delegation_admin: &Keypair,
+        vault: &Pubkey,
+        operator: &Pubkey,
+        amount: u64,
+    ) -> JsmClientResult<Signature> {
+        let instructions =
+            self.undelegate_instructions(&delegation_admin.pubkey(), vault, operator, amount);
+        self.rpc
+            .process_instructions(&instructions, delegation_admin, &[])
+            .await
+    }
+}
+
+/// The vault's mint burn admin, if it has one
+fn mint_burn_admin(vault: &Vault) -> Option<Pubkey> {
+    (vault.mint_burn_admin != Pubkey::default()).then_some(vault.mint_burn_admin)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::HashMap, sync::Mutex};
+
+    use async_trait::async_trait;
+    use bytemuck::Zeroable;
+    use jito_bytemuck::AccountDeserialize;
+    use solana_program::hash::Hash;
+    use solana_sdk::{account::Account, transaction::Transaction};
+
+    use super::*;
+    use crate::error::JsmClientError;
+
+    /// Serves accounts from memory and records the transactions sent
+    #[derive(Default)]
+    struct MemoryRpc {
+        accounts: HashMap<Pubkey, Account>,
+        slot: u64,
+        sent: Mutex<Vec<Transaction>>,
+    }
+
+    impl MemoryRpc {
+        fn set<T: AccountDeserialize + bytemuck::Pod>(&mut self, pubkey: Pubkey, account: &T) {
+            let mut data = vec![0; 8 + std::mem::size_of::<T>()];
+            T::write_header(&mut data).unwrap();
+            data[8..].copy_from_slice(bytemuck::bytes_of(account));
+            self.accounts.insert(
+                pubkey,
+                Account {
+                    data,
+                    owner: JITO_VAULT_ID,
+                    ..Account::default()
+                },
+            );
+        }
+    }
+
+    #[async_trait]
+    impl JsmRpc for MemoryRpc {
+        async fn get_account(&self, pubkey: &Pubkey) -> JsmClientResult<Option<Account>> {
+            Ok(self.accounts.get(pubkey).cloned())
+        }
+
+        async fn get_latest_blockhash(&self) -> JsmClientResult<Hash> {
+            Ok(Hash::new_unique())
+        }
+
+        async fn get_slot(&self) -> JsmClientResult<u64> {
+            Ok(self.slot)
+        }
+
+        async fn send_transaction(&self, transaction: &Transaction) -> JsmClientResult<Signature> {
+            self.sent.lock().unwrap().push(transaction.clone());
+            Ok(transaction.signatures[0])
+        }
+    }
+
+    fn config_account() -> Config {
+        Config::new(
+            Pubkey::new_unique(),
+            JITO_RESTAKING_ID,
+            Pubkey::new_unique(),
+            0,
+            0,
+        )
+    }
+
+    fn vault_account() -> Vault {
+        let mut vault = Vault::zeroed();
+        vault.vrt_mint = Pubkey::new_unique();
+        vault.supported_mint = Pubkey::new_unique();
+        vault.fee_wallet = Pubkey::new_unique();
+        vault.operator_admin = Pubkey::new_unique();
+        vault.ncn_admin = Pubkey::new_unique();
+        vault
+    }
+
+    #[tokio::test]
+    async fn test_deposit_resolves_accounts() {
+        let vault = Pubkey::new_unique();
+        let vault_account = vault_account();
+        let mut rpc = MemoryRpc::default();
+        rpc.set(vault, &vault_account);
+        let client = VaultClient::new(rpc);
+
+        let depositor = Keypair::new();
+        client
+            .deposit(&depositor, &vault, 1_000, 900)
+            .await
+            .unwrap();
+
+        let sent = client.rpc().sent.lock().unwrap();
+        let mint_to_ix = &sent[0].message.instructions[1];
+        let keys: Vec<Pubkey> = mint_to_ix
+            .accounts
+            .iter()
+            .map(|index| sent[0].message.account_keys[*index as usize])
+            .collect();
+        assert_eq!(
+            keys,
+            vec![
+                client.config_address(),
+                vault,
+                vault_account.vrt_mint,
+                depositor.pubkey(),
+                get_associated_token_address(&depositor.pubkey(), &vault_account.supported_mint),
+                get_associated_token_address(&vault, &vault_account.supported_mint),
+                get_associated_token_address(&depositor.pubkey(), &vault_account.vrt_mint),
+                get_associated_token_address(&vault_account.fee_wallet, &vault_account.vrt_mint),
+                spl_token::id(),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_deposit_requires_mint_burn_admin() {
+        let vault = Pubkey::new_unique();
+        let mut vault_account = vault_account();
+        vault_account.mint_burn_admin = Pubkey::new_unique();
+        let mut rpc = MemoryRpc::default();
+        rpc.set(vault, &vault_account);
+        let client = VaultClient::new(rpc);
+
+        let depositor = Keypair::new();
+        let instructions = client
+            .deposit_instructions(&depositor.pubkey(), &vault, 1_000, 0)
+            .await
+            .unwrap();
+        let last = instructions[1].accounts.last().unwrap();
+        assert_eq!(last.pubkey, vault_account.mint_burn_admin);
+        assert!(last.is_signer);
+
+        // The depositor alone can't sign for the mint burn admin
+        assert!(matches!(
+            client.deposit(&depositor, &vault, 1_000, 0).await,
+            Err(JsmClientError::Signer(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_claim_uses_program_fee_wallet() {
+        let vault = Pubkey::new_unique();
+        let vault_account = vault_account();
+        let staker = Pubkey::new_unique();
+        let ticket = Pubkey::new_unique();
+        let mut ticket_account = VaultStakerWithdrawalTicket::zeroed();
+        ticket_account.vault = vault;
+        ticket_account.staker = staker;
+        let mut config = Config::zeroed();
+        config.program_fee_wallet = Pubkey::new_unique();
+
+        let mut rpc = MemoryRpc::default();
+        rpc.set(vault, &vault_account);
+        rpc.set(ticket, &ticket_account);
+        rpc.set(Config::find_program_address(&JITO_VAULT_ID).0, &config);
+        let client = VaultClient::new(rpc);
+
+        let instructions = client
+            .claim_instructions(&Pubkey::new_unique(), &ticket)
+            .await
+            .unwrap();
+        assert_eq!(instructions.len(), 3);
+        let burn_ix = &instructions[2];
+        assert_eq!(burn_ix.accounts[4].pubkey, staker);
+        assert_eq!(
+            burn_ix.accounts[9].pubkey,
+            get_associated_token_address(&config.program_fee_wallet, &vault_account.vrt_mint)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_delegate_initializes_missing_delegation() {
+        let vault = Pubkey::new_unique();
+        let operator = Pubkey::new_unique();
+        let vault_account = vault_account();
+        let mut rpc = MemoryRpc::default();
+        rpc.set(vault, &vault_account);
+        let payer = Pubkey::new_unique();
+        let admin = Pubkey::new_unique();
+
+        let client = VaultClient::new(rpc);
+        let instructions = client
+            .delegate_instructions(&payer, &admin, &vault, &operator, 100)
+            .await
+            .unwrap();
+        assert_eq!(instructions.len(), 2);
+        assert_eq!(
+            instructions[0].accounts[5].pubkey,
+            vault_account.operator_admin
+        );
+
+        let mut rpc = MemoryRpc::default();
+        rpc.set(vault, &vault_account);
+        rpc.set(
+            client.vault_operator_delegation_address(&vault, &operator),
+            &VaultOperatorDelegation::zeroed(),
+        );
+        let client = VaultClient::new(rpc);
+        let instructions = client
+            .delegate_instructions(&payer, &admin, &vault, &operator, 100)
+            .await
+            .unwrap();
+        assert_eq!(instructions.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_connect_ncn_initializes_then_warms_up_once() {
+        let vault = Pubkey::new_unique();
+        let ncn = Pubkey::new_unique();
+        let vault_account = vault_account();
+        let mut rpc = MemoryRpc::default();
+        rpc.set(vault, &vault_account);
+        let payer = Pubkey::new_unique();
+
+        let client = VaultClient::new(rpc);
+        let instructions = client
+            .connect_ncn_instructions(&payer, &vault, &ncn)
+            .await
+            .unwrap();
+        assert_eq!(instructions.len(), 1);
+        assert_eq!(
+            instructions[0].accounts[3].pubkey,
+            NcnVaultTicket::find_program_address(&JITO_RESTAKING_ID, &ncn, &vault).0
+        );
+        assert_eq!(instructions[0].accounts[5].pubkey, vault_account.ncn_admin);
+        assert_eq!(instructions[0].accounts[6].pubkey, payer);
+
+        let mut rpc = MemoryRpc::default();
+        rpc.set(vault, &vault_account);
+        rpc.set(client.config_address(), &config_account());
+        rpc.set(
+            client.vault_ncn_ticket_address(&vault, &ncn),
+            &VaultNcnTicket::zeroed(),
+        );
+        let client = VaultClient::new(rpc);
+        let instructions = client
+            .connect_ncn_instructions(&payer, &vault, &ncn)
+            .await
+            .unwrap();
+        assert_eq!(instructions.len(), 1);
+        assert_eq!(
+            instructions[0],
+            warmup_vault_ncn_ticket(
+                &JITO_VAULT_ID,
+                &client.config_address(),
+                &vault,
+                &ncn,
+                &client.vault_ncn_ticket_address(&vault, &ncn),
+                &vault_account.ncn_admin,
+            )
+        );
+
+        let mut ticket = VaultNcnTicket::zeroed();
+        assert!(ticket.state.activate(1, 10).unwrap());
+        let mut rpc = MemoryRpc::default();
+        rpc.set(vault, &vault_account);
+        rpc.set(client.vault_ncn_ticket_address(&vault, &ncn), &ticket);
+        let client = VaultClient::new(rpc);
+        assert!(client
+            .connect_ncn_instructions(&payer, &vault, &ncn)
+            .await
+            .unwrap()
+            .is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_connect_ncn_fails_while_cooling_down() {
+        let vault = Pubkey::new_unique();
+        let ncn = Pubkey::new_unique();
+        let vault_account = vault_account();
+        let config = config_account();
+        let epoch_length = config.epoch_length();
+        let payer = Pubkey::new_unique();
+
+        // Active from the second epoch, then deactivated
+        let mut ticket = VaultNcnTicket::zeroed();
+        assert!(ticket.state.activate(1, epoch_length).unwrap());
+        assert!(ticket
+            .state
+            .deactivate(2 * epoch_length, epoch_length)
+            .unwrap());
+
+        let client = VaultClient::new(MemoryRpc::default());
+        let ticket_address = client.vault_ncn_ticket_address(&vault, &ncn);
+        let mut rpc = MemoryRpc {
+            slot: 2 * epoch_length + 1,
+            ..MemoryRpc::default()
+        };
+        rpc.set(vault, &vault_account);
+        rpc.set(client.config_address(), &config);
+        rpc.set(ticket_address, &ticket);
+        let client = VaultClient::new(rpc);
+        assert!(matches!(
+            client.connect_ncn_instructions(&payer, &vault, &ncn).await,
+            Err(JsmClientError::TicketCoolingDown(address)) if address == ticket_address
+        ));
+
+        // Inactive again once the cooldown epoch is over
+        let mut rpc = MemoryRpc {
+            slot: 4 * epoch_length,
+            ..MemoryRpc::default()
+        };
+        rpc.set(vault, &vault_account);
+        rpc.set(client.config_address(), &config);
+        rpc.set(ticket_address, &ticket);
+        let client = VaultClient::new(rpc);
+        let instructions = client
+            .connect_ncn_instructions(&payer, &vault, &ncn)
+            .await
+            .unwrap();
+        assert_eq!(instructions.len(), 1);
+        assert_eq!(
+            instructions[0].data,
+            warmup_vault_ncn_ticket(
+                &JITO_VAULT_ID,
+                &client.config_address(),
+                &vault,
+                &ncn,
+                &ticket_address,
+                &vault_account.ncn_admin,
+            )
+            .data
+        );
+    }
+}
@@ -1,8 +1,5 @@
-//! This code was AUTOGENERATED using the kinobi library.
-//! Please DO NOT EDIT THIS FILE, instead use visitors
-//! to add features, then rerun kinobi to update it.
-//!
-//! <https://github.com/kinobi-so/kinobi>
+//! This code was AUTOGENERATED by `jito-shank-cli generate-clients` from the program IDL.
+//! Please DO NOT EDIT THIS FILE, instead update the program and rerun the generator.
 
 use borsh::{BorshDeserialize, BorshSerialize};
 use solana_program::pubkey::Pubkey;
@@ -61,8 +58,9 @@ pub struct Ncn {
     pub vault_count: u64,
     pub slasher_count: u64,
     pub bump: u8,
+    pub paused_categories: u16,
     #[cfg_attr(feature = "serde", serde(with = "serde_with::As::<serde_with::Bytes>"))]
-    pub reserved: [u8; 263],
+    pub reserved: [u8; 261],
 }
 
 impl Ncn {
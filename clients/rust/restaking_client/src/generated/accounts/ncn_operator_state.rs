@@ -1,8 +1,5 @@
-//! This code was AUTOGENERATED using the kinobi library.
-//! Please DO NOT EDIT THIS FILE, instead use visitors
-//! to add features, then rerun kinobi to update it.
-//!
-//! <https://github.com/kinobi-so/kinobi>
+//! This code was AUTOGENERATED by `jito-shank-cli generate-clients` from the program IDL.
+//! Please DO NOT EDIT THIS FILE, instead update the program and rerun the generator.
 
 use borsh::{BorshDeserialize, BorshSerialize};
 use solana_program::pubkey::Pubkey;
@@ -27,8 +24,11 @@ pub struct NcnOperatorState {
     pub ncn_opt_in_state: SlotToggle,
     pub operator_opt_in_state: SlotToggle,
     pub bump: u8,
+    pub is_jailed: bool,
+    pub jail_reason: u16,
+    pub jailed_slot: u64,
     #[cfg_attr(feature = "serde", serde(with = "serde_with::As::<serde_with::Bytes>"))]
-    pub reserved: [u8; 263],
+    pub reserved: [u8; 252],
 }
 
 impl NcnOperatorState {
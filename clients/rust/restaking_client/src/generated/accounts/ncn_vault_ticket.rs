@@ -1,8 +1,5 @@
-//! This code was AUTOGENERATED using the kinobi library.
-//! Please DO NOT EDIT THIS FILE, instead use visitors
-//! to add features, then rerun kinobi to update it.
-//!
-//! <https://github.com/kinobi-so/kinobi>
+//! This code was AUTOGENERATED by `jito-shank-cli generate-clients` from the program IDL.
+//! Please DO NOT EDIT THIS FILE, instead update the program and rerun the generator.
 
 use borsh::{BorshDeserialize, BorshSerialize};
 use solana_program::pubkey::Pubkey;
@@ -1,8 +1,5 @@
-//! This code was AUTOGENERATED using the kinobi library.
-//! Please DO NOT EDIT THIS FILE, instead use visitors
-//! to add features, then rerun kinobi to update it.
-//!
-//! <https://github.com/kinobi-so/kinobi>
+//! This code was AUTOGENERATED by `jito-shank-cli generate-clients` from the program IDL.
+//! Please DO NOT EDIT THIS FILE, instead update the program and rerun the generator.
 
 use num_derive::FromPrimitive;
 use thiserror::Error;
@@ -45,6 +42,15 @@ pub enum JitoRestakingError {
     /// 1010 - NcnVaultTicketFailedWarmup
     #[error("NcnVaultTicketFailedWarmup")]
     NcnVaultTicketFailedWarmup = 0x3F2,
+    /// 1011 - NcnOperatorAlreadyJailed
+    #[error("NcnOperatorAlreadyJailed")]
+    NcnOperatorAlreadyJailed = 0x3F3,
+    /// 1012 - NcnOperatorNotJailed
+    #[error("NcnOperatorNotJailed")]
+    NcnOperatorNotJailed = 0x3F4,
+    /// 1013 - NcnPaused
+    #[error("NcnPaused")]
+    NcnPaused = 0x3F5,
     /// 2000 - OperatorNcnAdminInvalid
     #[error("OperatorNcnAdminInvalid")]
     OperatorNcnAdminInvalid = 0x7D0,
@@ -90,6 +96,12 @@ pub enum JitoRestakingError {
     /// 2014 - ConfigAdminInvalid
     #[error("ConfigAdminInvalid")]
     ConfigAdminInvalid = 0x7DE,
+    /// 2015 - OperatorPaused
+    #[error("OperatorPaused")]
+    OperatorPaused = 0x7DF,
+    /// 2016 - ConfigPaused
+    #[error("ConfigPaused")]
+    ConfigPaused = 0x7E0,
     /// 3000 - ArithmeticOverflow
     #[error("ArithmeticOverflow")]
     ArithmeticOverflow = 0xBB8,
@@ -1,8 +1,5 @@
-//! This code was AUTOGENERATED using the kinobi library.
-//! Please DO NOT EDIT THIS FILE, instead use visitors
-//! to add features, then rerun kinobi to update it.
-//!
-//! <https://github.com/kinobi-so/kinobi>
+//! This code was AUTOGENERATED by `jito-shank-cli generate-clients` from the program IDL.
+//! Please DO NOT EDIT THIS FILE, instead update the program and rerun the generator.
 
 pub(crate) mod r#cooldown_ncn_vault_slasher_ticket;
 pub(crate) mod r#cooldown_ncn_vault_ticket;
@@ -14,18 +11,24 @@ pub(crate) mod r#initialize_ncn_vault_slasher_ticket;
 pub(crate) mod r#initialize_ncn_vault_ticket;
 pub(crate) mod r#initialize_operator;
 pub(crate) mod r#initialize_operator_vault_ticket;
+pub(crate) mod r#jail_operator;
+pub(crate) mod r#migrate_account;
 pub(crate) mod r#ncn_cooldown_operator;
 pub(crate) mod r#ncn_delegate_token_account;
 pub(crate) mod r#ncn_set_admin;
+pub(crate) mod r#ncn_set_paused_categories;
 pub(crate) mod r#ncn_set_secondary_admin;
 pub(crate) mod r#ncn_warmup_operator;
 pub(crate) mod r#operator_cooldown_ncn;
 pub(crate) mod r#operator_delegate_token_account;
 pub(crate) mod r#operator_set_admin;
 pub(crate) mod r#operator_set_fee;
+pub(crate) mod r#operator_set_paused_categories;
 pub(crate) mod r#operator_set_secondary_admin;
 pub(crate) mod r#operator_warmup_ncn;
 pub(crate) mod r#set_config_admin;
+pub(crate) mod r#set_config_paused_categories;
+pub(crate) mod r#unjail_operator;
 pub(crate) mod r#warmup_ncn_vault_slasher_ticket;
 pub(crate) mod r#warmup_ncn_vault_ticket;
 pub(crate) mod r#warmup_operator_vault_ticket;
@@ -35,10 +38,12 @@ pub use self::{
     r#cooldown_operator_vault_ticket::*, r#initialize_config::*, r#initialize_ncn::*,
     r#initialize_ncn_operator_state::*, r#initialize_ncn_vault_slasher_ticket::*,
     r#initialize_ncn_vault_ticket::*, r#initialize_operator::*,
-    r#initialize_operator_vault_ticket::*, r#ncn_cooldown_operator::*,
-    r#ncn_delegate_token_account::*, r#ncn_set_admin::*, r#ncn_set_secondary_admin::*,
-    r#ncn_warmup_operator::*, r#operator_cooldown_ncn::*, r#operator_delegate_token_account::*,
-    r#operator_set_admin::*, r#operator_set_fee::*, r#operator_set_secondary_admin::*,
-    r#operator_warmup_ncn::*, r#set_config_admin::*, r#warmup_ncn_vault_slasher_ticket::*,
-    r#warmup_ncn_vault_ticket::*, r#warmup_operator_vault_ticket::*,
+    r#initialize_operator_vault_ticket::*, r#jail_operator::*, r#migrate_account::*,
+    r#ncn_cooldown_operator::*, r#ncn_delegate_token_account::*, r#ncn_set_admin::*,
+    r#ncn_set_paused_categories::*, r#ncn_set_secondary_admin::*, r#ncn_warmup_operator::*,
+    r#operator_cooldown_ncn::*, r#operator_delegate_token_account::*, r#operator_set_admin::*,
+    r#operator_set_fee::*, r#operator_set_paused_categories::*, r#operator_set_secondary_admin::*,
+    r#operator_warmup_ncn::*, r#set_config_admin::*, r#set_config_paused_categories::*,
+    r#unjail_operator::*, r#warmup_ncn_vault_slasher_ticket::*, r#warmup_ncn_vault_ticket::*,
+    r#warmup_operator_vault_ticket::*,
 };
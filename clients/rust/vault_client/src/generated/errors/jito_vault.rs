@@ -1,8 +1,5 @@
-//! This code was AUTOGENERATED using the kinobi library.
-//! Please DO NOT EDIT THIS FILE, instead use visitors
-//! to add features, then rerun kinobi to update it.
-//!
-//! <https://github.com/kinobi-so/kinobi>
+//! This code was AUTOGENERATED by `jito-shank-cli generate-clients` from the program IDL.
+//! Please DO NOT EDIT THIS FILE, instead update the program and rerun the generator.
 
 use num_derive::FromPrimitive;
 use thiserror::Error;
@@ -192,6 +189,36 @@ pub enum JitoVaultError {
     /// 1059 - NonZeroAdditionalAssetsNeededForWithdrawalAtEndOfUpdate
     #[error("NonZeroAdditionalAssetsNeededForWithdrawalAtEndOfUpdate")]
     NonZeroAdditionalAssetsNeededForWithdrawalAtEndOfUpdate = 0x423,
+    /// 1060 - OperatorJailed
+    #[error("OperatorJailed")]
+    OperatorJailed = 0x424,
+    /// 1061 - VaultAlreadyNested
+    #[error("VaultAlreadyNested")]
+    VaultAlreadyNested = 0x425,
+    /// 1062 - VaultNestingCycle
+    #[error("VaultNestingCycle")]
+    VaultNestingCycle = 0x426,
+    /// 1063 - VaultNestingTooDeep
+    #[error("VaultNestingTooDeep")]
+    VaultNestingTooDeep = 0x427,
+    /// 1064 - VaultChildVaultInvalid
+    #[error("VaultChildVaultInvalid")]
+    VaultChildVaultInvalid = 0x428,
+    /// 1065 - VaultInEmergencyExit
+    #[error("VaultInEmergencyExit")]
+    VaultInEmergencyExit = 0x429,
+    /// 1066 - VaultCategoryPaused
+    #[error("VaultCategoryPaused")]
+    VaultCategoryPaused = 0x42A,
+    /// 1067 - VaultStakerWithdrawalTicketInvalidAutoClaimDelegate
+    #[error("VaultStakerWithdrawalTicketInvalidAutoClaimDelegate")]
+    VaultStakerWithdrawalTicketInvalidAutoClaimDelegate = 0x42B,
+    /// 1068 - VaultStakerWithdrawalTicketAutoClaimTipTooHigh
+    #[error("VaultStakerWithdrawalTicketAutoClaimTipTooHigh")]
+    VaultStakerWithdrawalTicketAutoClaimTipTooHigh = 0x42C,
+    /// 1069 - VaultStakerWithdrawalTicketInvalidVault
+    #[error("VaultStakerWithdrawalTicketInvalidVault")]
+    VaultStakerWithdrawalTicketInvalidVault = 0x42D,
     /// 3000 - ArithmeticOverflow
     #[error("ArithmeticOverflow")]
     ArithmeticOverflow = 0xBB8,
@@ -1,10 +1,8 @@
-//! This code was AUTOGENERATED using the kinobi library.
-//! Please DO NOT EDIT THIS FILE, instead use visitors
-//! to add features, then rerun kinobi to update it.
-//!
-//! <https://github.com/kinobi-so/kinobi>
+//! This code was AUTOGENERATED by `jito-shank-cli generate-clients` from the program IDL.
+//! Please DO NOT EDIT THIS FILE, instead update the program and rerun the generator.
 
 pub(crate) mod r#add_delegation;
+pub(crate) mod r#auto_claim_withdrawal_ticket;
 pub(crate) mod r#burn_withdrawal_ticket;
 pub(crate) mod r#change_withdrawal_ticket_owner;
 pub(crate) mod r#close_vault_update_state_tracker;
@@ -12,6 +10,7 @@ pub(crate) mod r#cooldown_delegation;
 pub(crate) mod r#cooldown_vault_ncn_slasher_ticket;
 pub(crate) mod r#cooldown_vault_ncn_ticket;
 pub(crate) mod r#crank_vault_update_state_tracker;
+pub(crate) mod r#crank_vault_update_state_tracker_many;
 pub(crate) mod r#create_token_metadata;
 pub(crate) mod r#delegate_token_account;
 pub(crate) mod r#enqueue_withdrawal;
@@ -23,12 +22,17 @@ pub(crate) mod r#initialize_vault_ncn_ticket;
 pub(crate) mod r#initialize_vault_operator_delegation;
 pub(crate) mod r#initialize_vault_update_state_tracker;
 pub(crate) mod r#initialize_vault_with_mint;
+pub(crate) mod r#migrate_account;
 pub(crate) mod r#mint_to;
 pub(crate) mod r#set_admin;
+pub(crate) mod r#set_auto_claim_delegate;
+pub(crate) mod r#set_child_vault;
 pub(crate) mod r#set_config_admin;
 pub(crate) mod r#set_deposit_capacity;
 pub(crate) mod r#set_fees;
+pub(crate) mod r#set_is_emergency_exit;
 pub(crate) mod r#set_is_paused;
+pub(crate) mod r#set_paused_categories;
 pub(crate) mod r#set_program_fee;
 pub(crate) mod r#set_program_fee_wallet;
 pub(crate) mod r#set_secondary_admin;
@@ -38,16 +42,19 @@ pub(crate) mod r#warmup_vault_ncn_slasher_ticket;
 pub(crate) mod r#warmup_vault_ncn_ticket;
 
 pub use self::{
-    r#add_delegation::*, r#burn_withdrawal_ticket::*, r#change_withdrawal_ticket_owner::*,
-    r#close_vault_update_state_tracker::*, r#cooldown_delegation::*,
-    r#cooldown_vault_ncn_slasher_ticket::*, r#cooldown_vault_ncn_ticket::*,
-    r#crank_vault_update_state_tracker::*, r#create_token_metadata::*, r#delegate_token_account::*,
-    r#enqueue_withdrawal::*, r#initialize_config::*, r#initialize_vault::*,
-    r#initialize_vault_ncn_slasher_operator_ticket::*, r#initialize_vault_ncn_slasher_ticket::*,
-    r#initialize_vault_ncn_ticket::*, r#initialize_vault_operator_delegation::*,
-    r#initialize_vault_update_state_tracker::*, r#initialize_vault_with_mint::*, r#mint_to::*,
-    r#set_admin::*, r#set_config_admin::*, r#set_deposit_capacity::*, r#set_fees::*,
-    r#set_is_paused::*, r#set_program_fee::*, r#set_program_fee_wallet::*,
+    r#add_delegation::*, r#auto_claim_withdrawal_ticket::*, r#burn_withdrawal_ticket::*,
+    r#change_withdrawal_ticket_owner::*, r#close_vault_update_state_tracker::*,
+    r#cooldown_delegation::*, r#cooldown_vault_ncn_slasher_ticket::*,
+    r#cooldown_vault_ncn_ticket::*, r#crank_vault_update_state_tracker::*,
+    r#crank_vault_update_state_tracker_many::*, r#create_token_metadata::*,
+    r#delegate_token_account::*, r#enqueue_withdrawal::*, r#initialize_config::*,
+    r#initialize_vault::*, r#initialize_vault_ncn_slasher_operator_ticket::*,
+    r#initialize_vault_ncn_slasher_ticket::*, r#initialize_vault_ncn_ticket::*,
+    r#initialize_vault_operator_delegation::*, r#initialize_vault_update_state_tracker::*,
+    r#initialize_vault_with_mint::*, r#migrate_account::*, r#mint_to::*, r#set_admin::*,
+    r#set_auto_claim_delegate::*, r#set_child_vault::*, r#set_config_admin::*,
+    r#set_deposit_capacity::*, r#set_fees::*, r#set_is_emergency_exit::*, r#set_is_paused::*,
+    r#set_paused_categories::*, r#set_program_fee::*, r#set_program_fee_wallet::*,
     r#set_secondary_admin::*, r#update_token_metadata::*, r#update_vault_balance::*,
     r#warmup_vault_ncn_slasher_ticket::*, r#warmup_vault_ncn_ticket::*,
 };
@@ -1,8 +1,5 @@
-//! This code was AUTOGENERATED using the kinobi library.
-//! Please DO NOT EDIT THIS FILE, instead use visitors
-//! to add features, then rerun kinobi to update it.
-//!
-//! <https://github.com/kinobi-so/kinobi>
+//! This code was AUTOGENERATED by `jito-shank-cli generate-clients` from the program IDL.
+//! Please DO NOT EDIT THIS FILE, instead update the program and rerun the generator.
 
 use borsh::{BorshDeserialize, BorshSerialize};
 
@@ -19,6 +16,8 @@ pub struct UpdateVaultBalance {
     pub vault_fee_token_account: solana_program::pubkey::Pubkey,
 
     pub token_program: solana_program::pubkey::Pubkey,
+    /// Required for nested vaults
+    pub child_vault: Option<solana_program::pubkey::Pubkey>,
 }
 
 impl UpdateVaultBalance {
@@ -30,7 +29,7 @@ impl UpdateVaultBalance {
         &self,
         remaining_accounts: &[solana_program::instruction::AccountMeta],
     ) -> solana_program::instruction::Instruction {
-        let mut accounts = Vec::with_capacity(6 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(7 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             self.config,
             false,
@@ -54,6 +53,17 @@ impl UpdateVaultBalance {
             self.token_program,
             false,
         ));
+        if let Some(child_vault) = self.child_vault {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                child_vault,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::JITO_VAULT_ID,
+                false,
+            ));
+        }
         accounts.extend_from_slice(remaining_accounts);
         let data = UpdateVaultBalanceInstructionData::new()
             .try_to_vec()
@@ -94,6 +104,7 @@ impl Default for UpdateVaultBalanceInstructionData {
 ///   3. `[writable]` vrt_mint
 ///   4. `[writable]` vault_fee_token_account
 ///   5. `[optional]` token_program (default to `TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA`)
+///   6. `[optional]` child_vault
 #[derive(Clone, Debug, Default)]
 pub struct UpdateVaultBalanceBuilder {
     config: Option<solana_program::pubkey::Pubkey>,
@@ -102,6 +113,7 @@ pub struct UpdateVaultBalanceBuilder {
     vrt_mint: Option<solana_program::pubkey::Pubkey>,
     vault_fee_token_account: Option<solana_program::pubkey::Pubkey>,
     token_program: Option<solana_program::pubkey::Pubkey>,
+    child_vault: Option<solana_program::pubkey::Pubkey>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
 
@@ -146,6 +158,16 @@ impl UpdateVaultBalanceBuilder {
         self.token_program = Some(token_program);
         self
     }
+    /// `[optional account]`
+    /// Required for nested vaults
+    #[inline(always)]
+    pub fn child_vault(
+        &mut self,
+        child_vault: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.child_vault = child_vault;
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -179,6 +201,7 @@ impl UpdateVaultBalanceBuilder {
             token_program: self.token_program.unwrap_or(solana_program::pubkey!(
                 "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
             )),
+            child_vault: self.child_vault,
         };
 
         accounts.instruction_with_remaining_accounts(&self.__remaining_accounts)
@@ -198,6 +221,8 @@ pub struct UpdateVaultBalanceCpiAccounts<'a, 'b> {
     pub vault_fee_token_account: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub token_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Required for nested vaults
+    pub child_vault: Option<&'b solana_program::account_info::AccountInfo<'a>>,
 }
 
 /// `update_vault_balance` CPI instruction.
@@ -216,6 +241,8 @@ pub struct UpdateVaultBalanceCpi<'a, 'b> {
     pub vault_fee_token_account: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub token_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Required for nested vaults
+    pub child_vault: Option<&'b solana_program::account_info::AccountInfo<'a>>,
 }
 
 impl<'a, 'b> UpdateVaultBalanceCpi<'a, 'b> {
@@ -231,6 +258,7 @@ impl<'a, 'b> UpdateVaultBalanceCpi<'a, 'b> {
             vrt_mint: accounts.vrt_mint,
             vault_fee_token_account: accounts.vault_fee_token_account,
             token_program: accounts.token_program,
+            child_vault: accounts.child_vault,
         }
     }
     #[inline(always)]
@@ -266,7 +294,7 @@ impl<'a, 'b> UpdateVaultBalanceCpi<'a, 'b> {
             bool,
         )],
     ) -> solana_program::entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(6 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(7 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             *self.config.key,
             false,
@@ -291,6 +319,17 @@ impl<'a, 'b> UpdateVaultBalanceCpi<'a, 'b> {
             *self.token_program.key,
             false,
         ));
+        if let Some(child_vault) = self.child_vault {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *child_vault.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::JITO_VAULT_ID,
+                false,
+            ));
+        }
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_program::instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -307,7 +346,7 @@ impl<'a, 'b> UpdateVaultBalanceCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(6 + 1 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(7 + 1 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.config.clone());
         account_infos.push(self.vault.clone());
@@ -315,6 +354,9 @@ impl<'a, 'b> UpdateVaultBalanceCpi<'a, 'b> {
         account_infos.push(self.vrt_mint.clone());
         account_infos.push(self.vault_fee_token_account.clone());
         account_infos.push(self.token_program.clone());
+        if let Some(child_vault) = self.child_vault {
+            account_infos.push(child_vault.clone());
+        }
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -337,6 +379,7 @@ impl<'a, 'b> UpdateVaultBalanceCpi<'a, 'b> {
 ///   3. `[writable]` vrt_mint
 ///   4. `[writable]` vault_fee_token_account
 ///   5. `[]` token_program
+///   6. `[optional]` child_vault
 #[derive(Clone, Debug)]
 pub struct UpdateVaultBalanceCpiBuilder<'a, 'b> {
     instruction: Box<UpdateVaultBalanceCpiBuilderInstruction<'a, 'b>>,
@@ -352,6 +395,7 @@ impl<'a, 'b> UpdateVaultBalanceCpiBuilder<'a, 'b> {
             vrt_mint: None,
             vault_fee_token_account: None,
             token_program: None,
+            child_vault: None,
             __remaining_accounts: Vec::new(),
         });
         Self { instruction }
@@ -401,6 +445,16 @@ impl<'a, 'b> UpdateVaultBalanceCpiBuilder<'a, 'b> {
         self.instruction.token_program = Some(token_program);
         self
     }
+    /// `[optional account]`
+    /// Required for nested vaults
+    #[inline(always)]
+    pub fn child_vault(
+        &mut self,
+        child_vault: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.child_vault = child_vault;
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -465,6 +519,8 @@ impl<'a, 'b> UpdateVaultBalanceCpiBuilder<'a, 'b> {
                 .instruction
                 .token_program
                 .expect("token_program is not set"),
+
+            child_vault: self.instruction.child_vault,
         };
         instruction.invoke_signed_with_remaining_accounts(
             signers_seeds,
@@ -482,6 +538,7 @@ struct UpdateVaultBalanceCpiBuilderInstruction<'a, 'b> {
     vrt_mint: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     vault_fee_token_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    child_vault: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
         &'b solana_program::account_info::AccountInfo<'a>,
@@ -0,0 +1,276 @@
+//! Compact records of failed account checks.
+//!
+//! Error codes say what went wrong but not where: `InvalidAccountData` can come from any of a
+//! dozen checks on any account of an instruction. Before returning an error, loaders and account
+//! check functions log one line naming the [`Check`] that failed and the account it failed on:
+//!
+//! ```text
+//! jsm-check:07:<pubkey>
+//! ```
+//!
+//! The code is the check as two hex digits. The pubkey is left out when the account wasn't passed
+//! at all. Clients parse the line with [`FailedCheck::parse_log`] and look the pubkey up in the
+//! instruction's accounts to get its index. State checks such as `check_is_paused` don't log a
+//! record; their error codes already name the check and they aren't tied to an account. The
+//! exception is [`Check::EmergencyExit`], logged on the vault when an admin tries to pause it in a
+//! way emergency exit doesn't allow.
+
+use solana_program::{msg, pubkey::Pubkey};
+
+/// Prefix of every failed check record in the program logs
+pub const LOG_PREFIX: &str = "jsm-check:";
+
+/// An account check. The values are part of the log format, so new checks are only ever appended.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum Check {
+    /// The account must sign the transaction
+    Signer = 0x01,
+    /// The account must be writable
+    Writable = 0x02,
+    /// The account must be owned by the expected program
+    Owner = 0x03,
+    /// The account must hold data
+    Initialized = 0x04,
+    /// The account must not hold any data
+    Uninitialized = 0x05,
+    /// The account data must start with the expected discriminator
+    Discriminator = 0x06,
+    /// The account must be at the expected PDA or associated token address
+    Address = 0x07,
+    /// The account must be the expected program
+    ProgramId = 0x08,
+    /// The token account must be owned by the expected wallet
+    TokenOwner = 0x09,
+    /// The token account must hold the expected mint
+    TokenMint = 0x0a,
+    /// The vault must not be in emergency exit
+    EmergencyExit = 0x0b,
+
+    // The account must match a field stored in a program account
+    /// `admin`
+    Admin = 0x20,
+    /// `delegation_admin`
+    DelegationAdmin = 0x21,
+    /// `operator_admin`
+    OperatorAdmin = 0x22,
+    /// `ncn_admin`
+    NcnAdmin = 0x23,
+    /// `slasher_admin`
+    SlasherAdmin = 0x24,
+    /// `capacity_admin`
+    CapacityAdmin = 0x25,
+    /// `fee_admin`
+    FeeAdmin = 0x26,
+    /// `delegate_asset_admin`
+    DelegateAssetAdmin = 0x27,
+    /// `mint_burn_admin`
+    MintBurnAdmin = 0x28,
+    /// `vrt_mint`
+    VrtMint = 0x29,
+    /// `staker`
+    Staker = 0x2a,
+    /// `auto_claim_delegate`
+    AutoClaimDelegate = 0x2b,
+    /// `delegate_admin`
+    DelegateAdmin = 0x2c,
+    /// `vault_admin`
+    VaultAdmin = 0x2d,
+    /// `child_vault`
+    ChildVault = 0x2e,
+    /// `vault`
+    Vault = 0x2f,
+}
+
+impl Check {
+    /// Every check
+    pub const ALL: [Self; 27] = [
+        Self::Signer,
+        Self::Writable,
+        Self::Owner,
+        Self::Initialized,
+        Self::Uninitialized,
+        Self::Discriminator,
+        Self::Address,
+        Self::ProgramId,
+        Self::TokenOwner,
+        Self::TokenMint,
+        Self::EmergencyExit,
+        Self::Admin,
+        Self::DelegationAdmin,
+        Self::OperatorAdmin,
+        Self::NcnAdmin,
+        Self::SlasherAdmin,
+        Self::CapacityAdmin,
+        Self::FeeAdmin,
+        Self::DelegateAssetAdmin,
+        Self::MintBurnAdmin,
+        Self::VrtMint,
+        Self::Staker,
+        Self::AutoClaimDelegate,
+        Self::DelegateAdmin,
+        Self::VaultAdmin,
+        Self::ChildVault,
+        Self::Vault,
+    ];
+
+    /// The stored field the account must match, for checks against a program account
+    pub const fn field(self) -> Option<&'static str> {
+        match self {
+            Self::Admin => Some("admin"),
+            Self::DelegationAdmin => Some("delegation_admin"),
+            Self::OperatorAdmin => Some("operator_admin"),
+            Self::NcnAdmin => Some("ncn_admin"),
+            Self::SlasherAdmin => Some("slasher_admin"),
+            Self::CapacityAdmin => Some("capacity_admin"),
+            Self::FeeAdmin => Some("fee_admin"),
+            Self::DelegateAssetAdmin => Some("delegate_asset_admin"),
+            Self::MintBurnAdmin => Some("mint_burn_admin"),
+            Self::VrtMint => Some("vrt_mint"),
+            Self::Staker => Some("staker"),
+            Self::AutoClaimDelegate => Some("auto_claim_delegate"),
+            Self::DelegateAdmin => Some("delegate_admin"),
+            Self::VaultAdmin => Some("vault_admin"),
+            Self::ChildVault => Some("child_vault"),
+            Self::Vault => Some("vault"),
+            _ => None,
+        }
+    }
+
+    /// What the account failed to satisfy, e.g. "must be writable"
+    pub const fn requirement(self) -> &'static str {
+        match self {
+            Self::Signer => "must sign the transaction",
+            Self::Writable => "must be writable",
+            Self::Owner => "is owned by the wrong program",
+            Self::Initialized => "has no data",
+            Self::Uninitialized => "must not hold any data",
+            Self::Discriminator => "holds a different account type",
+            Self::Address => "is not at the expected address",
+            Self::ProgramId => "is not the expected program",
+            Self::TokenOwner => "is a token account with the wrong owner",
+            Self::TokenMint => "is a token account for the wrong mint",
+            Self::EmergencyExit => "is a vault in emergency exit",
+            _ => "does not match the stored field",
+        }
+    }
+
+    /// Logs that this check failed on `account`
+    #[cold]
+    pub fn log_failure(self, account: &Pubkey) {
+        msg!("{}{:02x}:{}", LOG_PREFIX, self as u8, account);
+    }
+
+    /// Logs that this check failed because its account wasn't passed to the instruction
+    #[cold]
+    pub fn log_missing(self) {
+        msg!("{}{:02x}", LOG_PREFIX, self as u8);
+    }
+}
+
+impl TryFrom<u8> for Check {
+    type Error = u8;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        Self::ALL
+            .into_iter()
+            .find(|check| *check as u8 == value)
+            .ok_or(value)
+    }
+}
+
+/// A failed check decoded from the program logs
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FailedCheck {
+    pub check: Check,
+    /// The account that failed the check, `None` if it wasn't passed
+    pub account: Option<Pubkey>,
+}
+
+impl FailedCheck {
+    /// Parses a failed check record, with or without the runtime's `Program log: ` prefix.
+    /// Returns `None` for any other log line.
+    pub fn parse_log(line: &str) -> Option<Self> {
+        let line = line.strip_prefix("Program log: ").unwrap_or(line);
+        let record = line.strip_prefix(LOG_PREFIX)?;
+        let (code, account) = match record.split_once(':') {
+            Some((code, account)) => (code, Some(account.parse().ok()?)),
+            None => (record, None),
+        };
+        if code.len() != 2 {
+            return None;
+        }
+        let check = u8::from_str_radix(code, 16).ok()?.try_into().ok()?;
+        Some(Self { check, account })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use solana_program::pubkey::Pubkey;
+
+    use super::{Check, FailedCheck, LOG_PREFIX};
+
+    #[test]
+    fn test_check_codes_round_trip() {
+        let codes: HashSet<u8> = Check::ALL.iter().map(|check| *check as u8).collect();
+        assert_eq!(codes.len(), Check::ALL.len());
+
+        for check in Check::ALL {
+            assert_eq!(Check::try_from(check as u8), Ok(check));
+        }
+        assert_eq!(Check::try_from(0), Err(0));
+        assert_eq!(Check::try_from(0xff), Err(0xff));
+    }
+
+    #[test]
+    fn test_only_field_checks_have_fields() {
+        for check in Check::ALL {
+            assert_eq!(check.field().is_some(), check as u8 >= Check::Admin as u8);
+        }
+    }
+
+    #[test]
+    fn test_parse_log() {
+        let account = Pubkey::new_unique();
+
+        assert_eq!(
+            FailedCheck::parse_log(&format!("Program log: {}07:{}", LOG_PREFIX, account)),
+            Some(FailedCheck {
+                check: Check::Address,
+                account: Some(account),
+            })
+        );
+        assert_eq!(
+            FailedCheck::parse_log(&format!("{}28", LOG_PREFIX)),
+            Some(FailedCheck {
+                check: Check::MintBurnAdmin,
+                account: None,
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_log_rejects_other_lines() {
+        let account = Pubkey::new_unique();
+
+        assert_eq!(
+            FailedCheck::parse_log("Program log: Account is not writable"),
+            None
+        );
+        assert_eq!(
+            FailedCheck::parse_log(&format!("{}ff:{}", LOG_PREFIX, account)),
+            None
+        );
+        assert_eq!(
+            FailedCheck::parse_log(&format!("{}7:{}", LOG_PREFIX, account)),
+            None
+        );
+        assert_eq!(
+            FailedCheck::parse_log(&format!("{}07:not-a-pubkey", LOG_PREFIX)),
+            None
+        );
+    }
+}
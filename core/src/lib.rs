@@ -1,7 +1,9 @@
 use error::CoreError;
+use jito_bytemuck::Migrate;
 use solana_program::{
     account_info::AccountInfo,
     entrypoint::ProgramResult,
+    msg,
     program::{invoke, invoke_signed},
     program_error::ProgramError,
     pubkey::Pubkey,
@@ -9,8 +11,10 @@ use solana_program::{
     system_instruction,
 };
 
+pub mod diagnostic;
 pub mod error;
 pub mod loader;
+pub mod pause;
 pub mod slot_toggle;
 
 /// Creates a new account or initializes an existing account
@@ -130,6 +134,62 @@ pub fn realloc<'a, 'info>(
     Ok(())
 }
 
+/// Rewrites an account created under an older layout version in the current layout of `T`,
+/// resizing it to fit the current layout first
+/// # Arguments
+/// * `account` - The account to migrate, owned by the calling program
+/// * `payer` - The account that pays for any additional rent
+/// * `rent` - The rent sysvar
+/// # Returns
+/// * `ProgramResult` - The result of the operation
+pub fn migrate_account<'a, 'info, T: Migrate>(
+    account: &'a AccountInfo<'info>,
+    payer: &'a AccountInfo<'info>,
+    rent: &Rent,
+) -> ProgramResult {
+    let (version, previous) = {
+        let data = account.try_borrow_data()?;
+        if data.len() < 8 || data[0] != T::DISCRIMINATOR {
+            msg!("Account is not a {}", std::any::type_name::<T>());
+            return Err(ProgramError::InvalidAccountData);
+        }
+        let version = data[1];
+        if version == T::VERSION {
+            msg!("Account is already at version {}", T::VERSION);
+            return Err(ProgramError::InvalidAccountData);
+        }
+        if version > T::VERSION {
+            msg!(
+                "Account version is unknown; expected at most {}, got {}",
+                T::VERSION,
+                version
+            );
+            return Err(ProgramError::InvalidAccountData);
+        }
+        (version, data[8..].to_vec())
+    };
+
+    let new_size = 8_usize
+        .checked_add(std::mem::size_of::<T>())
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    if account.data_len() != new_size {
+        realloc(account, new_size, payer, rent)?;
+    }
+
+    let mut data = account.try_borrow_mut_data()?;
+    data.fill(0);
+    T::write_header(&mut data)?;
+    let migrated = T::try_from_slice_unchecked_mut(&mut data)?;
+    T::migrate(version, &previous, migrated)?;
+
+    msg!(
+        "Migrated account from version {} to {}",
+        version,
+        T::VERSION
+    );
+    Ok(())
+}
+
 pub fn get_epoch(slot: u64, epoch_length: u64) -> Result<u64, CoreError> {
     let epoch = slot
         .checked_div(epoch_length)
@@ -5,6 +5,8 @@ use solana_program::{
 use spl_associated_token_account::get_associated_token_address;
 use spl_token_2022::extension::StateWithExtensions;
 
+use crate::diagnostic::Check;
+
 /// Loads the account as a signer, returning an error if it is not or if it is not writable while
 /// expected to be.
 ///
@@ -17,10 +19,12 @@ use spl_token_2022::extension::StateWithExtensions;
 pub fn load_signer(info: &AccountInfo, expect_writable: bool) -> Result<(), ProgramError> {
     if !info.is_signer {
         msg!("Account is not a signer");
+        Check::Signer.log_failure(info.key);
         return Err(ProgramError::MissingRequiredSignature);
     }
     if expect_writable && !info.is_writable {
         msg!("Signer is not writable");
+        Check::Writable.log_failure(info.key);
         return Err(ProgramError::InvalidAccountData);
     }
 
@@ -37,6 +41,7 @@ pub fn load_signer(info: &AccountInfo, expect_writable: bool) -> Result<(), Prog
 pub fn load_system_program(info: &AccountInfo) -> Result<(), ProgramError> {
     if info.key.ne(&system_program::id()) {
         msg!("Account is not the system program");
+        Check::ProgramId.log_failure(info.key);
         return Err(ProgramError::IncorrectProgramId);
     }
 
@@ -53,6 +58,7 @@ pub fn load_system_program(info: &AccountInfo) -> Result<(), ProgramError> {
 pub fn load_associated_token_account_program(info: &AccountInfo) -> Result<(), ProgramError> {
     if info.key.ne(&spl_associated_token_account::id()) {
         msg!("Account is not the spl associated token program");
+        Check::ProgramId.log_failure(info.key);
         return Err(ProgramError::IncorrectProgramId);
     }
 
@@ -69,6 +75,7 @@ pub fn load_associated_token_account_program(info: &AccountInfo) -> Result<(), P
 pub fn load_token_program(info: &AccountInfo) -> Result<(), ProgramError> {
     if info.key.ne(&spl_token::id()) {
         msg!("Account is not the spl token program");
+        Check::ProgramId.log_failure(info.key);
         return Err(ProgramError::IncorrectProgramId);
     }
 
@@ -85,6 +92,7 @@ pub fn load_token_program(info: &AccountInfo) -> Result<(), ProgramError> {
 pub fn load_token_2022_program(info: &AccountInfo) -> Result<(), ProgramError> {
     if info.key.ne(&spl_token_2022::id()) {
         msg!("Account is not the spl token 2022 program");
+        Check::ProgramId.log_failure(info.key);
         return Err(ProgramError::IncorrectProgramId);
     }
 
@@ -103,16 +111,19 @@ pub fn load_token_2022_program(info: &AccountInfo) -> Result<(), ProgramError> {
 pub fn load_system_account(info: &AccountInfo, is_writable: bool) -> Result<(), ProgramError> {
     if info.owner.ne(&system_program::id()) {
         msg!("Account is not owned by the system program");
+        Check::Owner.log_failure(info.key);
         return Err(ProgramError::InvalidAccountOwner);
     }
 
     if !info.data_is_empty() {
         msg!("Account data is not empty");
+        Check::Uninitialized.log_failure(info.key);
         return Err(ProgramError::AccountAlreadyInitialized);
     }
 
     if is_writable && !info.is_writable {
         msg!("Account is not writable");
+        Check::Writable.log_failure(info.key);
         return Err(ProgramError::InvalidAccountData);
     }
 
@@ -136,17 +147,20 @@ pub fn load_associated_token_account(
 ) -> Result<(), ProgramError> {
     if token_account.owner.ne(&spl_token::id()) {
         msg!("Account is not owned by the spl token program");
+        Check::Owner.log_failure(token_account.key);
         return Err(ProgramError::InvalidAccountOwner);
     }
 
     if token_account.data_is_empty() {
         msg!("Account data is empty");
+        Check::Initialized.log_failure(token_account.key);
         return Err(ProgramError::InvalidAccountData);
     }
 
     let associated_token_account = get_associated_token_address(owner, mint);
     if token_account.key.ne(&associated_token_account) {
         msg!("Account is not the associated token account");
+        Check::Address.log_failure(token_account.key);
         return Err(ProgramError::InvalidAccountData);
     }
 
@@ -180,19 +194,23 @@ pub fn load_token_account(
 ) -> Result<(), ProgramError> {
     if token_program.key.ne(&spl_token::id()) {
         msg!("Account is not owned by the spl token program");
+        Check::ProgramId.log_failure(token_program.key);
         return Err(ProgramError::IncorrectProgramId);
     }
 
     if token_account.owner.ne(&spl_token::id()) {
         msg!("Account is not owned by the token program");
+        Check::Owner.log_failure(token_account.key);
         return Err(ProgramError::InvalidAccountOwner);
     }
 
     if token_account.data_is_empty() {
         msg!("Account data is empty");
+        Check::Initialized.log_failure(token_account.key);
         return Err(ProgramError::InvalidAccountData);
     }
 
+    let token_account_key = token_account.key;
     let data = token_account.data.borrow();
     let token_account = StateWithExtensions::<spl_token_2022::state::Account>::unpack(&data)?;
     if token_account.base.owner.ne(owner) {
@@ -201,6 +219,7 @@ pub fn load_token_account(
             owner,
             token_account.base.owner
         );
+        Check::TokenOwner.log_failure(token_account_key);
         return Err(ProgramError::InvalidAccountData);
     }
 
@@ -210,6 +229,7 @@ pub fn load_token_account(
             mint,
             token_account.base.mint
         );
+        Check::TokenMint.log_failure(token_account_key);
         return Err(ProgramError::InvalidAccountData);
     }
 
@@ -226,11 +246,13 @@ pub fn load_token_account(
 pub fn load_token_mint(info: &AccountInfo) -> Result<(), ProgramError> {
     if info.owner.ne(&spl_token::id()) {
         msg!("Account is not owned by the spl token program");
+        Check::Owner.log_failure(info.key);
         return Err(ProgramError::InvalidAccountOwner);
     }
 
     if info.data_is_empty() {
         msg!("Account data is empty");
+        Check::Initialized.log_failure(info.key);
         return Err(ProgramError::InvalidAccountData);
     }
 
@@ -0,0 +1,79 @@
+//! Instruction categories that can be paused independently of each other. Accounts store the
+//! paused categories as a bitmask, one bit per [`PauseCategory`].
+
+/// A category of instructions that can be paused
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum PauseCategory {
+    /// Depositing assets, such as minting VRT
+    Deposits = 0,
+    /// Enqueuing, burning and transferring withdrawal tickets
+    Withdrawals = 1,
+    /// Delegating and undelegating assets
+    Delegation = 2,
+    /// Creating, warming up and cooling down tickets
+    TicketChanges = 3,
+    /// Changing fees
+    FeeChanges = 4,
+}
+
+impl PauseCategory {
+    /// Every category
+    pub const ALL: [Self; 5] = [
+        Self::Deposits,
+        Self::Withdrawals,
+        Self::Delegation,
+        Self::TicketChanges,
+        Self::FeeChanges,
+    ];
+
+    /// Bitmask with every category paused
+    pub const ALL_MASK: u16 = (1 << Self::ALL.len()) - 1;
+
+    /// The bit for this category
+    pub const fn mask(self) -> u16 {
+        1 << self as u16
+    }
+
+    /// Whether this category is paused in the given bitmask
+    pub const fn is_paused(self, paused_categories: u16) -> bool {
+        paused_categories & self.mask() != 0
+    }
+
+    /// Whether the bitmask only contains known categories
+    pub const fn is_valid_mask(paused_categories: u16) -> bool {
+        paused_categories & !Self::ALL_MASK == 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PauseCategory;
+
+    #[test]
+    fn test_masks_are_distinct() {
+        let combined = PauseCategory::ALL.iter().fold(0u16, |acc, category| {
+            assert_eq!(acc & category.mask(), 0);
+            acc | category.mask()
+        });
+        assert_eq!(combined, PauseCategory::ALL_MASK);
+    }
+
+    #[test]
+    fn test_is_paused() {
+        let paused_categories = PauseCategory::Deposits.mask() | PauseCategory::FeeChanges.mask();
+
+        assert!(PauseCategory::Deposits.is_paused(paused_categories));
+        assert!(!PauseCategory::Withdrawals.is_paused(paused_categories));
+        assert!(!PauseCategory::Delegation.is_paused(paused_categories));
+        assert!(!PauseCategory::TicketChanges.is_paused(paused_categories));
+        assert!(PauseCategory::FeeChanges.is_paused(paused_categories));
+    }
+
+    #[test]
+    fn test_is_valid_mask() {
+        assert!(PauseCategory::is_valid_mask(0));
+        assert!(PauseCategory::is_valid_mask(PauseCategory::ALL_MASK));
+        assert!(!PauseCategory::is_valid_mask(1 << 5));
+    }
+}
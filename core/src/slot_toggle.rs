@@ -103,6 +103,12 @@ impl SlotToggle {
         }
     }
 
+    /// Check if the feature was activated and not deactivated since, meaning it's warming up or
+    /// active at any slot.
+    pub fn is_activated(&self) -> bool {
+        self.slot_added() > self.slot_removed()
+    }
+
     /// Check if the feature is active or in cooldown state at the given slot.
     pub fn is_active_or_cooldown(
         &self,
@@ -171,8 +177,12 @@ impl SlotToggle {
 #[cfg(test)]
 mod tests {
     use jito_bytemuck::types::PodU64;
+    use proptest::prelude::*;
 
-    use crate::slot_toggle::{SlotToggle, SlotToggleState};
+    use crate::{
+        get_epoch,
+        slot_toggle::{SlotToggle, SlotToggleState},
+    };
 
     #[test]
     fn test_slot_toggle_no_padding() {
@@ -317,6 +327,22 @@ mod tests {
             .unwrap());
     }
 
+    #[test]
+    fn test_is_activated() {
+        let creation_slot = 100;
+        let epoch_length = 150;
+        let mut toggle = SlotToggle::new(creation_slot);
+        assert!(!toggle.is_activated());
+
+        let activation_slot = creation_slot + 1;
+        assert!(toggle.activate(activation_slot, epoch_length).unwrap());
+        assert!(toggle.is_activated());
+
+        let active_slot = activation_slot + (epoch_length * 2);
+        assert!(toggle.deactivate(active_slot, epoch_length).unwrap());
+        assert!(!toggle.is_activated());
+    }
+
     #[test]
     fn test_is_active() {
         let creation_slot = 100;
@@ -347,4 +373,176 @@ mod tests {
         let inactive_slot = active_slot + (epoch_length * 2);
         assert!(!toggle.is_active(inactive_slot, epoch_length).unwrap());
     }
+
+    #[derive(Debug, Clone, Copy)]
+    enum ToggleAction {
+        Wait,
+        Activate,
+        Deactivate,
+    }
+
+    fn toggle_action() -> impl Strategy<Value = ToggleAction> {
+        prop_oneof![
+            Just(ToggleAction::Wait),
+            Just(ToggleAction::Activate),
+            Just(ToggleAction::Deactivate),
+        ]
+    }
+
+    /// Whether the state can change from `from` to `to` by time passing alone
+    fn is_time_transition(from: &SlotToggleState, to: &SlotToggleState) -> bool {
+        matches!(
+            (from, to),
+            (SlotToggleState::Inactive, SlotToggleState::Inactive)
+                | (SlotToggleState::WarmUp, SlotToggleState::WarmUp)
+                | (SlotToggleState::WarmUp, SlotToggleState::Active)
+                | (SlotToggleState::Active, SlotToggleState::Active)
+                | (SlotToggleState::Cooldown, SlotToggleState::Cooldown)
+                | (SlotToggleState::Cooldown, SlotToggleState::Inactive)
+        )
+    }
+
+    proptest! {
+        #[test]
+        fn test_state_never_panics(
+            slot_added in any::<u64>(),
+            slot_removed in any::<u64>(),
+            slot in any::<u64>(),
+            epoch_length in any::<u64>(),
+        ) {
+            let toggle = SlotToggle {
+                slot_added: PodU64::from(slot_added),
+                slot_removed: PodU64::from(slot_removed),
+                reserved: [0; 32],
+            };
+            let state = toggle.state(slot, epoch_length);
+            if epoch_length > 1 {
+                prop_assert!(state.is_ok());
+            }
+        }
+
+        /// Walks a toggle through random activations, deactivations and waits, checking it only
+        /// moves Inactive -> WarmUp -> Active -> Cooldown -> Inactive and that warm-up and
+        /// cooldown each last until the epoch after the one they started in has passed
+        #[test]
+        fn test_transitions_follow_lifecycle(
+            epoch_length in 1..1_000u64,
+            creation_slot in 0..1_000_000u64,
+            steps in proptest::collection::vec((toggle_action(), 0..3_000u64), 1..50),
+        ) {
+            let mut toggle = SlotToggle::new(creation_slot);
+            let mut slot = creation_slot;
+            let mut state = toggle.state(slot, epoch_length).unwrap();
+            prop_assert_eq!(&state, &SlotToggleState::Inactive);
+
+            for (action, slots_to_wait) in steps {
+                let before = toggle;
+                match action {
+                    ToggleAction::Wait => {}
+                    ToggleAction::Activate => {
+                        if toggle.activate(slot, epoch_length).unwrap() {
+                            prop_assert_eq!(&state, &SlotToggleState::Inactive);
+                            state = toggle.state(slot, epoch_length).unwrap();
+                            prop_assert_eq!(&state, &SlotToggleState::WarmUp);
+                        } else {
+                            prop_assert_eq!(toggle, before);
+                        }
+                    }
+                    ToggleAction::Deactivate => {
+                        if toggle.deactivate(slot, epoch_length).unwrap() {
+                            prop_assert_eq!(&state, &SlotToggleState::Active);
+                            state = toggle.state(slot, epoch_length).unwrap();
+                            prop_assert_eq!(&state, &SlotToggleState::Cooldown);
+                        } else {
+                            prop_assert_eq!(toggle, before);
+                        }
+                    }
+                }
+
+                slot += slots_to_wait;
+                let next_state = toggle.state(slot, epoch_length).unwrap();
+                prop_assert!(
+                    is_time_transition(&state, &next_state),
+                    "{:?} -> {:?}",
+                    state,
+                    next_state
+                );
+
+                let epoch = get_epoch(slot, epoch_length).unwrap();
+                match next_state {
+                    SlotToggleState::WarmUp => {
+                        prop_assert!(epoch <= get_epoch(toggle.slot_added(), epoch_length).unwrap() + 1);
+                    }
+                    SlotToggleState::Active => {
+                        prop_assert!(epoch > get_epoch(toggle.slot_added(), epoch_length).unwrap() + 1);
+                    }
+                    SlotToggleState::Cooldown => {
+                        prop_assert!(
+                            epoch <= get_epoch(toggle.slot_removed(), epoch_length).unwrap() + 1
+                        );
+                    }
+                    SlotToggleState::Inactive => {}
+                }
+                state = next_state;
+            }
+        }
+    }
+}
+
+/// Proof harnesses for `cargo kani`
+#[cfg(kani)]
+mod verification {
+    use jito_bytemuck::types::PodU64;
+
+    use super::{SlotToggle, SlotToggleState};
+
+    #[kani::proof]
+    fn verify_state_never_panics() {
+        let toggle = SlotToggle {
+            slot_added: PodU64::from(kani::any::<u64>()),
+            slot_removed: PodU64::from(kani::any::<u64>()),
+            reserved: [0; 32],
+        };
+        let _ = toggle.state(kani::any(), kani::any());
+    }
+
+    #[kani::proof]
+    fn verify_activate_starts_warm_up() {
+        let creation_slot: u64 = kani::any();
+        let slot: u64 = kani::any();
+        let epoch_length: u64 = kani::any();
+        kani::assume(epoch_length > 1);
+        kani::assume(creation_slot < slot);
+
+        let mut toggle = SlotToggle::new(creation_slot);
+        assert!(toggle.activate(slot, epoch_length).unwrap());
+        assert_eq!(
+            toggle.state(slot, epoch_length).unwrap(),
+            SlotToggleState::WarmUp
+        );
+        assert!(!toggle.deactivate(slot, epoch_length).unwrap());
+    }
+
+    #[kani::proof]
+    fn verify_deactivate_starts_cooldown() {
+        let slot_added: u64 = kani::any();
+        let slot: u64 = kani::any();
+        let epoch_length: u64 = kani::any();
+        kani::assume(epoch_length > 1);
+        kani::assume(slot_added > 0 && slot_added < slot);
+
+        let mut toggle = SlotToggle {
+            slot_added: PodU64::from(slot_added),
+            slot_removed: PodU64::from(0),
+            reserved: [0; 32],
+        };
+        if toggle.state(slot, epoch_length).unwrap() == SlotToggleState::Active {
+            assert!(toggle.deactivate(slot, epoch_length).unwrap());
+            assert_eq!(
+                toggle.state(slot, epoch_length).unwrap(),
+                SlotToggleState::Cooldown
+            );
+            assert!(!toggle.activate(slot, epoch_length).unwrap());
+        }
+    }
 }
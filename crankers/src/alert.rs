@@ -0,0 +1,126 @@
+//! Destinations for [`Violation`]s raised by the monitor.
+
+use std::io::{self, Write};
+
+use log::warn;
+
+use crate::monitor::Violation;
+
+/// Delivers violations somewhere an operator will see them
+pub trait AlertSink {
+    fn alert(&mut self, violation: &Violation) -> anyhow::Result<()>;
+}
+
+/// Writes one human readable line per violation
+pub struct TextSink<W: Write> {
+    writer: W,
+}
+
+impl<W: Write> TextSink<W> {
+    pub const fn new(writer: W) -> Self {
+        Self { writer }
+    }
+}
+
+impl TextSink<io::Stdout> {
+    pub fn stdout() -> Self {
+        Self::new(io::stdout())
+    }
+}
+
+impl<W: Write> AlertSink for TextSink<W> {
+    fn alert(&mut self, violation: &Violation) -> anyhow::Result<()> {
+        writeln!(self.writer, "{violation}")?;
+        Ok(())
+    }
+}
+
+/// Writes one JSON object per line, for log pipelines
+pub struct JsonSink<W: Write> {
+    writer: W,
+}
+
+impl<W: Write> JsonSink<W> {
+    pub const fn new(writer: W) -> Self {
+        Self { writer }
+    }
+}
+
+impl JsonSink<io::Stdout> {
+    pub fn stdout() -> Self {
+        Self::new(io::stdout())
+    }
+}
+
+impl<W: Write> AlertSink for JsonSink<W> {
+    fn alert(&mut self, violation: &Violation) -> anyhow::Result<()> {
+        serde_json::to_writer(&mut self.writer, violation)?;
+        writeln!(self.writer)?;
+        Ok(())
+    }
+}
+
+/// Placeholder for posting violations to a webhook. The JSON payload is built but only logged
+/// until an HTTP client is wired in.
+pub struct WebhookSink {
+    url: String,
+}
+
+impl WebhookSink {
+    pub const fn new(url: String) -> Self {
+        Self { url }
+    }
+}
+
+impl AlertSink for WebhookSink {
+    fn alert(&mut self, violation: &Violation) -> anyhow::Result<()> {
+        let payload = serde_json::to_string(violation)?;
+        warn!(
+            "Webhook delivery not implemented, would POST to {}: {payload}",
+            self.url
+        );
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_sdk::pubkey::Pubkey;
+
+    use super::*;
+    use crate::monitor::Invariant;
+
+    #[test]
+    fn test_sinks_write_one_line_per_violation() {
+        let violation = Violation {
+            vault: Pubkey::new_unique(),
+            invariant: Invariant::VrtSupply,
+            message: "vault VRT supply 1 != mint supply 2".to_string(),
+        };
+
+        let mut text = TextSink::new(Vec::new());
+        text.alert(&violation).unwrap();
+        assert_eq!(
+            String::from_utf8(text.writer).unwrap(),
+            format!(
+                "vault {} broke vrt_supply: vault VRT supply 1 != mint supply 2\n",
+                violation.vault
+            )
+        );
+
+        let mut json = JsonSink::new(Vec::new());
+        json.alert(&violation).unwrap();
+        json.alert(&violation).unwrap();
+        let output = String::from_utf8(json.writer).unwrap();
+        let lines: Vec<&str> = output.lines().collect();
+        assert_eq!(lines.len(), 2);
+        assert_eq!(
+            lines[0],
+            format!(
+                "{{\"vault\":\"{}\",\"invariant\":\"vrt_supply\",\"message\":\"vault VRT supply 1 \
+                 != mint supply 2\"}}",
+                violation.vault
+            )
+        );
+    }
+}
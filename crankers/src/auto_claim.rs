@@ -0,0 +1,214 @@
+//! Burns withdrawal tickets on behalf of stakers who registered the cranker as their auto-claim
+//! delegate, so their assets land in their wallet without them coming back to claim.
+
+use std::{collections::HashMap, time::Duration};
+
+use jito_jsm_core::pause::PauseCategory;
+use jito_vault_core::{vault::Vault, vault_staker_withdrawal_ticket::VaultStakerWithdrawalTicket};
+use log::{error, info};
+use solana_rpc_client::nonblocking::rpc_client::RpcClient;
+use solana_sdk::pubkey::Pubkey;
+
+use crate::vault_handler::VaultHandler;
+
+/// A withdrawal ticket the cranker can burn right now
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ClaimableTicket {
+    pub vault: Pubkey,
+    pub ticket: Pubkey,
+    pub staker: Pubkey,
+    /// The tip to request, capped at what the staker allowed
+    pub tip_bps: u16,
+}
+
+/// Picks the tickets delegated to `delegate` that would burn successfully at `slot`. Vaults that
+/// need an update, are paused, or, outside of emergency exit, have withdrawals paused or require
+/// the mint burn admin to co-sign are skipped.
+pub fn claimable_tickets(
+    delegate: &Pubkey,
+    vaults: &HashMap<Pubkey, Vault>,
+    tickets: &[(Pubkey, VaultStakerWithdrawalTicket)],
+    slot: u64,
+    epoch_length: u64,
+    tip_bps: u16,
+) -> Vec<ClaimableTicket> {
+    tickets
+        .iter()
+        .filter(|(_, ticket)| ticket.has_auto_claim_delegate())
+        .filter(|(_, ticket)| ticket.auto_claim_delegate.eq(delegate))
+        .filter(|(_, ticket)| ticket.is_withdrawable(slot, epoch_length).unwrap_or(false))
+        .filter_map(|(pubkey, ticket)| {
+            let vault = vaults.get(&ticket.vault)?;
+            let burnable = !vault.is_update_needed(slot, epoch_length).unwrap_or(true)
+                && vault.check_is_paused().is_ok()
+                && (vault.is_emergency_exit()
+                    || (vault
+                        .check_is_category_paused(PauseCategory::Withdrawals)
+                        .is_ok()
+                        && vault.mint_burn_admin.eq(&Pubkey::default())));
+            burnable.then(|| ClaimableTicket {
+                vault: ticket.vault,
+                ticket: *pubkey,
+                staker: ticket.staker,
+                tip_bps: tip_bps.min(ticket.auto_claim_max_tip_bps()),
+            })
+        })
+        .collect()
+}
+
+/// Scans withdrawal tickets every `interval` and burns the ones delegated to the cranker's payer
+pub async fn run_auto_claim(
+    rpc_client: &RpcClient,
+    vault_handler: &VaultHandler<'_>,
+    program_fee_wallet: Pubkey,
+    epoch_length: u64,
+    tip_bps: u16,
+    interval: Duration,
+) {
+    loop {
+        if let Err(e) = auto_claim_once(
+            rpc_client,
+            vault_handler,
+            program_fee_wallet,
+            epoch_length,
+            tip_bps,
+        )
+        .await
+        {
+            error!("Failed to auto-claim withdrawal tickets: {e:#}");
+        }
+        tokio::time::sleep(interval).await;
+    }
+}
+
+async fn auto_claim_once(
+    rpc_client: &RpcClient,
+    vault_handler: &VaultHandler<'_>,
+    program_fee_wallet: Pubkey,
+    epoch_length: u64,
+    tip_bps: u16,
+) -> anyhow::Result<()> {
+    let slot = rpc_client.get_slot().await?;
+    let vaults: HashMap<Pubkey, Vault> = vault_handler.get_vaults().await?.into_iter().collect();
+    let tickets = vault_handler.get_withdrawal_tickets().await?;
+
+    let claimable = claimable_tickets(
+        &vault_handler.payer(),
+        &vaults,
+        &tickets,
+        slot,
+        epoch_length,
+        tip_bps,
+    );
+    info!("Auto-claiming {} withdrawal tickets", claimable.len());
+
+    for claimable_ticket in claimable {
+        let vault = &vaults[&claimable_ticket.vault];
+        match vault_handler
+            .auto_claim_withdrawal_ticket(vault, &claimable_ticket, &program_fee_wallet)
+            .await
+        {
+            Ok(()) => info!(
+                "Auto-claimed withdrawal ticket {} for staker {}",
+                claimable_ticket.ticket, claimable_ticket.staker
+            ),
+            Err(e) => error!(
+                "Failed to auto-claim withdrawal ticket {}: {e:#}",
+                claimable_ticket.ticket
+            ),
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EPOCH_LENGTH: u64 = 100;
+
+    fn vault() -> Vault {
+        Vault::new(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            0,
+            Pubkey::new_unique(),
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+        )
+        .unwrap()
+    }
+
+    fn ticket(vault: Pubkey, delegate: Pubkey, max_tip_bps: u16) -> VaultStakerWithdrawalTicket {
+        let mut ticket = VaultStakerWithdrawalTicket::new(
+            vault,
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            100,
+            0,
+            0,
+        );
+        ticket
+            .set_auto_claim_delegate(delegate, max_tip_bps)
+            .unwrap();
+        ticket
+    }
+
+    #[test]
+    fn test_claimable_tickets() {
+        let delegate = Pubkey::new_unique();
+        let vault_pubkey = Pubkey::new_unique();
+        let mut vaults = HashMap::from([(vault_pubkey, vault())]);
+        let tickets = vec![
+            (Pubkey::new_unique(), ticket(vault_pubkey, delegate, 10)),
+            (
+                Pubkey::new_unique(),
+                ticket(vault_pubkey, Pubkey::new_unique(), 10),
+            ),
+            (
+                Pubkey::new_unique(),
+                ticket(vault_pubkey, Pubkey::default(), 0),
+            ),
+        ];
+        // The vault was last updated in epoch 2, the ticket from slot 0 is withdrawable
+        let slot = 2 * EPOCH_LENGTH;
+        vaults
+            .get_mut(&vault_pubkey)
+            .unwrap()
+            .set_last_full_state_update_slot(slot);
+
+        let claimable = claimable_tickets(&delegate, &vaults, &tickets, slot, EPOCH_LENGTH, 50);
+        assert_eq!(claimable.len(), 1);
+        assert_eq!(claimable[0].ticket, tickets[0].0);
+        assert_eq!(claimable[0].tip_bps, 10);
+
+        // A ticket enqueued last epoch isn't withdrawable yet
+        assert!(
+            claimable_tickets(&delegate, &vaults, &tickets, EPOCH_LENGTH, EPOCH_LENGTH, 50)
+                .is_empty()
+        );
+
+        // The cranker can't co-sign for the mint burn admin
+        vaults.get_mut(&vault_pubkey).unwrap().mint_burn_admin = Pubkey::new_unique();
+        assert!(claimable_tickets(&delegate, &vaults, &tickets, slot, EPOCH_LENGTH, 50).is_empty());
+
+        // Neither the mint burn admin nor paused withdrawals block an emergency exit
+        let vault = vaults.get_mut(&vault_pubkey).unwrap();
+        vault.set_paused_categories(PauseCategory::Withdrawals.mask());
+        assert!(claimable_tickets(&delegate, &vaults, &tickets, slot, EPOCH_LENGTH, 50).is_empty());
+        vaults
+            .get_mut(&vault_pubkey)
+            .unwrap()
+            .set_is_emergency_exit(true);
+        assert_eq!(
+            claimable_tickets(&delegate, &vaults, &tickets, slot, EPOCH_LENGTH, 50).len(),
+            1
+        );
+    }
+}
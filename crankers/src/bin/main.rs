@@ -1,4 +1,4 @@
-use std::{collections::HashMap, fmt, path::PathBuf, time::Duration};
+use std::{collections::HashMap, fmt, net::SocketAddr, path::PathBuf, time::Duration};
 
 use anyhow::{anyhow, Context};
 use clap::{arg, Parser};
@@ -6,10 +6,22 @@ use dotenv::dotenv;
 use jito_bytemuck::AccountDeserialize;
 use jito_jsm_core::get_epoch;
 use jito_vault_core::{vault::Vault, vault_operator_delegation::VaultOperatorDelegation};
-use jito_vault_cranker::{metrics::emit_vault_metrics, vault_handler::VaultHandler};
+use jito_vault_cranker::{
+    auto_claim::run_auto_claim,
+    core::PriorityFeeConfig,
+    event_driven::EventDrivenCranker,
+    metrics::emit_vault_metrics,
+    policy::DesiredState,
+    policy_handler::PolicyHandler,
+    prometheus::{serve_metrics, CRANKER_METRICS},
+    subscriptions::{poll_vault_program, subscribe_vault_program},
+    vault_handler::VaultHandler,
+    vault_update::update_vaults,
+};
 use log::{error, info};
 use solana_rpc_client::nonblocking::rpc_client::RpcClient;
 use solana_sdk::{pubkey::Pubkey, signature::read_keypair_file};
+use tokio::sync::mpsc::unbounded_channel;
 
 #[derive(Parser)]
 struct Args {
@@ -45,9 +57,41 @@ struct Args {
     #[arg(long, env, default_value = "300")]
     metrics_interval: u64,
 
-    /// Priority fees (in microlamports per compute unit)
+    /// Minimum priority fees (in microlamports per compute unit). Transactions start at recent
+    /// prioritization fees and escalate while they fail to land.
     #[arg(long, env, default_value = "10000")]
     priority_fees: u64,
+
+    /// Maximum priority fees (in microlamports per compute unit)
+    #[arg(long, env, default_value = "100000")]
+    max_priority_fees: u64,
+
+    /// Maximum number of vaults updated concurrently
+    #[arg(long, env, default_value = "4")]
+    vault_update_workers: usize,
+
+    /// Path to a YAML or TOML policy describing which NCN, operator and vault links should be
+    /// active. When set, the cranker maintains those links instead of updating vaults. The file
+    /// is re-read on every pass.
+    #[arg(long, env)]
+    policy_path: Option<PathBuf>,
+
+    /// Websocket URL for the cluster. When set, the cranker subscribes to slot and vault account
+    /// notifications and updates vaults as soon as they need it, polling every crank interval as
+    /// a fallback.
+    #[arg(long, env)]
+    ws_url: Option<String>,
+
+    /// Address to serve Prometheus metrics on at `/metrics` (e.g. 0.0.0.0:9090). Vault metrics
+    /// refresh every metrics interval.
+    #[arg(long, env)]
+    prometheus_addr: Option<SocketAddr>,
+
+    /// Burn withdrawal tickets whose stakers registered the payer as their auto-claim delegate,
+    /// requesting this tip in basis points of the withdrawn assets (capped at what each staker
+    /// allowed). Tickets are scanned every crank interval.
+    #[arg(long, env)]
+    auto_claim_tip_bps: Option<u16>,
 }
 
 impl fmt::Display for Args {
@@ -62,7 +106,12 @@ impl fmt::Display for Args {
             Restaking Program ID: {}\n\
             Crank Interval: {} seconds\n\
             Metrics Interval: {} seconds\n\
-            Priority Fees: {} microlamports\n\
+            Priority Fees: {} - {} microlamports\n\
+            Vault Update Workers: {}\n\
+            Policy Path: {:?}\n\
+            Websocket URL: {:?}\n\
+            Prometheus Address: {:?}\n\
+            Auto-claim Tip: {:?} bps\n\
             -------------------------------",
             self.rpc_url,
             self.keypair_path,
@@ -71,6 +120,12 @@ impl fmt::Display for Args {
             self.crank_interval,
             self.metrics_interval,
             self.priority_fees,
+            self.max_priority_fees,
+            self.vault_update_workers,
+            self.policy_path,
+            self.ws_url,
+            self.prometheus_addr,
+            self.auto_claim_tip_bps,
         )
     }
 }
@@ -99,19 +154,24 @@ async fn main() -> anyhow::Result<(), anyhow::Error> {
     let config = jito_vault_core::config::Config::try_from_slice_unchecked(&account.data)
         .context("Failed to deserialize Jito vault config")?;
 
+    let priority_fees = PriorityFeeConfig {
+        min_priority_fee: args.priority_fees,
+        max_priority_fee: args.max_priority_fees,
+    };
     let vault_handler = VaultHandler::new(
         &args.rpc_url,
         &payer,
         args.vault_program_id,
         config_address,
-        args.priority_fees,
+        priority_fees,
     );
 
     // Track vault metrics in separate thread
     tokio::spawn({
         let epoch_length = config.epoch_length();
+        let rpc_url = args.rpc_url.clone();
         async move {
-            let metrics_client = RpcClient::new_with_timeout(args.rpc_url, Duration::from_secs(60));
+            let metrics_client = RpcClient::new_with_timeout(rpc_url, Duration::from_secs(60));
             loop {
                 if let Err(e) = emit_vault_metrics(&metrics_client, epoch_length).await {
                     error!("Failed to emit metrics: {}", e);
@@ -121,6 +181,111 @@ async fn main() -> anyhow::Result<(), anyhow::Error> {
         }
     });
 
+    if let Some(prometheus_addr) = args.prometheus_addr {
+        tokio::spawn(async move {
+            if let Err(e) = serve_metrics(prometheus_addr, &CRANKER_METRICS).await {
+                error!("Prometheus exporter stopped: {e:#}");
+            }
+        });
+    }
+
+    if let Some(tip_bps) = args.auto_claim_tip_bps {
+        tokio::spawn({
+            let rpc_url = args.rpc_url.clone();
+            let keypair_path = args.keypair_path.clone();
+            let vault_program_id = args.vault_program_id;
+            let program_fee_wallet = config.program_fee_wallet;
+            let epoch_length = config.epoch_length();
+            async move {
+                let payer = match read_keypair_file(&keypair_path) {
+                    Ok(payer) => payer,
+                    Err(e) => {
+                        error!("Failed to read keypair file for auto-claim: {e}");
+                        return;
+                    }
+                };
+                let auto_claim_client =
+                    RpcClient::new_with_timeout(rpc_url.clone(), Duration::from_secs(60));
+                let vault_handler = VaultHandler::new(
+                    &rpc_url,
+                    &payer,
+                    vault_program_id,
+                    config_address,
+                    priority_fees,
+                );
+                run_auto_claim(
+                    &auto_claim_client,
+                    &vault_handler,
+                    program_fee_wallet,
+                    epoch_length,
+                    tip_bps,
+                    Duration::from_secs(args.crank_interval),
+                )
+                .await;
+            }
+        });
+    }
+
+    if let Some(policy_path) = args.policy_path {
+        let policy_handler = PolicyHandler::new(
+            &args.rpc_url,
+            &payer,
+            args.restaking_program_id,
+            args.vault_program_id,
+            priority_fees,
+        );
+
+        loop {
+            info!("Enforcing policy: {}", policy_path.display());
+
+            match DesiredState::from_path(&policy_path) {
+                Ok(desired_state) => {
+                    if let Err(e) = policy_handler.enforce(&desired_state).await {
+                        error!("Failed to enforce policy: {e}");
+                    }
+                }
+                Err(e) => error!("Failed to load policy: {e:#}"),
+            }
+
+            info!("Sleeping for {} seconds", args.crank_interval);
+            tokio::time::sleep(Duration::from_secs(args.crank_interval)).await;
+        }
+    }
+
+    if let Some(ws_url) = args.ws_url {
+        let (sender, receiver) = unbounded_channel();
+
+        tokio::spawn({
+            let sender = sender.clone();
+            let vault_program_id = args.vault_program_id;
+            async move { subscribe_vault_program(&ws_url, vault_program_id, sender).await }
+        });
+
+        let mut cranker = EventDrivenCranker::new(
+            VaultHandler::new(
+                &args.rpc_url,
+                &payer,
+                args.vault_program_id,
+                config_address,
+                priority_fees,
+            ),
+            config.epoch_length(),
+            args.vault_update_workers,
+        );
+
+        tokio::join!(
+            poll_vault_program(
+                &rpc_client,
+                &vault_handler,
+                Duration::from_secs(args.crank_interval),
+                sender,
+            ),
+            cranker.run(receiver),
+        );
+
+        return Ok(());
+    }
+
     loop {
         let slot = rpc_client.get_slot().await.context("get slot")?;
         let epoch = get_epoch(slot, config.epoch_length()).unwrap();
@@ -156,18 +321,28 @@ async fn main() -> anyhow::Result<(), anyhow::Error> {
 
         info!("Updating {} vaults", vaults_need_update.len());
 
-        for (vault, mut delegations) in grouped_delegations {
-            // Sort by VaultOperatorDelegation index for correct cranking order
-            delegations.sort_by_key(|(_pubkey, delegation)| delegation.index());
-            let operators: Vec<Pubkey> = delegations
-                .iter()
-                .map(|(_pubkey, delegation)| delegation.operator)
-                .collect();
+        let vaults_to_update: Vec<(Pubkey, Vec<Pubkey>)> = grouped_delegations
+            .into_iter()
+            .map(|(vault, mut delegations)| {
+                // Sort by VaultOperatorDelegation index for correct cranking order
+                delegations.sort_by_key(|(_pubkey, delegation)| delegation.index());
+                let operators: Vec<Pubkey> = delegations
+                    .iter()
+                    .map(|(_pubkey, delegation)| delegation.operator)
+                    .collect();
+                (vault, operators)
+            })
+            .collect();
 
-            match vault_handler
-                .do_vault_update(epoch, &vault, &operators)
-                .await
-            {
+        for (vault, result) in update_vaults(
+            &vault_handler,
+            epoch,
+            vaults_to_update,
+            args.vault_update_workers,
+        )
+        .await
+        {
+            match result {
                 Err(e) => log::error!("Failed to update vault: {vault}, error: {e}"),
                 Ok(_) => info!("Successfully updated vault: {vault}"),
             }
@@ -0,0 +1,243 @@
+use std::{collections::HashMap, fmt, sync::Arc, time::Duration};
+
+use anyhow::Context;
+use clap::{arg, Parser, ValueEnum};
+use dotenv::dotenv;
+use jito_bytemuck::AccountDeserialize;
+use jito_vault_core::config::Config;
+use jito_vault_cranker::{
+    alert::{AlertSink, JsonSink, TextSink, WebhookSink},
+    core::{get_multiple_accounts_batched, PriorityFeeConfig},
+    monitor::{check_vault, VaultSnapshot, WithdrawalTicketSnapshot},
+    vault_handler::VaultHandler,
+};
+use log::{error, info};
+use solana_rpc_client::nonblocking::rpc_client::RpcClient;
+use solana_sdk::{account::Account, program_pack::Pack, pubkey::Pubkey, signature::Keypair};
+use spl_associated_token_account::get_associated_token_address;
+use spl_token::state::{Account as TokenAccount, Mint};
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+enum AlertSinkKind {
+    /// Human readable lines on stdout
+    Stdout,
+    /// One JSON object per line on stdout
+    Json,
+    /// POST each violation to `--webhook-url` (not implemented yet, payloads are logged)
+    Webhook,
+}
+
+#[derive(Parser)]
+struct Args {
+    /// RPC URL for the cluster
+    #[arg(short, long, env, default_value = "https://api.devnet.solana.com")]
+    rpc_url: String,
+
+    /// Vault program ID (Pubkey as base58 string)
+    #[arg(
+        long,
+        env,
+        default_value = "Vau1t6sLNxnzB7ZDsef8TLbPLfyZMYXH8WTNqUdm9g8"
+    )]
+    vault_program_id: Pubkey,
+
+    /// Interval in seconds between invariant checks (default: 5 minutes)
+    #[arg(long, env, default_value = "300")]
+    check_interval: u64,
+
+    /// Where to send violations
+    #[arg(long, env, value_enum, default_value = "stdout")]
+    alert_sink: AlertSinkKind,
+
+    /// Webhook URL, required by the webhook sink
+    #[arg(long, env, required_if_eq("alert_sink", "webhook"))]
+    webhook_url: Option<String>,
+}
+
+impl fmt::Display for Args {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Jito Vault Monitor Configuration:\n\
+            -------------------------------\n\
+            RPC URL: {}\n\
+            Vault Program ID: {}\n\
+            Check Interval: {} seconds\n\
+            Alert Sink: {:?}\n\
+            Webhook URL: {:?}\n\
+            -------------------------------",
+            self.rpc_url,
+            self.vault_program_id,
+            self.check_interval,
+            self.alert_sink,
+            self.webhook_url,
+        )
+    }
+}
+
+#[tokio::main]
+async fn main() -> anyhow::Result<(), anyhow::Error> {
+    dotenv().ok();
+
+    env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("info")).init();
+
+    let args = Args::parse();
+
+    info!("{}", args);
+
+    let rpc_client = Arc::new(RpcClient::new_with_timeout(
+        args.rpc_url.clone(),
+        Duration::from_secs(60),
+    ));
+
+    let config_address = Config::find_program_address(&args.vault_program_id).0;
+    let account = rpc_client
+        .get_account(&config_address)
+        .await
+        .context("Failed to read Jito vault config address")?;
+    let config = Config::try_from_slice_unchecked(&account.data)
+        .context("Failed to deserialize Jito vault config")?;
+
+    let dummy_keypair = Keypair::new(); // Dummy keypair since we're only reading
+    let vault_handler = VaultHandler::new(
+        &args.rpc_url,
+        &dummy_keypair,
+        args.vault_program_id,
+        config_address,
+        PriorityFeeConfig::default(),
+    );
+
+    let mut sink: Box<dyn AlertSink> = match args.alert_sink {
+        AlertSinkKind::Stdout => Box::new(TextSink::stdout()),
+        AlertSinkKind::Json => Box::new(JsonSink::stdout()),
+        AlertSinkKind::Webhook => Box::new(WebhookSink::new(
+            args.webhook_url.clone().unwrap_or_default(),
+        )),
+    };
+
+    loop {
+        match get_vault_snapshots(&rpc_client, &vault_handler).await {
+            Ok((slot, snapshots)) => {
+                let mut num_violations = 0;
+                for snapshot in snapshots.iter() {
+                    for violation in check_vault(snapshot, slot, config.epoch_length()) {
+                        num_violations += 1;
+                        if let Err(e) = sink.alert(&violation) {
+                            error!("Failed to send alert: {e:#}");
+                        }
+                    }
+                }
+                info!(
+                    "Checked {} vaults at slot {slot}, found {num_violations} violations",
+                    snapshots.len()
+                );
+            }
+            Err(e) => error!("Failed to read vault accounts: {e:#}"),
+        }
+
+        tokio::time::sleep(Duration::from_secs(args.check_interval)).await;
+    }
+}
+
+/// Reads every vault along with its delegations, withdrawal tickets and token balances
+async fn get_vault_snapshots(
+    rpc_client: &Arc<RpcClient>,
+    vault_handler: &VaultHandler<'_>,
+) -> anyhow::Result<(u64, Vec<VaultSnapshot>)> {
+    let slot = rpc_client.get_slot().await.context("get slot")?;
+    let vaults = vault_handler.get_vaults().await?;
+    let delegations = vault_handler.get_vault_operator_delegations().await?;
+    let tickets = vault_handler.get_withdrawal_tickets().await?;
+
+    let vrt_mints: Vec<Pubkey> = vaults.iter().map(|(_, vault)| vault.vrt_mint).collect();
+    let st_token_accounts: Vec<Pubkey> = vaults
+        .iter()
+        .map(|(address, vault)| get_associated_token_address(address, &vault.supported_mint))
+        .collect();
+    let ticket_token_accounts: Vec<Pubkey> = tickets
+        .iter()
+        .filter_map(|(address, ticket)| {
+            vaults
+                .iter()
+                .find(|(vault_address, _)| *vault_address == ticket.vault)
+                .map(|(_, vault)| get_associated_token_address(address, &vault.vrt_mint))
+        })
+        .collect();
+
+    let mint_supplies: HashMap<Pubkey, u64> = get_multiple_accounts_batched(&vrt_mints, rpc_client)
+        .await?
+        .into_iter()
+        .zip(vrt_mints)
+        .filter_map(|(account, pubkey)| {
+            account.and_then(|account| {
+                Mint::unpack(&account.data)
+                    .ok()
+                    .map(|mint| (pubkey, mint.supply))
+            })
+        })
+        .collect();
+    let mut token_balances = get_token_balances(rpc_client, &st_token_accounts).await?;
+    token_balances.extend(get_token_balances(rpc_client, &ticket_token_accounts).await?);
+
+    let mut snapshots: HashMap<Pubkey, VaultSnapshot> = vaults
+        .into_iter()
+        .map(|(address, vault)| {
+            let st_token_account = get_associated_token_address(&address, &vault.supported_mint);
+            let snapshot = VaultSnapshot {
+                vault_pubkey: address,
+                vault,
+                delegations: vec![],
+                withdrawal_tickets: vec![],
+                vrt_mint_supply: mint_supplies.get(&vault.vrt_mint).copied(),
+                st_balance: token_balances
+                    .get(&st_token_account)
+                    .copied()
+                    .unwrap_or_default(),
+            };
+            (address, snapshot)
+        })
+        .collect();
+
+    for (_pubkey, delegation) in delegations {
+        if let Some(snapshot) = snapshots.get_mut(&delegation.vault) {
+            snapshot.delegations.push(delegation);
+        }
+    }
+    for (pubkey, ticket) in tickets {
+        if let Some(snapshot) = snapshots.get_mut(&ticket.vault) {
+            let ticket_token_account =
+                get_associated_token_address(&pubkey, &snapshot.vault.vrt_mint);
+            snapshot.withdrawal_tickets.push(WithdrawalTicketSnapshot {
+                pubkey,
+                ticket,
+                vrt_balance: token_balances
+                    .get(&ticket_token_account)
+                    .copied()
+                    .unwrap_or_default(),
+            });
+        }
+    }
+
+    Ok((slot, snapshots.into_values().collect()))
+}
+
+/// Returns the balance of every token account that exists
+async fn get_token_balances(
+    rpc_client: &Arc<RpcClient>,
+    token_accounts: &[Pubkey],
+) -> anyhow::Result<HashMap<Pubkey, u64>> {
+    let accounts: Vec<Option<Account>> =
+        get_multiple_accounts_batched(token_accounts, rpc_client).await?;
+
+    Ok(token_accounts
+        .iter()
+        .zip(accounts)
+        .filter_map(|(pubkey, account)| {
+            account.and_then(|account| {
+                TokenAccount::unpack(&account.data)
+                    .ok()
+                    .map(|token_account| (*pubkey, token_account.amount))
+            })
+        })
+        .collect())
+}
@@ -1,11 +1,85 @@
-use std::{future::Future, sync::Arc};
+use std::{collections::HashSet, future::Future, sync::Arc, time::Duration};
 
+use anyhow::{anyhow, Context};
+use log::{info, warn};
 use solana_rpc_client::nonblocking::rpc_client::RpcClient;
-use solana_rpc_client_api::client_error::Error as ClientError;
+use solana_rpc_client_api::{
+    client_error::Error as ClientError,
+    config::{RpcSendTransactionConfig, RpcSimulateTransactionConfig},
+};
 use solana_sdk::{
-    account::Account, commitment_config::CommitmentConfig, hash::Hash, pubkey::Pubkey,
+    account::Account,
+    commitment_config::CommitmentConfig,
+    compute_budget::ComputeBudgetInstruction,
+    hash::Hash,
+    instruction::Instruction,
+    pubkey::Pubkey,
+    signature::{Keypair, Signature},
+    signer::Signer,
+    transaction::{Transaction, TransactionError},
 };
-use tokio::task;
+use tokio::{task, time::sleep};
+
+use crate::prometheus::CRANKER_METRICS;
+
+/// The maximum compute unit limit a transaction can request
+pub const MAX_COMPUTE_UNIT_LIMIT: u32 = 1_400_000;
+
+/// Extra compute units requested on top of the simulated usage, in basis points
+const COMPUTE_UNIT_MARGIN_BPS: u64 = 1_000;
+
+/// The percentile of recent prioritization fees a transaction starts at
+const PRIORITY_FEE_PERCENTILE: usize = 75;
+
+/// Each resend multiplies the priority fee by this amount, in basis points
+const PRIORITY_FEE_ESCALATION_BPS: u64 = 15_000;
+
+/// The smallest priority fee increase between resends, in microlamports per compute unit
+const MIN_PRIORITY_FEE_INCREMENT: u64 = 1_000;
+
+/// How long to wait for a transaction to confirm before resending it with a higher fee
+const RESEND_INTERVAL: Duration = Duration::from_secs(2);
+
+/// Bounds on the priority fee paid by [`send_and_confirm_transaction`], in microlamports per
+/// compute unit
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct PriorityFeeConfig {
+    /// The lowest fee paid, used when recent fees are lower or unavailable
+    pub min_priority_fee: u64,
+    /// The highest fee paid, including escalation
+    pub max_priority_fee: u64,
+}
+
+impl PriorityFeeConfig {
+    /// Clamps `fee` to the configured bounds. The cap wins if the bounds are inverted.
+    pub fn clamp(&self, fee: u64) -> u64 {
+        fee.max(self.min_priority_fee).min(self.max_priority_fee)
+    }
+
+    /// Picks the starting fee from recent prioritization fee samples
+    pub fn initial_fee(&self, mut samples: Vec<u64>) -> u64 {
+        if samples.is_empty() {
+            return self.clamp(0);
+        }
+        samples.sort_unstable();
+        let index = (samples.len() - 1) * PRIORITY_FEE_PERCENTILE / 100;
+        self.clamp(samples[index])
+    }
+
+    /// The fee for the next resend of a transaction that hasn't landed at `fee`
+    pub fn escalate(&self, fee: u64) -> u64 {
+        let escalated = (fee as u128 * PRIORITY_FEE_ESCALATION_BPS as u128 / 10_000)
+            .min(u64::MAX as u128) as u64;
+        self.clamp(escalated.max(fee.saturating_add(MIN_PRIORITY_FEE_INCREMENT)))
+    }
+}
+
+/// The compute unit limit to request for a transaction that used `units_consumed` in
+/// simulation
+pub fn compute_unit_limit(units_consumed: u64) -> u32 {
+    let limit = units_consumed.saturating_add(units_consumed * COMPUTE_UNIT_MARGIN_BPS / 10_000);
+    limit.min(MAX_COMPUTE_UNIT_LIMIT as u64) as u32
+}
 
 pub async fn retry<F, Fut, T, E>(mut f: F, retries: usize) -> Result<T, E>
 where
@@ -71,3 +145,298 @@ pub async fn get_multiple_accounts_batched(
     }
     Ok(accounts_result)
 }
+
+/// Sends `instructions` in a single transaction and waits for it to confirm.
+///
+/// The transaction is simulated to size its compute unit limit, and its priority fee starts at
+/// recent prioritization fees for its writable accounts. Until it confirms, the transaction is
+/// resent every [`RESEND_INTERVAL`] with an escalating fee, bounded by `priority_fees`, and is
+/// given up on once its blockhash expires.
+///
+/// # Arguments
+/// * `instructions` - The instructions to send, without compute budget instructions
+///
+/// # Returns
+/// The signature of the transaction that landed
+pub async fn send_and_confirm_transaction(
+    rpc_client: &RpcClient,
+    payer: &Keypair,
+    instructions: &[Instruction],
+    priority_fees: &PriorityFeeConfig,
+) -> anyhow::Result<Signature> {
+    let result =
+        try_send_and_confirm_transaction(rpc_client, payer, instructions, priority_fees).await;
+    if result.is_err() {
+        CRANKER_METRICS.record_transaction_failed();
+    }
+    result
+}
+
+async fn try_send_and_confirm_transaction(
+    rpc_client: &RpcClient,
+    payer: &Keypair,
+    instructions: &[Instruction],
+    priority_fees: &PriorityFeeConfig,
+) -> anyhow::Result<Signature> {
+    let (blockhash, last_valid_block_height) = retry(
+        || async {
+            rpc_client
+                .get_latest_blockhash_with_commitment(CommitmentConfig::confirmed())
+                .await
+        },
+        5,
+    )
+    .await
+    .context("Failed to get latest blockhash")?;
+
+    let sign = |compute_unit_limit: u32, priority_fee: u64| {
+        let mut transaction_instructions = vec![
+            ComputeBudgetInstruction::set_compute_unit_limit(compute_unit_limit),
+            ComputeBudgetInstruction::set_compute_unit_price(priority_fee),
+        ];
+        transaction_instructions.extend_from_slice(instructions);
+        Transaction::new_signed_with_payer(
+            &transaction_instructions,
+            Some(&payer.pubkey()),
+            &[payer],
+            blockhash,
+        )
+    };
+
+    let simulation = rpc_client
+        .simulate_transaction_with_config(
+            &sign(MAX_COMPUTE_UNIT_LIMIT, 0),
+            RpcSimulateTransactionConfig {
+                commitment: Some(CommitmentConfig::confirmed()),
+                ..RpcSimulateTransactionConfig::default()
+            },
+        )
+        .await
+        .context("Failed to simulate transaction")?
+        .value;
+    if let Some(err) = simulation.err {
+        return Err(anyhow!(
+            "Transaction simulation failed: {err}, logs: {:?}",
+            simulation.logs.unwrap_or_default()
+        ));
+    }
+    let compute_unit_limit = simulation
+        .units_consumed
+        .map_or(MAX_COMPUTE_UNIT_LIMIT, compute_unit_limit);
+
+    let writable_accounts: Vec<Pubkey> = instructions
+        .iter()
+        .flat_map(|instruction| instruction.accounts.iter())
+        .filter(|account| account.is_writable)
+        .map(|account| account.pubkey)
+        .collect::<HashSet<_>>()
+        .into_iter()
+        .collect();
+    let samples = match rpc_client
+        .get_recent_prioritization_fees(&writable_accounts)
+        .await
+    {
+        Ok(fees) => fees.into_iter().map(|fee| fee.prioritization_fee).collect(),
+        Err(e) => {
+            warn!("Failed to get recent prioritization fees: {e}");
+            vec![]
+        }
+    };
+    let mut priority_fee = priority_fees.initial_fee(samples);
+
+    let mut signatures: Vec<Signature> = Vec::new();
+    let mut sends = 0;
+    loop {
+        let transaction = sign(compute_unit_limit, priority_fee);
+        if sends > 0 {
+            CRANKER_METRICS.record_transaction_retried();
+        }
+        CRANKER_METRICS.record_transaction_sent();
+        sends += 1;
+        match rpc_client
+            .send_transaction_with_config(
+                &transaction,
+                RpcSendTransactionConfig {
+                    skip_preflight: true,
+                    max_retries: Some(0),
+                    ..RpcSendTransactionConfig::default()
+                },
+            )
+            .await
+        {
+            // Resending at the fee cap produces the same signature
+            Ok(signature) if !signatures.contains(&signature) => signatures.push(signature),
+            Ok(_) => {}
+            Err(e) => warn!("Failed to send transaction: {e}"),
+        }
+
+        sleep(RESEND_INTERVAL).await;
+
+        // Any of the resends may land, since they share a blockhash
+        if !signatures.is_empty() {
+            let statuses: Vec<SendStatus> = rpc_client
+                .get_signature_statuses(&signatures)
+                .await
+                .context("Failed to get signature statuses")?
+                .value
+                .into_iter()
+                .map(|status| {
+                    let Some(status) = status else {
+                        return SendStatus::Pending;
+                    };
+                    let confirmed = status.satisfies_commitment(CommitmentConfig::confirmed());
+                    match status.err {
+                        Some(err) => SendStatus::Failed(err),
+                        None if confirmed => SendStatus::Confirmed,
+                        None => SendStatus::Pending,
+                    }
+                })
+                .collect();
+            if let Some(signature) = confirmed_signature(&signatures, &statuses)? {
+                info!(
+                    "Transaction {signature} confirmed with priority fee {priority_fee}, \
+                     compute unit limit {compute_unit_limit}"
+                );
+                return Ok(signature);
+            }
+        }
+
+        let block_height = rpc_client
+            .get_block_height()
+            .await
+            .context("Failed to get block height")?;
+        if block_height > last_valid_block_height {
+            return Err(anyhow!(
+                "Blockhash expired after {sends} sends, last priority fee {priority_fee}"
+            ));
+        }
+
+        priority_fee = priority_fees.escalate(priority_fee);
+    }
+}
+
+/// What a signature status says about one send of a transaction
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum SendStatus {
+    /// Not seen yet, or not confirmed yet
+    Pending,
+    /// Landed with an error
+    Failed(TransactionError),
+    /// Landed without an error and reached confirmed commitment
+    Confirmed,
+}
+
+/// Returns the signature of a confirmed send, if any. Fails only once every send has failed,
+/// since a resend at a higher fee can still land after an earlier one failed
+fn confirmed_signature(
+    signatures: &[Signature],
+    statuses: &[SendStatus],
+) -> anyhow::Result<Option<Signature>> {
+    if let Some((signature, _)) = signatures
+        .iter()
+        .zip(statuses)
+        .find(|(_, status)| **status == SendStatus::Confirmed)
+    {
+        return Ok(Some(*signature));
+    }
+    if statuses
+        .iter()
+        .all(|status| matches!(status, SendStatus::Failed(_)))
+    {
+        if let (Some(signature), Some(SendStatus::Failed(err))) =
+            (signatures.first(), statuses.first())
+        {
+            return Err(anyhow!("Transaction {signature} failed: {err}"));
+        }
+    }
+    Ok(None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PRIORITY_FEES: PriorityFeeConfig = PriorityFeeConfig {
+        min_priority_fee: 1_000,
+        max_priority_fee: 10_000,
+    };
+
+    #[test]
+    fn test_compute_unit_limit() {
+        assert_eq!(compute_unit_limit(0), 0);
+        assert_eq!(compute_unit_limit(100_000), 110_000);
+        assert_eq!(compute_unit_limit(1_300_000), MAX_COMPUTE_UNIT_LIMIT);
+    }
+
+    #[test]
+    fn test_initial_priority_fee() {
+        assert_eq!(PRIORITY_FEES.initial_fee(vec![]), 1_000);
+        assert_eq!(
+            PRIORITY_FEES.initial_fee(vec![0, 8_000, 2_000, 4_000, 6_000]),
+            6_000
+        );
+        assert_eq!(PRIORITY_FEES.initial_fee(vec![0, 0, 0]), 1_000);
+        assert_eq!(PRIORITY_FEES.initial_fee(vec![50_000, 60_000]), 10_000);
+    }
+
+    #[test]
+    fn test_escalate_priority_fee() {
+        assert_eq!(PRIORITY_FEES.escalate(1_000), 2_000);
+        assert_eq!(PRIORITY_FEES.escalate(4_000), 6_000);
+        assert_eq!(PRIORITY_FEES.escalate(8_000), 10_000);
+        assert_eq!(PRIORITY_FEES.escalate(10_000), 10_000);
+
+        let inverted = PriorityFeeConfig {
+            min_priority_fee: 5_000,
+            max_priority_fee: 2_000,
+        };
+        assert_eq!(inverted.initial_fee(vec![]), 2_000);
+        assert_eq!(inverted.escalate(u64::MAX), 2_000);
+    }
+
+    #[test]
+    fn test_confirmed_signature() {
+        let signatures = [
+            Signature::new_unique(),
+            Signature::new_unique(),
+            Signature::new_unique(),
+        ];
+        let failed = SendStatus::Failed(TransactionError::AccountInUse);
+
+        assert_eq!(
+            confirmed_signature(
+                &signatures,
+                &[
+                    SendStatus::Pending,
+                    SendStatus::Pending,
+                    SendStatus::Pending
+                ]
+            )
+            .unwrap(),
+            None
+        );
+        // A later send confirming wins over an earlier one failing
+        assert_eq!(
+            confirmed_signature(
+                &signatures,
+                &[failed.clone(), SendStatus::Pending, SendStatus::Confirmed]
+            )
+            .unwrap(),
+            Some(signatures[2])
+        );
+        // Another send may still land
+        assert_eq!(
+            confirmed_signature(
+                &signatures,
+                &[failed.clone(), SendStatus::Pending, failed.clone()]
+            )
+            .unwrap(),
+            None
+        );
+        assert!(confirmed_signature(
+            &signatures,
+            &[failed.clone(), failed.clone(), failed.clone()]
+        )
+        .is_err());
+    }
+}
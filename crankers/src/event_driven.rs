@@ -0,0 +1,265 @@
+use std::collections::HashMap;
+
+use jito_jsm_core::get_epoch;
+use jito_vault_core::{vault::Vault, vault_operator_delegation::VaultOperatorDelegation};
+use log::{error, info};
+use solana_sdk::pubkey::Pubkey;
+use tokio::sync::mpsc::UnboundedReceiver;
+
+use crate::{
+    vault_cache::VaultCache,
+    vault_update::{update_vaults, VaultUpdater},
+};
+
+/// An update to the cranker's view of the cluster
+#[derive(Debug)]
+pub enum CrankerEvent {
+    /// The cluster reached a new slot
+    Slot(u64),
+    /// A vault program account changed. Closed accounts have empty data.
+    Account { pubkey: Pubkey, data: Vec<u8> },
+    /// A full poll of the vault program, used as a fallback when notifications are missed
+    Snapshot {
+        slot: u64,
+        vaults: Vec<(Pubkey, Vault)>,
+        delegations: Vec<(Pubkey, VaultOperatorDelegation)>,
+    },
+}
+
+/// Updates vaults as soon as [`Vault::is_update_needed`] flips, driven by [`CrankerEvent`]s
+/// instead of a fixed polling interval.
+///
+/// Each vault is attempted at most once per epoch from notifications. A failed update is retried
+/// after the next [`CrankerEvent::Snapshot`], which replaces the cache with authoritative state.
+pub struct EventDrivenCranker<U: VaultUpdater> {
+    updater: U,
+    cache: VaultCache,
+    epoch_length: u64,
+    slot: u64,
+    /// The epoch each vault was last attempted in
+    attempted: HashMap<Pubkey, u64>,
+    /// The maximum number of vaults updated concurrently
+    workers: usize,
+}
+
+impl<U: VaultUpdater> EventDrivenCranker<U> {
+    pub fn new(updater: U, epoch_length: u64, workers: usize) -> Self {
+        Self {
+            updater,
+            cache: VaultCache::default(),
+            epoch_length,
+            slot: 0,
+            attempted: HashMap::new(),
+            workers,
+        }
+    }
+
+    pub const fn cache(&self) -> &VaultCache {
+        &self.cache
+    }
+
+    /// Processes events until every sender is dropped
+    pub async fn run(&mut self, mut events: UnboundedReceiver<CrankerEvent>) {
+        while let Some(event) = events.recv().await {
+            self.handle_event(event).await;
+        }
+    }
+
+    /// Applies a single event to the cache and updates any vault that now needs it
+    pub async fn handle_event(&mut self, event: CrankerEvent) {
+        match event {
+            CrankerEvent::Slot(slot) => {
+                self.slot = self.slot.max(slot);
+            }
+            CrankerEvent::Account { pubkey, data } => {
+                self.cache.apply_account(pubkey, &data);
+            }
+            CrankerEvent::Snapshot {
+                slot,
+                vaults,
+                delegations,
+            } => {
+                self.slot = self.slot.max(slot);
+                self.cache.replace(vaults, delegations);
+                self.attempted.clear();
+            }
+        }
+
+        self.update_vaults().await;
+    }
+
+    async fn update_vaults(&mut self) {
+        let Ok(epoch) = get_epoch(self.slot, self.epoch_length) else {
+            error!("Config epoch length is 0");
+            return;
+        };
+
+        let vaults: Vec<(Pubkey, Vec<Pubkey>)> = self
+            .cache
+            .vaults_needing_update(self.slot, self.epoch_length)
+            .into_iter()
+            .filter(|(vault, _operators)| self.attempted.get(vault) != Some(&epoch))
+            .collect();
+        if vaults.is_empty() {
+            return;
+        }
+
+        info!(
+            "Updating {} vaults at slot {}, epoch {epoch}",
+            vaults.len(),
+            self.slot
+        );
+        for (vault, _operators) in vaults.iter() {
+            self.attempted.insert(*vault, epoch);
+        }
+
+        for (vault, result) in update_vaults(&self.updater, epoch, vaults, self.workers).await {
+            match result {
+                Err(e) => error!("Failed to update vault: {vault}, error: {e}"),
+                Ok(_) => info!("Successfully updated vault: {vault}"),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use tokio::sync::mpsc::unbounded_channel;
+
+    use super::*;
+    use crate::vault_cache::tests::{account_data, new_vault};
+
+    const EPOCH_LENGTH: u64 = 100;
+
+    /// `(epoch, vault, operators)` passed to [`VaultUpdater::update_vault`]
+    type RecordedUpdate = (u64, Pubkey, Vec<Pubkey>);
+
+    /// Records updates instead of sending transactions
+    #[derive(Clone, Default)]
+    struct MockUpdater {
+        updates: Arc<Mutex<Vec<RecordedUpdate>>>,
+        fail: bool,
+    }
+
+    impl VaultUpdater for MockUpdater {
+        async fn update_vault(
+            &self,
+            epoch: u64,
+            vault: Pubkey,
+            operators: Vec<Pubkey>,
+        ) -> anyhow::Result<()> {
+            self.updates.lock().unwrap().push((epoch, vault, operators));
+            if self.fail {
+                return Err(anyhow::anyhow!("mock failure"));
+            }
+            Ok(())
+        }
+    }
+
+    impl MockUpdater {
+        fn num_updates(&self) -> usize {
+            self.updates.lock().unwrap().len()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_update_starts_when_epoch_flips() {
+        let updater = MockUpdater::default();
+        let mut cranker = EventDrivenCranker::new(updater.clone(), EPOCH_LENGTH, 1);
+        let vault = Pubkey::new_unique();
+        let operator = Pubkey::new_unique();
+
+        let (sender, receiver) = unbounded_channel();
+        sender.send(CrankerEvent::Slot(1)).unwrap();
+        sender
+            .send(CrankerEvent::Account {
+                pubkey: vault,
+                data: account_data(&new_vault(0)),
+            })
+            .unwrap();
+        sender
+            .send(CrankerEvent::Account {
+                pubkey: Pubkey::new_unique(),
+                data: account_data(&VaultOperatorDelegation::new(vault, operator, 0, 0, 0)),
+            })
+            .unwrap();
+        sender.send(CrankerEvent::Slot(EPOCH_LENGTH - 1)).unwrap();
+        sender.send(CrankerEvent::Slot(EPOCH_LENGTH)).unwrap();
+        sender.send(CrankerEvent::Slot(EPOCH_LENGTH + 1)).unwrap();
+        drop(sender);
+
+        cranker.run(receiver).await;
+
+        // Updated once, as soon as the epoch flipped
+        assert_eq!(
+            *updater.updates.lock().unwrap(),
+            vec![(1, vault, vec![operator])]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_updated_vault_notification_stops_updates() {
+        let updater = MockUpdater::default();
+        let mut cranker = EventDrivenCranker::new(updater.clone(), EPOCH_LENGTH, 1);
+        let vault = Pubkey::new_unique();
+
+        cranker
+            .handle_event(CrankerEvent::Snapshot {
+                slot: EPOCH_LENGTH,
+                vaults: vec![(vault, new_vault(0))],
+                delegations: vec![],
+            })
+            .await;
+        assert_eq!(updater.num_updates(), 1);
+
+        // The updated vault arrives through a notification, so the next snapshot is a no-op
+        cranker
+            .handle_event(CrankerEvent::Account {
+                pubkey: vault,
+                data: account_data(&new_vault(EPOCH_LENGTH)),
+            })
+            .await;
+        cranker
+            .handle_event(CrankerEvent::Snapshot {
+                slot: EPOCH_LENGTH + 1,
+                vaults: vec![(vault, new_vault(EPOCH_LENGTH))],
+                delegations: vec![],
+            })
+            .await;
+        assert_eq!(updater.num_updates(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_failed_update_retried_on_snapshot() {
+        let updater = MockUpdater {
+            fail: true,
+            ..MockUpdater::default()
+        };
+        let mut cranker = EventDrivenCranker::new(updater.clone(), EPOCH_LENGTH, 1);
+        let vault = Pubkey::new_unique();
+
+        cranker
+            .handle_event(CrankerEvent::Account {
+                pubkey: vault,
+                data: account_data(&new_vault(0)),
+            })
+            .await;
+        cranker.handle_event(CrankerEvent::Slot(EPOCH_LENGTH)).await;
+        cranker
+            .handle_event(CrankerEvent::Slot(EPOCH_LENGTH + 1))
+            .await;
+        assert_eq!(updater.num_updates(), 1);
+
+        cranker
+            .handle_event(CrankerEvent::Snapshot {
+                slot: EPOCH_LENGTH + 2,
+                vaults: vec![(vault, new_vault(0))],
+                delegations: vec![],
+            })
+            .await;
+        assert_eq!(updater.num_updates(), 2);
+        assert_eq!(cranker.cache().num_vaults(), 1);
+    }
+}
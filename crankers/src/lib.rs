@@ -1,5 +1,15 @@
 #![allow(clippy::arithmetic_side_effects, clippy::integer_division)]
+pub mod alert;
+pub mod auto_claim;
 pub mod core;
+pub mod event_driven;
 pub mod metrics;
+pub mod monitor;
+pub mod policy;
+pub mod policy_handler;
+pub mod prometheus;
 pub mod restaking_handler;
+pub mod subscriptions;
+pub mod vault_cache;
 pub mod vault_handler;
+pub mod vault_update;
@@ -1,4 +1,4 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap, HashSet};
 
 use jito_jsm_core::get_epoch;
 use jito_vault_core::config::Config;
@@ -8,7 +8,12 @@ use solana_sdk::{program_pack::Pack, pubkey::Pubkey, signature::Keypair};
 use spl_associated_token_account::get_associated_token_address;
 use spl_token::state::{Account as TokenAccount, Mint};
 
-use crate::vault_handler::VaultHandler;
+use crate::{
+    core::PriorityFeeConfig,
+    prometheus::{VaultHealth, CRANKER_METRICS},
+    restaking_handler::RestakingHandler,
+    vault_handler::VaultHandler,
+};
 
 pub async fn emit_vault_metrics(
     rpc_client: &RpcClient,
@@ -26,7 +31,7 @@ pub async fn emit_vault_metrics(
         &dummy_keypair,
         jito_vault_client::programs::JITO_VAULT_ID,
         config_address,
-        0,
+        PriorityFeeConfig::default(),
     );
 
     // Get all vaults
@@ -54,6 +59,21 @@ pub async fn emit_vault_metrics(
         })
         .count() as i64;
 
+    // Get all operators vaults delegate to, so jailed operators show up in metrics
+    let operator_pubkeys: Vec<Pubkey> = delegations
+        .iter()
+        .map(|(_, delegation)| delegation.operator)
+        .collect::<HashSet<_>>()
+        .into_iter()
+        .collect();
+    let operators = RestakingHandler::new(rpc_client.url().as_str())
+        .get_operators(&operator_pubkeys)
+        .await?;
+    let num_jailed_operators = operators
+        .iter()
+        .filter(|(_, operator)| operator.jailed_ncn_count() > 0)
+        .count() as i64;
+
     let vrt_mint_pubkeys: Vec<Pubkey> = vaults.iter().map(|(_, vault)| vault.vrt_mint).collect();
     let vrt_mint_accounts = rpc_client.get_multiple_accounts(&vrt_mint_pubkeys).await?;
     let vrt_mint_map: HashMap<Pubkey, Mint> = vrt_mint_pubkeys
@@ -87,6 +107,7 @@ pub async fn emit_vault_metrics(
         })
         .collect();
 
+    let mut vault_health = BTreeMap::new();
     for (address, vault) in vaults.iter() {
         let vrt_mint = vrt_mint_map
             .get(&vault.vrt_mint)
@@ -110,6 +131,30 @@ pub async fn emit_vault_metrics(
             ("st_supply_internal", vault.tokens_deposited() as i64, i64),
             ("st_supply_external", st_deposit_account.amount as i64, i64),
         );
+
+        vault_health.insert(
+            *address,
+            VaultHealth::new(
+                vault,
+                epoch,
+                config_epoch_length,
+                vrt_mint.supply,
+                st_deposit_account.amount,
+            ),
+        );
+    }
+    CRANKER_METRICS.set_vault_health(vault_health);
+
+    for (address, operator) in operators
+        .iter()
+        .filter(|(_, operator)| operator.jailed_ncn_count() > 0)
+    {
+        datapoint_info!(
+            "restaking-operator-jailed",
+            "operator" => address.to_string(),
+            ("slot", slot as i64, i64),
+            ("jailed_ncn_count", operator.jailed_ncn_count() as i64, i64),
+        );
     }
 
     datapoint_info!(
@@ -128,6 +173,7 @@ pub async fn emit_vault_metrics(
             num_vault_operator_delegations_updated,
             i64
         ),
+        ("num_jailed_operators", num_jailed_operators, i64),
     );
 
     Ok(())
@@ -0,0 +1,390 @@
+//! Accounting invariants checked by the `jito-vault-monitor` binary.
+//!
+//! Each pass builds a [`VaultSnapshot`] per vault from its accounts and token balances, and
+//! [`check_vault`] reports every [`Invariant`] the snapshot breaks. Violations are delivered
+//! through an [`crate::alert::AlertSink`].
+
+use std::fmt;
+
+use jito_vault_core::{
+    delegation_state::DelegationState, vault::Vault,
+    vault_operator_delegation::VaultOperatorDelegation,
+    vault_staker_withdrawal_ticket::VaultStakerWithdrawalTicket,
+};
+use serde::Serialize;
+use solana_sdk::pubkey::Pubkey;
+
+/// An accounting invariant that should hold for every vault
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Invariant {
+    /// The vault's VRT supply equals the VRT mint supply
+    VrtSupply,
+    /// The vault's supported token account holds at least the tokens deposited
+    StBalance,
+    /// The vault's delegation state is the sum of its operator delegations. Only checked once
+    /// the vault has been fully updated for the epoch, since cranking updates the delegations
+    /// before the vault.
+    DelegationState,
+    /// VRT ready to claim can be redeemed from undelegated supported tokens. Only checked once
+    /// the vault has been fully updated for the epoch.
+    ReadyToClaimCovered,
+    /// Withdrawal tickets add up to the VRT enqueued for cooldown, cooling down and ready to
+    /// claim
+    WithdrawalTickets,
+    /// Every withdrawal ticket's token account holds the ticket's VRT
+    TicketVrtBalance,
+}
+
+impl Invariant {
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Self::VrtSupply => "vrt_supply",
+            Self::StBalance => "st_balance",
+            Self::DelegationState => "delegation_state",
+            Self::ReadyToClaimCovered => "ready_to_claim_covered",
+            Self::WithdrawalTickets => "withdrawal_tickets",
+            Self::TicketVrtBalance => "ticket_vrt_balance",
+        }
+    }
+}
+
+impl fmt::Display for Invariant {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// A broken [`Invariant`]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Violation {
+    #[serde(with = "serde_with::As::<serde_with::DisplayFromStr>")]
+    pub vault: Pubkey,
+    pub invariant: Invariant,
+    pub message: String,
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "vault {} broke {}: {}",
+            self.vault, self.invariant, self.message
+        )
+    }
+}
+
+/// A withdrawal ticket and the VRT balance of its token account
+#[derive(Debug, Clone, Copy)]
+pub struct WithdrawalTicketSnapshot {
+    pub pubkey: Pubkey,
+    pub ticket: VaultStakerWithdrawalTicket,
+    /// Zero if the token account doesn't exist
+    pub vrt_balance: u64,
+}
+
+/// Everything needed to check a single vault
+#[derive(Debug, Clone)]
+pub struct VaultSnapshot {
+    pub vault_pubkey: Pubkey,
+    pub vault: Vault,
+    pub delegations: Vec<VaultOperatorDelegation>,
+    pub withdrawal_tickets: Vec<WithdrawalTicketSnapshot>,
+    /// `None` if the VRT mint doesn't exist
+    pub vrt_mint_supply: Option<u64>,
+    /// The balance of the vault's supported token account, zero if it doesn't exist
+    pub st_balance: u64,
+}
+
+/// Checks every [`Invariant`] against a vault.
+///
+/// # Arguments
+/// * `snapshot` - The vault and its related accounts
+/// * `slot` - The slot the snapshot was taken at
+/// * `epoch_length` - The NCN epoch length in slots
+pub fn check_vault(snapshot: &VaultSnapshot, slot: u64, epoch_length: u64) -> Vec<Violation> {
+    let vault = &snapshot.vault;
+    let mut violations = Vec::new();
+    let mut violation = |invariant: Invariant, message: String| {
+        violations.push(Violation {
+            vault: snapshot.vault_pubkey,
+            invariant,
+            message,
+        })
+    };
+
+    match snapshot.vrt_mint_supply {
+        None => violation(
+            Invariant::VrtSupply,
+            format!("VRT mint {} not found", vault.vrt_mint),
+        ),
+        Some(supply) if supply != vault.vrt_supply() => violation(
+            Invariant::VrtSupply,
+            format!(
+                "vault VRT supply {} != mint supply {supply}",
+                vault.vrt_supply()
+            ),
+        ),
+        Some(_) => {}
+    }
+
+    if snapshot.st_balance < vault.tokens_deposited() {
+        violation(
+            Invariant::StBalance,
+            format!(
+                "tokens deposited {} > token account balance {}",
+                vault.tokens_deposited(),
+                snapshot.st_balance
+            ),
+        );
+    }
+
+    let is_updated = !vault
+        .is_update_needed(slot, epoch_length)
+        .expect("Config epoch length is 0");
+    if is_updated {
+        check_delegation_state(snapshot, &mut violation);
+        check_ready_to_claim_covered(vault, &mut violation);
+    }
+
+    let ticket_vrt: u128 = snapshot
+        .withdrawal_tickets
+        .iter()
+        .map(|ticket| ticket.ticket.vrt_amount() as u128)
+        .sum();
+    let vault_vrt_withdrawing = vault.vrt_enqueued_for_cooldown_amount() as u128
+        + vault.vrt_cooling_down_amount() as u128
+        + vault.vrt_ready_to_claim_amount() as u128;
+    if ticket_vrt != vault_vrt_withdrawing {
+        violation(
+            Invariant::WithdrawalTickets,
+            format!(
+                "{} withdrawal tickets hold {ticket_vrt} VRT, but the vault has {} enqueued, {} \
+                 cooling down and {} ready to claim",
+                snapshot.withdrawal_tickets.len(),
+                vault.vrt_enqueued_for_cooldown_amount(),
+                vault.vrt_cooling_down_amount(),
+                vault.vrt_ready_to_claim_amount()
+            ),
+        );
+    }
+
+    for ticket in snapshot.withdrawal_tickets.iter() {
+        if ticket.vrt_balance < ticket.ticket.vrt_amount() {
+            violation(
+                Invariant::TicketVrtBalance,
+                format!(
+                    "withdrawal ticket {} is for {} VRT, but its token account holds {}",
+                    ticket.pubkey,
+                    ticket.ticket.vrt_amount(),
+                    ticket.vrt_balance
+                ),
+            );
+        }
+    }
+
+    violations
+}
+
+fn check_delegation_state(snapshot: &VaultSnapshot, violation: &mut impl FnMut(Invariant, String)) {
+    let mut total = DelegationState::default();
+    for delegation in snapshot.delegations.iter() {
+        if let Err(e) = total.accumulate(&delegation.delegation_state) {
+            violation(
+                Invariant::DelegationState,
+                format!("failed to sum operator delegations: {e}"),
+            );
+            return;
+        }
+    }
+
+    let vault_state = &snapshot.vault.delegation_state;
+    if total != *vault_state {
+        violation(
+            Invariant::DelegationState,
+            format!(
+                "vault delegation state (staked {}, enqueued {}, cooling down {}) != sum of {} \
+                 operator delegations (staked {}, enqueued {}, cooling down {})",
+                vault_state.staked_amount(),
+                vault_state.enqueued_for_cooldown_amount(),
+                vault_state.cooling_down_amount(),
+                snapshot.delegations.len(),
+                total.staked_amount(),
+                total.enqueued_for_cooldown_amount(),
+                total.cooling_down_amount()
+            ),
+        );
+    }
+}
+
+fn check_ready_to_claim_covered(vault: &Vault, violation: &mut impl FnMut(Invariant, String)) {
+    let ready_to_claim = vault.vrt_ready_to_claim_amount();
+    if ready_to_claim == 0 {
+        return;
+    }
+
+    let (ready_to_claim_st, delegated) = match (
+        vault.calculate_burn_summary(false, false, ready_to_claim),
+        vault.delegation_state.total_security(),
+    ) {
+        (Ok(summary), Ok(delegated)) => (summary.out_amount, delegated),
+        (Err(e), _) | (_, Err(e)) => {
+            violation(
+                Invariant::ReadyToClaimCovered,
+                format!("failed to value VRT ready to claim: {e}"),
+            );
+            return;
+        }
+    };
+
+    let idle = vault.tokens_deposited().saturating_sub(delegated);
+    if ready_to_claim_st > idle {
+        violation(
+            Invariant::ReadyToClaimCovered,
+            format!(
+                "{ready_to_claim} VRT ready to claim redeems for {ready_to_claim_st} tokens, but \
+                 only {idle} are undelegated"
+            ),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EPOCH_LENGTH: u64 = 100;
+
+    /// A healthy vault with 1000 tokens deposited, 400 delegated to two operators, and 100 VRT
+    /// ready to claim through a single withdrawal ticket
+    fn healthy_snapshot() -> VaultSnapshot {
+        let vault_pubkey = Pubkey::new_unique();
+        let mut vault = Vault::new(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            0,
+            Pubkey::new_unique(),
+            0,
+            0,
+            0,
+            0,
+            0,
+            EPOCH_LENGTH,
+        )
+        .unwrap();
+        vault.set_tokens_deposited(1000);
+        vault.set_vrt_supply(1000);
+        vault.increment_vrt_ready_to_claim_amount(100).unwrap();
+        vault.delegation_state = DelegationState::new(300, 100, 0);
+
+        let delegations = vec![
+            delegation(vault_pubkey, DelegationState::new(200, 100, 0)),
+            delegation(vault_pubkey, DelegationState::new(100, 0, 0)),
+        ];
+        let ticket = VaultStakerWithdrawalTicket::new(
+            vault_pubkey,
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            100,
+            0,
+            0,
+        );
+
+        VaultSnapshot {
+            vault_pubkey,
+            vault,
+            delegations,
+            withdrawal_tickets: vec![WithdrawalTicketSnapshot {
+                pubkey: Pubkey::new_unique(),
+                ticket,
+                vrt_balance: 100,
+            }],
+            vrt_mint_supply: Some(1000),
+            st_balance: 1000,
+        }
+    }
+
+    fn delegation(vault: Pubkey, delegation_state: DelegationState) -> VaultOperatorDelegation {
+        let mut delegation = VaultOperatorDelegation::new(vault, Pubkey::new_unique(), 0, 0, 0);
+        delegation.delegation_state = delegation_state;
+        delegation
+    }
+
+    fn broken_invariants(snapshot: &VaultSnapshot, slot: u64) -> Vec<Invariant> {
+        check_vault(snapshot, slot, EPOCH_LENGTH)
+            .into_iter()
+            .map(|violation| violation.invariant)
+            .collect()
+    }
+
+    #[test]
+    fn test_healthy_vault() {
+        assert!(check_vault(&healthy_snapshot(), EPOCH_LENGTH, EPOCH_LENGTH).is_empty());
+    }
+
+    #[test]
+    fn test_supply_drift() {
+        let mut snapshot = healthy_snapshot();
+        snapshot.vrt_mint_supply = Some(999);
+        snapshot.st_balance = 999;
+        assert_eq!(
+            broken_invariants(&snapshot, EPOCH_LENGTH),
+            vec![Invariant::VrtSupply, Invariant::StBalance]
+        );
+
+        snapshot.vrt_mint_supply = None;
+        snapshot.st_balance = 1001;
+        assert_eq!(
+            broken_invariants(&snapshot, EPOCH_LENGTH),
+            vec![Invariant::VrtSupply]
+        );
+    }
+
+    #[test]
+    fn test_delegation_state_only_checked_once_updated() {
+        let mut snapshot = healthy_snapshot();
+        snapshot.delegations.pop();
+
+        // Mid-update the delegations are ahead of the vault
+        assert!(broken_invariants(&snapshot, 2 * EPOCH_LENGTH).is_empty());
+        assert_eq!(
+            broken_invariants(&snapshot, EPOCH_LENGTH),
+            vec![Invariant::DelegationState]
+        );
+    }
+
+    #[test]
+    fn test_ready_to_claim_not_covered() {
+        let mut snapshot = healthy_snapshot();
+        snapshot.vault.delegation_state = DelegationState::new(950, 0, 0);
+        snapshot.delegations = vec![delegation(
+            snapshot.vault_pubkey,
+            DelegationState::new(950, 0, 0),
+        )];
+
+        assert_eq!(
+            broken_invariants(&snapshot, EPOCH_LENGTH),
+            vec![Invariant::ReadyToClaimCovered]
+        );
+    }
+
+    #[test]
+    fn test_withdrawal_ticket_drift() {
+        let mut snapshot = healthy_snapshot();
+        snapshot.withdrawal_tickets[0].vrt_balance = 50;
+        snapshot.vault.set_vrt_enqueued_for_cooldown_amount(10);
+
+        let violations = check_vault(&snapshot, EPOCH_LENGTH, EPOCH_LENGTH);
+        assert_eq!(
+            violations
+                .iter()
+                .map(|violation| violation.invariant)
+                .collect::<Vec<_>>(),
+            vec![Invariant::WithdrawalTickets, Invariant::TicketVrtBalance]
+        );
+        assert!(violations[1]
+            .message
+            .contains(&snapshot.withdrawal_tickets[0].pubkey.to_string()));
+    }
+}
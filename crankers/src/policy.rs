@@ -0,0 +1,220 @@
+//! Declarative desired state for NCN, operator and vault links.
+//!
+//! A policy file lists which links should be active. The cranker compares each link against the
+//! on-chain [`SlotToggleState`] of the matching ticket and submits whichever `Initialize*`,
+//! `Warmup*` or `Cooldown*` instruction moves it closer to the desired state.
+//!
+//! Policies may be written in YAML (`.yaml`/`.yml`) or TOML (`.toml`):
+//!
+//! ```yaml
+//! ncn_operators:
+//!   - ncn: <NCN_PUBKEY>
+//!     operator: <OPERATOR_PUBKEY>
+//! operator_vaults:
+//!   - operator: <OPERATOR_PUBKEY>
+//!     vault: <VAULT_PUBKEY>
+//!     active: false
+//! ```
+
+use std::{fs, path::Path};
+
+use anyhow::{anyhow, Context};
+use jito_jsm_core::slot_toggle::SlotToggleState;
+use serde::Deserialize;
+use solana_sdk::pubkey::Pubkey;
+
+/// The links the cranker should maintain
+#[derive(Debug, Default, Clone, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct DesiredState {
+    /// NCN <> operator links, tracked by the `NcnOperatorState` account
+    #[serde(default)]
+    pub ncn_operators: Vec<NcnOperatorLink>,
+
+    /// NCN -> vault links, tracked by the `NcnVaultTicket` account
+    #[serde(default)]
+    pub ncn_vaults: Vec<NcnVaultLink>,
+
+    /// Operator -> vault links, tracked by the `OperatorVaultTicket` account
+    #[serde(default)]
+    pub operator_vaults: Vec<OperatorVaultLink>,
+
+    /// Vault -> NCN links, tracked by the `VaultNcnTicket` account
+    #[serde(default)]
+    pub vault_ncns: Vec<VaultNcnLink>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct NcnOperatorLink {
+    #[serde(with = "serde_with::As::<serde_with::DisplayFromStr>")]
+    pub ncn: Pubkey,
+    #[serde(with = "serde_with::As::<serde_with::DisplayFromStr>")]
+    pub operator: Pubkey,
+    #[serde(default = "default_active")]
+    pub active: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct NcnVaultLink {
+    #[serde(with = "serde_with::As::<serde_with::DisplayFromStr>")]
+    pub ncn: Pubkey,
+    #[serde(with = "serde_with::As::<serde_with::DisplayFromStr>")]
+    pub vault: Pubkey,
+    #[serde(default = "default_active")]
+    pub active: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct OperatorVaultLink {
+    #[serde(with = "serde_with::As::<serde_with::DisplayFromStr>")]
+    pub operator: Pubkey,
+    #[serde(with = "serde_with::As::<serde_with::DisplayFromStr>")]
+    pub vault: Pubkey,
+    #[serde(default = "default_active")]
+    pub active: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct VaultNcnLink {
+    #[serde(with = "serde_with::As::<serde_with::DisplayFromStr>")]
+    pub vault: Pubkey,
+    #[serde(with = "serde_with::As::<serde_with::DisplayFromStr>")]
+    pub ncn: Pubkey,
+    #[serde(default = "default_active")]
+    pub active: bool,
+}
+
+const fn default_active() -> bool {
+    true
+}
+
+impl DesiredState {
+    /// Reads a policy file, choosing the format from the file extension
+    pub fn from_path(path: &Path) -> anyhow::Result<Self> {
+        let contents = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read policy file {}", path.display()))?;
+
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some("yaml" | "yml") => Self::from_yaml(&contents),
+            Some("toml") => Self::from_toml(&contents),
+            _ => Err(anyhow!(
+                "Unsupported policy file {}, expected a .yaml, .yml or .toml extension",
+                path.display()
+            )),
+        }
+    }
+
+    pub fn from_yaml(contents: &str) -> anyhow::Result<Self> {
+        serde_yaml::from_str(contents).context("Failed to parse YAML policy")
+    }
+
+    pub fn from_toml(contents: &str) -> anyhow::Result<Self> {
+        toml::from_str(contents).context("Failed to parse TOML policy")
+    }
+}
+
+/// The next step needed to move a single [`jito_jsm_core::slot_toggle::SlotToggle`] towards its
+/// desired state
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ToggleAction {
+    /// The toggle already matches the desired state
+    None,
+    /// The ticket account does not exist yet and must be initialized
+    Initialize,
+    /// The toggle is inactive and should start warming up
+    Warmup,
+    /// The toggle is active and should start cooling down
+    Cooldown,
+    /// The toggle is in warm-up or cooldown and can only be changed once it settles at the next
+    /// epoch boundary
+    Wait,
+}
+
+/// Determines the action needed to move a toggle to the desired state.
+///
+/// # Arguments
+/// * `state` - The on-chain state of the toggle, or `None` if the ticket account does not exist
+/// * `active` - Whether the link should be active
+///
+/// A freshly initialized ticket is inactive, so an active link takes two passes: one to
+/// initialize the ticket and one to warm it up.
+pub const fn toggle_action(state: Option<&SlotToggleState>, active: bool) -> ToggleAction {
+    match (state, active) {
+        (None, true) => ToggleAction::Initialize,
+        (None, false) => ToggleAction::None,
+        (Some(SlotToggleState::Inactive), true) => ToggleAction::Warmup,
+        (Some(SlotToggleState::Active), false) => ToggleAction::Cooldown,
+        (Some(SlotToggleState::WarmUp), false) | (Some(SlotToggleState::Cooldown), true) => {
+            ToggleAction::Wait
+        }
+        (Some(_), _) => ToggleAction::None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+
+    const NCN: &str = "11111111111111111111111111111112";
+    const OPERATOR: &str = "11111111111111111111111111111113";
+    const VAULT: &str = "11111111111111111111111111111114";
+
+    #[test]
+    fn test_toggle_action() {
+        use SlotToggleState::*;
+
+        assert_eq!(toggle_action(None, true), ToggleAction::Initialize);
+        assert_eq!(toggle_action(None, false), ToggleAction::None);
+
+        assert_eq!(toggle_action(Some(&Inactive), true), ToggleAction::Warmup);
+        assert_eq!(toggle_action(Some(&WarmUp), true), ToggleAction::None);
+        assert_eq!(toggle_action(Some(&Active), true), ToggleAction::None);
+        assert_eq!(toggle_action(Some(&Cooldown), true), ToggleAction::Wait);
+
+        assert_eq!(toggle_action(Some(&Inactive), false), ToggleAction::None);
+        assert_eq!(toggle_action(Some(&WarmUp), false), ToggleAction::Wait);
+        assert_eq!(toggle_action(Some(&Active), false), ToggleAction::Cooldown);
+        assert_eq!(toggle_action(Some(&Cooldown), false), ToggleAction::None);
+    }
+
+    #[test]
+    fn test_yaml_and_toml_policies_match() {
+        let yaml = format!(
+            "ncn_operators:\n  - ncn: {NCN}\n    operator: {OPERATOR}\n\
+             operator_vaults:\n  - operator: {OPERATOR}\n    vault: {VAULT}\n    active: false\n"
+        );
+        let toml = format!(
+            "[[ncn_operators]]\nncn = \"{NCN}\"\noperator = \"{OPERATOR}\"\n\n\
+             [[operator_vaults]]\noperator = \"{OPERATOR}\"\nvault = \"{VAULT}\"\nactive = false\n"
+        );
+
+        let from_yaml = DesiredState::from_yaml(&yaml).unwrap();
+        let from_toml = DesiredState::from_toml(&toml).unwrap();
+        assert_eq!(from_yaml, from_toml);
+
+        assert_eq!(
+            from_yaml.ncn_operators,
+            vec![NcnOperatorLink {
+                ncn: Pubkey::from_str(NCN).unwrap(),
+                operator: Pubkey::from_str(OPERATOR).unwrap(),
+                active: true,
+            }]
+        );
+        assert!(!from_yaml.operator_vaults[0].active);
+        assert!(from_yaml.ncn_vaults.is_empty());
+        assert!(from_yaml.vault_ncns.is_empty());
+    }
+
+    #[test]
+    fn test_policy_rejects_unknown_fields() {
+        let yaml =
+            format!("ncn_operators:\n  - ncn: {NCN}\n    operator: {OPERATOR}\n    foo: 1\n");
+        assert!(DesiredState::from_yaml(&yaml).is_err());
+    }
+}
@@ -0,0 +1,892 @@
+use std::{collections::HashMap, fmt, sync::Arc};
+
+use anyhow::{anyhow, Context};
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::slot_toggle::{SlotToggle, SlotToggleState};
+use jito_restaking_client::instructions::{
+    CooldownNcnVaultTicketBuilder, CooldownOperatorVaultTicketBuilder,
+    InitializeNcnOperatorStateBuilder, InitializeNcnVaultTicketBuilder,
+    InitializeOperatorVaultTicketBuilder, NcnCooldownOperatorBuilder, NcnWarmupOperatorBuilder,
+    OperatorCooldownNcnBuilder, OperatorWarmupNcnBuilder, WarmupNcnVaultTicketBuilder,
+    WarmupOperatorVaultTicketBuilder,
+};
+use jito_restaking_core::{
+    ncn::Ncn, ncn_operator_state::NcnOperatorState, ncn_vault_ticket::NcnVaultTicket,
+    operator::Operator, operator_vault_ticket::OperatorVaultTicket,
+};
+use jito_vault_client::instructions::{
+    CooldownVaultNcnTicketBuilder, InitializeVaultNcnTicketBuilder, WarmupVaultNcnTicketBuilder,
+};
+use jito_vault_core::{vault::Vault, vault_ncn_ticket::VaultNcnTicket};
+use log::{error, info, warn};
+use solana_rpc_client::nonblocking::rpc_client::RpcClient;
+use solana_sdk::{
+    account::Account, commitment_config::CommitmentConfig, instruction::Instruction,
+    pubkey::Pubkey, signature::Keypair, signer::Signer,
+};
+
+use crate::{
+    core::{get_multiple_accounts_batched, send_and_confirm_transaction, PriorityFeeConfig},
+    policy::{
+        toggle_action, DesiredState, NcnOperatorLink, NcnVaultLink, OperatorVaultLink,
+        ToggleAction, VaultNcnLink,
+    },
+};
+
+/// An instruction needed to move a link towards its desired state
+pub struct PolicyInstruction {
+    /// Human readable summary used for logging
+    pub description: String,
+    pub instruction: Instruction,
+}
+
+/// On-chain accounts and cluster state shared by a single planning pass
+struct PolicyContext {
+    accounts: HashMap<Pubkey, Account>,
+    slot: u64,
+    restaking_config: Pubkey,
+    restaking_epoch_length: u64,
+    vault_config: Pubkey,
+    vault_epoch_length: u64,
+}
+
+impl PolicyContext {
+    /// Deserializes the account at `pubkey`, returning `None` if it does not exist
+    fn get<T: AccountDeserialize>(&self, pubkey: &Pubkey) -> anyhow::Result<Option<&T>> {
+        get_account(&self.accounts, pubkey)
+    }
+
+    /// Deserializes the account at `pubkey`, failing if it does not exist
+    fn get_required<T: AccountDeserialize>(&self, pubkey: &Pubkey) -> anyhow::Result<&T> {
+        get_required_account(&self.accounts, pubkey)
+    }
+}
+
+fn get_account<'a, T: AccountDeserialize>(
+    accounts: &'a HashMap<Pubkey, Account>,
+    pubkey: &Pubkey,
+) -> anyhow::Result<Option<&'a T>> {
+    accounts
+        .get(pubkey)
+        .map(|account| {
+            T::try_from_slice_unchecked(&account.data)
+                .with_context(|| format!("Failed to deserialize account {pubkey}"))
+        })
+        .transpose()
+}
+
+fn get_required_account<'a, T: AccountDeserialize>(
+    accounts: &'a HashMap<Pubkey, Account>,
+    pubkey: &Pubkey,
+) -> anyhow::Result<&'a T> {
+    get_account(accounts, pubkey)?.ok_or_else(|| anyhow!("Account {pubkey} does not exist"))
+}
+
+/// Drives NCN, operator and vault tickets towards a [`DesiredState`]
+pub struct PolicyHandler<'a> {
+    rpc_url: String,
+    payer: &'a Keypair,
+    restaking_program_id: Pubkey,
+    vault_program_id: Pubkey,
+    priority_fees: PriorityFeeConfig,
+}
+
+impl<'a> PolicyHandler<'a> {
+    pub fn new(
+        rpc_url: &str,
+        payer: &'a Keypair,
+        restaking_program_id: Pubkey,
+        vault_program_id: Pubkey,
+        priority_fees: PriorityFeeConfig,
+    ) -> Self {
+        Self {
+            rpc_url: rpc_url.to_string(),
+            payer,
+            restaking_program_id,
+            vault_program_id,
+            priority_fees,
+        }
+    }
+
+    /// Creates a new `RpcClient` instance with the `confirmed` commitment level.
+    fn get_rpc_client(&self) -> RpcClient {
+        RpcClient::new_with_commitment(self.rpc_url.clone(), CommitmentConfig::confirmed())
+    }
+
+    /// Fetches the current slot, both program configs and every account referenced by the
+    /// desired state
+    async fn get_context(&self, desired_state: &DesiredState) -> anyhow::Result<PolicyContext> {
+        let rpc_client = self.get_rpc_client();
+        let slot = rpc_client.get_slot().await.context("Failed to get slot")?;
+
+        let restaking_config =
+            jito_restaking_core::config::Config::find_program_address(&self.restaking_program_id).0;
+        let vault_config =
+            jito_vault_core::config::Config::find_program_address(&self.vault_program_id).0;
+
+        let mut pubkeys = vec![restaking_config, vault_config];
+        for link in desired_state.ncn_operators.iter() {
+            pubkeys.extend([
+                link.ncn,
+                link.operator,
+                NcnOperatorState::find_program_address(
+                    &self.restaking_program_id,
+                    &link.ncn,
+                    &link.operator,
+                )
+                .0,
+            ]);
+        }
+        for link in desired_state.ncn_vaults.iter() {
+            pubkeys.extend([
+                link.ncn,
+                NcnVaultTicket::find_program_address(
+                    &self.restaking_program_id,
+                    &link.ncn,
+                    &link.vault,
+                )
+                .0,
+            ]);
+        }
+        for link in desired_state.operator_vaults.iter() {
+            pubkeys.extend([
+                link.operator,
+                OperatorVaultTicket::find_program_address(
+                    &self.restaking_program_id,
+                    &link.operator,
+                    &link.vault,
+                )
+                .0,
+            ]);
+        }
+        for link in desired_state.vault_ncns.iter() {
+            pubkeys.extend([
+                link.vault,
+                VaultNcnTicket::find_program_address(
+                    &self.vault_program_id,
+                    &link.vault,
+                    &link.ncn,
+                )
+                .0,
+            ]);
+        }
+        pubkeys.sort();
+        pubkeys.dedup();
+
+        let accounts = get_multiple_accounts_batched(&pubkeys, &Arc::new(rpc_client)).await?;
+        let accounts: HashMap<Pubkey, Account> = pubkeys
+            .into_iter()
+            .zip(accounts)
+            .filter_map(|(pubkey, account)| account.map(|account| (pubkey, account)))
+            .collect();
+
+        let restaking_epoch_length = get_required_account::<jito_restaking_core::config::Config>(
+            &accounts,
+            &restaking_config,
+        )
+        .context("Failed to read Jito restaking config")?
+        .epoch_length();
+        let vault_epoch_length =
+            get_required_account::<jito_vault_core::config::Config>(&accounts, &vault_config)
+                .context("Failed to read Jito vault config")?
+                .epoch_length();
+
+        Ok(PolicyContext {
+            accounts,
+            slot,
+            restaking_config,
+            restaking_epoch_length,
+            vault_config,
+            vault_epoch_length,
+        })
+    }
+
+    /// Compares the desired state against the chain and returns the instructions needed to move
+    /// every link one step closer to it.
+    ///
+    /// Restaking tickets are planned before vault tickets since initializing a [`VaultNcnTicket`]
+    /// requires the matching [`NcnVaultTicket`]. Links that can't be planned are logged and
+    /// skipped so a single bad entry doesn't block the rest of the policy.
+    pub async fn plan(
+        &self,
+        desired_state: &DesiredState,
+    ) -> anyhow::Result<Vec<PolicyInstruction>> {
+        let context = self.get_context(desired_state).await?;
+        Ok(self.plan_with_context(&context, desired_state))
+    }
+
+    /// Plans the desired state against already fetched accounts, see [`Self::plan`]
+    fn plan_with_context(
+        &self,
+        context: &PolicyContext,
+        desired_state: &DesiredState,
+    ) -> Vec<PolicyInstruction> {
+        let mut instructions = Vec::new();
+
+        for link in desired_state.ncn_operators.iter() {
+            if let Err(e) = self.plan_ncn_operator(context, link, &mut instructions) {
+                warn!(
+                    "Skipping NCN {} <> operator {}: {e}",
+                    link.ncn, link.operator
+                );
+            }
+        }
+        for link in desired_state.ncn_vaults.iter() {
+            if let Err(e) = self.plan_ncn_vault(context, link, &mut instructions) {
+                warn!("Skipping NCN {} -> vault {}: {e}", link.ncn, link.vault);
+            }
+        }
+        for link in desired_state.operator_vaults.iter() {
+            if let Err(e) = self.plan_operator_vault(context, link, &mut instructions) {
+                warn!(
+                    "Skipping operator {} -> vault {}: {e}",
+                    link.operator, link.vault
+                );
+            }
+        }
+        for link in desired_state.vault_ncns.iter() {
+            if let Err(e) = self.plan_vault_ncn(context, link, &mut instructions) {
+                warn!("Skipping vault {} -> NCN {}: {e}", link.vault, link.ncn);
+            }
+        }
+
+        instructions
+    }
+
+    /// Plans and submits a single pass over the desired state. Each instruction is sent in its
+    /// own transaction; failures are logged and picked up again on the next pass.
+    pub async fn enforce(&self, desired_state: &DesiredState) -> anyhow::Result<()> {
+        let instructions = self.plan(desired_state).await?;
+
+        info!("Submitting {} policy instructions", instructions.len());
+
+        for PolicyInstruction {
+            description,
+            instruction,
+        } in instructions
+        {
+            match send_and_confirm_transaction(
+                &self.get_rpc_client(),
+                self.payer,
+                &[instruction],
+                &self.priority_fees,
+            )
+            .await
+            {
+                Ok(signature) => info!("{description}: {signature}"),
+                Err(e) => error!("{description}: {e}"),
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Queues the instruction for `action` if the payer is allowed to submit it.
+    ///
+    /// # Arguments
+    /// * `action` - The action needed to reach the desired state
+    /// * `description` - The link being updated, used for logging
+    /// * `admin` - The admin that must sign the instruction
+    /// * `build` - Builds the instruction for an `Initialize`, `Warmup` or `Cooldown` action
+    fn push_action(
+        &self,
+        instructions: &mut Vec<PolicyInstruction>,
+        action: ToggleAction,
+        description: impl fmt::Display,
+        admin: &Pubkey,
+        build: impl FnOnce(ToggleAction) -> Instruction,
+    ) {
+        match action {
+            ToggleAction::None => {}
+            ToggleAction::Wait => {
+                info!("{description}: waiting for the next epoch boundary");
+            }
+            _ if admin.ne(&self.payer.pubkey()) => {
+                warn!(
+                    "{description}: {action:?} requires admin {admin}, but the cranker signs as {}",
+                    self.payer.pubkey()
+                );
+            }
+            _ => instructions.push(PolicyInstruction {
+                description: format!("{action:?} {description}"),
+                instruction: build(action),
+            }),
+        }
+    }
+
+    /// Plans both sides of an NCN <> operator link. Only the NCN can initialize the
+    /// [`NcnOperatorState`], so the operator side waits until it exists.
+    fn plan_ncn_operator(
+        &self,
+        context: &PolicyContext,
+        link: &NcnOperatorLink,
+        instructions: &mut Vec<PolicyInstruction>,
+    ) -> anyhow::Result<()> {
+        let ncn = context.get_required::<Ncn>(&link.ncn)?;
+        let operator = context.get_required::<Operator>(&link.operator)?;
+        let ncn_operator_state_pubkey = NcnOperatorState::find_program_address(
+            &self.restaking_program_id,
+            &link.ncn,
+            &link.operator,
+        )
+        .0;
+        let ncn_operator_state = context.get::<NcnOperatorState>(&ncn_operator_state_pubkey)?;
+
+        let ncn_state = ncn_operator_state
+            .map(|state| {
+                Self::toggle_state(
+                    &state.ncn_opt_in_state,
+                    context.slot,
+                    context.restaking_epoch_length,
+                )
+            })
+            .transpose()?;
+        self.push_action(
+            instructions,
+            toggle_action(ncn_state.as_ref(), link.active),
+            format_args!("NCN {} opt-in for operator {}", link.ncn, link.operator),
+            &ncn.operator_admin,
+            |action| {
+                let mut ix = match action {
+                    ToggleAction::Initialize => InitializeNcnOperatorStateBuilder::new()
+                        .config(context.restaking_config)
+                        .ncn(link.ncn)
+                        .operator(link.operator)
+                        .ncn_operator_state(ncn_operator_state_pubkey)
+                        .admin(self.payer.pubkey())
+                        .payer(self.payer.pubkey())
+                        .instruction(),
+                    ToggleAction::Warmup => NcnWarmupOperatorBuilder::new()
+                        .config(context.restaking_config)
+                        .ncn(link.ncn)
+                        .operator(link.operator)
+                        .ncn_operator_state(ncn_operator_state_pubkey)
+                        .admin(self.payer.pubkey())
+                        .instruction(),
+                    _ => NcnCooldownOperatorBuilder::new()
+                        .config(context.restaking_config)
+                        .ncn(link.ncn)
+                        .operator(link.operator)
+                        .ncn_operator_state(ncn_operator_state_pubkey)
+                        .admin(self.payer.pubkey())
+                        .instruction(),
+                };
+                ix.program_id = self.restaking_program_id;
+                ix
+            },
+        );
+
+        let Some(ncn_operator_state) = ncn_operator_state else {
+            if link.active {
+                info!(
+                    "Operator {} opt-in for NCN {}: waiting for the NCN to initialize the link",
+                    link.operator, link.ncn
+                );
+            }
+            return Ok(());
+        };
+        let operator_state = Self::toggle_state(
+            &ncn_operator_state.operator_opt_in_state,
+            context.slot,
+            context.restaking_epoch_length,
+        )?;
+        self.push_action(
+            instructions,
+            toggle_action(Some(&operator_state), link.active),
+            format_args!("operator {} opt-in for NCN {}", link.operator, link.ncn),
+            &operator.ncn_admin,
+            |action| {
+                let mut ix = match action {
+                    ToggleAction::Warmup => OperatorWarmupNcnBuilder::new()
+                        .config(context.restaking_config)
+                        .ncn(link.ncn)
+                        .operator(link.operator)
+                        .ncn_operator_state(ncn_operator_state_pubkey)
+                        .admin(self.payer.pubkey())
+                        .instruction(),
+                    _ => OperatorCooldownNcnBuilder::new()
+                        .config(context.restaking_config)
+                        .ncn(link.ncn)
+                        .operator(link.operator)
+                        .ncn_operator_state(ncn_operator_state_pubkey)
+                        .admin(self.payer.pubkey())
+                        .instruction(),
+                };
+                ix.program_id = self.restaking_program_id;
+                ix
+            },
+        );
+
+        Ok(())
+    }
+
+    fn plan_ncn_vault(
+        &self,
+        context: &PolicyContext,
+        link: &NcnVaultLink,
+        instructions: &mut Vec<PolicyInstruction>,
+    ) -> anyhow::Result<()> {
+        let ncn = context.get_required::<Ncn>(&link.ncn)?;
+        let ncn_vault_ticket_pubkey = NcnVaultTicket::find_program_address(
+            &self.restaking_program_id,
+            &link.ncn,
+            &link.vault,
+        )
+        .0;
+        let state = context
+            .get::<NcnVaultTicket>(&ncn_vault_ticket_pubkey)?
+            .map(|ticket| {
+                Self::toggle_state(&ticket.state, context.slot, context.restaking_epoch_length)
+            })
+            .transpose()?;
+
+        self.push_action(
+            instructions,
+            toggle_action(state.as_ref(), link.active),
+            format_args!("NCN {} -> vault {}", link.ncn, link.vault),
+            &ncn.vault_admin,
+            |action| {
+                let mut ix = match action {
+                    ToggleAction::Initialize => InitializeNcnVaultTicketBuilder::new()
+                        .config(context.restaking_config)
+                        .ncn(link.ncn)
+                        .vault(link.vault)
+                        .ncn_vault_ticket(ncn_vault_ticket_pubkey)
+                        .admin(self.payer.pubkey())
+                        .payer(self.payer.pubkey())
+                        .instruction(),
+                    ToggleAction::Warmup => WarmupNcnVaultTicketBuilder::new()
+                        .config(context.restaking_config)
+                        .ncn(link.ncn)
+                        .vault(link.vault)
+                        .ncn_vault_ticket(ncn_vault_ticket_pubkey)
+                        .admin(self.payer.pubkey())
+                        .instruction(),
+                    _ => CooldownNcnVaultTicketBuilder::new()
+                        .config(context.restaking_config)
+                        .ncn(link.ncn)
+                        .vault(link.vault)
+                        .ncn_vault_ticket(ncn_vault_ticket_pubkey)
+                        .admin(self.payer.pubkey())
+                        .instruction(),
+                };
+                ix.program_id = self.restaking_program_id;
+                ix
+            },
+        );
+
+        Ok(())
+    }
+
+    fn plan_operator_vault(
+        &self,
+        context: &PolicyContext,
+        link: &OperatorVaultLink,
+        instructions: &mut Vec<PolicyInstruction>,
+    ) -> anyhow::Result<()> {
+        let operator = context.get_required::<Operator>(&link.operator)?;
+        let operator_vault_ticket_pubkey = OperatorVaultTicket::find_program_address(
+            &self.restaking_program_id,
+            &link.operator,
+            &link.vault,
+        )
+        .0;
+        let state = context
+            .get::<OperatorVaultTicket>(&operator_vault_ticket_pubkey)?
+            .map(|ticket| {
+                Self::toggle_state(&ticket.state, context.slot, context.restaking_epoch_length)
+            })
+            .transpose()?;
+
+        self.push_action(
+            instructions,
+            toggle_action(state.as_ref(), link.active),
+            format_args!("operator {} -> vault {}", link.operator, link.vault),
+            &operator.vault_admin,
+            |action| {
+                let mut ix = match action {
+                    ToggleAction::Initialize => InitializeOperatorVaultTicketBuilder::new()
+                        .config(context.restaking_config)
+                        .operator(link.operator)
+                        .vault(link.vault)
+                        .operator_vault_ticket(operator_vault_ticket_pubkey)
+                        .admin(self.payer.pubkey())
+                        .payer(self.payer.pubkey())
+                        .instruction(),
+                    ToggleAction::Warmup => WarmupOperatorVaultTicketBuilder::new()
+                        .config(context.restaking_config)
+                        .operator(link.operator)
+                        .vault(link.vault)
+                        .operator_vault_ticket(operator_vault_ticket_pubkey)
+                        .admin(self.payer.pubkey())
+                        .instruction(),
+                    _ => CooldownOperatorVaultTicketBuilder::new()
+                        .config(context.restaking_config)
+                        .operator(link.operator)
+                        .vault(link.vault)
+                        .operator_vault_ticket(operator_vault_ticket_pubkey)
+                        .admin(self.payer.pubkey())
+                        .instruction(),
+                };
+                ix.program_id = self.restaking_program_id;
+                ix
+            },
+        );
+
+        Ok(())
+    }
+
+    fn plan_vault_ncn(
+        &self,
+        context: &PolicyContext,
+        link: &VaultNcnLink,
+        instructions: &mut Vec<PolicyInstruction>,
+    ) -> anyhow::Result<()> {
+        let vault = context.get_required::<Vault>(&link.vault)?;
+        let vault_ncn_ticket_pubkey =
+            VaultNcnTicket::find_program_address(&self.vault_program_id, &link.vault, &link.ncn).0;
+        let state = context
+            .get::<VaultNcnTicket>(&vault_ncn_ticket_pubkey)?
+            .map(|ticket| {
+                Self::toggle_state(&ticket.state, context.slot, context.vault_epoch_length)
+            })
+            .transpose()?;
+
+        self.push_action(
+            instructions,
+            toggle_action(state.as_ref(), link.active),
+            format_args!("vault {} -> NCN {}", link.vault, link.ncn),
+            &vault.ncn_admin,
+            |action| {
+                let mut ix = match action {
+                    ToggleAction::Initialize => InitializeVaultNcnTicketBuilder::new()
+                        .config(context.vault_config)
+                        .vault(link.vault)
+                        .ncn(link.ncn)
+                        .ncn_vault_ticket(
+                            NcnVaultTicket::find_program_address(
+                                &self.restaking_program_id,
+                                &link.ncn,
+                                &link.vault,
+                            )
+                            .0,
+                        )
+                        .vault_ncn_ticket(vault_ncn_ticket_pubkey)
+                        .admin(self.payer.pubkey())
+                        .payer(self.payer.pubkey())
+                        .instruction(),
+                    ToggleAction::Warmup => WarmupVaultNcnTicketBuilder::new()
+                        .config(context.vault_config)
+                        .vault(link.vault)
+                        .ncn(link.ncn)
+                        .vault_ncn_ticket(vault_ncn_ticket_pubkey)
+                        .admin(self.payer.pubkey())
+                        .instruction(),
+                    _ => CooldownVaultNcnTicketBuilder::new()
+                        .config(context.vault_config)
+                        .vault(link.vault)
+                        .ncn(link.ncn)
+                        .vault_ncn_ticket(vault_ncn_ticket_pubkey)
+                        .admin(self.payer.pubkey())
+                        .instruction(),
+                };
+                ix.program_id = self.vault_program_id;
+                ix
+            },
+        );
+
+        Ok(())
+    }
+
+    fn toggle_state(
+        toggle: &SlotToggle,
+        slot: u64,
+        epoch_length: u64,
+    ) -> anyhow::Result<SlotToggleState> {
+        toggle
+            .state(slot, epoch_length)
+            .map_err(|e| anyhow!("Failed to read slot toggle state: {e}"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        policy::{NcnOperatorLink, NcnVaultLink, OperatorVaultLink, VaultNcnLink},
+        vault_cache::tests::account_data,
+    };
+
+    const EPOCH_LENGTH: u64 = 100;
+    /// Every ticket below is planned in epoch 10
+    const SLOT: u64 = 10 * EPOCH_LENGTH;
+
+    fn account<T: AccountDeserialize>(owner: &Pubkey, account: &T) -> Account {
+        Account {
+            lamports: 1,
+            data: account_data(account),
+            owner: *owner,
+            executable: false,
+            rent_epoch: 0,
+        }
+    }
+
+    /// A toggle created at slot 0 and activated in epoch 1, so it is active by epoch 10
+    fn active_toggle() -> SlotToggle {
+        let mut toggle = SlotToggle::new(0);
+        assert!(toggle.activate(EPOCH_LENGTH, EPOCH_LENGTH).unwrap());
+        toggle
+    }
+
+    /// An active toggle deactivated in epoch 9, so it is still cooling down in epoch 10
+    fn cooling_toggle() -> SlotToggle {
+        let mut toggle = active_toggle();
+        assert!(toggle.deactivate(SLOT - 1, EPOCH_LENGTH).unwrap());
+        toggle
+    }
+
+    struct Fixture {
+        payer: Keypair,
+        restaking_program_id: Pubkey,
+        vault_program_id: Pubkey,
+        accounts: HashMap<Pubkey, Account>,
+    }
+
+    impl Fixture {
+        fn new() -> Self {
+            Self {
+                payer: Keypair::new(),
+                restaking_program_id: Pubkey::new_unique(),
+                vault_program_id: Pubkey::new_unique(),
+                accounts: HashMap::new(),
+            }
+        }
+
+        fn add_ncn(&mut self, admin: Pubkey) -> Pubkey {
+            let pubkey = Pubkey::new_unique();
+            let ncn = Ncn::new(Pubkey::new_unique(), admin, 0, 0);
+            self.accounts
+                .insert(pubkey, account(&self.restaking_program_id, &ncn));
+            pubkey
+        }
+
+        fn add_operator(&mut self, admin: Pubkey) -> Pubkey {
+            let pubkey = Pubkey::new_unique();
+            let operator = Operator::new(Pubkey::new_unique(), admin, 0, 0, 0);
+            self.accounts
+                .insert(pubkey, account(&self.restaking_program_id, &operator));
+            pubkey
+        }
+
+        fn add_vault(&mut self, admin: Pubkey) -> Pubkey {
+            let pubkey = Pubkey::new_unique();
+            let vault = Vault::new(
+                Pubkey::new_unique(),
+                Pubkey::new_unique(),
+                admin,
+                0,
+                Pubkey::new_unique(),
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+            )
+            .unwrap();
+            self.accounts
+                .insert(pubkey, account(&self.vault_program_id, &vault));
+            pubkey
+        }
+
+        fn add_ncn_vault_ticket(&mut self, ncn: &Pubkey, vault: &Pubkey, state: SlotToggle) {
+            let mut ticket = NcnVaultTicket::new(*ncn, *vault, 0, 0, 0);
+            ticket.state = state;
+            self.accounts.insert(
+                NcnVaultTicket::find_program_address(&self.restaking_program_id, ncn, vault).0,
+                account(&self.restaking_program_id, &ticket),
+            );
+        }
+
+        fn add_operator_vault_ticket(
+            &mut self,
+            operator: &Pubkey,
+            vault: &Pubkey,
+            state: SlotToggle,
+        ) {
+            let mut ticket = OperatorVaultTicket::new(*operator, *vault, 0, 0, 0);
+            ticket.state = state;
+            self.accounts.insert(
+                OperatorVaultTicket::find_program_address(
+                    &self.restaking_program_id,
+                    operator,
+                    vault,
+                )
+                .0,
+                account(&self.restaking_program_id, &ticket),
+            );
+        }
+
+        fn handler(&self) -> PolicyHandler<'_> {
+            PolicyHandler::new(
+                "http://localhost:8899",
+                &self.payer,
+                self.restaking_program_id,
+                self.vault_program_id,
+                PriorityFeeConfig::default(),
+            )
+        }
+
+        fn plan(&self, desired_state: &DesiredState) -> Vec<(String, Instruction)> {
+            let context = PolicyContext {
+                accounts: self.accounts.clone(),
+                slot: SLOT,
+                restaking_config: Pubkey::new_unique(),
+                restaking_epoch_length: EPOCH_LENGTH,
+                vault_config: Pubkey::new_unique(),
+                vault_epoch_length: EPOCH_LENGTH,
+            };
+            self.handler()
+                .plan_with_context(&context, desired_state)
+                .into_iter()
+                .map(|ix| (ix.description, ix.instruction))
+                .collect()
+        }
+    }
+
+    #[test]
+    fn test_plan_active_and_cooling_tickets_wait() {
+        let mut fixture = Fixture::new();
+        let admin = fixture.payer.pubkey();
+        let ncn = fixture.add_ncn(admin);
+        let operator = fixture.add_operator(admin);
+        let vault = fixture.add_vault(admin);
+        fixture.add_ncn_vault_ticket(&ncn, &vault, active_toggle());
+        fixture.add_operator_vault_ticket(&operator, &vault, cooling_toggle());
+
+        // Already active, and cooling down until the next epoch boundary
+        let desired_state = DesiredState {
+            ncn_vaults: vec![NcnVaultLink {
+                ncn,
+                vault,
+                active: true,
+            }],
+            operator_vaults: vec![OperatorVaultLink {
+                operator,
+                vault,
+                active: true,
+            }],
+            ..DesiredState::default()
+        };
+        assert!(fixture.plan(&desired_state).is_empty());
+    }
+
+    #[test]
+    fn test_plan_initializes_missing_tickets() {
+        let mut fixture = Fixture::new();
+        let admin = fixture.payer.pubkey();
+        let ncn = fixture.add_ncn(admin);
+        let operator = fixture.add_operator(admin);
+        let vault = fixture.add_vault(admin);
+
+        let desired_state = DesiredState {
+            ncn_operators: vec![NcnOperatorLink {
+                ncn,
+                operator,
+                active: true,
+            }],
+            vault_ncns: vec![VaultNcnLink {
+                vault,
+                ncn,
+                active: true,
+            }],
+            ..DesiredState::default()
+        };
+        let plan = fixture.plan(&desired_state);
+
+        // The operator side of the NCN <> operator link waits for the NCN to initialize it
+        assert_eq!(
+            plan.iter()
+                .map(|(description, _)| description.as_str())
+                .collect::<Vec<_>>(),
+            vec![
+                format!("Initialize NCN {ncn} opt-in for operator {operator}").as_str(),
+                format!("Initialize vault {vault} -> NCN {ncn}").as_str(),
+            ]
+        );
+        assert_eq!(plan[0].1.program_id, fixture.restaking_program_id);
+        assert_eq!(plan[1].1.program_id, fixture.vault_program_id);
+        assert_eq!(
+            plan[1].1.accounts[4].pubkey,
+            VaultNcnTicket::find_program_address(&fixture.vault_program_id, &vault, &ncn).0
+        );
+    }
+
+    #[test]
+    fn test_plan_cools_down_extra_tickets() {
+        let mut fixture = Fixture::new();
+        let admin = fixture.payer.pubkey();
+        let ncn = fixture.add_ncn(admin);
+        let operator = fixture.add_operator(admin);
+        let vault = fixture.add_vault(admin);
+        let unlisted_vault = fixture.add_vault(admin);
+        fixture.add_ncn_vault_ticket(&ncn, &vault, active_toggle());
+        fixture.add_operator_vault_ticket(&operator, &vault, active_toggle());
+        // Tickets missing from the policy are left alone
+        fixture.add_ncn_vault_ticket(&ncn, &unlisted_vault, active_toggle());
+
+        let desired_state = DesiredState {
+            ncn_vaults: vec![NcnVaultLink {
+                ncn,
+                vault,
+                active: false,
+            }],
+            operator_vaults: vec![OperatorVaultLink {
+                operator,
+                vault,
+                active: false,
+            }],
+            ..DesiredState::default()
+        };
+        let plan = fixture.plan(&desired_state);
+        assert_eq!(
+            plan.iter()
+                .map(|(description, _)| description.as_str())
+                .collect::<Vec<_>>(),
+            vec![
+                format!("Cooldown NCN {ncn} -> vault {vault}").as_str(),
+                format!("Cooldown operator {operator} -> vault {vault}").as_str(),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_plan_skips_links_it_cannot_sign_or_resolve() {
+        let mut fixture = Fixture::new();
+        let ncn = fixture.add_ncn(Pubkey::new_unique());
+        let vault = fixture.add_vault(fixture.payer.pubkey());
+        let missing_operator = Pubkey::new_unique();
+
+        let desired_state = DesiredState {
+            ncn_vaults: vec![NcnVaultLink {
+                ncn,
+                vault,
+                active: true,
+            }],
+            operator_vaults: vec![OperatorVaultLink {
+                operator: missing_operator,
+                vault,
+                active: true,
+            }],
+            vault_ncns: vec![VaultNcnLink {
+                vault,
+                ncn,
+                active: true,
+            }],
+            ..DesiredState::default()
+        };
+        let plan = fixture.plan(&desired_state);
+        assert_eq!(plan.len(), 1);
+        assert_eq!(plan[0].0, format!("Initialize vault {vault} -> NCN {ncn}"));
+    }
+}
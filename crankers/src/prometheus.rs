@@ -0,0 +1,369 @@
+//! Prometheus exporter for the cranker.
+//!
+//! [`crate::metrics::emit_vault_metrics`] records per-vault health in [`CRANKER_METRICS`] every
+//! metrics interval and the transaction senders count every crank transaction. When the cranker
+//! is started with `--prometheus-addr`, [`serve_metrics`] exposes both in the Prometheus text
+//! format at `/metrics`.
+
+use std::{
+    collections::BTreeMap,
+    fmt::Write as _,
+    net::SocketAddr,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        RwLock,
+    },
+};
+
+use anyhow::Context;
+use jito_vault_core::vault::Vault;
+use log::{error, info};
+use solana_sdk::pubkey::Pubkey;
+use tokio::{
+    io::{AsyncBufReadExt, AsyncWriteExt, BufReader},
+    net::{TcpListener, TcpStream},
+};
+
+/// Metrics shared by every cranker task
+pub static CRANKER_METRICS: CrankerMetrics = CrankerMetrics::new();
+
+/// `(name, help, value)` of a per-vault gauge
+type VaultGauge = (&'static str, &'static str, fn(&VaultHealth) -> String);
+
+/// Health of a single vault, as of the last metrics pass
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct VaultHealth {
+    /// Supported tokens per VRT, or 0 if no VRT has been minted
+    pub exchange_rate: f64,
+    pub additional_assets_need_unstaking: u64,
+    pub vrt_enqueued_for_cooldown: u64,
+    pub vrt_cooling_down: u64,
+    pub vrt_ready_to_claim: u64,
+    /// Epochs since the vault was last fully updated, 0 when it is up to date
+    pub epochs_since_last_full_update: u64,
+    /// The vault's VRT supply minus the VRT mint supply
+    pub vrt_supply_gap: i128,
+    /// The vault's tokens deposited minus the balance of its supported token account
+    pub st_supply_gap: i128,
+}
+
+impl VaultHealth {
+    /// Computes the health of a vault.
+    ///
+    /// # Arguments
+    /// * `vault` - The vault account
+    /// * `epoch` - The current NCN epoch
+    /// * `epoch_length` - The NCN epoch length in slots
+    /// * `vrt_mint_supply` - The supply of the vault's VRT mint
+    /// * `st_deposit_amount` - The balance of the vault's supported token account
+    pub fn new(
+        vault: &Vault,
+        epoch: u64,
+        epoch_length: u64,
+        vrt_mint_supply: u64,
+        st_deposit_amount: u64,
+    ) -> Self {
+        let exchange_rate = if vault.vrt_supply() == 0 {
+            0.0
+        } else {
+            vault.tokens_deposited() as f64 / vault.vrt_supply() as f64
+        };
+        let last_update_epoch = vault.last_full_state_update_slot() / epoch_length;
+
+        Self {
+            exchange_rate,
+            additional_assets_need_unstaking: vault.additional_assets_need_unstaking(),
+            vrt_enqueued_for_cooldown: vault.vrt_enqueued_for_cooldown_amount(),
+            vrt_cooling_down: vault.vrt_cooling_down_amount(),
+            vrt_ready_to_claim: vault.vrt_ready_to_claim_amount(),
+            epochs_since_last_full_update: epoch.saturating_sub(last_update_epoch),
+            vrt_supply_gap: vault.vrt_supply() as i128 - vrt_mint_supply as i128,
+            st_supply_gap: vault.tokens_deposited() as i128 - st_deposit_amount as i128,
+        }
+    }
+}
+
+/// Counters for crank transactions and the latest [`VaultHealth`] of every vault
+#[derive(Debug)]
+pub struct CrankerMetrics {
+    transactions_sent: AtomicU64,
+    transactions_failed: AtomicU64,
+    transactions_retried: AtomicU64,
+    vaults: RwLock<BTreeMap<Pubkey, VaultHealth>>,
+}
+
+impl Default for CrankerMetrics {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CrankerMetrics {
+    pub const fn new() -> Self {
+        Self {
+            transactions_sent: AtomicU64::new(0),
+            transactions_failed: AtomicU64::new(0),
+            transactions_retried: AtomicU64::new(0),
+            vaults: RwLock::new(BTreeMap::new()),
+        }
+    }
+
+    /// Counts a transaction submission, including retries
+    pub fn record_transaction_sent(&self) {
+        self.transactions_sent.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Counts a transaction that was given up on after exhausting its retries
+    pub fn record_transaction_failed(&self) {
+        self.transactions_failed.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Counts a failed submission that is about to be retried
+    pub fn record_transaction_retried(&self) {
+        self.transactions_retried.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Replaces the health of every vault, so closed vaults stop being exported
+    pub fn set_vault_health(&self, vaults: BTreeMap<Pubkey, VaultHealth>) {
+        *self.vaults.write().unwrap() = vaults;
+    }
+
+    /// Renders every metric in the Prometheus text exposition format
+    pub fn render(&self) -> String {
+        let mut out = String::new();
+
+        for (name, help, counter) in [
+            (
+                "jito_vault_cranker_transactions_sent_total",
+                "Crank transactions submitted, including retries",
+                &self.transactions_sent,
+            ),
+            (
+                "jito_vault_cranker_transactions_failed_total",
+                "Crank transactions that failed after exhausting their retries",
+                &self.transactions_failed,
+            ),
+            (
+                "jito_vault_cranker_transactions_retried_total",
+                "Failed crank transaction submissions that were retried",
+                &self.transactions_retried,
+            ),
+        ] {
+            let _ = writeln!(out, "# HELP {name} {help}");
+            let _ = writeln!(out, "# TYPE {name} counter");
+            let _ = writeln!(out, "{name} {}", counter.load(Ordering::Relaxed));
+        }
+
+        let vaults = self.vaults.read().unwrap();
+        let gauges: [VaultGauge; 8] = [
+            (
+                "jito_vault_exchange_rate",
+                "Supported tokens per VRT",
+                |health| health.exchange_rate.to_string(),
+            ),
+            (
+                "jito_vault_additional_assets_need_unstaking",
+                "Supported tokens that must be undelegated to cover withdrawals",
+                |health| health.additional_assets_need_unstaking.to_string(),
+            ),
+            (
+                "jito_vault_vrt_enqueued_for_cooldown",
+                "VRT enqueued for cooldown",
+                |health| health.vrt_enqueued_for_cooldown.to_string(),
+            ),
+            (
+                "jito_vault_vrt_cooling_down",
+                "VRT cooling down",
+                |health| health.vrt_cooling_down.to_string(),
+            ),
+            (
+                "jito_vault_vrt_ready_to_claim",
+                "VRT ready to claim",
+                |health| health.vrt_ready_to_claim.to_string(),
+            ),
+            (
+                "jito_vault_epochs_since_last_full_update",
+                "Epochs since the vault was last fully updated",
+                |health| health.epochs_since_last_full_update.to_string(),
+            ),
+            (
+                "jito_vault_vrt_supply_gap",
+                "Vault VRT supply minus the VRT mint supply",
+                |health| health.vrt_supply_gap.to_string(),
+            ),
+            (
+                "jito_vault_st_supply_gap",
+                "Vault tokens deposited minus the vault's supported token balance",
+                |health| health.st_supply_gap.to_string(),
+            ),
+        ];
+        for (name, help, value) in gauges {
+            let _ = writeln!(out, "# HELP {name} {help}");
+            let _ = writeln!(out, "# TYPE {name} gauge");
+            for (vault, health) in vaults.iter() {
+                let _ = writeln!(out, "{name}{{vault=\"{vault}\"}} {}", value(health));
+            }
+        }
+
+        out
+    }
+}
+
+/// Serves `metrics` at `http://<addr>/metrics` until the listener fails
+pub async fn serve_metrics(
+    addr: SocketAddr,
+    metrics: &'static CrankerMetrics,
+) -> anyhow::Result<()> {
+    let listener = TcpListener::bind(addr)
+        .await
+        .with_context(|| format!("Failed to bind Prometheus exporter to {addr}"))?;
+    info!("Serving Prometheus metrics at http://{addr}/metrics");
+
+    serve_listener(listener, metrics).await
+}
+
+async fn serve_listener(
+    listener: TcpListener,
+    metrics: &'static CrankerMetrics,
+) -> anyhow::Result<()> {
+    loop {
+        let (stream, _peer) = listener
+            .accept()
+            .await
+            .context("Failed to accept metrics connection")?;
+        tokio::spawn(async move {
+            if let Err(e) = handle_connection(stream, metrics).await {
+                error!("Failed to serve metrics: {e:#}");
+            }
+        });
+    }
+}
+
+async fn handle_connection(stream: TcpStream, metrics: &CrankerMetrics) -> anyhow::Result<()> {
+    let mut stream = BufReader::new(stream);
+
+    let mut request_line = String::new();
+    stream.read_line(&mut request_line).await?;
+    // Drain the headers so the client sees a clean close
+    let mut header = String::new();
+    while stream.read_line(&mut header).await? > 2 {
+        header.clear();
+    }
+
+    let mut parts = request_line.split_whitespace();
+    let (status, content_type, body) = match (parts.next(), parts.next()) {
+        (Some("GET"), Some("/metrics")) => {
+            ("200 OK", "text/plain; version=0.0.4", metrics.render())
+        }
+        _ => ("404 Not Found", "text/plain", "Not Found\n".to_string()),
+    };
+
+    let response = format!(
+        "HTTP/1.1 {status}\r\nContent-Type: {content_type}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+        body.len()
+    );
+    stream.get_mut().write_all(response.as_bytes()).await?;
+    stream.get_mut().shutdown().await?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use tokio::io::AsyncReadExt;
+
+    use super::*;
+
+    fn vault(tokens_deposited: u64, vrt_supply: u64, last_full_state_update_slot: u64) -> Vault {
+        let mut vault = Vault::new(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            0,
+            Pubkey::new_unique(),
+            0,
+            0,
+            0,
+            0,
+            0,
+            last_full_state_update_slot,
+        )
+        .unwrap();
+        vault.set_tokens_deposited(tokens_deposited);
+        vault.set_vrt_supply(vrt_supply);
+        vault.set_vrt_cooling_down_amount(7);
+        vault
+    }
+
+    #[test]
+    fn test_vault_health() {
+        let health = VaultHealth::new(&vault(150, 100, 250), 5, 100, 90, 160);
+
+        assert_eq!(health.exchange_rate, 1.5);
+        assert_eq!(health.vrt_cooling_down, 7);
+        assert_eq!(health.epochs_since_last_full_update, 3);
+        assert_eq!(health.vrt_supply_gap, 10);
+        assert_eq!(health.st_supply_gap, -10);
+
+        let empty = VaultHealth::new(&vault(0, 0, 500), 5, 100, 0, 0);
+        assert_eq!(empty.exchange_rate, 0.0);
+        assert_eq!(empty.epochs_since_last_full_update, 0);
+    }
+
+    #[test]
+    fn test_render() {
+        let metrics = CrankerMetrics::new();
+        let vault_pubkey = Pubkey::new_unique();
+        metrics.record_transaction_sent();
+        metrics.record_transaction_sent();
+        metrics.record_transaction_retried();
+        metrics.set_vault_health(BTreeMap::from([(
+            vault_pubkey,
+            VaultHealth::new(&vault(150, 100, 250), 5, 100, 100, 150),
+        )]));
+
+        let rendered = metrics.render();
+        assert!(rendered.contains("# TYPE jito_vault_cranker_transactions_sent_total counter\n"));
+        assert!(rendered.contains("jito_vault_cranker_transactions_sent_total 2\n"));
+        assert!(rendered.contains("jito_vault_cranker_transactions_failed_total 0\n"));
+        assert!(rendered.contains("jito_vault_cranker_transactions_retried_total 1\n"));
+        assert!(rendered.contains(&format!(
+            "jito_vault_exchange_rate{{vault=\"{vault_pubkey}\"}} 1.5\n"
+        )));
+        assert!(rendered.contains(&format!(
+            "jito_vault_epochs_since_last_full_update{{vault=\"{vault_pubkey}\"}} 3\n"
+        )));
+
+        // Vaults missing from the next pass are no longer exported
+        metrics.set_vault_health(BTreeMap::new());
+        assert!(!metrics.render().contains(&vault_pubkey.to_string()));
+    }
+
+    async fn get(addr: SocketAddr, path: &str) -> String {
+        let mut stream = TcpStream::connect(addr).await.unwrap();
+        stream
+            .write_all(format!("GET {path} HTTP/1.1\r\nHost: localhost\r\n\r\n").as_bytes())
+            .await
+            .unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).await.unwrap();
+        response
+    }
+
+    #[tokio::test]
+    async fn test_serve_metrics() {
+        static METRICS: CrankerMetrics = CrankerMetrics::new();
+        METRICS.record_transaction_failed();
+
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(serve_listener(listener, &METRICS));
+
+        let response = get(addr, "/metrics").await;
+        assert!(response.starts_with("HTTP/1.1 200 OK\r\n"));
+        assert!(response.contains("jito_vault_cranker_transactions_failed_total 1\n"));
+
+        let response = get(addr, "/").await;
+        assert!(response.starts_with("HTTP/1.1 404 Not Found\r\n"));
+    }
+}
@@ -0,0 +1,125 @@
+use std::{str::FromStr, time::Duration};
+
+use anyhow::{anyhow, Context};
+use futures::StreamExt;
+use log::{error, info};
+use solana_account_decoder::UiAccountEncoding;
+use solana_pubsub_client::nonblocking::pubsub_client::PubsubClient;
+use solana_rpc_client::nonblocking::rpc_client::RpcClient;
+use solana_rpc_client_api::config::{RpcAccountInfoConfig, RpcProgramAccountsConfig};
+use solana_sdk::{account::Account, commitment_config::CommitmentConfig, pubkey::Pubkey};
+use tokio::{sync::mpsc::UnboundedSender, time::sleep};
+
+use crate::{event_driven::CrankerEvent, vault_handler::VaultHandler};
+
+/// Time to wait before reconnecting a dropped websocket subscription
+const RECONNECT_DELAY: Duration = Duration::from_secs(5);
+
+/// Streams slot and vault program account notifications into `sender`, reconnecting whenever
+/// the websocket drops. Returns once the receiving side is closed.
+pub async fn subscribe_vault_program(
+    ws_url: &str,
+    vault_program_id: Pubkey,
+    sender: UnboundedSender<CrankerEvent>,
+) {
+    while !sender.is_closed() {
+        if let Err(e) = forward_notifications(ws_url, &vault_program_id, &sender).await {
+            error!("Vault program subscription dropped: {e:#}");
+        }
+        sleep(RECONNECT_DELAY).await;
+    }
+}
+
+async fn forward_notifications(
+    ws_url: &str,
+    vault_program_id: &Pubkey,
+    sender: &UnboundedSender<CrankerEvent>,
+) -> anyhow::Result<()> {
+    let pubsub_client = PubsubClient::new(ws_url)
+        .await
+        .context("Failed to connect to websocket")?;
+
+    let config = RpcProgramAccountsConfig {
+        account_config: RpcAccountInfoConfig {
+            encoding: Some(UiAccountEncoding::Base64),
+            commitment: Some(CommitmentConfig::confirmed()),
+            ..RpcAccountInfoConfig::default()
+        },
+        ..RpcProgramAccountsConfig::default()
+    };
+    let (mut accounts, accounts_unsubscribe) = pubsub_client
+        .program_subscribe(vault_program_id, Some(config))
+        .await
+        .context("Failed to subscribe to vault program accounts")?;
+    let (mut slots, slots_unsubscribe) = pubsub_client
+        .slot_subscribe()
+        .await
+        .context("Failed to subscribe to slots")?;
+
+    info!("Subscribed to vault program {vault_program_id} at {ws_url}");
+
+    let result = loop {
+        let event = tokio::select! {
+            Some(slot_info) = slots.next() => CrankerEvent::Slot(slot_info.slot),
+            Some(response) = accounts.next() => {
+                let keyed_account = response.value;
+                let pubkey = Pubkey::from_str(&keyed_account.pubkey)?;
+                let Some(account) = keyed_account.account.decode::<Account>() else {
+                    error!("Failed to decode account notification for {pubkey}");
+                    continue;
+                };
+                CrankerEvent::Account {
+                    pubkey,
+                    data: account.data,
+                }
+            }
+            else => break Err(anyhow!("Subscription stream ended")),
+        };
+
+        if sender.send(event).is_err() {
+            break Ok(());
+        }
+    };
+
+    accounts_unsubscribe().await;
+    slots_unsubscribe().await;
+
+    result
+}
+
+/// Sends a full [`CrankerEvent::Snapshot`] of the vault program every `interval`, so missed or
+/// dropped notifications are eventually corrected. Returns once the receiving side is closed.
+pub async fn poll_vault_program(
+    rpc_client: &RpcClient,
+    vault_handler: &VaultHandler<'_>,
+    interval: Duration,
+    sender: UnboundedSender<CrankerEvent>,
+) {
+    while !sender.is_closed() {
+        match get_snapshot(rpc_client, vault_handler).await {
+            Ok(snapshot) => {
+                if sender.send(snapshot).is_err() {
+                    return;
+                }
+            }
+            Err(e) => error!("Failed to poll vault program: {e:#}"),
+        }
+
+        sleep(interval).await;
+    }
+}
+
+async fn get_snapshot(
+    rpc_client: &RpcClient,
+    vault_handler: &VaultHandler<'_>,
+) -> anyhow::Result<CrankerEvent> {
+    let slot = rpc_client.get_slot().await.context("get slot")?;
+    let vaults = vault_handler.get_vaults().await?;
+    let delegations = vault_handler.get_vault_operator_delegations().await?;
+
+    Ok(CrankerEvent::Snapshot {
+        slot,
+        vaults,
+        delegations,
+    })
+}
@@ -0,0 +1,197 @@
+use std::collections::HashMap;
+
+use jito_bytemuck::{AccountDeserialize, Discriminator};
+use jito_vault_core::{vault::Vault, vault_operator_delegation::VaultOperatorDelegation};
+use solana_sdk::pubkey::Pubkey;
+
+/// Decoded [`Vault`] and [`VaultOperatorDelegation`] accounts, kept up to date from polled
+/// snapshots and account-change notifications so the cranker doesn't need to refetch every
+/// account with `get_program_accounts` on each pass.
+#[derive(Debug, Default)]
+pub struct VaultCache {
+    vaults: HashMap<Pubkey, Vault>,
+    delegations: HashMap<Pubkey, VaultOperatorDelegation>,
+}
+
+impl VaultCache {
+    /// Replaces the cache with a full snapshot of the vault program accounts
+    pub fn replace(
+        &mut self,
+        vaults: Vec<(Pubkey, Vault)>,
+        delegations: Vec<(Pubkey, VaultOperatorDelegation)>,
+    ) {
+        self.vaults = vaults.into_iter().collect();
+        self.delegations = delegations.into_iter().collect();
+    }
+
+    /// Applies an account-change notification. Accounts are decoded by discriminator; closed
+    /// accounts and accounts of other types are removed from the cache.
+    ///
+    /// # Returns
+    /// * `bool` - Whether the account is a [`Vault`] or [`VaultOperatorDelegation`]
+    pub fn apply_account(&mut self, pubkey: Pubkey, data: &[u8]) -> bool {
+        self.vaults.remove(&pubkey);
+        self.delegations.remove(&pubkey);
+
+        match data.first() {
+            Some(&Vault::DISCRIMINATOR) => match Vault::try_from_slice_unchecked(data) {
+                Ok(vault) => {
+                    self.vaults.insert(pubkey, *vault);
+                    true
+                }
+                Err(_) => false,
+            },
+            Some(&VaultOperatorDelegation::DISCRIMINATOR) => {
+                match VaultOperatorDelegation::try_from_slice_unchecked(data) {
+                    Ok(delegation) => {
+                        self.delegations.insert(pubkey, *delegation);
+                        true
+                    }
+                    Err(_) => false,
+                }
+            }
+            _ => false,
+        }
+    }
+
+    pub fn vault(&self, pubkey: &Pubkey) -> Option<&Vault> {
+        self.vaults.get(pubkey)
+    }
+
+    pub fn num_vaults(&self) -> usize {
+        self.vaults.len()
+    }
+
+    pub fn num_delegations(&self) -> usize {
+        self.delegations.len()
+    }
+
+    /// Returns every vault that needs an update at `slot`, along with its operators sorted by
+    /// [`VaultOperatorDelegation`] index for the correct cranking order
+    pub fn vaults_needing_update(
+        &self,
+        slot: u64,
+        epoch_length: u64,
+    ) -> Vec<(Pubkey, Vec<Pubkey>)> {
+        let mut vaults: Vec<(Pubkey, Vec<Pubkey>)> = self
+            .vaults
+            .iter()
+            .filter(|(_pubkey, vault)| {
+                vault
+                    .is_update_needed(slot, epoch_length)
+                    .expect("Config epoch length is 0")
+            })
+            .map(|(pubkey, _vault)| {
+                let mut delegations: Vec<&VaultOperatorDelegation> = self
+                    .delegations
+                    .values()
+                    .filter(|delegation| delegation.vault == *pubkey)
+                    .collect();
+                delegations.sort_by_key(|delegation| delegation.index());
+
+                (
+                    *pubkey,
+                    delegations
+                        .into_iter()
+                        .map(|delegation| delegation.operator)
+                        .collect(),
+                )
+            })
+            .collect();
+
+        // Deterministic ordering keeps logs and tests stable
+        vaults.sort_by_key(|(pubkey, _operators)| *pubkey);
+        vaults
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+
+    const EPOCH_LENGTH: u64 = 100;
+
+    /// Serializes an account the way the program lays it out: discriminator, seven reserved
+    /// bytes, then the account struct
+    pub(crate) fn account_data<T: AccountDeserialize>(account: &T) -> Vec<u8> {
+        let mut data = vec![T::DISCRIMINATOR, 0, 0, 0, 0, 0, 0, 0];
+        data.extend_from_slice(bytemuck::bytes_of(account));
+        data
+    }
+
+    /// Creates a vault that was last fully updated at `slot`
+    pub(crate) fn new_vault(slot: u64) -> Vault {
+        Vault::new(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            0,
+            Pubkey::new_unique(),
+            0,
+            0,
+            0,
+            0,
+            0,
+            slot,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_apply_account_tracks_vaults_and_delegations() {
+        let mut cache = VaultCache::default();
+        let vault_pubkey = Pubkey::new_unique();
+        let delegation_pubkey = Pubkey::new_unique();
+
+        assert!(cache.apply_account(vault_pubkey, &account_data(&new_vault(0))));
+        assert!(cache.apply_account(
+            delegation_pubkey,
+            &account_data(&VaultOperatorDelegation::new(
+                vault_pubkey,
+                Pubkey::new_unique(),
+                0,
+                0,
+                0
+            ))
+        ));
+        assert_eq!(cache.num_vaults(), 1);
+        assert_eq!(cache.num_delegations(), 1);
+
+        // Closed accounts are dropped
+        assert!(!cache.apply_account(delegation_pubkey, &[]));
+        assert_eq!(cache.num_delegations(), 0);
+
+        // Unknown accounts are ignored
+        assert!(!cache.apply_account(Pubkey::new_unique(), &[0xff; 16]));
+        assert_eq!(cache.num_vaults(), 1);
+    }
+
+    #[test]
+    fn test_vaults_needing_update_sorts_operators_by_index() {
+        let mut cache = VaultCache::default();
+        let vault_pubkey = Pubkey::new_unique();
+        let operators: Vec<Pubkey> = (0..3).map(|_| Pubkey::new_unique()).collect();
+
+        cache.apply_account(vault_pubkey, &account_data(&new_vault(0)));
+        for index in [2, 0, 1] {
+            cache.apply_account(
+                Pubkey::new_unique(),
+                &account_data(&VaultOperatorDelegation::new(
+                    vault_pubkey,
+                    operators[index],
+                    index as u64,
+                    0,
+                    0,
+                )),
+            );
+        }
+
+        assert!(cache
+            .vaults_needing_update(EPOCH_LENGTH - 1, EPOCH_LENGTH)
+            .is_empty());
+        assert_eq!(
+            cache.vaults_needing_update(EPOCH_LENGTH, EPOCH_LENGTH),
+            vec![(vault_pubkey, operators)]
+        );
+    }
+}
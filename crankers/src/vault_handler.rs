@@ -1,20 +1,21 @@
-use std::time::Duration;
+use std::cmp::min;
 
 use anyhow::Context;
 use base64::{engine::general_purpose, Engine};
 use jito_bytemuck::AccountDeserialize;
 use jito_vault_client::{
     instructions::{
-        CloseVaultUpdateStateTrackerBuilder, CrankVaultUpdateStateTrackerBuilder,
-        InitializeVaultUpdateStateTrackerBuilder,
+        AutoClaimWithdrawalTicketBuilder, CloseVaultUpdateStateTrackerBuilder,
+        CrankVaultUpdateStateTrackerManyBuilder, InitializeVaultUpdateStateTrackerBuilder,
     },
     types::WithdrawalAllocationMethod,
 };
 use jito_vault_core::{
     vault::Vault, vault_operator_delegation::VaultOperatorDelegation,
+    vault_staker_withdrawal_ticket::VaultStakerWithdrawalTicket,
     vault_update_state_tracker::VaultUpdateStateTracker,
 };
-use log::error;
+use jito_vault_sdk::compute_units::max_crank_vault_update_state_tracker_many_operators;
 use solana_account_decoder::{UiAccountEncoding, UiDataSliceConfig};
 use solana_rpc_client::nonblocking::rpc_client::RpcClient;
 use solana_rpc_client_api::{
@@ -22,20 +23,29 @@ use solana_rpc_client_api::{
     filter::{Memcmp, MemcmpEncodedBytes, RpcFilterType},
 };
 use solana_sdk::{
-    commitment_config::CommitmentConfig, compute_budget::ComputeBudgetInstruction,
-    instruction::Instruction, pubkey::Pubkey, signature::Keypair, signer::Signer,
-    transaction::Transaction,
+    commitment_config::CommitmentConfig, instruction::AccountMeta, pubkey::Pubkey,
+    signature::Keypair, signer::Signer,
+};
+use spl_associated_token_account::{
+    get_associated_token_address, instruction::create_associated_token_account_idempotent,
+};
+
+use crate::{
+    auto_claim::ClaimableTicket,
+    core::{send_and_confirm_transaction, PriorityFeeConfig, MAX_COMPUTE_UNIT_LIMIT},
+    vault_update::VaultUpdateState,
 };
-use tokio::time::sleep;
 
-use crate::core::get_latest_blockhash_with_retry;
+/// The number of `(operator, vault_operator_delegation)` pairs that fit in a legacy transaction
+/// alongside the compute budget instructions
+const MAX_OPERATORS_PER_CRANK_TRANSACTION: usize = 13;
 
 pub struct VaultHandler<'a> {
     rpc_url: String,
     payer: &'a Keypair,
     vault_program_id: Pubkey,
     config_address: Pubkey,
-    priority_fees: u64,
+    priority_fees: PriorityFeeConfig,
 }
 
 impl<'a> VaultHandler<'a> {
@@ -44,7 +54,7 @@ impl<'a> VaultHandler<'a> {
         payer: &'a Keypair,
         vault_program_id: Pubkey,
         config_address: Pubkey,
-        priority_fees: u64,
+        priority_fees: PriorityFeeConfig,
     ) -> Self {
         Self {
             rpc_url: rpc_url.to_string(),
@@ -55,6 +65,11 @@ impl<'a> VaultHandler<'a> {
         }
     }
 
+    /// The keypair paying for and signing the cranker's transactions
+    pub fn payer(&self) -> Pubkey {
+        self.payer.pubkey()
+    }
+
     /// Creates a new `RpcClient` instance with the specified commitment level.
     ///
     /// # Returns
@@ -101,66 +116,6 @@ impl<'a> VaultHandler<'a> {
         Ok(config)
     }
 
-    /// Sends and confirms a transaction with retries, priority fees, and blockhash refresh
-    ///
-    /// # Arguments
-    /// * `instructions` - Vector of instructions to include in the transaction
-    ///
-    /// # Returns
-    /// Returns `anyhow::Result<()>` indicating success or failure
-    async fn send_and_confirm_transaction_with_retry(
-        &self,
-        mut instructions: Vec<Instruction>,
-    ) -> anyhow::Result<()> {
-        let rpc_client = self.get_rpc_client();
-        let mut retries = 0;
-        const MAX_RETRIES: u8 = 10;
-
-        instructions.insert(
-            0,
-            ComputeBudgetInstruction::set_compute_unit_price(self.priority_fees),
-        );
-        while retries < MAX_RETRIES {
-            let blockhash = get_latest_blockhash_with_retry(&rpc_client).await?;
-
-            let tx = Transaction::new_signed_with_payer(
-                &instructions,
-                Some(&self.payer.pubkey()),
-                &[self.payer],
-                blockhash,
-            );
-
-            let err = match rpc_client
-                .send_and_confirm_transaction_with_spinner_and_commitment(
-                    &tx,
-                    CommitmentConfig::confirmed(),
-                )
-                .await
-            {
-                Ok(_) => return Ok(()),
-                Err(err) => {
-                    retries += 1;
-                    if retries < MAX_RETRIES {
-                        sleep(Duration::from_secs(1)).await;
-                    }
-                    err
-                }
-            };
-
-            if retries >= MAX_RETRIES {
-                error!(
-                    "Transaction failed after {} retries: {:?}",
-                    MAX_RETRIES, err
-                );
-            }
-        }
-
-        Err(anyhow::anyhow!(
-            "Transaction failed after {} retries",
-            MAX_RETRIES
-        ))
-    }
-
     /// Retrieves all existing vaults
     ///
     /// # Returns
@@ -217,42 +172,91 @@ impl<'a> VaultHandler<'a> {
         Ok(delegations)
     }
 
+    /// Retrieves all existing `VaultStakerWithdrawalTicket` accounts associated with the program.
+    ///
+    /// # Returns
+    ///
+    /// An `anyhow::Result` containing a vector of `(Pubkey, VaultStakerWithdrawalTicket)` tuples.
+    pub async fn get_withdrawal_tickets(
+        &self,
+    ) -> anyhow::Result<Vec<(Pubkey, VaultStakerWithdrawalTicket)>> {
+        let rpc_client = self.get_rpc_client();
+        let config = self.get_rpc_program_accounts_config::<VaultStakerWithdrawalTicket>()?;
+
+        let accounts = rpc_client
+            .get_program_accounts_with_config(&self.vault_program_id, config)
+            .await?;
+
+        let tickets: Vec<(Pubkey, VaultStakerWithdrawalTicket)> = accounts
+            .into_iter()
+            .filter_map(|(pubkey, acc)| {
+                VaultStakerWithdrawalTicket::try_from_slice_unchecked(&acc.data)
+                    .map_or(None, |v| Some((pubkey, *v)))
+            })
+            .collect();
+
+        Ok(tickets)
+    }
+
     /// Retrieves the `VaultUpdateStateTracker` for a specific vault and epoch.
     ///
     /// # Returns
     ///
-    /// Returns an `anyhow::Result<VaultUpdateStateTracker>` containing the deserialized state tracker
-    /// for the given vault and epoch. If successful, the state tracker is returned; otherwise,
-    /// an error is returned with contextual information.
+    /// Returns an `anyhow::Result<Option<VaultUpdateStateTracker>>` containing the deserialized
+    /// state tracker for the given vault and epoch, or `None` if it hasn't been initialized.
     pub async fn get_update_state_tracker(
         &self,
         vault: &Pubkey,
         ncn_epoch: u64,
-    ) -> anyhow::Result<VaultUpdateStateTracker> {
+    ) -> anyhow::Result<Option<VaultUpdateStateTracker>> {
         let rpc_client = self.get_rpc_client();
 
         let pubkey =
             VaultUpdateStateTracker::find_program_address(&self.vault_program_id, vault, ncn_epoch)
                 .0;
 
-        match rpc_client.get_account(&pubkey).await {
-            Ok(account) => match VaultUpdateStateTracker::try_from_slice_unchecked(&account.data) {
-                Ok(tracker) => Ok(*tracker),
-                Err(e) => {
-                    let context = format!("Failed deserializing VaultUpdateStateTracker: {pubkey}");
-                    Err(anyhow::Error::new(e).context(context))
-                }
-            },
-            Err(e) => {
-                let context =
-                    format!("Error: Failed to get VaultUpdateStateTracker account: {pubkey}");
-                Err(anyhow::Error::new(e).context(context))
-            }
-        }
+        let account = rpc_client
+            .get_account_with_commitment(&pubkey, CommitmentConfig::confirmed())
+            .await
+            .with_context(|| {
+                format!("Error: Failed to get VaultUpdateStateTracker account: {pubkey}")
+            })?
+            .value;
+
+        account
+            .map(
+                |account| match VaultUpdateStateTracker::try_from_slice_unchecked(&account.data) {
+                    Ok(tracker) => Ok(*tracker),
+                    Err(e) => {
+                        let context =
+                            format!("Failed deserializing VaultUpdateStateTracker: {pubkey}");
+                        Err(anyhow::Error::new(e).context(context))
+                    }
+                },
+            )
+            .transpose()
+    }
+
+    /// Reads the [`VaultUpdateState`] of a vault from its on-chain tracker
+    pub async fn get_vault_update_state(
+        &self,
+        epoch: u64,
+        vault: &Pubkey,
+        num_operators: usize,
+    ) -> anyhow::Result<VaultUpdateState> {
+        let tracker = self.get_update_state_tracker(vault, epoch).await?;
+        Ok(VaultUpdateState::from_tracker(
+            tracker.as_ref(),
+            epoch,
+            num_operators,
+        ))
     }
 
     /// Performs a complete vault update cycle: initializes tracker, cranks it, and closes it.
     ///
+    /// Each step starts from the on-chain [`VaultUpdateState`], so an update interrupted part way
+    /// through (for example by a cranker restart) resumes where it left off.
+    ///
     /// # Returns
     ///
     /// Returns `anyhow::Result<()>` indicating success or failure of the update operation.
@@ -267,31 +271,52 @@ impl<'a> VaultHandler<'a> {
 
         log::info!("Updating vault: {vault}");
 
+        let mut state = self
+            .get_vault_update_state(epoch, vault, operators.len())
+            .await?;
+
         // Initialize
-        if let Err(e) = self.get_update_state_tracker(vault, epoch).await {
-            log::info!("Get tracker failed, initializing. Expecting AccountNotFound: {e}");
+        if state == VaultUpdateState::TrackerMissing {
             self.initialize_vault_update_state_tracker(vault, tracker_pubkey)
                 .await?;
-        }
+            log::info!("Initialized tracker for vault: {vault}, tracker: {tracker_pubkey}");
 
-        log::info!("Initialized tracker for vault: {vault}, tracker: {tracker_pubkey}");
+            // A freshly initialized tracker hasn't cranked any operators
+            state = VaultUpdateState::from_tracker(
+                Some(&VaultUpdateStateTracker::new(*vault, epoch, 0)),
+                epoch,
+                operators.len(),
+            );
+        }
 
         // Crank
-        self.crank(epoch, vault, operators, tracker_pubkey).await?;
+        if let VaultUpdateState::PartiallyCranked {
+            next_index,
+            remaining,
+        } = state
+        {
+            log::info!(
+                "Cranking {remaining} of {} operators for vault: {vault}",
+                operators.len()
+            );
+            self.crank(vault, operators, tracker_pubkey, next_index, remaining)
+                .await?;
+            log::info!("Cranked vault: {vault}");
 
-        log::info!("Cranked vault: {vault}");
+            state = self
+                .get_vault_update_state(epoch, vault, operators.len())
+                .await?;
+        }
 
         // Close
-        let tracker = self.get_update_state_tracker(vault, epoch).await?;
-        if operators.is_empty() || tracker.all_operators_updated(operators.len() as u64)? {
-            self.close_vault_update_state_tracker(vault, epoch, tracker_pubkey)
-                .await?;
-        } else {
+        if state != VaultUpdateState::Closable {
             let context = format!(
-                "Cranking failed to update all operators for vault: {vault}, tracker: {tracker_pubkey}"
+                "Cranking failed to update all operators for vault: {vault}, tracker: {tracker_pubkey}, state: {state:?}"
             );
             return Err(anyhow::anyhow!(context));
         }
+        self.close_vault_update_state_tracker(vault, epoch, tracker_pubkey)
+            .await?;
 
         log::info!("Closed tracker for vault: {vault}");
 
@@ -318,12 +343,19 @@ impl<'a> VaultHandler<'a> {
         let mut init_ix = init_ix_builder.instruction();
         init_ix.program_id = self.vault_program_id;
 
-        self.send_and_confirm_transaction_with_retry(vec![init_ix])
-            .await?;
+        send_and_confirm_transaction(
+            &self.get_rpc_client(),
+            self.payer,
+            &[init_ix],
+            &self.priority_fees,
+        )
+        .await?;
         Ok(())
     }
 
-    /// Cranks the [`VaultUpdateStateTracker`] for a specific epoch and list of operators.
+    /// Cranks `remaining` operators of the [`VaultUpdateStateTracker`], starting at the operator
+    /// at `next_index` and wrapping around. Operators are cranked in batches sized to fit within
+    /// the compute unit and transaction size limits.
     ///
     /// # Returns
     ///
@@ -331,67 +363,53 @@ impl<'a> VaultHandler<'a> {
     /// was successful or not.
     pub async fn crank(
         &self,
-        epoch: u64,
         vault: &Pubkey,
         operators: &[Pubkey],
         tracker_pubkey: Pubkey,
+        next_index: usize,
+        remaining: usize,
     ) -> anyhow::Result<()> {
-        let tracker = self.get_update_state_tracker(vault, epoch).await?;
-
-        if operators.is_empty() || tracker.all_operators_updated(operators.len() as u64)? {
-            return Ok(());
-        }
-
-        let end_index = (epoch as usize)
-            .checked_rem(operators.len())
-            .context("No operators to crank")?;
-
-        // Skip updated operators if cranking has already started
-        let start_index = if tracker.last_updated_index() == u64::MAX {
-            end_index
-        } else {
-            tracker.last_updated_index() as usize
-        };
-
-        let operators_iter = if start_index < end_index {
-            // Crank from start index to end index
-            operators
-                .iter()
-                .take(end_index)
-                .skip(start_index)
-                .collect::<Vec<_>>()
-                .into_iter()
-        } else {
-            // Crank through operators from start index to operators.len() and then 0 to end_index
-            operators
-                .iter()
-                .skip(start_index)
-                .chain(operators.iter().take(end_index))
-                .collect::<Vec<_>>()
-                .into_iter()
-        };
+        let num_operators = operators.len();
+        let delegations: Vec<(Pubkey, Pubkey)> = (0..remaining)
+            .map(|i| {
+                let operator = operators[(next_index + i) % num_operators];
+                let vault_operator_delegation = VaultOperatorDelegation::find_program_address(
+                    &self.vault_program_id,
+                    vault,
+                    &operator,
+                )
+                .0;
+                (operator, vault_operator_delegation)
+            })
+            .collect();
 
-        // Need to send each transaction in serial since strict sequence is required
-        for operator in operators_iter {
-            let vault_operator_delegation = VaultOperatorDelegation::find_program_address(
-                &self.vault_program_id,
-                vault,
-                operator,
-            )
-            .0;
+        let batch_size = min(
+            MAX_OPERATORS_PER_CRANK_TRANSACTION,
+            max_crank_vault_update_state_tracker_many_operators(MAX_COMPUTE_UNIT_LIMIT) as usize,
+        );
 
-            let mut ix_builder = CrankVaultUpdateStateTrackerBuilder::new();
+        // Need to send each batch in serial since strict sequence is required
+        for batch in delegations.chunks(batch_size) {
+            let mut ix_builder = CrankVaultUpdateStateTrackerManyBuilder::new();
             ix_builder
                 .config(self.config_address)
                 .vault(*vault)
-                .operator(*operator)
-                .vault_operator_delegation(vault_operator_delegation)
                 .vault_update_state_tracker(tracker_pubkey);
+            for (operator, vault_operator_delegation) in batch {
+                ix_builder
+                    .add_remaining_account(AccountMeta::new_readonly(*operator, false))
+                    .add_remaining_account(AccountMeta::new(*vault_operator_delegation, false));
+            }
             let mut ix = ix_builder.instruction();
             ix.program_id = self.vault_program_id;
 
-            self.send_and_confirm_transaction_with_retry(vec![ix])
-                .await?;
+            send_and_confirm_transaction(
+                &self.get_rpc_client(),
+                self.payer,
+                &[ix],
+                &self.priority_fees,
+            )
+            .await?;
         }
 
         Ok(())
@@ -418,8 +436,79 @@ impl<'a> VaultHandler<'a> {
         let mut close_ix = close_ix_builder.instruction();
         close_ix.program_id = self.vault_program_id;
 
-        self.send_and_confirm_transaction_with_retry(vec![close_ix])
-            .await?;
+        send_and_confirm_transaction(
+            &self.get_rpc_client(),
+            self.payer,
+            &[close_ix],
+            &self.priority_fees,
+        )
+        .await?;
+        Ok(())
+    }
+
+    /// Burns a withdrawal ticket that names the payer as its auto-claim delegate, creating the
+    /// payer's token account for the tip if needed.
+    ///
+    /// # Returns
+    ///
+    /// Returns `anyhow::Result<()>` indicating success or failure of the burn.
+    pub async fn auto_claim_withdrawal_ticket(
+        &self,
+        vault: &Vault,
+        claimable_ticket: &ClaimableTicket,
+        program_fee_wallet: &Pubkey,
+    ) -> anyhow::Result<()> {
+        let payer = self.payer.pubkey();
+        let create_ata_ix = create_associated_token_account_idempotent(
+            &payer,
+            &payer,
+            &vault.supported_mint,
+            &spl_token::id(),
+        );
+
+        let mut ix_builder = AutoClaimWithdrawalTicketBuilder::new();
+        ix_builder
+            .config(self.config_address)
+            .vault(claimable_ticket.vault)
+            .vault_token_account(get_associated_token_address(
+                &claimable_ticket.vault,
+                &vault.supported_mint,
+            ))
+            .vrt_mint(vault.vrt_mint)
+            .staker(claimable_ticket.staker)
+            .staker_token_account(get_associated_token_address(
+                &claimable_ticket.staker,
+                &vault.supported_mint,
+            ))
+            .vault_staker_withdrawal_ticket(claimable_ticket.ticket)
+            .vault_staker_withdrawal_ticket_token_account(get_associated_token_address(
+                &claimable_ticket.ticket,
+                &vault.vrt_mint,
+            ))
+            .vault_fee_token_account(get_associated_token_address(
+                &vault.fee_wallet,
+                &vault.vrt_mint,
+            ))
+            .program_fee_token_account(get_associated_token_address(
+                program_fee_wallet,
+                &vault.vrt_mint,
+            ))
+            .auto_claim_delegate(payer)
+            .auto_claim_delegate_token_account(get_associated_token_address(
+                &payer,
+                &vault.supported_mint,
+            ))
+            .tip_bps(claimable_ticket.tip_bps);
+        let mut ix = ix_builder.instruction();
+        ix.program_id = self.vault_program_id;
+
+        send_and_confirm_transaction(
+            &self.get_rpc_client(),
+            self.payer,
+            &[create_ata_ix, ix],
+            &self.priority_fees,
+        )
+        .await?;
         Ok(())
     }
 }
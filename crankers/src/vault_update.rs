@@ -0,0 +1,221 @@
+use std::future::Future;
+
+use futures::{stream, StreamExt};
+use jito_vault_core::vault_update_state_tracker::VaultUpdateStateTracker;
+use solana_sdk::pubkey::Pubkey;
+
+use crate::vault_handler::VaultHandler;
+
+/// Where a vault is in its update cycle for an epoch, rebuilt from the on-chain
+/// [`VaultUpdateStateTracker`] so a restarted cranker resumes a partial update instead of
+/// starting over.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VaultUpdateState {
+    /// The tracker for the epoch hasn't been initialized
+    TrackerMissing,
+    /// The tracker exists and `remaining` operators still need cranking, starting at the
+    /// operator at `next_index`
+    PartiallyCranked { next_index: usize, remaining: usize },
+    /// Every operator has been cranked and the tracker can be closed
+    Closable,
+}
+
+impl VaultUpdateState {
+    /// Determines the update state of a vault.
+    ///
+    /// # Arguments
+    /// * `tracker` - The tracker for `epoch`, or `None` if it doesn't exist
+    /// * `epoch` - The NCN epoch being updated
+    /// * `num_operators` - The number of operators the vault delegates to
+    pub fn from_tracker(
+        tracker: Option<&VaultUpdateStateTracker>,
+        epoch: u64,
+        num_operators: usize,
+    ) -> Self {
+        let Some(tracker) = tracker else {
+            return Self::TrackerMissing;
+        };
+        if num_operators == 0 {
+            return Self::Closable;
+        }
+
+        // Cranking starts at a different operator each epoch and wraps around
+        let start_index = (epoch % num_operators as u64) as usize;
+        if tracker.last_updated_index() == u64::MAX {
+            return Self::PartiallyCranked {
+                next_index: start_index,
+                remaining: num_operators,
+            };
+        }
+
+        let next_index = (tracker.last_updated_index() as usize + 1) % num_operators;
+        match (start_index + num_operators - next_index) % num_operators {
+            0 => Self::Closable,
+            remaining => Self::PartiallyCranked {
+                next_index,
+                remaining,
+            },
+        }
+    }
+}
+
+/// Runs a full update for a single vault
+pub trait VaultUpdater {
+    fn update_vault(
+        &self,
+        epoch: u64,
+        vault: Pubkey,
+        operators: Vec<Pubkey>,
+    ) -> impl Future<Output = anyhow::Result<()>>;
+}
+
+impl VaultUpdater for VaultHandler<'_> {
+    async fn update_vault(
+        &self,
+        epoch: u64,
+        vault: Pubkey,
+        operators: Vec<Pubkey>,
+    ) -> anyhow::Result<()> {
+        self.do_vault_update(epoch, &vault, &operators).await
+    }
+}
+
+/// Updates `vaults` with at most `workers` updates in flight. A failed or slow vault doesn't
+/// hold back the others; each vault's result is returned in completion order.
+///
+/// # Arguments
+/// * `vaults` - `(vault, operators)` pairs, with operators sorted by delegation index
+pub async fn update_vaults<U: VaultUpdater>(
+    updater: &U,
+    epoch: u64,
+    vaults: Vec<(Pubkey, Vec<Pubkey>)>,
+    workers: usize,
+) -> Vec<(Pubkey, anyhow::Result<()>)> {
+    stream::iter(vaults)
+        .map(|(vault, operators)| async move {
+            (vault, updater.update_vault(epoch, vault, operators).await)
+        })
+        .buffer_unordered(workers.max(1))
+        .collect()
+        .await
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        sync::{
+            atomic::{AtomicUsize, Ordering},
+            Arc,
+        },
+        time::Duration,
+    };
+
+    use super::*;
+
+    fn tracker_with_last_updated_index(last_updated_index: Option<u64>) -> VaultUpdateStateTracker {
+        match last_updated_index {
+            None => VaultUpdateStateTracker::new(Pubkey::new_unique(), 0, 0),
+            Some(index) => {
+                // The first index cranked in epoch `index` with `index + 1` operators is `index`
+                let mut tracker = VaultUpdateStateTracker::new(Pubkey::new_unique(), index, 0);
+                tracker.check_and_update_index(index, index + 1).unwrap();
+                tracker
+            }
+        }
+    }
+
+    #[test]
+    fn test_vault_update_state_from_tracker() {
+        assert_eq!(
+            VaultUpdateState::from_tracker(None, 0, 3),
+            VaultUpdateState::TrackerMissing
+        );
+
+        let fresh = tracker_with_last_updated_index(None);
+        assert_eq!(
+            VaultUpdateState::from_tracker(Some(&fresh), 0, 0),
+            VaultUpdateState::Closable
+        );
+        // Epoch 4 with 3 operators starts at operator 1
+        assert_eq!(
+            VaultUpdateState::from_tracker(Some(&fresh), 4, 3),
+            VaultUpdateState::PartiallyCranked {
+                next_index: 1,
+                remaining: 3
+            }
+        );
+    }
+
+    #[test]
+    fn test_vault_update_state_resumes_partial_update() {
+        // Epoch 4 with 3 operators cranks 1, 2, 0
+        let after_first = tracker_with_last_updated_index(Some(1));
+        assert_eq!(
+            VaultUpdateState::from_tracker(Some(&after_first), 4, 3),
+            VaultUpdateState::PartiallyCranked {
+                next_index: 2,
+                remaining: 2
+            }
+        );
+
+        let after_wrap = tracker_with_last_updated_index(Some(2));
+        assert_eq!(
+            VaultUpdateState::from_tracker(Some(&after_wrap), 4, 3),
+            VaultUpdateState::PartiallyCranked {
+                next_index: 0,
+                remaining: 1
+            }
+        );
+
+        let done = tracker_with_last_updated_index(Some(0));
+        assert_eq!(
+            VaultUpdateState::from_tracker(Some(&done), 4, 3),
+            VaultUpdateState::Closable
+        );
+    }
+
+    /// Tracks the number of concurrent updates and fails for a single vault
+    struct MockUpdater {
+        in_flight: AtomicUsize,
+        max_in_flight: AtomicUsize,
+        failing_vault: Pubkey,
+    }
+
+    impl VaultUpdater for Arc<MockUpdater> {
+        async fn update_vault(
+            &self,
+            _epoch: u64,
+            vault: Pubkey,
+            _operators: Vec<Pubkey>,
+        ) -> anyhow::Result<()> {
+            let in_flight = self.in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+            self.max_in_flight.fetch_max(in_flight, Ordering::SeqCst);
+            tokio::time::sleep(Duration::from_millis(10)).await;
+            self.in_flight.fetch_sub(1, Ordering::SeqCst);
+
+            if vault == self.failing_vault {
+                return Err(anyhow::anyhow!("mock failure"));
+            }
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_update_vaults_is_concurrent_and_isolates_failures() {
+        let vaults: Vec<(Pubkey, Vec<Pubkey>)> =
+            (0..8).map(|_| (Pubkey::new_unique(), vec![])).collect();
+        let updater = Arc::new(MockUpdater {
+            in_flight: AtomicUsize::new(0),
+            max_in_flight: AtomicUsize::new(0),
+            failing_vault: vaults[0].0,
+        });
+
+        let results = update_vaults(&updater, 1, vaults.clone(), 3).await;
+
+        assert_eq!(results.len(), vaults.len());
+        assert_eq!(updater.max_in_flight.load(Ordering::SeqCst), 3);
+        for (vault, result) in results {
+            assert_eq!(result.is_err(), vault == vaults[0].0);
+        }
+    }
+}
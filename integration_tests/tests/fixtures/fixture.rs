@@ -1,10 +1,14 @@
 use std::fmt::{Debug, Formatter};
 
+use jito_jsm_client::{RestakingClient, VaultClient};
 use jito_vault_sdk::inline_mpl_token_metadata;
 use solana_program::{
-    clock::Clock, native_token::sol_to_lamports, pubkey::Pubkey, system_instruction::transfer,
+    clock::Clock, native_token::sol_to_lamports, program_pack::Pack, pubkey::Pubkey,
+    system_instruction::transfer,
+};
+use solana_program_test::{
+    processor, BanksClient, BanksClientError, ProgramTest, ProgramTestContext,
 };
-use solana_program_test::{processor, BanksClientError, ProgramTest, ProgramTestContext};
 use solana_sdk::{
     commitment_config::CommitmentLevel,
     signature::{Keypair, Signer},
@@ -367,6 +371,45 @@ impl TestBuilder {
         Ok(())
     }
 
+    /// Overwrites the layout version in a program account's header
+    pub async fn set_account_version(
+        &mut self,
+        address: &Pubkey,
+        version: u8,
+    ) -> Result<(), BanksClientError> {
+        let mut account = self
+            .context
+            .banks_client
+            .get_account(*address)
+            .await?
+            .ok_or(BanksClientError::ClientError("account not found"))?;
+        account.data[1] = version;
+        self.context.set_account(address, &account.into());
+        Ok(())
+    }
+
+    /// Overwrites the balance of a token account, e.g. to simulate a loss of the tokens a vault
+    /// holds
+    pub async fn set_token_account_amount(
+        &mut self,
+        token_account: &Pubkey,
+        amount: u64,
+    ) -> Result<(), BanksClientError> {
+        let mut account = self
+            .context
+            .banks_client
+            .get_account(*token_account)
+            .await?
+            .ok_or(BanksClientError::ClientError("account not found"))?;
+        let mut state = spl_token::state::Account::unpack(&account.data)
+            .map_err(|_e| BanksClientError::ClientError("failed to unpack"))?;
+        state.amount = amount;
+        spl_token::state::Account::pack(state, &mut account.data)
+            .map_err(|_e| BanksClientError::ClientError("failed to pack"))?;
+        self.context.set_account(token_account, &account.into());
+        Ok(())
+    }
+
     pub async fn get_current_slot(&mut self) -> Result<u64, BanksClientError> {
         let clock: Clock = self.context.banks_client.get_sysvar().await?;
         Ok(clock.slot)
@@ -379,6 +422,23 @@ impl TestBuilder {
         )
     }
 
+    /// A [`VaultClient`] over the test's `BanksClient`
+    pub fn jsm_vault_client(&self) -> VaultClient<BanksClient> {
+        VaultClient::with_program_ids(
+            self.context.banks_client.clone(),
+            jito_vault_program::id(),
+            jito_restaking_program::id(),
+        )
+    }
+
+    /// A [`RestakingClient`] over the test's `BanksClient`
+    pub fn jsm_restaking_client(&self) -> RestakingClient<BanksClient> {
+        RestakingClient::with_program_id(
+            self.context.banks_client.clone(),
+            jito_restaking_program::id(),
+        )
+    }
+
     pub fn restaking_program_client(&self) -> RestakingProgramClient {
         RestakingProgramClient::new(
             self.context.banks_client.clone(),
@@ -1,4 +1,5 @@
-use solana_program::{instruction::InstructionError, program_error::ProgramError};
+use jito_jsm_core::diagnostic::{Check, FailedCheck};
+use solana_program::{instruction::InstructionError, program_error::ProgramError, pubkey::Pubkey};
 use solana_program_test::BanksClientError;
 use solana_sdk::transaction::TransactionError;
 use thiserror::Error;
@@ -28,6 +29,17 @@ impl TestError {
             TestError::ProgramError(_) => None,
         }
     }
+
+    /// The account checks the failed transaction logged, empty if it wasn't simulated
+    pub fn failed_checks(&self) -> Vec<FailedCheck> {
+        match self {
+            TestError::BanksClientError(BanksClientError::SimulationError { logs, .. }) => logs
+                .iter()
+                .filter_map(|line| FailedCheck::parse_log(line))
+                .collect(),
+            _ => vec![],
+        }
+    }
 }
 
 #[inline(always)]
@@ -39,3 +51,15 @@ pub fn assert_ix_error<T>(test_error: Result<T, TestError>, ix_error: Instructio
         TransactionError::InstructionError(0, ix_error)
     );
 }
+
+/// Asserts that the transaction failed after logging exactly `check` on `account`
+#[track_caller]
+pub fn assert_failed_check<T>(test_error: &Result<T, TestError>, check: Check, account: &Pubkey) {
+    assert_eq!(
+        test_error.as_ref().err().unwrap().failed_checks(),
+        vec![FailedCheck {
+            check,
+            account: Some(*account),
+        }]
+    );
+}
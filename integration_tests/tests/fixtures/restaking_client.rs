@@ -11,10 +11,11 @@ use jito_restaking_sdk::{
         cooldown_ncn_vault_ticket, initialize_config, initialize_ncn,
         initialize_ncn_operator_state, initialize_ncn_vault_slasher_ticket,
         initialize_ncn_vault_ticket, initialize_operator, initialize_operator_vault_ticket,
-        ncn_cooldown_operator, ncn_set_admin, ncn_warmup_operator, operator_cooldown_ncn,
-        operator_set_admin, operator_set_fee, operator_set_secondary_admin, operator_warmup_ncn,
-        set_config_admin, warmup_ncn_vault_slasher_ticket, warmup_ncn_vault_ticket,
-        warmup_operator_vault_ticket,
+        jail_operator, migrate_account, ncn_cooldown_operator, ncn_set_admin,
+        ncn_set_paused_categories, ncn_warmup_operator, operator_cooldown_ncn, operator_set_admin,
+        operator_set_fee, operator_set_paused_categories, operator_set_secondary_admin,
+        operator_warmup_ncn, set_config_admin, set_config_paused_categories, unjail_operator,
+        warmup_ncn_vault_slasher_ticket, warmup_ncn_vault_ticket, warmup_operator_vault_ticket,
     },
 };
 use solana_program::{
@@ -484,6 +485,102 @@ impl RestakingProgramClient {
         .await
     }
 
+    pub async fn do_jail_operator(
+        &mut self,
+        ncn_root: &NcnRoot,
+        operator_pubkey: &Pubkey,
+        reason: u16,
+    ) -> TestResult<()> {
+        self.jail_operator(
+            &Config::find_program_address(&jito_restaking_program::id()).0,
+            &ncn_root.ncn_pubkey,
+            operator_pubkey,
+            &NcnOperatorState::find_program_address(
+                &jito_restaking_program::id(),
+                &ncn_root.ncn_pubkey,
+                operator_pubkey,
+            )
+            .0,
+            &ncn_root.ncn_admin,
+            reason,
+        )
+        .await
+    }
+
+    pub async fn jail_operator(
+        &mut self,
+        config: &Pubkey,
+        ncn: &Pubkey,
+        operator_pubkey: &Pubkey,
+        ncn_operator_state: &Pubkey,
+        admin: &Keypair,
+        reason: u16,
+    ) -> TestResult<()> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[jail_operator(
+                &jito_restaking_program::id(),
+                config,
+                ncn,
+                operator_pubkey,
+                ncn_operator_state,
+                &admin.pubkey(),
+                reason,
+            )],
+            Some(&self.payer.pubkey()),
+            &[admin, &self.payer],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn do_unjail_operator(
+        &mut self,
+        ncn_root: &NcnRoot,
+        operator_pubkey: &Pubkey,
+    ) -> TestResult<()> {
+        self.unjail_operator(
+            &Config::find_program_address(&jito_restaking_program::id()).0,
+            &ncn_root.ncn_pubkey,
+            operator_pubkey,
+            &NcnOperatorState::find_program_address(
+                &jito_restaking_program::id(),
+                &ncn_root.ncn_pubkey,
+                operator_pubkey,
+            )
+            .0,
+            &ncn_root.ncn_admin,
+        )
+        .await
+    }
+
+    pub async fn unjail_operator(
+        &mut self,
+        config: &Pubkey,
+        ncn: &Pubkey,
+        operator_pubkey: &Pubkey,
+        ncn_operator_state: &Pubkey,
+        admin: &Keypair,
+    ) -> TestResult<()> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[unjail_operator(
+                &jito_restaking_program::id(),
+                config,
+                ncn,
+                operator_pubkey,
+                ncn_operator_state,
+                &admin.pubkey(),
+            )],
+            Some(&self.payer.pubkey()),
+            &[admin, &self.payer],
+            blockhash,
+        ))
+        .await
+    }
+
     pub async fn ncn_warmup_operator(
         &mut self,
         config: &Pubkey,
@@ -982,6 +1079,76 @@ impl RestakingProgramClient {
         .await
     }
 
+    pub async fn set_config_paused_categories(
+        &mut self,
+        config: &Pubkey,
+        admin: &Keypair,
+        paused_categories: u16,
+    ) -> TestResult<()> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[set_config_paused_categories(
+                &jito_restaking_program::id(),
+                config,
+                &admin.pubkey(),
+                paused_categories,
+            )],
+            Some(&self.payer.pubkey()),
+            &[admin, &self.payer],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn ncn_set_paused_categories(
+        &mut self,
+        config: &Pubkey,
+        ncn: &Pubkey,
+        admin: &Keypair,
+        paused_categories: u16,
+    ) -> TestResult<()> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[ncn_set_paused_categories(
+                &jito_restaking_program::id(),
+                config,
+                ncn,
+                &admin.pubkey(),
+                paused_categories,
+            )],
+            Some(&self.payer.pubkey()),
+            &[admin, &self.payer],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn operator_set_paused_categories(
+        &mut self,
+        config: &Pubkey,
+        operator: &Pubkey,
+        admin: &Keypair,
+        paused_categories: u16,
+    ) -> TestResult<()> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[operator_set_paused_categories(
+                &jito_restaking_program::id(),
+                config,
+                operator,
+                &admin.pubkey(),
+                paused_categories,
+            )],
+            Some(&self.payer.pubkey()),
+            &[admin, &self.payer],
+            blockhash,
+        ))
+        .await
+    }
+
     pub async fn ncn_delegate_token_account(
         &mut self,
         ncn_pubkey: &Pubkey,
@@ -1082,6 +1249,21 @@ impl RestakingProgramClient {
         ))
         .await
     }
+
+    pub async fn migrate_account(&mut self, account: &Pubkey) -> Result<(), TestError> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[migrate_account(
+                &jito_restaking_program::id(),
+                account,
+                &self.payer.pubkey(),
+            )],
+            Some(&self.payer.pubkey()),
+            &[&self.payer],
+            blockhash,
+        ))
+        .await
+    }
 }
 
 #[track_caller]
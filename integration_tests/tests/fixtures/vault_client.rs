@@ -1,10 +1,10 @@
 use std::{fmt, fmt::Debug};
 
-use borsh::BorshDeserialize;
+use borsh::{BorshDeserialize, BorshSerialize};
 use jito_bytemuck::AccountDeserialize;
 use jito_restaking_core::{
-    ncn_vault_slasher_ticket::NcnVaultSlasherTicket, ncn_vault_ticket::NcnVaultTicket,
-    operator_vault_ticket::OperatorVaultTicket,
+    ncn_operator_state::NcnOperatorState, ncn_vault_slasher_ticket::NcnVaultSlasherTicket,
+    ncn_vault_ticket::NcnVaultTicket, operator_vault_ticket::OperatorVaultTicket,
 };
 use jito_vault_core::{
     burn_vault::BurnVault, config::Config, vault::Vault,
@@ -17,7 +17,7 @@ use jito_vault_core::{
 use jito_vault_sdk::{
     error::VaultError,
     inline_mpl_token_metadata,
-    instruction::{VaultAdminRole, WithdrawalAllocationMethod},
+    instruction::{VaultAdminRole, VaultInstruction, WithdrawalAllocationMethod},
     sdk::{
         add_delegation, cooldown_delegation, cooldown_vault_ncn_ticket, initialize_config,
         initialize_vault, set_deposit_capacity, warmup_vault_ncn_slasher_ticket,
@@ -36,7 +36,8 @@ use solana_program::{
 use solana_program_test::{BanksClient, BanksClientError};
 use solana_sdk::{
     commitment_config::CommitmentLevel,
-    instruction::InstructionError,
+    hash::Hash,
+    instruction::{AccountMeta, Instruction, InstructionError},
     signature::{Keypair, Signer},
     transaction::{Transaction, TransactionError},
 };
@@ -292,6 +293,44 @@ impl VaultProgramClient {
         reward_fee_bps: u16,
         decimals: u8,
         program_fee_wallet: &Pubkey,
+    ) -> Result<VaultRoot, TestError> {
+        let vault_admin = Keypair::new();
+        let st_mint = Keypair::new();
+
+        self.airdrop(&vault_admin.pubkey(), 100.0).await?;
+        self.create_token_mint(&st_mint, &spl_token::id()).await?;
+        self.create_ata(&st_mint.pubkey(), &vault_admin.pubkey())
+            .await?;
+        self.mint_spl_to(
+            &st_mint.pubkey(),
+            &vault_admin.pubkey(),
+            Vault::DEFAULT_INITIALIZATION_TOKEN_AMOUNT,
+        )
+        .await?;
+
+        self.do_initialize_vault_with_supported_mint(
+            vault_admin,
+            &st_mint.pubkey(),
+            deposit_fee_bps,
+            withdrawal_fee_bps,
+            reward_fee_bps,
+            decimals,
+            program_fee_wallet,
+        )
+        .await
+    }
+
+    /// Initializes a vault for an existing supported mint. The admin must hold
+    /// [`Vault::DEFAULT_INITIALIZATION_TOKEN_AMOUNT`] of it.
+    pub async fn do_initialize_vault_with_supported_mint(
+        &mut self,
+        vault_admin: Keypair,
+        st_mint: &Pubkey,
+        deposit_fee_bps: u16,
+        withdrawal_fee_bps: u16,
+        reward_fee_bps: u16,
+        decimals: u8,
+        program_fee_wallet: &Pubkey,
     ) -> Result<VaultRoot, TestError> {
         let vault_base = Keypair::new();
 
@@ -301,15 +340,9 @@ impl VaultProgramClient {
             Vault::find_program_address(&jito_vault_program::id(), &vault_base.pubkey()).0;
 
         let vrt_mint = Keypair::new();
-        let vault_admin = Keypair::new();
-        let st_mint = Keypair::new();
 
-        self.airdrop(&vault_admin.pubkey(), 100.0).await?;
-        self.create_token_mint(&st_mint, &spl_token::id()).await?;
-
-        let admin_st_token_account =
-            get_associated_token_address(&vault_admin.pubkey(), &st_mint.pubkey());
-        let vault_st_token_account = get_associated_token_address(&vault_pubkey, &st_mint.pubkey());
+        let admin_st_token_account = get_associated_token_address(&vault_admin.pubkey(), st_mint);
+        let vault_st_token_account = get_associated_token_address(&vault_pubkey, st_mint);
 
         let burn_vault =
             BurnVault::find_program_address(&jito_vault_program::id(), &vault_base.pubkey()).0;
@@ -318,22 +351,13 @@ impl VaultProgramClient {
             get_associated_token_address(&burn_vault, &vrt_mint.pubkey());
 
         // Needs to be created before initialize vault
-        self.create_ata(&st_mint.pubkey(), &vault_pubkey).await?;
-        self.create_ata(&st_mint.pubkey(), &vault_admin.pubkey())
-            .await?;
-
-        self.mint_spl_to(
-            &st_mint.pubkey(),
-            &vault_admin.pubkey(),
-            initialize_token_amount,
-        )
-        .await?;
+        self.create_ata(st_mint, &vault_pubkey).await?;
 
         self.initialize_vault(
             &Config::find_program_address(&jito_vault_program::id()).0,
             &vault_pubkey,
             &vrt_mint,
-            &st_mint,
+            st_mint,
             &admin_st_token_account,
             &vault_st_token_account,
             &burn_vault,
@@ -362,6 +386,55 @@ impl VaultProgramClient {
         })
     }
 
+    /// Initializes `len` vaults with the same admin, each supporting the previous vault's VRT so
+    /// it can be nested over it. The vaults aren't nested yet.
+    pub async fn do_initialize_vault_chain(
+        &mut self,
+        len: usize,
+        program_fee_wallet: &Pubkey,
+    ) -> Result<Vec<VaultRoot>, TestError> {
+        let initialize_token_amount = Vault::DEFAULT_INITIALIZATION_TOKEN_AMOUNT;
+        let vault_admin = Keypair::new();
+
+        let mut vault_roots = vec![
+            self.do_initialize_vault(0, 0, 0, 9, program_fee_wallet)
+                .await?,
+        ];
+        // The admin deposits enough to initialize every vault above the first one
+        let mut amount = initialize_token_amount * (len as u64 - 1);
+        self.configure_depositor(&vault_roots[0], &vault_admin.pubkey(), amount)
+            .await?;
+        self.do_mint_to(&vault_roots[0], &vault_admin, amount, amount)
+            .await?;
+
+        for _ in 1..len {
+            let child_vault = self
+                .get_vault(&vault_roots.last().unwrap().vault_pubkey)
+                .await?;
+            let vault_root = self
+                .do_initialize_vault_with_supported_mint(
+                    vault_admin.insecure_clone(),
+                    &child_vault.vrt_mint,
+                    0,
+                    0,
+                    0,
+                    9,
+                    program_fee_wallet,
+                )
+                .await?;
+
+            amount -= initialize_token_amount;
+            // The admin's VRT token account was created to hold fees
+            if amount > 0 {
+                self.do_mint_to(&vault_root, &vault_admin, amount, amount)
+                    .await?;
+            }
+            vault_roots.push(vault_root);
+        }
+
+        Ok(vault_roots)
+    }
+
     pub async fn do_initialize_vault_ncn_ticket(
         &mut self,
         vault_root: &VaultRoot,
@@ -710,12 +783,67 @@ impl VaultProgramClient {
         Ok(())
     }
 
+    /// Adds a delegation to an operator that is jailed by at least one NCN. The vault's NCNs must
+    /// be passed in the order of their [`VaultNcnTicket`] index.
+    pub async fn do_add_delegation_with_ncns(
+        &mut self,
+        vault_root: &VaultRoot,
+        operator: &Pubkey,
+        ncns: &[Pubkey],
+        amount: u64,
+    ) -> Result<(), TestError> {
+        let mut ix = add_delegation(
+            &jito_vault_program::id(),
+            &Config::find_program_address(&jito_vault_program::id()).0,
+            &vault_root.vault_pubkey,
+            operator,
+            &VaultOperatorDelegation::find_program_address(
+                &jito_vault_program::id(),
+                &vault_root.vault_pubkey,
+                operator,
+            )
+            .0,
+            &vault_root.vault_admin.pubkey(),
+            amount,
+        );
+        for ncn in ncns {
+            ix.accounts.push(AccountMeta::new_readonly(*ncn, false));
+            ix.accounts.push(AccountMeta::new_readonly(
+                VaultNcnTicket::find_program_address(
+                    &jito_vault_program::id(),
+                    &vault_root.vault_pubkey,
+                    ncn,
+                )
+                .0,
+                false,
+            ));
+            ix.accounts.push(AccountMeta::new_readonly(
+                NcnOperatorState::find_program_address(
+                    &jito_restaking_program::id(),
+                    ncn,
+                    operator,
+                )
+                .0,
+                false,
+            ));
+        }
+
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self._process_transaction(&Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&vault_root.vault_admin.pubkey()),
+            &[&vault_root.vault_admin],
+            blockhash,
+        ))
+        .await
+    }
+
     pub async fn initialize_vault(
         &mut self,
         config: &Pubkey,
         vault: &Pubkey,
         vrt_mint: &Keypair,
-        st_mint: &Keypair,
+        st_mint: &Pubkey,
         admin_st_token_account: &Pubkey,
         vault_st_token_account: &Pubkey,
         burn_vault: &Pubkey,
@@ -736,7 +864,7 @@ impl VaultProgramClient {
                 config,
                 vault,
                 &vrt_mint.pubkey(),
-                &st_mint.pubkey(),
+                st_mint,
                 admin_st_token_account,
                 vault_st_token_account,
                 burn_vault,
@@ -1163,7 +1291,99 @@ impl VaultProgramClient {
         Ok(())
     }
 
+    fn crank_vault_update_state_tracker_many_transaction(
+        &self,
+        vault: &Pubkey,
+        vault_update_state_tracker: &Pubkey,
+        operators: &[Pubkey],
+        blockhash: Hash,
+    ) -> Transaction {
+        let delegations: Vec<(Pubkey, Pubkey)> = operators
+            .iter()
+            .map(|operator| {
+                (
+                    *operator,
+                    VaultOperatorDelegation::find_program_address(
+                        &jito_vault_program::id(),
+                        vault,
+                        operator,
+                    )
+                    .0,
+                )
+            })
+            .collect();
+
+        Transaction::new_signed_with_payer(
+            &[jito_vault_sdk::sdk::crank_vault_update_state_tracker_many(
+                &jito_vault_program::id(),
+                &Config::find_program_address(&jito_vault_program::id()).0,
+                vault,
+                vault_update_state_tracker,
+                &delegations,
+            )],
+            Some(&self.payer.pubkey()),
+            &[&self.payer],
+            blockhash,
+        )
+    }
+
+    pub async fn crank_vault_update_state_tracker_many(
+        &mut self,
+        vault: &Pubkey,
+        vault_update_state_tracker: &Pubkey,
+        operators: &[Pubkey],
+    ) -> TestResult<()> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        let tx = self.crank_vault_update_state_tracker_many_transaction(
+            vault,
+            vault_update_state_tracker,
+            operators,
+            blockhash,
+        );
+        self._process_transaction(&tx).await
+    }
+
+    /// Simulates cranking the operators in a single instruction and returns the compute units
+    /// consumed by the transaction
+    pub async fn simulate_crank_vault_update_state_tracker_many(
+        &mut self,
+        vault: &Pubkey,
+        vault_update_state_tracker: &Pubkey,
+        operators: &[Pubkey],
+    ) -> TestResult<u64> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        let tx = self.crank_vault_update_state_tracker_many_transaction(
+            vault,
+            vault_update_state_tracker,
+            operators,
+            blockhash,
+        );
+        let simulation = self.banks_client.simulate_transaction(tx).await?;
+        if let Some(Err(err)) = simulation.result {
+            return Err(BanksClientError::TransactionError(err).into());
+        }
+        Ok(simulation
+            .simulation_details
+            .map(|details| details.units_consumed)
+            .unwrap_or_default())
+    }
+
     pub async fn update_vault_balance(&mut self, vault_pubkey: &Pubkey) -> TestResult<()> {
+        let vault = self.get_vault(vault_pubkey).await?;
+        self.update_vault_balance_with_child_vault(
+            vault_pubkey,
+            vault.is_nested().then_some(&vault.child_vault),
+        )
+        .await
+    }
+
+    /// Updates the vault balance passing `child_vault` as the child vault account, whether or not
+    /// it's the vault's child vault
+    pub async fn update_vault_balance_with_child_vault(
+        &mut self,
+        vault_pubkey: &Pubkey,
+        child_vault: Option<&Pubkey>,
+    ) -> TestResult<()> {
         let blockhash = self.banks_client.get_latest_blockhash().await?;
 
         let vault = self.get_vault(vault_pubkey).await?;
@@ -1177,6 +1397,7 @@ impl VaultProgramClient {
                 &vault.vrt_mint,
                 &get_associated_token_address(&vault.fee_wallet, &vault.vrt_mint),
                 &spl_token::ID,
+                child_vault,
             )],
             Some(&self.payer.pubkey()),
             &[&self.payer],
@@ -1333,6 +1554,115 @@ impl VaultProgramClient {
         .await
     }
 
+    pub async fn set_auto_claim_delegate(
+        &mut self,
+        config: &Pubkey,
+        vault: &Pubkey,
+        vault_staker_withdrawal_ticket: &Pubkey,
+        staker: &Keypair,
+        auto_claim_delegate: &Pubkey,
+        max_tip_bps: u16,
+    ) -> Result<(), TestError> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self._process_transaction(&Transaction::new_signed_with_payer(
+            &[jito_vault_sdk::sdk::set_auto_claim_delegate(
+                &jito_vault_program::id(),
+                config,
+                vault,
+                vault_staker_withdrawal_ticket,
+                &staker.pubkey(),
+                auto_claim_delegate,
+                max_tip_bps,
+            )],
+            Some(&self.payer.pubkey()),
+            &[&self.payer, staker],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn migrate_account(&mut self, account: &Pubkey) -> Result<(), TestError> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self._process_transaction(&Transaction::new_signed_with_payer(
+            &[jito_vault_sdk::sdk::migrate_account(
+                &jito_vault_program::id(),
+                account,
+                &self.payer.pubkey(),
+            )],
+            Some(&self.payer.pubkey()),
+            &[&self.payer],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn do_auto_claim_withdrawal_ticket(
+        &mut self,
+        vault_root: &VaultRoot,
+        staker: &Pubkey,
+        vault_staker_withdrawal_ticket_base: &Pubkey,
+        program_fee_wallet: &Pubkey,
+        auto_claim_delegate: &Keypair,
+        tip_bps: u16,
+    ) -> Result<(), TestError> {
+        let vault = self.get_vault(&vault_root.vault_pubkey).await.unwrap();
+        let vault_staker_withdrawal_ticket = VaultStakerWithdrawalTicket::find_program_address(
+            &jito_vault_program::id(),
+            &vault_root.vault_pubkey,
+            vault_staker_withdrawal_ticket_base,
+        )
+        .0;
+
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self._process_transaction(&Transaction::new_signed_with_payer(
+            &[jito_vault_sdk::sdk::auto_claim_withdrawal_ticket(
+                &jito_vault_program::id(),
+                &Config::find_program_address(&jito_vault_program::id()).0,
+                &vault_root.vault_pubkey,
+                &get_associated_token_address(&vault_root.vault_pubkey, &vault.supported_mint),
+                &vault.vrt_mint,
+                staker,
+                &get_associated_token_address(staker, &vault.supported_mint),
+                &vault_staker_withdrawal_ticket,
+                &get_associated_token_address(&vault_staker_withdrawal_ticket, &vault.vrt_mint),
+                &get_associated_token_address(&vault.fee_wallet, &vault.vrt_mint),
+                &get_associated_token_address(program_fee_wallet, &vault.vrt_mint),
+                &auto_claim_delegate.pubkey(),
+                &get_associated_token_address(&auto_claim_delegate.pubkey(), &vault.supported_mint),
+                tip_bps,
+            )],
+            Some(&self.payer.pubkey()),
+            &[&self.payer, auto_claim_delegate],
+            blockhash,
+        ))
+        .await
+    }
+
+    /// Sends an auto-claim with `num_accounts` placeholder accounts
+    pub async fn auto_claim_withdrawal_ticket_with_num_accounts(
+        &mut self,
+        num_accounts: usize,
+    ) -> Result<(), TestError> {
+        let instruction = Instruction {
+            program_id: jito_vault_program::id(),
+            accounts: (0..num_accounts)
+                .map(|_| AccountMeta::new_readonly(Pubkey::new_unique(), false))
+                .collect(),
+            data: VaultInstruction::AutoClaimWithdrawalTicket { tip_bps: 0 }
+                .try_to_vec()
+                .unwrap(),
+        };
+
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self._process_transaction(&Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&self.payer.pubkey()),
+            &[&self.payer],
+            blockhash,
+        ))
+        .await
+    }
+
     pub async fn add_delegation(
         &mut self,
         config: &Pubkey,
@@ -1806,6 +2136,72 @@ impl VaultProgramClient {
         .await
     }
 
+    pub async fn set_is_emergency_exit(
+        &mut self,
+        vault: &Pubkey,
+        admin: &Keypair,
+        is_emergency_exit: bool,
+    ) -> Result<(), TestError> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self._process_transaction(&Transaction::new_signed_with_payer(
+            &[jito_vault_sdk::sdk::set_is_emergency_exit(
+                &jito_vault_program::id(),
+                &Config::find_program_address(&jito_vault_program::id()).0,
+                vault,
+                &admin.pubkey(),
+                is_emergency_exit,
+            )],
+            Some(&admin.pubkey()),
+            &[admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn set_paused_categories(
+        &mut self,
+        vault: &Pubkey,
+        admin: &Keypair,
+        paused_categories: u16,
+    ) -> Result<(), TestError> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self._process_transaction(&Transaction::new_signed_with_payer(
+            &[jito_vault_sdk::sdk::set_paused_categories(
+                &jito_vault_program::id(),
+                &Config::find_program_address(&jito_vault_program::id()).0,
+                vault,
+                &admin.pubkey(),
+                paused_categories,
+            )],
+            Some(&admin.pubkey()),
+            &[admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn set_child_vault(
+        &mut self,
+        vault: &Pubkey,
+        child_vault: &Pubkey,
+        admin: &Keypair,
+    ) -> Result<(), TestError> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self._process_transaction(&Transaction::new_signed_with_payer(
+            &[jito_vault_sdk::sdk::set_child_vault(
+                &jito_vault_program::id(),
+                &Config::find_program_address(&jito_vault_program::id()).0,
+                vault,
+                child_vault,
+                &admin.pubkey(),
+            )],
+            Some(&admin.pubkey()),
+            &[admin],
+            blockhash,
+        ))
+        .await
+    }
+
     pub async fn set_config_admin(
         &mut self,
         config: &Pubkey,
@@ -0,0 +1 @@
+mod vault_accounting;
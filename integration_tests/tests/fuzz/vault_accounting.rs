@@ -0,0 +1,475 @@
+#[cfg(test)]
+mod tests {
+    use jito_vault_core::{
+        burn_vault::BurnVault,
+        config::Config,
+        delegation_state::DelegationState,
+        vault::{BurnSummary, MintSummary, Vault},
+        vault_staker_withdrawal_ticket::VaultStakerWithdrawalTicket,
+    };
+    use proptest::{collection::vec, prelude::*};
+    use solana_program::{epoch_schedule::DEFAULT_SLOTS_PER_EPOCH, pubkey::Pubkey};
+    use solana_sdk::signature::{Keypair, Signer};
+    use spl_associated_token_account::get_associated_token_address;
+
+    use crate::fixtures::{
+        fixture::{ConfiguredVault, TestBuilder},
+        vault_client::{VaultProgramClient, VaultRoot, VaultStakerWithdrawalTicketRoot},
+    };
+
+    const NUM_STAKERS: usize = 3;
+    const NUM_OPERATORS: u16 = 2;
+    const STAKER_FUNDING: u64 = 1_000_000_000;
+
+    /// A single step of a fuzzed sequence. Amounts are picked relative to the current state so
+    /// that most steps are ones the program can accept.
+    #[derive(Debug, Clone)]
+    enum Op {
+        MintTo { staker: usize, amount: u64 },
+        EnqueueWithdrawal { staker: usize, bps: u64 },
+        BurnWithdrawalTicket { ticket: usize },
+        AddDelegation { operator: usize, bps: u64 },
+        CooldownDelegation { operator: usize, bps: u64 },
+        Warp { slots: u64 },
+        FullVaultUpdate,
+    }
+
+    fn op_strategy() -> impl Strategy<Value = Op> {
+        prop_oneof![
+            3 => (0..NUM_STAKERS, 1..=STAKER_FUNDING / 4)
+                .prop_map(|(staker, amount)| Op::MintTo { staker, amount }),
+            2 => (0..NUM_STAKERS, 1..=10_000u64)
+                .prop_map(|(staker, bps)| Op::EnqueueWithdrawal { staker, bps }),
+            2 => any::<usize>().prop_map(|ticket| Op::BurnWithdrawalTicket { ticket }),
+            2 => (0..NUM_OPERATORS as usize, 1..=10_000u64)
+                .prop_map(|(operator, bps)| Op::AddDelegation { operator, bps }),
+            1 => (0..NUM_OPERATORS as usize, 1..=10_000u64)
+                .prop_map(|(operator, bps)| Op::CooldownDelegation { operator, bps }),
+            2 => (1..=2 * DEFAULT_SLOTS_PER_EPOCH).prop_map(|slots| Op::Warp { slots }),
+            2 => Just(Op::FullVaultUpdate),
+        ]
+    }
+
+    /// An open withdrawal ticket, as the model expects it to be on chain
+    struct Ticket {
+        staker: usize,
+        base: Pubkey,
+        vrt_amount: u64,
+    }
+
+    /// Drives the vault program through [`TestBuilder`] while keeping a model of what every
+    /// instruction should have done.
+    struct Harness {
+        fixture: TestBuilder,
+        vault_program_client: VaultProgramClient,
+        vault_root: VaultRoot,
+        operators: Vec<Pubkey>,
+        stakers: Vec<Keypair>,
+        tickets: Vec<Ticket>,
+        program_fee_wallet: Pubkey,
+    }
+
+    impl Harness {
+        async fn new(deposit_fee_bps: u16, withdrawal_fee_bps: u16) -> Self {
+            let mut fixture = TestBuilder::new().await;
+            let ConfiguredVault {
+                mut vault_program_client,
+                vault_root,
+                operator_roots,
+                ..
+            } = fixture
+                .setup_vault_with_ncn_and_operators(
+                    deposit_fee_bps,
+                    withdrawal_fee_bps,
+                    0,
+                    NUM_OPERATORS,
+                    &[],
+                )
+                .await
+                .unwrap();
+
+            let mut stakers = Vec::with_capacity(NUM_STAKERS);
+            for _ in 0..NUM_STAKERS {
+                let staker = Keypair::new();
+                vault_program_client
+                    .configure_depositor(&vault_root, &staker.pubkey(), STAKER_FUNDING)
+                    .await
+                    .unwrap();
+                stakers.push(staker);
+            }
+
+            let config = vault_program_client
+                .get_config(&Config::find_program_address(&jito_vault_program::id()).0)
+                .await
+                .unwrap();
+
+            Self {
+                fixture,
+                vault_program_client,
+                vault_root,
+                operators: operator_roots
+                    .iter()
+                    .map(|operator_root| operator_root.operator_pubkey)
+                    .collect(),
+                stakers,
+                tickets: Vec::new(),
+                program_fee_wallet: config.program_fee_wallet,
+            }
+        }
+
+        async fn vault(&mut self) -> Vault {
+            self.vault_program_client
+                .get_vault(&self.vault_root.vault_pubkey)
+                .await
+                .unwrap()
+        }
+
+        async fn token_balance(&mut self, owner: &Pubkey, mint: &Pubkey) -> u64 {
+            self.fixture
+                .get_token_account(&get_associated_token_address(owner, mint))
+                .await
+                .unwrap()
+                .amount
+        }
+
+        fn ticket_address(&self, base: &Pubkey) -> Pubkey {
+            VaultStakerWithdrawalTicket::find_program_address(
+                &jito_vault_program::id(),
+                &self.vault_root.vault_pubkey,
+                base,
+            )
+            .0
+        }
+
+        /// Applies the op and checks its effects against the vault math. An op the program
+        /// rejects must leave the vault untouched.
+        async fn apply(&mut self, op: &Op) {
+            let vault_before = self.vault().await;
+
+            let applied = match *op {
+                Op::MintTo { staker, amount } => self.mint_to(&vault_before, staker, amount).await,
+                Op::EnqueueWithdrawal { staker, bps } => {
+                    self.enqueue_withdrawal(&vault_before, staker, bps).await
+                }
+                Op::BurnWithdrawalTicket { ticket } => {
+                    self.burn_withdrawal_ticket(&vault_before, ticket).await
+                }
+                Op::AddDelegation { operator, bps } => {
+                    let amount =
+                        (vault_before.tokens_deposited() as u128 * bps as u128 / 10_000) as u64;
+                    self.vault_program_client
+                        .do_add_delegation(&self.vault_root, &self.operators[operator], amount)
+                        .await
+                        .is_ok()
+                }
+                Op::CooldownDelegation { operator, bps } => {
+                    let staked_amount = self
+                        .vault_program_client
+                        .get_vault_operator_delegation(
+                            &self.vault_root.vault_pubkey,
+                            &self.operators[operator],
+                        )
+                        .await
+                        .unwrap()
+                        .delegation_state
+                        .staked_amount();
+                    let amount = (staked_amount as u128 * bps as u128 / 10_000) as u64;
+                    self.vault_program_client
+                        .do_cooldown_delegation(&self.vault_root, &self.operators[operator], amount)
+                        .await
+                        .is_ok()
+                }
+                Op::Warp { slots } => {
+                    self.fixture.warp_slot_incremental(slots).await.unwrap();
+                    true
+                }
+                // Starting an update when one isn't needed fails without side effects, but once
+                // the tracker is initialized the crank and close must always be able to finish
+                Op::FullVaultUpdate => self
+                    .vault_program_client
+                    .do_full_vault_update(&self.vault_root.vault_pubkey, &self.operators)
+                    .await
+                    .is_ok(),
+            };
+
+            if !applied {
+                assert_eq!(
+                    self.vault().await,
+                    vault_before,
+                    "rejected {:?} modified the vault",
+                    op
+                );
+            }
+        }
+
+        async fn mint_to(&mut self, vault_before: &Vault, staker: usize, amount: u64) -> bool {
+            let staker_pubkey = self.stakers[staker].pubkey();
+            let staker_vrt_before = self
+                .token_balance(&staker_pubkey, &vault_before.vrt_mint)
+                .await;
+            let fee_wallet_vrt_before = self
+                .token_balance(&vault_before.fee_wallet, &vault_before.vrt_mint)
+                .await;
+
+            let result = self
+                .vault_program_client
+                .do_mint_to(&self.vault_root, &self.stakers[staker], amount, 0)
+                .await;
+            if result.is_err() {
+                return false;
+            }
+
+            let MintSummary {
+                vrt_to_depositor,
+                vrt_to_fee_wallet,
+            } = { *vault_before }
+                .mint_with_fee(amount, 0)
+                .expect("program minted where the vault math fails");
+            assert_eq!(
+                self.token_balance(&staker_pubkey, &vault_before.vrt_mint)
+                    .await,
+                staker_vrt_before + vrt_to_depositor
+            );
+            assert_eq!(
+                self.token_balance(&vault_before.fee_wallet, &vault_before.vrt_mint)
+                    .await,
+                fee_wallet_vrt_before + vrt_to_fee_wallet
+            );
+            true
+        }
+
+        async fn enqueue_withdrawal(
+            &mut self,
+            vault_before: &Vault,
+            staker: usize,
+            bps: u64,
+        ) -> bool {
+            let staker_pubkey = self.stakers[staker].pubkey();
+            let staker_vrt = self
+                .token_balance(&staker_pubkey, &vault_before.vrt_mint)
+                .await;
+            let vrt_amount = (staker_vrt as u128 * bps as u128 / 10_000) as u64;
+
+            let result = self
+                .vault_program_client
+                .do_enqueue_withdrawal(&self.vault_root, &self.stakers[staker], vrt_amount)
+                .await;
+            let Ok(VaultStakerWithdrawalTicketRoot { base }) = result else {
+                return false;
+            };
+
+            assert_eq!(
+                self.token_balance(&staker_pubkey, &vault_before.vrt_mint)
+                    .await,
+                staker_vrt - vrt_amount
+            );
+            assert_eq!(
+                self.token_balance(&self.ticket_address(&base), &vault_before.vrt_mint)
+                    .await,
+                vrt_amount
+            );
+            self.tickets.push(Ticket {
+                staker,
+                base,
+                vrt_amount,
+            });
+            true
+        }
+
+        async fn burn_withdrawal_ticket(&mut self, vault_before: &Vault, ticket: usize) -> bool {
+            if self.tickets.is_empty() {
+                return false;
+            }
+            let ticket = ticket % self.tickets.len();
+            let Ticket {
+                staker,
+                base,
+                vrt_amount,
+            } = self.tickets[ticket];
+            let staker_pubkey = self.stakers[staker].pubkey();
+            let program_fee_wallet = self.program_fee_wallet;
+
+            let staker_st_before = self
+                .token_balance(&staker_pubkey, &vault_before.supported_mint)
+                .await;
+            let fee_wallet_vrt_before = self
+                .token_balance(&vault_before.fee_wallet, &vault_before.vrt_mint)
+                .await;
+            let program_fee_wallet_vrt_before = self
+                .token_balance(&program_fee_wallet, &vault_before.vrt_mint)
+                .await;
+
+            let result = self
+                .vault_program_client
+                .do_burn_withdrawal_ticket(
+                    &self.vault_root,
+                    &self.stakers[staker],
+                    &base,
+                    &program_fee_wallet,
+                )
+                .await;
+            if result.is_err() {
+                return false;
+            }
+
+            let BurnSummary {
+                vault_fee_amount,
+                program_fee_amount,
+                out_amount,
+                ..
+            } = { *vault_before }
+                .burn_with_fee(false, false, vrt_amount)
+                .expect("program burned where the vault math fails");
+            assert_eq!(
+                self.token_balance(&staker_pubkey, &vault_before.supported_mint)
+                    .await,
+                staker_st_before + out_amount
+            );
+            assert_eq!(
+                self.token_balance(&vault_before.fee_wallet, &vault_before.vrt_mint)
+                    .await,
+                fee_wallet_vrt_before + vault_fee_amount
+            );
+            assert_eq!(
+                self.token_balance(&program_fee_wallet, &vault_before.vrt_mint)
+                    .await,
+                program_fee_wallet_vrt_before + program_fee_amount
+            );
+            self.tickets.remove(ticket);
+            true
+        }
+
+        /// Checks the invariants that must hold between any two instructions
+        async fn check_invariants(&mut self) {
+            let vault = self.vault().await;
+
+            // No supported tokens are created or destroyed, the vault's accounting matches its
+            // token account and the stakers as a group never get back more than they put in
+            let supported_mint = self
+                .fixture
+                .get_token_mint(&vault.supported_mint)
+                .await
+                .unwrap();
+            let vault_pubkey = self.vault_root.vault_pubkey;
+            let vault_st = self
+                .token_balance(&vault_pubkey, &vault.supported_mint)
+                .await;
+            let admin_st = self
+                .token_balance(&self.vault_root.vault_admin.pubkey(), &vault.supported_mint)
+                .await;
+            let mut stakers_st = 0;
+            for staker in self
+                .stakers
+                .iter()
+                .map(|staker| staker.pubkey())
+                .collect::<Vec<_>>()
+            {
+                stakers_st += self.token_balance(&staker, &vault.supported_mint).await;
+            }
+            assert_eq!(vault_st + admin_st + stakers_st, supported_mint.supply);
+            assert_eq!(vault.tokens_deposited(), vault_st);
+            assert!(stakers_st <= STAKER_FUNDING * NUM_STAKERS as u64);
+
+            // vrt_supply tracks the mint, and every VRT is held by a known account
+            let vrt_mint = self.fixture.get_token_mint(&vault.vrt_mint).await.unwrap();
+            assert_eq!(vault.vrt_supply(), vrt_mint.supply);
+
+            let burn_vault =
+                BurnVault::find_program_address(&jito_vault_program::id(), &vault.base).0;
+            let mut vrt_held = self.token_balance(&burn_vault, &vault.vrt_mint).await
+                + self.token_balance(&vault.fee_wallet, &vault.vrt_mint).await;
+            let program_fee_wallet = self.program_fee_wallet;
+            if program_fee_wallet != vault.fee_wallet {
+                vrt_held += self
+                    .token_balance(&program_fee_wallet, &vault.vrt_mint)
+                    .await;
+            }
+            for staker in self
+                .stakers
+                .iter()
+                .map(|staker| staker.pubkey())
+                .collect::<Vec<_>>()
+            {
+                vrt_held += self.token_balance(&staker, &vault.vrt_mint).await;
+            }
+            let mut vrt_in_tickets = 0;
+            for ticket_address in self
+                .tickets
+                .iter()
+                .map(|ticket| self.ticket_address(&ticket.base))
+                .collect::<Vec<_>>()
+            {
+                vrt_in_tickets += self.token_balance(&ticket_address, &vault.vrt_mint).await;
+            }
+            assert_eq!(vrt_held + vrt_in_tickets, vrt_mint.supply);
+
+            // Every open ticket is somewhere in the withdrawal queue
+            assert_eq!(
+                vault.vrt_enqueued_for_cooldown_amount()
+                    + vault.vrt_cooling_down_amount()
+                    + vault.vrt_ready_to_claim_amount(),
+                self.tickets
+                    .iter()
+                    .map(|ticket| ticket.vrt_amount)
+                    .sum::<u64>()
+            );
+            assert_eq!(
+                vrt_in_tickets,
+                self.tickets
+                    .iter()
+                    .map(|ticket| ticket.vrt_amount)
+                    .sum::<u64>()
+            );
+
+            // The vault's delegation state is the sum of its operators' and is backed by deposits
+            let mut operators_delegation_state = DelegationState::default();
+            for operator in self.operators.clone() {
+                let vault_operator_delegation = self
+                    .vault_program_client
+                    .get_vault_operator_delegation(&self.vault_root.vault_pubkey, &operator)
+                    .await
+                    .unwrap();
+                operators_delegation_state
+                    .accumulate(&vault_operator_delegation.delegation_state)
+                    .unwrap();
+            }
+            assert_eq!(vault.delegation_state, operators_delegation_state);
+            assert!(vault.delegation_state.total_security().unwrap() <= vault.tokens_deposited());
+        }
+    }
+
+    async fn run_ops(deposit_fee_bps: u16, withdrawal_fee_bps: u16, ops: Vec<Op>) {
+        let mut harness = Harness::new(deposit_fee_bps, withdrawal_fee_bps).await;
+        harness.check_invariants().await;
+        for op in ops.iter() {
+            harness.apply(op).await;
+            harness.check_invariants().await;
+        }
+    }
+
+    proptest! {
+        // Every case boots a fresh bank, so keep the count low by default and raise it with
+        // PROPTEST_CASES for longer runs
+        #![proptest_config(ProptestConfig {
+            cases: std::env::var("PROPTEST_CASES")
+                .ok()
+                .and_then(|cases| cases.parse().ok())
+                .unwrap_or(16),
+            failure_persistence: None,
+            ..ProptestConfig::default()
+        })]
+
+        #[test]
+        fn test_vault_accounting_fuzz(
+            deposit_fee_bps in 0..=100u16,
+            withdrawal_fee_bps in 0..=100u16,
+            ops in vec(op_strategy(), 1..40),
+        ) {
+            tokio::runtime::Builder::new_current_thread()
+                .enable_all()
+                .build()
+                .unwrap()
+                .block_on(run_ops(deposit_fee_bps, withdrawal_fee_bps, ops));
+        }
+    }
+}
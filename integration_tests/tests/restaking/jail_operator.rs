@@ -0,0 +1,199 @@
+#[cfg(test)]
+mod tests {
+    use jito_restaking_core::{config::Config, ncn_operator_state::NcnOperatorState};
+    use jito_restaking_sdk::error::RestakingError;
+    use solana_sdk::signature::Keypair;
+
+    use crate::fixtures::{fixture::TestBuilder, restaking_client::assert_restaking_error};
+
+    #[tokio::test]
+    async fn test_jail_operator_ok() {
+        let mut fixture = TestBuilder::new().await;
+        let mut restaking_program_client = fixture.restaking_program_client();
+
+        let _config_admin = restaking_program_client
+            .do_initialize_config()
+            .await
+            .unwrap();
+        let ncn_root = restaking_program_client.do_initialize_ncn().await.unwrap();
+        let operator_root = restaking_program_client
+            .do_initialize_operator()
+            .await
+            .unwrap();
+
+        restaking_program_client
+            .do_initialize_ncn_operator_state(&ncn_root, &operator_root.operator_pubkey)
+            .await
+            .unwrap();
+        fixture.warp_slot_incremental(1).await.unwrap();
+        restaking_program_client
+            .do_ncn_warmup_operator(&ncn_root, &operator_root.operator_pubkey)
+            .await
+            .unwrap();
+        restaking_program_client
+            .do_operator_warmup_ncn(&operator_root, &ncn_root.ncn_pubkey)
+            .await
+            .unwrap();
+
+        let config = restaking_program_client
+            .get_config(&Config::find_program_address(&jito_restaking_program::id()).0)
+            .await
+            .unwrap();
+        fixture
+            .warp_slot_incremental(2 * config.epoch_length())
+            .await
+            .unwrap();
+
+        let ncn_operator_state = restaking_program_client
+            .get_ncn_operator_state(&ncn_root.ncn_pubkey, &operator_root.operator_pubkey)
+            .await
+            .unwrap();
+        let slot = fixture.get_current_slot().await.unwrap();
+        assert!(ncn_operator_state
+            .is_active(slot, config.epoch_length())
+            .unwrap());
+
+        restaking_program_client
+            .do_jail_operator(&ncn_root, &operator_root.operator_pubkey, 7)
+            .await
+            .unwrap();
+
+        let ncn_operator_state = restaking_program_client
+            .get_ncn_operator_state(&ncn_root.ncn_pubkey, &operator_root.operator_pubkey)
+            .await
+            .unwrap();
+        assert!(ncn_operator_state.is_jailed());
+        assert_eq!(ncn_operator_state.jail_reason(), 7);
+        assert_eq!(ncn_operator_state.jailed_slot(), slot);
+        assert!(!ncn_operator_state
+            .is_active(slot, config.epoch_length())
+            .unwrap());
+
+        let operator = restaking_program_client
+            .get_operator(&operator_root.operator_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(operator.jailed_ncn_count(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_jail_operator_wrong_admin_fails() {
+        let fixture = TestBuilder::new().await;
+        let mut restaking_program_client = fixture.restaking_program_client();
+
+        let _config_admin = restaking_program_client
+            .do_initialize_config()
+            .await
+            .unwrap();
+        let ncn_root = restaking_program_client.do_initialize_ncn().await.unwrap();
+        let operator_root = restaking_program_client
+            .do_initialize_operator()
+            .await
+            .unwrap();
+
+        restaking_program_client
+            .do_initialize_ncn_operator_state(&ncn_root, &operator_root.operator_pubkey)
+            .await
+            .unwrap();
+
+        let result = restaking_program_client
+            .jail_operator(
+                &Config::find_program_address(&jito_restaking_program::id()).0,
+                &ncn_root.ncn_pubkey,
+                &operator_root.operator_pubkey,
+                &NcnOperatorState::find_program_address(
+                    &jito_restaking_program::id(),
+                    &ncn_root.ncn_pubkey,
+                    &operator_root.operator_pubkey,
+                )
+                .0,
+                &Keypair::new(),
+                1,
+            )
+            .await;
+        assert_restaking_error(result, RestakingError::NcnOperatorAdminInvalid);
+    }
+
+    #[tokio::test]
+    async fn test_jail_operator_already_jailed_fails() {
+        let mut fixture = TestBuilder::new().await;
+        let mut restaking_program_client = fixture.restaking_program_client();
+
+        let _config_admin = restaking_program_client
+            .do_initialize_config()
+            .await
+            .unwrap();
+        let ncn_root = restaking_program_client.do_initialize_ncn().await.unwrap();
+        let operator_root = restaking_program_client
+            .do_initialize_operator()
+            .await
+            .unwrap();
+
+        restaking_program_client
+            .do_initialize_ncn_operator_state(&ncn_root, &operator_root.operator_pubkey)
+            .await
+            .unwrap();
+        restaking_program_client
+            .do_jail_operator(&ncn_root, &operator_root.operator_pubkey, 1)
+            .await
+            .unwrap();
+
+        // new blockhash
+        fixture.warp_slot_incremental(1).await.unwrap();
+
+        let result = restaking_program_client
+            .do_jail_operator(&ncn_root, &operator_root.operator_pubkey, 2)
+            .await;
+        assert_restaking_error(result, RestakingError::NcnOperatorAlreadyJailed);
+    }
+
+    #[tokio::test]
+    async fn test_unjail_operator_ok() {
+        let mut fixture = TestBuilder::new().await;
+        let mut restaking_program_client = fixture.restaking_program_client();
+
+        let _config_admin = restaking_program_client
+            .do_initialize_config()
+            .await
+            .unwrap();
+        let ncn_root = restaking_program_client.do_initialize_ncn().await.unwrap();
+        let operator_root = restaking_program_client
+            .do_initialize_operator()
+            .await
+            .unwrap();
+
+        restaking_program_client
+            .do_initialize_ncn_operator_state(&ncn_root, &operator_root.operator_pubkey)
+            .await
+            .unwrap();
+        restaking_program_client
+            .do_jail_operator(&ncn_root, &operator_root.operator_pubkey, 3)
+            .await
+            .unwrap();
+        restaking_program_client
+            .do_unjail_operator(&ncn_root, &operator_root.operator_pubkey)
+            .await
+            .unwrap();
+
+        let ncn_operator_state = restaking_program_client
+            .get_ncn_operator_state(&ncn_root.ncn_pubkey, &operator_root.operator_pubkey)
+            .await
+            .unwrap();
+        assert!(!ncn_operator_state.is_jailed());
+        assert_eq!(ncn_operator_state.jail_reason(), 3);
+
+        let operator = restaking_program_client
+            .get_operator(&operator_root.operator_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(operator.jailed_ncn_count(), 0);
+
+        // new blockhash
+        fixture.warp_slot_incremental(1).await.unwrap();
+
+        let result = restaking_program_client
+            .do_unjail_operator(&ncn_root, &operator_root.operator_pubkey)
+            .await;
+        assert_restaking_error(result, RestakingError::NcnOperatorNotJailed);
+    }
+}
@@ -0,0 +1,101 @@
+#[cfg(test)]
+mod tests {
+    use jito_jsm_core::slot_toggle::SlotToggleState;
+
+    use crate::fixtures::fixture::TestBuilder;
+
+    /// Connects an NCN, an operator and a vault through the high-level restaking client: the first
+    /// call initializes each ticket, the second warms it up and the third sends nothing
+    #[tokio::test]
+    async fn test_jsm_restaking_client_connect_is_idempotent() {
+        let mut fixture = TestBuilder::new().await;
+        let mut restaking_program_client = fixture.restaking_program_client();
+        let mut vault_program_client = fixture.vault_program_client();
+
+        let (_vault_config_admin, vault_root) = vault_program_client
+            .setup_config_and_vault(0, 0, 0)
+            .await
+            .unwrap();
+        restaking_program_client
+            .do_initialize_config()
+            .await
+            .unwrap();
+        let ncn_root = restaking_program_client.do_initialize_ncn().await.unwrap();
+        let operator_root = restaking_program_client
+            .do_initialize_operator()
+            .await
+            .unwrap();
+        let ncn = ncn_root.ncn_pubkey;
+        let operator = operator_root.operator_pubkey;
+        let vault = vault_root.vault_pubkey;
+        let restaking_client = fixture.jsm_restaking_client();
+
+        for _ in 0..2 {
+            assert!(restaking_client
+                .connect_ncn_vault(&ncn_root.ncn_admin, &ncn, &vault)
+                .await
+                .unwrap()
+                .is_some());
+            assert!(restaking_client
+                .connect_operator_vault(&operator_root.operator_admin, &operator, &vault)
+                .await
+                .unwrap()
+                .is_some());
+            assert!(restaking_client
+                .connect_ncn_operator(&ncn_root.ncn_admin, &ncn, &operator)
+                .await
+                .unwrap()
+                .is_some());
+            fixture.warp_slot_incremental(1).await.unwrap();
+        }
+
+        assert!(restaking_client
+            .connect_ncn_vault(&ncn_root.ncn_admin, &ncn, &vault)
+            .await
+            .unwrap()
+            .is_none());
+        assert!(restaking_client
+            .connect_operator_vault(&operator_root.operator_admin, &operator, &vault)
+            .await
+            .unwrap()
+            .is_none());
+        assert!(restaking_client
+            .connect_ncn_operator(&ncn_root.ncn_admin, &ncn, &operator)
+            .await
+            .unwrap()
+            .is_none());
+
+        let slot = fixture.get_current_slot().await.unwrap();
+        let epoch_length = restaking_client.get_config().await.unwrap().epoch_length();
+        let ncn_vault_ticket = restaking_client
+            .get_ncn_vault_ticket(&ncn, &vault)
+            .await
+            .unwrap();
+        assert_eq!(
+            ncn_vault_ticket.state.state(slot, epoch_length).unwrap(),
+            SlotToggleState::WarmUp
+        );
+        let operator_vault_ticket = restaking_client
+            .get_operator_vault_ticket(&operator, &vault)
+            .await
+            .unwrap();
+        assert_eq!(
+            operator_vault_ticket
+                .state
+                .state(slot, epoch_length)
+                .unwrap(),
+            SlotToggleState::WarmUp
+        );
+        let ncn_operator_state = restaking_client
+            .get_ncn_operator_state(&ncn, &operator)
+            .await
+            .unwrap();
+        assert_eq!(
+            ncn_operator_state
+                .ncn_opt_in_state
+                .state(slot, epoch_length)
+                .unwrap(),
+            SlotToggleState::WarmUp
+        );
+    }
+}
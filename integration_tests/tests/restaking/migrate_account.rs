@@ -0,0 +1,62 @@
+#[cfg(test)]
+mod tests {
+    use jito_restaking_core::config::Config;
+    use solana_program::instruction::InstructionError;
+    use solana_sdk::signature::Signer;
+
+    use crate::fixtures::{assert_ix_error, fixture::TestBuilder};
+
+    #[tokio::test]
+    async fn test_migrate_account_current_version_fails() {
+        let fixture = TestBuilder::new().await;
+        let mut restaking_program_client = fixture.restaking_program_client();
+
+        restaking_program_client
+            .do_initialize_config()
+            .await
+            .unwrap();
+        let config_pubkey = Config::find_program_address(&jito_restaking_program::id()).0;
+
+        let result = restaking_program_client
+            .migrate_account(&config_pubkey)
+            .await;
+        assert_ix_error(result, InstructionError::InvalidAccountData);
+    }
+
+    #[tokio::test]
+    async fn test_migrate_account_unknown_version_fails() {
+        let mut fixture = TestBuilder::new().await;
+        let mut restaking_program_client = fixture.restaking_program_client();
+
+        restaking_program_client
+            .do_initialize_config()
+            .await
+            .unwrap();
+        let ncn_root = restaking_program_client.do_initialize_ncn().await.unwrap();
+
+        fixture
+            .set_account_version(&ncn_root.ncn_pubkey, 1)
+            .await
+            .unwrap();
+
+        // Accounts with a version newer than the program knows can't be loaded or migrated
+        assert!(restaking_program_client
+            .get_ncn(&ncn_root.ncn_pubkey)
+            .await
+            .is_err());
+        let result = restaking_program_client
+            .migrate_account(&ncn_root.ncn_pubkey)
+            .await;
+        assert_ix_error(result, InstructionError::InvalidAccountData);
+
+        fixture
+            .set_account_version(&ncn_root.ncn_pubkey, 0)
+            .await
+            .unwrap();
+        let ncn = restaking_program_client
+            .get_ncn(&ncn_root.ncn_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(ncn.admin, ncn_root.ncn_admin.pubkey());
+    }
+}
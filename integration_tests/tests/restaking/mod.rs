@@ -5,6 +5,9 @@ mod initialize_ncn_vault_slasher_ticket;
 mod initialize_ncn_vault_ticket;
 mod initialize_operator;
 mod initialize_operator_vault_ticket;
+mod jail_operator;
+mod jsm_client;
+mod migrate_account;
 mod ncn_cooldown_operator;
 mod ncn_delegate_token_account;
 mod ncn_set_admin;
@@ -16,3 +19,4 @@ mod operator_set_fee;
 mod operator_set_secondary_admin;
 mod operator_warmup_ncn;
 mod set_config_admin;
+mod set_paused_categories;
@@ -1,9 +1,11 @@
 #[cfg(test)]
 mod tests {
+    use jito_jsm_core::diagnostic::Check;
     use jito_restaking_sdk::error::RestakingError;
     use solana_sdk::{signature::Keypair, signer::Signer};
 
     use crate::fixtures::{
+        assert_failed_check,
         fixture::TestBuilder,
         restaking_client::{assert_restaking_error, NcnRoot, RestakingProgramClient},
     };
@@ -37,6 +39,7 @@ mod tests {
             .ncn_set_admin(&ncn_root.ncn_pubkey, &bad_admin, &new_admin)
             .await;
 
+        assert_failed_check(&response, Check::Admin, &bad_admin.pubkey());
         assert_restaking_error(response, RestakingError::NcnAdminInvalid);
     }
 
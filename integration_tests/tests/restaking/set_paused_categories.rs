@@ -0,0 +1,206 @@
+#[cfg(test)]
+mod tests {
+    use jito_jsm_core::pause::PauseCategory;
+    use jito_restaking_core::config::Config;
+    use jito_restaking_sdk::error::RestakingError;
+    use solana_sdk::{instruction::InstructionError, signature::Keypair};
+
+    use crate::fixtures::{
+        assert_ix_error, fixture::TestBuilder, restaking_client::assert_restaking_error,
+    };
+
+    #[tokio::test]
+    async fn test_set_config_paused_categories_blocks_ticket_changes() {
+        let fixture = TestBuilder::new().await;
+        let mut restaking_program_client = fixture.restaking_program_client();
+
+        let config_admin = restaking_program_client
+            .do_initialize_config()
+            .await
+            .unwrap();
+        let ncn_root = restaking_program_client.do_initialize_ncn().await.unwrap();
+        let operator_root = restaking_program_client
+            .do_initialize_operator()
+            .await
+            .unwrap();
+
+        let config_pubkey = Config::find_program_address(&jito_restaking_program::id()).0;
+        restaking_program_client
+            .set_config_paused_categories(
+                &config_pubkey,
+                &config_admin,
+                PauseCategory::TicketChanges.mask(),
+            )
+            .await
+            .unwrap();
+
+        let config = restaking_program_client
+            .get_config(&config_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(
+            config.paused_categories(),
+            PauseCategory::TicketChanges.mask()
+        );
+
+        let response = restaking_program_client
+            .do_initialize_ncn_operator_state(&ncn_root, &operator_root.operator_pubkey)
+            .await;
+        assert_restaking_error(response, RestakingError::ConfigPaused);
+
+        restaking_program_client
+            .set_config_paused_categories(&config_pubkey, &config_admin, 0)
+            .await
+            .unwrap();
+        restaking_program_client
+            .do_initialize_ncn_operator_state(&ncn_root, &operator_root.operator_pubkey)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_set_config_paused_categories_with_bad_admin() {
+        let fixture = TestBuilder::new().await;
+        let mut restaking_program_client = fixture.restaking_program_client();
+
+        restaking_program_client
+            .do_initialize_config()
+            .await
+            .unwrap();
+
+        let bad_admin = Keypair::new();
+        let response = restaking_program_client
+            .set_config_paused_categories(
+                &Config::find_program_address(&jito_restaking_program::id()).0,
+                &bad_admin,
+                PauseCategory::ALL_MASK,
+            )
+            .await;
+        assert_restaking_error(response, RestakingError::ConfigAdminInvalid);
+    }
+
+    #[tokio::test]
+    async fn test_ncn_set_paused_categories_blocks_ticket_changes() {
+        let fixture = TestBuilder::new().await;
+        let mut restaking_program_client = fixture.restaking_program_client();
+
+        restaking_program_client
+            .do_initialize_config()
+            .await
+            .unwrap();
+        let ncn_root = restaking_program_client.do_initialize_ncn().await.unwrap();
+        let operator_root = restaking_program_client
+            .do_initialize_operator()
+            .await
+            .unwrap();
+
+        let config_pubkey = Config::find_program_address(&jito_restaking_program::id()).0;
+        restaking_program_client
+            .ncn_set_paused_categories(
+                &config_pubkey,
+                &ncn_root.ncn_pubkey,
+                &ncn_root.ncn_admin,
+                PauseCategory::TicketChanges.mask(),
+            )
+            .await
+            .unwrap();
+
+        let response = restaking_program_client
+            .do_initialize_ncn_operator_state(&ncn_root, &operator_root.operator_pubkey)
+            .await;
+        assert_restaking_error(response, RestakingError::NcnPaused);
+    }
+
+    #[tokio::test]
+    async fn test_ncn_set_paused_categories_with_bad_admin() {
+        let fixture = TestBuilder::new().await;
+        let mut restaking_program_client = fixture.restaking_program_client();
+
+        restaking_program_client
+            .do_initialize_config()
+            .await
+            .unwrap();
+        let ncn_root = restaking_program_client.do_initialize_ncn().await.unwrap();
+
+        let bad_admin = Keypair::new();
+        let response = restaking_program_client
+            .ncn_set_paused_categories(
+                &Config::find_program_address(&jito_restaking_program::id()).0,
+                &ncn_root.ncn_pubkey,
+                &bad_admin,
+                PauseCategory::ALL_MASK,
+            )
+            .await;
+        assert_restaking_error(response, RestakingError::NcnAdminInvalid);
+    }
+
+    #[tokio::test]
+    async fn test_operator_set_paused_categories_blocks_fee_changes() {
+        let fixture = TestBuilder::new().await;
+        let mut restaking_program_client = fixture.restaking_program_client();
+
+        restaking_program_client
+            .do_initialize_config()
+            .await
+            .unwrap();
+        let operator_root = restaking_program_client
+            .do_initialize_operator()
+            .await
+            .unwrap();
+
+        let config_pubkey = Config::find_program_address(&jito_restaking_program::id()).0;
+        restaking_program_client
+            .operator_set_paused_categories(
+                &config_pubkey,
+                &operator_root.operator_pubkey,
+                &operator_root.operator_admin,
+                PauseCategory::FeeChanges.mask(),
+            )
+            .await
+            .unwrap();
+
+        let operator = restaking_program_client
+            .get_operator(&operator_root.operator_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(
+            operator.paused_categories(),
+            PauseCategory::FeeChanges.mask()
+        );
+
+        let response = restaking_program_client
+            .operator_set_fee(
+                &config_pubkey,
+                &operator_root.operator_pubkey,
+                &operator_root.operator_admin,
+                100,
+            )
+            .await;
+        assert_restaking_error(response, RestakingError::OperatorPaused);
+    }
+
+    #[tokio::test]
+    async fn test_operator_set_paused_categories_with_unknown_category_fails() {
+        let fixture = TestBuilder::new().await;
+        let mut restaking_program_client = fixture.restaking_program_client();
+
+        restaking_program_client
+            .do_initialize_config()
+            .await
+            .unwrap();
+        let operator_root = restaking_program_client
+            .do_initialize_operator()
+            .await
+            .unwrap();
+
+        let response = restaking_program_client
+            .operator_set_paused_categories(
+                &Config::find_program_address(&jito_restaking_program::id()).0,
+                &operator_root.operator_pubkey,
+                &operator_root.operator_admin,
+                PauseCategory::ALL_MASK + 1,
+            )
+            .await;
+        assert_ix_error(response, InstructionError::InvalidInstructionData);
+    }
+}
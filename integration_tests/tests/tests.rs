@@ -1,4 +1,5 @@
 mod fixtures;
+mod fuzz;
 mod helpers;
 mod restaking;
 mod vault;
@@ -2,9 +2,13 @@
 mod tests {
     use jito_vault_core::vault::Vault;
     use jito_vault_sdk::error::VaultError;
-    use solana_sdk::signature::{Keypair, Signer};
+    use solana_sdk::{
+        instruction::InstructionError,
+        signature::{Keypair, Signer},
+    };
 
     use crate::fixtures::{
+        assert_ix_error,
         fixture::{ConfiguredVault, TestBuilder},
         vault_client::assert_vault_error,
     };
@@ -195,4 +199,102 @@ mod tests {
 
         assert_vault_error(result, VaultError::VaultIsPaused);
     }
+
+    #[tokio::test]
+    async fn test_add_delegation_operator_jailed_fails() {
+        const MINT_AMOUNT: u64 = 100_000;
+        const MIN_AMOUNT_OUT: u64 = 100_000;
+        let mut fixture = TestBuilder::new().await;
+        let mut vault_program_client = fixture.vault_program_client();
+        let mut restaking_program_client = fixture.restaking_program_client();
+
+        let (_vault_config_admin, vault_root) = vault_program_client
+            .setup_config_and_vault(0, 0, 0)
+            .await
+            .unwrap();
+        let _restaking_config_admin = restaking_program_client
+            .do_initialize_config()
+            .await
+            .unwrap();
+
+        // the vault is connected to two NCNs, the second one jails the operator
+        let operator_root = restaking_program_client
+            .do_initialize_operator()
+            .await
+            .unwrap();
+        let mut ncn_roots = Vec::with_capacity(2);
+        for _ in 0..2 {
+            let ncn_root = restaking_program_client.do_initialize_ncn().await.unwrap();
+            restaking_program_client
+                .do_initialize_ncn_vault_ticket(&ncn_root, &vault_root.vault_pubkey)
+                .await
+                .unwrap();
+            vault_program_client
+                .do_initialize_vault_ncn_ticket(&vault_root, &ncn_root.ncn_pubkey)
+                .await
+                .unwrap();
+            restaking_program_client
+                .do_initialize_ncn_operator_state(&ncn_root, &operator_root.operator_pubkey)
+                .await
+                .unwrap();
+            ncn_roots.push(ncn_root);
+        }
+        restaking_program_client
+            .do_initialize_operator_vault_ticket(&operator_root, &vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        fixture.warp_slot_incremental(1).await.unwrap();
+        restaking_program_client
+            .do_warmup_operator_vault_ticket(&operator_root, &vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_initialize_vault_operator_delegation(&vault_root, &operator_root.operator_pubkey)
+            .await
+            .unwrap();
+
+        let depositor = Keypair::new();
+        vault_program_client
+            .configure_depositor(&vault_root, &depositor.pubkey(), MINT_AMOUNT)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_mint_to(&vault_root, &depositor, MINT_AMOUNT, MIN_AMOUNT_OUT)
+            .await
+            .unwrap();
+
+        restaking_program_client
+            .do_jail_operator(&ncn_roots[1], &operator_root.operator_pubkey, 1)
+            .await
+            .unwrap();
+
+        let ncns: Vec<_> = ncn_roots
+            .iter()
+            .map(|ncn_root| ncn_root.ncn_pubkey)
+            .collect();
+
+        // the NCN accounts are required once the operator is jailed
+        let result = vault_program_client
+            .do_add_delegation(&vault_root, &operator_root.operator_pubkey, 50_000)
+            .await;
+        assert_ix_error(result, InstructionError::NotEnoughAccountKeys);
+
+        let result = vault_program_client
+            .do_add_delegation_with_ncns(&vault_root, &operator_root.operator_pubkey, &ncns, 50_000)
+            .await;
+        assert_vault_error(result, VaultError::OperatorJailed);
+
+        restaking_program_client
+            .do_unjail_operator(&ncn_roots[1], &operator_root.operator_pubkey)
+            .await
+            .unwrap();
+
+        // new blockhash
+        fixture.warp_slot_incremental(1).await.unwrap();
+
+        vault_program_client
+            .do_add_delegation(&vault_root, &operator_root.operator_pubkey, 50_000)
+            .await
+            .unwrap();
+    }
 }
@@ -0,0 +1,362 @@
+#[cfg(test)]
+mod tests {
+    use jito_jsm_core::{diagnostic::Check, pause::PauseCategory};
+    use jito_vault_core::{
+        config::Config, vault_staker_withdrawal_ticket::VaultStakerWithdrawalTicket,
+    };
+    use jito_vault_sdk::error::VaultError;
+    use solana_program::pubkey::Pubkey;
+    use solana_sdk::{instruction::InstructionError, signature::Keypair, signer::Signer};
+    use spl_associated_token_account::get_associated_token_address;
+
+    use crate::fixtures::{
+        assert_failed_check, assert_ix_error,
+        fixture::{ConfiguredVault, TestBuilder},
+        vault_client::{assert_vault_error, VaultProgramClient, VaultRoot},
+    };
+
+    const MINT_AMOUNT: u64 = 100_000;
+
+    struct WithdrawableTicket {
+        vault_program_client: VaultProgramClient,
+        vault_root: VaultRoot,
+        config: Config,
+        depositor: Keypair,
+        base: Pubkey,
+        vault_staker_withdrawal_ticket: Pubkey,
+        auto_claim_delegate: Keypair,
+        fixture: TestBuilder,
+    }
+
+    /// Enqueues a withdrawal for all of a depositor's VRT and warps until the ticket can be burned
+    async fn setup_withdrawable_ticket() -> WithdrawableTicket {
+        let mut fixture = TestBuilder::new().await;
+        let ConfiguredVault {
+            mut vault_program_client,
+            restaking_program_client: _,
+            vault_config_admin: _,
+            vault_root,
+            restaking_config_admin: _,
+            operator_roots,
+        } = fixture
+            .setup_vault_with_ncn_and_operators(0, 0, 0, 1, &[])
+            .await
+            .unwrap();
+
+        let depositor = Keypair::new();
+        vault_program_client
+            .configure_depositor(&vault_root, &depositor.pubkey(), MINT_AMOUNT)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_mint_to(&vault_root, &depositor, MINT_AMOUNT, MINT_AMOUNT)
+            .await
+            .unwrap();
+
+        let config = vault_program_client
+            .get_config(&Config::find_program_address(&jito_vault_program::id()).0)
+            .await
+            .unwrap();
+
+        let base = vault_program_client
+            .do_enqueue_withdrawal(&vault_root, &depositor, MINT_AMOUNT)
+            .await
+            .unwrap()
+            .base;
+        let vault_staker_withdrawal_ticket = VaultStakerWithdrawalTicket::find_program_address(
+            &jito_vault_program::id(),
+            &vault_root.vault_pubkey,
+            &base,
+        )
+        .0;
+
+        for _ in 0..2 {
+            fixture
+                .warp_slot_incremental(config.epoch_length())
+                .await
+                .unwrap();
+            vault_program_client
+                .do_full_vault_update(
+                    &vault_root.vault_pubkey,
+                    &[operator_roots[0].operator_pubkey],
+                )
+                .await
+                .unwrap();
+        }
+
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        let auto_claim_delegate = Keypair::new();
+        vault_program_client
+            .create_ata(&vault.supported_mint, &auto_claim_delegate.pubkey())
+            .await
+            .unwrap();
+
+        WithdrawableTicket {
+            vault_program_client,
+            vault_root,
+            config,
+            depositor,
+            base,
+            vault_staker_withdrawal_ticket,
+            auto_claim_delegate,
+            fixture,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_auto_claim_withdrawal_ticket_pays_tip_ok() {
+        let WithdrawableTicket {
+            mut vault_program_client,
+            vault_root,
+            config,
+            depositor,
+            base,
+            vault_staker_withdrawal_ticket,
+            auto_claim_delegate,
+            mut fixture,
+        } = setup_withdrawable_ticket().await;
+
+        vault_program_client
+            .set_auto_claim_delegate(
+                &Config::find_program_address(&jito_vault_program::id()).0,
+                &vault_root.vault_pubkey,
+                &vault_staker_withdrawal_ticket,
+                &depositor,
+                &auto_claim_delegate.pubkey(),
+                50,
+            )
+            .await
+            .unwrap();
+
+        let ticket = vault_program_client
+            .get_vault_staker_withdrawal_ticket(
+                &vault_root.vault_pubkey,
+                &depositor.pubkey(),
+                &base,
+            )
+            .await
+            .unwrap();
+        assert_eq!(ticket.auto_claim_delegate, auto_claim_delegate.pubkey());
+        assert_eq!(ticket.auto_claim_max_tip_bps(), 50);
+
+        vault_program_client
+            .do_auto_claim_withdrawal_ticket(
+                &vault_root,
+                &depositor.pubkey(),
+                &base,
+                &config.program_fee_wallet,
+                &auto_claim_delegate,
+                50,
+            )
+            .await
+            .unwrap();
+
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        let delegate_token_account = fixture
+            .get_token_account(&get_associated_token_address(
+                &auto_claim_delegate.pubkey(),
+                &vault.supported_mint,
+            ))
+            .await
+            .unwrap();
+        assert_eq!(delegate_token_account.amount, 500);
+        let depositor_token_account = fixture
+            .get_token_account(&get_associated_token_address(
+                &depositor.pubkey(),
+                &vault.supported_mint,
+            ))
+            .await
+            .unwrap();
+        assert_eq!(depositor_token_account.amount, MINT_AMOUNT - 500);
+    }
+
+    #[tokio::test]
+    async fn test_auto_claim_withdrawal_ticket_tip_too_high_fails() {
+        let WithdrawableTicket {
+            mut vault_program_client,
+            vault_root,
+            config,
+            depositor,
+            base,
+            vault_staker_withdrawal_ticket,
+            auto_claim_delegate,
+            fixture: _,
+        } = setup_withdrawable_ticket().await;
+
+        vault_program_client
+            .set_auto_claim_delegate(
+                &Config::find_program_address(&jito_vault_program::id()).0,
+                &vault_root.vault_pubkey,
+                &vault_staker_withdrawal_ticket,
+                &depositor,
+                &auto_claim_delegate.pubkey(),
+                50,
+            )
+            .await
+            .unwrap();
+
+        let result = vault_program_client
+            .do_auto_claim_withdrawal_ticket(
+                &vault_root,
+                &depositor.pubkey(),
+                &base,
+                &config.program_fee_wallet,
+                &auto_claim_delegate,
+                51,
+            )
+            .await;
+        assert_vault_error(
+            result,
+            VaultError::VaultStakerWithdrawalTicketAutoClaimTipTooHigh,
+        );
+    }
+
+    #[tokio::test]
+    async fn test_change_withdrawal_ticket_owner_clears_auto_claim_delegate() {
+        let WithdrawableTicket {
+            mut vault_program_client,
+            vault_root,
+            config,
+            depositor,
+            base,
+            vault_staker_withdrawal_ticket,
+            auto_claim_delegate,
+            fixture: _,
+        } = setup_withdrawable_ticket().await;
+
+        let config_address = Config::find_program_address(&jito_vault_program::id()).0;
+        vault_program_client
+            .set_auto_claim_delegate(
+                &config_address,
+                &vault_root.vault_pubkey,
+                &vault_staker_withdrawal_ticket,
+                &depositor,
+                &auto_claim_delegate.pubkey(),
+                50,
+            )
+            .await
+            .unwrap();
+
+        let new_owner = Keypair::new();
+        vault_program_client
+            .do_change_withdrawal_ticket_owner(
+                &config_address,
+                &vault_root.vault_pubkey,
+                &vault_staker_withdrawal_ticket,
+                &depositor,
+                &new_owner.pubkey(),
+            )
+            .await
+            .unwrap();
+
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        vault_program_client
+            .create_ata(&vault.supported_mint, &new_owner.pubkey())
+            .await
+            .unwrap();
+
+        let result = vault_program_client
+            .do_auto_claim_withdrawal_ticket(
+                &vault_root,
+                &new_owner.pubkey(),
+                &base,
+                &config.program_fee_wallet,
+                &auto_claim_delegate,
+                0,
+            )
+            .await;
+        assert_vault_error(
+            result,
+            VaultError::VaultStakerWithdrawalTicketInvalidAutoClaimDelegate,
+        );
+    }
+
+    /// A ticket must be passed with its own vault, or an unpaused vault could stand in for a paused
+    /// one
+    #[tokio::test]
+    async fn test_ticket_changes_with_other_vault_fail() {
+        let WithdrawableTicket {
+            mut vault_program_client,
+            vault_root,
+            config,
+            depositor,
+            base,
+            vault_staker_withdrawal_ticket,
+            auto_claim_delegate,
+            fixture: _,
+        } = setup_withdrawable_ticket().await;
+
+        vault_program_client
+            .set_paused_categories(
+                &vault_root.vault_pubkey,
+                &vault_root.vault_admin,
+                PauseCategory::Withdrawals.mask(),
+            )
+            .await
+            .unwrap();
+        let other_vault = vault_program_client
+            .do_initialize_vault(0, 0, 0, 9, &config.program_fee_wallet)
+            .await
+            .unwrap()
+            .vault_pubkey;
+
+        let config_address = Config::find_program_address(&jito_vault_program::id()).0;
+        let result = vault_program_client
+            .set_auto_claim_delegate(
+                &config_address,
+                &other_vault,
+                &vault_staker_withdrawal_ticket,
+                &depositor,
+                &auto_claim_delegate.pubkey(),
+                50,
+            )
+            .await;
+        assert_failed_check(&result, Check::Vault, &other_vault);
+        assert_vault_error(result, VaultError::VaultStakerWithdrawalTicketInvalidVault);
+
+        let result = vault_program_client
+            .do_change_withdrawal_ticket_owner(
+                &config_address,
+                &other_vault,
+                &vault_staker_withdrawal_ticket,
+                &depositor,
+                &Pubkey::new_unique(),
+            )
+            .await;
+        assert_failed_check(&result, Check::Vault, &other_vault);
+        assert_vault_error(result, VaultError::VaultStakerWithdrawalTicketInvalidVault);
+
+        let ticket = vault_program_client
+            .get_vault_staker_withdrawal_ticket(
+                &vault_root.vault_pubkey,
+                &depositor.pubkey(),
+                &base,
+            )
+            .await
+            .unwrap();
+        assert_eq!(ticket.staker, depositor.pubkey());
+        assert!(!ticket.has_auto_claim_delegate());
+    }
+
+    #[tokio::test]
+    async fn test_auto_claim_withdrawal_ticket_not_enough_accounts() {
+        let fixture = TestBuilder::new().await;
+        let mut vault_program_client = fixture.vault_program_client();
+
+        for num_accounts in [0, 11, 13] {
+            let result = vault_program_client
+                .auto_claim_withdrawal_ticket_with_num_accounts(num_accounts)
+                .await;
+            assert_ix_error(result, InstructionError::NotEnoughAccountKeys);
+        }
+    }
+}
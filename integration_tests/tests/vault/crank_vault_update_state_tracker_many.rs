@@ -0,0 +1,264 @@
+#[cfg(test)]
+mod tests {
+    use jito_vault_core::{
+        config::Config, delegation_state::DelegationState,
+        vault_update_state_tracker::VaultUpdateStateTracker,
+    };
+    use jito_vault_sdk::{
+        compute_units::crank_vault_update_state_tracker_many_compute_units, error::VaultError,
+    };
+    use solana_sdk::{
+        pubkey::Pubkey,
+        signature::{Keypair, Signer},
+    };
+
+    use crate::fixtures::{
+        fixture::{ConfiguredVault, TestBuilder},
+        vault_client::{assert_vault_error, VaultProgramClient, VaultRoot},
+    };
+
+    const DELEGATION_PER_OPERATOR: u64 = 10_000;
+
+    struct CrankSetup {
+        fixture: TestBuilder,
+        vault_program_client: VaultProgramClient,
+        vault_root: VaultRoot,
+        /// Operators in the order they must be cranked this epoch
+        operators: Vec<Pubkey>,
+        vault_update_state_tracker: Pubkey,
+        ncn_epoch: u64,
+    }
+
+    /// Creates a vault with `num_operators` operators that each have
+    /// [`DELEGATION_PER_OPERATOR`] staked, advances to the next epoch and initializes the
+    /// update state tracker
+    async fn setup(num_operators: u16) -> CrankSetup {
+        let mut fixture = TestBuilder::new().await;
+
+        let ConfiguredVault {
+            mut vault_program_client,
+            vault_root,
+            operator_roots,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(0, 0, 0, num_operators, &[])
+            .await
+            .unwrap();
+
+        let total_amount = DELEGATION_PER_OPERATOR * num_operators as u64;
+        let depositor = Keypair::new();
+        vault_program_client
+            .configure_depositor(&vault_root, &depositor.pubkey(), total_amount)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_mint_to(&vault_root, &depositor, total_amount, total_amount)
+            .await
+            .unwrap();
+
+        for operator_root in operator_roots.iter() {
+            vault_program_client
+                .do_add_delegation(
+                    &vault_root,
+                    &operator_root.operator_pubkey,
+                    DELEGATION_PER_OPERATOR,
+                )
+                .await
+                .unwrap();
+        }
+
+        let config = vault_program_client
+            .get_config(&Config::find_program_address(&jito_vault_program::id()).0)
+            .await
+            .unwrap();
+        fixture
+            .warp_slot_incremental(config.epoch_length())
+            .await
+            .unwrap();
+
+        let slot = fixture.get_current_slot().await.unwrap();
+        let ncn_epoch = slot / config.epoch_length();
+
+        let vault_update_state_tracker = VaultUpdateStateTracker::find_program_address(
+            &jito_vault_program::id(),
+            &vault_root.vault_pubkey,
+            ncn_epoch,
+        )
+        .0;
+        vault_program_client
+            .initialize_vault_update_state_tracker(
+                &vault_root.vault_pubkey,
+                &vault_update_state_tracker,
+            )
+            .await
+            .unwrap();
+
+        let start_index = (ncn_epoch % num_operators as u64) as usize;
+        let operators = (0..operator_roots.len())
+            .map(|i| operator_roots[(start_index + i) % operator_roots.len()].operator_pubkey)
+            .collect();
+
+        CrankSetup {
+            fixture,
+            vault_program_client,
+            vault_root,
+            operators,
+            vault_update_state_tracker,
+            ncn_epoch,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_crank_vault_update_state_tracker_many_ok() {
+        let CrankSetup {
+            mut fixture,
+            mut vault_program_client,
+            vault_root,
+            operators,
+            vault_update_state_tracker,
+            ncn_epoch,
+        } = setup(4).await;
+
+        vault_program_client
+            .crank_vault_update_state_tracker_many(
+                &vault_root.vault_pubkey,
+                &vault_update_state_tracker,
+                &operators,
+            )
+            .await
+            .unwrap();
+
+        let tracker = vault_program_client
+            .get_vault_update_state_tracker(&vault_root.vault_pubkey, ncn_epoch)
+            .await
+            .unwrap();
+        assert!(tracker.all_operators_updated(4).unwrap());
+        assert_eq!(
+            tracker.delegation_state,
+            DelegationState::new(DELEGATION_PER_OPERATOR * 4, 0, 0)
+        );
+
+        let slot = fixture.get_current_slot().await.unwrap();
+        for operator in operators.iter() {
+            let vault_operator_delegation = vault_program_client
+                .get_vault_operator_delegation(&vault_root.vault_pubkey, operator)
+                .await
+                .unwrap();
+            assert_eq!(vault_operator_delegation.last_update_slot(), slot);
+        }
+
+        vault_program_client
+            .close_vault_update_state_tracker(
+                &vault_root.vault_pubkey,
+                &vault_update_state_tracker,
+                ncn_epoch,
+            )
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_crank_vault_update_state_tracker_many_in_batches_ok() {
+        let CrankSetup {
+            fixture: _fixture,
+            mut vault_program_client,
+            vault_root,
+            operators,
+            vault_update_state_tracker,
+            ncn_epoch,
+        } = setup(4).await;
+
+        for batch in operators.chunks(2) {
+            vault_program_client
+                .crank_vault_update_state_tracker_many(
+                    &vault_root.vault_pubkey,
+                    &vault_update_state_tracker,
+                    batch,
+                )
+                .await
+                .unwrap();
+        }
+
+        let tracker = vault_program_client
+            .get_vault_update_state_tracker(&vault_root.vault_pubkey, ncn_epoch)
+            .await
+            .unwrap();
+        assert!(tracker.all_operators_updated(4).unwrap());
+        assert_eq!(
+            tracker.delegation_state,
+            DelegationState::new(DELEGATION_PER_OPERATOR * 4, 0, 0)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_crank_vault_update_state_tracker_many_wrong_order_fails() {
+        let CrankSetup {
+            fixture: _fixture,
+            mut vault_program_client,
+            vault_root,
+            mut operators,
+            vault_update_state_tracker,
+            ..
+        } = setup(3).await;
+
+        operators.swap(1, 2);
+
+        let result = vault_program_client
+            .crank_vault_update_state_tracker_many(
+                &vault_root.vault_pubkey,
+                &vault_update_state_tracker,
+                &operators,
+            )
+            .await;
+        assert_vault_error(result, VaultError::VaultUpdateIncorrectIndex);
+    }
+
+    #[tokio::test]
+    async fn test_crank_vault_update_state_tracker_many_duplicate_operator_fails() {
+        let CrankSetup {
+            fixture: _fixture,
+            mut vault_program_client,
+            vault_root,
+            operators,
+            vault_update_state_tracker,
+            ..
+        } = setup(2).await;
+
+        let result = vault_program_client
+            .crank_vault_update_state_tracker_many(
+                &vault_root.vault_pubkey,
+                &vault_update_state_tracker,
+                &[operators[0], operators[0]],
+            )
+            .await;
+        assert_vault_error(result, VaultError::VaultOperatorDelegationIsUpdated);
+    }
+
+    #[tokio::test]
+    async fn test_crank_vault_update_state_tracker_many_within_compute_budget() {
+        let CrankSetup {
+            fixture: _fixture,
+            mut vault_program_client,
+            vault_root,
+            operators,
+            vault_update_state_tracker,
+            ..
+        } = setup(4).await;
+
+        for num_operators in 1..=operators.len() {
+            let units_consumed = vault_program_client
+                .simulate_crank_vault_update_state_tracker_many(
+                    &vault_root.vault_pubkey,
+                    &vault_update_state_tracker,
+                    &operators[..num_operators],
+                )
+                .await
+                .unwrap();
+            assert!(
+                units_consumed
+                    <= crank_vault_update_state_tracker_many_compute_units(num_operators as u32)
+                        as u64
+            );
+        }
+    }
+}
@@ -0,0 +1,119 @@
+#[cfg(test)]
+mod tests {
+    use jito_vault_core::{config::Config, vault::Vault};
+    use solana_sdk::{signature::Keypair, signer::Signer};
+    use spl_associated_token_account::get_associated_token_address;
+
+    use crate::fixtures::fixture::{ConfiguredVault, TestBuilder};
+
+    /// Deposits, delegates, withdraws and claims through the high-level vault client
+    #[tokio::test]
+    async fn test_jsm_vault_client_deposit_withdraw_claim() {
+        const MINT_AMOUNT: u64 = 100_000;
+
+        let mut fixture = TestBuilder::new().await;
+        let ConfiguredVault {
+            mut vault_program_client,
+            restaking_program_client: _,
+            vault_config_admin: _,
+            vault_root,
+            restaking_config_admin: _,
+            operator_roots,
+        } = fixture
+            .setup_vault_with_ncn_and_operators(0, 0, 0, 1, &[])
+            .await
+            .unwrap();
+        let operator = operator_roots[0].operator_pubkey;
+        let vault_client = fixture.jsm_vault_client();
+
+        let depositor = Keypair::new();
+        vault_program_client
+            .configure_depositor(&vault_root, &depositor.pubkey(), MINT_AMOUNT)
+            .await
+            .unwrap();
+        vault_client
+            .deposit(
+                &depositor,
+                &vault_root.vault_pubkey,
+                MINT_AMOUNT,
+                MINT_AMOUNT,
+            )
+            .await
+            .unwrap();
+
+        let config = vault_program_client
+            .get_config(&Config::find_program_address(&jito_vault_program::id()).0)
+            .await
+            .unwrap();
+        fixture
+            .warp_slot_incremental(2 * config.epoch_length())
+            .await
+            .unwrap();
+        vault_program_client
+            .do_full_vault_update(&vault_root.vault_pubkey, &[operator])
+            .await
+            .unwrap();
+
+        vault_client
+            .delegate(
+                &vault_root.vault_admin,
+                &vault_root.vault_pubkey,
+                &operator,
+                MINT_AMOUNT,
+            )
+            .await
+            .unwrap();
+        let delegation = vault_client
+            .get_vault_operator_delegation(&vault_root.vault_pubkey, &operator)
+            .await
+            .unwrap();
+        assert_eq!(delegation.delegation_state.staked_amount(), MINT_AMOUNT);
+
+        let (ticket, _) = vault_client
+            .request_withdrawal(&depositor, &vault_root.vault_pubkey, MINT_AMOUNT)
+            .await
+            .unwrap();
+        let ticket_account = vault_client.get_withdrawal_ticket(&ticket).await.unwrap();
+        assert_eq!(ticket_account.staker, depositor.pubkey());
+        assert_eq!(ticket_account.vrt_amount(), MINT_AMOUNT);
+
+        vault_client
+            .undelegate(
+                &vault_root.vault_admin,
+                &vault_root.vault_pubkey,
+                &operator,
+                MINT_AMOUNT,
+            )
+            .await
+            .unwrap();
+        for _ in 0..2 {
+            fixture
+                .warp_slot_incremental(config.epoch_length())
+                .await
+                .unwrap();
+            vault_program_client
+                .do_full_vault_update(&vault_root.vault_pubkey, &[operator])
+                .await
+                .unwrap();
+        }
+
+        vault_client.claim(&depositor, &ticket).await.unwrap();
+
+        let vault: Vault = vault_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(
+            vault.vrt_supply() - Vault::DEFAULT_INITIALIZATION_TOKEN_AMOUNT,
+            0
+        );
+        let depositor_token_account = fixture
+            .get_token_account(&get_associated_token_address(
+                &depositor.pubkey(),
+                &vault.supported_mint,
+            ))
+            .await
+            .unwrap();
+        assert_eq!(depositor_token_account.amount, MINT_AMOUNT);
+    }
+}
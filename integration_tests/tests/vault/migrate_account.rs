@@ -0,0 +1,68 @@
+#[cfg(test)]
+mod tests {
+    use jito_vault_core::config::Config;
+    use solana_program::{instruction::InstructionError, pubkey::Pubkey};
+    use solana_sdk::signature::Signer;
+
+    use crate::fixtures::{assert_ix_error, fixture::TestBuilder};
+
+    #[tokio::test]
+    async fn test_migrate_account_current_version_fails() {
+        let fixture = TestBuilder::new().await;
+        let mut vault_program_client = fixture.vault_program_client();
+
+        vault_program_client.do_initialize_config().await.unwrap();
+        let config_pubkey = Config::find_program_address(&jito_vault_program::id()).0;
+
+        let result = vault_program_client.migrate_account(&config_pubkey).await;
+        assert_ix_error(result, InstructionError::InvalidAccountData);
+    }
+
+    #[tokio::test]
+    async fn test_migrate_account_unknown_version_fails() {
+        let mut fixture = TestBuilder::new().await;
+        let mut vault_program_client = fixture.vault_program_client();
+
+        let config_admin = vault_program_client.do_initialize_config().await.unwrap();
+        let vault_root = vault_program_client
+            .do_initialize_vault(0, 0, 0, 9, &config_admin.pubkey())
+            .await
+            .unwrap();
+
+        fixture
+            .set_account_version(&vault_root.vault_pubkey, 1)
+            .await
+            .unwrap();
+
+        // Accounts with a version newer than the program knows can't be loaded or migrated
+        assert!(vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .is_err());
+        let result = vault_program_client
+            .migrate_account(&vault_root.vault_pubkey)
+            .await;
+        assert_ix_error(result, InstructionError::InvalidAccountData);
+
+        fixture
+            .set_account_version(&vault_root.vault_pubkey, 0)
+            .await
+            .unwrap();
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(vault.admin, vault_root.vault_admin.pubkey());
+    }
+
+    #[tokio::test]
+    async fn test_migrate_account_not_program_owned_fails() {
+        let fixture = TestBuilder::new().await;
+        let mut vault_program_client = fixture.vault_program_client();
+
+        let result = vault_program_client
+            .migrate_account(&Pubkey::new_unique())
+            .await;
+        assert_ix_error(result, InstructionError::InvalidAccountOwner);
+    }
+}
@@ -1,10 +1,12 @@
 mod add_delegation;
+mod auto_claim_withdrawal_ticket;
 mod burn_withdrawal_ticket;
 mod close_update_state_tracker;
 mod close_vault_update_state_tracker;
 mod cooldown_delegation;
 mod cooldown_vault_ncn_ticket;
 mod crank_vault_update_state_tracker;
+mod crank_vault_update_state_tracker_many;
 mod create_token_metadata;
 mod delegate_token_account;
 mod enqueue_withdrawal;
@@ -14,12 +16,17 @@ mod initialize_vault_ncn_slasher_ticket;
 mod initialize_vault_ncn_ticket;
 mod initialize_vault_operator_delegation;
 mod initialize_vault_update_state_tracker;
+mod jsm_client;
+mod migrate_account;
 mod reward_fee;
 mod set_admin;
 mod set_capacity;
+mod set_child_vault;
 mod set_config_admin;
 mod set_fees;
+mod set_is_emergency_exit;
 mod set_is_paused;
+mod set_paused_categories;
 mod set_program_fee_wallet;
 mod set_secondary_admin;
 mod update_token_metadata;
@@ -0,0 +1,329 @@
+#[cfg(test)]
+mod tests {
+    use jito_jsm_core::diagnostic::Check;
+    use jito_vault_core::vault::Vault;
+    use jito_vault_sdk::error::VaultError;
+    use solana_sdk::{
+        instruction::InstructionError,
+        signature::{Keypair, Signer},
+    };
+    use spl_associated_token_account::get_associated_token_address;
+
+    use crate::fixtures::{
+        assert_failed_check, assert_ix_error,
+        fixture::TestBuilder,
+        vault_client::{assert_vault_error, VaultRoot},
+    };
+
+    #[tokio::test]
+    async fn test_set_child_vault_ok() {
+        let fixture = TestBuilder::new().await;
+        let mut vault_program_client = fixture.vault_program_client();
+
+        let config_admin = vault_program_client.do_initialize_config().await.unwrap();
+        let vault_roots = vault_program_client
+            .do_initialize_vault_chain(3, &config_admin.pubkey())
+            .await
+            .unwrap();
+
+        for (depth, pair) in (1..).zip(vault_roots.windows(2)) {
+            let [child_vault_root, vault_root] = pair else {
+                unreachable!()
+            };
+            vault_program_client
+                .set_child_vault(
+                    &vault_root.vault_pubkey,
+                    &child_vault_root.vault_pubkey,
+                    &vault_root.vault_admin,
+                )
+                .await
+                .unwrap();
+
+            let vault = vault_program_client
+                .get_vault(&vault_root.vault_pubkey)
+                .await
+                .unwrap();
+            assert!(vault.is_nested());
+            assert_eq!(vault.child_vault, child_vault_root.vault_pubkey);
+            assert_eq!(vault.nesting_depth(), depth);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_set_child_vault_too_deep_fails() {
+        let fixture = TestBuilder::new().await;
+        let mut vault_program_client = fixture.vault_program_client();
+
+        let config_admin = vault_program_client.do_initialize_config().await.unwrap();
+        let len = Vault::MAX_NESTING_DEPTH as usize + 2;
+        let vault_roots = vault_program_client
+            .do_initialize_vault_chain(len, &config_admin.pubkey())
+            .await
+            .unwrap();
+
+        for pair in vault_roots[..len - 1].windows(2) {
+            let [child_vault_root, vault_root] = pair else {
+                unreachable!()
+            };
+            vault_program_client
+                .set_child_vault(
+                    &vault_root.vault_pubkey,
+                    &child_vault_root.vault_pubkey,
+                    &vault_root.vault_admin,
+                )
+                .await
+                .unwrap();
+        }
+        let child_vault = vault_program_client
+            .get_vault(&vault_roots[len - 2].vault_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(child_vault.nesting_depth(), Vault::MAX_NESTING_DEPTH);
+
+        let response = vault_program_client
+            .set_child_vault(
+                &vault_roots[len - 1].vault_pubkey,
+                &vault_roots[len - 2].vault_pubkey,
+                &vault_roots[len - 1].vault_admin,
+            )
+            .await;
+
+        assert_vault_error(response, VaultError::VaultNestingTooDeep);
+        let vault = vault_program_client
+            .get_vault(&vault_roots[len - 1].vault_pubkey)
+            .await
+            .unwrap();
+        assert!(!vault.is_nested());
+    }
+
+    #[tokio::test]
+    async fn test_update_vault_balance_nested_vault_ok() {
+        let mut fixture = TestBuilder::new().await;
+        let mut vault_program_client = fixture.vault_program_client();
+
+        let config_admin = vault_program_client.do_initialize_config().await.unwrap();
+        let vault_roots = vault_program_client
+            .do_initialize_vault_chain(2, &config_admin.pubkey())
+            .await
+            .unwrap();
+        let child_vault_pubkey = vault_roots[0].vault_pubkey;
+        let vault_pubkey = vault_roots[1].vault_pubkey;
+        vault_program_client
+            .set_child_vault(
+                &vault_pubkey,
+                &child_vault_pubkey,
+                &vault_roots[1].vault_admin,
+            )
+            .await
+            .unwrap();
+
+        vault_program_client
+            .update_vault_balance(&vault_pubkey)
+            .await
+            .unwrap();
+
+        // The vault holds half of the child's VRT, which is backed 1:1
+        let child_vault = vault_program_client
+            .get_vault(&child_vault_pubkey)
+            .await
+            .unwrap();
+        let vault = vault_program_client.get_vault(&vault_pubkey).await.unwrap();
+        assert_eq!(
+            vault.tokens_deposited(),
+            Vault::DEFAULT_INITIALIZATION_TOKEN_AMOUNT
+        );
+        assert_eq!(child_vault.vrt_supply(), 2 * vault.tokens_deposited());
+        assert_eq!(
+            vault.nested_underlying_value(),
+            Vault::DEFAULT_INITIALIZATION_TOKEN_AMOUNT
+        );
+        assert_eq!(vault.underlying_value(), vault.nested_underlying_value());
+
+        // The child loses half of its tokens
+        let child_token_account =
+            get_associated_token_address(&child_vault_pubkey, &child_vault.supported_mint);
+        fixture
+            .set_token_account_amount(&child_token_account, child_vault.tokens_deposited() / 2)
+            .await
+            .unwrap();
+        vault_program_client
+            .update_vault_balance(&child_vault_pubkey)
+            .await
+            .unwrap();
+        vault_program_client
+            .update_vault_balance(&vault_pubkey)
+            .await
+            .unwrap();
+
+        let child_vault = vault_program_client
+            .get_vault(&child_vault_pubkey)
+            .await
+            .unwrap();
+        let vault = vault_program_client.get_vault(&vault_pubkey).await.unwrap();
+        assert_eq!(
+            child_vault.tokens_deposited(),
+            Vault::DEFAULT_INITIALIZATION_TOKEN_AMOUNT
+        );
+        assert_eq!(
+            vault.nested_underlying_value(),
+            Vault::DEFAULT_INITIALIZATION_TOKEN_AMOUNT / 2
+        );
+        assert_eq!(
+            vault.nested_underlying_value(),
+            child_vault
+                .calculate_underlying_value(vault.tokens_deposited())
+                .unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_update_vault_balance_nested_vault_without_child_vault_fails() {
+        let fixture = TestBuilder::new().await;
+        let mut vault_program_client = fixture.vault_program_client();
+
+        let config_admin = vault_program_client.do_initialize_config().await.unwrap();
+        let vault_roots = vault_program_client
+            .do_initialize_vault_chain(2, &config_admin.pubkey())
+            .await
+            .unwrap();
+        let vault_pubkey = vault_roots[1].vault_pubkey;
+        vault_program_client
+            .set_child_vault(
+                &vault_pubkey,
+                &vault_roots[0].vault_pubkey,
+                &vault_roots[1].vault_admin,
+            )
+            .await
+            .unwrap();
+
+        let response = vault_program_client
+            .update_vault_balance_with_child_vault(&vault_pubkey, None)
+            .await;
+
+        assert_ix_error(response, InstructionError::NotEnoughAccountKeys);
+    }
+
+    #[tokio::test]
+    async fn test_update_vault_balance_nested_vault_with_wrong_child_vault_fails() {
+        let fixture = TestBuilder::new().await;
+        let mut vault_program_client = fixture.vault_program_client();
+
+        let config_admin = vault_program_client.do_initialize_config().await.unwrap();
+        let vault_roots = vault_program_client
+            .do_initialize_vault_chain(2, &config_admin.pubkey())
+            .await
+            .unwrap();
+        let vault_pubkey = vault_roots[1].vault_pubkey;
+        vault_program_client
+            .set_child_vault(
+                &vault_pubkey,
+                &vault_roots[0].vault_pubkey,
+                &vault_roots[1].vault_admin,
+            )
+            .await
+            .unwrap();
+        let VaultRoot {
+            vault_pubkey: wrong_child_vault_pubkey,
+            ..
+        } = vault_program_client
+            .do_initialize_vault(0, 0, 0, 9, &config_admin.pubkey())
+            .await
+            .unwrap();
+
+        let response = vault_program_client
+            .update_vault_balance_with_child_vault(&vault_pubkey, Some(&wrong_child_vault_pubkey))
+            .await;
+
+        assert_failed_check(&response, Check::ChildVault, &wrong_child_vault_pubkey);
+        assert_vault_error(response, VaultError::VaultChildVaultInvalid);
+    }
+
+    #[tokio::test]
+    async fn test_set_child_vault_with_bad_admin() {
+        let fixture = TestBuilder::new().await;
+        let mut vault_program_client = fixture.vault_program_client();
+
+        let (
+            config_admin,
+            VaultRoot {
+                vault_pubkey: child_vault_pubkey,
+                ..
+            },
+        ) = vault_program_client
+            .setup_config_and_vault(0, 0, 0)
+            .await
+            .unwrap();
+        let VaultRoot { vault_pubkey, .. } = vault_program_client
+            .do_initialize_vault(0, 0, 0, 9, &config_admin.pubkey())
+            .await
+            .unwrap();
+
+        let bad_admin = Keypair::new();
+        vault_program_client
+            .airdrop(&bad_admin.pubkey(), 10.0)
+            .await
+            .unwrap();
+
+        let response = vault_program_client
+            .set_child_vault(&vault_pubkey, &child_vault_pubkey, &bad_admin)
+            .await;
+
+        assert_vault_error(response, VaultError::VaultAdminInvalid);
+    }
+
+    #[tokio::test]
+    async fn test_set_child_vault_to_self_fails() {
+        let fixture = TestBuilder::new().await;
+        let mut vault_program_client = fixture.vault_program_client();
+
+        let (
+            _config_admin,
+            VaultRoot {
+                vault_pubkey,
+                vault_admin,
+            },
+        ) = vault_program_client
+            .setup_config_and_vault(0, 0, 0)
+            .await
+            .unwrap();
+
+        let response = vault_program_client
+            .set_child_vault(&vault_pubkey, &vault_pubkey, &vault_admin)
+            .await;
+
+        assert_vault_error(response, VaultError::VaultNestingCycle);
+    }
+
+    #[tokio::test]
+    async fn test_set_child_vault_with_mismatched_mint_fails() {
+        let fixture = TestBuilder::new().await;
+        let mut vault_program_client = fixture.vault_program_client();
+
+        let (
+            config_admin,
+            VaultRoot {
+                vault_pubkey: child_vault_pubkey,
+                ..
+            },
+        ) = vault_program_client
+            .setup_config_and_vault(0, 0, 0)
+            .await
+            .unwrap();
+        let VaultRoot {
+            vault_pubkey,
+            vault_admin,
+        } = vault_program_client
+            .do_initialize_vault(0, 0, 0, 9, &config_admin.pubkey())
+            .await
+            .unwrap();
+
+        let response = vault_program_client
+            .set_child_vault(&vault_pubkey, &child_vault_pubkey, &vault_admin)
+            .await;
+
+        assert_vault_error(response, VaultError::VaultChildVaultInvalid);
+
+        let vault = vault_program_client.get_vault(&vault_pubkey).await.unwrap();
+        assert!(!vault.is_nested());
+    }
+}
@@ -0,0 +1,221 @@
+#[cfg(test)]
+mod tests {
+    use jito_jsm_core::diagnostic::Check;
+    use jito_vault_core::config::Config;
+    use jito_vault_sdk::{error::VaultError, instruction::VaultAdminRole};
+    use solana_sdk::signature::{Keypair, Signer};
+
+    use crate::fixtures::{
+        assert_failed_check,
+        fixture::{ConfiguredVault, TestBuilder},
+        vault_client::{assert_vault_error, VaultRoot},
+    };
+
+    #[tokio::test]
+    async fn test_set_is_emergency_exit_with_bad_admin() {
+        let fixture = TestBuilder::new().await;
+        let mut vault_program_client = fixture.vault_program_client();
+
+        let (_config_admin, VaultRoot { vault_pubkey, .. }) = vault_program_client
+            .setup_config_and_vault(0, 0, 0)
+            .await
+            .unwrap();
+
+        let bad_admin = Keypair::new();
+        vault_program_client
+            .airdrop(&bad_admin.pubkey(), 10.0)
+            .await
+            .unwrap();
+
+        let response = vault_program_client
+            .set_is_emergency_exit(&vault_pubkey, &bad_admin, true)
+            .await;
+
+        assert_vault_error(response, VaultError::VaultAdminInvalid);
+    }
+
+    #[tokio::test]
+    async fn test_set_is_emergency_exit_by_config_admin_unpauses() {
+        let fixture = TestBuilder::new().await;
+        let mut vault_program_client = fixture.vault_program_client();
+
+        let (
+            config_admin,
+            VaultRoot {
+                vault_pubkey,
+                vault_admin,
+            },
+        ) = vault_program_client
+            .setup_config_and_vault(0, 0, 0)
+            .await
+            .unwrap();
+
+        vault_program_client
+            .set_is_paused(&vault_pubkey, &vault_admin, true)
+            .await
+            .unwrap();
+
+        vault_program_client
+            .set_is_emergency_exit(&vault_pubkey, &config_admin, true)
+            .await
+            .unwrap();
+
+        let vault = vault_program_client.get_vault(&vault_pubkey).await.unwrap();
+        assert!(vault.is_emergency_exit());
+        assert!(!vault.is_paused());
+
+        vault_program_client
+            .set_is_emergency_exit(&vault_pubkey, &config_admin, false)
+            .await
+            .unwrap();
+
+        let vault = vault_program_client.get_vault(&vault_pubkey).await.unwrap();
+        assert!(!vault.is_emergency_exit());
+    }
+
+    #[tokio::test]
+    async fn test_set_is_emergency_exit_only_config_admin_can_leave() {
+        let fixture = TestBuilder::new().await;
+        let mut vault_program_client = fixture.vault_program_client();
+
+        let (
+            config_admin,
+            VaultRoot {
+                vault_pubkey,
+                vault_admin,
+            },
+        ) = vault_program_client
+            .setup_config_and_vault(0, 0, 0)
+            .await
+            .unwrap();
+
+        vault_program_client
+            .set_is_emergency_exit(&vault_pubkey, &vault_admin, true)
+            .await
+            .unwrap();
+
+        let response = vault_program_client
+            .set_is_emergency_exit(&vault_pubkey, &vault_admin, false)
+            .await;
+        assert_failed_check(&response, Check::Admin, &vault_admin.pubkey());
+        assert_vault_error(response, VaultError::ConfigAdminInvalid);
+
+        let vault = vault_program_client.get_vault(&vault_pubkey).await.unwrap();
+        assert!(vault.is_emergency_exit());
+
+        vault_program_client
+            .set_is_emergency_exit(&vault_pubkey, &config_admin, false)
+            .await
+            .unwrap();
+
+        let vault = vault_program_client.get_vault(&vault_pubkey).await.unwrap();
+        assert!(!vault.is_emergency_exit());
+    }
+
+    #[tokio::test]
+    async fn test_emergency_exit_force_cools_delegations() {
+        const MINT_AMOUNT: u64 = 100_000;
+
+        let mut fixture = TestBuilder::new().await;
+        let ConfiguredVault {
+            mut vault_program_client,
+            vault_config_admin,
+            vault_root,
+            operator_roots,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(0, 0, 0, 1, &[])
+            .await
+            .unwrap();
+
+        let depositor = Keypair::new();
+        vault_program_client
+            .configure_depositor(&vault_root, &depositor.pubkey(), MINT_AMOUNT)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_mint_to(&vault_root, &depositor, MINT_AMOUNT / 2, MINT_AMOUNT / 2)
+            .await
+            .unwrap();
+
+        let config = vault_program_client
+            .get_config(&Config::find_program_address(&jito_vault_program::id()).0)
+            .await
+            .unwrap();
+        fixture
+            .warp_slot_incremental(2 * config.epoch_length())
+            .await
+            .unwrap();
+
+        let operator_pubkeys: Vec<_> = operator_roots
+            .iter()
+            .map(|root| root.operator_pubkey)
+            .collect();
+        vault_program_client
+            .do_full_vault_update(&vault_root.vault_pubkey, &operator_pubkeys)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_add_delegation(&vault_root, &operator_pubkeys[0], MINT_AMOUNT / 2)
+            .await
+            .unwrap();
+
+        vault_program_client
+            .set_is_emergency_exit(&vault_root.vault_pubkey, &vault_config_admin, true)
+            .await
+            .unwrap();
+
+        // Minting and delegating are disabled
+        let response = vault_program_client
+            .do_mint_to(&vault_root, &depositor, MINT_AMOUNT / 2, 0)
+            .await;
+        assert_vault_error(response, VaultError::VaultInEmergencyExit);
+        let response = vault_program_client
+            .do_add_delegation(&vault_root, &operator_pubkeys[0], 1)
+            .await;
+        assert_vault_error(response, VaultError::VaultInEmergencyExit);
+
+        // Withdrawals no longer need the mint burn admin
+        vault_program_client
+            .set_secondary_admin(
+                &Config::find_program_address(&jito_vault_program::id()).0,
+                &vault_root.vault_pubkey,
+                &vault_root.vault_admin,
+                &Keypair::new().pubkey(),
+                VaultAdminRole::MintBurnAdmin,
+            )
+            .await
+            .unwrap();
+
+        // The next crank force-cools every delegation
+        fixture
+            .warp_slot_incremental(config.epoch_length())
+            .await
+            .unwrap();
+        vault_program_client
+            .do_full_vault_update(&vault_root.vault_pubkey, &operator_pubkeys)
+            .await
+            .unwrap();
+
+        let vault_operator_delegation = vault_program_client
+            .get_vault_operator_delegation(&vault_root.vault_pubkey, &operator_pubkeys[0])
+            .await
+            .unwrap();
+        assert_eq!(
+            vault_operator_delegation.delegation_state.staked_amount(),
+            0
+        );
+        assert_eq!(
+            vault_operator_delegation
+                .delegation_state
+                .total_security()
+                .unwrap(),
+            MINT_AMOUNT / 2
+        );
+
+        vault_program_client
+            .do_enqueue_withdrawal(&vault_root, &depositor, MINT_AMOUNT / 2)
+            .await
+            .unwrap();
+    }
+}
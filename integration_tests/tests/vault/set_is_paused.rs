@@ -66,4 +66,28 @@ mod tests {
 
         assert!(vault.is_paused());
     }
+
+    #[tokio::test]
+    async fn test_set_is_paused_in_emergency_exit_fails() {
+        let (mut vault_program_client, vault_pubkey, vault_admin) = setup().await;
+
+        vault_program_client
+            .set_is_emergency_exit(&vault_pubkey, &vault_admin, true)
+            .await
+            .unwrap();
+
+        let response = vault_program_client
+            .set_is_paused(&vault_pubkey, &vault_admin, true)
+            .await;
+        assert_vault_error(response, VaultError::VaultInEmergencyExit);
+
+        vault_program_client
+            .set_is_paused(&vault_pubkey, &vault_admin, false)
+            .await
+            .unwrap();
+
+        let vault = vault_program_client.get_vault(&vault_pubkey).await.unwrap();
+        assert!(!vault.is_paused());
+        assert!(vault.is_emergency_exit());
+    }
 }
@@ -0,0 +1,404 @@
+#[cfg(test)]
+mod tests {
+    use jito_jsm_core::{diagnostic::Check, pause::PauseCategory};
+    use jito_vault_core::config::Config;
+    use jito_vault_sdk::error::VaultError;
+    use solana_sdk::{
+        instruction::InstructionError,
+        signature::{Keypair, Signer},
+    };
+
+    use crate::fixtures::{
+        assert_failed_check, assert_ix_error,
+        fixture::{ConfiguredVault, TestBuilder},
+        vault_client::{assert_vault_error, VaultRoot, VaultStakerWithdrawalTicketRoot},
+    };
+
+    #[tokio::test]
+    async fn test_set_paused_categories_ok() {
+        let fixture = TestBuilder::new().await;
+        let mut vault_program_client = fixture.vault_program_client();
+
+        let (
+            _config_admin,
+            VaultRoot {
+                vault_pubkey,
+                vault_admin,
+            },
+        ) = vault_program_client
+            .setup_config_and_vault(0, 0, 0)
+            .await
+            .unwrap();
+
+        let paused_categories =
+            PauseCategory::Deposits.mask() | PauseCategory::TicketChanges.mask();
+        vault_program_client
+            .set_paused_categories(&vault_pubkey, &vault_admin, paused_categories)
+            .await
+            .unwrap();
+
+        let vault = vault_program_client.get_vault(&vault_pubkey).await.unwrap();
+        assert_eq!(vault.paused_categories(), paused_categories);
+        assert!(!vault.is_paused());
+
+        vault_program_client
+            .set_paused_categories(&vault_pubkey, &vault_admin, 0)
+            .await
+            .unwrap();
+
+        let vault = vault_program_client.get_vault(&vault_pubkey).await.unwrap();
+        assert_eq!(vault.paused_categories(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_set_paused_categories_with_bad_admin() {
+        let fixture = TestBuilder::new().await;
+        let mut vault_program_client = fixture.vault_program_client();
+
+        let (_config_admin, VaultRoot { vault_pubkey, .. }) = vault_program_client
+            .setup_config_and_vault(0, 0, 0)
+            .await
+            .unwrap();
+
+        let bad_admin = Keypair::new();
+        vault_program_client
+            .airdrop(&bad_admin.pubkey(), 10.0)
+            .await
+            .unwrap();
+
+        let response = vault_program_client
+            .set_paused_categories(&vault_pubkey, &bad_admin, PauseCategory::ALL_MASK)
+            .await;
+
+        assert_vault_error(response, VaultError::VaultAdminInvalid);
+    }
+
+    #[tokio::test]
+    async fn test_set_paused_categories_with_unknown_category_fails() {
+        let fixture = TestBuilder::new().await;
+        let mut vault_program_client = fixture.vault_program_client();
+
+        let (
+            _config_admin,
+            VaultRoot {
+                vault_pubkey,
+                vault_admin,
+            },
+        ) = vault_program_client
+            .setup_config_and_vault(0, 0, 0)
+            .await
+            .unwrap();
+
+        let response = vault_program_client
+            .set_paused_categories(&vault_pubkey, &vault_admin, PauseCategory::ALL_MASK + 1)
+            .await;
+
+        assert_ix_error(response, InstructionError::InvalidInstructionData);
+    }
+
+    #[tokio::test]
+    async fn test_deposits_paused_blocks_mint_only() {
+        const MINT_AMOUNT: u64 = 100_000;
+
+        let fixture = TestBuilder::new().await;
+        let mut vault_program_client = fixture.vault_program_client();
+
+        let (_config_admin, vault_root) = vault_program_client
+            .setup_config_and_vault(0, 0, 0)
+            .await
+            .unwrap();
+
+        let depositor = Keypair::new();
+        vault_program_client
+            .configure_depositor(&vault_root, &depositor.pubkey(), MINT_AMOUNT)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_mint_to(&vault_root, &depositor, MINT_AMOUNT / 2, MINT_AMOUNT / 2)
+            .await
+            .unwrap();
+
+        vault_program_client
+            .set_paused_categories(
+                &vault_root.vault_pubkey,
+                &vault_root.vault_admin,
+                PauseCategory::Deposits.mask(),
+            )
+            .await
+            .unwrap();
+
+        let response = vault_program_client
+            .do_mint_to(&vault_root, &depositor, MINT_AMOUNT / 2, MINT_AMOUNT / 2)
+            .await;
+        assert_vault_error(response, VaultError::VaultCategoryPaused);
+
+        // Withdrawals are unaffected
+        vault_program_client
+            .do_enqueue_withdrawal(&vault_root, &depositor, MINT_AMOUNT / 2)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_withdrawals_paused_blocks_enqueue_withdrawal() {
+        const MINT_AMOUNT: u64 = 100_000;
+
+        let fixture = TestBuilder::new().await;
+        let mut vault_program_client = fixture.vault_program_client();
+
+        let (_config_admin, vault_root) = vault_program_client
+            .setup_config_and_vault(0, 0, 0)
+            .await
+            .unwrap();
+
+        let depositor = Keypair::new();
+        vault_program_client
+            .configure_depositor(&vault_root, &depositor.pubkey(), MINT_AMOUNT)
+            .await
+            .unwrap();
+
+        vault_program_client
+            .set_paused_categories(
+                &vault_root.vault_pubkey,
+                &vault_root.vault_admin,
+                PauseCategory::Withdrawals.mask(),
+            )
+            .await
+            .unwrap();
+
+        // Deposits are unaffected
+        vault_program_client
+            .do_mint_to(&vault_root, &depositor, MINT_AMOUNT, MINT_AMOUNT)
+            .await
+            .unwrap();
+
+        let response = vault_program_client
+            .do_enqueue_withdrawal(&vault_root, &depositor, MINT_AMOUNT)
+            .await;
+        assert_vault_error(response, VaultError::VaultCategoryPaused);
+    }
+
+    #[tokio::test]
+    async fn test_withdrawals_paused_ignored_in_emergency_exit() {
+        const MINT_AMOUNT: u64 = 100_000;
+
+        let mut fixture = TestBuilder::new().await;
+        let ConfiguredVault {
+            mut vault_program_client,
+            vault_root,
+            operator_roots,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(0, 0, 0, 1, &[])
+            .await
+            .unwrap();
+
+        let depositor = Keypair::new();
+        vault_program_client
+            .configure_depositor(&vault_root, &depositor.pubkey(), MINT_AMOUNT)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_mint_to(&vault_root, &depositor, MINT_AMOUNT, MINT_AMOUNT)
+            .await
+            .unwrap();
+
+        vault_program_client
+            .set_paused_categories(
+                &vault_root.vault_pubkey,
+                &vault_root.vault_admin,
+                PauseCategory::Withdrawals.mask(),
+            )
+            .await
+            .unwrap();
+        let response = vault_program_client
+            .do_enqueue_withdrawal(&vault_root, &depositor, MINT_AMOUNT)
+            .await;
+        assert_vault_error(response, VaultError::VaultCategoryPaused);
+
+        vault_program_client
+            .set_is_emergency_exit(&vault_root.vault_pubkey, &vault_root.vault_admin, true)
+            .await
+            .unwrap();
+
+        // Withdrawals can't be paused again once in emergency exit
+        let response = vault_program_client
+            .set_paused_categories(
+                &vault_root.vault_pubkey,
+                &vault_root.vault_admin,
+                PauseCategory::Withdrawals.mask(),
+            )
+            .await;
+        assert_failed_check(&response, Check::EmergencyExit, &vault_root.vault_pubkey);
+        assert_vault_error(response, VaultError::VaultInEmergencyExit);
+
+        // The withdrawals bit set before entering emergency exit no longer blocks enqueuing or
+        // burning
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        assert!(PauseCategory::Withdrawals.is_paused(vault.paused_categories()));
+        let VaultStakerWithdrawalTicketRoot { base } = vault_program_client
+            .do_enqueue_withdrawal(&vault_root, &depositor, MINT_AMOUNT)
+            .await
+            .unwrap();
+
+        let config = vault_program_client
+            .get_config(&Config::find_program_address(&jito_vault_program::id()).0)
+            .await
+            .unwrap();
+        fixture
+            .warp_slot_incremental(2 * config.epoch_length())
+            .await
+            .unwrap();
+        let operator_pubkeys: Vec<_> = operator_roots
+            .iter()
+            .map(|root| root.operator_pubkey)
+            .collect();
+        vault_program_client
+            .do_full_vault_update(&vault_root.vault_pubkey, &operator_pubkeys)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_burn_withdrawal_ticket(&vault_root, &depositor, &base, &config.program_fee_wallet)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_delegation_paused_blocks_add_delegation() {
+        const MINT_AMOUNT: u64 = 100_000;
+
+        let mut fixture = TestBuilder::new().await;
+        let ConfiguredVault {
+            mut vault_program_client,
+            vault_root,
+            operator_roots,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(0, 0, 0, 1, &[])
+            .await
+            .unwrap();
+
+        let depositor = Keypair::new();
+        vault_program_client
+            .configure_depositor(&vault_root, &depositor.pubkey(), MINT_AMOUNT)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_mint_to(&vault_root, &depositor, MINT_AMOUNT, MINT_AMOUNT)
+            .await
+            .unwrap();
+
+        let config = vault_program_client
+            .get_config(&Config::find_program_address(&jito_vault_program::id()).0)
+            .await
+            .unwrap();
+        fixture
+            .warp_slot_incremental(2 * config.epoch_length())
+            .await
+            .unwrap();
+
+        let operator_pubkeys: Vec<_> = operator_roots
+            .iter()
+            .map(|root| root.operator_pubkey)
+            .collect();
+        vault_program_client
+            .do_full_vault_update(&vault_root.vault_pubkey, &operator_pubkeys)
+            .await
+            .unwrap();
+
+        vault_program_client
+            .set_paused_categories(
+                &vault_root.vault_pubkey,
+                &vault_root.vault_admin,
+                PauseCategory::Delegation.mask(),
+            )
+            .await
+            .unwrap();
+
+        let response = vault_program_client
+            .do_add_delegation(&vault_root, &operator_pubkeys[0], MINT_AMOUNT)
+            .await;
+        assert_vault_error(response, VaultError::VaultCategoryPaused);
+    }
+
+    #[tokio::test]
+    async fn test_ticket_changes_paused_blocks_initialize_vault_ncn_ticket() {
+        let fixture = TestBuilder::new().await;
+        let mut restaking_program_client = fixture.restaking_program_client();
+        let mut vault_program_client = fixture.vault_program_client();
+
+        let (_config_admin, vault_root) = vault_program_client
+            .setup_config_and_vault(0, 0, 0)
+            .await
+            .unwrap();
+
+        restaking_program_client
+            .do_initialize_config()
+            .await
+            .unwrap();
+        let ncn_root = restaking_program_client.do_initialize_ncn().await.unwrap();
+        restaking_program_client
+            .do_initialize_ncn_vault_ticket(&ncn_root, &vault_root.vault_pubkey)
+            .await
+            .unwrap();
+
+        vault_program_client
+            .set_paused_categories(
+                &vault_root.vault_pubkey,
+                &vault_root.vault_admin,
+                PauseCategory::TicketChanges.mask(),
+            )
+            .await
+            .unwrap();
+
+        let response = vault_program_client
+            .do_initialize_vault_ncn_ticket(&vault_root, &ncn_root.ncn_pubkey)
+            .await;
+        assert_vault_error(response, VaultError::VaultCategoryPaused);
+    }
+
+    #[tokio::test]
+    async fn test_fee_changes_paused_blocks_set_fees() {
+        let mut fixture = TestBuilder::new().await;
+        let mut vault_program_client = fixture.vault_program_client();
+
+        let (_config_admin, vault_root) = vault_program_client
+            .setup_config_and_vault(0, 0, 0)
+            .await
+            .unwrap();
+
+        let config_pubkey = Config::find_program_address(&jito_vault_program::id()).0;
+        let config = vault_program_client
+            .get_config(&config_pubkey)
+            .await
+            .unwrap();
+        fixture
+            .warp_slot_incremental(config.epoch_length() * 2)
+            .await
+            .unwrap();
+
+        vault_program_client
+            .set_paused_categories(
+                &vault_root.vault_pubkey,
+                &vault_root.vault_admin,
+                PauseCategory::FeeChanges.mask(),
+            )
+            .await
+            .unwrap();
+
+        let response = vault_program_client
+            .set_fees(
+                &config_pubkey,
+                &vault_root.vault_pubkey,
+                &vault_root.vault_admin,
+                Some(100),
+                None,
+                None,
+            )
+            .await;
+        assert_vault_error(response, VaultError::VaultCategoryPaused);
+    }
+}
@@ -0,0 +1,100 @@
+//! Exports accounts in the JSON format `solana account --output json` prints, which
+//! `solana-test-validator --account-dir` loads at genesis.
+
+use std::{fs, path::Path};
+
+use base64::{engine::general_purpose, Engine};
+use serde::{Deserialize, Serialize};
+use solana_program::pubkey::Pubkey;
+use solana_sdk::account::Account;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AccountFile {
+    pub pubkey: String,
+    pub account: UiAccount,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UiAccount {
+    pub lamports: u64,
+    /// The data and its encoding, which is always `base64`
+    pub data: [String; 2],
+    pub owner: String,
+    pub executable: bool,
+    pub rent_epoch: u64,
+    pub space: u64,
+}
+
+impl AccountFile {
+    pub fn new(pubkey: &Pubkey, account: &Account) -> Self {
+        Self {
+            pubkey: pubkey.to_string(),
+            account: UiAccount {
+                lamports: account.lamports,
+                data: [
+                    general_purpose::STANDARD.encode(&account.data),
+                    "base64".to_string(),
+                ],
+                owner: account.owner.to_string(),
+                executable: account.executable,
+                rent_epoch: account.rent_epoch,
+                space: account.data.len() as u64,
+            },
+        }
+    }
+}
+
+/// Writes each account to `<pubkey>.json` in `dir`, replacing anything the directory held so the
+/// validator doesn't load accounts left over from an earlier topology
+pub fn write_account_dir(dir: &Path, accounts: &[(Pubkey, Account)]) -> anyhow::Result<()> {
+    if dir.exists() {
+        fs::remove_dir_all(dir)?;
+    }
+    fs::create_dir_all(dir)?;
+    for (pubkey, account) in accounts {
+        fs::write(
+            dir.join(format!("{}.json", pubkey)),
+            serde_json::to_string_pretty(&AccountFile::new(pubkey, account))?,
+        )?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use base64::{engine::general_purpose, Engine};
+    use solana_program::pubkey::Pubkey;
+    use solana_sdk::account::Account;
+
+    use super::AccountFile;
+
+    #[test]
+    fn test_account_file_format() {
+        let pubkey = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+        let account = Account {
+            lamports: 1_000_000,
+            data: vec![1, 2, 3, 4],
+            owner,
+            executable: false,
+            rent_epoch: u64::MAX,
+        };
+
+        let json = serde_json::to_value(AccountFile::new(&pubkey, &account)).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "pubkey": pubkey.to_string(),
+                "account": {
+                    "lamports": 1_000_000,
+                    "data": [general_purpose::STANDARD.encode([1, 2, 3, 4]), "base64"],
+                    "owner": owner.to_string(),
+                    "executable": false,
+                    "rentEpoch": u64::MAX,
+                    "space": 4,
+                },
+            })
+        );
+    }
+}
@@ -0,0 +1,112 @@
+//! The addresses of a bootstrapped topology, written as JSON for downstream tooling.
+
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::topology::Topology;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AddressBook {
+    pub vault_program_id: String,
+    pub restaking_program_id: String,
+    /// Holds every admin role, and is the program fee wallet and each vault's fee wallet
+    pub admin: String,
+    /// Path of the admin keypair file
+    pub admin_keypair: String,
+    /// The slot from which every ticket is active
+    pub active_slot: u64,
+    pub restaking_config: String,
+    pub vault_config: String,
+    pub ncns: Vec<String>,
+    pub operators: Vec<String>,
+    pub vaults: Vec<VaultEntry>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VaultEntry {
+    pub address: String,
+    pub vrt_mint: String,
+    /// Minted by the admin
+    pub supported_mint: String,
+}
+
+impl AddressBook {
+    pub fn new(topology: &Topology, admin_keypair: &Path, active_slot: u64) -> Self {
+        Self {
+            vault_program_id: topology.vault_program_id.to_string(),
+            restaking_program_id: topology.restaking_program_id.to_string(),
+            admin: topology.admin.to_string(),
+            admin_keypair: admin_keypair.display().to_string(),
+            active_slot,
+            restaking_config: topology.restaking_config().to_string(),
+            vault_config: topology.vault_config().to_string(),
+            ncns: topology.ncns.iter().map(|ncn| ncn.to_string()).collect(),
+            operators: topology
+                .operators
+                .iter()
+                .map(|operator| operator.to_string())
+                .collect(),
+            vaults: topology
+                .vaults
+                .iter()
+                .map(|vault| VaultEntry {
+                    address: vault.address.to_string(),
+                    vrt_mint: vault.vrt_mint.to_string(),
+                    supported_mint: vault.supported_mint.to_string(),
+                })
+                .collect(),
+        }
+    }
+
+    pub fn write(&self, path: &Path) -> anyhow::Result<()> {
+        std::fs::write(path, serde_json::to_string_pretty(self)?)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use solana_program::pubkey::Pubkey;
+
+    use super::AddressBook;
+    use crate::topology::{Topology, TopologyVault};
+
+    #[test]
+    fn test_address_book_json() {
+        let vault = TopologyVault {
+            address: Pubkey::new_unique(),
+            base: Pubkey::new_unique(),
+            vrt_mint: Pubkey::new_unique(),
+            supported_mint: Pubkey::new_unique(),
+        };
+        let topology = Topology {
+            vault_program_id: Pubkey::new_unique(),
+            restaking_program_id: Pubkey::new_unique(),
+            admin: Pubkey::new_unique(),
+            ncns: vec![Pubkey::new_unique()],
+            operators: vec![Pubkey::new_unique(), Pubkey::new_unique()],
+            vaults: vec![vault],
+        };
+
+        let address_book =
+            AddressBook::new(&topology, Path::new("target/localnet/admin.json"), 864_000);
+        let json: serde_json::Value = serde_json::to_value(&address_book).unwrap();
+        assert_eq!(json["admin"], topology.admin.to_string());
+        assert_eq!(json["admin_keypair"], "target/localnet/admin.json");
+        assert_eq!(json["active_slot"], 864_000);
+        assert_eq!(
+            json["restaking_config"],
+            topology.restaking_config().to_string()
+        );
+        assert_eq!(json["operators"][1], topology.operators[1].to_string());
+        assert_eq!(json["vaults"][0]["vrt_mint"], vault.vrt_mint.to_string());
+
+        assert_eq!(
+            serde_json::from_value::<AddressBook>(json).unwrap(),
+            address_book
+        );
+    }
+}
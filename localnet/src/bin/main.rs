@@ -0,0 +1,196 @@
+use std::path::PathBuf;
+
+use anyhow::{anyhow, Context};
+use clap::Parser;
+use jito_localnet::{
+    accounts::write_account_dir,
+    address_book::AddressBook,
+    topology::{Bootstrap, TopologyConfig},
+    validator::ValidatorArgs,
+};
+use solana_program::{clock::Clock, native_token::sol_to_lamports, system_program};
+use solana_program_test::{processor, ProgramTest, ProgramTestContext};
+use solana_sdk::{
+    account::Account,
+    signature::{read_keypair_file, write_keypair_file, Keypair, Signer},
+};
+
+#[derive(Parser)]
+#[command(
+    about = "Bootstraps a restaking topology with every ticket active and exports it for solana-test-validator"
+)]
+struct Args {
+    /// Number of NCNs
+    #[arg(long, default_value_t = 1)]
+    ncns: usize,
+
+    /// Number of operators, each connected to every NCN
+    #[arg(long, default_value_t = 2)]
+    operators: usize,
+
+    /// Number of vaults, each with its own supported mint and connected to every NCN and operator
+    #[arg(long, default_value_t = 1)]
+    vaults: usize,
+
+    /// Decimals of the supported mints and VRTs
+    #[arg(long, default_value_t = 9)]
+    decimals: u8,
+
+    /// Keypair that holds every admin role. A new one is written to the output directory if not
+    /// set.
+    #[arg(long)]
+    admin: Option<PathBuf>,
+
+    /// Directory for the address book, the admin keypair, the exported accounts and the ledger
+    #[arg(long, default_value = "target/localnet")]
+    out_dir: PathBuf,
+
+    /// Start solana-test-validator with the topology once it's exported
+    #[arg(long)]
+    start_validator: bool,
+
+    /// The compiled vault program
+    #[arg(long, default_value = "target/deploy/jito_vault_program.so")]
+    vault_program: PathBuf,
+
+    /// The compiled restaking program
+    #[arg(long, default_value = "target/deploy/jito_restaking_program.so")]
+    restaking_program: PathBuf,
+}
+
+/// Funds the admin, which pays for every account of the topology
+const ADMIN_SOL: f64 = 1_000.0;
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    let args = Args::parse();
+    std::fs::create_dir_all(&args.out_dir)?;
+
+    let (admin, admin_keypair) = match &args.admin {
+        Some(path) => (
+            read_keypair_file(path)
+                .map_err(|e| anyhow!("Failed to read {}: {}", path.display(), e))?,
+            path.clone(),
+        ),
+        None => {
+            let admin = Keypair::new();
+            let path = args.out_dir.join("admin.json");
+            write_keypair_file(&admin, &path)
+                .map_err(|e| anyhow!("Failed to write {}: {}", path.display(), e))?;
+            (admin, path)
+        }
+    };
+
+    let mut program_test = ProgramTest::new(
+        "jito_vault_program",
+        jito_vault_program::id(),
+        processor!(jito_vault_program::process_instruction),
+    );
+    program_test.add_program(
+        "jito_restaking_program",
+        jito_restaking_program::id(),
+        processor!(jito_restaking_program::process_instruction),
+    );
+    program_test.add_account(
+        admin.pubkey(),
+        Account::new(sol_to_lamports(ADMIN_SOL), 0, &system_program::id()),
+    );
+    let mut context = program_test.start_with_context().await;
+
+    let bootstrap = Bootstrap::new(
+        context.banks_client.clone(),
+        admin.insecure_clone(),
+        jito_vault_program::id(),
+        jito_restaking_program::id(),
+    );
+    let config = TopologyConfig {
+        ncns: args.ncns,
+        operators: args.operators,
+        vaults: args.vaults,
+        decimals: args.decimals,
+        ..TopologyConfig::default()
+    };
+
+    let topology = bootstrap
+        .create(&config)
+        .await
+        .context("Failed to create the topology")?;
+
+    let warmup_slot = current_slot(&mut context).await? + 1;
+    warp_to_slot(&mut context, warmup_slot)?;
+    bootstrap
+        .warmup(&topology)
+        .await
+        .context("Failed to warm up the tickets")?;
+
+    let active_slot = bootstrap.active_slot(warmup_slot).await?;
+    warp_to_slot(&mut context, active_slot)?;
+    bootstrap
+        .update_vaults(&topology, active_slot)
+        .await
+        .context("Failed to update the vaults")?;
+
+    let mut accounts = Vec::new();
+    for address in topology.accounts() {
+        let account = context
+            .banks_client
+            .get_account(address)
+            .await?
+            .ok_or_else(|| anyhow!("Account {} was not created", address))?;
+        accounts.push((address, account));
+    }
+    let account_dir = args.out_dir.join("accounts");
+    write_account_dir(&account_dir, &accounts)?;
+
+    let address_book_path = args.out_dir.join("address_book.json");
+    AddressBook::new(&topology, &admin_keypair, active_slot).write(&address_book_path)?;
+    println!(
+        "Bootstrapped {} NCNs, {} operators and {} vaults, active from slot {}",
+        topology.ncns.len(),
+        topology.operators.len(),
+        topology.vaults.len(),
+        active_slot
+    );
+    println!("Address book: {}", address_book_path.display());
+
+    let validator = ValidatorArgs {
+        vault_program_id: jito_vault_program::id(),
+        vault_program: args.vault_program,
+        restaking_program_id: jito_restaking_program::id(),
+        restaking_program: args.restaking_program,
+        account_dir,
+        ledger: args.out_dir.join("ledger"),
+        warp_slot: active_slot,
+    };
+    if !args.start_validator {
+        println!("Start the validator with:\n  {}", validator.command_line());
+        return Ok(());
+    }
+
+    for program in [&validator.vault_program, &validator.restaking_program] {
+        if !program.exists() {
+            return Err(anyhow!(
+                "{} not found, build the programs with `cargo-build-sbf`",
+                program.display()
+            ));
+        }
+    }
+    let status = validator
+        .command()
+        .status()
+        .context("Failed to run solana-test-validator")?;
+    if !status.success() {
+        return Err(anyhow!("solana-test-validator exited with {}", status));
+    }
+    Ok(())
+}
+
+async fn current_slot(context: &mut ProgramTestContext) -> anyhow::Result<u64> {
+    Ok(context.banks_client.get_sysvar::<Clock>().await?.slot)
+}
+
+fn warp_to_slot(context: &mut ProgramTestContext, slot: u64) -> anyhow::Result<()> {
+    context
+        .warp_to_slot(slot)
+        .map_err(|e| anyhow!("Failed to warp to slot {}: {:?}", slot, e))
+}
@@ -0,0 +1,10 @@
+//! Bootstraps a complete restaking topology for local NCN development.
+//!
+//! [`topology::Bootstrap`] initializes both program configs, creates NCNs, operators and vaults
+//! and links every pair of them over any [`jito_jsm_client::JsmRpc`]. The `jito-localnet` binary
+//! runs it against a `solana-program-test` bank, warps through the ticket warmups and exports the
+//! resulting accounts, so `solana-test-validator` starts with every ticket already active.
+pub mod accounts;
+pub mod address_book;
+pub mod topology;
+pub mod validator;
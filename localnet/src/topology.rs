@@ -0,0 +1,592 @@
+//! Creates the accounts of a restaking topology and links every NCN, operator and vault.
+//!
+//! A ticket can't be warmed up in the slot it was initialized in, so bootstrapping runs in three
+//! steps with the caller advancing the clock between them: [`Bootstrap::create`] initializes
+//! every account, [`Bootstrap::warmup`] warms up every ticket in a later slot, and once the
+//! tickets are active at [`Bootstrap::active_slot`], [`Bootstrap::update_vaults`] runs the vault
+//! update the new epoch requires.
+
+use anyhow::anyhow;
+use jito_jsm_client::{JsmRpc, RestakingClient, VaultClient};
+use jito_restaking_core::{
+    config::Config as RestakingConfig, ncn::Ncn, ncn_operator_state::NcnOperatorState,
+    ncn_vault_ticket::NcnVaultTicket, operator::Operator,
+    operator_vault_ticket::OperatorVaultTicket,
+};
+use jito_vault_core::{
+    burn_vault::BurnVault, config::Config as VaultConfig, vault::Vault,
+    vault_ncn_ticket::VaultNcnTicket, vault_operator_delegation::VaultOperatorDelegation,
+    vault_update_state_tracker::VaultUpdateStateTracker,
+};
+use jito_vault_sdk::instruction::WithdrawalAllocationMethod;
+use solana_program::{
+    instruction::Instruction, program_pack::Pack, pubkey::Pubkey, rent::Rent, system_instruction,
+};
+use solana_sdk::signature::{Keypair, Signer};
+use spl_associated_token_account::{
+    get_associated_token_address, instruction::create_associated_token_account_idempotent,
+};
+
+/// The size of the topology and the fees its accounts are created with
+#[derive(Debug, Clone)]
+pub struct TopologyConfig {
+    pub ncns: usize,
+    pub operators: usize,
+    pub vaults: usize,
+    /// Decimals of each vault's supported token and VRT
+    pub decimals: u8,
+    pub program_fee_bps: u16,
+    pub operator_fee_bps: u16,
+    pub deposit_fee_bps: u16,
+    pub withdrawal_fee_bps: u16,
+    pub reward_fee_bps: u16,
+}
+
+impl Default for TopologyConfig {
+    fn default() -> Self {
+        Self {
+            ncns: 1,
+            operators: 2,
+            vaults: 1,
+            decimals: 9,
+            program_fee_bps: 0,
+            operator_fee_bps: 0,
+            deposit_fee_bps: 0,
+            withdrawal_fee_bps: 0,
+            reward_fee_bps: 0,
+        }
+    }
+}
+
+/// A vault and its mints
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TopologyVault {
+    pub address: Pubkey,
+    /// The PDA base the vault and its burn vault are derived from
+    pub base: Pubkey,
+    pub vrt_mint: Pubkey,
+    /// The mint of the token the vault accepts, with the admin as mint authority
+    pub supported_mint: Pubkey,
+}
+
+/// The addresses of a bootstrapped topology. The admin holds every admin role, is the program fee
+/// wallet and is each vault's fee wallet.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Topology {
+    pub vault_program_id: Pubkey,
+    pub restaking_program_id: Pubkey,
+    pub admin: Pubkey,
+    pub ncns: Vec<Pubkey>,
+    pub operators: Vec<Pubkey>,
+    pub vaults: Vec<TopologyVault>,
+}
+
+impl Topology {
+    pub fn restaking_config(&self) -> Pubkey {
+        RestakingConfig::find_program_address(&self.restaking_program_id).0
+    }
+
+    pub fn vault_config(&self) -> Pubkey {
+        VaultConfig::find_program_address(&self.vault_program_id).0
+    }
+
+    /// Every account the topology consists of, including the admin's
+    pub fn accounts(&self) -> Vec<Pubkey> {
+        let restaking_program_id = &self.restaking_program_id;
+        let vault_program_id = &self.vault_program_id;
+
+        let mut accounts = vec![self.admin, self.restaking_config(), self.vault_config()];
+        accounts.extend(self.ncns.iter().copied());
+        accounts.extend(self.operators.iter().copied());
+        for ncn in self.ncns.iter() {
+            for operator in self.operators.iter() {
+                accounts.push(
+                    NcnOperatorState::find_program_address(restaking_program_id, ncn, operator).0,
+                );
+            }
+        }
+        for vault in self.vaults.iter() {
+            let burn_vault = BurnVault::find_program_address(vault_program_id, &vault.base).0;
+            accounts.extend([
+                vault.address,
+                vault.vrt_mint,
+                vault.supported_mint,
+                get_associated_token_address(&vault.address, &vault.supported_mint),
+                get_associated_token_address(&self.admin, &vault.supported_mint),
+                get_associated_token_address(&self.admin, &vault.vrt_mint),
+                get_associated_token_address(&burn_vault, &vault.vrt_mint),
+            ]);
+            for ncn in self.ncns.iter() {
+                accounts.extend([
+                    NcnVaultTicket::find_program_address(restaking_program_id, ncn, &vault.address)
+                        .0,
+                    VaultNcnTicket::find_program_address(vault_program_id, &vault.address, ncn).0,
+                ]);
+            }
+            for operator in self.operators.iter() {
+                accounts.extend([
+                    OperatorVaultTicket::find_program_address(
+                        restaking_program_id,
+                        operator,
+                        &vault.address,
+                    )
+                    .0,
+                    VaultOperatorDelegation::find_program_address(
+                        vault_program_id,
+                        &vault.address,
+                        operator,
+                    )
+                    .0,
+                ]);
+            }
+        }
+        accounts
+    }
+}
+
+/// The first slot at which tickets warmed up at `warmup_slot` are active. A ticket is active once
+/// a full epoch has passed after the one it was warmed up in.
+pub const fn first_active_slot(warmup_slot: u64, epoch_length: u64) -> u64 {
+    (warmup_slot / epoch_length + 2) * epoch_length
+}
+
+/// Builds a [`Topology`] with a single admin keypair that signs and pays for everything
+pub struct Bootstrap<R> {
+    rpc: R,
+    admin: Keypair,
+    vault_client: VaultClient<R>,
+    restaking_client: RestakingClient<R>,
+}
+
+impl<R: JsmRpc + Clone> Bootstrap<R> {
+    pub fn new(
+        rpc: R,
+        admin: Keypair,
+        vault_program_id: Pubkey,
+        restaking_program_id: Pubkey,
+    ) -> Self {
+        Self {
+            vault_client: VaultClient::with_program_ids(
+                rpc.clone(),
+                vault_program_id,
+                restaking_program_id,
+            ),
+            restaking_client: RestakingClient::with_program_id(rpc.clone(), restaking_program_id),
+            rpc,
+            admin,
+        }
+    }
+
+    fn vault_program_id(&self) -> &Pubkey {
+        self.vault_client.vault_program_id()
+    }
+
+    fn restaking_program_id(&self) -> &Pubkey {
+        self.restaking_client.restaking_program_id()
+    }
+
+    /// Initializes both configs, creates the NCNs, operators and vaults and initializes every
+    /// ticket between them
+    pub async fn create(&self, config: &TopologyConfig) -> anyhow::Result<Topology> {
+        let admin = self.admin.pubkey();
+        let vault_program_id = *self.vault_program_id();
+        let restaking_program_id = *self.restaking_program_id();
+
+        self.process(
+            &[
+                jito_restaking_sdk::sdk::initialize_config(
+                    &restaking_program_id,
+                    &self.restaking_client.config_address(),
+                    &admin,
+                    &vault_program_id,
+                ),
+                jito_vault_sdk::sdk::initialize_config(
+                    &vault_program_id,
+                    &self.vault_client.config_address(),
+                    &admin,
+                    &restaking_program_id,
+                    &admin,
+                    config.program_fee_bps,
+                ),
+            ],
+            &[],
+        )
+        .await?;
+
+        let mut topology = Topology {
+            vault_program_id,
+            restaking_program_id,
+            admin,
+            ncns: Vec::with_capacity(config.ncns),
+            operators: Vec::with_capacity(config.operators),
+            vaults: Vec::with_capacity(config.vaults),
+        };
+        for _ in 0..config.ncns {
+            topology.ncns.push(self.create_ncn().await?);
+        }
+        for _ in 0..config.operators {
+            topology
+                .operators
+                .push(self.create_operator(config.operator_fee_bps).await?);
+        }
+        for _ in 0..config.vaults {
+            topology.vaults.push(self.create_vault(config).await?);
+        }
+
+        // The first connect call initializes each ticket, the vault side after the restaking side
+        self.connect_all(&topology).await?;
+
+        for vault in topology.vaults.iter() {
+            for operator in topology.operators.iter() {
+                let instructions = self
+                    .vault_client
+                    .connect_operator_instructions(&admin, &vault.address, operator)
+                    .await?;
+                self.process(&instructions, &[]).await?;
+            }
+        }
+
+        Ok(topology)
+    }
+
+    /// Warms up every ticket of the topology. Must run in a later slot than [`Self::create`].
+    pub async fn warmup(&self, topology: &Topology) -> anyhow::Result<()> {
+        self.connect_all(topology).await?;
+
+        for ncn in topology.ncns.iter() {
+            for operator in topology.operators.iter() {
+                let instructions = self.restaking_client.operator_opt_in_ncn_instructions(
+                    &self.admin.pubkey(),
+                    ncn,
+                    operator,
+                );
+                self.process(&instructions, &[]).await?;
+            }
+        }
+        Ok(())
+    }
+
+    /// The first slot at which the tickets warmed up at `warmup_slot` are active in both programs
+    pub async fn active_slot(&self, warmup_slot: u64) -> anyhow::Result<u64> {
+        let restaking_epoch_length = self.restaking_client.get_config().await?.epoch_length();
+        let vault_epoch_length = self.vault_client.get_config().await?.epoch_length();
+        Ok(first_active_slot(warmup_slot, restaking_epoch_length)
+            .max(first_active_slot(warmup_slot, vault_epoch_length)))
+    }
+
+    /// Runs a full update of every vault, which the vaults need in each new epoch before they
+    /// accept deposits or delegations. `slot` must be the current slot.
+    pub async fn update_vaults(&self, topology: &Topology, slot: u64) -> anyhow::Result<()> {
+        let vault_program_id = self.vault_program_id();
+        let config = self.vault_client.config_address();
+        let ncn_epoch = slot / self.vault_client.get_config().await?.epoch_length();
+        let admin = self.admin.pubkey();
+
+        for vault in topology.vaults.iter() {
+            let tracker = VaultUpdateStateTracker::find_program_address(
+                vault_program_id,
+                &vault.address,
+                ncn_epoch,
+            )
+            .0;
+            self.process(
+                &[jito_vault_sdk::sdk::initialize_vault_update_state_tracker(
+                    vault_program_id,
+                    &config,
+                    &vault.address,
+                    &tracker,
+                    &admin,
+                    WithdrawalAllocationMethod::Greedy,
+                )],
+                &[],
+            )
+            .await?;
+
+            // Operators are cranked in delegation index order, starting at the epoch's offset
+            let operators = &topology.operators;
+            for i in 0..operators.len() {
+                let operator = &operators[(i + ncn_epoch as usize) % operators.len()];
+                self.process(
+                    &[jito_vault_sdk::sdk::crank_vault_update_state_tracker(
+                        vault_program_id,
+                        &config,
+                        &vault.address,
+                        operator,
+                        &self
+                            .vault_client
+                            .vault_operator_delegation_address(&vault.address, operator),
+                        &tracker,
+                    )],
+                    &[],
+                )
+                .await?;
+            }
+
+            self.process(
+                &[
+                    jito_vault_sdk::sdk::close_vault_update_state_tracker(
+                        vault_program_id,
+                        &config,
+                        &vault.address,
+                        &tracker,
+                        &admin,
+                        ncn_epoch,
+                    ),
+                    jito_vault_sdk::sdk::update_vault_balance(
+                        vault_program_id,
+                        &config,
+                        &vault.address,
+                        &get_associated_token_address(&vault.address, &vault.supported_mint),
+                        &vault.vrt_mint,
+                        &get_associated_token_address(&admin, &vault.vrt_mint),
+                        &spl_token::id(),
+                        None,
+                    ),
+                ],
+                &[],
+            )
+            .await?;
+        }
+        Ok(())
+    }
+
+    /// Sends every connect call of the topology once: the first time initializes the tickets, the
+    /// second warms them up
+    async fn connect_all(&self, topology: &Topology) -> anyhow::Result<()> {
+        let admin = self.admin.pubkey();
+
+        for ncn in topology.ncns.iter() {
+            for operator in topology.operators.iter() {
+                let instructions = self
+                    .restaking_client
+                    .connect_ncn_operator_instructions(&admin, &admin, ncn, operator)
+                    .await?;
+                self.process(&instructions, &[]).await?;
+            }
+        }
+        for vault in topology.vaults.iter() {
+            for ncn in topology.ncns.iter() {
+                let instructions = self
+                    .restaking_client
+                    .connect_ncn_vault_instructions(&admin, &admin, ncn, &vault.address)
+                    .await?;
+                self.process(&instructions, &[]).await?;
+            }
+            for operator in topology.operators.iter() {
+                let instructions = self
+                    .restaking_client
+                    .connect_operator_vault_instructions(&admin, &admin, operator, &vault.address)
+                    .await?;
+                self.process(&instructions, &[]).await?;
+            }
+        }
+        for vault in topology.vaults.iter() {
+            for ncn in topology.ncns.iter() {
+                let instructions = self
+                    .vault_client
+                    .connect_ncn_instructions(&admin, &vault.address, ncn)
+                    .await?;
+                self.process(&instructions, &[]).await?;
+            }
+        }
+        Ok(())
+    }
+
+    async fn create_ncn(&self) -> anyhow::Result<Pubkey> {
+        let base = Keypair::new();
+        let ncn = Ncn::find_program_address(self.restaking_program_id(), &base.pubkey()).0;
+        self.process(
+            &[jito_restaking_sdk::sdk::initialize_ncn(
+                self.restaking_program_id(),
+                &self.restaking_client.config_address(),
+                &ncn,
+                &self.admin.pubkey(),
+                &base.pubkey(),
+            )],
+            &[&base],
+        )
+        .await?;
+        Ok(ncn)
+    }
+
+    async fn create_operator(&self, operator_fee_bps: u16) -> anyhow::Result<Pubkey> {
+        let base = Keypair::new();
+        let operator =
+            Operator::find_program_address(self.restaking_program_id(), &base.pubkey()).0;
+        self.process(
+            &[jito_restaking_sdk::sdk::initialize_operator(
+                self.restaking_program_id(),
+                &self.restaking_client.config_address(),
+                &operator,
+                &self.admin.pubkey(),
+                &base.pubkey(),
+                operator_fee_bps,
+            )],
+            &[&base],
+        )
+        .await?;
+        Ok(operator)
+    }
+
+    /// Creates a supported token mint and a vault for it. The vault is initialized with
+    /// [`Vault::DEFAULT_INITIALIZATION_TOKEN_AMOUNT`] minted to the admin.
+    async fn create_vault(&self, config: &TopologyConfig) -> anyhow::Result<TopologyVault> {
+        let admin = self.admin.pubkey();
+        let vault_program_id = self.vault_program_id();
+        let base = Keypair::new();
+        let vrt_mint = Keypair::new();
+        let supported_mint = Keypair::new();
+
+        let vault = Vault::find_program_address(vault_program_id, &base.pubkey()).0;
+        let burn_vault = BurnVault::find_program_address(vault_program_id, &base.pubkey()).0;
+        let admin_token_account = get_associated_token_address(&admin, &supported_mint.pubkey());
+        let vault_token_account = get_associated_token_address(&vault, &supported_mint.pubkey());
+
+        self.process(
+            &[
+                system_instruction::create_account(
+                    &admin,
+                    &supported_mint.pubkey(),
+                    Rent::default().minimum_balance(spl_token::state::Mint::LEN),
+                    spl_token::state::Mint::LEN as u64,
+                    &spl_token::id(),
+                ),
+                spl_token::instruction::initialize_mint2(
+                    &spl_token::id(),
+                    &supported_mint.pubkey(),
+                    &admin,
+                    None,
+                    config.decimals,
+                )?,
+                create_associated_token_account_idempotent(
+                    &admin,
+                    &admin,
+                    &supported_mint.pubkey(),
+                    &spl_token::id(),
+                ),
+                create_associated_token_account_idempotent(
+                    &admin,
+                    &vault,
+                    &supported_mint.pubkey(),
+                    &spl_token::id(),
+                ),
+                spl_token::instruction::mint_to(
+                    &spl_token::id(),
+                    &supported_mint.pubkey(),
+                    &admin_token_account,
+                    &admin,
+                    &[],
+                    Vault::DEFAULT_INITIALIZATION_TOKEN_AMOUNT,
+                )?,
+            ],
+            &[&supported_mint],
+        )
+        .await?;
+
+        self.process(
+            &[
+                jito_vault_sdk::sdk::initialize_vault(
+                    vault_program_id,
+                    &self.vault_client.config_address(),
+                    &vault,
+                    &vrt_mint.pubkey(),
+                    &supported_mint.pubkey(),
+                    &admin_token_account,
+                    &vault_token_account,
+                    &burn_vault,
+                    &get_associated_token_address(&burn_vault, &vrt_mint.pubkey()),
+                    &admin,
+                    &base.pubkey(),
+                    config.deposit_fee_bps,
+                    config.withdrawal_fee_bps,
+                    config.reward_fee_bps,
+                    config.decimals,
+                    Vault::DEFAULT_INITIALIZATION_TOKEN_AMOUNT,
+                ),
+                // Holds the vault and program fees
+                create_associated_token_account_idempotent(
+                    &admin,
+                    &admin,
+                    &vrt_mint.pubkey(),
+                    &spl_token::id(),
+                ),
+            ],
+            &[&vrt_mint, &base],
+        )
+        .await?;
+
+        Ok(TopologyVault {
+            address: vault,
+            base: base.pubkey(),
+            vrt_mint: vrt_mint.pubkey(),
+            supported_mint: supported_mint.pubkey(),
+        })
+    }
+
+    /// Sends the instructions in one transaction paid for by the admin, decoding the failed
+    /// account check into the error if the transaction fails
+    async fn process(
+        &self,
+        instructions: &[Instruction],
+        signers: &[&Keypair],
+    ) -> anyhow::Result<()> {
+        if instructions.is_empty() {
+            return Ok(());
+        }
+        self.rpc
+            .process_instructions(instructions, &self.admin, signers)
+            .await
+            .map_err(|e| match e.diagnose(instructions) {
+                Some(diagnostic) => anyhow!("{}\n{}", e, diagnostic),
+                None => anyhow!(e),
+            })?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use solana_program::pubkey::Pubkey;
+
+    use super::{first_active_slot, Topology, TopologyVault};
+
+    #[test]
+    fn test_first_active_slot() {
+        assert_eq!(first_active_slot(0, 100), 200);
+        assert_eq!(first_active_slot(1, 100), 200);
+        assert_eq!(first_active_slot(99, 100), 200);
+        assert_eq!(first_active_slot(100, 100), 300);
+        assert_eq!(first_active_slot(432_001, 432_000), 3 * 432_000);
+    }
+
+    #[test]
+    fn test_accounts_cover_every_link() {
+        let topology = Topology {
+            vault_program_id: Pubkey::new_unique(),
+            restaking_program_id: Pubkey::new_unique(),
+            admin: Pubkey::new_unique(),
+            ncns: vec![Pubkey::new_unique(), Pubkey::new_unique()],
+            operators: (0..3).map(|_| Pubkey::new_unique()).collect(),
+            vaults: (0..2)
+                .map(|_| TopologyVault {
+                    address: Pubkey::new_unique(),
+                    base: Pubkey::new_unique(),
+                    vrt_mint: Pubkey::new_unique(),
+                    supported_mint: Pubkey::new_unique(),
+                })
+                .collect(),
+        };
+
+        let accounts = topology.accounts();
+        let unique: HashSet<_> = accounts.iter().collect();
+        assert_eq!(unique.len(), accounts.len());
+
+        // admin and configs, NCNs and operators, NCN operator states, 7 accounts per vault and
+        // two tickets for each of the vault's NCNs and operators
+        assert_eq!(accounts.len(), 3 + 5 + 2 * 3 + 2 * (7 + 2 * 2 + 2 * 3));
+        assert!(accounts.contains(&topology.restaking_config()));
+        assert!(accounts.contains(&topology.vault_config()));
+    }
+}
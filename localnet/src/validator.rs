@@ -0,0 +1,94 @@
+//! Starts `solana-test-validator` with both programs deployed and the topology loaded.
+
+use std::{ffi::OsString, path::PathBuf, process::Command};
+
+use solana_program::pubkey::Pubkey;
+
+#[derive(Debug, Clone)]
+pub struct ValidatorArgs {
+    pub vault_program_id: Pubkey,
+    /// The compiled vault program, e.g. `target/deploy/jito_vault_program.so`
+    pub vault_program: PathBuf,
+    pub restaking_program_id: Pubkey,
+    pub restaking_program: PathBuf,
+    /// The directory the accounts were exported to
+    pub account_dir: PathBuf,
+    pub ledger: PathBuf,
+    /// The slot the validator starts at, at or after the slot the tickets are active from
+    pub warp_slot: u64,
+}
+
+impl ValidatorArgs {
+    /// The validator's arguments. The ledger is reset since it can only be warped from genesis.
+    pub fn args(&self) -> Vec<OsString> {
+        vec![
+            "--reset".into(),
+            "--ledger".into(),
+            self.ledger.clone().into(),
+            "--bpf-program".into(),
+            self.vault_program_id.to_string().into(),
+            self.vault_program.clone().into(),
+            "--bpf-program".into(),
+            self.restaking_program_id.to_string().into(),
+            self.restaking_program.clone().into(),
+            "--account-dir".into(),
+            self.account_dir.clone().into(),
+            "--warp-slot".into(),
+            self.warp_slot.to_string().into(),
+        ]
+    }
+
+    pub fn command(&self) -> Command {
+        let mut command = Command::new("solana-test-validator");
+        command.args(self.args());
+        command
+    }
+
+    /// The command line, for printing
+    pub fn command_line(&self) -> String {
+        std::iter::once("solana-test-validator".to_string())
+            .chain(
+                self.args()
+                    .iter()
+                    .map(|arg| arg.to_string_lossy().into_owned()),
+            )
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use solana_program::pubkey::Pubkey;
+
+    use super::ValidatorArgs;
+
+    #[test]
+    fn test_command_line() {
+        let vault_program_id = Pubkey::new_unique();
+        let restaking_program_id = Pubkey::new_unique();
+        let args = ValidatorArgs {
+            vault_program_id,
+            vault_program: PathBuf::from("target/deploy/jito_vault_program.so"),
+            restaking_program_id,
+            restaking_program: PathBuf::from("target/deploy/jito_restaking_program.so"),
+            account_dir: PathBuf::from("target/localnet/accounts"),
+            ledger: PathBuf::from("target/localnet/ledger"),
+            warp_slot: 864_000,
+        };
+
+        assert_eq!(
+            args.command_line(),
+            format!(
+                "solana-test-validator --reset --ledger target/localnet/ledger \
+                 --bpf-program {} target/deploy/jito_vault_program.so \
+                 --bpf-program {} target/deploy/jito_restaking_program.so \
+                 --account-dir target/localnet/accounts --warp-slot 864000",
+                vault_program_id, restaking_program_id
+            )
+        );
+        assert_eq!(args.command().get_program(), "solana-test-validator");
+    }
+}
@@ -0,0 +1,170 @@
+use std::path::Path;
+
+use jito_jsm_client::{RestakingClient, VaultClient};
+use jito_jsm_core::slot_toggle::SlotToggleState;
+use jito_localnet::{
+    address_book::AddressBook,
+    topology::{Bootstrap, TopologyConfig},
+};
+use solana_program::{clock::Clock, native_token::sol_to_lamports, system_program};
+use solana_program_test::{processor, ProgramTest, ProgramTestContext};
+use solana_sdk::{
+    account::Account,
+    signature::{Keypair, Signer},
+};
+
+async fn current_slot(context: &mut ProgramTestContext) -> u64 {
+    context
+        .banks_client
+        .get_sysvar::<Clock>()
+        .await
+        .unwrap()
+        .slot
+}
+
+/// Bootstraps a topology the way the binary does and checks the exported address book against
+/// the bank
+#[tokio::test]
+async fn test_bootstrap_exports_address_book() {
+    let admin = Keypair::new();
+    let mut program_test = ProgramTest::new(
+        "jito_vault_program",
+        jito_vault_program::id(),
+        processor!(jito_vault_program::process_instruction),
+    );
+    program_test.add_program(
+        "jito_restaking_program",
+        jito_restaking_program::id(),
+        processor!(jito_restaking_program::process_instruction),
+    );
+    program_test.add_account(
+        admin.pubkey(),
+        Account::new(sol_to_lamports(1_000.0), 0, &system_program::id()),
+    );
+    let mut context = program_test.start_with_context().await;
+
+    let bootstrap = Bootstrap::new(
+        context.banks_client.clone(),
+        admin.insecure_clone(),
+        jito_vault_program::id(),
+        jito_restaking_program::id(),
+    );
+    let config = TopologyConfig {
+        ncns: 1,
+        operators: 1,
+        vaults: 1,
+        ..TopologyConfig::default()
+    };
+    let topology = bootstrap.create(&config).await.unwrap();
+
+    let warmup_slot = current_slot(&mut context).await + 1;
+    context.warp_to_slot(warmup_slot).unwrap();
+    bootstrap.warmup(&topology).await.unwrap();
+    let active_slot = bootstrap.active_slot(warmup_slot).await.unwrap();
+    context.warp_to_slot(active_slot).unwrap();
+    bootstrap
+        .update_vaults(&topology, active_slot)
+        .await
+        .unwrap();
+
+    for address in topology.accounts() {
+        assert!(
+            context
+                .banks_client
+                .get_account(address)
+                .await
+                .unwrap()
+                .is_some(),
+            "{} was not created",
+            address
+        );
+    }
+
+    let path = Path::new(env!("CARGO_TARGET_TMPDIR")).join("address_book.json");
+    AddressBook::new(&topology, Path::new("admin.json"), active_slot)
+        .write(&path)
+        .unwrap();
+    let address_book: AddressBook =
+        serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+
+    assert_eq!(
+        address_book.vault_program_id,
+        jito_vault_program::id().to_string()
+    );
+    assert_eq!(
+        address_book.restaking_program_id,
+        jito_restaking_program::id().to_string()
+    );
+    assert_eq!(address_book.admin, admin.pubkey().to_string());
+    assert_eq!(address_book.admin_keypair, "admin.json");
+    assert_eq!(address_book.active_slot, active_slot);
+    assert_eq!(
+        address_book.restaking_config,
+        topology.restaking_config().to_string()
+    );
+    assert_eq!(
+        address_book.vault_config,
+        topology.vault_config().to_string()
+    );
+    assert_eq!(address_book.ncns, vec![topology.ncns[0].to_string()]);
+    assert_eq!(
+        address_book.operators,
+        vec![topology.operators[0].to_string()]
+    );
+    assert_eq!(address_book.vaults.len(), 1);
+
+    let vault_client = VaultClient::with_program_ids(
+        context.banks_client.clone(),
+        jito_vault_program::id(),
+        jito_restaking_program::id(),
+    );
+    let vault = vault_client
+        .get_vault(&topology.vaults[0].address)
+        .await
+        .unwrap();
+    assert_eq!(
+        address_book.vaults[0].address,
+        topology.vaults[0].address.to_string()
+    );
+    assert_eq!(address_book.vaults[0].vrt_mint, vault.vrt_mint.to_string());
+    assert_eq!(
+        address_book.vaults[0].supported_mint,
+        vault.supported_mint.to_string()
+    );
+
+    // Every ticket is active from the exported slot
+    let restaking_client = RestakingClient::with_program_id(
+        context.banks_client.clone(),
+        jito_restaking_program::id(),
+    );
+    let epoch_length = restaking_client.get_config().await.unwrap().epoch_length();
+    let ncn_operator_state = restaking_client
+        .get_ncn_operator_state(&topology.ncns[0], &topology.operators[0])
+        .await
+        .unwrap();
+    assert_eq!(
+        ncn_operator_state
+            .ncn_opt_in_state
+            .state(active_slot, epoch_length)
+            .unwrap(),
+        SlotToggleState::Active
+    );
+    assert_eq!(
+        ncn_operator_state
+            .operator_opt_in_state
+            .state(active_slot, epoch_length)
+            .unwrap(),
+        SlotToggleState::Active
+    );
+    let ncn_vault_ticket = restaking_client
+        .get_ncn_vault_ticket(&topology.ncns[0], &topology.vaults[0].address)
+        .await
+        .unwrap();
+    assert_eq!(
+        ncn_vault_ticket
+            .state
+            .state(active_slot, epoch_length)
+            .unwrap(),
+        SlotToggleState::Active
+    );
+}
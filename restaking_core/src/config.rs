@@ -1,19 +1,26 @@
 //! Global configuration account for the restaking program
 
 use bytemuck::{Pod, Zeroable};
-use jito_bytemuck::{types::PodU64, AccountDeserialize, Discriminator};
+use jito_bytemuck::{
+    types::{PodU16, PodU64},
+    AccountDeserialize, JitoAccount,
+};
+use jito_jsm_core::pause::PauseCategory;
 use jito_restaking_sdk::error::RestakingError;
 use shank::ShankAccount;
-use solana_program::{
-    account_info::AccountInfo, clock::DEFAULT_SLOTS_PER_EPOCH, msg, program_error::ProgramError,
-    pubkey::Pubkey,
-};
+use solana_program::{clock::DEFAULT_SLOTS_PER_EPOCH, msg, pubkey::Pubkey};
 
-const RESERVED_SPACE_LEN: usize = 263;
+use crate::discriminators::RestakingDiscriminator;
+
+const RESERVED_SPACE_LEN: usize = 261;
 
 /// The global configuration account for the restaking program. Manages
 /// program-wide settings and state.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Pod, Zeroable, AccountDeserialize, ShankAccount)]
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Pod, Zeroable, AccountDeserialize, JitoAccount, ShankAccount,
+)]
+#[discriminator(RestakingDiscriminator::Config)]
+#[account_seeds(b"config")]
 #[repr(C)]
 pub struct Config {
     /// The configuration admin
@@ -34,8 +41,11 @@ pub struct Config {
     /// The bump seed for the PDA
     pub bump: u8,
 
+    /// Bitmask of paused instruction categories, see [`PauseCategory`]
+    paused_categories: PodU16,
+
     /// Reserved space
-    reserved: [u8; 263],
+    reserved: [u8; 261],
 }
 
 impl Config {
@@ -47,6 +57,7 @@ impl Config {
             ncn_count: PodU64::from(0),
             operator_count: PodU64::from(0),
             bump,
+            paused_categories: PodU16::from(0),
             reserved: [0; RESERVED_SPACE_LEN],
         }
     }
@@ -63,6 +74,26 @@ impl Config {
         self.operator_count.into()
     }
 
+    pub fn paused_categories(&self) -> u16 {
+        self.paused_categories.into()
+    }
+
+    pub fn set_paused_categories(&mut self, paused_categories: u16) {
+        self.paused_categories = PodU16::from(paused_categories);
+    }
+
+    /// Checks whether an instruction category is paused on the Config.
+    ///
+    /// # Errors
+    /// * [`RestakingError::ConfigPaused`] - If the category is paused.
+    pub fn check_is_category_paused(&self, category: PauseCategory) -> Result<(), RestakingError> {
+        if category.is_paused(self.paused_categories()) {
+            msg!("Config {:?} are currently paused", category);
+            return Err(RestakingError::ConfigPaused);
+        }
+        Ok(())
+    }
+
     pub fn increment_ncn_count(&mut self) -> Result<(), RestakingError> {
         let ncn_count = self
             .ncn_count()
@@ -81,63 +112,6 @@ impl Config {
         Ok(())
     }
 
-    /// Returns the seeds for the PDA
-    pub fn seeds() -> Vec<Vec<u8>> {
-        vec![b"config".to_vec()]
-    }
-
-    /// Find the program address for the global configuration account
-    ///
-    /// # Arguments
-    /// * `program_id` - The program ID
-    /// # Returns
-    /// * `Pubkey` - The program address
-    /// * `u8` - The bump seed
-    /// * `Vec<Vec<u8>>` - The seeds used to generate the PDA
-    pub fn find_program_address(program_id: &Pubkey) -> (Pubkey, u8, Vec<Vec<u8>>) {
-        let seeds = Self::seeds();
-        let seeds_iter: Vec<_> = seeds.iter().map(|s| s.as_slice()).collect();
-        let (pda, bump) = Pubkey::find_program_address(&seeds_iter, program_id);
-        (pda, bump, seeds)
-    }
-
-    /// Attempts to load the account as [`Config`], returning an error if it's not valid.
-    ///
-    /// # Arguments
-    /// * `program_id` - The program ID
-    /// * `account` - The account to load the configuration from
-    /// * `expect_writable` - Whether the account should be writable
-    ///
-    /// # Returns
-    /// * `Result<(), ProgramError>` - The result of the operation
-    pub fn load(
-        program_id: &Pubkey,
-        account: &AccountInfo,
-        expect_writable: bool,
-    ) -> Result<(), ProgramError> {
-        if account.owner.ne(program_id) {
-            msg!("Config account has an invalid owner");
-            return Err(ProgramError::InvalidAccountOwner);
-        }
-        if account.data_is_empty() {
-            msg!("Config account data is empty");
-            return Err(ProgramError::InvalidAccountData);
-        }
-        if expect_writable && !account.is_writable {
-            msg!("Config account is not writable");
-            return Err(ProgramError::InvalidAccountData);
-        }
-        if account.data.borrow()[0].ne(&Self::DISCRIMINATOR) {
-            msg!("Config account discriminator is invalid");
-            return Err(ProgramError::InvalidAccountData);
-        }
-        if account.key.ne(&Self::find_program_address(program_id).0) {
-            msg!("Config account is not at the correct PDA");
-            return Err(ProgramError::InvalidAccountData);
-        }
-        Ok(())
-    }
-
     pub fn set_admin(&mut self, new_admin: Pubkey) {
         self.admin = new_admin;
     }
@@ -156,6 +130,7 @@ mod tests {
             std::mem::size_of::<PodU64>() + // operator_count
             std::mem::size_of::<PodU64>() + // epoch_length
             std::mem::size_of::<u8>() + // bump
+            std::mem::size_of::<PodU16>() + // paused_categories
             RESERVED_SPACE_LEN; // reserved
         assert_eq!(config_size, sum_of_fields);
     }
@@ -1,4 +1,4 @@
-use jito_bytemuck::Discriminator;
+use jito_bytemuck::Migrate;
 
 use crate::{
     config::Config, ncn::Ncn, ncn_operator_state::NcnOperatorState,
@@ -7,7 +7,8 @@ use crate::{
 };
 
 /// Discriminators for restaking accounts
-/// Values must not change as they are written on chain to determine the type of account
+/// Values must not change as they are written on chain to determine the type of account.
+/// Accounts pick their variant with `#[discriminator(..)]`, which rejects two accounts sharing one.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum RestakingDiscriminator {
     Config = 1,
@@ -19,30 +20,19 @@ pub enum RestakingDiscriminator {
     NcnVaultSlasherTicket = 7,
 }
 
-impl Discriminator for Config {
-    const DISCRIMINATOR: u8 = RestakingDiscriminator::Config as u8;
-}
+// Every account is still at its first layout version, so there is nothing to migrate from yet.
+// When a layout changes, set `version` in its `#[discriminator(..)]` and convert the previous
+// layout in `migrate`.
+impl Migrate for Config {}
 
-impl Discriminator for Ncn {
-    const DISCRIMINATOR: u8 = RestakingDiscriminator::Ncn as u8;
-}
+impl Migrate for Ncn {}
 
-impl Discriminator for Operator {
-    const DISCRIMINATOR: u8 = RestakingDiscriminator::Operator as u8;
-}
+impl Migrate for Operator {}
 
-impl Discriminator for NcnOperatorState {
-    const DISCRIMINATOR: u8 = RestakingDiscriminator::NcnOperatorState as u8;
-}
+impl Migrate for NcnOperatorState {}
 
-impl Discriminator for OperatorVaultTicket {
-    const DISCRIMINATOR: u8 = RestakingDiscriminator::OperatorVaultTicket as u8;
-}
+impl Migrate for OperatorVaultTicket {}
 
-impl Discriminator for NcnVaultTicket {
-    const DISCRIMINATOR: u8 = RestakingDiscriminator::NcnVaultTicket as u8;
-}
+impl Migrate for NcnVaultTicket {}
 
-impl Discriminator for NcnVaultSlasherTicket {
-    const DISCRIMINATOR: u8 = RestakingDiscriminator::NcnVaultSlasherTicket as u8;
-}
+impl Migrate for NcnVaultSlasherTicket {}
@@ -6,15 +6,25 @@
 use std::fmt::Debug;
 
 use bytemuck::{Pod, Zeroable};
-use jito_bytemuck::{types::PodU64, AccountDeserialize, Discriminator};
+use jito_bytemuck::{
+    types::{PodU16, PodU64},
+    AccountDeserialize, JitoAccount,
+};
+use jito_jsm_core::{diagnostic::Check, pause::PauseCategory};
 use jito_restaking_sdk::error::RestakingError;
 use shank::ShankAccount;
-use solana_program::{account_info::AccountInfo, msg, program_error::ProgramError, pubkey::Pubkey};
+use solana_program::{msg, pubkey::Pubkey};
 
-const RESERVED_SPACE_LEN: usize = 263;
+use crate::discriminators::RestakingDiscriminator;
+
+const RESERVED_SPACE_LEN: usize = 261;
 
 /// The NCN manages the operators, vaults, and slashers associated with a network
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Pod, Zeroable, AccountDeserialize, ShankAccount)]
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Pod, Zeroable, AccountDeserialize, JitoAccount, ShankAccount,
+)]
+#[discriminator(RestakingDiscriminator::Ncn)]
+#[account_seeds(b"ncn", self.base)]
 #[repr(C)]
 pub struct Ncn {
     /// The base account used as a PDA seed
@@ -59,8 +69,11 @@ pub struct Ncn {
     /// The bump seed for the PDA
     pub bump: u8,
 
+    /// Bitmask of paused instruction categories, see [`PauseCategory`]
+    paused_categories: PodU16,
+
     /// Reserved space
-    reserved: [u8; 263],
+    reserved: [u8; 261],
 }
 
 impl Ncn {
@@ -81,6 +94,7 @@ impl Ncn {
             vault_count: PodU64::from(0),
             slasher_count: PodU64::from(0),
             bump,
+            paused_categories: PodU16::from(0),
             reserved: [0; RESERVED_SPACE_LEN],
         }
     }
@@ -101,6 +115,26 @@ impl Ncn {
         self.slasher_count.into()
     }
 
+    pub fn paused_categories(&self) -> u16 {
+        self.paused_categories.into()
+    }
+
+    pub fn set_paused_categories(&mut self, paused_categories: u16) {
+        self.paused_categories = PodU16::from(paused_categories);
+    }
+
+    /// Checks whether an instruction category is paused on the NCN.
+    ///
+    /// # Errors
+    /// * [`RestakingError::NcnPaused`] - If the category is paused.
+    pub fn check_is_category_paused(&self, category: PauseCategory) -> Result<(), RestakingError> {
+        if category.is_paused(self.paused_categories()) {
+            msg!("NCN {:?} are currently paused", category);
+            return Err(RestakingError::NcnPaused);
+        }
+        Ok(())
+    }
+
     pub fn increment_operator_count(&mut self) -> Result<(), RestakingError> {
         let mut operator_count: u64 = self.operator_count.into();
         operator_count = operator_count
@@ -147,6 +181,7 @@ impl Ncn {
                 self.admin,
                 admin
             );
+            Check::Admin.log_failure(admin);
             return Err(RestakingError::NcnAdminInvalid);
         }
         Ok(())
@@ -171,6 +206,7 @@ impl Ncn {
                 self.delegate_admin,
                 delegate_admin
             );
+            Check::DelegateAdmin.log_failure(delegate_admin);
             return Err(RestakingError::NcnDelegateAdminInvalid);
         }
         Ok(())
@@ -217,73 +253,15 @@ impl Ncn {
             msg!("Ncn program admin set to {:?}", new_admin);
         }
     }
-
-    /// Returns the seeds for the PDA
-    pub fn seeds(base: &Pubkey) -> Vec<Vec<u8>> {
-        Vec::from_iter([b"ncn".to_vec(), base.as_ref().to_vec()])
-    }
-
-    /// Find the program address for the NCN account
-    ///
-    /// # Arguments
-    /// * `program_id` - The program ID
-    /// * `base` - The base account used as a PDA seed
-    ///
-    /// # Returns
-    /// * [`Pubkey`] - The program address
-    pub fn find_program_address(program_id: &Pubkey, base: &Pubkey) -> (Pubkey, u8, Vec<Vec<u8>>) {
-        let seeds = Self::seeds(base);
-        let seeds_iter: Vec<_> = seeds.iter().map(|s| s.as_slice()).collect();
-        let (pda, bump) = Pubkey::find_program_address(&seeds_iter, program_id);
-        (pda, bump, seeds)
-    }
-
-    /// Attempts to load the account as [`Ncn`], returning an error if it's not valid.
-    ///
-    /// # Arguments
-    /// * `program_id` - The program ID
-    /// * `account` - The account to load the NCN from
-    /// * `expect_writable` - Whether the account should be writable
-    ///
-    /// # Returns
-    /// * `Result<(), ProgramError>` - The result of the operation
-    pub fn load(
-        program_id: &Pubkey,
-        account: &AccountInfo,
-        expect_writable: bool,
-    ) -> Result<(), ProgramError> {
-        if account.owner.ne(program_id) {
-            msg!("NCN account has an invalid owner");
-            return Err(ProgramError::InvalidAccountOwner);
-        }
-        if account.data_is_empty() {
-            msg!("NCN account data is empty");
-            return Err(ProgramError::InvalidAccountData);
-        }
-        if expect_writable && !account.is_writable {
-            msg!("NCN account is not writable");
-            return Err(ProgramError::InvalidAccountData);
-        }
-        if account.data.borrow()[0].ne(&Self::DISCRIMINATOR) {
-            msg!("NCN account discriminator is invalid");
-            return Err(ProgramError::InvalidAccountData);
-        }
-        let base = Self::try_from_slice_unchecked(&account.data.borrow())?.base;
-        if account
-            .key
-            .ne(&Self::find_program_address(program_id, &base).0)
-        {
-            msg!("NCN account is not at the correct PDA");
-            return Err(ProgramError::InvalidAccountData);
-        }
-        Ok(())
-    }
 }
 
 #[cfg(test)]
 mod tests {
-    use jito_bytemuck::types::PodU64;
-    use solana_program::pubkey::Pubkey;
+    use jito_bytemuck::{
+        types::{PodU16, PodU64},
+        AccountDeserialize,
+    };
+    use solana_program::{account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey};
 
     use super::{Ncn, RESERVED_SPACE_LEN};
 
@@ -304,6 +282,7 @@ mod tests {
             std::mem::size_of::<PodU64>() + // vault_count
             std::mem::size_of::<PodU64>() + // slasher_count
             std::mem::size_of::<u8>() + // bump
+            std::mem::size_of::<PodU16>() + // paused_categories
             RESERVED_SPACE_LEN; // reserved
         assert_eq!(ncn_size, sum_of_fields);
     }
@@ -332,4 +311,45 @@ mod tests {
         assert_eq!(ncn.weight_table_admin, new_admin);
         assert_eq!(ncn.ncn_program_admin, new_admin);
     }
+
+    #[test]
+    fn test_ncn_load_checks_stored_base() {
+        let program_id = Pubkey::new_unique();
+        let base = Pubkey::new_unique();
+        let (address, bump, _) = Ncn::find_program_address(&program_id, &base);
+
+        let mut data = vec![0; 8 + std::mem::size_of::<Ncn>()];
+        Ncn::write_header(&mut data).unwrap();
+        data[8..].copy_from_slice(bytemuck::bytes_of(&Ncn::new(
+            base,
+            Pubkey::new_unique(),
+            0,
+            bump,
+        )));
+        let mut lamports = 0;
+        let ncn_info = AccountInfo::new(
+            &address,
+            false,
+            false,
+            &mut lamports,
+            &mut data,
+            &program_id,
+            false,
+            0,
+        );
+        Ncn::load(&program_id, &ncn_info, false).unwrap();
+        assert_eq!(
+            Ncn::load(&program_id, &ncn_info, true),
+            Err(ProgramError::InvalidAccountData)
+        );
+
+        // An NCN whose stored base doesn't derive its address is rejected
+        Ncn::try_from_slice_unchecked_mut(&mut ncn_info.data.borrow_mut())
+            .unwrap()
+            .base = Pubkey::new_unique();
+        assert_eq!(
+            Ncn::load(&program_id, &ncn_info, false),
+            Err(ProgramError::InvalidAccountData)
+        );
+    }
 }
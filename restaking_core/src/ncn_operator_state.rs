@@ -1,14 +1,24 @@
 use std::fmt::Debug;
 
 use bytemuck::{Pod, Zeroable};
-use jito_bytemuck::{types::PodU64, AccountDeserialize, Discriminator};
+use jito_bytemuck::{
+    types::{PodBool, PodU16, PodU64},
+    AccountDeserialize, JitoAccount,
+};
 use jito_jsm_core::slot_toggle::SlotToggle;
+use jito_restaking_sdk::error::RestakingError;
 use shank::ShankAccount;
-use solana_program::{account_info::AccountInfo, msg, program_error::ProgramError, pubkey::Pubkey};
+use solana_program::{msg, program_error::ProgramError, pubkey::Pubkey};
 
-const RESERVED_SPACE_LEN: usize = 263;
+use crate::discriminators::RestakingDiscriminator;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Pod, Zeroable, AccountDeserialize, ShankAccount)]
+const RESERVED_SPACE_LEN: usize = 252;
+
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Pod, Zeroable, AccountDeserialize, JitoAccount, ShankAccount,
+)]
+#[discriminator(RestakingDiscriminator::NcnOperatorState)]
+#[account_seeds(b"ncn_operator_state", ncn, operator)]
 #[repr(C)]
 pub struct NcnOperatorState {
     /// The NCN account
@@ -28,8 +38,17 @@ pub struct NcnOperatorState {
 
     pub bump: u8,
 
+    /// Whether the NCN has jailed the operator
+    is_jailed: PodBool,
+
+    /// The NCN-defined reason code for the most recent jailing
+    jail_reason: PodU16,
+
+    /// The slot the operator was most recently jailed at
+    jailed_slot: PodU64,
+
     /// Reserved space
-    reserved: [u8; 263],
+    reserved: [u8; 252],
 }
 
 impl NcnOperatorState {
@@ -41,6 +60,9 @@ impl NcnOperatorState {
             ncn_opt_in_state: SlotToggle::new(slot),
             operator_opt_in_state: SlotToggle::new(slot),
             bump,
+            is_jailed: PodBool::from_bool(false),
+            jail_reason: PodU16::from(0),
+            jailed_slot: PodU64::from(0),
             reserved: [0; RESERVED_SPACE_LEN],
         }
     }
@@ -49,66 +71,65 @@ impl NcnOperatorState {
         self.index.into()
     }
 
-    pub fn seeds(ncn: &Pubkey, operator: &Pubkey) -> Vec<Vec<u8>> {
-        Vec::from_iter([
-            b"ncn_operator_state".to_vec(),
-            ncn.to_bytes().to_vec(),
-            operator.to_bytes().to_vec(),
-        ])
+    pub fn is_jailed(&self) -> bool {
+        self.is_jailed.into()
     }
 
-    pub fn find_program_address(
-        program_id: &Pubkey,
-        ncn: &Pubkey,
-        operator: &Pubkey,
-    ) -> (Pubkey, u8, Vec<Vec<u8>>) {
-        let seeds = Self::seeds(ncn, operator);
-        let seeds_iter: Vec<_> = seeds.iter().map(|s| s.as_slice()).collect();
-        let (pda, bump) = Pubkey::find_program_address(&seeds_iter, program_id);
-        (pda, bump, seeds)
+    pub fn jail_reason(&self) -> u16 {
+        self.jail_reason.into()
     }
 
-    /// Loads the account as an [`NcnOperatorState`] account, returning an error if it is not.
+    pub fn jailed_slot(&self) -> u64 {
+        self.jailed_slot.into()
+    }
+
+    /// Jails the operator, recording the reason code and slot.
     ///
     /// # Arguments
-    /// * `program_id` - The program ID
-    /// * `ncn_operator_ticket` - The account to load the NCN operator ticket from
-    /// * `ncn` - The NCN account
-    /// * `operator` - The operator account
-    /// * `expect_writable` - Whether the account should be writable
+    /// * `reason` - The NCN-defined reason code
+    /// * `slot` - The current slot
     ///
-    /// # Returns
-    /// * `Result<(), ProgramError>` - The result of the operation
-    pub fn load(
-        program_id: &Pubkey,
-        ncn_operator_state: &AccountInfo,
-        ncn: &AccountInfo,
-        operator: &AccountInfo,
-        expect_writable: bool,
-    ) -> Result<(), ProgramError> {
-        if ncn_operator_state.owner.ne(program_id) {
-            msg!("NCNOperatorState account has an invalid owner");
-            return Err(ProgramError::InvalidAccountOwner);
-        }
-        if ncn_operator_state.data_is_empty() {
-            msg!("NCNOperatorState account data is empty");
-            return Err(ProgramError::InvalidAccountData);
-        }
-        if expect_writable && !ncn_operator_state.is_writable {
-            msg!("NCNOperatorState account is not writable");
-            return Err(ProgramError::InvalidAccountData);
+    /// # Errors
+    /// * [`RestakingError::NcnOperatorAlreadyJailed`] - The operator is already jailed
+    pub fn jail(&mut self, reason: u16, slot: u64) -> Result<(), RestakingError> {
+        if self.is_jailed() {
+            msg!("Operator is already jailed by the NCN");
+            return Err(RestakingError::NcnOperatorAlreadyJailed);
         }
-        if ncn_operator_state.data.borrow()[0].ne(&Self::DISCRIMINATOR) {
-            msg!("NCNOperatorState account discriminator is invalid");
-            return Err(ProgramError::InvalidAccountData);
-        }
-        let expected_pubkey = Self::find_program_address(program_id, ncn.key, operator.key).0;
-        if ncn_operator_state.key.ne(&expected_pubkey) {
-            msg!("NCNOperatorState account is not at the correct PDA");
-            return Err(ProgramError::InvalidAccountData);
+        self.is_jailed = PodBool::from_bool(true);
+        self.jail_reason = PodU16::from(reason);
+        self.jailed_slot = PodU64::from(slot);
+        Ok(())
+    }
+
+    /// Releases the operator from jail. The reason code and slot of the most recent
+    /// jailing are kept for reference.
+    ///
+    /// # Errors
+    /// * [`RestakingError::NcnOperatorNotJailed`] - The operator is not jailed
+    pub fn unjail(&mut self) -> Result<(), RestakingError> {
+        if !self.is_jailed() {
+            msg!("Operator is not jailed by the NCN");
+            return Err(RestakingError::NcnOperatorNotJailed);
         }
+        self.is_jailed = PodBool::from_bool(false);
         Ok(())
     }
+
+    /// Whether the operator should be counted by the NCN, which requires both the NCN
+    /// and the operator to have opted in and the operator to not be jailed. NCN programs
+    /// should use this when computing operator weights.
+    ///
+    /// # Arguments
+    /// * `slot` - The current slot
+    /// * `epoch_length` - The epoch length
+    pub fn is_active(&self, slot: u64, epoch_length: u64) -> Result<bool, ProgramError> {
+        if self.is_jailed() {
+            return Ok(false);
+        }
+        Ok(self.ncn_opt_in_state.is_active(slot, epoch_length)?
+            && self.operator_opt_in_state.is_active(slot, epoch_length)?)
+    }
 }
 
 #[cfg(test)]
@@ -126,6 +147,9 @@ mod tests {
             size_of::<SlotToggle>() + // ncn_opt_in_state
             size_of::<SlotToggle>() + // operator_opt_in_state
             size_of::<u8>() + // bump
+            size_of::<PodBool>() + // is_jailed
+            size_of::<PodU16>() + // jail_reason
+            size_of::<PodU64>() + // jailed_slot
             RESERVED_SPACE_LEN; // reserved
         assert_eq!(ncn_operator_state_size, sum_of_fields);
     }
@@ -150,4 +174,53 @@ mod tests {
             SlotToggleState::Inactive
         );
     }
+
+    #[test]
+    fn test_ncn_operator_state_jail_and_unjail() {
+        let mut ncn_operator_state =
+            NcnOperatorState::new(Pubkey::default(), Pubkey::default(), 0, 0, 1);
+        assert!(!ncn_operator_state.is_jailed());
+
+        ncn_operator_state.jail(7, 100).unwrap();
+        assert!(ncn_operator_state.is_jailed());
+        assert_eq!(ncn_operator_state.jail_reason(), 7);
+        assert_eq!(ncn_operator_state.jailed_slot(), 100);
+        assert_eq!(
+            ncn_operator_state.jail(8, 101),
+            Err(RestakingError::NcnOperatorAlreadyJailed)
+        );
+
+        ncn_operator_state.unjail().unwrap();
+        assert!(!ncn_operator_state.is_jailed());
+        assert_eq!(ncn_operator_state.jail_reason(), 7);
+        assert_eq!(ncn_operator_state.jailed_slot(), 100);
+        assert_eq!(
+            ncn_operator_state.unjail(),
+            Err(RestakingError::NcnOperatorNotJailed)
+        );
+    }
+
+    #[test]
+    fn test_ncn_operator_state_jailed_is_not_active() {
+        let epoch_length = 100;
+        let mut ncn_operator_state =
+            NcnOperatorState::new(Pubkey::default(), Pubkey::default(), 0, 0, 0);
+        ncn_operator_state
+            .ncn_opt_in_state
+            .activate(1, epoch_length)
+            .unwrap();
+        ncn_operator_state
+            .operator_opt_in_state
+            .activate(1, epoch_length)
+            .unwrap();
+
+        let slot = epoch_length * 3;
+        assert!(ncn_operator_state.is_active(slot, epoch_length).unwrap());
+
+        ncn_operator_state.jail(1, slot).unwrap();
+        assert!(!ncn_operator_state.is_active(slot, epoch_length).unwrap());
+
+        ncn_operator_state.unjail().unwrap();
+        assert!(ncn_operator_state.is_active(slot, epoch_length).unwrap());
+    }
 }
@@ -2,10 +2,12 @@
 //! The NcnVaultSlasherTicket can be activated and deactivated over time by the NCN slasher admin.
 
 use bytemuck::{Pod, Zeroable};
-use jito_bytemuck::{types::PodU64, AccountDeserialize, Discriminator};
+use jito_bytemuck::{types::PodU64, AccountDeserialize, JitoAccount};
 use jito_jsm_core::slot_toggle::SlotToggle;
 use shank::ShankAccount;
-use solana_program::{account_info::AccountInfo, msg, program_error::ProgramError, pubkey::Pubkey};
+use solana_program::pubkey::Pubkey;
+
+use crate::discriminators::RestakingDiscriminator;
 
 const RESERVED_SPACE_LEN: usize = 263;
 
@@ -13,7 +15,11 @@ const RESERVED_SPACE_LEN: usize = 263;
 /// opting-in to a vault slasher. The NcnVaultSlasherTicket can be activated and deactivated over time.
 /// The NcnVaultSlasherTicket can slash a specific operator that's receiving delegation from a
 /// vault for a maximum amount per epoch.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Pod, Zeroable, AccountDeserialize, ShankAccount)]
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Pod, Zeroable, AccountDeserialize, JitoAccount, ShankAccount,
+)]
+#[discriminator(RestakingDiscriminator::NcnVaultSlasherTicket)]
+#[account_seeds(b"ncn_slasher_ticket", ncn, vault, slasher)]
 #[repr(C)]
 pub struct NcnVaultSlasherTicket {
     /// The NCN
@@ -81,93 +87,6 @@ impl NcnVaultSlasherTicket {
     pub fn max_slashable_per_epoch(&self) -> u64 {
         self.max_slashable_per_epoch.into()
     }
-
-    /// Returns the seeds for the PDA
-    ///
-    /// # Arguments
-    /// * `ncn` - The node consensus network
-    /// * `vault` - The vault account this slasher can slash
-    /// * `slasher` - The slasher signer
-    ///
-    /// # Returns
-    /// * `Vec<Vec<u8>>` - The seeds used to generate the PDA
-    pub fn seeds(ncn: &Pubkey, vault: &Pubkey, slasher: &Pubkey) -> Vec<Vec<u8>> {
-        Vec::from_iter([
-            b"ncn_slasher_ticket".to_vec(),
-            ncn.as_ref().to_vec(),
-            vault.as_ref().to_vec(),
-            slasher.as_ref().to_vec(),
-        ])
-    }
-
-    /// Find the program address for the NcnVaultSlasherTicket
-    ///
-    /// # Arguments
-    /// * `program_id` - The program ID
-    /// * `ncn` - The node consensus network
-    /// * `vault` - The vault account this slasher can slash
-    /// * `slasher` - The slasher signer
-    ///
-    /// # Returns
-    /// * `Pubkey` - The program address
-    /// * `u8` - The bump seed
-    /// * `Vec<Vec<u8>>` - The seeds used to generate the PDA
-    pub fn find_program_address(
-        program_id: &Pubkey,
-        ncn: &Pubkey,
-        vault: &Pubkey,
-        slasher: &Pubkey,
-    ) -> (Pubkey, u8, Vec<Vec<u8>>) {
-        let seeds = Self::seeds(ncn, vault, slasher);
-        let seeds_iter: Vec<_> = seeds.iter().map(|s| s.as_slice()).collect();
-        let (pda, bump) = Pubkey::find_program_address(&seeds_iter, program_id);
-        (pda, bump, seeds)
-    }
-
-    /// Loads the account as an [`NcnVaultSlasherTicket`] account, returning an error if it is not.
-    ///
-    /// # Arguments
-    /// * `program_id` - The program ID
-    /// * `ncn_vault_slasher_ticket` - The account to load the NCN vault slasher ticket from
-    /// * `ncn` - The NCN account
-    /// * `vault` - The vault account
-    /// * `slasher` - The slasher account
-    /// * `expect_writable` - Whether the account should be writable
-    ///
-    /// # Returns
-    /// * `Result<(), ProgramError>` - The result of the operation
-    pub fn load(
-        program_id: &Pubkey,
-        ncn_vault_slasher_ticket: &AccountInfo,
-        ncn: &AccountInfo,
-        vault: &AccountInfo,
-        slasher: &AccountInfo,
-        expect_writable: bool,
-    ) -> Result<(), ProgramError> {
-        if ncn_vault_slasher_ticket.owner.ne(program_id) {
-            msg!("NCN vault slasher ticket account has an invalid owner");
-            return Err(ProgramError::InvalidAccountOwner);
-        }
-        if ncn_vault_slasher_ticket.data_is_empty() {
-            msg!("NCN vault slasher ticket account data is empty");
-            return Err(ProgramError::InvalidAccountData);
-        }
-        if expect_writable && !ncn_vault_slasher_ticket.is_writable {
-            msg!("NCN vault slasher ticket account is not writable");
-            return Err(ProgramError::InvalidAccountData);
-        }
-        if ncn_vault_slasher_ticket.data.borrow()[0].ne(&Self::DISCRIMINATOR) {
-            msg!("NCN vault slasher ticket account discriminator is invalid");
-            return Err(ProgramError::InvalidAccountData);
-        }
-        let expected_pubkey =
-            Self::find_program_address(program_id, ncn.key, vault.key, slasher.key).0;
-        if ncn_vault_slasher_ticket.key.ne(&expected_pubkey) {
-            msg!("NCN vault slasher ticket account is not at the correct PDA");
-            return Err(ProgramError::InvalidAccountData);
-        }
-        Ok(())
-    }
 }
 
 #[cfg(test)]
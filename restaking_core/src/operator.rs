@@ -6,17 +6,24 @@ use std::fmt::Debug;
 use bytemuck::{Pod, Zeroable};
 use jito_bytemuck::{
     types::{PodU16, PodU64},
-    AccountDeserialize, Discriminator,
+    AccountDeserialize, JitoAccount,
 };
+use jito_jsm_core::{diagnostic::Check, pause::PauseCategory};
 use jito_restaking_sdk::error::RestakingError;
 use shank::ShankAccount;
-use solana_program::{account_info::AccountInfo, msg, program_error::ProgramError, pubkey::Pubkey};
+use solana_program::{msg, pubkey::Pubkey};
 
-const RESERVED_SPACE_LEN: usize = 261;
+use crate::discriminators::RestakingDiscriminator;
+
+const RESERVED_SPACE_LEN: usize = 251;
 
 /// The Operator account stores global information for a particular operator
 /// including the admin, voter, and the number of NCN and vault accounts.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Pod, Zeroable, AccountDeserialize, ShankAccount)]
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Pod, Zeroable, AccountDeserialize, JitoAccount, ShankAccount,
+)]
+#[discriminator(RestakingDiscriminator::Operator)]
+#[account_seeds(b"operator", self.base)]
 #[repr(C)]
 pub struct Operator {
     /// The base pubkey used as a seed for the PDA
@@ -58,8 +65,15 @@ pub struct Operator {
     /// The bump seed for the PDA
     pub bump: u8,
 
+    /// The number of NCNs currently jailing the operator.
+    /// Vaults check this before delegating to the operator.
+    jailed_ncn_count: PodU64,
+
+    /// Bitmask of paused instruction categories, see [`PauseCategory`]
+    paused_categories: PodU16,
+
     /// Reserved space
-    reserved_space: [u8; 261],
+    reserved_space: [u8; 251],
 }
 
 impl Operator {
@@ -83,6 +97,8 @@ impl Operator {
             vault_count: PodU64::from(0),
             operator_fee_bps: PodU16::from(operator_fee_bps),
             bump,
+            jailed_ncn_count: PodU64::from(0),
+            paused_categories: PodU16::from(0),
             reserved_space: [0; RESERVED_SPACE_LEN],
         }
     }
@@ -99,6 +115,30 @@ impl Operator {
         self.vault_count.into()
     }
 
+    pub fn jailed_ncn_count(&self) -> u64 {
+        self.jailed_ncn_count.into()
+    }
+
+    pub fn paused_categories(&self) -> u16 {
+        self.paused_categories.into()
+    }
+
+    pub fn set_paused_categories(&mut self, paused_categories: u16) {
+        self.paused_categories = PodU16::from(paused_categories);
+    }
+
+    /// Checks whether an instruction category is paused on the Operator.
+    ///
+    /// # Errors
+    /// * [`RestakingError::OperatorPaused`] - If the category is paused.
+    pub fn check_is_category_paused(&self, category: PauseCategory) -> Result<(), RestakingError> {
+        if category.is_paused(self.paused_categories()) {
+            msg!("Operator {:?} are currently paused", category);
+            return Err(RestakingError::OperatorPaused);
+        }
+        Ok(())
+    }
+
     pub fn increment_ncn_count(&mut self) -> Result<(), RestakingError> {
         let mut ncn_count: u64 = self.ncn_count.into();
         ncn_count = ncn_count
@@ -117,6 +157,24 @@ impl Operator {
         Ok(())
     }
 
+    pub fn increment_jailed_ncn_count(&mut self) -> Result<(), RestakingError> {
+        let mut jailed_ncn_count: u64 = self.jailed_ncn_count.into();
+        jailed_ncn_count = jailed_ncn_count
+            .checked_add(1)
+            .ok_or(RestakingError::ArithmeticOverflow)?;
+        self.jailed_ncn_count = PodU64::from(jailed_ncn_count);
+        Ok(())
+    }
+
+    pub fn decrement_jailed_ncn_count(&mut self) -> Result<(), RestakingError> {
+        let mut jailed_ncn_count: u64 = self.jailed_ncn_count.into();
+        jailed_ncn_count = jailed_ncn_count
+            .checked_sub(1)
+            .ok_or(RestakingError::ArithmeticUnderflow)?;
+        self.jailed_ncn_count = PodU64::from(jailed_ncn_count);
+        Ok(())
+    }
+
     /// Validates the admin account and ensures it matches the expected admin.
     ///
     /// # Arguments
@@ -136,6 +194,7 @@ impl Operator {
                 self.admin,
                 admin
             );
+            Check::Admin.log_failure(admin);
             return Err(RestakingError::OperatorAdminInvalid);
         }
         Ok(())
@@ -160,6 +219,7 @@ impl Operator {
                 self.delegate_admin,
                 delegate_admin
             );
+            Check::DelegateAdmin.log_failure(delegate_admin);
             return Err(RestakingError::OperatorDelegateAdminInvalid);
         }
         Ok(())
@@ -196,80 +256,13 @@ impl Operator {
             msg!("Metadata admin set to {:?}", new_admin);
         }
     }
-
-    /// Returns the seeds for the PDA
-    ///
-    /// # Arguments
-    /// * `base` - The base account used as a PDA seed
-    ///
-    /// # Returns
-    /// * `Vec<Vec<u8>>` - The seeds used to generate the PDA
-    pub fn seeds(base: &Pubkey) -> Vec<Vec<u8>> {
-        Vec::from_iter([b"operator".to_vec(), base.as_ref().to_vec()])
-    }
-
-    /// Find the program address for the Operator account
-    ///
-    /// # Arguments
-    /// * `program_id` - The program ID
-    /// * `base` - The base account used as a PDA seed
-    ///
-    /// # Returns
-    /// * `Pubkey` - The program address
-    /// * `u8` - The bump seed
-    /// * `Vec<Vec<u8>>` - The seeds used to generate the PDA
-    pub fn find_program_address(program_id: &Pubkey, base: &Pubkey) -> (Pubkey, u8, Vec<Vec<u8>>) {
-        let seeds = Self::seeds(base);
-        let seeds_iter: Vec<_> = seeds.iter().map(|s| s.as_slice()).collect();
-        let (pda, bump) = Pubkey::find_program_address(&seeds_iter, program_id);
-        (pda, bump, seeds)
-    }
-
-    /// Attempts to load the account as [`Operator`], returning an error if it's not valid.
-    ///
-    /// # Arguments
-    /// * `program_id` - The program ID
-    /// * `account` - The account to load the operator from
-    /// * `expect_writable` - Whether the account should be writable
-    ///
-    /// # Returns
-    /// * `Result<(), ProgramError>` - The result of the operation
-    pub fn load(
-        program_id: &Pubkey,
-        account: &AccountInfo,
-        expect_writable: bool,
-    ) -> Result<(), ProgramError> {
-        if account.owner.ne(program_id) {
-            msg!("Operator account has an invalid owner");
-            return Err(ProgramError::InvalidAccountOwner);
-        }
-        if account.data_is_empty() {
-            msg!("Operator account data is empty");
-            return Err(ProgramError::InvalidAccountData);
-        }
-        if expect_writable && !account.is_writable {
-            msg!("Operator account is not writable");
-            return Err(ProgramError::InvalidAccountData);
-        }
-        if account.data.borrow()[0].ne(&Self::DISCRIMINATOR) {
-            msg!("Operator account discriminator is invalid");
-            return Err(ProgramError::InvalidAccountData);
-        }
-        let base = Self::try_from_slice_unchecked(&account.data.borrow())?.base;
-        if account
-            .key
-            .ne(&Self::find_program_address(program_id, &base).0)
-        {
-            msg!("Operator account is not at the correct PDA");
-            return Err(ProgramError::InvalidAccountData);
-        }
-        Ok(())
-    }
 }
 
 #[cfg(test)]
 mod tests {
     use jito_bytemuck::types::{PodU16, PodU64};
+    use jito_jsm_core::pause::PauseCategory;
+    use jito_restaking_sdk::error::RestakingError;
     use solana_program::pubkey::Pubkey;
 
     use super::{Operator, RESERVED_SPACE_LEN};
@@ -289,10 +282,26 @@ mod tests {
             std::mem::size_of::<PodU64>() + // vault_count
             std::mem::size_of::<PodU16>() + // operator_fee_bps
             std::mem::size_of::<u8>() + // bump
+            std::mem::size_of::<PodU64>() + // jailed_ncn_count
+            std::mem::size_of::<PodU16>() + // paused_categories
             RESERVED_SPACE_LEN; // reserved
         assert_eq!(operator_size, sum_of_fields);
     }
 
+    #[test]
+    fn test_jailed_ncn_count() {
+        let mut operator = Operator::new(Pubkey::new_unique(), Pubkey::new_unique(), 0, 0, 0);
+        assert_eq!(operator.jailed_ncn_count(), 0);
+        assert!(operator.decrement_jailed_ncn_count().is_err());
+
+        operator.increment_jailed_ncn_count().unwrap();
+        operator.increment_jailed_ncn_count().unwrap();
+        assert_eq!(operator.jailed_ncn_count(), 2);
+
+        operator.decrement_jailed_ncn_count().unwrap();
+        assert_eq!(operator.jailed_ncn_count(), 1);
+    }
+
     #[test]
     fn test_update_secondary_admin_ok() {
         let old_admin = Pubkey::new_unique();
@@ -312,4 +321,23 @@ mod tests {
         assert_eq!(operator.delegate_admin, new_admin);
         assert_eq!(operator.metadata_admin, new_admin);
     }
+
+    #[test]
+    fn test_check_is_category_paused() {
+        let mut operator = Operator::new(Pubkey::new_unique(), Pubkey::new_unique(), 0, 0, 0);
+        assert_eq!(
+            operator.check_is_category_paused(PauseCategory::FeeChanges),
+            Ok(())
+        );
+
+        operator.set_paused_categories(PauseCategory::FeeChanges.mask());
+        assert_eq!(
+            operator.check_is_category_paused(PauseCategory::FeeChanges),
+            Err(RestakingError::OperatorPaused)
+        );
+        assert_eq!(
+            operator.check_is_category_paused(PauseCategory::TicketChanges),
+            Ok(())
+        );
+    }
 }
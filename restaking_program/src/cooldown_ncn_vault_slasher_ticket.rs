@@ -1,5 +1,5 @@
 use jito_bytemuck::AccountDeserialize;
-use jito_jsm_core::loader::load_signer;
+use jito_jsm_core::{diagnostic::Check, loader::load_signer, pause::PauseCategory};
 use jito_restaking_core::{
     config::Config, ncn::Ncn, ncn_vault_slasher_ticket::NcnVaultSlasherTicket,
 };
@@ -10,6 +10,8 @@ use solana_program::{
     program_error::ProgramError, pubkey::Pubkey, sysvar::Sysvar,
 };
 
+use crate::pause::check_is_category_paused;
+
 /// [`crate::RestakingInstruction::CooldownNcnVaultSlasherTicket`]
 pub fn process_cooldown_ncn_vault_slasher_ticket(
     program_id: &Pubkey,
@@ -21,6 +23,7 @@ pub fn process_cooldown_ncn_vault_slasher_ticket(
     };
     Config::load(program_id, config, false)?;
     Ncn::load(program_id, ncn, false)?;
+    check_is_category_paused(config, Some(ncn), None, PauseCategory::TicketChanges)?;
     let config_data = config.data.borrow();
     let config = Config::try_from_slice_unchecked(&config_data)?;
     Vault::load(&config.vault_program, vault, false)?;
@@ -39,6 +42,7 @@ pub fn process_cooldown_ncn_vault_slasher_ticket(
     let ncn = Ncn::try_from_slice_unchecked(&ncn_data)?;
     if ncn.slasher_admin.ne(ncn_slasher_admin.key) {
         msg!("Invalid slasher admin for NCN");
+        Check::SlasherAdmin.log_failure(ncn_slasher_admin.key);
         return Err(RestakingError::NcnSlasherAdminInvalid.into());
     }
 
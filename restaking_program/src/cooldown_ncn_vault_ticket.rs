@@ -1,5 +1,5 @@
 use jito_bytemuck::AccountDeserialize;
-use jito_jsm_core::loader::load_signer;
+use jito_jsm_core::{diagnostic::Check, loader::load_signer, pause::PauseCategory};
 use jito_restaking_core::{config::Config, ncn::Ncn, ncn_vault_ticket::NcnVaultTicket};
 use jito_restaking_sdk::error::RestakingError;
 use solana_program::{
@@ -7,6 +7,8 @@ use solana_program::{
     program_error::ProgramError, pubkey::Pubkey, sysvar::Sysvar,
 };
 
+use crate::pause::check_is_category_paused;
+
 /// [`crate::RestakingInstruction::CooldownNcnVaultTicket`]
 pub fn process_cooldown_ncn_vault_ticket(
     program_id: &Pubkey,
@@ -20,12 +22,14 @@ pub fn process_cooldown_ncn_vault_ticket(
     Ncn::load(program_id, ncn, false)?;
     NcnVaultTicket::load(program_id, ncn_vault_ticket, ncn, vault, true)?;
     load_signer(ncn_vault_admin, false)?;
+    check_is_category_paused(config, Some(ncn), None, PauseCategory::TicketChanges)?;
 
     // The NCN vault admin shall be the signer of the transaction
     let ncn_data = ncn.data.borrow();
     let ncn = Ncn::try_from_slice_unchecked(&ncn_data)?;
     if ncn.vault_admin.ne(ncn_vault_admin.key) {
         msg!("Invalid vault admin for NCN");
+        Check::VaultAdmin.log_failure(ncn_vault_admin.key);
         return Err(RestakingError::NcnVaultAdminInvalid.into());
     }
 
@@ -1,5 +1,5 @@
 use jito_bytemuck::AccountDeserialize;
-use jito_jsm_core::loader::load_signer;
+use jito_jsm_core::{diagnostic::Check, loader::load_signer, pause::PauseCategory};
 use jito_restaking_core::{
     config::Config, operator::Operator, operator_vault_ticket::OperatorVaultTicket,
 };
@@ -10,6 +10,8 @@ use solana_program::{
     program_error::ProgramError, pubkey::Pubkey, sysvar::Sysvar,
 };
 
+use crate::pause::check_is_category_paused;
+
 /// [`crate::RestakingInstruction::CooldownOperatorVaultTicket`]
 pub fn process_cooldown_operator_vault_ticket(
     program_id: &Pubkey,
@@ -21,6 +23,7 @@ pub fn process_cooldown_operator_vault_ticket(
 
     Config::load(program_id, config, false)?;
     Operator::load(program_id, operator, false)?;
+    check_is_category_paused(config, None, Some(operator), PauseCategory::TicketChanges)?;
     let config_data = config.data.borrow();
     let config = Config::try_from_slice_unchecked(&config_data)?;
     Vault::load(&config.vault_program, vault, false)?;
@@ -32,6 +35,7 @@ pub fn process_cooldown_operator_vault_ticket(
     let operator = Operator::try_from_slice_unchecked(&operator_data)?;
     if operator.vault_admin.ne(operator_vault_admin.key) {
         msg!("Invalid operator vault admin");
+        Check::VaultAdmin.log_failure(operator_vault_admin.key);
         return Err(RestakingError::OperatorVaultAdminInvalid.into());
     }
 
@@ -1,8 +1,9 @@
 use std::mem::size_of;
 
-use jito_bytemuck::{AccountDeserialize, Discriminator};
+use jito_bytemuck::AccountDeserialize;
 use jito_jsm_core::{
     create_account,
+    diagnostic::Check,
     loader::{load_signer, load_system_account, load_system_program},
 };
 use jito_restaking_core::config::Config;
@@ -28,6 +29,7 @@ pub fn process_initialize_config(program_id: &Pubkey, accounts: &[AccountInfo])
     config_seeds.push(vec![config_bump]);
     if config.key.ne(&config_pubkey) {
         msg!("Config account is not at the correct PDA");
+        Check::Address.log_failure(config.key);
         return Err(ProgramError::InvalidAccountData);
     }
 
@@ -45,7 +47,7 @@ pub fn process_initialize_config(program_id: &Pubkey, accounts: &[AccountInfo])
     )?;
 
     let mut config_data = config.try_borrow_mut_data()?;
-    config_data[0] = Config::DISCRIMINATOR;
+    Config::write_header(&mut config_data)?;
     let config = Config::try_from_slice_unchecked_mut(&mut config_data)?;
     *config = Config::new(*admin.key, *vault_program.key, config_bump);
 
@@ -1,8 +1,9 @@
 use std::mem::size_of;
 
-use jito_bytemuck::{AccountDeserialize, Discriminator};
+use jito_bytemuck::AccountDeserialize;
 use jito_jsm_core::{
     create_account,
+    diagnostic::Check,
     loader::{load_signer, load_system_account, load_system_program},
 };
 use jito_restaking_core::{config::Config, ncn::Ncn};
@@ -29,6 +30,7 @@ pub fn process_initialize_ncn(program_id: &Pubkey, accounts: &[AccountInfo]) ->
     ncn_seeds.push(vec![ncn_bump]);
     if ncn.key.ne(&ncn_pubkey) {
         msg!("NCN account is not at the correct PDA");
+        Check::Address.log_failure(ncn.key);
         return Err(ProgramError::InvalidAccountData);
     }
 
@@ -49,7 +51,7 @@ pub fn process_initialize_ncn(program_id: &Pubkey, accounts: &[AccountInfo]) ->
     let config = Config::try_from_slice_unchecked_mut(&mut config_data)?;
 
     let mut ncn_data = ncn.try_borrow_mut_data()?;
-    ncn_data[0] = Ncn::DISCRIMINATOR;
+    Ncn::write_header(&mut ncn_data)?;
     let ncn = Ncn::try_from_slice_unchecked_mut(&mut ncn_data)?;
     *ncn = Ncn::new(*base.key, *admin.key, config.ncn_count(), ncn_bump);
 
@@ -1,9 +1,11 @@
 use std::mem::size_of;
 
-use jito_bytemuck::{AccountDeserialize, Discriminator};
+use jito_bytemuck::AccountDeserialize;
 use jito_jsm_core::{
     create_account,
+    diagnostic::Check,
     loader::{load_signer, load_system_account, load_system_program},
+    pause::PauseCategory,
 };
 use jito_restaking_core::{
     config::Config, ncn::Ncn, ncn_operator_state::NcnOperatorState, operator::Operator,
@@ -14,6 +16,8 @@ use solana_program::{
     program_error::ProgramError, pubkey::Pubkey, rent::Rent, sysvar::Sysvar,
 };
 
+use crate::pause::check_is_category_paused;
+
 /// After an operator opts-in to an NCN, the NCN operator admin can add the operator to the NCN.
 /// The operator must have opted-in to the NCN before the NCN opts-in to the operator.
 ///
@@ -35,6 +39,12 @@ pub fn process_initialize_ncn_operator_state(
     load_signer(ncn_operator_admin, false)?;
     load_signer(payer, true)?;
     load_system_program(system_program)?;
+    check_is_category_paused(
+        config,
+        Some(ncn_info),
+        Some(operator),
+        PauseCategory::TicketChanges,
+    )?;
 
     // The NcnOperatorState shall be at the canonical PDA
     let (ncn_operator_state_pubkey, ncn_operator_state_bump, mut ncn_operator_state_seeds) =
@@ -42,6 +52,7 @@ pub fn process_initialize_ncn_operator_state(
     ncn_operator_state_seeds.push(vec![ncn_operator_state_bump]);
     if ncn_operator_state_pubkey.ne(ncn_operator_state.key) {
         msg!("NcnOperatorState is not at the correct PDA");
+        Check::Address.log_failure(ncn_operator_state.key);
         return Err(ProgramError::InvalidAccountData);
     }
 
@@ -50,6 +61,7 @@ pub fn process_initialize_ncn_operator_state(
     let ncn = Ncn::try_from_slice_unchecked_mut(&mut ncn_data)?;
     if ncn.operator_admin.ne(ncn_operator_admin.key) {
         msg!("Invalid operator admin for NCN");
+        Check::OperatorAdmin.log_failure(ncn_operator_admin.key);
         return Err(RestakingError::NcnOperatorAdminInvalid.into());
     }
 
@@ -67,7 +79,7 @@ pub fn process_initialize_ncn_operator_state(
     )?;
 
     let mut ncn_operator_state_data = ncn_operator_state.try_borrow_mut_data()?;
-    ncn_operator_state_data[0] = NcnOperatorState::DISCRIMINATOR;
+    NcnOperatorState::write_header(&mut ncn_operator_state_data)?;
     let ncn_operator_state =
         NcnOperatorState::try_from_slice_unchecked_mut(&mut ncn_operator_state_data)?;
     *ncn_operator_state = NcnOperatorState::new(
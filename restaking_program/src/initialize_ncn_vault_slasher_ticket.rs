@@ -1,9 +1,11 @@
 use std::mem::size_of;
 
-use jito_bytemuck::{AccountDeserialize, Discriminator};
+use jito_bytemuck::AccountDeserialize;
 use jito_jsm_core::{
     create_account,
+    diagnostic::Check,
     loader::{load_signer, load_system_account, load_system_program},
+    pause::PauseCategory,
 };
 use jito_restaking_core::{
     config::Config, ncn::Ncn, ncn_vault_slasher_ticket::NcnVaultSlasherTicket,
@@ -16,6 +18,8 @@ use solana_program::{
     program_error::ProgramError, pubkey::Pubkey, rent::Rent, sysvar::Sysvar,
 };
 
+use crate::pause::check_is_category_paused;
+
 pub fn process_initialize_ncn_vault_slasher_ticket(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
@@ -29,6 +33,7 @@ pub fn process_initialize_ncn_vault_slasher_ticket(
 
     Config::load(program_id, config, false)?;
     Ncn::load(program_id, ncn_info, true)?;
+    check_is_category_paused(config, Some(ncn_info), None, PauseCategory::TicketChanges)?;
     let config_data = config.data.borrow();
     let config = Config::try_from_slice_unchecked(&config_data)?;
     Vault::load(&config.vault_program, vault, false)?;
@@ -55,6 +60,7 @@ pub fn process_initialize_ncn_vault_slasher_ticket(
         .ne(&ncn_vault_slasher_ticket_pubkey)
     {
         msg!("Ncn vault slasher ticket account is not at the correct PDA");
+        Check::Address.log_failure(ncn_vault_slasher_ticket.key);
         return Err(ProgramError::InvalidAccountData);
     }
 
@@ -62,6 +68,7 @@ pub fn process_initialize_ncn_vault_slasher_ticket(
     let ncn = Ncn::try_from_slice_unchecked_mut(&mut ncn_data)?;
     if ncn.slasher_admin.ne(ncn_slasher_admin.key) {
         msg!("Admin is not the slasher admin");
+        Check::SlasherAdmin.log_failure(ncn_slasher_admin.key);
         return Err(RestakingError::NcnSlasherAdminInvalid.into());
     }
 
@@ -81,7 +88,7 @@ pub fn process_initialize_ncn_vault_slasher_ticket(
         &ncn_vault_slasher_ticket_seeds,
     )?;
     let mut ncn_vault_slasher_ticket_data = ncn_vault_slasher_ticket.try_borrow_mut_data()?;
-    ncn_vault_slasher_ticket_data[0] = NcnVaultSlasherTicket::DISCRIMINATOR;
+    NcnVaultSlasherTicket::write_header(&mut ncn_vault_slasher_ticket_data)?;
     let ncn_vault_slasher_ticket =
         NcnVaultSlasherTicket::try_from_slice_unchecked_mut(&mut ncn_vault_slasher_ticket_data)?;
     *ncn_vault_slasher_ticket = NcnVaultSlasherTicket::new(
@@ -1,9 +1,11 @@
 use std::mem::size_of;
 
-use jito_bytemuck::{AccountDeserialize, Discriminator};
+use jito_bytemuck::AccountDeserialize;
 use jito_jsm_core::{
     create_account,
+    diagnostic::Check,
     loader::{load_signer, load_system_account, load_system_program},
+    pause::PauseCategory,
 };
 use jito_restaking_core::{config::Config, ncn::Ncn, ncn_vault_ticket::NcnVaultTicket};
 use jito_restaking_sdk::error::RestakingError;
@@ -13,6 +15,8 @@ use solana_program::{
     program_error::ProgramError, pubkey::Pubkey, rent::Rent, sysvar::Sysvar,
 };
 
+use crate::pause::check_is_category_paused;
+
 /// The NCN opts-in to vaults by storing the vault in the NCN vault list. It also CPI's into
 /// the vault program and adds the NCN to the vault's NCN list.
 ///
@@ -29,6 +33,7 @@ pub fn process_initialize_ncn_vault_ticket(
 
     Config::load(program_id, config, false)?;
     Ncn::load(program_id, ncn_info, true)?;
+    check_is_category_paused(config, Some(ncn_info), None, PauseCategory::TicketChanges)?;
     let config_data = config.data.borrow();
     let config = Config::try_from_slice_unchecked(&config_data)?;
     Vault::load(&config.vault_program, vault, false)?;
@@ -43,6 +48,7 @@ pub fn process_initialize_ncn_vault_ticket(
     ncn_vault_ticket_seeds.push(vec![ncn_vault_ticket_bump]);
     if ncn_vault_ticket_pubkey.ne(ncn_vault_ticket.key) {
         msg!("NCN vault ticket is not at the correct PDA");
+        Check::Address.log_failure(ncn_vault_ticket.key);
         return Err(ProgramError::InvalidAccountData);
     }
 
@@ -50,6 +56,7 @@ pub fn process_initialize_ncn_vault_ticket(
     let ncn = Ncn::try_from_slice_unchecked_mut(&mut ncn_data)?;
     if ncn.vault_admin.ne(ncn_vault_admin.key) {
         msg!("Invalid vault admin for NCN");
+        Check::VaultAdmin.log_failure(ncn_vault_admin.key);
         return Err(RestakingError::NcnVaultAdminInvalid.into());
     }
 
@@ -70,7 +77,7 @@ pub fn process_initialize_ncn_vault_ticket(
     )?;
 
     let mut ncn_vault_ticket_data = ncn_vault_ticket.try_borrow_mut_data()?;
-    ncn_vault_ticket_data[0] = NcnVaultTicket::DISCRIMINATOR;
+    NcnVaultTicket::write_header(&mut ncn_vault_ticket_data)?;
     let ncn_vault_ticket =
         NcnVaultTicket::try_from_slice_unchecked_mut(&mut ncn_vault_ticket_data)?;
     *ncn_vault_ticket = NcnVaultTicket::new(
@@ -1,8 +1,9 @@
 use std::mem::size_of;
 
-use jito_bytemuck::{AccountDeserialize, Discriminator};
+use jito_bytemuck::AccountDeserialize;
 use jito_jsm_core::{
     create_account,
+    diagnostic::Check,
     loader::{load_signer, load_system_account, load_system_program},
 };
 use jito_restaking_core::{config::Config, operator::Operator, MAX_FEE_BPS};
@@ -34,6 +35,7 @@ pub fn process_initialize_operator(
     operator_seed.push(vec![operator_bump]);
     if operator.key.ne(&operator_pubkey) {
         msg!("Operator account is not at the correct PDA");
+        Check::Address.log_failure(operator.key);
         return Err(ProgramError::InvalidAccountData);
     }
     // Check that the fee is not greater than the maximum allowed
@@ -59,7 +61,7 @@ pub fn process_initialize_operator(
     let config = Config::try_from_slice_unchecked_mut(&mut config_data)?;
 
     let mut operator_data = operator.try_borrow_mut_data()?;
-    operator_data[0] = Operator::DISCRIMINATOR;
+    Operator::write_header(&mut operator_data)?;
     let operator = Operator::try_from_slice_unchecked_mut(&mut operator_data)?;
 
     *operator = Operator::new(
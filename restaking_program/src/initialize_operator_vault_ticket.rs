@@ -1,9 +1,11 @@
 use std::mem::size_of;
 
-use jito_bytemuck::{AccountDeserialize, Discriminator};
+use jito_bytemuck::AccountDeserialize;
 use jito_jsm_core::{
     create_account,
+    diagnostic::Check,
     loader::{load_signer, load_system_account, load_system_program},
+    pause::PauseCategory,
 };
 use jito_restaking_core::{
     config::Config, operator::Operator, operator_vault_ticket::OperatorVaultTicket,
@@ -15,6 +17,8 @@ use solana_program::{
     program_error::ProgramError, pubkey::Pubkey, rent::Rent, sysvar::Sysvar,
 };
 
+use crate::pause::check_is_category_paused;
+
 /// The node operator admin can add support for receiving delegation from a vault.
 /// The vault can be used at the end of epoch + 1.
 /// This method is permissioned to the node operator admin.
@@ -32,6 +36,12 @@ pub fn process_initialize_operator_vault_ticket(
 
     Config::load(program_id, config, false)?;
     Operator::load(program_id, operator_info, true)?;
+    check_is_category_paused(
+        config,
+        None,
+        Some(operator_info),
+        PauseCategory::TicketChanges,
+    )?;
     let config_data = config.data.borrow();
     let config = Config::try_from_slice_unchecked(&config_data)?;
     Vault::load(&config.vault_program, vault, false)?;
@@ -49,6 +59,7 @@ pub fn process_initialize_operator_vault_ticket(
         .ne(&operator_vault_ticket_pubkey)
     {
         msg!("Operator vault ticket account is not at the correct PDA");
+        Check::Address.log_failure(operator_vault_ticket_account.key);
         return Err(ProgramError::InvalidArgument);
     }
 
@@ -56,6 +67,7 @@ pub fn process_initialize_operator_vault_ticket(
     let operator = Operator::try_from_slice_unchecked_mut(&mut operator_data)?;
     if operator.vault_admin.ne(operator_vault_admin.key) {
         msg!("Invalid operator vault admin");
+        Check::VaultAdmin.log_failure(operator_vault_admin.key);
         return Err(ProgramError::InvalidAccountData);
     }
 
@@ -76,7 +88,7 @@ pub fn process_initialize_operator_vault_ticket(
     )?;
     let mut operator_vault_ticket_account_data =
         operator_vault_ticket_account.try_borrow_mut_data()?;
-    operator_vault_ticket_account_data[0] = OperatorVaultTicket::DISCRIMINATOR;
+    OperatorVaultTicket::write_header(&mut operator_vault_ticket_account_data)?;
     let operator_vault_ticket =
         OperatorVaultTicket::try_from_slice_unchecked_mut(&mut operator_vault_ticket_account_data)?;
     *operator_vault_ticket = OperatorVaultTicket::new(
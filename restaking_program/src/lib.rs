@@ -8,18 +8,25 @@ mod initialize_ncn_vault_slasher_ticket;
 mod initialize_ncn_vault_ticket;
 mod initialize_operator;
 mod initialize_operator_vault_ticket;
+mod jail_operator;
+mod migrate_account;
 mod ncn_cooldown_operator;
 mod ncn_delegate_token_account;
 mod ncn_set_admin;
+mod ncn_set_paused_categories;
 mod ncn_set_secondary_admin;
 mod ncn_warmup_operator;
 mod operator_cooldown_ncn;
 mod operator_delegate_token_account;
 mod operator_set_admin;
 mod operator_set_fee;
+mod operator_set_paused_categories;
 mod operator_set_secondary_admin;
 mod operator_warmup_ncn;
+mod pause;
 mod set_config_admin;
+mod set_config_paused_categories;
+mod unjail_operator;
 mod warmup_ncn_vault_slasher_ticket;
 mod warmup_ncn_vault_ticket;
 mod warmup_operator_vault_ticket;
@@ -44,15 +51,21 @@ use crate::{
     initialize_ncn_vault_ticket::process_initialize_ncn_vault_ticket,
     initialize_operator::process_initialize_operator,
     initialize_operator_vault_ticket::process_initialize_operator_vault_ticket,
+    jail_operator::process_jail_operator, migrate_account::process_migrate_account,
     ncn_cooldown_operator::process_ncn_cooldown_operator,
     ncn_delegate_token_account::process_ncn_delegate_token_account,
-    ncn_set_admin::process_ncn_set_admin, ncn_set_secondary_admin::process_ncn_set_secondary_admin,
+    ncn_set_admin::process_ncn_set_admin,
+    ncn_set_paused_categories::process_ncn_set_paused_categories,
+    ncn_set_secondary_admin::process_ncn_set_secondary_admin,
     ncn_warmup_operator::process_ncn_warmup_operator,
     operator_cooldown_ncn::process_operator_cooldown_ncn,
     operator_delegate_token_account::process_operator_delegate_token_account,
     operator_set_admin::process_set_node_operator_admin,
+    operator_set_paused_categories::process_operator_set_paused_categories,
     operator_set_secondary_admin::process_set_operator_secondary_admin,
     operator_warmup_ncn::process_operator_warmup_ncn, set_config_admin::process_set_config_admin,
+    set_config_paused_categories::process_set_config_paused_categories,
+    unjail_operator::process_unjail_operator,
     warmup_ncn_vault_slasher_ticket::process_warmup_ncn_vault_slasher_ticket,
     warmup_ncn_vault_ticket::process_warmup_ncn_vault_ticket,
     warmup_operator_vault_ticket::process_warmup_operator_vault_ticket,
@@ -193,5 +206,29 @@ pub fn process_instruction(
             msg!("Instruction: SetConfigAdmin");
             process_set_config_admin(program_id, accounts)
         }
+        RestakingInstruction::JailOperator { reason } => {
+            msg!("Instruction: JailOperator");
+            process_jail_operator(program_id, accounts, reason)
+        }
+        RestakingInstruction::UnjailOperator => {
+            msg!("Instruction: UnjailOperator");
+            process_unjail_operator(program_id, accounts)
+        }
+        RestakingInstruction::SetConfigPausedCategories { paused_categories } => {
+            msg!("Instruction: SetConfigPausedCategories");
+            process_set_config_paused_categories(program_id, accounts, paused_categories)
+        }
+        RestakingInstruction::NcnSetPausedCategories { paused_categories } => {
+            msg!("Instruction: NcnSetPausedCategories");
+            process_ncn_set_paused_categories(program_id, accounts, paused_categories)
+        }
+        RestakingInstruction::OperatorSetPausedCategories { paused_categories } => {
+            msg!("Instruction: OperatorSetPausedCategories");
+            process_operator_set_paused_categories(program_id, accounts, paused_categories)
+        }
+        RestakingInstruction::MigrateAccount => {
+            msg!("Instruction: MigrateAccount");
+            process_migrate_account(program_id, accounts)
+        }
     }
 }
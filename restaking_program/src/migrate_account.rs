@@ -0,0 +1,62 @@
+use jito_bytemuck::Discriminator;
+use jito_jsm_core::{
+    diagnostic::Check,
+    loader::{load_signer, load_system_program},
+    migrate_account,
+};
+use jito_restaking_core::{
+    config::Config, ncn::Ncn, ncn_operator_state::NcnOperatorState,
+    ncn_vault_slasher_ticket::NcnVaultSlasherTicket, ncn_vault_ticket::NcnVaultTicket,
+    operator::Operator, operator_vault_ticket::OperatorVaultTicket,
+};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey, rent::Rent, sysvar::Sysvar,
+};
+
+/// Converts a restaking program account written under an older layout version to the current
+/// layout. Anyone can migrate an account; the payer covers the rent of any space the new layout
+/// adds.
+pub fn process_migrate_account(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let [account, payer, system_program] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    if account.owner.ne(program_id) {
+        msg!("Account is not owned by the restaking program");
+        Check::Owner.log_failure(account.key);
+        return Err(ProgramError::InvalidAccountOwner);
+    }
+    if !account.is_writable {
+        msg!("Account is not writable");
+        Check::Writable.log_failure(account.key);
+        return Err(ProgramError::InvalidAccountData);
+    }
+    load_signer(payer, true)?;
+    load_system_program(system_program)?;
+
+    let discriminator = account
+        .try_borrow_data()?
+        .first()
+        .copied()
+        .ok_or(ProgramError::InvalidAccountData)?;
+    let rent = Rent::get()?;
+    match discriminator {
+        Config::DISCRIMINATOR => migrate_account::<Config>(account, payer, &rent),
+        Ncn::DISCRIMINATOR => migrate_account::<Ncn>(account, payer, &rent),
+        Operator::DISCRIMINATOR => migrate_account::<Operator>(account, payer, &rent),
+        NcnOperatorState::DISCRIMINATOR => {
+            migrate_account::<NcnOperatorState>(account, payer, &rent)
+        }
+        OperatorVaultTicket::DISCRIMINATOR => {
+            migrate_account::<OperatorVaultTicket>(account, payer, &rent)
+        }
+        NcnVaultTicket::DISCRIMINATOR => migrate_account::<NcnVaultTicket>(account, payer, &rent),
+        NcnVaultSlasherTicket::DISCRIMINATOR => {
+            migrate_account::<NcnVaultSlasherTicket>(account, payer, &rent)
+        }
+        _ => {
+            msg!("Unknown account discriminator {}", discriminator);
+            Err(ProgramError::InvalidAccountData)
+        }
+    }
+}
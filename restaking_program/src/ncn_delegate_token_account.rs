@@ -1,5 +1,9 @@
 use jito_bytemuck::AccountDeserialize;
-use jito_jsm_core::loader::{load_signer, load_token_account, load_token_mint, load_token_program};
+use jito_jsm_core::{
+    diagnostic::Check,
+    loader::{load_signer, load_token_account, load_token_mint, load_token_program},
+    pause::PauseCategory,
+};
 use jito_restaking_core::ncn::Ncn;
 use solana_program::{
     account_info::AccountInfo, entrypoint::ProgramResult, program::invoke_signed,
@@ -41,6 +45,7 @@ pub fn process_ncn_delegate_token_account(
 
     // The owner of token mint and token account must match
     if token_mint.owner.ne(token_account.owner) {
+        Check::Owner.log_failure(token_account.key);
         return Err(ProgramError::InvalidAccountData);
     }
 
@@ -50,6 +55,8 @@ pub fn process_ncn_delegate_token_account(
     // The Ncn delegate_admin shall be the signer of the transaction
     ncn.check_delegate_admin(delegate_admin.key)?;
 
+    ncn.check_is_category_paused(PauseCategory::Delegation)?;
+
     let mut ncn_seeds = Ncn::seeds(&ncn.base);
     ncn_seeds.push(vec![ncn.bump]);
     let ncn_seeds_slice = ncn_seeds
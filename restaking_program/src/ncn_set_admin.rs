@@ -1,5 +1,5 @@
 use jito_bytemuck::AccountDeserialize;
-use jito_jsm_core::loader::load_signer;
+use jito_jsm_core::{diagnostic::Check, loader::load_signer};
 use jito_restaking_core::ncn::Ncn;
 use jito_restaking_sdk::error::RestakingError;
 use solana_program::{
@@ -21,6 +21,7 @@ pub fn process_ncn_set_admin(program_id: &Pubkey, accounts: &[AccountInfo]) -> P
     let ncn = Ncn::try_from_slice_unchecked_mut(&mut ncn_data)?;
     if ncn.admin.ne(old_admin.key) {
         msg!("Invalid admin for NCN");
+        Check::Admin.log_failure(old_admin.key);
         return Err(RestakingError::NcnAdminInvalid.into());
     }
 
@@ -0,0 +1,35 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::{loader::load_signer, pause::PauseCategory};
+use jito_restaking_core::{config::Config, ncn::Ncn};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// Processes the NCN set paused categories instruction: [`crate::RestakingInstruction::NcnSetPausedCategories`]
+pub fn process_ncn_set_paused_categories(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    paused_categories: u16,
+) -> ProgramResult {
+    let [config, ncn, admin] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    Config::load(program_id, config, false)?;
+    Ncn::load(program_id, ncn, true)?;
+    load_signer(admin, false)?;
+
+    // The NCN admin shall be the signer of the transaction
+    let mut ncn_data = ncn.data.borrow_mut();
+    let ncn = Ncn::try_from_slice_unchecked_mut(&mut ncn_data)?;
+    ncn.check_admin(admin.key)?;
+
+    if !PauseCategory::is_valid_mask(paused_categories) {
+        msg!("Invalid paused categories: {:#b}", paused_categories);
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    ncn.set_paused_categories(paused_categories);
+
+    Ok(())
+}
@@ -1,5 +1,5 @@
 use jito_bytemuck::AccountDeserialize;
-use jito_jsm_core::loader::load_signer;
+use jito_jsm_core::{diagnostic::Check, loader::load_signer};
 use jito_restaking_core::ncn::Ncn;
 use jito_restaking_sdk::{error::RestakingError, instruction::NcnAdminRole};
 use solana_program::{
@@ -24,6 +24,7 @@ pub fn process_ncn_set_secondary_admin(
     let ncn = Ncn::try_from_slice_unchecked_mut(&mut ncn_data)?;
     if ncn.admin.ne(admin.key) {
         msg!("Invalid admin for NCN");
+        Check::Admin.log_failure(admin.key);
         return Err(RestakingError::NcnAdminInvalid.into());
     }
 
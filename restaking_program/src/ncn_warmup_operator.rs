@@ -1,5 +1,5 @@
 use jito_bytemuck::AccountDeserialize;
-use jito_jsm_core::loader::load_signer;
+use jito_jsm_core::{diagnostic::Check, loader::load_signer, pause::PauseCategory};
 use jito_restaking_core::{
     config::Config, ncn::Ncn, ncn_operator_state::NcnOperatorState, operator::Operator,
 };
@@ -9,6 +9,8 @@ use solana_program::{
     program_error::ProgramError, pubkey::Pubkey, sysvar::Sysvar,
 };
 
+use crate::pause::check_is_category_paused;
+
 /// [`crate::RestakingInstruction::NcnWarmupOperator`]
 pub fn process_ncn_warmup_operator(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
     let [config, ncn, operator, ncn_operator_state, ncn_operator_admin] = accounts else {
@@ -19,12 +21,19 @@ pub fn process_ncn_warmup_operator(program_id: &Pubkey, accounts: &[AccountInfo]
     Operator::load(program_id, operator, false)?;
     NcnOperatorState::load(program_id, ncn_operator_state, ncn, operator, true)?;
     load_signer(ncn_operator_admin, false)?;
+    check_is_category_paused(
+        config,
+        Some(ncn),
+        Some(operator),
+        PauseCategory::TicketChanges,
+    )?;
 
     // The NCN operator admin shall be the signer of the transaction
     let ncn_data = ncn.data.borrow();
     let ncn = Ncn::try_from_slice_unchecked(&ncn_data)?;
     if ncn.operator_admin.ne(ncn_operator_admin.key) {
         msg!("Invalid operator admin for NCN");
+        Check::OperatorAdmin.log_failure(ncn_operator_admin.key);
         return Err(RestakingError::NcnOperatorAdminInvalid.into());
     }
 
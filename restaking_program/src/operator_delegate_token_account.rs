@@ -1,5 +1,9 @@
 use jito_bytemuck::AccountDeserialize;
-use jito_jsm_core::loader::{load_signer, load_token_account, load_token_mint, load_token_program};
+use jito_jsm_core::{
+    diagnostic::Check,
+    loader::{load_signer, load_token_account, load_token_mint, load_token_program},
+    pause::PauseCategory,
+};
 use jito_restaking_core::operator::Operator;
 use solana_program::{
     account_info::AccountInfo, entrypoint::ProgramResult, program::invoke_signed,
@@ -42,6 +46,7 @@ pub fn process_operator_delegate_token_account(
 
     // The owner of token mint and token account must match
     if token_mint.owner.ne(token_account.owner) {
+        Check::Owner.log_failure(token_account.key);
         return Err(ProgramError::InvalidAccountData);
     }
 
@@ -51,6 +56,8 @@ pub fn process_operator_delegate_token_account(
     // The Operator delegate_admin shall be the signer of the transaction
     operator.check_delegate_admin(delegate_admin.key)?;
 
+    operator.check_is_category_paused(PauseCategory::Delegation)?;
+
     let mut operator_seeds = Operator::seeds(&operator.base);
     operator_seeds.push(vec![operator.bump]);
     let operator_seeds_slice = operator_seeds
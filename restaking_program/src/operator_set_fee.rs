@@ -1,12 +1,14 @@
 use jito_bytemuck::AccountDeserialize;
-use jito_jsm_core::loader::load_signer;
-use jito_restaking_core::{operator::Operator, MAX_FEE_BPS};
+use jito_jsm_core::{loader::load_signer, pause::PauseCategory};
+use jito_restaking_core::{config::Config, operator::Operator, MAX_FEE_BPS};
 use jito_restaking_sdk::error::RestakingError;
 use solana_program::{
     account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
     pubkey::Pubkey,
 };
 
+use crate::pause::check_is_category_paused;
+
 /// The node operator admin can set a new fee. This method is permissioned to the node operator admin.
 ///
 /// [`crate::RestakingInstruction::OperatorSetFee`]
@@ -15,12 +17,19 @@ pub fn process_operator_set_fee(
     accounts: &[AccountInfo],
     new_fee_bps: u16,
 ) -> ProgramResult {
-    let [_config, operator_account, admin] = accounts else {
+    let [config, operator_account, admin] = accounts else {
         return Err(ProgramError::NotEnoughAccountKeys);
     };
 
+    Config::load(program_id, config, false)?;
     Operator::load(program_id, operator_account, true)?;
     load_signer(admin, false)?;
+    check_is_category_paused(
+        config,
+        None,
+        Some(operator_account),
+        PauseCategory::FeeChanges,
+    )?;
 
     let mut operator_data = operator_account.try_borrow_mut_data()?;
     let operator = Operator::try_from_slice_unchecked_mut(&mut operator_data)?;
@@ -0,0 +1,35 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::{loader::load_signer, pause::PauseCategory};
+use jito_restaking_core::{config::Config, operator::Operator};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// Processes the operator set paused categories instruction: [`crate::RestakingInstruction::OperatorSetPausedCategories`]
+pub fn process_operator_set_paused_categories(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    paused_categories: u16,
+) -> ProgramResult {
+    let [config, operator, admin] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    Config::load(program_id, config, false)?;
+    Operator::load(program_id, operator, true)?;
+    load_signer(admin, false)?;
+
+    // The operator admin shall be the signer of the transaction
+    let mut operator_data = operator.data.borrow_mut();
+    let operator = Operator::try_from_slice_unchecked_mut(&mut operator_data)?;
+    operator.check_admin(admin.key)?;
+
+    if !PauseCategory::is_valid_mask(paused_categories) {
+        msg!("Invalid paused categories: {:#b}", paused_categories);
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    operator.set_paused_categories(paused_categories);
+
+    Ok(())
+}
@@ -1,5 +1,5 @@
 use jito_bytemuck::AccountDeserialize;
-use jito_jsm_core::loader::load_signer;
+use jito_jsm_core::{diagnostic::Check, loader::load_signer};
 use jito_restaking_core::operator::Operator;
 use jito_restaking_sdk::{error::RestakingError, instruction::OperatorAdminRole};
 use solana_program::{
@@ -27,6 +27,7 @@ pub fn process_set_operator_secondary_admin(
     let operator = Operator::try_from_slice_unchecked_mut(&mut operator_data)?;
     if operator.admin.ne(admin.key) {
         msg!("Invalid operator admin");
+        Check::Admin.log_failure(admin.key);
         return Err(RestakingError::OperatorAdminInvalid.into());
     }
 
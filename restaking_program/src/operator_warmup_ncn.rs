@@ -1,5 +1,5 @@
 use jito_bytemuck::AccountDeserialize;
-use jito_jsm_core::loader::load_signer;
+use jito_jsm_core::{diagnostic::Check, loader::load_signer, pause::PauseCategory};
 use jito_restaking_core::{
     config::Config, ncn::Ncn, ncn_operator_state::NcnOperatorState, operator::Operator,
 };
@@ -9,6 +9,8 @@ use solana_program::{
     program_error::ProgramError, pubkey::Pubkey, sysvar::Sysvar,
 };
 
+use crate::pause::check_is_category_paused;
+
 /// [`crate::RestakingInstruction::OperatorWarmupNcn`]
 pub fn process_operator_warmup_ncn(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
     let [config, ncn, operator, ncn_operator_state, operator_ncn_admin] = accounts else {
@@ -19,12 +21,19 @@ pub fn process_operator_warmup_ncn(program_id: &Pubkey, accounts: &[AccountInfo]
     Ncn::load(program_id, ncn, false)?;
     NcnOperatorState::load(program_id, ncn_operator_state, ncn, operator, true)?;
     load_signer(operator_ncn_admin, false)?;
+    check_is_category_paused(
+        config,
+        Some(ncn),
+        Some(operator),
+        PauseCategory::TicketChanges,
+    )?;
 
     // The operator NCN admin shall be the signer of the transaction
     let operator_data = operator.data.borrow();
     let operator = Operator::try_from_slice_unchecked(&operator_data)?;
     if operator.ncn_admin.ne(operator_ncn_admin.key) {
         msg!("Invalid NCN admin for operator");
+        Check::NcnAdmin.log_failure(operator_ncn_admin.key);
         return Err(RestakingError::OperatorNcnAdminInvalid.into());
     }
 
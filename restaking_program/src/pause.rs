@@ -0,0 +1,25 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::pause::PauseCategory;
+use jito_restaking_core::{config::Config, ncn::Ncn, operator::Operator};
+use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult};
+
+/// Checks that an instruction category isn't paused on the config, or on the NCN and operator
+/// when they're part of the instruction.
+///
+/// The accounts shall already be loaded and must not be mutably borrowed.
+pub fn check_is_category_paused(
+    config: &AccountInfo,
+    ncn: Option<&AccountInfo>,
+    operator: Option<&AccountInfo>,
+    category: PauseCategory,
+) -> ProgramResult {
+    Config::try_from_slice_unchecked(&config.data.borrow())?.check_is_category_paused(category)?;
+    if let Some(ncn) = ncn {
+        Ncn::try_from_slice_unchecked(&ncn.data.borrow())?.check_is_category_paused(category)?;
+    }
+    if let Some(operator) = operator {
+        Operator::try_from_slice_unchecked(&operator.data.borrow())?
+            .check_is_category_paused(category)?;
+    }
+    Ok(())
+}
@@ -1,5 +1,5 @@
 use jito_bytemuck::AccountDeserialize;
-use jito_jsm_core::loader::load_signer;
+use jito_jsm_core::{diagnostic::Check, loader::load_signer};
 use jito_restaking_core::config::Config;
 use jito_restaking_sdk::error::RestakingError;
 use solana_program::{
@@ -18,6 +18,7 @@ pub fn process_set_config_admin(program_id: &Pubkey, accounts: &[AccountInfo]) -
     load_signer(old_admin, false)?;
 
     if config.admin != *old_admin.key {
+        Check::Admin.log_failure(old_admin.key);
         return Err(RestakingError::ConfigAdminInvalid.into());
     }
     config.set_admin(*new_admin.key);
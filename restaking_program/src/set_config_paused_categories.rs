@@ -0,0 +1,40 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::{diagnostic::Check, loader::load_signer, pause::PauseCategory};
+use jito_restaking_core::config::Config;
+use jito_restaking_sdk::error::RestakingError;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// Processes the set config paused categories instruction: [`crate::RestakingInstruction::SetConfigPausedCategories`]
+///
+/// Categories paused on the config apply to every NCN and operator.
+pub fn process_set_config_paused_categories(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    paused_categories: u16,
+) -> ProgramResult {
+    let [config, admin] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    Config::load(program_id, config, true)?;
+    let mut config_data = config.data.borrow_mut();
+    let config = Config::try_from_slice_unchecked_mut(&mut config_data)?;
+    load_signer(admin, false)?;
+
+    if config.admin.ne(admin.key) {
+        msg!("Invalid admin for config");
+        Check::Admin.log_failure(admin.key);
+        return Err(RestakingError::ConfigAdminInvalid.into());
+    }
+
+    if !PauseCategory::is_valid_mask(paused_categories) {
+        msg!("Invalid paused categories: {:#b}", paused_categories);
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    config.set_paused_categories(paused_categories);
+
+    Ok(())
+}
@@ -0,0 +1,53 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::{diagnostic::Check, loader::load_signer};
+use jito_restaking_core::{
+    config::Config, ncn::Ncn, ncn_operator_state::NcnOperatorState, operator::Operator,
+};
+use jito_restaking_sdk::error::RestakingError;
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
+    program_error::ProgramError, pubkey::Pubkey, sysvar::Sysvar,
+};
+
+/// The NCN operator admin can release a jailed operator.
+/// This method is permissioned to the NCN operator admin.
+/// [`crate::RestakingInstruction::UnjailOperator`]
+pub fn process_unjail_operator(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let [config, ncn, operator, ncn_operator_state, ncn_operator_admin] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    Config::load(program_id, config, false)?;
+    Ncn::load(program_id, ncn, false)?;
+    Operator::load(program_id, operator, true)?;
+    NcnOperatorState::load(program_id, ncn_operator_state, ncn, operator, true)?;
+    load_signer(ncn_operator_admin, false)?;
+
+    // The NCN operator admin shall be the signer of the transaction
+    let ncn_data = ncn.data.borrow();
+    let ncn = Ncn::try_from_slice_unchecked(&ncn_data)?;
+    if !ncn.operator_admin.eq(ncn_operator_admin.key) {
+        msg!("Invalid operator admin for NCN");
+        Check::OperatorAdmin.log_failure(ncn_operator_admin.key);
+        return Err(RestakingError::NcnOperatorAdminInvalid.into());
+    }
+
+    // The operator shall be jailed by the NCN
+    let mut ncn_operator_state_data = ncn_operator_state.data.borrow_mut();
+    let ncn_operator_state =
+        NcnOperatorState::try_from_slice_unchecked_mut(&mut ncn_operator_state_data)?;
+    ncn_operator_state.unjail()?;
+
+    let mut operator_data = operator.data.borrow_mut();
+    let operator = Operator::try_from_slice_unchecked_mut(&mut operator_data)?;
+    operator.decrement_jailed_ncn_count()?;
+
+    msg!(
+        "Operator {} unjailed by NCN {} at slot {}",
+        ncn_operator_state.operator,
+        ncn_operator_state.ncn,
+        Clock::get()?.slot
+    );
+
+    Ok(())
+}
@@ -1,5 +1,5 @@
 use jito_bytemuck::AccountDeserialize;
-use jito_jsm_core::loader::load_signer;
+use jito_jsm_core::{diagnostic::Check, loader::load_signer, pause::PauseCategory};
 use jito_restaking_core::{
     config::Config, ncn::Ncn, ncn_vault_slasher_ticket::NcnVaultSlasherTicket,
     ncn_vault_ticket::NcnVaultTicket,
@@ -11,6 +11,8 @@ use solana_program::{
     program_error::ProgramError, pubkey::Pubkey, sysvar::Sysvar,
 };
 
+use crate::pause::check_is_category_paused;
+
 /// [`crate::RestakingInstruction::WarmupNcnVaultSlasherTicket`]
 pub fn process_warmup_ncn_vault_slasher_ticket(
     program_id: &Pubkey,
@@ -22,6 +24,7 @@ pub fn process_warmup_ncn_vault_slasher_ticket(
     };
     Config::load(program_id, config, false)?;
     Ncn::load(program_id, ncn, false)?;
+    check_is_category_paused(config, Some(ncn), None, PauseCategory::TicketChanges)?;
     let config_data = config.data.borrow_mut();
     let config = Config::try_from_slice_unchecked(&config_data)?;
     Vault::load(&config.vault_program, vault, false)?;
@@ -41,6 +44,7 @@ pub fn process_warmup_ncn_vault_slasher_ticket(
     let ncn = Ncn::try_from_slice_unchecked(&ncn_data)?;
     if ncn.slasher_admin.ne(admin.key) {
         msg!("Invalid slasher admin for NCN");
+        Check::SlasherAdmin.log_failure(admin.key);
         return Err(RestakingError::NcnSlasherAdminInvalid.into());
     }
 
@@ -1,5 +1,5 @@
 use jito_bytemuck::AccountDeserialize;
-use jito_jsm_core::loader::load_signer;
+use jito_jsm_core::{diagnostic::Check, loader::load_signer, pause::PauseCategory};
 use jito_restaking_core::{config::Config, ncn::Ncn, ncn_vault_ticket::NcnVaultTicket};
 use jito_restaking_sdk::error::RestakingError;
 use jito_vault_core::vault::Vault;
@@ -8,6 +8,8 @@ use solana_program::{
     program_error::ProgramError, pubkey::Pubkey, sysvar::Sysvar,
 };
 
+use crate::pause::check_is_category_paused;
+
 /// [`crate::RestakingInstruction::WarmupNcnVaultTicket`]
 pub fn process_warmup_ncn_vault_ticket(
     program_id: &Pubkey,
@@ -19,6 +21,7 @@ pub fn process_warmup_ncn_vault_ticket(
 
     Config::load(program_id, config, false)?;
     Ncn::load(program_id, ncn, false)?;
+    check_is_category_paused(config, Some(ncn), None, PauseCategory::TicketChanges)?;
     let config_data = config.data.borrow_mut();
     let config = Config::try_from_slice_unchecked(&config_data)?;
     Vault::load(&config.vault_program, vault, false)?;
@@ -30,6 +33,7 @@ pub fn process_warmup_ncn_vault_ticket(
     let ncn = Ncn::try_from_slice_unchecked(&ncn_data)?;
     if ncn.vault_admin.ne(ncn_vault_admin.key) {
         msg!("Invalid vault admin for NCN");
+        Check::VaultAdmin.log_failure(ncn_vault_admin.key);
         return Err(RestakingError::NcnVaultAdminInvalid.into());
     }
 
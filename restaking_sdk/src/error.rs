@@ -1,7 +1,7 @@
 use solana_program::{decode_error::DecodeError, program_error::ProgramError};
 use thiserror::Error;
 
-#[derive(Debug, Error)]
+#[derive(Debug, Error, PartialEq, Eq)]
 pub enum RestakingError {
     #[error("NcnOperatorAdminInvalid")]
     NcnOperatorAdminInvalid = 1000,
@@ -25,6 +25,12 @@ pub enum RestakingError {
     NcnVaultSlasherTicketFailedWarmup,
     #[error("NcnVaultTicketFailedWarmup")]
     NcnVaultTicketFailedWarmup,
+    #[error("NcnOperatorAlreadyJailed")]
+    NcnOperatorAlreadyJailed,
+    #[error("NcnOperatorNotJailed")]
+    NcnOperatorNotJailed,
+    #[error("NcnPaused")]
+    NcnPaused,
 
     #[error("OperatorNcnAdminInvalid")]
     OperatorNcnAdminInvalid = 2000,
@@ -57,6 +63,10 @@ pub enum RestakingError {
 
     #[error("ConfigAdminInvalid")]
     ConfigAdminInvalid,
+    #[error("OperatorPaused")]
+    OperatorPaused,
+    #[error("ConfigPaused")]
+    ConfigPaused,
 
     #[error("ArithmeticOverflow")]
     ArithmeticOverflow = 3000,
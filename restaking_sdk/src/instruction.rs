@@ -197,6 +197,47 @@ pub enum RestakingInstruction {
     #[account(1, signer, name = "old_admin")]
     #[account(2, name = "new_admin")]
     SetConfigAdmin,
+
+    /// The NCN jails an operator, immediately excluding it from the NCN and
+    /// blocking new delegations to it from vaults connected to the NCN
+    #[account(0, name = "config")]
+    #[account(1, name = "ncn")]
+    #[account(2, writable, name = "operator")]
+    #[account(3, writable, name = "ncn_operator_state")]
+    #[account(4, signer, name = "admin")]
+    JailOperator { reason: u16 },
+
+    /// The NCN releases a previously jailed operator
+    #[account(0, name = "config")]
+    #[account(1, name = "ncn")]
+    #[account(2, writable, name = "operator")]
+    #[account(3, writable, name = "ncn_operator_state")]
+    #[account(4, signer, name = "admin")]
+    UnjailOperator,
+
+    /// Sets the instruction categories paused across the whole program
+    #[account(0, writable, name = "config")]
+    #[account(1, signer, name = "admin")]
+    SetConfigPausedCategories { paused_categories: u16 },
+
+    /// Sets the instruction categories paused for an NCN
+    #[account(0, name = "config")]
+    #[account(1, writable, name = "ncn")]
+    #[account(2, signer, name = "admin")]
+    NcnSetPausedCategories { paused_categories: u16 },
+
+    /// Sets the instruction categories paused for an operator
+    #[account(0, name = "config")]
+    #[account(1, writable, name = "operator")]
+    #[account(2, signer, name = "admin")]
+    OperatorSetPausedCategories { paused_categories: u16 },
+
+    /// Converts an account written under an older layout version to the current layout.
+    /// Permissionless; the payer funds any rent needed for the account to grow.
+    #[account(0, writable, name = "account")]
+    #[account(1, writable, signer, name = "payer")]
+    #[account(2, name = "system_program")]
+    MigrateAccount,
 }
 
 #[derive(Debug, BorshSerialize, BorshDeserialize, PartialEq, Eq)]
@@ -533,6 +533,53 @@ pub fn ncn_cooldown_operator(
     }
 }
 
+pub fn jail_operator(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    ncn: &Pubkey,
+    operator: &Pubkey,
+    ncn_operator_state: &Pubkey,
+    admin: &Pubkey,
+    reason: u16,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new_readonly(*ncn, false),
+        AccountMeta::new(*operator, false),
+        AccountMeta::new(*ncn_operator_state, false),
+        AccountMeta::new_readonly(*admin, true),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: RestakingInstruction::JailOperator { reason }
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+pub fn unjail_operator(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    ncn: &Pubkey,
+    operator: &Pubkey,
+    ncn_operator_state: &Pubkey,
+    admin: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new_readonly(*ncn, false),
+        AccountMeta::new(*operator, false),
+        AccountMeta::new(*ncn_operator_state, false),
+        AccountMeta::new_readonly(*admin, true),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: RestakingInstruction::UnjailOperator.try_to_vec().unwrap(),
+    }
+}
+
 pub fn warmup_ncn_vault_ticket(
     program_id: &Pubkey,
     config: &Pubkey,
@@ -627,3 +674,77 @@ pub fn set_config_admin(
         data: RestakingInstruction::SetConfigAdmin.try_to_vec().unwrap(),
     }
 }
+
+pub fn set_config_paused_categories(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    admin: &Pubkey,
+    paused_categories: u16,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(*config, false),
+        AccountMeta::new_readonly(*admin, true),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: RestakingInstruction::SetConfigPausedCategories { paused_categories }
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+pub fn ncn_set_paused_categories(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    ncn: &Pubkey,
+    admin: &Pubkey,
+    paused_categories: u16,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new(*ncn, false),
+        AccountMeta::new_readonly(*admin, true),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: RestakingInstruction::NcnSetPausedCategories { paused_categories }
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+pub fn operator_set_paused_categories(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    operator: &Pubkey,
+    admin: &Pubkey,
+    paused_categories: u16,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new(*operator, false),
+        AccountMeta::new_readonly(*admin, true),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: RestakingInstruction::OperatorSetPausedCategories { paused_categories }
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+pub fn migrate_account(program_id: &Pubkey, account: &Pubkey, payer: &Pubkey) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(*account, false),
+        AccountMeta::new(*payer, true),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: RestakingInstruction::MigrateAccount.try_to_vec().unwrap(),
+    }
+}
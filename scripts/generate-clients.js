@@ -8,11 +8,10 @@ const projectRoot = path.join(__dirname, "..");
 
 const idlDir = path.join(projectRoot, "idl");
 
-const rustClientsDir = path.join(__dirname, "..", "clients", "rust");
+// The Rust clients are generated by `jito-shank-cli generate-clients`.
 const jsClientsDir = path.join(__dirname, "..", "clients", "js");
 
-// Generate the restaking client in Rust and JavaScript.
-const rustRestakingClientDir = path.join(rustClientsDir, "restaking_client");
+// Generate the restaking client in JavaScript.
 const jsRestakingClientDir = path.join(jsClientsDir, "restaking_client");
 const restakingRootNode = anchorIdl.rootNodeFromAnchor(require(path.join(idlDir, "jito_restaking.json")));
 const restakingKinobi = kinobi.createFromRoot(restakingRootNode);
@@ -81,6 +80,22 @@ restakingKinobi.update(kinobi.bottomUpTransformerVisitor([
             };
         },
     },
+    {
+        // PodBool -> bool
+        select: (node) => {
+            return (
+                kinobi.isNode(node, "structFieldTypeNode") &&
+                node.type.name === "podBool"
+            );
+        },
+        transform: (node) => {
+            kinobi.assertIsNode(node, "structFieldTypeNode");
+            return {
+                ...node,
+                type: kinobi.booleanTypeNode(),
+            };
+        },
+    },
     // add 8 byte discriminator to accountNode
     {
         select: (node) => {
@@ -104,16 +119,9 @@ restakingKinobi.update(kinobi.bottomUpTransformerVisitor([
         },
     },
 ]));
-restakingKinobi.accept(renderers.renderRustVisitor(path.join(rustRestakingClientDir, "src", "generated"), {
-    formatCode: true,
-    crateFolder: rustRestakingClientDir,
-    deleteFolderBeforeRendering: true,
-    toolchain: "+nightly-2024-07-25"
-}));
 restakingKinobi.accept(renderers.renderJavaScriptVisitor(path.join(jsRestakingClientDir), {}));
 
-// Generate the vault client in Rust and JavaScript.
-const rustVaultClientDir = path.join(rustClientsDir, "vault_client");
+// Generate the vault client in JavaScript.
 const jsVaultClientDir = path.join(jsClientsDir, "vault_client");
 const vaultRootNode = anchorIdl.rootNodeFromAnchor(require(path.join(idlDir, "jito_vault.json")));
 const vaultKinobi = kinobi.createFromRoot(vaultRootNode);
@@ -220,10 +228,4 @@ vaultKinobi.update(kinobi.bottomUpTransformerVisitor([
         },
     },
 ]));
-vaultKinobi.accept(renderers.renderRustVisitor(path.join(rustVaultClientDir, "src", "generated"), {
-    formatCode: true,
-    crateFolder: rustVaultClientDir,
-    deleteFolderBeforeRendering: true,
-    toolchain: "+nightly-2024-07-25"
-}));
 vaultKinobi.accept(renderers.renderJavaScriptVisitor(path.join(jsVaultClientDir), {}));
@@ -0,0 +1,72 @@
+use anyhow::Result;
+use serde_json::Value;
+
+use crate::codegen::{
+    items, str_field,
+    types::{render_struct_fields, TypeImports},
+    Program, HEADER,
+};
+
+/// Renders an account and its decoders. The 8 byte account header is exposed as a `u64`
+/// discriminator ahead of the IDL fields.
+pub(crate) fn render_account(program: &Program, item: &Value) -> Result<String> {
+    let name = str_field(item, "name")?;
+    let mut imports = TypeImports::default();
+    let fields = render_struct_fields(items(&item["type"], "fields"), &mut imports)?;
+    // The anchor Owner impl always needs Pubkey
+    imports.pubkey = true;
+
+    Ok(format!(
+        "{HEADER}{imports}
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = \"serde\", derive(serde::Serialize, serde::Deserialize))]
+pub struct {name} {{
+pub discriminator: u64,
+{fields}}}
+
+impl {name} {{
+    #[inline(always)]
+    pub fn from_bytes(data: &[u8]) -> Result<Self, std::io::Error> {{
+        let mut data = data;
+        Self::deserialize(&mut data)
+    }}
+}}
+
+impl<'a> TryFrom<&solana_program::account_info::AccountInfo<'a>> for {name} {{
+    type Error = std::io::Error;
+
+    fn try_from(account_info: &solana_program::account_info::AccountInfo<'a>) -> Result<Self, Self::Error> {{
+        let mut data: &[u8] = &(*account_info.data).borrow();
+        Self::deserialize(&mut data)
+    }}
+}}
+
+#[cfg(feature = \"anchor\")]
+impl anchor_lang::AccountDeserialize for {name} {{
+    fn try_deserialize_unchecked(buf: &mut &[u8]) -> anchor_lang::Result<Self> {{
+        Ok(Self::deserialize(buf)?)
+    }}
+}}
+
+#[cfg(feature = \"anchor\")]
+impl anchor_lang::AccountSerialize for {name} {{}}
+
+#[cfg(feature = \"anchor\")]
+impl anchor_lang::Owner for {name} {{
+    fn owner() -> Pubkey {{
+        {program_id}
+    }}
+}}
+
+#[cfg(feature = \"anchor-idl-build\")]
+impl anchor_lang::IdlBuild for {name} {{}}
+
+#[cfg(feature = \"anchor-idl-build\")]
+impl anchor_lang::Discriminator for {name} {{
+    const DISCRIMINATOR: &'static [u8] = &[0; 8];
+}}
+",
+        imports = imports.render(&["borsh::{BorshDeserialize, BorshSerialize}"]),
+        program_id = program.id(),
+    ))
+}
@@ -0,0 +1,58 @@
+use std::path::PathBuf;
+
+use anyhow::{anyhow, Result};
+use serde_json::Value;
+
+use crate::codegen::{items, pascal_case, str_field, GeneratedFile, Program, HEADER};
+
+/// Renders the program error enum and its module, if the IDL defines any errors
+pub(crate) fn render_errors(program: &Program, idl: &Value) -> Result<Vec<GeneratedFile>> {
+    let errors = items(idl, "errors");
+    if errors.is_empty() {
+        return Ok(Vec::new());
+    }
+    let enum_name = format!("{}Error", pascal_case(&program.name));
+
+    let mut variants = String::new();
+    for error in errors {
+        let code = error["code"]
+            .as_u64()
+            .ok_or_else(|| anyhow!("Error is missing a code: {}", error))?;
+        let name = str_field(error, "name")?;
+        let message = error["msg"].as_str().unwrap_or(name);
+        variants.push_str(&format!(
+            "/// {code} - {message}\n#[error({message:?})]\n{name} = 0x{code:X},\n"
+        ));
+    }
+
+    Ok(vec![
+        GeneratedFile {
+            path: PathBuf::from("errors").join(format!("{}.rs", program.name)),
+            contents: format!(
+                "{HEADER}use num_derive::FromPrimitive;
+use thiserror::Error;
+
+#[derive(Clone, Debug, Eq, Error, FromPrimitive, PartialEq)]
+pub enum {enum_name} {{
+{variants}}}
+
+impl solana_program::program_error::PrintProgramError for {enum_name} {{
+    fn print<E>(&self) {{
+        solana_program::msg!(&self.to_string());
+    }}
+}}
+"
+            ),
+        },
+        GeneratedFile {
+            path: PathBuf::from("errors").join("mod.rs"),
+            contents: format!(
+                "{HEADER}pub(crate) mod {module};
+
+pub use self::{module}::{enum_name};
+",
+                module = program.name,
+            ),
+        },
+    ])
+}
@@ -0,0 +1,662 @@
+use anyhow::{anyhow, Result};
+use serde_json::Value;
+
+use crate::codegen::{
+    bool_field, docs, items, pascal_case, snake_case, str_field,
+    types::{rust_type, TypeImports},
+    Program, HEADER,
+};
+
+/// Program accounts the builders fill in when they aren't set
+const DEFAULT_ACCOUNTS: [(&str, &str); 4] = [
+    ("systemProgram", "11111111111111111111111111111111"),
+    (
+        "tokenProgram",
+        "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA",
+    ),
+    ("ataProgram", "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"),
+    (
+        "mplTokenMetadataProgram",
+        "metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s",
+    ),
+];
+
+const PUBKEY: &str = "solana_program::pubkey::Pubkey";
+const ACCOUNT_INFO: &str = "&'b solana_program::account_info::AccountInfo<'a>";
+const ACCOUNT_META: &str = "solana_program::instruction::AccountMeta";
+const INSTRUCTION: &str = "solana_program::instruction::Instruction";
+const PROGRAM_RESULT: &str = "solana_program::entrypoint::ProgramResult";
+
+struct Account<'a> {
+    name: String,
+    is_writable: bool,
+    is_signer: bool,
+    is_optional: bool,
+    docs: Vec<&'a str>,
+    default: Option<&'static str>,
+}
+
+impl<'a> Account<'a> {
+    fn new(account: &'a Value) -> Result<Self> {
+        let idl_name = str_field(account, "name")?;
+        let is_optional = bool_field(account, "isOptional");
+        Ok(Self {
+            name: snake_case(idl_name),
+            is_writable: bool_field(account, "isMut"),
+            is_signer: bool_field(account, "isSigner"),
+            is_optional,
+            docs: docs(account),
+            default: DEFAULT_ACCOUNTS
+                .iter()
+                .find(|(name, _)| !is_optional && *name == idl_name)
+                .map(|(_, address)| *address),
+        })
+    }
+
+    /// The `/// ...` lines for the account, or a blank line when it has none
+    fn doc_block(&self) -> String {
+        if self.docs.is_empty() {
+            return "\n".to_string();
+        }
+        self.docs
+            .iter()
+            .map(|line| format!("/// {}\n", line))
+            .collect()
+    }
+
+    fn meta(&self, key: &str) -> String {
+        let constructor = if self.is_writable {
+            "new"
+        } else {
+            "new_readonly"
+        };
+        format!(
+            "accounts.push({}::{}({}, {}));\n",
+            ACCOUNT_META, constructor, key, self.is_signer
+        )
+    }
+
+    /// Pushes the account meta, substituting the program ID for a missing optional account
+    fn push_meta(&self, program: &Program, key: impl Fn(&str) -> String) -> String {
+        if !self.is_optional {
+            return self.meta(&key(&format!("self.{}", self.name)));
+        }
+        format!(
+            "if let Some({name}) = self.{name} {{\n{some}}} else {{\naccounts.push({ACCOUNT_META}::new_readonly({program_id}, false));\n}}\n",
+            name = self.name,
+            some = self.meta(&key(&self.name)),
+            program_id = program.id(),
+        )
+    }
+
+    /// The account's line in the builder docs, e.g. `[writable, signer]` payer
+    fn doc_line(&self, index: usize, with_default: bool) -> String {
+        let default = self.default.filter(|_| with_default);
+        let mut flags = Vec::new();
+        if self.is_writable {
+            flags.push("writable");
+        }
+        if self.is_signer {
+            flags.push("signer");
+        }
+        if self.is_optional || default.is_some() {
+            flags.push("optional");
+        }
+        let mut line = format!("///   {}. `[{}]` {}", index, flags.join(", "), self.name);
+        if let Some(address) = default {
+            line.push_str(&format!(" (default to `{}`)", address));
+        }
+        line.push('\n');
+        line
+    }
+}
+
+struct Arg {
+    name: String,
+    ty: String,
+    /// The type taken by the builder setter, which unwraps optional arguments
+    setter_ty: String,
+    is_optional: bool,
+}
+
+impl Arg {
+    fn new(arg: &Value, imports: &mut TypeImports) -> Result<Self> {
+        let ty = &arg["type"];
+        let is_optional = !ty["option"].is_null();
+        let setter_ty = if is_optional {
+            rust_type(&ty["option"], imports)?
+        } else {
+            rust_type(ty, imports)?
+        };
+        Ok(Self {
+            name: snake_case(str_field(arg, "name")?),
+            ty: rust_type(ty, imports)?,
+            setter_ty,
+            is_optional,
+        })
+    }
+
+    fn value(&self, target: &str) -> String {
+        if self.is_optional {
+            format!("{}: {}.{}.clone(),\n", self.name, target, self.name)
+        } else {
+            format!(
+                "{name}: {target}.{name}.clone().expect(\"{name} is not set\"),\n",
+                name = self.name,
+            )
+        }
+    }
+}
+
+pub(crate) fn render_instruction(program: &Program, item: &Value) -> Result<String> {
+    let idl_name = str_field(item, "name")?;
+    let name = pascal_case(idl_name);
+    let snake_name = snake_case(idl_name);
+    let discriminator = item["discriminant"]["value"]
+        .as_u64()
+        .ok_or_else(|| anyhow!("Instruction {} is missing a discriminant", idl_name))?;
+
+    let accounts = items(item, "accounts")
+        .iter()
+        .map(Account::new)
+        .collect::<Result<Vec<_>>>()?;
+    let mut imports = TypeImports::default();
+    let args = items(item, "args")
+        .iter()
+        .map(|arg| Arg::new(arg, &mut imports))
+        .collect::<Result<Vec<_>>>()?;
+    let has_args = !args.is_empty();
+    let args_name = format!("{}InstructionArgs", name);
+    let program_id = program.id();
+    let account_count = accounts.len();
+
+    let mut out = format!(
+        "{HEADER}{}\n",
+        imports.render(&["borsh::{BorshDeserialize, BorshSerialize}"])
+    );
+
+    // Accounts and the instruction they build
+    out.push_str(&format!("/// Accounts.\npub struct {name} {{\n"));
+    for account in accounts.iter() {
+        let ty = if account.is_optional {
+            format!("Option<{}>", PUBKEY)
+        } else {
+            PUBKEY.to_string()
+        };
+        out.push_str(&format!(
+            "{}pub {}: {},\n",
+            account.doc_block(),
+            account.name,
+            ty
+        ));
+    }
+    out.push_str("}\n\n");
+
+    let (args_param, args_arg) = if has_args {
+        (format!("args: {},", args_name), "args, ")
+    } else {
+        (String::new(), "")
+    };
+    out.push_str(&format!(
+        "impl {name} {{
+pub fn instruction(&self, {args_param}) -> {INSTRUCTION} {{
+self.instruction_with_remaining_accounts({args_arg}&[])
+}}
+#[allow(clippy::vec_init_then_push)]
+pub fn instruction_with_remaining_accounts(&self, {args_param} remaining_accounts: &[{ACCOUNT_META}]) -> {INSTRUCTION} {{
+let mut accounts = Vec::with_capacity({account_count} + remaining_accounts.len());
+"
+    ));
+    for account in accounts.iter() {
+        out.push_str(&account.push_meta(program, |key| key.to_string()));
+    }
+    out.push_str("accounts.extend_from_slice(remaining_accounts);\n");
+    out.push_str(&render_data(&name, has_args, "args"));
+    out.push_str(&format!(
+        "
+{INSTRUCTION} {{
+program_id: {program_id},
+accounts,
+data,
+}}
+}}
+}}
+
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct {name}InstructionData {{
+discriminator: u8,
+}}
+
+impl {name}InstructionData {{
+pub fn new() -> Self {{
+Self {{ discriminator: {discriminator} }}
+}}
+}}
+
+impl Default for {name}InstructionData {{
+fn default() -> Self {{
+Self::new()
+}}
+}}
+
+"
+    ));
+
+    if has_args {
+        out.push_str(&format!(
+            "#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = \"serde\", derive(serde::Serialize, serde::Deserialize))]
+pub struct {args_name} {{
+"
+        ));
+        for arg in args.iter() {
+            out.push_str(&format!("pub {}: {},\n", arg.name, arg.ty));
+        }
+        out.push_str("}\n\n");
+    }
+
+    // Instruction builder
+    out.push_str(&format!("/// Instruction builder for `{name}`.\n"));
+    out.push_str(&render_account_docs(&accounts, true));
+    out.push_str(&format!(
+        "#[derive(Clone, Debug, Default)]\npub struct {name}Builder {{\n"
+    ));
+    for account in accounts.iter() {
+        out.push_str(&format!("{}: Option<{}>,\n", account.name, PUBKEY));
+    }
+    for arg in args.iter() {
+        out.push_str(&format!("{}: Option<{}>,\n", arg.name, arg.setter_ty));
+    }
+    out.push_str(&format!(
+        "__remaining_accounts: Vec<{ACCOUNT_META}>,
+}}
+
+impl {name}Builder {{
+pub fn new() -> Self {{
+Self::default()
+}}
+"
+    ));
+    for account in accounts.iter() {
+        if let Some(address) = account.default {
+            out.push_str(&format!(
+                "/// `[optional account, default to '{}']`\n",
+                address
+            ));
+        }
+        out.push_str(&render_account_setter(account, PUBKEY, "self"));
+    }
+    for arg in args.iter() {
+        out.push_str(&render_arg_setter(arg, "self"));
+    }
+    out.push_str(&format!(
+        "/// Add an additional account to the instruction.
+#[inline(always)]
+pub fn add_remaining_account(&mut self, account: {ACCOUNT_META}) -> &mut Self {{
+self.__remaining_accounts.push(account);
+self
+}}
+/// Add additional accounts to the instruction.
+#[inline(always)]
+pub fn add_remaining_accounts(&mut self, accounts: &[{ACCOUNT_META}]) -> &mut Self {{
+self.__remaining_accounts.extend_from_slice(accounts);
+self
+}}
+#[allow(clippy::clone_on_copy)]
+pub fn instruction(&self) -> {INSTRUCTION} {{
+let accounts = {name} {{
+"
+    ));
+    for account in accounts.iter() {
+        let value = if account.is_optional {
+            format!("self.{}", account.name)
+        } else if let Some(address) = account.default {
+            format!(
+                "self.{}.unwrap_or(solana_program::pubkey!(\"{}\"))",
+                account.name, address
+            )
+        } else {
+            format!(
+                "self.{name}.expect(\"{name} is not set\")",
+                name = account.name
+            )
+        };
+        out.push_str(&format!("{}: {},\n", account.name, value));
+    }
+    out.push_str("};\n");
+    if has_args {
+        out.push_str(&format!("let args = {} {{\n", args_name));
+        for arg in args.iter() {
+            out.push_str(&arg.value("self"));
+        }
+        out.push_str("};\n");
+    }
+    out.push_str(&format!(
+        "
+accounts.instruction_with_remaining_accounts({args_arg}&self.__remaining_accounts)
+}}
+}}
+
+"
+    ));
+
+    // CPI accounts and instruction
+    if !accounts.is_empty() {
+        out.push_str(&format!(
+            "/// `{snake_name}` CPI accounts.\npub struct {name}CpiAccounts<'a, 'b> {{\n"
+        ));
+        out.push_str(&render_cpi_account_fields(&accounts));
+        out.push_str("}\n\n");
+    }
+    out.push_str(&format!(
+        "/// `{snake_name}` CPI instruction.
+pub struct {name}Cpi<'a, 'b> {{
+/// The program to invoke.
+pub __program: {ACCOUNT_INFO},
+"
+    ));
+    out.push_str(&render_cpi_account_fields(&accounts));
+    if has_args {
+        out.push_str(&format!(
+            "/// The arguments for the instruction.\npub __args: {},\n",
+            args_name
+        ));
+    }
+    out.push_str("}\n\n");
+
+    let accounts_new_param = if accounts.is_empty() {
+        String::new()
+    } else {
+        format!("accounts: {}CpiAccounts<'a, 'b>,", name)
+    };
+    let args_new_param = if has_args {
+        format!("args: {},", args_name)
+    } else {
+        String::new()
+    };
+    out.push_str(&format!(
+        "impl<'a, 'b> {name}Cpi<'a, 'b> {{
+pub fn new(program: {ACCOUNT_INFO}, {accounts_new_param} {args_new_param}) -> Self {{
+Self {{
+__program: program,
+"
+    ));
+    for account in accounts.iter() {
+        out.push_str(&format!("{name}: accounts.{name},\n", name = account.name));
+    }
+    if has_args {
+        out.push_str("__args: args,\n");
+    }
+    out.push_str(&format!(
+        "}}
+}}
+#[inline(always)]
+pub fn invoke(&self) -> {PROGRAM_RESULT} {{
+self.invoke_signed_with_remaining_accounts(&[], &[])
+}}
+#[inline(always)]
+pub fn invoke_with_remaining_accounts(&self, remaining_accounts: &[({ACCOUNT_INFO}, bool, bool)]) -> {PROGRAM_RESULT} {{
+self.invoke_signed_with_remaining_accounts(&[], remaining_accounts)
+}}
+#[inline(always)]
+pub fn invoke_signed(&self, signers_seeds: &[&[&[u8]]]) -> {PROGRAM_RESULT} {{
+self.invoke_signed_with_remaining_accounts(signers_seeds, &[])
+}}
+#[allow(clippy::clone_on_copy)]
+#[allow(clippy::vec_init_then_push)]
+pub fn invoke_signed_with_remaining_accounts(&self, signers_seeds: &[&[&[u8]]], remaining_accounts: &[({ACCOUNT_INFO}, bool, bool)]) -> {PROGRAM_RESULT} {{
+let mut accounts = Vec::with_capacity({account_count} + remaining_accounts.len());
+"
+    ));
+    for account in accounts.iter() {
+        out.push_str(&account.push_meta(program, |key| format!("*{}.key", key)));
+    }
+    out.push_str(&format!(
+        "remaining_accounts.iter().for_each(|remaining_account| {{
+accounts.push({ACCOUNT_META} {{
+pubkey: *remaining_account.0.key,
+is_signer: remaining_account.1,
+is_writable: remaining_account.2,
+}})
+}});
+"
+    ));
+    out.push_str(&render_data(&name, has_args, "self.__args"));
+    out.push_str(&format!(
+        "
+let instruction = {INSTRUCTION} {{
+program_id: {program_id},
+accounts,
+data,
+}};
+let mut account_infos = Vec::with_capacity({account_count} + 1 + remaining_accounts.len());
+account_infos.push(self.__program.clone());
+"
+    ));
+    for account in accounts.iter() {
+        if account.is_optional {
+            out.push_str(&format!(
+                "if let Some({name}) = self.{name} {{\naccount_infos.push({name}.clone());\n}}\n",
+                name = account.name
+            ));
+        } else {
+            out.push_str(&format!(
+                "account_infos.push(self.{}.clone());\n",
+                account.name
+            ));
+        }
+    }
+    out.push_str(
+        "remaining_accounts.iter().for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
+
+if signers_seeds.is_empty() {
+solana_program::program::invoke(&instruction, &account_infos)
+} else {
+solana_program::program::invoke_signed(&instruction, &account_infos, signers_seeds)
+}
+}
+}
+
+",
+    );
+
+    // CPI instruction builder
+    out.push_str(&format!("/// Instruction builder for `{name}` via CPI.\n"));
+    out.push_str(&render_account_docs(&accounts, false));
+    out.push_str(&format!(
+        "#[derive(Clone, Debug)]
+pub struct {name}CpiBuilder<'a, 'b> {{
+instruction: Box<{name}CpiBuilderInstruction<'a, 'b>>,
+}}
+
+impl<'a, 'b> {name}CpiBuilder<'a, 'b> {{
+pub fn new(program: {ACCOUNT_INFO}) -> Self {{
+let instruction = Box::new({name}CpiBuilderInstruction {{
+__program: program,
+"
+    ));
+    for account in accounts.iter() {
+        out.push_str(&format!("{}: None,\n", account.name));
+    }
+    for arg in args.iter() {
+        out.push_str(&format!("{}: None,\n", arg.name));
+    }
+    out.push_str(
+        "__remaining_accounts: Vec::new(),
+});
+Self { instruction }
+}
+",
+    );
+    for account in accounts.iter() {
+        out.push_str(&render_account_setter(
+            account,
+            ACCOUNT_INFO,
+            "self.instruction",
+        ));
+    }
+    for arg in args.iter() {
+        out.push_str(&render_arg_setter(arg, "self.instruction"));
+    }
+    out.push_str(&format!(
+        "/// Add an additional account to the instruction.
+#[inline(always)]
+pub fn add_remaining_account(&mut self, account: {ACCOUNT_INFO}, is_writable: bool, is_signer: bool) -> &mut Self {{
+self.instruction.__remaining_accounts.push((account, is_writable, is_signer));
+self
+}}
+/// Add additional accounts to the instruction.
+///
+/// Each account is represented by a tuple of the `AccountInfo`, a `bool` indicating whether the account is writable or not,
+/// and a `bool` indicating whether the account is a signer or not.
+#[inline(always)]
+pub fn add_remaining_accounts(&mut self, accounts: &[({ACCOUNT_INFO}, bool, bool)]) -> &mut Self {{
+self.instruction.__remaining_accounts.extend_from_slice(accounts);
+self
+}}
+#[inline(always)]
+pub fn invoke(&self) -> {PROGRAM_RESULT} {{
+self.invoke_signed(&[])
+}}
+#[allow(clippy::clone_on_copy)]
+#[allow(clippy::vec_init_then_push)]
+pub fn invoke_signed(&self, signers_seeds: &[&[&[u8]]]) -> {PROGRAM_RESULT} {{
+"
+    ));
+    if has_args {
+        out.push_str(&format!("let args = {} {{\n", args_name));
+        for arg in args.iter() {
+            out.push_str(&arg.value("self.instruction"));
+        }
+        out.push_str("};\n");
+    }
+    out.push_str(&format!(
+        "let instruction = {name}Cpi {{\n__program: self.instruction.__program,\n"
+    ));
+    for account in accounts.iter() {
+        let value = if account.is_optional {
+            format!("self.instruction.{}", account.name)
+        } else {
+            format!(
+                "self.instruction.{name}.expect(\"{name} is not set\")",
+                name = account.name
+            )
+        };
+        out.push_str(&format!("\n{}: {},\n", account.name, value));
+    }
+    if has_args {
+        out.push_str("__args: args,\n");
+    }
+    out.push_str(&format!(
+        "}};
+instruction.invoke_signed_with_remaining_accounts(signers_seeds, &self.instruction.__remaining_accounts)
+}}
+}}
+
+#[derive(Clone, Debug)]
+struct {name}CpiBuilderInstruction<'a, 'b> {{
+__program: {ACCOUNT_INFO},
+"
+    ));
+    for account in accounts.iter() {
+        out.push_str(&format!("{}: Option<{}>,\n", account.name, ACCOUNT_INFO));
+    }
+    for arg in args.iter() {
+        out.push_str(&format!("{}: Option<{}>,\n", arg.name, arg.setter_ty));
+    }
+    out.push_str(&format!(
+        "/// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
+__remaining_accounts: Vec<({ACCOUNT_INFO}, bool, bool)>,
+}}
+"
+    ));
+
+    Ok(out)
+}
+
+/// Serializes the discriminator followed by the arguments
+fn render_data(name: &str, has_args: bool, args: &str) -> String {
+    if has_args {
+        format!(
+            "let mut data = {name}InstructionData::new().try_to_vec().unwrap();
+let mut args = {args}.try_to_vec().unwrap();
+data.append(&mut args);
+"
+        )
+    } else {
+        format!("let data = {name}InstructionData::new().try_to_vec().unwrap();\n")
+    }
+}
+
+fn render_account_docs(accounts: &[Account], with_defaults: bool) -> String {
+    let mut out = "///\n/// ### Accounts:\n".to_string();
+    if !accounts.is_empty() {
+        out.push_str("///\n");
+    }
+    for (index, account) in accounts.iter().enumerate() {
+        out.push_str(&account.doc_line(index, with_defaults));
+    }
+    out
+}
+
+fn render_cpi_account_fields(accounts: &[Account]) -> String {
+    let mut out = String::new();
+    for account in accounts {
+        let ty = if account.is_optional {
+            format!("Option<{}>", ACCOUNT_INFO)
+        } else {
+            ACCOUNT_INFO.to_string()
+        };
+        out.push_str(&format!(
+            "{}pub {}: {},\n",
+            account.doc_block(),
+            account.name,
+            ty
+        ));
+    }
+    out
+}
+
+/// Renders a builder setter for an account, where `target` holds the builder's fields
+fn render_account_setter(account: &Account, ty: &str, target: &str) -> String {
+    let mut out = String::new();
+    if account.is_optional {
+        out.push_str("/// `[optional account]`\n");
+    }
+    for line in account.docs.iter() {
+        out.push_str(&format!("/// {}\n", line));
+    }
+    let (param_ty, value) = if account.is_optional {
+        (format!("Option<{}>", ty), account.name.clone())
+    } else {
+        (ty.to_string(), format!("Some({})", account.name))
+    };
+    out.push_str(&format!(
+        "#[inline(always)]
+pub fn {name}(&mut self, {name}: {param_ty}) -> &mut Self {{
+{target}.{name} = {value};
+self
+}}
+",
+        name = account.name,
+    ));
+    out
+}
+
+fn render_arg_setter(arg: &Arg, target: &str) -> String {
+    let mut out = String::new();
+    if arg.is_optional {
+        out.push_str("/// `[optional argument]`\n");
+    }
+    out.push_str(&format!(
+        "#[inline(always)]
+pub fn {name}(&mut self, {name}: {ty}) -> &mut Self {{
+{target}.{name} = Some({name});
+self
+}}
+",
+        name = arg.name,
+        ty = arg.setter_ty,
+    ));
+    out
+}
@@ -0,0 +1,320 @@
+//! Generates the Rust program clients in `clients/rust` from the shank IDLs.
+//!
+//! The generated API matches what the Kinobi renderer used to produce: account structs with borsh
+//! decoders, instruction builders, CPI builders and a program error enum. Every file is passed
+//! through rustfmt with the workspace configuration, so the committed clients are exactly what
+//! the generator emits.
+
+mod accounts;
+mod errors;
+mod instructions;
+mod types;
+
+use std::{
+    fs,
+    io::Write,
+    path::{Path, PathBuf},
+    process::{Command, Stdio},
+};
+
+use anyhow::{anyhow, Result};
+use serde_json::Value;
+
+const HEADER: &str = "\
+//! This code was AUTOGENERATED by `jito-shank-cli generate-clients` from the program IDL.
+//! Please DO NOT EDIT THIS FILE, instead update the program and rerun the generator.
+
+";
+
+/// Renders the file for one entry of an IDL section
+type RenderItem = fn(&Program, &Value) -> Result<String>;
+
+pub struct GeneratedFile {
+    /// Path relative to the client's `src/generated` directory
+    pub path: PathBuf,
+    pub contents: String,
+}
+
+/// Renders every file of a program client from its IDL, before formatting
+pub fn generate_client(idl: &Value) -> Result<Vec<GeneratedFile>> {
+    let program = Program::new(idl)?;
+    let mut files = vec![
+        GeneratedFile {
+            path: PathBuf::from("mod.rs"),
+            contents: render_root_mod(idl),
+        },
+        GeneratedFile {
+            path: PathBuf::from("programs.rs"),
+            contents: program.render(),
+        },
+    ];
+
+    let sections: [(&str, RenderItem); 3] = [
+        ("accounts", accounts::render_account),
+        ("types", types::render_defined_type),
+        ("instructions", instructions::render_instruction),
+    ];
+    for (section, render) in sections {
+        let mut modules = Vec::new();
+        for item in items(idl, section) {
+            let module = snake_case(str_field(item, "name")?);
+            files.push(GeneratedFile {
+                path: Path::new(section).join(format!("{}.rs", module)),
+                contents: render(&program, item)?,
+            });
+            modules.push(module);
+        }
+        files.push(GeneratedFile {
+            path: Path::new(section).join("mod.rs"),
+            contents: render_section_mod(modules),
+        });
+    }
+
+    files.extend(errors::render_errors(&program, idl)?);
+
+    Ok(files)
+}
+
+/// Formats the generated files with rustfmt and replaces the contents of `out_dir` with them
+pub fn write_client(files: &[GeneratedFile], rustfmt_config: &Path, out_dir: &Path) -> Result<()> {
+    if out_dir.exists() {
+        fs::remove_dir_all(out_dir)?;
+    }
+    for file in files {
+        let path = out_dir.join(&file.path);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, rustfmt(&file.contents, rustfmt_config)?)?;
+    }
+    Ok(())
+}
+
+/// Formats a source file the same way `cargo fmt` formats the workspace
+pub fn rustfmt(source: &str, config: &Path) -> Result<String> {
+    let mut child = Command::new("rustfmt")
+        .arg("--edition")
+        .arg("2021")
+        .arg("--config-path")
+        .arg(config)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| anyhow!("Failed to run rustfmt: {}", e))?;
+    child
+        .stdin
+        .take()
+        .ok_or_else(|| anyhow!("Failed to open rustfmt stdin"))?
+        .write_all(source.as_bytes())?;
+
+    let output = child.wait_with_output()?;
+    if !output.status.success() {
+        return Err(anyhow!(
+            "rustfmt failed: {}",
+            String::from_utf8_lossy(&output.stderr)
+        ));
+    }
+    Ok(String::from_utf8(output.stdout)?)
+}
+
+/// The program a client is generated for
+pub(crate) struct Program {
+    /// The IDL name, e.g. `jito_vault`
+    pub name: String,
+    pub address: String,
+}
+
+impl Program {
+    fn new(idl: &Value) -> Result<Self> {
+        Ok(Self {
+            name: str_field(idl, "name")?.to_string(),
+            address: idl["metadata"]["address"]
+                .as_str()
+                .ok_or_else(|| anyhow!("IDL is missing metadata.address"))?
+                .to_string(),
+        })
+    }
+
+    /// The path of the program ID constant, e.g. `crate::JITO_VAULT_ID`
+    pub fn id(&self) -> String {
+        format!("crate::{}", self.id_name())
+    }
+
+    fn id_name(&self) -> String {
+        format!("{}_ID", self.name.to_uppercase())
+    }
+
+    fn render(&self) -> String {
+        format!(
+            "{HEADER}use solana_program::{{pubkey, pubkey::Pubkey}};
+
+/// `{name}` program ID.
+pub const {id}: Pubkey = pubkey!(\"{address}\");
+",
+            name = self.name,
+            id = self.id_name(),
+            address = self.address,
+        )
+    }
+}
+
+fn render_root_mod(idl: &Value) -> String {
+    let mut modules = vec!["accounts", "instructions", "programs", "types"];
+    if !items(idl, "errors").is_empty() {
+        modules.push("errors");
+    }
+    modules.sort_unstable();
+
+    let mut out = HEADER.to_string();
+    for module in modules {
+        out.push_str(&format!("pub mod {};\n", module));
+    }
+    out.push_str("\npub(crate) use programs::*;\n");
+    out
+}
+
+fn render_section_mod(mut modules: Vec<String>) -> String {
+    modules.sort_unstable();
+
+    let mut out = HEADER.to_string();
+    for module in modules.iter() {
+        out.push_str(&format!("pub(crate) mod r#{};\n", module));
+    }
+    let exports: Vec<String> = modules
+        .iter()
+        .map(|module| format!("r#{}::*", module))
+        .collect();
+    out.push_str(&format!("\npub use self::{{{}}};\n", exports.join(", ")));
+    out
+}
+
+/// The entries of an IDL section such as `accounts` or `instructions`
+pub(crate) fn items<'a>(value: &'a Value, section: &str) -> &'a [Value] {
+    value[section]
+        .as_array()
+        .map(Vec::as_slice)
+        .unwrap_or_default()
+}
+
+pub(crate) fn str_field<'a>(value: &'a Value, field: &str) -> Result<&'a str> {
+    value[field]
+        .as_str()
+        .ok_or_else(|| anyhow!("IDL entry is missing `{}`: {}", field, value))
+}
+
+pub(crate) fn bool_field(value: &Value, field: &str) -> bool {
+    value[field].as_bool().unwrap_or(false)
+}
+
+pub(crate) fn docs(value: &Value) -> Vec<&str> {
+    value["docs"]
+        .as_array()
+        .map(|docs| docs.iter().filter_map(Value::as_str).collect())
+        .unwrap_or_default()
+}
+
+/// Converts an IDL name such as `vaultStakerWithdrawalTicket` or `DataV2` to snake case
+pub(crate) fn snake_case(name: &str) -> String {
+    let mut out = String::with_capacity(name.len() + 4);
+    for (i, c) in name.chars().enumerate() {
+        if c.is_ascii_uppercase() {
+            if i > 0 && !out.ends_with('_') {
+                out.push('_');
+            }
+            out.push(c.to_ascii_lowercase());
+        } else {
+            out.push(c);
+        }
+    }
+    out
+}
+
+/// Converts an IDL name such as `jito_vault` or `amountIn` to pascal case
+pub(crate) fn pascal_case(name: &str) -> String {
+    snake_case(name)
+        .split('_')
+        .filter(|word| !word.is_empty())
+        .map(|word| {
+            let mut chars = word.chars();
+            chars
+                .next()
+                .map(|first| first.to_ascii_uppercase().to_string() + chars.as_str())
+                .unwrap_or_default()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use super::{generate_client, pascal_case, rustfmt, snake_case};
+
+    #[test]
+    fn test_case_conversions() {
+        assert_eq!(
+            snake_case("vaultStakerWithdrawalTicket"),
+            "vault_staker_withdrawal_ticket"
+        );
+        assert_eq!(
+            snake_case("CreateMetadataAccountArgsV3"),
+            "create_metadata_account_args_v3"
+        );
+        assert_eq!(pascal_case("jito_vault"), "JitoVault");
+        assert_eq!(pascal_case("amountIn"), "AmountIn");
+    }
+
+    #[test]
+    fn test_generated_clients_match_committed() {
+        let workspace = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+        let rustfmt_config = workspace.join("rustfmt.toml");
+
+        for (idl_name, client) in [
+            ("jito_restaking", "restaking_client"),
+            ("jito_vault", "vault_client"),
+        ] {
+            let idl_path = workspace.join("idl").join(format!("{}.json", idl_name));
+            let idl = serde_json::from_str(&std::fs::read_to_string(idl_path).unwrap()).unwrap();
+            let generated_dir = workspace
+                .join("clients")
+                .join("rust")
+                .join(client)
+                .join("src")
+                .join("generated");
+
+            let files = generate_client(&idl).unwrap();
+            for file in files.iter() {
+                let committed_path = generated_dir.join(&file.path);
+                let committed = std::fs::read_to_string(&committed_path).unwrap_or_default();
+                let generated = rustfmt(&file.contents, &rustfmt_config).unwrap();
+                assert!(
+                    committed == generated,
+                    "{} is out of date, run `jito-shank-cli generate-clients`",
+                    committed_path.display()
+                );
+            }
+
+            let committed_files = walk(&generated_dir);
+            assert_eq!(
+                committed_files.len(),
+                files.len(),
+                "{} contains files the generator doesn't produce",
+                generated_dir.display()
+            );
+        }
+    }
+
+    fn walk(dir: &Path) -> Vec<std::path::PathBuf> {
+        let mut files = Vec::new();
+        for entry in std::fs::read_dir(dir).unwrap() {
+            let path = entry.unwrap().path();
+            if path.is_dir() {
+                files.extend(walk(&path));
+            } else {
+                files.push(path);
+            }
+        }
+        files
+    }
+}
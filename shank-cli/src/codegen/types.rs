@@ -0,0 +1,155 @@
+use std::collections::BTreeSet;
+
+use anyhow::{anyhow, Result};
+use serde_json::Value;
+
+use crate::codegen::{items, snake_case, str_field, Program, HEADER};
+
+/// The `jito_bytemuck` Pod wrappers are plain integers on the wire
+const POD_TYPES: [(&str, &str); 5] = [
+    ("PodBool", "bool"),
+    ("PodU16", "u16"),
+    ("PodU32", "u32"),
+    ("PodU64", "u64"),
+    ("PodU128", "u128"),
+];
+
+/// serde can't derive arrays longer than this without `serde_with::Bytes`
+const MAX_SERDE_ARRAY_LEN: u64 = 32;
+
+/// The imports a generated file needs for the types it references
+#[derive(Default)]
+pub(crate) struct TypeImports {
+    pub pubkey: bool,
+    pub defined: BTreeSet<String>,
+}
+
+impl TypeImports {
+    /// Renders the `use` block: borsh and solana first, then the generated types
+    pub fn render(&self, external: &[&str]) -> String {
+        let mut out = String::new();
+        for import in external {
+            out.push_str(&format!("use {};\n", import));
+        }
+        if self.pubkey {
+            out.push_str("use solana_program::pubkey::Pubkey;\n");
+        }
+        match self.defined.len() {
+            0 => {}
+            1 => out.push_str(&format!(
+                "\nuse crate::generated::types::{};\n",
+                self.defined.iter().next().unwrap()
+            )),
+            _ => out.push_str(&format!(
+                "\nuse crate::generated::types::{{{}}};\n",
+                self.defined.iter().cloned().collect::<Vec<_>>().join(", ")
+            )),
+        }
+        out
+    }
+}
+
+/// Maps an IDL type to the Rust type used by the client
+pub(crate) fn rust_type(ty: &Value, imports: &mut TypeImports) -> Result<String> {
+    if let Some(primitive) = ty.as_str() {
+        return Ok(match primitive {
+            "publicKey" => {
+                imports.pubkey = true;
+                "Pubkey".to_string()
+            }
+            "string" => "String".to_string(),
+            "bytes" => "Vec<u8>".to_string(),
+            _ => primitive.to_string(),
+        });
+    }
+    if let Some(defined) = ty["defined"].as_str() {
+        if let Some((_, primitive)) = POD_TYPES.iter().find(|(name, _)| *name == defined) {
+            return Ok(primitive.to_string());
+        }
+        imports.defined.insert(defined.to_string());
+        return Ok(defined.to_string());
+    }
+    if let Some([element, len]) = ty["array"].as_array().map(Vec::as_slice) {
+        let len = len
+            .as_u64()
+            .ok_or_else(|| anyhow!("Unsupported array length: {}", ty))?;
+        return Ok(format!("[{}; {}]", rust_type(element, imports)?, len));
+    }
+    if !ty["option"].is_null() {
+        return Ok(format!("Option<{}>", rust_type(&ty["option"], imports)?));
+    }
+    if !ty["vec"].is_null() {
+        return Ok(format!("Vec<{}>", rust_type(&ty["vec"], imports)?));
+    }
+    Err(anyhow!("Unsupported IDL type: {}", ty))
+}
+
+/// Renders the fields of a borsh struct, adding serde adapters for types serde can't derive
+pub(crate) fn render_struct_fields(fields: &[Value], imports: &mut TypeImports) -> Result<String> {
+    let mut out = String::new();
+    for field in fields {
+        let ty = &field["type"];
+        if ty.as_str() == Some("publicKey") {
+            out.push_str(
+                "#[cfg_attr(feature = \"serde\", serde(with = \"serde_with::As::<serde_with::DisplayFromStr>\"))]\n",
+            );
+        }
+        if ty["array"][1]
+            .as_u64()
+            .is_some_and(|len| len > MAX_SERDE_ARRAY_LEN)
+        {
+            out.push_str(
+                "#[cfg_attr(feature = \"serde\", serde(with = \"serde_with::As::<serde_with::Bytes>\"))]\n",
+            );
+        }
+        out.push_str(&format!(
+            "pub {}: {},\n",
+            snake_case(str_field(field, "name")?),
+            rust_type(ty, imports)?
+        ));
+    }
+    Ok(out)
+}
+
+pub(crate) fn render_defined_type(_program: &Program, item: &Value) -> Result<String> {
+    let name = str_field(item, "name")?;
+    let definition = &item["type"];
+    match str_field(definition, "kind")? {
+        "struct" => {
+            let mut imports = TypeImports::default();
+            let fields = render_struct_fields(items(definition, "fields"), &mut imports)?;
+            Ok(format!(
+                "{HEADER}{imports}
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = \"serde\", derive(serde::Serialize, serde::Deserialize))]
+pub struct {name} {{
+{fields}}}
+",
+                imports = imports.render(&["borsh::{BorshDeserialize, BorshSerialize}"]),
+            ))
+        }
+        "enum" => {
+            let mut variants = String::new();
+            for variant in items(definition, "variants") {
+                if !variant["fields"].is_null() {
+                    return Err(anyhow!(
+                        "Enum variants with data are not supported: {}",
+                        name
+                    ));
+                }
+                variants.push_str(&format!("{},\n", str_field(variant, "name")?));
+            }
+            Ok(format!(
+                "{HEADER}use borsh::{{BorshDeserialize, BorshSerialize}};
+use num_derive::FromPrimitive;
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq, Copy, PartialOrd, Hash, FromPrimitive)]
+#[cfg_attr(feature = \"serde\", derive(serde::Serialize, serde::Deserialize))]
+pub enum {name} {{
+{variants}}}
+"
+            ))
+        }
+        kind => Err(anyhow!("Unsupported type kind `{}` for {}", kind, name)),
+    }
+}
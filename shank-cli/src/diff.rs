@@ -0,0 +1,691 @@
+//! Compares two shank IDLs and classifies every difference as additive or breaking.
+//!
+//! Account layouts are compared by byte offset: a field may be renamed or carved out of the
+//! trailing `reserved` array, but anything that moves, resizes or retypes existing data is
+//! breaking. Instructions are compared by name: their discriminants, account order and argument
+//! encoding must not change for existing callers.
+
+use std::{collections::HashMap, fmt};
+
+use serde_json::Value;
+
+/// Sizes of the `jito_bytemuck` Pod wrappers, which the IDL references without defining
+const POD_TYPE_SIZES: [(&str, usize); 5] = [
+    ("PodBool", 1),
+    ("PodU16", 2),
+    ("PodU32", 4),
+    ("PodU64", 8),
+    ("PodU128", 16),
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChangeKind {
+    Additive,
+    Breaking,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IdlChange {
+    pub kind: ChangeKind,
+    /// The account, type or instruction that changed
+    pub item: String,
+    pub description: String,
+}
+
+impl fmt::Display for IdlChange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let kind = match self.kind {
+            ChangeKind::Additive => "additive",
+            ChangeKind::Breaking => "breaking",
+        };
+        write!(f, "[{}] {}: {}", kind, self.item, self.description)
+    }
+}
+
+/// Returns every difference between the committed IDL and a freshly extracted one
+pub fn diff_idls(old: &Value, new: &Value) -> Vec<IdlChange> {
+    let mut changes = Vec::new();
+    let old_types = TypeTable::new(old);
+    let new_types = TypeTable::new(new);
+
+    for section in ["accounts", "types"] {
+        let old_items = named_items(old, section);
+        let new_items = named_items(new, section);
+        for (name, old_item) in old_items.iter() {
+            let item = format!("{} {}", &section[..section.len() - 1], name);
+            match new_items.get(name) {
+                None => changes.push(breaking(&item, "removed")),
+                Some(new_item) => diff_type_definition(
+                    &item,
+                    &old_item["type"],
+                    &new_item["type"],
+                    &old_types,
+                    &new_types,
+                    &mut changes,
+                ),
+            }
+        }
+        for name in new_items.keys() {
+            if !old_items.contains_key(name) {
+                changes.push(additive(
+                    &format!("{} {}", &section[..section.len() - 1], name),
+                    "added",
+                ));
+            }
+        }
+    }
+
+    let old_instructions = named_items(old, "instructions");
+    let new_instructions = named_items(new, "instructions");
+    for (name, old_instruction) in old_instructions.iter() {
+        let item = format!("instruction {}", name);
+        match new_instructions.get(name) {
+            None => changes.push(breaking(&item, "removed")),
+            Some(new_instruction) => {
+                diff_instruction(&item, old_instruction, new_instruction, &mut changes)
+            }
+        }
+    }
+    for (name, new_instruction) in new_instructions.iter() {
+        if old_instructions.contains_key(name) {
+            continue;
+        }
+        let item = format!("instruction {}", name);
+        let discriminant = &new_instruction["discriminant"]["value"];
+        let reused_by = old_instructions
+            .iter()
+            .find(|(_, old_instruction)| old_instruction["discriminant"]["value"] == *discriminant);
+        match reused_by {
+            // Removals are reported separately, but a renamed instruction keeps its callers
+            Some((old_name, old_instruction)) if !new_instructions.contains_key(old_name) => {
+                changes.push(additive(&item, &format!("renamed from {}", old_name)));
+                changes.retain(|change| change.item != format!("instruction {}", old_name));
+                diff_instruction(&item, old_instruction, new_instruction, &mut changes);
+            }
+            _ => changes.push(additive(
+                &item,
+                &format!("added with discriminant {}", discriminant),
+            )),
+        }
+    }
+
+    changes
+}
+
+fn breaking(item: &str, description: &str) -> IdlChange {
+    IdlChange {
+        kind: ChangeKind::Breaking,
+        item: item.to_string(),
+        description: description.to_string(),
+    }
+}
+
+fn additive(item: &str, description: &str) -> IdlChange {
+    IdlChange {
+        kind: ChangeKind::Additive,
+        item: item.to_string(),
+        description: description.to_string(),
+    }
+}
+
+/// The items of an IDL section keyed by name, in a stable order
+fn named_items<'a>(idl: &'a Value, section: &str) -> Vec<(&'a str, &'a Value)> {
+    idl[section]
+        .as_array()
+        .map(|items| {
+            items
+                .iter()
+                .filter_map(|item| item["name"].as_str().map(|name| (name, item)))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+trait NamedItems<'a> {
+    fn get(&self, name: &str) -> Option<&'a Value>;
+    fn contains_key(&self, name: &str) -> bool;
+    fn keys(&self) -> Vec<&'a str>;
+}
+
+impl<'a> NamedItems<'a> for Vec<(&'a str, &'a Value)> {
+    fn get(&self, name: &str) -> Option<&'a Value> {
+        self.iter()
+            .find(|(item_name, _)| *item_name == name)
+            .map(|(_, item)| *item)
+    }
+
+    fn contains_key(&self, name: &str) -> bool {
+        self.get(name).is_some()
+    }
+
+    fn keys(&self) -> Vec<&'a str> {
+        self.iter().map(|(name, _)| *name).collect()
+    }
+}
+
+/// Resolves the size of types defined in the IDL
+struct TypeTable<'a> {
+    definitions: HashMap<&'a str, &'a Value>,
+}
+
+impl<'a> TypeTable<'a> {
+    fn new(idl: &'a Value) -> Self {
+        let mut definitions = HashMap::new();
+        for section in ["accounts", "types"] {
+            for (name, item) in named_items(idl, section) {
+                definitions.insert(name, &item["type"]);
+            }
+        }
+        Self { definitions }
+    }
+
+    /// The serialized size of a type, or None if it's variable length
+    fn size_of(&self, ty: &Value) -> Option<usize> {
+        if let Some(primitive) = ty.as_str() {
+            return match primitive {
+                "bool" | "u8" | "i8" => Some(1),
+                "u16" | "i16" => Some(2),
+                "u32" | "i32" | "f32" => Some(4),
+                "u64" | "i64" | "f64" => Some(8),
+                "u128" | "i128" => Some(16),
+                "publicKey" => Some(32),
+                _ => None,
+            };
+        }
+        if let Some([element, len]) = ty["array"].as_array().map(Vec::as_slice) {
+            return Some(self.size_of(element)? * len.as_u64()? as usize);
+        }
+        if let Some(defined) = ty["defined"].as_str() {
+            if let Some((_, size)) = POD_TYPE_SIZES.iter().find(|(name, _)| *name == defined) {
+                return Some(*size);
+            }
+            let definition = self.definitions.get(defined)?;
+            return match definition["kind"].as_str()? {
+                "struct" => definition["fields"]
+                    .as_array()?
+                    .iter()
+                    .map(|field| self.size_of(&field["type"]))
+                    .sum(),
+                // Fieldless enums are stored as a u8
+                "enum" => Some(1),
+                _ => None,
+            };
+        }
+        None
+    }
+}
+
+struct Field<'a> {
+    name: &'a str,
+    ty: &'a Value,
+    offset: Option<usize>,
+    size: Option<usize>,
+}
+
+fn struct_fields<'a>(definition: &'a Value, types: &TypeTable) -> Vec<Field<'a>> {
+    let mut offset = Some(0);
+    definition["fields"]
+        .as_array()
+        .map(|fields| {
+            fields
+                .iter()
+                .map(|field| {
+                    let size = types.size_of(&field["type"]);
+                    let current = Field {
+                        name: field["name"].as_str().unwrap_or_default(),
+                        ty: &field["type"],
+                        offset,
+                        size,
+                    };
+                    offset = offset.zip(size).map(|(offset, size)| offset + size);
+                    current
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn is_reserved(field: &Field) -> bool {
+    field.name.starts_with("reserved")
+}
+
+fn diff_type_definition(
+    item: &str,
+    old: &Value,
+    new: &Value,
+    old_types: &TypeTable,
+    new_types: &TypeTable,
+    changes: &mut Vec<IdlChange>,
+) {
+    match (old["kind"].as_str(), new["kind"].as_str()) {
+        (Some("struct"), Some("struct")) => {
+            diff_struct(item, old, new, old_types, new_types, changes)
+        }
+        (Some("enum"), Some("enum")) => diff_enum(item, old, new, changes),
+        (old_kind, new_kind) => changes.push(breaking(
+            item,
+            &format!(
+                "changed from {} to {}",
+                old_kind.unwrap_or("unknown"),
+                new_kind.unwrap_or("unknown")
+            ),
+        )),
+    }
+}
+
+fn diff_struct(
+    item: &str,
+    old: &Value,
+    new: &Value,
+    old_types: &TypeTable,
+    new_types: &TypeTable,
+    changes: &mut Vec<IdlChange>,
+) {
+    let old_fields = struct_fields(old, old_types);
+    let new_fields = struct_fields(new, new_types);
+
+    for (old_index, old_field) in old_fields.iter().enumerate() {
+        if is_reserved(old_field) {
+            continue;
+        }
+        let at_offset = new_fields
+            .iter()
+            .find(|new_field| old_field.offset.is_some() && new_field.offset == old_field.offset);
+        let by_name = new_fields
+            .iter()
+            .position(|new_field| new_field.name == old_field.name);
+
+        match (at_offset, by_name) {
+            (_, Some(new_index)) if new_fields[new_index].offset != old_field.offset => {
+                changes.push(breaking(
+                    item,
+                    &format!(
+                        "field {} moved from position {} to {}",
+                        old_field.name, old_index, new_index
+                    ),
+                ));
+            }
+            (_, Some(new_index)) => {
+                let new_field = &new_fields[new_index];
+                if new_field.ty != old_field.ty {
+                    changes.push(breaking(
+                        item,
+                        &format!(
+                            "field {} changed type from {} to {}",
+                            old_field.name, old_field.ty, new_field.ty
+                        ),
+                    ));
+                } else if new_field.size != old_field.size {
+                    changes.push(breaking(
+                        item,
+                        &format!("field {} changed size", old_field.name),
+                    ));
+                }
+            }
+            (Some(new_field), None) if new_field.ty == old_field.ty && !is_reserved(new_field) => {
+                changes.push(additive(
+                    item,
+                    &format!("field {} renamed to {}", old_field.name, new_field.name),
+                ));
+            }
+            _ => changes.push(breaking(item, &format!("field {} removed", old_field.name))),
+        }
+    }
+
+    for new_field in new_fields.iter() {
+        if is_reserved(new_field)
+            || old_fields
+                .iter()
+                .any(|old_field| old_field.name == new_field.name)
+            || old_fields.iter().any(|old_field| {
+                !is_reserved(old_field)
+                    && old_field.offset == new_field.offset
+                    && old_field.ty == new_field.ty
+            })
+        {
+            continue;
+        }
+        // A new field is only additive if it sits where reserved space used to be
+        let carved_from_reserved = old_fields.iter().any(|old_field| {
+            is_reserved(old_field)
+                && old_field
+                    .offset
+                    .zip(old_field.size)
+                    .zip(new_field.offset.zip(new_field.size))
+                    .is_some_and(|((reserved_start, reserved_len), (start, len))| {
+                        start >= reserved_start && start + len <= reserved_start + reserved_len
+                    })
+        });
+        if carved_from_reserved {
+            changes.push(additive(
+                item,
+                &format!("field {} added in reserved space", new_field.name),
+            ));
+        } else {
+            changes.push(breaking(
+                item,
+                &format!("field {} added outside reserved space", new_field.name),
+            ));
+        }
+    }
+
+    let old_size = old_fields
+        .iter()
+        .map(|field| field.size)
+        .sum::<Option<usize>>();
+    let new_size = new_fields
+        .iter()
+        .map(|field| field.size)
+        .sum::<Option<usize>>();
+    if old_size != new_size {
+        changes.push(breaking(
+            item,
+            &format!(
+                "size changed from {} to {}",
+                display_size(old_size),
+                display_size(new_size)
+            ),
+        ));
+    }
+}
+
+fn display_size(size: Option<usize>) -> String {
+    size.map(|size| size.to_string())
+        .unwrap_or_else(|| "variable".to_string())
+}
+
+fn diff_enum(item: &str, old: &Value, new: &Value, changes: &mut Vec<IdlChange>) {
+    let variant_names = |definition: &Value| -> Vec<String> {
+        definition["variants"]
+            .as_array()
+            .map(|variants| {
+                variants
+                    .iter()
+                    .filter_map(|variant| variant["name"].as_str().map(str::to_string))
+                    .collect()
+            })
+            .unwrap_or_default()
+    };
+    let old_variants = variant_names(old);
+    let new_variants = variant_names(new);
+
+    for (old_index, name) in old_variants.iter().enumerate() {
+        match new_variants.iter().position(|new_name| new_name == name) {
+            None => changes.push(breaking(item, &format!("variant {} removed", name))),
+            Some(new_index) if new_index != old_index => changes.push(breaking(
+                item,
+                &format!(
+                    "variant {} renumbered from {} to {}",
+                    name, old_index, new_index
+                ),
+            )),
+            Some(_) => {}
+        }
+    }
+    for (new_index, name) in new_variants.iter().enumerate() {
+        if !old_variants.contains(name) {
+            let kind = if new_index >= old_variants.len() {
+                ChangeKind::Additive
+            } else {
+                ChangeKind::Breaking
+            };
+            changes.push(IdlChange {
+                kind,
+                item: item.to_string(),
+                description: format!("variant {} added at {}", name, new_index),
+            });
+        }
+    }
+}
+
+fn diff_instruction(item: &str, old: &Value, new: &Value, changes: &mut Vec<IdlChange>) {
+    let old_discriminant = &old["discriminant"]["value"];
+    let new_discriminant = &new["discriminant"]["value"];
+    if old_discriminant != new_discriminant {
+        changes.push(breaking(
+            item,
+            &format!(
+                "discriminant renumbered from {} to {}",
+                old_discriminant, new_discriminant
+            ),
+        ));
+    }
+
+    let empty = Vec::new();
+    let old_accounts = old["accounts"].as_array().unwrap_or(&empty);
+    let new_accounts = new["accounts"].as_array().unwrap_or(&empty);
+    for (index, old_account) in old_accounts.iter().enumerate() {
+        let name = &old_account["name"];
+        match new_accounts
+            .iter()
+            .position(|account| account["name"] == *name)
+        {
+            None => changes.push(breaking(item, &format!("account {} removed", name))),
+            Some(new_index) if new_index != index => changes.push(breaking(
+                item,
+                &format!("account {} moved from {} to {}", name, index, new_index),
+            )),
+            Some(new_index) => {
+                let new_account = &new_accounts[new_index];
+                for flag in ["isMut", "isSigner"] {
+                    let was = old_account[flag].as_bool().unwrap_or(false);
+                    let is = new_account[flag].as_bool().unwrap_or(false);
+                    if was != is {
+                        // Callers already passing a writable signer keep working if it's relaxed
+                        let kind = if is {
+                            ChangeKind::Breaking
+                        } else {
+                            ChangeKind::Additive
+                        };
+                        changes.push(IdlChange {
+                            kind,
+                            item: item.to_string(),
+                            description: format!("account {} {} changed to {}", name, flag, is),
+                        });
+                    }
+                }
+            }
+        }
+    }
+    for (index, new_account) in new_accounts.iter().enumerate() {
+        let name = &new_account["name"];
+        if old_accounts.iter().any(|account| account["name"] == *name) {
+            continue;
+        }
+        let is_optional = new_account["isOptional"].as_bool().unwrap_or(false);
+        let kind = if is_optional && index >= old_accounts.len() {
+            ChangeKind::Additive
+        } else {
+            ChangeKind::Breaking
+        };
+        changes.push(IdlChange {
+            kind,
+            item: item.to_string(),
+            description: format!("account {} added at {}", name, index),
+        });
+    }
+
+    // Arguments are borsh encoded back to back, so only renames are compatible
+    let old_args = old["args"].as_array().unwrap_or(&empty);
+    let new_args = new["args"].as_array().unwrap_or(&empty);
+    let old_arg_types: Vec<&Value> = old_args.iter().map(|arg| &arg["type"]).collect();
+    let new_arg_types: Vec<&Value> = new_args.iter().map(|arg| &arg["type"]).collect();
+    if old_arg_types != new_arg_types {
+        changes.push(breaking(item, "arguments changed"));
+    } else {
+        for (old_arg, new_arg) in old_args.iter().zip(new_args.iter()) {
+            if old_arg["name"] != new_arg["name"] {
+                changes.push(additive(
+                    item,
+                    &format!(
+                        "argument {} renamed to {}",
+                        old_arg["name"], new_arg["name"]
+                    ),
+                ));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, Value};
+
+    use super::{diff_idls, ChangeKind};
+
+    fn idl_with_account(fields: Value) -> Value {
+        json!({
+            "accounts": [{ "name": "Vault", "type": { "kind": "struct", "fields": fields } }],
+            "types": [],
+            "instructions": [],
+        })
+    }
+
+    fn idl_with_instructions(instructions: Value) -> Value {
+        json!({ "accounts": [], "types": [], "instructions": instructions })
+    }
+
+    fn kinds(old: &Value, new: &Value) -> Vec<ChangeKind> {
+        diff_idls(old, new)
+            .into_iter()
+            .map(|change| change.kind)
+            .collect()
+    }
+
+    #[test]
+    fn test_identical_idls() {
+        let idl: Value = serde_json::from_str(include_str!("../../idl/jito_vault.json")).unwrap();
+        assert!(diff_idls(&idl, &idl).is_empty());
+    }
+
+    #[test]
+    fn test_field_carved_from_reserved_is_additive() {
+        let old = idl_with_account(json!([
+            { "name": "admin", "type": "publicKey" },
+            { "name": "reserved", "type": { "array": ["u8", 64] } },
+        ]));
+        let new = idl_with_account(json!([
+            { "name": "admin", "type": "publicKey" },
+            { "name": "epoch", "type": { "defined": "PodU64" } },
+            { "name": "reserved", "type": { "array": ["u8", 56] } },
+        ]));
+        assert_eq!(kinds(&old, &new), vec![ChangeKind::Additive]);
+    }
+
+    #[test]
+    fn test_field_added_past_reserved_is_breaking() {
+        let old = idl_with_account(json!([
+            { "name": "admin", "type": "publicKey" },
+            { "name": "reserved", "type": { "array": ["u8", 4] } },
+        ]));
+        let new = idl_with_account(json!([
+            { "name": "admin", "type": "publicKey" },
+            { "name": "epoch", "type": { "defined": "PodU64" } },
+            { "name": "reserved", "type": { "array": ["u8", 4] } },
+        ]));
+        let changes = diff_idls(&old, &new);
+        assert!(changes
+            .iter()
+            .all(|change| change.kind == ChangeKind::Breaking));
+        assert!(changes
+            .iter()
+            .any(|change| change.description.starts_with("size changed")));
+    }
+
+    #[test]
+    fn test_field_reorder_is_breaking() {
+        let old = idl_with_account(json!([
+            { "name": "admin", "type": "publicKey" },
+            { "name": "operator", "type": "publicKey" },
+        ]));
+        let new = idl_with_account(json!([
+            { "name": "operator", "type": "publicKey" },
+            { "name": "admin", "type": "publicKey" },
+        ]));
+        assert_eq!(
+            kinds(&old, &new),
+            vec![ChangeKind::Breaking, ChangeKind::Breaking]
+        );
+    }
+
+    #[test]
+    fn test_field_rename_is_additive() {
+        let old = idl_with_account(json!([{ "name": "admin", "type": "publicKey" }]));
+        let new = idl_with_account(json!([{ "name": "vaultAdmin", "type": "publicKey" }]));
+        assert_eq!(kinds(&old, &new), vec![ChangeKind::Additive]);
+    }
+
+    #[test]
+    fn test_instruction_renumbered_is_breaking() {
+        let old = idl_with_instructions(json!([
+            { "name": "MintTo", "accounts": [], "args": [], "discriminant": { "type": "u8", "value": 1 } },
+            { "name": "Burn", "accounts": [], "args": [], "discriminant": { "type": "u8", "value": 2 } },
+        ]));
+        let new = idl_with_instructions(json!([
+            { "name": "Burn", "accounts": [], "args": [], "discriminant": { "type": "u8", "value": 1 } },
+            { "name": "MintTo", "accounts": [], "args": [], "discriminant": { "type": "u8", "value": 2 } },
+        ]));
+        assert_eq!(
+            kinds(&old, &new),
+            vec![ChangeKind::Breaking, ChangeKind::Breaking]
+        );
+    }
+
+    #[test]
+    fn test_instruction_appended_is_additive() {
+        let old = idl_with_instructions(json!([
+            { "name": "MintTo", "accounts": [], "args": [], "discriminant": { "type": "u8", "value": 0 } },
+        ]));
+        let new = idl_with_instructions(json!([
+            { "name": "MintTo", "accounts": [], "args": [], "discriminant": { "type": "u8", "value": 0 } },
+            { "name": "Burn", "accounts": [], "args": [], "discriminant": { "type": "u8", "value": 1 } },
+        ]));
+        assert_eq!(kinds(&old, &new), vec![ChangeKind::Additive]);
+    }
+
+    #[test]
+    fn test_instruction_account_order_is_breaking() {
+        let old = idl_with_instructions(json!([{
+            "name": "MintTo",
+            "accounts": [
+                { "name": "vault", "isMut": true, "isSigner": false },
+                { "name": "depositor", "isMut": false, "isSigner": true },
+            ],
+            "args": [],
+            "discriminant": { "type": "u8", "value": 0 },
+        }]));
+        let new = idl_with_instructions(json!([{
+            "name": "MintTo",
+            "accounts": [
+                { "name": "depositor", "isMut": false, "isSigner": true },
+                { "name": "vault", "isMut": true, "isSigner": false },
+            ],
+            "args": [],
+            "discriminant": { "type": "u8", "value": 0 },
+        }]));
+        assert_eq!(
+            kinds(&old, &new),
+            vec![ChangeKind::Breaking, ChangeKind::Breaking]
+        );
+    }
+
+    #[test]
+    fn test_optional_account_appended_is_additive() {
+        let old = idl_with_instructions(json!([{
+            "name": "MintTo",
+            "accounts": [{ "name": "vault", "isMut": true, "isSigner": false }],
+            "args": [{ "name": "amount", "type": "u64" }],
+            "discriminant": { "type": "u8", "value": 0 },
+        }]));
+        let new = idl_with_instructions(json!([{
+            "name": "MintTo",
+            "accounts": [
+                { "name": "vault", "isMut": true, "isSigner": false },
+                { "name": "mintSigner", "isMut": false, "isSigner": true, "isOptional": true },
+            ],
+            "args": [{ "name": "amount", "type": "u64" }],
+            "discriminant": { "type": "u8", "value": 0 },
+        }]));
+        assert_eq!(kinds(&old, &new), vec![ChangeKind::Additive]);
+    }
+}
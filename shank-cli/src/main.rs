@@ -1,18 +1,44 @@
-use std::{fs::File, io::Write};
+mod codegen;
+mod diff;
+
+use std::{fs::File, io::Write, path::Path};
 
 use anyhow::{anyhow, Result};
+use clap::{Parser, Subcommand};
 use env_logger::Env;
 use log::{debug, info};
-use shank_idl::{extract_idl, manifest::Manifest, ParseIdlOpts};
+use shank_idl::{extract_idl, idl::Idl, manifest::Manifest, ParseIdlOpts};
+
+use crate::diff::{diff_idls, ChangeKind};
+
+#[derive(Parser)]
+#[command(author, version, about = "Generates and checks the Jito program IDLs")]
+struct Args {
+    #[command(subcommand)]
+    command: Option<Command>,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Extract the IDLs and write them to the idl directory (default)
+    Generate,
+    /// Compare freshly extracted IDLs against the committed ones and fail on breaking changes
+    Diff,
+    /// Generate the Rust clients in clients/rust from the committed IDLs
+    GenerateClients,
+}
 
 struct IdlConfiguration {
     program_id: String,
     name: &'static str,
     paths: Vec<&'static str>,
+    /// The crate in clients/rust generated from this IDL
+    client: &'static str,
 }
 
 fn main() -> Result<()> {
     env_logger::Builder::from_env(Env::default().default_filter_or("info")).init();
+    let args = Args::parse();
     let crate_root = std::env::current_dir()?;
 
     let envs = envfile::EnvFile::new(crate_root.join("config").join("program.env"))?;
@@ -36,6 +62,7 @@ fn main() -> Result<()> {
                 "bytemuck",
                 "core",
             ],
+            client: "restaking_client",
         },
         IdlConfiguration {
             program_id: vault_program_id,
@@ -47,73 +74,127 @@ fn main() -> Result<()> {
                 "bytemuck",
                 "core",
             ],
+            client: "vault_client",
         },
     ];
 
-    let crate_root = std::env::current_dir().unwrap();
     let out_dir = crate_root.join("idl");
-    for idl in idl_configs {
-        let mut idls = Vec::new();
-        for path in idl.paths {
-            let cargo_toml = crate_root.join(path).join("Cargo.toml");
-            if !cargo_toml.exists() {
-                return Err(anyhow!(
-                    "Did not find Cargo.toml at the path: {}",
-                    crate_root.display()
-                ));
+    match args.command.unwrap_or(Command::Generate) {
+        Command::Generate => {
+            for idl in idl_configs {
+                let idl_json = extract_program_idl(&crate_root, &idl)?.try_into_json()?;
+                let mut idl_path = out_dir.join(idl.name);
+                idl_path.set_extension("json");
+
+                info!("Writing IDL to {:?}", idl_path);
+                let mut idl_json_file = File::create(idl_path)?;
+                idl_json_file.write_all(idl_json.as_bytes())?;
             }
-            let manifest = Manifest::from_path(&cargo_toml)?;
-            debug!("manifest: {:?}", manifest);
-            let lib_rel_path = manifest
-                .lib_rel_path()
-                .ok_or_else(|| anyhow!("Program needs to be a lib"))?;
-            debug!("lib_rel_path: {:?}", lib_rel_path);
-            let lib_full_path_str = crate_root.join(path).join(lib_rel_path);
-            let lib_full_path = lib_full_path_str
-                .to_str()
-                .ok_or_else(|| anyhow!("Invalid Path"))?;
-            debug!("lib_full_path: {:?}", lib_full_path);
-            // Extract IDL and convert to JSON
-            let opts = ParseIdlOpts {
-                program_address_override: Some(idl.program_id.to_string()),
-                ..ParseIdlOpts::default()
-            };
-            let idl = extract_idl(lib_full_path, opts)?
-                .ok_or_else(|| anyhow!("No IDL could be extracted"))?;
-            idls.push(idl);
         }
+        Command::Diff => {
+            let mut breaking_changes = 0;
+            for idl in idl_configs {
+                let mut idl_path = out_dir.join(idl.name);
+                idl_path.set_extension("json");
+
+                let committed: serde_json::Value =
+                    serde_json::from_str(&std::fs::read_to_string(&idl_path)?)?;
+                let extracted: serde_json::Value = serde_json::from_str(
+                    &extract_program_idl(&crate_root, &idl)?.try_into_json()?,
+                )?;
 
-        let mut accumulator = idls.pop().unwrap();
-        for other_idls in idls {
-            accumulator.constants.extend(other_idls.constants);
-            accumulator.instructions.extend(other_idls.instructions);
-            accumulator.accounts.extend(other_idls.accounts);
-            accumulator.types.extend(other_idls.types);
-            if let Some(events) = other_idls.events {
-                if let Some(accumulator_events) = &mut accumulator.events {
-                    accumulator_events.extend(events);
-                } else {
-                    accumulator.events = Some(events);
+                let changes = diff_idls(&committed, &extracted);
+                if changes.is_empty() {
+                    info!("{}: no changes", idl.name);
                 }
-            }
-            if let Some(errors) = other_idls.errors {
-                if let Some(accumulator_errors) = &mut accumulator.errors {
-                    accumulator_errors.extend(errors);
-                } else {
-                    accumulator.errors = Some(errors);
+                for change in changes {
+                    println!("{}: {}", idl.name, change);
+                    if change.kind == ChangeKind::Breaking {
+                        breaking_changes += 1;
+                    }
                 }
             }
+            if breaking_changes > 0 {
+                return Err(anyhow!("Found {} breaking IDL change(s)", breaking_changes));
+            }
         }
-        accumulator.name = idl.name.to_string();
-
-        let idl_json = accumulator.try_into_json()?;
-        let mut idl_path = out_dir.join(idl.name);
-        idl_path.set_extension("json");
+        Command::GenerateClients => {
+            for idl in idl_configs {
+                let mut idl_path = out_dir.join(idl.name);
+                idl_path.set_extension("json");
+                let idl_json: serde_json::Value =
+                    serde_json::from_str(&std::fs::read_to_string(&idl_path)?)?;
 
-        info!("Writing IDL to {:?}", idl_path);
-        let mut idl_json_file = File::create(idl_path)?;
-        idl_json_file.write_all(idl_json.as_bytes())?;
+                let client_dir = crate_root
+                    .join("clients")
+                    .join("rust")
+                    .join(idl.client)
+                    .join("src")
+                    .join("generated");
+                info!("Writing {} client to {:?}", idl.name, client_dir);
+                let files = codegen::generate_client(&idl_json)?;
+                codegen::write_client(&files, &crate_root.join("rustfmt.toml"), &client_dir)?;
+            }
+        }
     }
 
     Ok(())
 }
+
+/// Extracts and merges the IDLs of every crate that makes up a program
+fn extract_program_idl(crate_root: &Path, idl: &IdlConfiguration) -> Result<Idl> {
+    let mut idls = Vec::new();
+    for path in idl.paths.iter() {
+        let cargo_toml = crate_root.join(path).join("Cargo.toml");
+        if !cargo_toml.exists() {
+            return Err(anyhow!(
+                "Did not find Cargo.toml at the path: {}",
+                crate_root.display()
+            ));
+        }
+        let manifest = Manifest::from_path(&cargo_toml)?;
+        debug!("manifest: {:?}", manifest);
+        let lib_rel_path = manifest
+            .lib_rel_path()
+            .ok_or_else(|| anyhow!("Program needs to be a lib"))?;
+        debug!("lib_rel_path: {:?}", lib_rel_path);
+        let lib_full_path_str = crate_root.join(path).join(lib_rel_path);
+        let lib_full_path = lib_full_path_str
+            .to_str()
+            .ok_or_else(|| anyhow!("Invalid Path"))?;
+        debug!("lib_full_path: {:?}", lib_full_path);
+        // Extract IDL and convert to JSON
+        let opts = ParseIdlOpts {
+            program_address_override: Some(idl.program_id.to_string()),
+            ..ParseIdlOpts::default()
+        };
+        let extracted = extract_idl(lib_full_path, opts)?
+            .ok_or_else(|| anyhow!("No IDL could be extracted"))?;
+        idls.push(extracted);
+    }
+
+    let mut accumulator = idls.pop().unwrap();
+    for other_idls in idls {
+        accumulator.constants.extend(other_idls.constants);
+        accumulator.instructions.extend(other_idls.instructions);
+        accumulator.accounts.extend(other_idls.accounts);
+        accumulator.types.extend(other_idls.types);
+        if let Some(events) = other_idls.events {
+            if let Some(accumulator_events) = &mut accumulator.events {
+                accumulator_events.extend(events);
+            } else {
+                accumulator.events = Some(events);
+            }
+        }
+        if let Some(errors) = other_idls.errors {
+            if let Some(accumulator_errors) = &mut accumulator.errors {
+                accumulator_errors.extend(errors);
+            } else {
+                accumulator.errors = Some(errors);
+            }
+        }
+    }
+    accumulator.name = idl.name.to_string();
+
+    Ok(accumulator)
+}
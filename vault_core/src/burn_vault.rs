@@ -1,3 +1,4 @@
+use jito_jsm_core::diagnostic::Check;
 use solana_program::{account_info::AccountInfo, msg, program_error::ProgramError, pubkey::Pubkey};
 
 // Empty PDA to send tokens to "burn"
@@ -41,11 +42,13 @@ impl BurnVault {
     ) -> Result<(), ProgramError> {
         if account.owner.ne(&solana_program::system_program::ID) {
             msg!("Burn Vault account has an invalid owner");
+            Check::Owner.log_failure(account.key);
             return Err(ProgramError::InvalidAccountOwner);
         }
 
         if expect_writable && !account.is_writable {
             msg!("Burn Vault account is not writable");
+            Check::Writable.log_failure(account.key);
             return Err(ProgramError::InvalidAccountData);
         }
 
@@ -54,6 +57,7 @@ impl BurnVault {
             .ne(&Self::find_program_address(program_id, base).0)
         {
             msg!("Burn Vault account is not at the correct PDA");
+            Check::Address.log_failure(account.key);
             return Err(ProgramError::InvalidAccountData);
         }
         Ok(())
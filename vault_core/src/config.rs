@@ -5,22 +5,25 @@ use std::fmt::Debug;
 use bytemuck::{Pod, Zeroable};
 use jito_bytemuck::{
     types::{PodU16, PodU64},
-    AccountDeserialize, Discriminator,
+    AccountDeserialize, JitoAccount,
 };
 use jito_vault_sdk::error::VaultError;
 use shank::ShankAccount;
 use solana_program::{
-    account_info::AccountInfo, epoch_schedule::DEFAULT_SLOTS_PER_EPOCH, msg,
-    program_error::ProgramError, pubkey::Pubkey,
+    epoch_schedule::DEFAULT_SLOTS_PER_EPOCH, msg, program_error::ProgramError, pubkey::Pubkey,
 };
 
-use crate::MAX_BPS;
+use crate::{discriminators::VaultDiscriminator, MAX_BPS};
 
 const RESERVED_SPACE_LEN: usize = 229;
 
 /// The vault configuration account for the vault program.
 /// Manages program-wide settings and state.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Pod, Zeroable, AccountDeserialize, ShankAccount)]
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Pod, Zeroable, AccountDeserialize, JitoAccount, ShankAccount,
+)]
+#[discriminator(VaultDiscriminator::Config)]
+#[account_seeds(b"config")]
 #[repr(C)]
 pub struct Config {
     /// The configuration admin
@@ -177,54 +180,6 @@ impl Config {
         Ok(fee)
     }
 
-    pub fn seeds() -> Vec<Vec<u8>> {
-        vec![b"config".to_vec()]
-    }
-
-    pub fn find_program_address(program_id: &Pubkey) -> (Pubkey, u8, Vec<Vec<u8>>) {
-        let seeds = Self::seeds();
-        let seeds_iter: Vec<_> = seeds.iter().map(|s| s.as_slice()).collect();
-        let (pda, bump) = Pubkey::find_program_address(&seeds_iter, program_id);
-        (pda, bump, seeds)
-    }
-
-    /// Loads the vault [`Config`] account
-    ///
-    /// # Arguments
-    /// * `program_id` - The program ID
-    /// * `account` - The account to load
-    /// * `expect_writable` - Whether the account should be writable
-    ///
-    /// # Returns
-    /// * `Result<(), ProgramError>` - The result of the operation
-    pub fn load(
-        program_id: &Pubkey,
-        account: &AccountInfo,
-        expect_writable: bool,
-    ) -> Result<(), ProgramError> {
-        if account.owner.ne(program_id) {
-            msg!("Config account has an invalid owner");
-            return Err(ProgramError::InvalidAccountOwner);
-        }
-        if account.data_is_empty() {
-            msg!("Config account data is empty");
-            return Err(ProgramError::InvalidAccountData);
-        }
-        if expect_writable && !account.is_writable {
-            msg!("Config account is not writable");
-            return Err(ProgramError::InvalidAccountData);
-        }
-        if account.data.borrow()[0].ne(&Self::DISCRIMINATOR) {
-            msg!("Config account discriminator is invalid");
-            return Err(ProgramError::InvalidAccountData);
-        }
-        if account.key.ne(&Self::find_program_address(program_id).0) {
-            msg!("Config account is not at the correct PDA");
-            return Err(ProgramError::InvalidAccountData);
-        }
-        Ok(())
-    }
-
     pub fn set_admin(&mut self, new_admin: Pubkey) {
         self.admin = new_admin;
         self.fee_admin = new_admin;
@@ -230,6 +230,7 @@ impl DelegationState {
 mod tests {
     use jito_bytemuck::types::PodU64;
     use jito_vault_sdk::error::VaultError;
+    use proptest::prelude::*;
 
     use super::{DelegationState, RESERVED_SPACE_LEN};
 
@@ -310,4 +311,228 @@ mod tests {
             Err(VaultError::VaultCooldownZero)
         );
     }
+
+    fn delegation_state() -> impl Strategy<Value = DelegationState> {
+        (any::<u64>(), any::<u64>(), any::<u64>()).prop_map(
+            |(staked_amount, enqueued_for_cooldown_amount, cooling_down_amount)| {
+                DelegationState::new(
+                    staked_amount,
+                    enqueued_for_cooldown_amount,
+                    cooling_down_amount,
+                )
+            },
+        )
+    }
+
+    /// Keeps the total under u64::MAX so total_security() is defined
+    fn bounded_delegation_state() -> impl Strategy<Value = DelegationState> {
+        (0..=u64::MAX / 3, 0..=u64::MAX / 3, 0..=u64::MAX / 3).prop_map(
+            |(staked_amount, enqueued_for_cooldown_amount, cooling_down_amount)| {
+                DelegationState::new(
+                    staked_amount,
+                    enqueued_for_cooldown_amount,
+                    cooling_down_amount,
+                )
+            },
+        )
+    }
+
+    proptest! {
+        /// Only the stake that finished cooling down leaves the security total on update
+        #[test]
+        fn test_update_conserves_total_security(mut state in bounded_delegation_state()) {
+            let before = state;
+            state.update();
+
+            prop_assert_eq!(state.staked_amount(), before.staked_amount());
+            prop_assert_eq!(state.enqueued_for_cooldown_amount(), 0);
+            prop_assert_eq!(
+                state.cooling_down_amount(),
+                before.enqueued_for_cooldown_amount()
+            );
+            prop_assert_eq!(
+                state.total_security().unwrap() + before.cooling_down_amount(),
+                before.total_security().unwrap()
+            );
+        }
+
+        /// Updates only move stake out of cooldown, and two are enough to settle it
+        #[test]
+        fn test_update_is_monotonic(mut state in bounded_delegation_state(), updates in 0..5usize) {
+            let mut previous = state;
+            for _ in 0..updates {
+                state.update();
+                prop_assert!(state.total_security().unwrap() <= previous.total_security().unwrap());
+                prop_assert_eq!(state.staked_amount(), previous.staked_amount());
+                previous = state;
+            }
+            if updates >= 2 {
+                prop_assert_eq!(state.total_security().unwrap(), state.staked_amount());
+            }
+        }
+
+        #[test]
+        fn test_delegate_adds_to_staked(mut state in delegation_state(), amount in any::<u64>()) {
+            let before = state;
+            match state.delegate(amount) {
+                Ok(()) => {
+                    prop_assert_eq!(state.staked_amount(), before.staked_amount() + amount);
+                    prop_assert_eq!(
+                        state.enqueued_for_cooldown_amount(),
+                        before.enqueued_for_cooldown_amount()
+                    );
+                    prop_assert_eq!(state.cooling_down_amount(), before.cooling_down_amount());
+                }
+                Err(_) => {
+                    prop_assert!(
+                        amount == 0 || before.staked_amount().checked_add(amount).is_none()
+                    );
+                    prop_assert_eq!(state, before);
+                }
+            }
+        }
+
+        /// Cooling down moves stake between buckets without changing the security total
+        #[test]
+        fn test_cooldown_conserves_total_security(
+            mut state in bounded_delegation_state(),
+            amount in any::<u64>(),
+        ) {
+            let before = state;
+            match state.cooldown(amount) {
+                Ok(()) => {
+                    prop_assert_eq!(state.staked_amount(), before.staked_amount() - amount);
+                    prop_assert_eq!(
+                        state.enqueued_for_cooldown_amount(),
+                        before.enqueued_for_cooldown_amount() + amount
+                    );
+                    prop_assert_eq!(state.cooling_down_amount(), before.cooling_down_amount());
+                    prop_assert_eq!(
+                        state.total_security().unwrap(),
+                        before.total_security().unwrap()
+                    );
+                }
+                Err(_) => {
+                    prop_assert!(amount == 0 || amount > before.staked_amount());
+                    prop_assert_eq!(state, before);
+                }
+            }
+        }
+
+        #[test]
+        fn test_accumulate_subtract_round_trip(
+            mut state in delegation_state(),
+            other in delegation_state(),
+        ) {
+            let before = state;
+            match state.accumulate(&other) {
+                Ok(()) => {
+                    state.subtract(&other).unwrap();
+                    prop_assert_eq!(state, before);
+                }
+                Err(e) => {
+                    prop_assert_eq!(e, VaultError::VaultSecurityOverflow);
+                }
+            }
+        }
+
+        #[test]
+        fn test_slash(mut state in bounded_delegation_state(), amount in any::<u64>()) {
+            let before = state;
+            let total_security = before.total_security().unwrap();
+            match state.slash(amount) {
+                Ok(()) => {
+                    prop_assert_eq!(state.total_security().unwrap(), total_security - amount);
+                    prop_assert!(state.staked_amount() <= before.staked_amount());
+                    prop_assert!(
+                        state.enqueued_for_cooldown_amount() <= before.enqueued_for_cooldown_amount()
+                    );
+                    prop_assert!(state.cooling_down_amount() <= before.cooling_down_amount());
+                }
+                Err(e) => {
+                    prop_assert_eq!(e, VaultError::VaultSlashUnderflow);
+                    prop_assert!(amount > total_security);
+                }
+            }
+        }
+    }
+}
+
+/// Proof harnesses for `cargo kani`, covering every input the property tests sample from
+#[cfg(kani)]
+mod verification {
+    use super::DelegationState;
+
+    fn any_delegation_state() -> DelegationState {
+        DelegationState::new(kani::any(), kani::any(), kani::any())
+    }
+
+    #[kani::proof]
+    fn verify_update_conserves_total_security() {
+        let mut state = any_delegation_state();
+        let before = state;
+        kani::assume(before.total_security().is_ok());
+
+        state.update();
+
+        assert_eq!(state.staked_amount(), before.staked_amount());
+        assert_eq!(
+            state.total_security().unwrap() + before.cooling_down_amount(),
+            before.total_security().unwrap()
+        );
+    }
+
+    #[kani::proof]
+    fn verify_two_updates_settle() {
+        let mut state = any_delegation_state();
+        state.update();
+        state.update();
+
+        assert_eq!(state.enqueued_for_cooldown_amount(), 0);
+        assert_eq!(state.cooling_down_amount(), 0);
+    }
+
+    #[kani::proof]
+    fn verify_cooldown_conserves_total_security() {
+        let mut state = any_delegation_state();
+        let before = state;
+        let amount: u64 = kani::any();
+        kani::assume(amount > 0);
+        kani::assume(before.total_security().is_ok());
+
+        if state.cooldown(amount).is_ok() {
+            assert_eq!(
+                state.total_security().unwrap(),
+                before.total_security().unwrap()
+            );
+        } else {
+            assert_eq!(state, before);
+        }
+    }
+
+    #[kani::proof]
+    fn verify_delegate_never_panics() {
+        let mut state = any_delegation_state();
+        let before = state;
+        let amount: u64 = kani::any();
+        kani::assume(amount > 0);
+
+        if state.delegate(amount).is_ok() {
+            assert_eq!(state.staked_amount(), before.staked_amount() + amount);
+        } else {
+            assert_eq!(state, before);
+        }
+    }
+
+    #[kani::proof]
+    fn verify_accumulate_subtract_round_trip() {
+        let mut state = any_delegation_state();
+        let other = any_delegation_state();
+        let before = state;
+
+        if state.accumulate(&other).is_ok() {
+            assert!(state.subtract(&other).is_ok());
+            assert_eq!(state, before);
+        }
+    }
 }
@@ -1,4 +1,4 @@
-use jito_bytemuck::Discriminator;
+use jito_bytemuck::Migrate;
 
 use crate::{
     config::Config, vault::Vault, vault_ncn_slasher_operator_ticket::VaultNcnSlasherOperatorTicket,
@@ -9,7 +9,8 @@ use crate::{
 };
 
 /// Discriminators for vault accounts
-/// Values must not change as they are written on chain to determine the type of account
+/// Values must not change as they are written on chain to determine the type of account.
+/// Accounts pick their variant with `#[discriminator(..)]`, which rejects two accounts sharing one.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum VaultDiscriminator {
     Config = 1,
@@ -22,34 +23,21 @@ pub enum VaultDiscriminator {
     VaultUpdateStateTracker = 8,
 }
 
-impl Discriminator for Config {
-    const DISCRIMINATOR: u8 = VaultDiscriminator::Config as u8;
-}
+// Every account is still at its first layout version, so there is nothing to migrate from yet.
+// When a layout changes, set `version` in its `#[discriminator(..)]` and convert the previous
+// layout in `migrate`.
+impl Migrate for Config {}
 
-impl Discriminator for Vault {
-    const DISCRIMINATOR: u8 = VaultDiscriminator::Vault as u8;
-}
+impl Migrate for Vault {}
 
-impl Discriminator for VaultNcnTicket {
-    const DISCRIMINATOR: u8 = VaultDiscriminator::VaultNcnTicket as u8;
-}
+impl Migrate for VaultNcnTicket {}
 
-impl Discriminator for VaultOperatorDelegation {
-    const DISCRIMINATOR: u8 = VaultDiscriminator::VaultOperatorDelegation as u8;
-}
+impl Migrate for VaultOperatorDelegation {}
 
-impl Discriminator for VaultNcnSlasherTicket {
-    const DISCRIMINATOR: u8 = VaultDiscriminator::VaultNcnSlasherTicket as u8;
-}
+impl Migrate for VaultNcnSlasherTicket {}
 
-impl Discriminator for VaultNcnSlasherOperatorTicket {
-    const DISCRIMINATOR: u8 = VaultDiscriminator::VaultNcnSlasherOperatorTicket as u8;
-}
+impl Migrate for VaultNcnSlasherOperatorTicket {}
 
-impl Discriminator for VaultStakerWithdrawalTicket {
-    const DISCRIMINATOR: u8 = VaultDiscriminator::VaultStakerWithdrawalTicket as u8;
-}
+impl Migrate for VaultStakerWithdrawalTicket {}
 
-impl Discriminator for VaultUpdateStateTracker {
-    const DISCRIMINATOR: u8 = VaultDiscriminator::VaultUpdateStateTracker as u8;
-}
+impl Migrate for VaultUpdateStateTracker {}
@@ -1,4 +1,5 @@
 //! Loader functions for the vault program.
+use jito_jsm_core::diagnostic::Check;
 use jito_vault_sdk::inline_mpl_token_metadata::{self, pda::find_metadata_account};
 use solana_program::{account_info::AccountInfo, msg, program_error::ProgramError, pubkey::Pubkey};
 
@@ -16,6 +17,7 @@ pub fn load_mpl_metadata_program(info: &AccountInfo) -> Result<(), ProgramError>
             inline_mpl_token_metadata::id(),
             info.key
         );
+        Check::ProgramId.log_failure(info.key);
         return Err(ProgramError::IncorrectProgramId);
     }
 
@@ -33,6 +35,7 @@ pub fn load_mpl_metadata(info: &AccountInfo, vrt_mint: &Pubkey) -> Result<(), Pr
     let (metadata_account_pubkey, _) = find_metadata_account(vrt_mint);
 
     if metadata_account_pubkey.ne(info.key) {
+        Check::Address.log_failure(info.key);
         Err(ProgramError::InvalidAccountData)
     } else {
         Ok(())
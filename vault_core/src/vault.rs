@@ -2,16 +2,18 @@
 use bytemuck::{Pod, Zeroable};
 use jito_bytemuck::{
     types::{PodBool, PodU16, PodU64},
-    AccountDeserialize, Discriminator,
+    AccountDeserialize, JitoAccount,
 };
-use jito_jsm_core::{get_epoch, loader::load_signer};
+use jito_jsm_core::{diagnostic::Check, get_epoch, loader::load_signer, pause::PauseCategory};
 use jito_vault_sdk::error::VaultError;
 use shank::ShankAccount;
 use solana_program::{account_info::AccountInfo, msg, program_error::ProgramError, pubkey::Pubkey};
 
-use crate::{config::Config, delegation_state::DelegationState, MAX_BPS};
+use crate::{
+    config::Config, delegation_state::DelegationState, discriminators::VaultDiscriminator, MAX_BPS,
+};
 
-const RESERVED_SPACE_LEN: usize = 251;
+const RESERVED_SPACE_LEN: usize = 207;
 
 #[derive(Debug, PartialEq, Eq)]
 pub struct BurnSummary {
@@ -31,7 +33,11 @@ pub struct MintSummary {
     pub vrt_to_fee_wallet: u64,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Pod, Zeroable, AccountDeserialize, ShankAccount)]
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Pod, Zeroable, AccountDeserialize, JitoAccount, ShankAccount,
+)]
+#[discriminator(VaultDiscriminator::Vault)]
+#[account_seeds(b"vault", self.base)]
 #[repr(C)]
 pub struct Vault {
     /// The base account of the VRT
@@ -153,14 +159,37 @@ pub struct Vault {
     /// last
     last_start_state_update_slot: PodU64,
 
+    // ------------------------------------------
+    // Nested vaults
+    // ------------------------------------------
+    /// The child vault whose VRT is this vault's supported mint, or the default pubkey if the
+    /// vault is not nested
+    pub child_vault: Pubkey,
+
+    /// The value of the vault's holdings in the child vault's underlying tokens as of the last
+    /// balance update. Only meaningful for nested vaults.
+    nested_underlying_value: PodU64,
+
+    /// The number of vaults nested below this one as of the last link or balance update, zero if
+    /// the vault is not nested
+    nesting_depth: u8,
+
+    /// Whether the vault is in emergency exit mode. Delegations are force-cooled and withdrawals
+    /// no longer require the mint burn admin while set.
+    is_emergency_exit: PodBool,
+
+    /// Bitmask of paused instruction categories, see [`PauseCategory`]
+    paused_categories: PodU16,
+
     /// Reserved space
-    reserved: [u8; 251],
+    reserved: [u8; 207],
 }
 
 impl Vault {
     pub const MAX_REWARD_DELTA_BPS: u16 = 50; // 0.5%
     pub const MIN_WITHDRAWAL_SLIPPAGE_BPS: u16 = 50; // 0.5%
     pub const DEFAULT_INITIALIZATION_TOKEN_AMOUNT: u64 = 10_000;
+    pub const MAX_NESTING_DEPTH: u8 = 4;
 
     #[allow(clippy::too_many_arguments)]
     pub fn new(
@@ -226,6 +255,11 @@ impl Vault {
             delegation_state: DelegationState::default(),
             additional_assets_need_unstaking: PodU64::from(0),
             is_paused: PodBool::from_bool(false),
+            child_vault: Pubkey::default(),
+            nested_underlying_value: PodU64::from(0),
+            nesting_depth: 0,
+            is_emergency_exit: PodBool::from_bool(false),
+            paused_categories: PodU16::from(0),
             reserved: [0; RESERVED_SPACE_LEN],
         })
     }
@@ -463,6 +497,27 @@ impl Vault {
         self.is_paused = PodBool::from_bool(is_paused);
     }
 
+    pub fn paused_categories(&self) -> u16 {
+        self.paused_categories.into()
+    }
+
+    pub fn set_paused_categories(&mut self, paused_categories: u16) {
+        self.paused_categories = PodU16::from(paused_categories);
+    }
+
+    pub fn is_emergency_exit(&self) -> bool {
+        self.is_emergency_exit.into()
+    }
+
+    /// Enters or leaves emergency exit mode. Entering emergency exit also unpauses the vault so
+    /// stakers can crank, enqueue and burn their way out.
+    pub fn set_is_emergency_exit(&mut self, is_emergency_exit: bool) {
+        self.is_emergency_exit = PodBool::from_bool(is_emergency_exit);
+        if is_emergency_exit {
+            self.is_paused = PodBool::from_bool(false);
+        }
+    }
+
     // Only to be used in initialize_vault
     pub fn initialize_vault_override_deposit_fee_bps(
         &mut self,
@@ -501,9 +556,44 @@ impl Vault {
         Ok(())
     }
 
+    /// Checks whether an instruction category is paused on the vault.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(())` if the category is not paused.
+    ///
+    /// # Errors
+    /// * [`VaultError::VaultCategoryPaused`] - If the category is paused.
+    pub fn check_is_category_paused(&self, category: PauseCategory) -> Result<(), VaultError> {
+        if category.is_paused(self.paused_categories()) {
+            msg!("Vault {:?} are currently paused.", category);
+            return Err(VaultError::VaultCategoryPaused);
+        }
+
+        Ok(())
+    }
+
+    /// Checks whether the vault is in emergency exit mode.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(())` if the vault is not in emergency exit mode.
+    ///
+    /// # Errors
+    /// * [`VaultError::VaultInEmergencyExit`] - If the vault is in emergency exit mode.
+    pub fn check_is_emergency_exit(&self) -> Result<(), VaultError> {
+        if self.is_emergency_exit() {
+            msg!("Vault is in emergency exit mode.");
+            return Err(VaultError::VaultInEmergencyExit);
+        }
+
+        Ok(())
+    }
+
     pub fn check_vrt_mint(&self, vrt_mint: &Pubkey) -> Result<(), ProgramError> {
         if self.vrt_mint.ne(vrt_mint) {
             msg!("Vault VRT mint does not match the provided VRT mint");
+            Check::VrtMint.log_failure(vrt_mint);
             return Err(ProgramError::InvalidAccountData);
         }
         Ok(())
@@ -514,6 +604,7 @@ impl Vault {
     pub fn check_admin(&self, admin: &Pubkey) -> Result<(), ProgramError> {
         if self.admin.ne(admin) {
             msg!("Vault admin does not match the provided admin");
+            Check::Admin.log_failure(admin);
             return Err(VaultError::VaultAdminInvalid.into());
         }
         Ok(())
@@ -523,6 +614,7 @@ impl Vault {
     pub fn check_delegation_admin(&self, delegation_admin: &Pubkey) -> Result<(), VaultError> {
         if self.delegation_admin.ne(delegation_admin) {
             msg!("Vault delegation admin does not match the provided delegation admin");
+            Check::DelegationAdmin.log_failure(delegation_admin);
             return Err(VaultError::VaultDelegationAdminInvalid);
         }
         Ok(())
@@ -531,6 +623,7 @@ impl Vault {
     pub fn check_operator_admin(&self, operator_admin: &Pubkey) -> Result<(), VaultError> {
         if self.operator_admin.ne(operator_admin) {
             msg!("Vault operator admin does not match the provided operator admin");
+            Check::OperatorAdmin.log_failure(operator_admin);
             return Err(VaultError::VaultOperatorAdminInvalid);
         }
         Ok(())
@@ -539,6 +632,7 @@ impl Vault {
     pub fn check_ncn_admin(&self, ncn_admin: &Pubkey) -> Result<(), VaultError> {
         if self.ncn_admin.ne(ncn_admin) {
             msg!("Vault NCN admin does not match the provided NCN admin");
+            Check::NcnAdmin.log_failure(ncn_admin);
             return Err(VaultError::VaultNcnAdminInvalid);
         }
         Ok(())
@@ -547,6 +641,7 @@ impl Vault {
     pub fn check_slasher_admin(&self, slasher_admin: &Pubkey) -> Result<(), VaultError> {
         if self.slasher_admin.ne(slasher_admin) {
             msg!("Vault slasher admin does not match the provided slasher admin");
+            Check::SlasherAdmin.log_failure(slasher_admin);
             return Err(VaultError::VaultSlasherAdminInvalid);
         }
         Ok(())
@@ -555,6 +650,7 @@ impl Vault {
     pub fn check_capacity_admin(&self, capacity_admin: &Pubkey) -> Result<(), VaultError> {
         if self.capacity_admin.ne(capacity_admin) {
             msg!("Vault capacity admin does not match the provided capacity admin");
+            Check::CapacityAdmin.log_failure(capacity_admin);
             return Err(VaultError::VaultCapacityAdminInvalid);
         }
         Ok(())
@@ -563,6 +659,7 @@ impl Vault {
     pub fn check_fee_admin(&self, fee_admin: &Pubkey) -> Result<(), VaultError> {
         if self.fee_admin.ne(fee_admin) {
             msg!("Vault fee admin does not match the provided fee admin");
+            Check::FeeAdmin.log_failure(fee_admin);
             return Err(VaultError::VaultFeeAdminInvalid);
         }
         Ok(())
@@ -586,6 +683,7 @@ impl Vault {
     ) -> Result<(), VaultError> {
         if self.delegate_asset_admin.ne(delegate_asset_admin) {
             msg!("Vault delegate asset admin does not match the provided delegate asset admin");
+            Check::DelegateAssetAdmin.log_failure(delegate_asset_admin);
             return Err(VaultError::VaultDelegateAssetAdminInvalid);
         }
         Ok(())
@@ -644,6 +742,141 @@ impl Vault {
         }
     }
 
+    // ------------------------------------------
+    // Nested vaults
+    // ------------------------------------------
+
+    pub fn is_nested(&self) -> bool {
+        self.child_vault.ne(&Pubkey::default())
+    }
+
+    pub fn nesting_depth(&self) -> u8 {
+        self.nesting_depth
+    }
+
+    pub fn nested_underlying_value(&self) -> u64 {
+        self.nested_underlying_value.into()
+    }
+
+    /// Links a child vault whose VRT is this vault's supported mint, turning this vault into a
+    /// nested vault.
+    ///
+    /// Only a vault created before this one can become its child: every vault's VRT mint is a
+    /// fresh mint created with the vault, and this vault's supported mint had to exist when it was
+    /// created. Since a child always predates its parent, checking the direct relationship is
+    /// enough to keep the structure acyclic. This relies on `InitializeVaultWithMint`, which would
+    /// let a vault adopt an existing mint as its VRT, remaining a no-op.
+    ///
+    /// The depth is a snapshot of the child's depth, which grows if a descendant is linked later.
+    /// [`Self::update_nested_underlying_value`] recomputes it and enforces
+    /// [`Self::MAX_NESTING_DEPTH`] against the child's current depth.
+    ///
+    /// # Arguments
+    /// * `vault` - The address of this vault
+    /// * `child_vault` - The address of the child vault
+    /// * `child` - The child vault
+    ///
+    /// # Errors
+    /// * [`VaultError::VaultAlreadyNested`] - The vault already has a child vault
+    /// * [`VaultError::VaultNestingCycle`] - The child vault is this vault or is nested in it
+    /// * [`VaultError::VaultChildVaultInvalid`] - The child's VRT is not the supported mint
+    /// * [`VaultError::VaultNestingTooDeep`] - The nesting depth would exceed [`Self::MAX_NESTING_DEPTH`]
+    pub fn set_child_vault(
+        &mut self,
+        vault: &Pubkey,
+        child_vault: &Pubkey,
+        child: &Self,
+    ) -> Result<(), VaultError> {
+        if self.is_nested() {
+            msg!("Vault already has a child vault");
+            return Err(VaultError::VaultAlreadyNested);
+        }
+        if child_vault.eq(vault) || child.child_vault.eq(vault) {
+            msg!("Vault nesting would create a cycle");
+            return Err(VaultError::VaultNestingCycle);
+        }
+        if child.vrt_mint.ne(&self.supported_mint) {
+            msg!("Child vault VRT mint does not match the supported mint");
+            return Err(VaultError::VaultChildVaultInvalid);
+        }
+        let nesting_depth = Self::nesting_depth_over(child)?;
+
+        self.child_vault = *child_vault;
+        self.nesting_depth = nesting_depth;
+        Ok(())
+    }
+
+    /// The nesting depth of a vault nested directly over `child`
+    ///
+    /// # Errors
+    /// * [`VaultError::VaultNestingTooDeep`] - The depth would exceed [`Self::MAX_NESTING_DEPTH`]
+    fn nesting_depth_over(child: &Self) -> Result<u8, VaultError> {
+        let nesting_depth = child
+            .nesting_depth()
+            .checked_add(1)
+            .ok_or(VaultError::VaultNestingTooDeep)?;
+        if nesting_depth > Self::MAX_NESTING_DEPTH {
+            msg!(
+                "Vault nesting depth {} exceeds maximum of {}",
+                nesting_depth,
+                Self::MAX_NESTING_DEPTH
+            );
+            return Err(VaultError::VaultNestingTooDeep);
+        }
+        Ok(nesting_depth)
+    }
+
+    /// The value of the vault's holdings in the underlying tokens at the bottom of the nesting,
+    /// which is the tokens deposited for a vault that is not nested.
+    pub fn underlying_value(&self) -> u64 {
+        if self.is_nested() {
+            self.nested_underlying_value()
+        } else {
+            self.tokens_deposited()
+        }
+    }
+
+    /// Values an amount of this vault's VRT in underlying tokens using the vault's exchange rate.
+    pub fn calculate_underlying_value(&self, vrt_amount: u64) -> Result<u64, VaultError> {
+        if self.vrt_supply() == 0 {
+            return Ok(0);
+        }
+
+        (vrt_amount as u128)
+            .checked_mul(self.underlying_value() as u128)
+            .and_then(|x| x.checked_div(self.vrt_supply() as u128))
+            .and_then(|x| x.try_into().ok())
+            .ok_or(VaultError::VaultOverflow)
+    }
+
+    /// Revalues the vault's holdings of the child vault's VRT at the child's exchange rate. Losses
+    /// in the child, such as slashing, lower the value and so propagate upward.
+    ///
+    /// The nesting depth is recomputed from the child's current depth, so a descendant linked
+    /// after this vault can't push the nesting past [`Self::MAX_NESTING_DEPTH`].
+    ///
+    /// Returns the previous value.
+    ///
+    /// # Errors
+    /// * [`VaultError::VaultChildVaultInvalid`] - The child is not this vault's child vault
+    /// * [`VaultError::VaultNestingTooDeep`] - The child's current depth is too deep to nest over
+    pub fn update_nested_underlying_value(
+        &mut self,
+        child_vault: &Pubkey,
+        child: &Self,
+    ) -> Result<u64, VaultError> {
+        if self.child_vault.ne(child_vault) || child.vrt_mint.ne(&self.supported_mint) {
+            msg!("Child vault does not match the vault's child vault");
+            return Err(VaultError::VaultChildVaultInvalid);
+        }
+        self.nesting_depth = Self::nesting_depth_over(child)?;
+
+        let previous_value = self.nested_underlying_value();
+        let value = child.calculate_underlying_value(self.tokens_deposited())?;
+        self.nested_underlying_value = PodU64::from(value);
+        Ok(previous_value)
+    }
+
     // ------------------------------------------
     // Asset accounting and tracking
     // ------------------------------------------
@@ -676,10 +909,12 @@ impl Vault {
                     .map_err(|_| VaultError::VaultMintBurnAdminInvalid)?;
                 if burn_signer.key.ne(&self.mint_burn_admin) {
                     msg!("Burn signer does not match vault burn signer");
+                    Check::MintBurnAdmin.log_failure(burn_signer.key);
                     return Err(VaultError::VaultMintBurnAdminInvalid);
                 }
             } else {
                 msg!("Mint signer is required for vault mint");
+                Check::MintBurnAdmin.log_missing();
                 return Err(VaultError::VaultMintBurnAdminInvalid);
             }
         }
@@ -1231,81 +1466,12 @@ impl Vault {
     // Serialization & Deserialization
     // ------------------------------------------
 
-    /// Returns the seeds for the PDA
-    ///
-    /// # Returns
-    /// * `Vec<Vec<u8>>` - containing the seed vectors
-    pub fn seeds(base: &Pubkey) -> Vec<Vec<u8>> {
-        vec![b"vault".as_ref().to_vec(), base.to_bytes().to_vec()]
-    }
-
     /// Returns the seeds for the PDA used for signing
     pub fn signing_seeds(&self) -> Vec<Vec<u8>> {
         let mut vault_seeds = Self::seeds(&self.base);
         vault_seeds.push(vec![self.bump]);
         vault_seeds
     }
-
-    /// Find the program address for the Vault
-    ///
-    /// # Arguments
-    /// * `program_id` - The program ID
-    /// * `base` - The base account used as a PDA seed
-    ///
-    /// # Returns
-    /// * [`Pubkey`] - The program address
-    /// * `u8` - The bump seed
-    /// * `Vec<Vec<u8>>` - The seeds used to generate the PDA
-    pub fn find_program_address(program_id: &Pubkey, base: &Pubkey) -> (Pubkey, u8, Vec<Vec<u8>>) {
-        let seeds = Self::seeds(base);
-        let seeds_iter: Vec<_> = seeds.iter().map(|s| s.as_slice()).collect();
-        let (pda, bump) = Pubkey::find_program_address(&seeds_iter, program_id);
-        (pda, bump, seeds)
-    }
-
-    /// Loads the [`Vault`] account
-    ///
-    /// # Arguments
-    /// * `program_id` - The program ID
-    /// * `account` - The account to load
-    /// * `expect_writable` - Whether the account should be writable
-    ///
-    /// # Returns
-    /// * `Result<(), ProgramError>` - The result of the operation
-    pub fn load(
-        program_id: &Pubkey,
-        account: &AccountInfo,
-        expect_writable: bool,
-    ) -> Result<(), ProgramError> {
-        if account.owner.ne(program_id) {
-            msg!("Vault account has an invalid owner");
-            return Err(ProgramError::InvalidAccountOwner);
-        }
-        if account.data_is_empty() {
-            msg!("Vault account data is empty");
-            return Err(ProgramError::InvalidAccountData);
-        }
-        if expect_writable && !account.is_writable {
-            msg!("Vault account is not writable");
-            return Err(ProgramError::InvalidAccountData);
-        }
-        if account.data.borrow()[0].ne(&Self::DISCRIMINATOR) {
-            msg!("Vault account discriminator is invalid");
-            return Err(ProgramError::InvalidAccountData);
-        }
-
-        let vault_data = &account.data.borrow();
-        let vault = Self::try_from_slice_unchecked(vault_data)?;
-        let seeds = vault.signing_seeds();
-        let seed_slices: Vec<&[u8]> = seeds.iter().map(|seed| seed.as_slice()).collect();
-        let expected_pubkey = Pubkey::create_program_address(&seed_slices, program_id)?;
-        if account.key.ne(&expected_pubkey) {
-            msg!("Vault account is not at the correct PDA");
-            return Err(ProgramError::InvalidAccountData);
-        }
-
-        Ok(())
-    }
 }
 
 #[cfg(test)]
@@ -1313,6 +1479,7 @@ mod tests {
     use std::{cell::RefCell, rc::Rc};
 
     use jito_bytemuck::types::{PodBool, PodU16, PodU64};
+    use jito_jsm_core::pause::PauseCategory;
     use jito_vault_sdk::error::VaultError;
     use solana_program::{account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey};
 
@@ -1390,6 +1557,11 @@ mod tests {
             std::mem::size_of::<PodBool>() + // is_paused
             std::mem::size_of::<PodU64>() + // last_start_state_update_slot
             1 + // bump
+            std::mem::size_of::<Pubkey>() + // child_vault
+            std::mem::size_of::<PodU64>() + // nested_underlying_value
+            1 + // nesting_depth
+            std::mem::size_of::<PodBool>() + // is_emergency_exit
+            std::mem::size_of::<PodU16>() + // paused_categories
             RESERVED_SPACE_LEN; // reserved
 
         assert_eq!(vault_size, sum_of_fields);
@@ -2671,11 +2843,11 @@ mod tests {
         )
         .unwrap();
         // Verify reserved space is initialized to zeros
-        assert_eq!(vault.reserved, [0u8; 251]);
+        assert_eq!(vault.reserved, [0u8; 207]);
 
         // Get the size of the reserved field
         let reserved_size = std::mem::size_of_val(&vault.reserved);
-        assert_eq!(reserved_size, 251);
+        assert_eq!(reserved_size, 207);
 
         // Verify the reserved field maintains alignment
         assert_eq!(std::mem::align_of_val(&vault.reserved), 1);
@@ -2861,4 +3033,184 @@ mod tests {
         );
         assert_eq!(vault.deposit_fee_bps(), MAX_BPS);
     }
+
+    fn make_nested_vaults() -> (Pubkey, Vault, Pubkey, Vault) {
+        let child_address = Pubkey::new_unique();
+        let child = make_test_vault(0, 0, 0, 1_000, 1_000, DelegationState::default());
+
+        let parent_address = Pubkey::new_unique();
+        let mut parent = Vault::new(
+            Pubkey::new_unique(),
+            child.vrt_mint,
+            Pubkey::new_unique(),
+            1,
+            Pubkey::new_unique(),
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+        )
+        .unwrap();
+        parent.set_tokens_deposited(500);
+        parent.set_vrt_supply(500);
+
+        (parent_address, parent, child_address, child)
+    }
+
+    #[test]
+    fn test_set_child_vault_ok() {
+        let (parent_address, mut parent, child_address, child) = make_nested_vaults();
+        assert!(!parent.is_nested());
+
+        parent
+            .set_child_vault(&parent_address, &child_address, &child)
+            .unwrap();
+        assert!(parent.is_nested());
+        assert_eq!(parent.child_vault, child_address);
+        assert_eq!(parent.nesting_depth(), 1);
+
+        assert_eq!(
+            parent.set_child_vault(&parent_address, &child_address, &child),
+            Err(VaultError::VaultAlreadyNested)
+        );
+    }
+
+    #[test]
+    fn test_set_child_vault_invalid() {
+        let (parent_address, mut parent, child_address, mut child) = make_nested_vaults();
+
+        assert_eq!(
+            parent.set_child_vault(&parent_address, &parent_address, &child),
+            Err(VaultError::VaultNestingCycle)
+        );
+
+        let mut other = child;
+        other.vrt_mint = Pubkey::new_unique();
+        assert_eq!(
+            parent.set_child_vault(&parent_address, &child_address, &other),
+            Err(VaultError::VaultChildVaultInvalid)
+        );
+
+        child.child_vault = parent_address;
+        assert_eq!(
+            parent.set_child_vault(&parent_address, &child_address, &child),
+            Err(VaultError::VaultNestingCycle)
+        );
+
+        child.child_vault = Pubkey::new_unique();
+        child.nesting_depth = Vault::MAX_NESTING_DEPTH;
+        assert_eq!(
+            parent.set_child_vault(&parent_address, &child_address, &child),
+            Err(VaultError::VaultNestingTooDeep)
+        );
+    }
+
+    #[test]
+    fn test_nested_underlying_value_propagates_losses() {
+        let (parent_address, mut parent, child_address, mut child) = make_nested_vaults();
+        parent
+            .set_child_vault(&parent_address, &child_address, &child)
+            .unwrap();
+
+        // 500 of 1,000 child VRT backed by 1,000 tokens
+        assert_eq!(
+            parent
+                .update_nested_underlying_value(&child_address, &child)
+                .unwrap(),
+            0
+        );
+        assert_eq!(parent.underlying_value(), 500);
+
+        // the child is slashed by 20%
+        child.set_tokens_deposited(800);
+        assert_eq!(
+            parent
+                .update_nested_underlying_value(&child_address, &child)
+                .unwrap(),
+            500
+        );
+        assert_eq!(parent.underlying_value(), 400);
+        assert_eq!(parent.tokens_deposited(), 500);
+
+        // a vault nested on top of the parent sees the loss as well
+        let grandparent_address = Pubkey::new_unique();
+        let mut grandparent = make_test_vault(0, 0, 0, 250, 250, DelegationState::default());
+        grandparent.supported_mint = parent.vrt_mint;
+        grandparent
+            .set_child_vault(&grandparent_address, &parent_address, &parent)
+            .unwrap();
+        assert_eq!(grandparent.nesting_depth(), 2);
+        grandparent
+            .update_nested_underlying_value(&parent_address, &parent)
+            .unwrap();
+        assert_eq!(grandparent.underlying_value(), 200);
+
+        assert_eq!(
+            grandparent.update_nested_underlying_value(&child_address, &child),
+            Err(VaultError::VaultChildVaultInvalid)
+        );
+    }
+
+    #[test]
+    fn test_nesting_depth_recomputed_on_update() {
+        let (parent_address, mut parent, child_address, mut child) = make_nested_vaults();
+        parent
+            .set_child_vault(&parent_address, &child_address, &child)
+            .unwrap();
+        assert_eq!(parent.nesting_depth(), 1);
+
+        // a descendant linked below the child after the parent was nested deepens the nesting
+        child.child_vault = Pubkey::new_unique();
+        child.nesting_depth = 2;
+        parent
+            .update_nested_underlying_value(&child_address, &child)
+            .unwrap();
+        assert_eq!(parent.nesting_depth(), 3);
+
+        child.nesting_depth = Vault::MAX_NESTING_DEPTH;
+        assert_eq!(
+            parent.update_nested_underlying_value(&child_address, &child),
+            Err(VaultError::VaultNestingTooDeep)
+        );
+        assert_eq!(parent.nesting_depth(), 3);
+    }
+
+    #[test]
+    fn test_emergency_exit() {
+        let mut vault = make_test_vault(0, 0, 0, 1_000, 1_000, DelegationState::default());
+        vault.set_is_paused(true);
+        assert_eq!(vault.check_is_emergency_exit(), Ok(()));
+
+        vault.set_is_emergency_exit(true);
+        assert!(vault.is_emergency_exit());
+        assert!(!vault.is_paused());
+        assert_eq!(
+            vault.check_is_emergency_exit(),
+            Err(VaultError::VaultInEmergencyExit)
+        );
+
+        vault.set_is_emergency_exit(false);
+        assert!(!vault.is_emergency_exit());
+        assert_eq!(vault.check_is_emergency_exit(), Ok(()));
+    }
+
+    #[test]
+    fn test_check_is_category_paused() {
+        let mut vault = make_test_vault(0, 0, 0, 1_000, 1_000, DelegationState::default());
+        for category in PauseCategory::ALL {
+            assert_eq!(vault.check_is_category_paused(category), Ok(()));
+        }
+
+        vault.set_paused_categories(PauseCategory::Deposits.mask());
+        assert_eq!(
+            vault.check_is_category_paused(PauseCategory::Deposits),
+            Err(VaultError::VaultCategoryPaused)
+        );
+        assert_eq!(
+            vault.check_is_category_paused(PauseCategory::Withdrawals),
+            Ok(())
+        );
+    }
 }
@@ -1,16 +1,22 @@
 //! The [`VaultNcnTicket`] account tracks a vault supporting a node consensus network. It can be
 //! enabled and disabled over time by the vault NCN admin.
 use bytemuck::{Pod, Zeroable};
-use jito_bytemuck::{types::PodU64, AccountDeserialize, Discriminator};
+use jito_bytemuck::{types::PodU64, AccountDeserialize, JitoAccount};
 use jito_jsm_core::slot_toggle::SlotToggle;
 use shank::ShankAccount;
-use solana_program::{account_info::AccountInfo, msg, program_error::ProgramError, pubkey::Pubkey};
+use solana_program::pubkey::Pubkey;
+
+use crate::discriminators::VaultDiscriminator;
 
 const RESERVED_SPACE_LEN: usize = 263;
 
 /// The [`VaultNcnTicket`] account tracks a vault supporting a node consensus network. It can be
 /// enabled and disabled over time by the vault NCN admin.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Pod, Zeroable, AccountDeserialize, ShankAccount)]
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Pod, Zeroable, AccountDeserialize, JitoAccount, ShankAccount,
+)]
+#[discriminator(VaultDiscriminator::VaultNcnTicket)]
+#[account_seeds(b"vault_ncn_ticket", vault, ncn)]
 #[repr(C)]
 pub struct VaultNcnTicket {
     /// The vault account
@@ -47,88 +53,13 @@ impl VaultNcnTicket {
     pub fn index(&self) -> u64 {
         self.index.into()
     }
-
-    /// The seeds for the PDA
-    ///
-    /// # Arguments
-    /// * `vault` - The vault account
-    /// * `ncn` - The ncn account
-    pub fn seeds(vault: &Pubkey, ncn: &Pubkey) -> Vec<Vec<u8>> {
-        Vec::from_iter([
-            b"vault_ncn_ticket".to_vec(),
-            vault.as_ref().to_vec(),
-            ncn.as_ref().to_vec(),
-        ])
-    }
-
-    /// Find the program address for the PDA
-    ///
-    /// # Arguments
-    /// * `program_id` - The program ID
-    /// * `vault` - The vault account
-    /// * `ncn` - The ncn account
-    ///
-    /// # Returns
-    /// * [`Pubkey`] - The program address
-    /// * `u8` - The bump seed
-    /// * `Vec<Vec<u8>>` - The seeds
-    pub fn find_program_address(
-        program_id: &Pubkey,
-        vault: &Pubkey,
-        ncn: &Pubkey,
-    ) -> (Pubkey, u8, Vec<Vec<u8>>) {
-        let seeds = Self::seeds(vault, ncn);
-        let seeds_iter: Vec<_> = seeds.iter().map(|s| s.as_slice()).collect();
-        let (pda, bump) = Pubkey::find_program_address(&seeds_iter, program_id);
-        (pda, bump, seeds)
-    }
-
-    /// Loads the [`VaultNcnTicket`] account
-    ///
-    /// # Arguments
-    /// * `program_id` - The program ID
-    /// * `vault_ncn_ticket` - The account to load
-    /// * `vault` - The vault account
-    /// * `ncn` - The ncn account
-    /// * `expect_writable` - Whether the account should be writable
-    ///
-    /// # Returns
-    /// * `Result<(), ProgramError>` - The result of the operation
-    pub fn load(
-        program_id: &Pubkey,
-        vault_ncn_ticket: &AccountInfo,
-        vault: &AccountInfo,
-        ncn: &AccountInfo,
-        expect_writable: bool,
-    ) -> Result<(), ProgramError> {
-        if vault_ncn_ticket.owner.ne(program_id) {
-            msg!("Vault NCN ticket account has an invalid owner");
-            return Err(ProgramError::InvalidAccountOwner);
-        }
-        if vault_ncn_ticket.data_is_empty() {
-            msg!("Vault NCN ticket account data is empty");
-            return Err(ProgramError::InvalidAccountData);
-        }
-        if expect_writable && !vault_ncn_ticket.is_writable {
-            msg!("Vault NCN ticket account is not writable");
-            return Err(ProgramError::InvalidAccountData);
-        }
-        if vault_ncn_ticket.data.borrow()[0].ne(&Self::DISCRIMINATOR) {
-            msg!("Vault NCN ticket account discriminator is invalid");
-            return Err(ProgramError::InvalidAccountData);
-        }
-        let expected_pubkey = Self::find_program_address(program_id, vault.key, ncn.key).0;
-        if vault_ncn_ticket.key.ne(&expected_pubkey) {
-            msg!("Vault NCN ticket account is not at the correct PDA");
-            return Err(ProgramError::InvalidAccountData);
-        }
-        Ok(())
-    }
 }
 
 #[cfg(test)]
 mod tests {
+    use jito_bytemuck::Discriminator;
     use jito_jsm_core::slot_toggle::SlotToggleState;
+    use solana_program::{account_info::AccountInfo, program_error::ProgramError};
 
     use super::*;
 
@@ -154,4 +85,91 @@ mod tests {
             SlotToggleState::Inactive
         );
     }
+
+    #[test]
+    fn test_vault_ncn_ticket_seeds() {
+        let vault = Pubkey::new_unique();
+        let ncn = Pubkey::new_unique();
+        assert_eq!(
+            VaultNcnTicket::seeds(&vault, &ncn),
+            vec![
+                b"vault_ncn_ticket".to_vec(),
+                vault.to_bytes().to_vec(),
+                ncn.to_bytes().to_vec()
+            ]
+        );
+        assert_eq!(
+            VaultNcnTicket::DISCRIMINATOR,
+            VaultDiscriminator::VaultNcnTicket as u8
+        );
+    }
+
+    #[test]
+    fn test_vault_ncn_ticket_load() {
+        let program_id = Pubkey::new_unique();
+        let vault = Pubkey::new_unique();
+        let ncn = Pubkey::new_unique();
+        let (address, bump, _) = VaultNcnTicket::find_program_address(&program_id, &vault, &ncn);
+
+        let mut data = vec![0; 8 + std::mem::size_of::<VaultNcnTicket>()];
+        VaultNcnTicket::write_header(&mut data).unwrap();
+        data[8..].copy_from_slice(bytemuck::bytes_of(&VaultNcnTicket::new(
+            vault, ncn, 0, bump, 0,
+        )));
+        let (mut lamports, mut vault_lamports, mut ncn_lamports) = (0, 0, 0);
+        let ticket_info = AccountInfo::new(
+            &address,
+            false,
+            true,
+            &mut lamports,
+            &mut data,
+            &program_id,
+            false,
+            0,
+        );
+        let vault_info = AccountInfo::new(
+            &vault,
+            false,
+            false,
+            &mut vault_lamports,
+            &mut [],
+            &program_id,
+            false,
+            0,
+        );
+        let ncn_info = AccountInfo::new(
+            &ncn,
+            false,
+            false,
+            &mut ncn_lamports,
+            &mut [],
+            &program_id,
+            false,
+            0,
+        );
+
+        VaultNcnTicket::load(&program_id, &ticket_info, &vault_info, &ncn_info, true).unwrap();
+
+        // The seed accounts are checked in order
+        assert_eq!(
+            VaultNcnTicket::load(&program_id, &ticket_info, &ncn_info, &vault_info, false),
+            Err(ProgramError::InvalidAccountData)
+        );
+        assert_eq!(
+            VaultNcnTicket::load(
+                &Pubkey::new_unique(),
+                &ticket_info,
+                &vault_info,
+                &ncn_info,
+                false
+            ),
+            Err(ProgramError::InvalidAccountOwner)
+        );
+
+        ticket_info.data.borrow_mut()[0] = VaultDiscriminator::Vault as u8;
+        assert_eq!(
+            VaultNcnTicket::load(&program_id, &ticket_info, &vault_info, &ncn_info, false),
+            Err(ProgramError::InvalidAccountData)
+        );
+    }
 }
@@ -1,21 +1,22 @@
 //! The [`VaultOperatorDelegation`] account tracks a vault's delegation to an operator
 
 use bytemuck::{Pod, Zeroable};
-use jito_bytemuck::{types::PodU64, AccountDeserialize, Discriminator};
+use jito_bytemuck::{types::PodU64, AccountDeserialize, JitoAccount};
 use jito_jsm_core::get_epoch;
 use jito_vault_sdk::error::VaultError;
 use shank::ShankAccount;
-use solana_program::{
-    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
-    pubkey::Pubkey,
-};
+use solana_program::{entrypoint::ProgramResult, msg, program_error::ProgramError, pubkey::Pubkey};
 
-use crate::delegation_state::DelegationState;
+use crate::{delegation_state::DelegationState, discriminators::VaultDiscriminator};
 
 const RESERVED_SPACE_LEN: usize = 263;
 
 /// The [`VaultOperatorDelegation`] account tracks a vault's delegation to an operator
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Pod, Zeroable, AccountDeserialize, ShankAccount)]
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Pod, Zeroable, AccountDeserialize, JitoAccount, ShankAccount,
+)]
+#[discriminator(VaultDiscriminator::VaultOperatorDelegation)]
+#[account_seeds(b"vault_operator_delegation", vault, operator)]
 #[repr(C)]
 pub struct VaultOperatorDelegation {
     /// The vault account
@@ -105,79 +106,6 @@ impl VaultOperatorDelegation {
         self.last_update_slot = PodU64::from(slot);
         Ok(())
     }
-
-    /// The seeds for the PDA
-    pub fn seeds(vault: &Pubkey, operator: &Pubkey) -> Vec<Vec<u8>> {
-        Vec::from_iter([
-            b"vault_operator_delegation".to_vec(),
-            vault.as_ref().to_vec(),
-            operator.as_ref().to_vec(),
-        ])
-    }
-
-    /// Find the program address for the PDA
-    ///
-    /// # Arguments
-    /// * `program_id` - The program ID
-    /// * `vault` - The vault account
-    /// * `operator` - The operator account
-    ///
-    /// # Returns
-    /// * [`Pubkey`] - The program address
-    /// * `u8` - The bump seed
-    /// * `Vec<Vec<u8>>` - The seeds
-    pub fn find_program_address(
-        program_id: &Pubkey,
-        vault: &Pubkey,
-        operator: &Pubkey,
-    ) -> (Pubkey, u8, Vec<Vec<u8>>) {
-        let seeds = Self::seeds(vault, operator);
-        let seeds_iter: Vec<_> = seeds.iter().map(|s| s.as_slice()).collect();
-        let (pda, bump) = Pubkey::find_program_address(&seeds_iter, program_id);
-        (pda, bump, seeds)
-    }
-
-    /// Loads the [`VaultOperatorDelegation`] account
-    ///
-    /// # Arguments
-    /// * `program_id` - The program ID
-    /// * `vault_operator_delegation` - The [`VaultOperatorDelegation`] account
-    /// * `vault` - The vault account
-    /// * `operator` - The operator account
-    /// * `expect_writable` - Whether the account should be writable
-    ///
-    /// # Returns
-    /// * `Result<(), ProgramError>` - The result of the operation
-    pub fn load(
-        program_id: &Pubkey,
-        vault_operator_delegation: &AccountInfo,
-        vault: &AccountInfo,
-        operator: &AccountInfo,
-        expect_writable: bool,
-    ) -> Result<(), ProgramError> {
-        if vault_operator_delegation.owner.ne(program_id) {
-            msg!("Vault operator ticket account has an invalid owner");
-            return Err(ProgramError::InvalidAccountOwner);
-        }
-        if vault_operator_delegation.data_is_empty() {
-            msg!("Vault operator ticket account data is empty");
-            return Err(ProgramError::InvalidAccountData);
-        }
-        if expect_writable && !vault_operator_delegation.is_writable {
-            msg!("Vault operator ticket account is not writable");
-            return Err(ProgramError::InvalidAccountData);
-        }
-        if vault_operator_delegation.data.borrow()[0].ne(&Self::DISCRIMINATOR) {
-            msg!("Vault operator ticket account discriminator is invalid");
-            return Err(ProgramError::InvalidAccountData);
-        }
-        let expected_pubkey = Self::find_program_address(program_id, vault.key, operator.key).0;
-        if vault_operator_delegation.key.ne(&expected_pubkey) {
-            msg!("Vault operator ticket account is not at the correct PDA");
-            return Err(ProgramError::InvalidAccountData);
-        }
-        Ok(())
-    }
 }
 
 #[cfg(test)]
@@ -1,17 +1,30 @@
 //! The [`VaultStakerWithdrawalTicket`] account is used to represent a pending withdrawal from a vault by a staker.
 //! For every withdraw ticket, there's an associated token account owned by the withdrawal ticket with the staker's VRT.
 use bytemuck::{Pod, Zeroable};
-use jito_bytemuck::{types::PodU64, AccountDeserialize, Discriminator};
-use jito_jsm_core::get_epoch;
+use jito_bytemuck::{
+    types::{PodU16, PodU64},
+    AccountDeserialize, JitoAccount,
+};
+use jito_jsm_core::{diagnostic::Check, get_epoch};
 use jito_vault_sdk::error::VaultError;
 use shank::ShankAccount;
-use solana_program::{account_info::AccountInfo, msg, program_error::ProgramError, pubkey::Pubkey};
+use solana_program::{msg, program_error::ProgramError, pubkey::Pubkey};
 
-const RESERVED_SPACE_LEN: usize = 263;
+use crate::{discriminators::VaultDiscriminator, MAX_BPS};
+
+const RESERVED_SPACE_LEN: usize = 229;
+
+/// The largest tip, in basis points of the withdrawn assets, a staker can allow an auto-claim
+/// delegate to take
+pub const MAX_AUTO_CLAIM_TIP_BPS: u16 = 100;
 
 /// The [`VaultStakerWithdrawalTicket`] account is used to represent a pending withdrawal from a vault by a staker.
 /// For every withdrawal ticket, there's an associated token account owned by the withdrawal ticket with the staker's VRT.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Pod, Zeroable, AccountDeserialize, ShankAccount)]
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Pod, Zeroable, AccountDeserialize, JitoAccount, ShankAccount,
+)]
+#[discriminator(VaultDiscriminator::VaultStakerWithdrawalTicket)]
+#[account_seeds(b"vault_staker_withdrawal_ticket", self.vault, self.base)]
 #[repr(C)]
 pub struct VaultStakerWithdrawalTicket {
     /// The vault being withdrawn from
@@ -33,7 +46,14 @@ pub struct VaultStakerWithdrawalTicket {
     /// The bump seed used to create the PDA
     pub bump: u8,
 
-    reserved: [u8; 263],
+    /// An account the staker allows to burn the ticket on their behalf once it's withdrawable,
+    /// or the default pubkey if there is none
+    pub auto_claim_delegate: Pubkey,
+
+    /// The largest tip, in basis points of the withdrawn assets, the auto-claim delegate may take
+    auto_claim_max_tip_bps: PodU16,
+
+    reserved: [u8; 229],
 }
 
 impl VaultStakerWithdrawalTicket {
@@ -52,6 +72,8 @@ impl VaultStakerWithdrawalTicket {
             vrt_amount: PodU64::from(vrt_amount),
             slot_unstaked: PodU64::from(slot_unstaked),
             bump,
+            auto_claim_delegate: Pubkey::default(),
+            auto_claim_max_tip_bps: PodU16::from(0),
             reserved: [0; RESERVED_SPACE_LEN],
         }
     }
@@ -64,14 +86,85 @@ impl VaultStakerWithdrawalTicket {
         self.slot_unstaked.into()
     }
 
+    pub fn auto_claim_max_tip_bps(&self) -> u16 {
+        self.auto_claim_max_tip_bps.into()
+    }
+
+    /// Whether the staker has registered an auto-claim delegate
+    pub fn has_auto_claim_delegate(&self) -> bool {
+        self.auto_claim_delegate.ne(&Pubkey::default())
+    }
+
+    /// Registers an auto-claim delegate, or removes it when `delegate` is the default pubkey
+    pub fn set_auto_claim_delegate(
+        &mut self,
+        delegate: Pubkey,
+        max_tip_bps: u16,
+    ) -> Result<(), VaultError> {
+        if max_tip_bps > MAX_AUTO_CLAIM_TIP_BPS {
+            msg!(
+                "Auto-claim tip exceeds the maximum of {} bps",
+                MAX_AUTO_CLAIM_TIP_BPS
+            );
+            return Err(VaultError::VaultStakerWithdrawalTicketAutoClaimTipTooHigh);
+        }
+        self.auto_claim_delegate = delegate;
+        self.auto_claim_max_tip_bps = if delegate.eq(&Pubkey::default()) {
+            PodU16::from(0)
+        } else {
+            PodU16::from(max_tip_bps)
+        };
+        Ok(())
+    }
+
+    /// Checks that `delegate` is the registered auto-claim delegate and that `tip_bps` is within
+    /// the tip the staker allowed
+    pub fn check_auto_claim(&self, delegate: &Pubkey, tip_bps: u16) -> Result<(), VaultError> {
+        if !self.has_auto_claim_delegate() || self.auto_claim_delegate.ne(delegate) {
+            msg!("Signer is not the auto-claim delegate of the withdrawal ticket");
+            Check::AutoClaimDelegate.log_failure(delegate);
+            return Err(VaultError::VaultStakerWithdrawalTicketInvalidAutoClaimDelegate);
+        }
+        if tip_bps > self.auto_claim_max_tip_bps() {
+            msg!(
+                "Auto-claim tip of {} bps exceeds the {} bps allowed by the staker",
+                tip_bps,
+                self.auto_claim_max_tip_bps()
+            );
+            return Err(VaultError::VaultStakerWithdrawalTicketAutoClaimTipTooHigh);
+        }
+        Ok(())
+    }
+
+    /// The share of `out_amount` paid to the auto-claim delegate, rounded down in favor of the
+    /// staker
+    pub fn calculate_auto_claim_tip(out_amount: u64, tip_bps: u16) -> Result<u64, VaultError> {
+        let tip = (out_amount as u128)
+            .checked_mul(tip_bps as u128)
+            .and_then(|x| x.checked_div(MAX_BPS as u128))
+            .and_then(|x| x.try_into().ok())
+            .ok_or(VaultError::VaultOverflow)?;
+        Ok(tip)
+    }
+
     pub fn check_staker(&self, staker: &Pubkey) -> Result<(), VaultError> {
         if self.staker.ne(staker) {
             msg!("Staker is not the owner of the withdrawal ticket");
+            Check::Staker.log_failure(staker);
             return Err(VaultError::VaultStakerWithdrawalTicketInvalidStaker);
         }
         Ok(())
     }
 
+    pub fn check_vault(&self, vault: &Pubkey) -> Result<(), VaultError> {
+        if self.vault.ne(vault) {
+            msg!("Withdrawal ticket does not belong to the vault");
+            Check::Vault.log_failure(vault);
+            return Err(VaultError::VaultStakerWithdrawalTicketInvalidVault);
+        }
+        Ok(())
+    }
+
     /// In order for the ticket to be withdrawable, it needs to be more than one **full** epoch
     /// since unstaking
     pub fn is_withdrawable(&self, slot: u64, epoch_length: u64) -> Result<bool, ProgramError> {
@@ -89,19 +182,6 @@ impl VaultStakerWithdrawalTicket {
         }
     }
 
-    /// Returns the seeds for the PDA
-    ///
-    /// # Arguments
-    /// * `vault` - The vault
-    /// * `base` - The base account used as a PDA seed
-    pub fn seeds(vault: &Pubkey, base: &Pubkey) -> Vec<Vec<u8>> {
-        Vec::from_iter([
-            b"vault_staker_withdrawal_ticket".to_vec(),
-            vault.to_bytes().to_vec(),
-            base.to_bytes().to_vec(),
-        ])
-    }
-
     /// Returns the seeds for the PDA used for signing
     ///
     /// # Returns
@@ -111,73 +191,6 @@ impl VaultStakerWithdrawalTicket {
         vault_seeds.push(vec![self.bump]);
         vault_seeds
     }
-
-    /// Find the program address for the PDA
-    ///
-    /// # Arguments
-    /// * `program_id` - The program ID
-    /// * `vault` - The vault
-    /// * `staker` - The staker
-    /// * `base` - The base account used as a PDA seed
-    ///
-    /// # Returns
-    /// * [`Pubkey`] - The program address
-    /// * `u8` - The bump seed
-    /// * `Vec<Vec<u8>` - The seeds used to generate the PDA
-    pub fn find_program_address(
-        program_id: &Pubkey,
-        vault: &Pubkey,
-        base: &Pubkey,
-    ) -> (Pubkey, u8, Vec<Vec<u8>>) {
-        let seeds = Self::seeds(vault, base);
-        let seeds_iter: Vec<_> = seeds.iter().map(|s| s.as_slice()).collect();
-        let (pda, bump) = Pubkey::find_program_address(&seeds_iter, program_id);
-        (pda, bump, seeds)
-    }
-
-    /// Loads the [`VaultStakerWithdrawalTicket`] account
-    ///
-    /// # Arguments
-    /// * `program_id` - The program ID
-    /// * `vault_staker_withdrawal_ticket` - The [`VaultStakerWithdrawalTicket`] account
-    /// * `expect_writable` - Whether the account should be writable
-    ///
-    /// # Returns
-    /// * `Result<(), ProgramError>` - The result of the operation
-    pub fn load(
-        program_id: &Pubkey,
-        vault_staker_withdrawal_ticket: &AccountInfo,
-        expect_writable: bool,
-    ) -> Result<(), ProgramError> {
-        if vault_staker_withdrawal_ticket.owner.ne(program_id) {
-            msg!("Vault staker withdrawal ticket has an invalid owner");
-            return Err(ProgramError::InvalidAccountOwner);
-        }
-        if vault_staker_withdrawal_ticket.data_is_empty() {
-            msg!("Vault staker withdrawal ticket data is empty");
-            return Err(ProgramError::InvalidAccountData);
-        }
-        if expect_writable && !vault_staker_withdrawal_ticket.is_writable {
-            msg!("Vault staker withdrawal ticket is not writable");
-            return Err(ProgramError::InvalidAccountData);
-        }
-        if vault_staker_withdrawal_ticket.data.borrow()[0].ne(&Self::DISCRIMINATOR) {
-            msg!("Vault staker withdrawal ticket discriminator is invalid");
-            return Err(ProgramError::InvalidAccountData);
-        }
-
-        let vault_staker_withdrawal_ticket_data = vault_staker_withdrawal_ticket.data.borrow();
-        let ticket = Self::try_from_slice_unchecked(&vault_staker_withdrawal_ticket_data)?;
-        let seeds = ticket.signing_seeds();
-        let seed_slices: Vec<&[u8]> = seeds.iter().map(|seed| seed.as_slice()).collect();
-        let expected_pubkey = Pubkey::create_program_address(&seed_slices, program_id)?;
-        if vault_staker_withdrawal_ticket.key.ne(&expected_pubkey) {
-            msg!("Vault staker withdrawal ticket is not at the correct PDA");
-            return Err(ProgramError::InvalidAccountData);
-        }
-
-        Ok(())
-    }
 }
 
 #[cfg(test)]
@@ -194,7 +207,75 @@ mod tests {
             size_of::<PodU64>() + // vrt_amount
             size_of::<PodU64>() + // slot_unstaked
             size_of::<u8>() + // bump
+            size_of::<Pubkey>() + // auto_claim_delegate
+            size_of::<PodU16>() + // auto_claim_max_tip_bps
             RESERVED_SPACE_LEN; // reserved
         assert_eq!(vault_staker_withdrawal_ticket_size, sum_of_fields);
     }
+
+    #[test]
+    fn test_check_vault() {
+        let vault = Pubkey::new_unique();
+        let ticket = VaultStakerWithdrawalTicket::new(
+            vault,
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            100,
+            0,
+            0,
+        );
+        ticket.check_vault(&vault).unwrap();
+        assert_eq!(
+            ticket.check_vault(&Pubkey::new_unique()),
+            Err(VaultError::VaultStakerWithdrawalTicketInvalidVault)
+        );
+    }
+
+    #[test]
+    fn test_auto_claim_delegate() {
+        let delegate = Pubkey::new_unique();
+        let mut ticket = VaultStakerWithdrawalTicket::new(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            100,
+            0,
+            0,
+        );
+        assert!(!ticket.has_auto_claim_delegate());
+        assert_eq!(
+            ticket.check_auto_claim(&Pubkey::default(), 0),
+            Err(VaultError::VaultStakerWithdrawalTicketInvalidAutoClaimDelegate)
+        );
+
+        assert_eq!(
+            ticket.set_auto_claim_delegate(delegate, MAX_AUTO_CLAIM_TIP_BPS + 1),
+            Err(VaultError::VaultStakerWithdrawalTicketAutoClaimTipTooHigh)
+        );
+        ticket.set_auto_claim_delegate(delegate, 50).unwrap();
+        ticket.check_auto_claim(&delegate, 50).unwrap();
+        assert_eq!(
+            ticket.check_auto_claim(&delegate, 51),
+            Err(VaultError::VaultStakerWithdrawalTicketAutoClaimTipTooHigh)
+        );
+        assert_eq!(
+            ticket.check_auto_claim(&Pubkey::new_unique(), 0),
+            Err(VaultError::VaultStakerWithdrawalTicketInvalidAutoClaimDelegate)
+        );
+
+        assert_eq!(
+            VaultStakerWithdrawalTicket::calculate_auto_claim_tip(10_000, 50),
+            Ok(50)
+        );
+        assert_eq!(
+            VaultStakerWithdrawalTicket::calculate_auto_claim_tip(199, 50),
+            Ok(0)
+        );
+
+        ticket
+            .set_auto_claim_delegate(Pubkey::default(), 50)
+            .unwrap();
+        assert!(!ticket.has_auto_claim_delegate());
+        assert_eq!(ticket.auto_claim_max_tip_bps(), 0);
+    }
 }
@@ -1,14 +1,24 @@
+use std::cmp::min;
+
 use bytemuck::{Pod, Zeroable};
-use jito_bytemuck::{types::PodU64, AccountDeserialize, Discriminator};
-use jito_vault_sdk::error::VaultError;
+use jito_bytemuck::{types::PodU64, AccountDeserialize, JitoAccount};
+use jito_jsm_core::get_epoch;
+use jito_vault_sdk::{error::VaultError, instruction::WithdrawalAllocationMethod};
 use shank::ShankAccount;
-use solana_program::{account_info::AccountInfo, msg, program_error::ProgramError, pubkey::Pubkey};
+use solana_program::{entrypoint::ProgramResult, msg, pubkey::Pubkey};
 
-use crate::delegation_state::DelegationState;
+use crate::{
+    delegation_state::DelegationState, discriminators::VaultDiscriminator, vault::Vault,
+    vault_operator_delegation::VaultOperatorDelegation,
+};
 
 const RESERVED_SPACE_LEN: usize = 263;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Pod, Zeroable, AccountDeserialize, ShankAccount)]
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Pod, Zeroable, AccountDeserialize, JitoAccount, ShankAccount,
+)]
+#[discriminator(VaultDiscriminator::VaultUpdateStateTracker)]
+#[account_seeds(b"vault_update_state_tracker", vault, ncn_epoch: u64)]
 #[repr(C)]
 pub struct VaultUpdateStateTracker {
     /// The vault associated with this update ticket
@@ -103,73 +113,123 @@ impl VaultUpdateStateTracker {
                 .checked_sub(1)
                 .ok_or(VaultError::ArithmeticUnderflow)?)
     }
+}
 
-    /// Returns the seeds for the PDA
-    ///
-    /// # Arguments
-    /// * `vault` - The vault
-    /// * `ncn_epoch` - The NCN epoch
-    pub fn seeds(vault: &Pubkey, ncn_epoch: u64) -> Vec<Vec<u8>> {
-        Vec::from_iter([
-            b"vault_update_state_tracker".to_vec(),
-            vault.to_bytes().to_vec(),
-            ncn_epoch.to_le_bytes().to_vec(),
-        ])
-    }
+/// Updates a single [`VaultOperatorDelegation`] and accumulates its delegation state into the
+/// [`VaultUpdateStateTracker`]. The delegations must be cranked in the order enforced by
+/// [`VaultUpdateStateTracker::check_and_update_index`].
+///
+/// # Arguments
+/// * `vault` - The vault being updated
+/// * `vault_operator_delegation` - The delegation to crank, already loaded
+/// * `vault_update_state_tracker` - The tracker for the current NCN epoch, already loaded
+/// * `slot` - The current slot
+/// * `epoch_length` - The NCN epoch length
+pub fn crank_vault_operator_delegation(
+    vault: &mut Vault,
+    vault_operator_delegation: &mut VaultOperatorDelegation,
+    vault_update_state_tracker: &mut VaultUpdateStateTracker,
+    slot: u64,
+    epoch_length: u64,
+) -> ProgramResult {
+    let last_full_state_update_slot = vault.last_full_state_update_slot();
+    let last_full_state_update_epoch = get_epoch(last_full_state_update_slot, epoch_length)?;
+
+    let operator_last_update_slot = vault_operator_delegation.last_update_slot();
+    let operator_last_updated_epoch = get_epoch(operator_last_update_slot, epoch_length)?;
+
+    // If an operator has been updated in an epoch where the vault has not been fully updated,
+    // it would have unstaked it's fair share of assets. So no further unstaking is needed, however,
+    // the vault_operator_delegation should be updated to reflect the new state. In the case that
+    // all operators have been updated and close_vault_update_state_tracker has not been called,
+    // there should be zero additional_assets_need_unstaking, and it'd be okay to 'skip' withdrawing
+    // the assets from the operator.
+    let has_been_partially_updated = last_full_state_update_epoch < operator_last_updated_epoch;
+
+    vault_operator_delegation.check_is_already_updated(slot, epoch_length)?;
+    vault_update_state_tracker
+        .check_and_update_index(vault_operator_delegation.index(), vault.operator_count())?;
+
+    // In emergency exit, every operator's stake is force-cooled so stakers can withdraw
+    // everything once the cooldown completes
+    if vault.is_emergency_exit() && vault_operator_delegation.delegation_state.staked_amount() > 0 {
+        let staked_amount = vault_operator_delegation.delegation_state.staked_amount();
+
+        msg!(
+            "Emergency exit: force cooling down {} assets from operator {}",
+            staked_amount,
+            vault_operator_delegation.operator
+        );
 
-    /// Find the program address for the PDA
-    pub fn find_program_address(
-        program_id: &Pubkey,
-        vault: &Pubkey,
-        ncn_epoch: u64,
-    ) -> (Pubkey, u8, Vec<Vec<u8>>) {
-        let seeds = Self::seeds(vault, ncn_epoch);
-        let seeds_iter: Vec<_> = seeds.iter().map(|s| s.as_slice()).collect();
-        let (pda, bump) = Pubkey::find_program_address(&seeds_iter, program_id);
-        (pda, bump, seeds)
+        vault_operator_delegation
+            .delegation_state
+            .cooldown(staked_amount)?;
+        vault.decrement_additional_assets_need_unstaking(min(
+            staked_amount,
+            vault.additional_assets_need_unstaking(),
+        ))?;
     }
 
-    pub fn load(
-        program_id: &Pubkey,
-        vault_update_delegation_ticket: &AccountInfo,
-        vault: &AccountInfo,
-        ncn_epoch: u64,
-        expect_writable: bool,
-    ) -> Result<(), ProgramError> {
-        if vault_update_delegation_ticket.owner.ne(program_id) {
-            msg!("Vault update delegations ticket has an invalid owner");
-            return Err(ProgramError::InvalidAccountOwner);
-        }
-        if vault_update_delegation_ticket.data_is_empty() {
-            msg!("Vault update delegations ticket data is empty");
-            return Err(ProgramError::InvalidAccountData);
-        }
-        if expect_writable && !vault_update_delegation_ticket.is_writable {
-            msg!("Vault update delegations ticket is not writable");
-            return Err(ProgramError::InvalidAccountData);
-        }
-        if vault_update_delegation_ticket.data.borrow()[0].ne(&Self::DISCRIMINATOR) {
-            msg!("Vault update delegations ticket discriminator is invalid");
-            return Err(ProgramError::InvalidAccountData);
+    match WithdrawalAllocationMethod::try_from(
+        vault_update_state_tracker.withdrawal_allocation_method,
+    ) {
+        Ok(WithdrawalAllocationMethod::Greedy) => {
+            // If an operator has been updated in a previous, partial update cycle,
+            // they should no longer be the destination for any remaining `additional_assets_need_unstaking`
+            // additionally, this keeps all of the `additional_assets_need_unstaking` at the same cooldown level
+            // since the operator_delegation is updated for X epochs since the operator's last update
+            if !has_been_partially_updated
+                && vault.additional_assets_need_unstaking() > 0
+                && vault_operator_delegation.delegation_state.staked_amount() > 0
+            {
+                let max_cooldown = min(
+                    vault_operator_delegation.delegation_state.staked_amount(),
+                    vault.additional_assets_need_unstaking(),
+                );
+
+                msg!(
+                    "Force cooling down {} assets from operator {}",
+                    max_cooldown,
+                    vault_operator_delegation.operator
+                );
+
+                vault_operator_delegation
+                    .delegation_state
+                    .cooldown(max_cooldown)?;
+                vault.decrement_additional_assets_need_unstaking(max_cooldown)?;
+            }
         }
-        let expected_pubkey = Self::find_program_address(program_id, vault.key, ncn_epoch).0;
-        if vault_update_delegation_ticket.key.ne(&expected_pubkey) {
-            msg!("Vault update delegations ticket is not at the correct PDA");
-            return Err(ProgramError::InvalidAccountData);
+        Err(e) => {
+            msg!(
+                "Invalid withdrawal allocation method: {:?}",
+                vault_update_state_tracker.withdrawal_allocation_method
+            );
+            return Err(e);
         }
-        Ok(())
     }
+
+    vault_operator_delegation.update(slot, epoch_length)?;
+    vault_update_state_tracker
+        .delegation_state
+        .accumulate(&vault_operator_delegation.delegation_state)?;
+
+    Ok(())
 }
 
 #[cfg(test)]
 mod tests {
     use jito_bytemuck::types::PodU64;
-    use jito_vault_sdk::error::VaultError;
+    use jito_vault_sdk::{error::VaultError, instruction::WithdrawalAllocationMethod};
+    use proptest::prelude::*;
     use solana_program::pubkey::Pubkey;
 
     use crate::{
         delegation_state::DelegationState,
-        vault_update_state_tracker::{VaultUpdateStateTracker, RESERVED_SPACE_LEN},
+        vault::Vault,
+        vault_operator_delegation::VaultOperatorDelegation,
+        vault_update_state_tracker::{
+            crank_vault_operator_delegation, VaultUpdateStateTracker, RESERVED_SPACE_LEN,
+        },
     };
 
     #[test]
@@ -299,4 +359,135 @@ mod tests {
             Err(VaultError::DivisionByZero)
         );
     }
+
+    proptest! {
+        /// `calculate_additional_supported_assets_needed_to_unstake` simulates the update on the
+        /// vault's aggregate delegation state. Cranking every operator must land on the same
+        /// state, except for the stake the crank force-cools to cover withdrawals.
+        #[test]
+        fn test_simulated_update_matches_crank(
+            delegations in proptest::collection::vec(
+                (0..1_000_000_000u64, 0..1_000_000_000u64, 0..1_000_000_000u64),
+                1..5,
+            ),
+            undelegated in 0..1_000_000_000u64,
+            vrt_queue in (0..1_000_000_000u64, 0..1_000_000_000u64, 0..1_000_000_000u64),
+            last_update_epoch in 0..100u64,
+            epochs_passed in 1..4u64,
+        ) {
+            const EPOCH_LENGTH: u64 = 100;
+            let last_update_slot = last_update_epoch * EPOCH_LENGTH + EPOCH_LENGTH / 2;
+            let ncn_epoch = last_update_epoch + epochs_passed;
+            let slot = ncn_epoch * EPOCH_LENGTH;
+
+            let vault_pubkey = Pubkey::new_unique();
+            let mut vault = Vault::new(
+                Pubkey::new_unique(),
+                Pubkey::new_unique(),
+                Pubkey::new_unique(),
+                0,
+                Pubkey::new_unique(),
+                0,
+                0,
+                0,
+                0,
+                0,
+                last_update_slot,
+            )
+            .unwrap();
+            let mut vault_operator_delegations = Vec::with_capacity(delegations.len());
+            for (index, (staked_amount, enqueued_for_cooldown_amount, cooling_down_amount)) in
+                delegations.into_iter().enumerate()
+            {
+                let mut vault_operator_delegation = VaultOperatorDelegation::new(
+                    vault_pubkey,
+                    Pubkey::new_unique(),
+                    index as u64,
+                    0,
+                    last_update_slot,
+                );
+                vault_operator_delegation.delegation_state = DelegationState::new(
+                    staked_amount,
+                    enqueued_for_cooldown_amount,
+                    cooling_down_amount,
+                );
+                vault
+                    .delegation_state
+                    .accumulate(&vault_operator_delegation.delegation_state)
+                    .unwrap();
+                vault.increment_operator_count().unwrap();
+                vault_operator_delegations.push(vault_operator_delegation);
+            }
+
+            let tokens_deposited = vault.delegation_state.total_security().unwrap() + undelegated;
+            let (vrt_enqueued_for_cooldown_amount, vrt_cooling_down_amount, vrt_ready_to_claim_amount) =
+                vrt_queue;
+            vault.set_tokens_deposited(tokens_deposited);
+            vault.set_vrt_supply(tokens_deposited.max(
+                vrt_enqueued_for_cooldown_amount + vrt_cooling_down_amount + vrt_ready_to_claim_amount,
+            ));
+            vault.set_vrt_enqueued_for_cooldown_amount(vrt_enqueued_for_cooldown_amount);
+            vault.set_vrt_cooling_down_amount(vrt_cooling_down_amount);
+            vault
+                .increment_vrt_ready_to_claim_amount(vrt_ready_to_claim_amount)
+                .unwrap();
+
+            let additional_assets_need_unstaking = vault
+                .calculate_additional_supported_assets_needed_to_unstake(slot, EPOCH_LENGTH)
+                .unwrap();
+            let mut simulated = vault.delegation_state;
+            for _ in 0..epochs_passed.min(2) {
+                simulated.update();
+            }
+
+            vault.set_additional_assets_need_unstaking(additional_assets_need_unstaking);
+            let mut vault_update_state_tracker = VaultUpdateStateTracker::new(
+                vault_pubkey,
+                ncn_epoch,
+                WithdrawalAllocationMethod::Greedy as u8,
+            );
+            let num_operators = vault_operator_delegations.len();
+            for offset in 0..num_operators {
+                let index = (ncn_epoch as usize + offset) % num_operators;
+                crank_vault_operator_delegation(
+                    &mut vault,
+                    &mut vault_operator_delegations[index],
+                    &mut vault_update_state_tracker,
+                    slot,
+                    EPOCH_LENGTH,
+                )
+                .unwrap();
+            }
+            prop_assert!(vault_update_state_tracker
+                .all_operators_updated(num_operators as u64)
+                .unwrap());
+
+            let cranked = vault_update_state_tracker.delegation_state;
+            let forced_cooldown = simulated.staked_amount() - cranked.staked_amount();
+            prop_assert_eq!(
+                forced_cooldown,
+                additional_assets_need_unstaking.min(simulated.staked_amount())
+            );
+            prop_assert_eq!(
+                vault.additional_assets_need_unstaking(),
+                additional_assets_need_unstaking - forced_cooldown
+            );
+            if epochs_passed == 1 {
+                // Force-cooled stake is still cooling down and counts toward security
+                prop_assert_eq!(
+                    cranked.total_security().unwrap(),
+                    simulated.total_security().unwrap()
+                );
+            } else {
+                // Force-cooled stake went through both transitions and is already undelegated
+                prop_assert_eq!(
+                    cranked.total_security().unwrap() + forced_cooldown,
+                    simulated.total_security().unwrap()
+                );
+            }
+            if forced_cooldown == 0 {
+                prop_assert_eq!(cranked, simulated);
+            }
+        }
+    }
 }
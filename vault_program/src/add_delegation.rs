@@ -1,11 +1,13 @@
 use jito_bytemuck::AccountDeserialize;
-use jito_jsm_core::loader::load_signer;
-use jito_restaking_core::operator::Operator;
+use jito_jsm_core::{diagnostic::Check, loader::load_signer, pause::PauseCategory};
+use jito_restaking_core::{ncn_operator_state::NcnOperatorState, operator::Operator};
 use jito_vault_core::{
-    config::Config, vault::Vault, vault_operator_delegation::VaultOperatorDelegation,
+    config::Config, vault::Vault, vault_ncn_ticket::VaultNcnTicket,
+    vault_operator_delegation::VaultOperatorDelegation,
 };
+use jito_vault_sdk::error::VaultError;
 use solana_program::{
-    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult,
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
     program_error::ProgramError, pubkey::Pubkey, sysvar::Sysvar,
 };
 
@@ -14,17 +16,21 @@ use solana_program::{
 /// Specification:
 /// - Only the vault delegation admin shall be able to call this instruction.
 /// - The vault must be up-to-date before adding a delegation.
+/// - The vault must not be in emergency exit mode.
 /// - The amount delegated must be less than or equal to: the amount of tokens in the vault minus the amount of tokens
 ///   already delegated minus the amount of tokens reserved for VRTs.
 /// - The amount delegated to the operator must be accurately reported in the VaultOperatorDelegation account.
 /// - The vault's delegation state must be updated accordingly to ensure it's accurately tracking state across the entire operator delegation set.
 /// - The amount delegated must be greater than zero.
+/// - The operator must not be jailed by any NCN the vault is connected to. When the operator is jailed
+///   by at least one NCN, the remaining accounts shall contain an `(ncn, vault_ncn_ticket, ncn_operator_state)`
+///   triple for every VaultNcnTicket of the vault, ordered by ticket index.
 pub fn process_add_delegation(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
     amount: u64,
 ) -> ProgramResult {
-    let [config, vault_info, operator, vault_operator_delegation, vault_delegation_admin] =
+    let [config, vault_info, operator, vault_operator_delegation, vault_delegation_admin, remaining_accounts @ ..] =
         accounts
     else {
         return Err(ProgramError::NotEnoughAccountKeys);
@@ -58,6 +64,22 @@ pub fn process_add_delegation(
     vault.check_update_state_ok(clock.slot, config.epoch_length())?;
 
     vault.check_is_paused()?;
+    vault.check_is_category_paused(PauseCategory::Delegation)?;
+    vault.check_is_emergency_exit()?;
+
+    // The operator shall not be jailed by any NCN the vault is connected to
+    let operator_data = operator.data.borrow();
+    let operator_account = Operator::try_from_slice_unchecked(&operator_data)?;
+    if operator_account.jailed_ncn_count() > 0 {
+        check_operator_not_jailed(
+            program_id,
+            &config.restaking_program,
+            vault,
+            vault_info,
+            operator,
+            remaining_accounts,
+        )?;
+    }
 
     vault.delegate(amount)?;
 
@@ -67,3 +89,77 @@ pub fn process_add_delegation(
 
     Ok(())
 }
+
+/// Checks the operator against every NCN the vault is connected to, failing if any of them
+/// has jailed the operator.
+///
+/// # Arguments
+/// * `program_id` - The vault program ID
+/// * `restaking_program` - The restaking program ID
+/// * `vault` - The vault
+/// * `vault_info` - The vault account
+/// * `operator` - The operator account
+/// * `remaining_accounts` - An `(ncn, vault_ncn_ticket, ncn_operator_state)` triple for every
+///   VaultNcnTicket of the vault, ordered by ticket index
+fn check_operator_not_jailed(
+    program_id: &Pubkey,
+    restaking_program: &Pubkey,
+    vault: &Vault,
+    vault_info: &AccountInfo,
+    operator: &AccountInfo,
+    remaining_accounts: &[AccountInfo],
+) -> ProgramResult {
+    let expected_accounts_len = vault
+        .ncn_count()
+        .checked_mul(3)
+        .ok_or(VaultError::ArithmeticOverflow)?;
+    if remaining_accounts.len() as u64 != expected_accounts_len {
+        msg!(
+            "Operator is jailed by at least one NCN, expected {} accounts to check the vault's NCNs, received {}",
+            expected_accounts_len,
+            remaining_accounts.len()
+        );
+        return Err(ProgramError::NotEnoughAccountKeys);
+    }
+
+    for (index, ncn_accounts) in remaining_accounts.chunks_exact(3).enumerate() {
+        let [ncn, vault_ncn_ticket, ncn_operator_state] = ncn_accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        VaultNcnTicket::load(program_id, vault_ncn_ticket, vault_info, ncn, false)?;
+        let vault_ncn_ticket_data = vault_ncn_ticket.data.borrow();
+        let vault_ncn_ticket = VaultNcnTicket::try_from_slice_unchecked(&vault_ncn_ticket_data)?;
+        if vault_ncn_ticket.index() != index as u64 {
+            msg!(
+                "Vault NCN ticket index mismatch, expected {}, received {}",
+                index,
+                vault_ncn_ticket.index()
+            );
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        // An operator that never connected to the NCN can't be jailed by it
+        if ncn_operator_state.data_is_empty() {
+            let expected_ncn_operator_state =
+                NcnOperatorState::find_program_address(restaking_program, ncn.key, operator.key).0;
+            if ncn_operator_state.key.ne(&expected_ncn_operator_state) {
+                msg!("NCNOperatorState account is not at the correct PDA");
+                Check::Address.log_failure(ncn_operator_state.key);
+                return Err(ProgramError::InvalidAccountData);
+            }
+            continue;
+        }
+
+        NcnOperatorState::load(restaking_program, ncn_operator_state, ncn, operator, false)?;
+        let ncn_operator_state_data = ncn_operator_state.data.borrow();
+        let ncn_operator_state =
+            NcnOperatorState::try_from_slice_unchecked(&ncn_operator_state_data)?;
+        if ncn_operator_state.is_jailed() {
+            msg!("Operator is jailed by NCN {}", ncn.key);
+            return Err(VaultError::OperatorJailed.into());
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,35 @@
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::burn_withdrawal_ticket::{burn_withdrawal_ticket, AutoClaimTip};
+
+/// Burns the withdrawal ticket on behalf of the staker. The ticket's auto-claim delegate signs and
+/// receives `tip_bps` of the withdrawn assets, the rest is sent to the staker like
+/// [`crate::burn_withdrawal_ticket::process_burn_withdrawal_ticket`].
+pub fn process_auto_claim_withdrawal_ticket(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    tip_bps: u16,
+) -> ProgramResult {
+    if accounts.len() < 14 {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    }
+    let (required_accounts, optional_accounts) = accounts.split_at(14);
+    let (burn_accounts, auto_claim_accounts) = required_accounts.split_at(12);
+    let [auto_claim_delegate, auto_claim_delegate_token_account] = auto_claim_accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    burn_withdrawal_ticket(
+        program_id,
+        burn_accounts,
+        optional_accounts.first(),
+        Some(AutoClaimTip {
+            delegate: auto_claim_delegate,
+            delegate_token_account: auto_claim_delegate_token_account,
+            tip_bps,
+        }),
+    )
+}
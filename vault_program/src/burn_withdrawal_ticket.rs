@@ -2,8 +2,10 @@ use jito_bytemuck::AccountDeserialize;
 use jito_jsm_core::{
     close_program_account,
     loader::{
-        load_associated_token_account, load_system_program, load_token_mint, load_token_program,
+        load_associated_token_account, load_signer, load_system_program, load_token_mint,
+        load_token_program,
     },
+    pause::PauseCategory,
 };
 use jito_vault_core::{
     config::Config,
@@ -28,6 +30,30 @@ pub fn process_burn_withdrawal_ticket(
     accounts: &[AccountInfo],
 ) -> ProgramResult {
     let (required_accounts, optional_accounts) = accounts.split_at(12);
+    burn_withdrawal_ticket(
+        program_id,
+        required_accounts,
+        optional_accounts.first(),
+        None,
+    )
+}
+
+/// The auto-claim delegate burning a ticket on the staker's behalf and the tip it takes
+pub struct AutoClaimTip<'a, 'info> {
+    pub delegate: &'a AccountInfo<'info>,
+    pub delegate_token_account: &'a AccountInfo<'info>,
+    pub tip_bps: u16,
+}
+
+/// Shared by [`process_burn_withdrawal_ticket`] and
+/// [`crate::auto_claim_withdrawal_ticket::process_auto_claim_withdrawal_ticket`]. When
+/// `auto_claim` is set, the tip is carved out of the assets sent to the staker.
+pub fn burn_withdrawal_ticket<'info>(
+    program_id: &Pubkey,
+    required_accounts: &[AccountInfo<'info>],
+    burn_signer: Option<&AccountInfo<'info>>,
+    auto_claim: Option<AutoClaimTip<'_, 'info>>,
+) -> ProgramResult {
     let [config, vault_info, vault_token_account, vrt_mint, staker, staker_token_account, vault_staker_withdrawal_ticket_info, vault_staker_withdrawal_ticket_token_account, vault_fee_token_account, program_fee_token_account, token_program, system_program] =
         required_accounts
     else {
@@ -71,12 +97,32 @@ pub fn process_burn_withdrawal_ticket(
 
     load_system_program(system_program)?;
 
-    vault.check_mint_burn_admin(optional_accounts.first())?;
+    if let Some(auto_claim) = &auto_claim {
+        load_signer(auto_claim.delegate, false)?;
+        load_associated_token_account(
+            auto_claim.delegate_token_account,
+            auto_claim.delegate.key,
+            &vault.supported_mint,
+        )?;
+    }
+
+    // In emergency exit, holders can leave without the mint burn admin co-signing
+    if !vault.is_emergency_exit() {
+        vault.check_mint_burn_admin(burn_signer)?;
+    }
     vault.check_vrt_mint(vrt_mint.key)?;
     vault.check_update_state_ok(Clock::get()?.slot, config.epoch_length())?;
     vault.check_is_paused()?;
+    // Pausing withdrawals can't trap holders in emergency exit either
+    if !vault.is_emergency_exit() {
+        vault.check_is_category_paused(PauseCategory::Withdrawals)?;
+    }
 
     vault_staker_withdrawal_ticket.check_staker(staker.key)?;
+    if let Some(auto_claim) = &auto_claim {
+        vault_staker_withdrawal_ticket
+            .check_auto_claim(auto_claim.delegate.key, auto_claim.tip_bps)?;
+    }
 
     if !vault_staker_withdrawal_ticket.is_withdrawable(Clock::get()?.slot, config.epoch_length())? {
         msg!("Vault staker withdrawal ticket is not withdrawable");
@@ -110,6 +156,16 @@ pub fn process_burn_withdrawal_ticket(
         .checked_add(extra_vrt_in_account)
         .ok_or(VaultError::ArithmeticOverflow)?;
 
+    let tip_amount = match &auto_claim {
+        Some(auto_claim) => {
+            VaultStakerWithdrawalTicket::calculate_auto_claim_tip(out_amount, auto_claim.tip_bps)?
+        }
+        None => 0,
+    };
+    let staker_amount = out_amount
+        .checked_sub(tip_amount)
+        .ok_or(VaultError::ArithmeticUnderflow)?;
+
     vault.decrement_vrt_ready_to_claim_amount(vault_staker_withdrawal_ticket.vrt_amount())?;
 
     let vault_staker_withdrawal_seeds = vault_staker_withdrawal_ticket.signing_seeds();
@@ -206,7 +262,7 @@ pub fn process_burn_withdrawal_ticket(
             staker_token_account.key,
             vault_info.key,
             &[],
-            out_amount,
+            staker_amount,
         )?,
         &[
             vault_token_account.clone(),
@@ -216,5 +272,27 @@ pub fn process_burn_withdrawal_ticket(
         &[&seed_slices],
     )?;
 
+    // pay the auto-claim delegate its tip
+    if let Some(auto_claim) = auto_claim {
+        if tip_amount > 0 {
+            invoke_signed(
+                &transfer(
+                    &spl_token::id(),
+                    vault_token_account.key,
+                    auto_claim.delegate_token_account.key,
+                    vault_info.key,
+                    &[],
+                    tip_amount,
+                )?,
+                &[
+                    vault_token_account.clone(),
+                    auto_claim.delegate_token_account.clone(),
+                    vault_info.clone(),
+                ],
+                &[&seed_slices],
+            )?;
+        }
+    }
+
     Ok(())
 }
@@ -1,5 +1,5 @@
 use jito_bytemuck::AccountDeserialize;
-use jito_jsm_core::loader::load_signer;
+use jito_jsm_core::{loader::load_signer, pause::PauseCategory};
 use jito_vault_core::{
     config::Config, vault::Vault, vault_staker_withdrawal_ticket::VaultStakerWithdrawalTicket,
 };
@@ -27,10 +27,15 @@ pub fn process_change_withdrawal_ticket_owner(
     )?;
     load_signer(old_owner, false)?;
 
+    // The pause checks below only hold for the vault the ticket was created in
+    vault_staker_withdrawal_ticket.check_vault(vault_info.key)?;
     vault.check_is_paused()?;
+    vault.check_is_category_paused(PauseCategory::Withdrawals)?;
 
     vault_staker_withdrawal_ticket.check_staker(old_owner.key)?;
     vault_staker_withdrawal_ticket.staker = *new_owner.key;
+    // The delegate was chosen by the previous owner
+    vault_staker_withdrawal_ticket.set_auto_claim_delegate(Pubkey::default(), 0)?;
 
     Ok(())
 }
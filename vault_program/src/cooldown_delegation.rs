@@ -1,5 +1,5 @@
 use jito_bytemuck::AccountDeserialize;
-use jito_jsm_core::loader::load_signer;
+use jito_jsm_core::{loader::load_signer, pause::PauseCategory};
 use jito_restaking_core::operator::Operator;
 use jito_vault_core::{
     config::Config, vault::Vault, vault_operator_delegation::VaultOperatorDelegation,
@@ -51,6 +51,7 @@ pub fn process_cooldown_delegation(
     vault.check_delegation_admin(vault_delegation_admin.key)?;
     vault.check_update_state_ok(Clock::get()?.slot, config.epoch_length())?;
     vault.check_is_paused()?;
+    vault.check_is_category_paused(PauseCategory::Delegation)?;
 
     vault_operator_delegation
         .delegation_state
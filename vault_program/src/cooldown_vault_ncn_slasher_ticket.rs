@@ -1,5 +1,5 @@
 use jito_bytemuck::AccountDeserialize;
-use jito_jsm_core::loader::load_signer;
+use jito_jsm_core::{loader::load_signer, pause::PauseCategory};
 use jito_restaking_core::ncn::Ncn;
 use jito_vault_core::{
     config::Config, vault::Vault, vault_ncn_slasher_ticket::VaultNcnSlasherTicket,
@@ -44,6 +44,7 @@ pub fn process_cooldown_vault_ncn_slasher_ticket(
     vault.check_slasher_admin(vault_slasher_admin.key)?;
     vault.check_update_state_ok(Clock::get()?.slot, config.epoch_length())?;
     vault.check_is_paused()?;
+    vault.check_is_category_paused(PauseCategory::TicketChanges)?;
 
     // The vault slasher ticket must be active in order to cooldown the slasher
     if !vault_ncn_slasher_ticket
@@ -1,5 +1,5 @@
 use jito_bytemuck::AccountDeserialize;
-use jito_jsm_core::loader::load_signer;
+use jito_jsm_core::{loader::load_signer, pause::PauseCategory};
 use jito_restaking_core::ncn::Ncn;
 use jito_vault_core::{config::Config, vault::Vault, vault_ncn_ticket::VaultNcnTicket};
 use jito_vault_sdk::error::VaultError;
@@ -39,6 +39,7 @@ pub fn process_cooldown_vault_ncn_ticket(
     vault.check_ncn_admin(vault_ncn_admin.key)?;
     vault.check_update_state_ok(Clock::get()?.slot, config.epoch_length())?;
     vault.check_is_paused()?;
+    vault.check_is_category_paused(PauseCategory::TicketChanges)?;
 
     // The VaultNcnTicket must be active in order to cooldown the NCN
     if !vault_ncn_ticket
@@ -0,0 +1,93 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::get_epoch;
+use jito_restaking_core::operator::Operator;
+use jito_vault_core::{
+    config::Config,
+    vault::Vault,
+    vault_operator_delegation::VaultOperatorDelegation,
+    vault_update_state_tracker::{crank_vault_operator_delegation, VaultUpdateStateTracker},
+};
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
+    program_error::ProgramError, pubkey::Pubkey, sysvar::Sysvar,
+};
+
+/// Processes the batched crank instruction: [`crate::VaultInstruction::CrankVaultUpdateStateTrackerMany`]
+///
+/// Specification:
+/// - The remaining accounts shall contain at least one `(operator, vault_operator_delegation)` pair.
+/// - The pairs shall be ordered exactly as they would be cranked one at a time with
+///   [`crate::VaultInstruction::CrankVaultUpdateStateTracker`], starting at the tracker's next index.
+/// - Every delegation is updated and accumulated into the tracker in a single pass.
+pub fn process_crank_vault_update_state_tracker_many(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let [config, vault_info, vault_update_state_tracker, delegation_accounts @ ..] = accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    if delegation_accounts.is_empty() || delegation_accounts.len() % 2 != 0 {
+        msg!(
+            "Expected (operator, vault_operator_delegation) pairs, received {} accounts",
+            delegation_accounts.len()
+        );
+        return Err(ProgramError::NotEnoughAccountKeys);
+    }
+
+    let slot = Clock::get()?.slot;
+
+    Config::load(program_id, config, false)?;
+    let config_data = config.data.borrow();
+    let config = Config::try_from_slice_unchecked(&config_data)?;
+    Vault::load(program_id, vault_info, true)?;
+    let mut vault_data = vault_info.data.borrow_mut();
+    let vault = Vault::try_from_slice_unchecked_mut(&mut vault_data)?;
+
+    let epoch_length = config.epoch_length();
+    let ncn_epoch = get_epoch(slot, epoch_length)?;
+
+    VaultUpdateStateTracker::load(
+        program_id,
+        vault_update_state_tracker,
+        vault_info,
+        ncn_epoch,
+        true,
+    )?;
+    let mut vault_update_state_tracker_data = vault_update_state_tracker.data.borrow_mut();
+    let vault_update_state_tracker = VaultUpdateStateTracker::try_from_slice_unchecked_mut(
+        &mut vault_update_state_tracker_data,
+    )?;
+
+    vault.check_is_paused()?;
+
+    for pair in delegation_accounts.chunks_exact(2) {
+        let [operator, vault_operator_delegation] = pair else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        Operator::load(&config.restaking_program, operator, false)?;
+        VaultOperatorDelegation::load(
+            program_id,
+            vault_operator_delegation,
+            vault_info,
+            operator,
+            true,
+        )?;
+        let mut vault_operator_delegation_data = vault_operator_delegation.data.borrow_mut();
+        let vault_operator_delegation = VaultOperatorDelegation::try_from_slice_unchecked_mut(
+            &mut vault_operator_delegation_data,
+        )?;
+
+        crank_vault_operator_delegation(
+            vault,
+            vault_operator_delegation,
+            vault_update_state_tracker,
+            slot,
+            epoch_length,
+        )?;
+    }
+
+    Ok(())
+}
@@ -1,6 +1,7 @@
 use jito_bytemuck::AccountDeserialize;
-use jito_jsm_core::loader::{
-    load_signer, load_system_account, load_system_program, load_token_mint,
+use jito_jsm_core::{
+    diagnostic::Check,
+    loader::{load_signer, load_system_account, load_system_program, load_token_mint},
 };
 use jito_vault_core::{loader::load_mpl_metadata_program, vault::Vault};
 use jito_vault_sdk::inline_mpl_token_metadata::{
@@ -40,6 +41,7 @@ pub fn process_create_token_metadata(
     let (metadata_account_pubkey, _) = find_metadata_account(vrt_mint.key);
     if metadata_account_pubkey != *metadata.key {
         msg!("Metadata account PDA does not match");
+        Check::Address.log_failure(metadata.key);
         return Err(ProgramError::InvalidAccountData);
     }
 
@@ -1,5 +1,8 @@
 use jito_bytemuck::AccountDeserialize;
-use jito_jsm_core::loader::{load_signer, load_token_account, load_token_mint, load_token_program};
+use jito_jsm_core::{
+    diagnostic::Check,
+    loader::{load_signer, load_token_account, load_token_mint, load_token_program},
+};
 use jito_vault_core::{config::Config, vault::Vault};
 use solana_program::{
     account_info::AccountInfo, entrypoint::ProgramResult, msg, program::invoke_signed,
@@ -43,6 +46,7 @@ pub fn process_delegate_token_account(
 
     // The owner of token mint and token account must match
     if token_mint.owner.ne(token_account.owner) {
+        Check::Owner.log_failure(token_account.key);
         return Err(ProgramError::InvalidAccountData);
     }
 
@@ -1,12 +1,14 @@
 use std::mem::size_of;
 
-use jito_bytemuck::{AccountDeserialize, Discriminator};
+use jito_bytemuck::AccountDeserialize;
 use jito_jsm_core::{
     create_account,
+    diagnostic::Check,
     loader::{
         load_associated_token_account, load_signer, load_system_account, load_system_program,
         load_token_program,
     },
+    pause::PauseCategory,
 };
 use jito_vault_core::{
     config::Config, vault::Vault, vault_staker_withdrawal_ticket::VaultStakerWithdrawalTicket,
@@ -22,7 +24,8 @@ use spl_token::instruction::transfer;
 /// staker's VRT token account to the VaultStakerWithdrawalTicket VRT token account.
 ///
 /// Specification:
-/// - If the vault has a mint burn admin, it shall be present and be a signer of the transaction
+/// - If the vault has a mint burn admin, it shall be present and be a signer of the transaction,
+///   unless the vault is in emergency exit mode
 /// - The vault shall be up to date
 /// - The amount to withdraw must be greater than zero
 /// - The VaultStakerWithdrawalTicket account shall be at the canonical PDA
@@ -60,9 +63,16 @@ pub fn process_enqueue_withdrawal(
     load_signer(base, false)?;
     load_system_program(system_program)?;
 
-    vault.check_mint_burn_admin(optional_accounts.first())?;
+    // In emergency exit, holders can leave without the mint burn admin co-signing
+    if !vault.is_emergency_exit() {
+        vault.check_mint_burn_admin(optional_accounts.first())?;
+    }
     vault.check_update_state_ok(Clock::get()?.slot, config.epoch_length())?;
     vault.check_is_paused()?;
+    // Pausing withdrawals can't trap holders in emergency exit either
+    if !vault.is_emergency_exit() {
+        vault.check_is_category_paused(PauseCategory::Withdrawals)?;
+    }
 
     if vrt_amount == 0 {
         msg!("VRT amount must be greater than zero");
@@ -81,6 +91,7 @@ pub fn process_enqueue_withdrawal(
         .ne(&vault_staker_withdrawal_ticket_pubkey)
     {
         msg!("Vault staker withdrawal ticket is not at the correct PDA");
+        Check::Address.log_failure(vault_staker_withdrawal_ticket.key);
         return Err(ProgramError::InvalidAccountData);
     }
 
@@ -101,7 +112,7 @@ pub fn process_enqueue_withdrawal(
         &vault_staker_withdrawal_ticket_seeds,
     )?;
     let mut vault_staker_withdrawal_ticket_data = vault_staker_withdrawal_ticket.data.borrow_mut();
-    vault_staker_withdrawal_ticket_data[0] = VaultStakerWithdrawalTicket::DISCRIMINATOR;
+    VaultStakerWithdrawalTicket::write_header(&mut vault_staker_withdrawal_ticket_data)?;
     let vault_staker_withdrawal_ticket = VaultStakerWithdrawalTicket::try_from_slice_unchecked_mut(
         &mut vault_staker_withdrawal_ticket_data,
     )?;
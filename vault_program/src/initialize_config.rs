@@ -1,8 +1,9 @@
 use std::mem::size_of;
 
-use jito_bytemuck::{AccountDeserialize, Discriminator};
+use jito_bytemuck::AccountDeserialize;
 use jito_jsm_core::{
     create_account,
+    diagnostic::Check,
     loader::{load_signer, load_system_account, load_system_program},
 };
 use jito_vault_core::{config::Config, MAX_BPS};
@@ -31,6 +32,7 @@ pub fn process_initialize_config(
     config_seeds.push(vec![config_bump]);
     if config_pubkey.ne(config.key) {
         msg!("Config account is not at the correct PDA");
+        Check::Address.log_failure(config.key);
         return Err(ProgramError::InvalidAccountData);
     }
 
@@ -53,7 +55,7 @@ pub fn process_initialize_config(
     )?;
 
     let mut config_data = config.try_borrow_mut_data()?;
-    config_data[0] = Config::DISCRIMINATOR;
+    Config::write_header(&mut config_data)?;
     let config = Config::try_from_slice_unchecked_mut(&mut config_data)?;
     *config = Config::new(
         *admin.key,
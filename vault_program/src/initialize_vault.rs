@@ -1,8 +1,9 @@
 use std::mem::size_of;
 
-use jito_bytemuck::{AccountDeserialize, Discriminator};
+use jito_bytemuck::AccountDeserialize;
 use jito_jsm_core::{
     create_account,
+    diagnostic::Check,
     loader::{
         load_associated_token_account_program, load_signer, load_system_account,
         load_system_program, load_token_account, load_token_mint, load_token_program,
@@ -83,6 +84,7 @@ pub fn process_initialize_vault(
     vault_seeds.push(vec![vault_bump]);
     if vault.key.ne(&vault_pubkey) {
         msg!("Vault account is not at the correct PDA");
+        Check::Address.log_failure(vault.key);
         return Err(ProgramError::InvalidAccountData);
     }
 
@@ -147,7 +149,7 @@ pub fn process_initialize_vault(
         )?;
 
         let mut vault_data = vault.try_borrow_mut_data()?;
-        vault_data[0] = Vault::DISCRIMINATOR;
+        Vault::write_header(&mut vault_data)?;
         let vault_account = Vault::try_from_slice_unchecked_mut(&mut vault_data)?;
 
         *vault_account = Vault::new(
@@ -1,8 +1,10 @@
 use std::mem::size_of;
 
-use jito_bytemuck::{AccountDeserialize, Discriminator};
+use jito_bytemuck::AccountDeserialize;
 use jito_jsm_core::{
-    create_account, get_epoch,
+    create_account,
+    diagnostic::Check,
+    get_epoch,
     loader::{load_signer, load_system_account, load_system_program},
 };
 use jito_restaking_core::{ncn::Ncn, operator::Operator};
@@ -69,6 +71,7 @@ pub fn process_initialize_vault_ncn_slasher_operator_ticket(
         .ne(&vault_ncn_slasher_operator_ticket_pubkey)
     {
         msg!("Vault NCN slasher operator ticket is not at the correct PDA");
+        Check::Address.log_failure(vault_ncn_slasher_operator_ticket.key);
         return Err(ProgramError::InvalidAccountData);
     }
 
@@ -94,7 +97,7 @@ pub fn process_initialize_vault_ncn_slasher_operator_ticket(
 
     let mut vault_ncn_slasher_operator_ticket_data =
         vault_ncn_slasher_operator_ticket.try_borrow_mut_data()?;
-    vault_ncn_slasher_operator_ticket_data[0] = VaultNcnSlasherOperatorTicket::DISCRIMINATOR;
+    VaultNcnSlasherOperatorTicket::write_header(&mut vault_ncn_slasher_operator_ticket_data)?;
     let vault_ncn_slasher_operator_ticket =
         VaultNcnSlasherOperatorTicket::try_from_slice_unchecked_mut(
             &mut vault_ncn_slasher_operator_ticket_data,
@@ -1,9 +1,11 @@
 use std::mem::size_of;
 
-use jito_bytemuck::{AccountDeserialize, Discriminator};
+use jito_bytemuck::AccountDeserialize;
 use jito_jsm_core::{
     create_account,
+    diagnostic::Check,
     loader::{load_signer, load_system_account, load_system_program},
+    pause::PauseCategory,
 };
 use jito_restaking_core::{ncn::Ncn, ncn_vault_slasher_ticket::NcnVaultSlasherTicket};
 use jito_vault_core::{
@@ -47,6 +49,7 @@ pub fn process_initialize_vault_ncn_slasher_ticket(
     load_system_program(system_program)?;
 
     vault.check_is_paused()?;
+    vault.check_is_category_paused(PauseCategory::TicketChanges)?;
 
     // The VaultNcnSlasherTicket shall be at the canonical PDA
     let (
@@ -62,6 +65,7 @@ pub fn process_initialize_vault_ncn_slasher_ticket(
     vault_ncn_slasher_ticket_seeds.push(vec![vault_ncn_slasher_ticket_bump]);
     if vault_ncn_slasher_ticket_pubkey.ne(vault_ncn_slasher_ticket.key) {
         msg!("Vault NCN slasher ticket is not at the correct PDA");
+        Check::Address.log_failure(vault_ncn_slasher_ticket.key);
         return Err(ProgramError::InvalidAccountData);
     }
 
@@ -91,7 +95,7 @@ pub fn process_initialize_vault_ncn_slasher_ticket(
         NcnVaultSlasherTicket::try_from_slice_unchecked(&ncn_vault_slasher_ticket_data)?;
 
     let mut vault_ncn_slasher_ticket_data = vault_ncn_slasher_ticket.try_borrow_mut_data()?;
-    vault_ncn_slasher_ticket_data[0] = VaultNcnSlasherTicket::DISCRIMINATOR;
+    VaultNcnSlasherTicket::write_header(&mut vault_ncn_slasher_ticket_data)?;
     let vault_ncn_slasher_ticket =
         VaultNcnSlasherTicket::try_from_slice_unchecked_mut(&mut vault_ncn_slasher_ticket_data)?;
     *vault_ncn_slasher_ticket = VaultNcnSlasherTicket::new(
@@ -1,9 +1,11 @@
 use std::mem::size_of;
 
-use jito_bytemuck::{AccountDeserialize, Discriminator};
+use jito_bytemuck::AccountDeserialize;
 use jito_jsm_core::{
     create_account,
+    diagnostic::Check,
     loader::{load_signer, load_system_account, load_system_program},
+    pause::PauseCategory,
 };
 use jito_restaking_core::{ncn::Ncn, ncn_vault_ticket::NcnVaultTicket};
 use jito_vault_core::{config::Config, vault::Vault, vault_ncn_ticket::VaultNcnTicket};
@@ -56,6 +58,7 @@ pub fn process_initialize_vault_ncn_ticket(
     vault_ncn_ticket_seeds.push(vec![vault_ncn_ticket_bump]);
     if vault_ncn_ticket_pubkey.ne(vault_ncn_ticket.key) {
         msg!("Vault NCN ticket is not at the correct PDA");
+        Check::Address.log_failure(vault_ncn_ticket.key);
         return Err(ProgramError::InvalidAccountData);
     }
 
@@ -64,6 +67,7 @@ pub fn process_initialize_vault_ncn_ticket(
     vault.check_ncn_admin(vault_ncn_admin.key)?;
     vault.check_update_state_ok(slot, config.epoch_length())?;
     vault.check_is_paused()?;
+    vault.check_is_category_paused(PauseCategory::TicketChanges)?;
 
     // The NcnVaultTicket shall be active
     msg!(
@@ -82,7 +86,7 @@ pub fn process_initialize_vault_ncn_ticket(
         &vault_ncn_ticket_seeds,
     )?;
     let mut vault_ncn_ticket_data = vault_ncn_ticket.try_borrow_mut_data()?;
-    vault_ncn_ticket_data[0] = VaultNcnTicket::DISCRIMINATOR;
+    VaultNcnTicket::write_header(&mut vault_ncn_ticket_data)?;
     let vault_ncn_ticket =
         VaultNcnTicket::try_from_slice_unchecked_mut(&mut vault_ncn_ticket_data)?;
     *vault_ncn_ticket = VaultNcnTicket::new(
@@ -1,9 +1,11 @@
 use std::mem::size_of;
 
-use jito_bytemuck::{AccountDeserialize, Discriminator};
+use jito_bytemuck::AccountDeserialize;
 use jito_jsm_core::{
     create_account,
+    diagnostic::Check,
     loader::{load_signer, load_system_account, load_system_program},
+    pause::PauseCategory,
 };
 use jito_restaking_core::{operator::Operator, operator_vault_ticket::OperatorVaultTicket};
 use jito_vault_core::{
@@ -54,6 +56,7 @@ pub fn process_initialize_vault_operator_delegation(
     vault_operator_delegation_seeds.push(vec![vault_operator_delegation_bump]);
     if vault_operator_delegation_pubkey.ne(vault_operator_delegation.key) {
         msg!("Vault operator delegation is not at the correct PDA");
+        Check::Address.log_failure(vault_operator_delegation.key);
         return Err(ProgramError::InvalidAccountData);
     }
 
@@ -62,6 +65,7 @@ pub fn process_initialize_vault_operator_delegation(
     vault.check_operator_admin(vault_operator_admin.key)?;
     vault.check_update_state_ok(slot, config.epoch_length())?;
     vault.check_is_paused()?;
+    vault.check_is_category_paused(PauseCategory::Delegation)?;
 
     msg!(
         "Initializing VaultOperatorDelegation at address {}",
@@ -80,7 +84,7 @@ pub fn process_initialize_vault_operator_delegation(
     )?;
 
     let mut vault_operator_delegation_data = vault_operator_delegation.try_borrow_mut_data()?;
-    vault_operator_delegation_data[0] = VaultOperatorDelegation::DISCRIMINATOR;
+    VaultOperatorDelegation::write_header(&mut vault_operator_delegation_data)?;
     let vault_operator_delegation =
         VaultOperatorDelegation::try_from_slice_unchecked_mut(&mut vault_operator_delegation_data)?;
     *vault_operator_delegation = VaultOperatorDelegation::new(
@@ -1,8 +1,10 @@
 use std::mem::size_of;
 
-use jito_bytemuck::{AccountDeserialize, Discriminator};
+use jito_bytemuck::AccountDeserialize;
 use jito_jsm_core::{
-    create_account, get_epoch,
+    create_account,
+    diagnostic::Check,
+    get_epoch,
     loader::{load_signer, load_system_account, load_system_program},
 };
 use jito_vault_core::{
@@ -47,6 +49,7 @@ pub fn process_initialize_vault_update_state_tracker(
     vault_update_state_tracker_seeds.push(vec![vault_update_state_tracker_bump]);
     if vault_update_state_tracker_pubkey.ne(vault_update_state_tracker.key) {
         msg!("Vault update delegations ticket is not at the correct PDA");
+        Check::Address.log_failure(vault_update_state_tracker.key);
         return Err(ProgramError::InvalidAccountData);
     }
 
@@ -114,7 +117,7 @@ pub fn process_initialize_vault_update_state_tracker(
     vault.set_last_start_state_update_slot(slot);
 
     let mut vault_update_state_tracker_data = vault_update_state_tracker.try_borrow_mut_data()?;
-    vault_update_state_tracker_data[0] = VaultUpdateStateTracker::DISCRIMINATOR;
+    VaultUpdateStateTracker::write_header(&mut vault_update_state_tracker_data)?;
     let vault_update_state_tracker = VaultUpdateStateTracker::try_from_slice_unchecked_mut(
         &mut vault_update_state_tracker_data,
     )?;
@@ -1,5 +1,7 @@
 use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult, pubkey::Pubkey};
 
+/// Nested vaults rely on every VRT mint being created with its vault to stay acyclic, see
+/// [`jito_vault_core::vault::Vault::set_child_vault`]. Implementing this must preserve that.
 pub const fn process_initialize_vault_with_mint(
     _program_id: &Pubkey,
     _accounts: &[AccountInfo],
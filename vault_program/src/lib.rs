@@ -1,4 +1,5 @@
 mod add_delegation;
+mod auto_claim_withdrawal_ticket;
 mod burn_withdrawal_ticket;
 mod change_withdrawal_ticket_owner;
 mod close_update_state_tracker;
@@ -6,6 +7,7 @@ mod cooldown_delegation;
 mod cooldown_vault_ncn_slasher_ticket;
 mod cooldown_vault_ncn_ticket;
 mod crank_vault_update_state_tracker;
+mod crank_vault_update_state_tracker_many;
 mod create_token_metadata;
 mod delegate_token_account;
 mod enqueue_withdrawal;
@@ -17,12 +19,17 @@ mod initialize_vault_ncn_ticket;
 mod initialize_vault_operator_delegation;
 mod initialize_vault_update_state_tracker;
 mod initialize_vault_with_mint;
+mod migrate_account;
 mod mint_to;
 mod set_admin;
+mod set_auto_claim_delegate;
 mod set_capacity;
+mod set_child_vault;
 mod set_config_admin;
 mod set_fees;
+mod set_is_emergency_exit;
 mod set_is_paused;
+mod set_paused_categories;
 mod set_program_fee;
 mod set_program_fee_wallet;
 mod set_secondary_admin;
@@ -42,13 +49,16 @@ use solana_program::{
 use solana_security_txt::security_txt;
 
 use crate::{
-    add_delegation::process_add_delegation, burn_withdrawal_ticket::process_burn_withdrawal_ticket,
+    add_delegation::process_add_delegation,
+    auto_claim_withdrawal_ticket::process_auto_claim_withdrawal_ticket,
+    burn_withdrawal_ticket::process_burn_withdrawal_ticket,
     change_withdrawal_ticket_owner::process_change_withdrawal_ticket_owner,
     close_update_state_tracker::process_close_vault_update_state_tracker,
     cooldown_delegation::process_cooldown_delegation,
     cooldown_vault_ncn_slasher_ticket::process_cooldown_vault_ncn_slasher_ticket,
     cooldown_vault_ncn_ticket::process_cooldown_vault_ncn_ticket,
     crank_vault_update_state_tracker::process_crank_vault_update_state_tracker,
+    crank_vault_update_state_tracker_many::process_crank_vault_update_state_tracker_many,
     create_token_metadata::process_create_token_metadata,
     delegate_token_account::process_delegate_token_account,
     enqueue_withdrawal::process_enqueue_withdrawal, initialize_config::process_initialize_config,
@@ -58,10 +68,14 @@ use crate::{
     initialize_vault_ncn_ticket::process_initialize_vault_ncn_ticket,
     initialize_vault_operator_delegation::process_initialize_vault_operator_delegation,
     initialize_vault_update_state_tracker::process_initialize_vault_update_state_tracker,
-    initialize_vault_with_mint::process_initialize_vault_with_mint, mint_to::process_mint,
-    set_admin::process_set_admin, set_capacity::process_set_deposit_capacity,
+    initialize_vault_with_mint::process_initialize_vault_with_mint,
+    migrate_account::process_migrate_account, mint_to::process_mint, set_admin::process_set_admin,
+    set_auto_claim_delegate::process_set_auto_claim_delegate,
+    set_capacity::process_set_deposit_capacity, set_child_vault::process_set_child_vault,
     set_config_admin::process_set_config_admin, set_fees::process_set_fees,
-    set_is_paused::process_set_is_paused, set_program_fee_wallet::process_set_program_fee_wallet,
+    set_is_emergency_exit::process_set_is_emergency_exit, set_is_paused::process_set_is_paused,
+    set_paused_categories::process_set_paused_categories,
+    set_program_fee_wallet::process_set_program_fee_wallet,
     set_secondary_admin::process_set_secondary_admin,
     update_token_metadata::process_update_token_metadata,
     update_vault_balance::process_update_vault_balance,
@@ -210,6 +224,14 @@ pub fn process_instruction(
             msg!("Instruction: BurnWithdrawalTicket");
             process_burn_withdrawal_ticket(program_id, accounts)
         }
+        VaultInstruction::SetAutoClaimDelegate { max_tip_bps } => {
+            msg!("Instruction: SetAutoClaimDelegate");
+            process_set_auto_claim_delegate(program_id, accounts, max_tip_bps)
+        }
+        VaultInstruction::AutoClaimWithdrawalTicket { tip_bps } => {
+            msg!("Instruction: AutoClaimWithdrawalTicket");
+            process_auto_claim_withdrawal_ticket(program_id, accounts, tip_bps)
+        }
         // ------------------------------------------
         // Vault-NCN operations
         // ------------------------------------------
@@ -261,6 +283,10 @@ pub fn process_instruction(
             msg!("Instruction: CrankVaultUpdateStateTracker");
             process_crank_vault_update_state_tracker(program_id, accounts)
         }
+        VaultInstruction::CrankVaultUpdateStateTrackerMany => {
+            msg!("Instruction: CrankVaultUpdateStateTrackerMany");
+            process_crank_vault_update_state_tracker_many(program_id, accounts)
+        }
         VaultInstruction::CloseVaultUpdateStateTracker { ncn_epoch } => {
             msg!("Instruction: CloseVaultUpdateStateTracker");
             process_close_vault_update_state_tracker(program_id, accounts, ncn_epoch)
@@ -280,5 +306,21 @@ pub fn process_instruction(
             msg!("Instruction: SetConfigAdmin");
             process_set_config_admin(program_id, accounts)
         }
+        VaultInstruction::SetChildVault => {
+            msg!("Instruction: SetChildVault");
+            process_set_child_vault(program_id, accounts)
+        }
+        VaultInstruction::SetIsEmergencyExit { is_emergency_exit } => {
+            msg!("Instruction: SetIsEmergencyExit");
+            process_set_is_emergency_exit(program_id, accounts, is_emergency_exit)
+        }
+        VaultInstruction::SetPausedCategories { paused_categories } => {
+            msg!("Instruction: SetPausedCategories");
+            process_set_paused_categories(program_id, accounts, paused_categories)
+        }
+        VaultInstruction::MigrateAccount => {
+            msg!("Instruction: MigrateAccount");
+            process_migrate_account(program_id, accounts)
+        }
     }
 }
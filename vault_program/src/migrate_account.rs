@@ -0,0 +1,68 @@
+use jito_bytemuck::Discriminator;
+use jito_jsm_core::{
+    diagnostic::Check,
+    loader::{load_signer, load_system_program},
+    migrate_account,
+};
+use jito_vault_core::{
+    config::Config, vault::Vault, vault_ncn_slasher_operator_ticket::VaultNcnSlasherOperatorTicket,
+    vault_ncn_slasher_ticket::VaultNcnSlasherTicket, vault_ncn_ticket::VaultNcnTicket,
+    vault_operator_delegation::VaultOperatorDelegation,
+    vault_staker_withdrawal_ticket::VaultStakerWithdrawalTicket,
+    vault_update_state_tracker::VaultUpdateStateTracker,
+};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey, rent::Rent, sysvar::Sysvar,
+};
+
+/// Converts a vault program account written under an older layout version to the current layout.
+/// Anyone can migrate an account; the payer covers the rent of any space the new layout adds.
+pub fn process_migrate_account(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let [account, payer, system_program] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    if account.owner.ne(program_id) {
+        msg!("Account is not owned by the vault program");
+        Check::Owner.log_failure(account.key);
+        return Err(ProgramError::InvalidAccountOwner);
+    }
+    if !account.is_writable {
+        msg!("Account is not writable");
+        Check::Writable.log_failure(account.key);
+        return Err(ProgramError::InvalidAccountData);
+    }
+    load_signer(payer, true)?;
+    load_system_program(system_program)?;
+
+    let discriminator = account
+        .try_borrow_data()?
+        .first()
+        .copied()
+        .ok_or(ProgramError::InvalidAccountData)?;
+    let rent = Rent::get()?;
+    match discriminator {
+        Config::DISCRIMINATOR => migrate_account::<Config>(account, payer, &rent),
+        Vault::DISCRIMINATOR => migrate_account::<Vault>(account, payer, &rent),
+        VaultNcnTicket::DISCRIMINATOR => migrate_account::<VaultNcnTicket>(account, payer, &rent),
+        VaultOperatorDelegation::DISCRIMINATOR => {
+            migrate_account::<VaultOperatorDelegation>(account, payer, &rent)
+        }
+        VaultNcnSlasherTicket::DISCRIMINATOR => {
+            migrate_account::<VaultNcnSlasherTicket>(account, payer, &rent)
+        }
+        VaultNcnSlasherOperatorTicket::DISCRIMINATOR => {
+            migrate_account::<VaultNcnSlasherOperatorTicket>(account, payer, &rent)
+        }
+        VaultStakerWithdrawalTicket::DISCRIMINATOR => {
+            migrate_account::<VaultStakerWithdrawalTicket>(account, payer, &rent)
+        }
+        VaultUpdateStateTracker::DISCRIMINATOR => {
+            migrate_account::<VaultUpdateStateTracker>(account, payer, &rent)
+        }
+        _ => {
+            msg!("Unknown account discriminator {}", discriminator);
+            Err(ProgramError::InvalidAccountData)
+        }
+    }
+}
@@ -1,6 +1,7 @@
 use jito_bytemuck::AccountDeserialize;
-use jito_jsm_core::loader::{
-    load_associated_token_account, load_signer, load_token_mint, load_token_program,
+use jito_jsm_core::{
+    loader::{load_associated_token_account, load_signer, load_token_mint, load_token_program},
+    pause::PauseCategory,
 };
 use jito_vault_core::{
     config::Config,
@@ -27,6 +28,7 @@ use spl_token::instruction::{mint_to, transfer};
 /// Specification:
 /// - If the vault has a mint burn admin, it must match be present and be a signer
 /// - The vault must be up-to-date
+/// - The vault must not be in emergency exit mode
 /// - The vault VRT mint must be correct
 /// - The amount to mint must be greater than zero
 /// - The post-mint tokens deposited shall be less than or equal to the vault capacity
@@ -73,6 +75,8 @@ pub fn process_mint(
     vault.check_vrt_mint(vrt_mint.key)?;
     vault.check_update_state_ok(Clock::get()?.slot, config.epoch_length())?;
     vault.check_is_paused()?;
+    vault.check_is_category_paused(PauseCategory::Deposits)?;
+    vault.check_is_emergency_exit()?;
 
     // Currently, this is not possible, since the there are currently no instructions that allow the
     // vault to deposit tokens into the vault token account. This check is for future proofing.
@@ -0,0 +1,46 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::{loader::load_signer, pause::PauseCategory};
+use jito_vault_core::{
+    config::Config, vault::Vault, vault_staker_withdrawal_ticket::VaultStakerWithdrawalTicket,
+};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// Registers the auto-claim delegate of a withdrawal ticket. Passing the system program as the
+/// delegate removes it.
+pub fn process_set_auto_claim_delegate(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    max_tip_bps: u16,
+) -> ProgramResult {
+    let [config, vault_info, vault_staker_withdrawal_ticket, staker, auto_claim_delegate] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    Config::load(program_id, config, false)?;
+    Vault::load(program_id, vault_info, false)?;
+    let vault_data = vault_info.data.borrow();
+    let vault = Vault::try_from_slice_unchecked(&vault_data)?;
+    VaultStakerWithdrawalTicket::load(program_id, vault_staker_withdrawal_ticket, true)?;
+    let mut vault_staker_withdrawal_ticket_data = vault_staker_withdrawal_ticket.data.borrow_mut();
+    let vault_staker_withdrawal_ticket = VaultStakerWithdrawalTicket::try_from_slice_unchecked_mut(
+        &mut vault_staker_withdrawal_ticket_data,
+    )?;
+    load_signer(staker, false)?;
+
+    // The pause checks below only hold for the vault the ticket was created in
+    vault_staker_withdrawal_ticket.check_vault(vault_info.key)?;
+    vault.check_is_paused()?;
+    vault.check_is_category_paused(PauseCategory::Withdrawals)?;
+
+    vault_staker_withdrawal_ticket.check_staker(staker.key)?;
+
+    // The system program id is the default pubkey, which marks the ticket as having no delegate
+    vault_staker_withdrawal_ticket
+        .set_auto_claim_delegate(*auto_claim_delegate.key, max_tip_bps)?;
+
+    Ok(())
+}
@@ -0,0 +1,50 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::loader::load_signer;
+use jito_vault_core::{config::Config, vault::Vault};
+use jito_vault_sdk::error::VaultError;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// Processes the set child vault instruction: [`crate::VaultInstruction::SetChildVault`]
+///
+/// Specification:
+/// - The admin must be the vault admin
+/// - The child vault's VRT mint must be the vault's supported mint
+/// - The vault must not already be nested, and the child can't be the vault or nested in it
+/// - The resulting nesting depth can't exceed [`Vault::MAX_NESTING_DEPTH`], which is re-checked
+///   against the child's current depth on every balance update
+pub fn process_set_child_vault(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let [config, vault_info, child_vault_info, admin] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    Config::load(program_id, config, false)?;
+    Vault::load(program_id, vault_info, true)?;
+    Vault::load(program_id, child_vault_info, false)?;
+    load_signer(admin, false)?;
+
+    // Checked before borrowing so the same account isn't borrowed twice
+    if vault_info.key.eq(child_vault_info.key) {
+        msg!("A vault can't be its own child vault");
+        return Err(VaultError::VaultNestingCycle.into());
+    }
+
+    let mut vault_data = vault_info.data.borrow_mut();
+    let vault = Vault::try_from_slice_unchecked_mut(&mut vault_data)?;
+    let child_vault_data = child_vault_info.data.borrow();
+    let child_vault = Vault::try_from_slice_unchecked(&child_vault_data)?;
+
+    vault.check_admin(admin.key)?;
+
+    vault.set_child_vault(vault_info.key, child_vault_info.key, child_vault)?;
+
+    msg!(
+        "Vault nested over child vault {} at depth {}",
+        child_vault_info.key,
+        vault.nesting_depth()
+    );
+
+    Ok(())
+}
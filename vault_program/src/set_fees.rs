@@ -1,5 +1,5 @@
 use jito_bytemuck::AccountDeserialize;
-use jito_jsm_core::loader::load_signer;
+use jito_jsm_core::{loader::load_signer, pause::PauseCategory};
 use jito_vault_core::{config::Config, vault::Vault};
 use solana_program::{
     account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
@@ -11,6 +11,7 @@ use solana_program::{
 /// Specification:
 /// - The fee can only be changed by the vault fee admin. The vault fee admin must sign the transaction.
 /// - The fees can only be changed at most once per epoch.
+/// - Fee changes must not be paused on the vault.
 /// - The fees can be changed the epoch after one full epoch has passed since the last fee change.
 /// - The Vault last_fee_change_slot shall be updated to the current slot only if any fees were updated.
 /// - The transaction shall fail if no fees are provided to update.
@@ -35,6 +36,7 @@ pub fn process_set_fees(
 
     vault.check_fee_admin(vault_fee_admin.key)?;
     vault.check_can_modify_fees(Clock::get()?.slot, config.epoch_length())?;
+    vault.check_is_category_paused(PauseCategory::FeeChanges)?;
 
     if deposit_fee_bps.is_none() && withdrawal_fee_bps.is_none() && reward_fee_bps.is_none() {
         msg!("No fees provided for update");
@@ -0,0 +1,51 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::{diagnostic::Check, loader::load_signer};
+use jito_vault_core::{config::Config, vault::Vault};
+use jito_vault_sdk::error::VaultError;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// Processes the set `is_emergency_exit` instruction: [`crate::VaultInstruction::SetIsEmergencyExit`]
+///
+/// Specification:
+/// - The admin shall be the vault admin or the config admin to enter emergency exit, and the
+///   config admin to leave it
+/// - Entering emergency exit unpauses the vault
+/// - While in emergency exit, operator delegations are force-cooled at the next crank, minting and
+///   delegating are disabled, and withdrawals don't require the mint burn admin
+pub fn process_set_is_emergency_exit(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    is_emergency_exit: bool,
+) -> ProgramResult {
+    let [config, vault, admin] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    Config::load(program_id, config, false)?;
+    let config_data = config.data.borrow();
+    let config = Config::try_from_slice_unchecked(&config_data)?;
+    Vault::load(program_id, vault, true)?;
+    let mut vault_data = vault.data.borrow_mut();
+    let vault = Vault::try_from_slice_unchecked_mut(&mut vault_data)?;
+    load_signer(admin, false)?;
+
+    if vault.admin.ne(admin.key) && config.admin.ne(admin.key) {
+        msg!("Admin is not the vault admin or the config admin");
+        Check::Admin.log_failure(admin.key);
+        return Err(VaultError::VaultAdminInvalid.into());
+    }
+
+    if !is_emergency_exit && config.admin.ne(admin.key) {
+        msg!("Only the config admin can leave emergency exit");
+        Check::Admin.log_failure(admin.key);
+        return Err(VaultError::ConfigAdminInvalid.into());
+    }
+
+    vault.set_is_emergency_exit(is_emergency_exit);
+
+    msg!("Vault emergency exit set to {}", is_emergency_exit);
+
+    Ok(())
+}
@@ -1,27 +1,38 @@
 use jito_bytemuck::AccountDeserialize;
-use jito_jsm_core::loader::load_signer;
+use jito_jsm_core::{diagnostic::Check, loader::load_signer};
 use jito_vault_core::{config::Config, vault::Vault};
+use jito_vault_sdk::error::VaultError;
 use solana_program::{
-    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
     pubkey::Pubkey,
 };
 
 /// Processes the set `is_paused` instruction: [`crate::VaultInstruction::SetIsPaused`]
+///
+/// Specification:
+/// - The admin shall be the vault admin
+/// - A vault in emergency exit can't be paused, since pausing would block the crank that
+///   force-cools its delegations
 pub fn process_set_is_paused(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
     is_paused: bool,
 ) -> ProgramResult {
-    let [config, vault, admin] = accounts else {
+    let [config, vault_info, admin] = accounts else {
         return Err(ProgramError::NotEnoughAccountKeys);
     };
     Config::load(program_id, config, false)?;
-    Vault::load(program_id, vault, true)?;
-    let mut vault_data = vault.data.borrow_mut();
+    Vault::load(program_id, vault_info, true)?;
+    let mut vault_data = vault_info.data.borrow_mut();
     let vault = Vault::try_from_slice_unchecked_mut(&mut vault_data)?;
     load_signer(admin, false)?;
 
     vault.check_admin(admin.key)?;
+    if is_paused && vault.is_emergency_exit() {
+        msg!("A vault in emergency exit can't be paused");
+        Check::EmergencyExit.log_failure(vault_info.key);
+        return Err(VaultError::VaultInEmergencyExit.into());
+    }
 
     vault.set_is_paused(is_paused);
 
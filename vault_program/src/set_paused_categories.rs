@@ -0,0 +1,45 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::{diagnostic::Check, loader::load_signer, pause::PauseCategory};
+use jito_vault_core::{config::Config, vault::Vault};
+use jito_vault_sdk::error::VaultError;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// Processes the set paused categories instruction: [`crate::VaultInstruction::SetPausedCategories`]
+///
+/// Specification:
+/// - The admin shall be the vault admin
+/// - The bitmask shall only contain known [`PauseCategory`] bits
+/// - Withdrawals can't be paused while the vault is in emergency exit
+pub fn process_set_paused_categories(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    paused_categories: u16,
+) -> ProgramResult {
+    let [config, vault_info, admin] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    Config::load(program_id, config, false)?;
+    Vault::load(program_id, vault_info, true)?;
+    let mut vault_data = vault_info.data.borrow_mut();
+    let vault = Vault::try_from_slice_unchecked_mut(&mut vault_data)?;
+    load_signer(admin, false)?;
+
+    vault.check_admin(admin.key)?;
+
+    if !PauseCategory::is_valid_mask(paused_categories) {
+        msg!("Invalid paused categories: {:#b}", paused_categories);
+        return Err(ProgramError::InvalidInstructionData);
+    }
+    if vault.is_emergency_exit() && PauseCategory::Withdrawals.is_paused(paused_categories) {
+        msg!("Withdrawals can't be paused in emergency exit");
+        Check::EmergencyExit.log_failure(vault_info.key);
+        return Err(VaultError::VaultInEmergencyExit.into());
+    }
+
+    vault.set_paused_categories(paused_categories);
+
+    Ok(())
+}
@@ -1,5 +1,5 @@
 use jito_bytemuck::AccountDeserialize;
-use jito_jsm_core::loader::load_signer;
+use jito_jsm_core::{diagnostic::Check, loader::load_signer};
 use jito_vault_core::config::Config;
 use jito_vault_sdk::error::VaultError;
 use solana_program::{
@@ -28,6 +28,7 @@ pub fn process_set_program_fee(
 
     if config_admin.key != &config.admin {
         msg!("Config admin does not match");
+        Check::Admin.log_failure(config_admin.key);
         return Err(VaultError::ConfigAdminInvalid.into());
     }
 
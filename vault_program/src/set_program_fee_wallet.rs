@@ -1,5 +1,5 @@
 use jito_bytemuck::AccountDeserialize;
-use jito_jsm_core::loader::load_signer;
+use jito_jsm_core::{diagnostic::Check, loader::load_signer};
 use jito_vault_core::config::Config;
 use jito_vault_sdk::error::VaultError;
 use solana_program::{
@@ -26,6 +26,7 @@ pub fn process_set_program_fee_wallet(
 
     if config_fee_admin.key != &config.fee_admin {
         msg!("Config fee admin does not match");
+        Check::FeeAdmin.log_failure(config_fee_admin.key);
         return Err(VaultError::ConfigFeeAdminInvalid.into());
     }
 
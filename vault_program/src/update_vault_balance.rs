@@ -1,5 +1,8 @@
 use jito_bytemuck::AccountDeserialize;
-use jito_jsm_core::loader::{load_associated_token_account, load_token_mint, load_token_program};
+use jito_jsm_core::{
+    diagnostic::Check,
+    loader::{load_associated_token_account, load_token_mint, load_token_program},
+};
 use jito_vault_core::{config::Config, vault::Vault};
 use jito_vault_sdk::error::VaultError;
 use solana_program::{
@@ -9,11 +12,20 @@ use solana_program::{
 };
 use spl_token::{instruction::mint_to, state::Account};
 
+/// Processes the update vault balance instruction: [`crate::VaultInstruction::UpdateVaultBalance`]
+///
+/// Specification:
+/// - The vault must be up-to-date and not paused
+/// - The reward fee is taken on any increase in the vault token account balance
+/// - Nested vaults must pass their child vault, which must be up-to-date, and revalue their
+///   holdings at the child vault's exchange rate so losses in the child propagate upward
+/// - The nesting depth is recomputed from the child vault's and can't exceed
+///   [`Vault::MAX_NESTING_DEPTH`]
 pub fn process_update_vault_balance(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
 ) -> ProgramResult {
-    let [config, vault_info, vault_token_account, vrt_mint, vault_fee_token_account, token_program] =
+    let [config, vault_info, vault_token_account, vrt_mint, vault_fee_token_account, token_program, optional_accounts @ ..] =
         accounts
     else {
         return Err(ProgramError::NotEnoughAccountKeys);
@@ -67,6 +79,34 @@ pub fn process_update_vault_balance(
         Vault::MAX_REWARD_DELTA_BPS,
     )?;
 
+    // 6. Revalue the holdings of nested vaults at the child vault's exchange rate
+    if vault.is_nested() {
+        let Some(child_vault_info) = optional_accounts.first() else {
+            msg!("Nested vault requires the child vault account");
+            Check::ChildVault.log_missing();
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+        if child_vault_info.key.ne(&vault.child_vault) {
+            msg!("Child vault does not match the vault's child vault");
+            Check::ChildVault.log_failure(child_vault_info.key);
+            return Err(VaultError::VaultChildVaultInvalid.into());
+        }
+        Vault::load(program_id, child_vault_info, false)?;
+        let child_vault_data = child_vault_info.data.borrow();
+        let child_vault = Vault::try_from_slice_unchecked(&child_vault_data)?;
+
+        // The child must be updated first so its losses are reflected in its exchange rate
+        child_vault.check_update_state_ok(Clock::get()?.slot, config.epoch_length())?;
+
+        let previous_value =
+            vault.update_nested_underlying_value(child_vault_info.key, child_vault)?;
+        msg!(
+            "Nested vault underlying value updated from {} to {}",
+            previous_value,
+            vault.nested_underlying_value()
+        );
+    }
+
     // Mint rewards
     if vrt_reward_fee > 0 {
         let vault_seeds = vault.signing_seeds();
@@ -1,5 +1,5 @@
 use jito_bytemuck::AccountDeserialize;
-use jito_jsm_core::loader::load_signer;
+use jito_jsm_core::{loader::load_signer, pause::PauseCategory};
 use jito_restaking_core::ncn::Ncn;
 use jito_vault_core::{
     config::Config, vault::Vault, vault_ncn_slasher_ticket::VaultNcnSlasherTicket,
@@ -43,6 +43,7 @@ pub fn process_warmup_vault_ncn_slasher_ticket(
     vault.check_slasher_admin(vault_slasher_admin.key)?;
     vault.check_update_state_ok(Clock::get()?.slot, config.epoch_length())?;
     vault.check_is_paused()?;
+    vault.check_is_category_paused(PauseCategory::TicketChanges)?;
 
     // The VaultNcnSlasherTicket shall be ready to be activated
     if !vault_ncn_slasher_ticket
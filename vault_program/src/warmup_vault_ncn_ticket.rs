@@ -1,5 +1,5 @@
 use jito_bytemuck::AccountDeserialize;
-use jito_jsm_core::loader::load_signer;
+use jito_jsm_core::{diagnostic::Check, loader::load_signer, pause::PauseCategory};
 use jito_restaking_core::ncn::Ncn;
 use jito_vault_core::{config::Config, vault::Vault, vault_ncn_ticket::VaultNcnTicket};
 use jito_vault_sdk::error::VaultError;
@@ -29,12 +29,14 @@ pub fn process_warmup_vault_ncn_ticket(
     let vault = Vault::try_from_slice_unchecked(&vault_data)?;
     if vault.ncn_admin.ne(vault_ncn_admin.key) {
         msg!("Invalid ncn admin for vault");
+        Check::NcnAdmin.log_failure(vault_ncn_admin.key);
         return Err(VaultError::VaultNcnAdminInvalid.into());
     }
 
     // The vault shall be up-to-date before warming up the NCN
     vault.check_update_state_ok(Clock::get()?.slot, config.epoch_length())?;
     vault.check_is_paused()?;
+    vault.check_is_category_paused(PauseCategory::TicketChanges)?;
 
     // The VaultNcnTicket shall be ready to be activated
     let mut vault_ncn_ticket_data = vault_ncn_ticket.data.borrow_mut();
@@ -0,0 +1,49 @@
+//! Compute unit estimates for instructions whose cost grows with the number of accounts passed in.
+//! Crankers use these to size batches and to request a compute unit limit for the transaction.
+
+/// Compute units consumed by [`crate::instruction::VaultInstruction::CrankVaultUpdateStateTrackerMany`]
+/// before any operator is cranked: loading the config, vault and tracker
+pub const CRANK_VAULT_UPDATE_STATE_TRACKER_MANY_BASE_COMPUTE_UNITS: u32 = 30_000;
+
+/// Compute units consumed by [`crate::instruction::VaultInstruction::CrankVaultUpdateStateTrackerMany`]
+/// for every `(operator, vault_operator_delegation)` pair, including the PDA derivation and the
+/// force cooldown logging
+pub const CRANK_VAULT_UPDATE_STATE_TRACKER_MANY_COMPUTE_UNITS_PER_OPERATOR: u32 = 20_000;
+
+/// Returns the compute units needed to crank `num_operators` delegations in one instruction
+pub const fn crank_vault_update_state_tracker_many_compute_units(num_operators: u32) -> u32 {
+    CRANK_VAULT_UPDATE_STATE_TRACKER_MANY_BASE_COMPUTE_UNITS.saturating_add(
+        CRANK_VAULT_UPDATE_STATE_TRACKER_MANY_COMPUTE_UNITS_PER_OPERATOR
+            .saturating_mul(num_operators),
+    )
+}
+
+/// Returns the largest number of delegations that can be cranked in one instruction without
+/// exceeding `compute_unit_limit`
+pub const fn max_crank_vault_update_state_tracker_many_operators(compute_unit_limit: u32) -> u32 {
+    compute_unit_limit.saturating_sub(CRANK_VAULT_UPDATE_STATE_TRACKER_MANY_BASE_COMPUTE_UNITS)
+        / CRANK_VAULT_UPDATE_STATE_TRACKER_MANY_COMPUTE_UNITS_PER_OPERATOR
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_max_operators_fits_in_limit() {
+        for compute_unit_limit in [0, 50_000, 200_000, 1_400_000] {
+            let max_operators =
+                max_crank_vault_update_state_tracker_many_operators(compute_unit_limit);
+            if max_operators > 0 {
+                assert!(
+                    crank_vault_update_state_tracker_many_compute_units(max_operators)
+                        <= compute_unit_limit
+                );
+            }
+            assert!(
+                crank_vault_update_state_tracker_many_compute_units(max_operators + 1)
+                    > compute_unit_limit
+            );
+        }
+    }
+}
@@ -123,6 +123,26 @@ pub enum VaultError {
     VrtOutCannotBeZero,
     #[error("NonZeroAdditionalAssetsNeededForWithdrawalAtEndOfUpdate")]
     NonZeroAdditionalAssetsNeededForWithdrawalAtEndOfUpdate,
+    #[error("OperatorJailed")]
+    OperatorJailed,
+    #[error("VaultAlreadyNested")]
+    VaultAlreadyNested,
+    #[error("VaultNestingCycle")]
+    VaultNestingCycle,
+    #[error("VaultNestingTooDeep")]
+    VaultNestingTooDeep,
+    #[error("VaultChildVaultInvalid")]
+    VaultChildVaultInvalid,
+    #[error("VaultInEmergencyExit")]
+    VaultInEmergencyExit,
+    #[error("VaultCategoryPaused")]
+    VaultCategoryPaused,
+    #[error("VaultStakerWithdrawalTicketInvalidAutoClaimDelegate")]
+    VaultStakerWithdrawalTicketInvalidAutoClaimDelegate,
+    #[error("VaultStakerWithdrawalTicketAutoClaimTipTooHigh")]
+    VaultStakerWithdrawalTicketAutoClaimTipTooHigh,
+    #[error("VaultStakerWithdrawalTicketInvalidVault")]
+    VaultStakerWithdrawalTicketInvalidVault,
     #[error("ArithmeticOverflow")]
     ArithmeticOverflow = 3000,
     #[error("ArithmeticUnderflow")]
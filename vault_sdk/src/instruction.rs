@@ -262,6 +262,7 @@ pub enum VaultInstruction {
     #[account(3, writable, name = "vrt_mint")]
     #[account(4, writable, name = "vault_fee_token_account")]
     #[account(5, name = "token_program")]
+    #[account(6, optional, name = "child_vault", description = "Required for nested vaults")]
     UpdateVaultBalance,
 
     /// Starts updating the vault
@@ -320,6 +321,78 @@ pub enum VaultInstruction {
     #[account(2, name = "new_admin")]
     SetConfigAdmin,
 
+    /// Links a child vault whose VRT is the vault's supported mint, making the vault a nested vault
+    #[account(0, name = "config")]
+    #[account(1, writable, name = "vault")]
+    #[account(2, name = "child_vault")]
+    #[account(3, signer, name = "admin")]
+    SetChildVault,
+
+    /// Sets `is_emergency_exit`. The vault admin or the config admin can enter emergency exit, and
+    /// only the config admin can leave it.
+    #[account(0, name = "config")]
+    #[account(1, writable, name = "vault")]
+    #[account(2, signer, name = "admin")]
+    SetIsEmergencyExit {
+        is_emergency_exit: bool,
+    },
+
+    /// Sets the bitmask of paused instruction categories
+    #[account(0, name = "config")]
+    #[account(1, writable, name = "vault")]
+    #[account(2, signer, name = "admin")]
+    SetPausedCategories {
+        paused_categories: u16,
+    },
+
+    /// Cranks a batch of vault_operator_delegations in a single instruction. The remaining accounts
+    /// are `(operator, vault_operator_delegation)` pairs in the order they would be cranked one at
+    /// a time with CrankVaultUpdateStateTracker
+    #[account(0, name = "config")]
+    #[account(1, writable, name = "vault")]
+    #[account(2, writable, name = "vault_update_state_tracker")]
+    CrankVaultUpdateStateTrackerMany,
+
+    /// Registers an auto-claim delegate that may burn the withdrawal ticket on the staker's behalf
+    /// once it's withdrawable, taking at most `max_tip_bps` of the withdrawn assets. Passing the
+    /// system program as the delegate removes it.
+    #[account(0, name = "config")]
+    #[account(1, name = "vault")]
+    #[account(2, writable, name = "vault_staker_withdrawal_ticket")]
+    #[account(3, signer, name = "staker")]
+    #[account(4, name = "auto_claim_delegate")]
+    SetAutoClaimDelegate {
+        max_tip_bps: u16,
+    },
+
+    /// Burns the withdrawal ticket on behalf of the staker. Signed by the ticket's auto-claim
+    /// delegate, which receives `tip_bps` of the withdrawn assets; the rest goes to the staker.
+    #[account(0, name = "config")]
+    #[account(1, writable, name = "vault")]
+    #[account(2, writable, name = "vault_token_account")]
+    #[account(3, writable, name = "vrt_mint")]
+    #[account(4, writable, name = "staker")]
+    #[account(5, writable, name = "staker_token_account")]
+    #[account(6, writable, name = "vault_staker_withdrawal_ticket")]
+    #[account(7, writable, name = "vault_staker_withdrawal_ticket_token_account")]
+    #[account(8, writable, name = "vault_fee_token_account")]
+    #[account(9, writable, name = "program_fee_token_account")]
+    #[account(10, name = "token_program")]
+    #[account(11, name = "system_program")]
+    #[account(12, signer, name = "auto_claim_delegate")]
+    #[account(13, writable, name = "auto_claim_delegate_token_account")]
+    #[account(14, signer, optional, name = "burn_signer", description = "Signer for burning")]
+    AutoClaimWithdrawalTicket {
+        tip_bps: u16,
+    },
+
+    /// Converts an account written under an older layout version to the current layout.
+    /// Permissionless; the payer funds any rent needed for the account to grow.
+    #[account(0, writable, name = "account")]
+    #[account(1, writable, signer, name = "payer")]
+    #[account(2, name = "system_program")]
+    MigrateAccount,
+
 }
 
 #[derive(Debug, BorshSerialize, BorshDeserialize)]
@@ -420,6 +420,32 @@ pub fn crank_vault_update_state_tracker(
     }
 }
 
+/// `delegations` are `(operator, vault_operator_delegation)` pairs in cranking order
+pub fn crank_vault_update_state_tracker_many(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    vault: &Pubkey,
+    vault_update_state_tracker: &Pubkey,
+    delegations: &[(Pubkey, Pubkey)],
+) -> Instruction {
+    let mut accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new(*vault, false),
+        AccountMeta::new(*vault_update_state_tracker, false),
+    ];
+    for (operator, vault_operator_delegation) in delegations {
+        accounts.push(AccountMeta::new_readonly(*operator, false));
+        accounts.push(AccountMeta::new(*vault_operator_delegation, false));
+    }
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: VaultInstruction::CrankVaultUpdateStateTrackerMany
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
 #[allow(clippy::too_many_arguments)]
 pub fn initialize_vault_ncn_slasher_ticket(
     program_id: &Pubkey,
@@ -634,6 +660,74 @@ pub fn burn_withdrawal_ticket(
     }
 }
 
+pub fn set_auto_claim_delegate(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    vault: &Pubkey,
+    vault_staker_withdrawal_ticket: &Pubkey,
+    staker: &Pubkey,
+    auto_claim_delegate: &Pubkey,
+    max_tip_bps: u16,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new_readonly(*vault, false),
+        AccountMeta::new(*vault_staker_withdrawal_ticket, false),
+        AccountMeta::new_readonly(*staker, true),
+        AccountMeta::new_readonly(*auto_claim_delegate, false),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: VaultInstruction::SetAutoClaimDelegate { max_tip_bps }
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn auto_claim_withdrawal_ticket(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    vault: &Pubkey,
+    vault_token_account: &Pubkey,
+    vrt_mint: &Pubkey,
+    staker: &Pubkey,
+    staker_token_account: &Pubkey,
+    vault_staker_withdrawal_ticket: &Pubkey,
+    vault_staker_withdrawal_ticket_token_account: &Pubkey,
+    vault_fee_token_account: &Pubkey,
+    program_fee_vrt_token_account: &Pubkey,
+    auto_claim_delegate: &Pubkey,
+    auto_claim_delegate_token_account: &Pubkey,
+    tip_bps: u16,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new(*vault, false),
+        AccountMeta::new(*vault_token_account, false),
+        AccountMeta::new(*vrt_mint, false),
+        AccountMeta::new(*staker, false),
+        AccountMeta::new(*staker_token_account, false),
+        AccountMeta::new(*vault_staker_withdrawal_ticket, false),
+        AccountMeta::new(*vault_staker_withdrawal_ticket_token_account, false),
+        AccountMeta::new(*vault_fee_token_account, false),
+        AccountMeta::new(*program_fee_vrt_token_account, false),
+        AccountMeta::new_readonly(spl_token::id(), false),
+        AccountMeta::new_readonly(system_program::id(), false),
+        AccountMeta::new_readonly(*auto_claim_delegate, true),
+        AccountMeta::new(*auto_claim_delegate_token_account, false),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: VaultInstruction::AutoClaimWithdrawalTicket { tip_bps }
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
 pub fn update_vault_balance(
     program_id: &Pubkey,
     config: &Pubkey,
@@ -642,8 +736,9 @@ pub fn update_vault_balance(
     vrt_mint: &Pubkey,
     vault_fee_token_account: &Pubkey,
     token_program: &Pubkey,
+    child_vault: Option<&Pubkey>,
 ) -> Instruction {
-    let accounts = vec![
+    let mut accounts = vec![
         AccountMeta::new_readonly(*config, false),
         AccountMeta::new(*vault, false),
         AccountMeta::new_readonly(*vault_token_account, false),
@@ -651,6 +746,9 @@ pub fn update_vault_balance(
         AccountMeta::new(*vault_fee_token_account, false),
         AccountMeta::new_readonly(*token_program, false),
     ];
+    if let Some(child_vault) = child_vault {
+        accounts.push(AccountMeta::new_readonly(*child_vault, false));
+    }
     Instruction {
         program_id: *program_id,
         accounts,
@@ -794,6 +892,68 @@ pub fn set_is_paused(
     }
 }
 
+pub fn set_is_emergency_exit(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    vault: &Pubkey,
+    admin: &Pubkey,
+    is_emergency_exit: bool,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new(*vault, false),
+        AccountMeta::new_readonly(*admin, true),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: VaultInstruction::SetIsEmergencyExit { is_emergency_exit }
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+pub fn set_paused_categories(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    vault: &Pubkey,
+    admin: &Pubkey,
+    paused_categories: u16,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new(*vault, false),
+        AccountMeta::new_readonly(*admin, true),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: VaultInstruction::SetPausedCategories { paused_categories }
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+pub fn set_child_vault(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    vault: &Pubkey,
+    child_vault: &Pubkey,
+    admin: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new(*vault, false),
+        AccountMeta::new_readonly(*child_vault, false),
+        AccountMeta::new_readonly(*admin, true),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: VaultInstruction::SetChildVault.try_to_vec().unwrap(),
+    }
+}
+
 pub fn set_config_admin(
     program_id: &Pubkey,
     config: &Pubkey,
@@ -811,3 +971,16 @@ pub fn set_config_admin(
         data: VaultInstruction::SetConfigAdmin.try_to_vec().unwrap(),
     }
 }
+
+pub fn migrate_account(program_id: &Pubkey, account: &Pubkey, payer: &Pubkey) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(*account, false),
+        AccountMeta::new(*payer, true),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: VaultInstruction::MigrateAccount.try_to_vec().unwrap(),
+    }
+}
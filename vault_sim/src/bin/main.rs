@@ -0,0 +1,81 @@
+use std::path::PathBuf;
+
+use anyhow::Context;
+use clap::{Parser, ValueEnum};
+use jito_vault_sim::{report::EpochReport, scenario::Scenario};
+use solana_program::program_stubs::{set_syscall_stubs, SyscallStubs};
+
+#[derive(Parser)]
+#[command(about = "Simulates a Jito vault across NCN epochs")]
+struct Args {
+    /// Path to a JSON or YAML scenario
+    scenario: PathBuf,
+
+    /// Output format for the epoch reports
+    #[arg(long, value_enum, default_value_t = OutputFormat::Table)]
+    output: OutputFormat,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum OutputFormat {
+    Table,
+    Json,
+}
+
+/// Drops the `msg!` logs `vault_core` emits on rejected actions, which show up in the report
+struct QuietStubs;
+
+impl SyscallStubs for QuietStubs {
+    fn sol_log(&self, _message: &str) {}
+}
+
+fn main() -> anyhow::Result<()> {
+    let args = Args::parse();
+    set_syscall_stubs(Box::new(QuietStubs));
+
+    let scenario = Scenario::from_file(&args.scenario)
+        .with_context(|| format!("Failed to load scenario {}", args.scenario.display()))?;
+    let reports = jito_vault_sim::run(&scenario)?;
+
+    match args.output {
+        OutputFormat::Json => println!("{}", serde_json::to_string_pretty(&reports)?),
+        OutputFormat::Table => print_table(&reports),
+    }
+    Ok(())
+}
+
+fn print_table(reports: &[EpochReport]) {
+    println!(
+        "{:>5} {:>20} {:>20} {:>10} {:>20} {:>20} {:>20} {:>20} {:>20} {:>20} {:>15}",
+        "epoch",
+        "tokens_deposited",
+        "vrt_supply",
+        "rate",
+        "staked",
+        "cooling_down",
+        "forced_cooldown",
+        "vrt_ready_to_claim",
+        "claimed",
+        "fees_vrt",
+        "tickets",
+    );
+    for report in reports {
+        println!(
+            "{:>5} {:>20} {:>20} {:>10.6} {:>20} {:>20} {:>20} {:>20} {:>20} {:>20} {:>15}",
+            report.epoch,
+            report.tokens_deposited,
+            report.vrt_supply,
+            report.exchange_rate,
+            report.staked_amount,
+            report.cooling_down_amount,
+            report.forced_cooldown_amount,
+            report.vrt_ready_to_claim_amount,
+            report.claimed_amount,
+            report.fees.total(),
+            report.open_tickets,
+        );
+        for rejected in report.rejected.iter() {
+            println!("      rejected {}: {}", rejected.action, rejected.error);
+        }
+    }
+}
@@ -0,0 +1,18 @@
+//! Off-chain simulator for Jito vaults.
+//!
+//! Runs a [`scenario::Scenario`] of deposits, withdrawals, delegations, rewards and fee changes
+//! through the same `vault_core` math the vault program uses, updating the vault at the start of
+//! every epoch, and reports the vault's state at the end of each epoch.
+
+pub mod report;
+pub mod scenario;
+pub mod simulator;
+
+use report::EpochReport;
+use scenario::Scenario;
+use simulator::{SimError, Simulator};
+
+/// Runs the scenario, returning a report for every epoch
+pub fn run(scenario: &Scenario) -> Result<Vec<EpochReport>, SimError> {
+    Simulator::run(scenario)
+}
@@ -0,0 +1,72 @@
+use serde::Serialize;
+
+/// The vault's state at the end of an epoch, after the epoch's actions
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct EpochReport {
+    pub epoch: u64,
+
+    pub tokens_deposited: u64,
+    pub vrt_supply: u64,
+
+    /// Supported tokens per VRT
+    pub exchange_rate: f64,
+
+    /// The vault's delegation state across all operators
+    pub staked_amount: u64,
+    pub enqueued_for_cooldown_amount: u64,
+    pub cooling_down_amount: u64,
+
+    /// VRT in the withdrawal queue, by stage
+    pub vrt_enqueued_for_cooldown_amount: u64,
+    pub vrt_cooling_down_amount: u64,
+    pub vrt_ready_to_claim_amount: u64,
+
+    /// Withdrawal tickets that haven't been claimed yet
+    pub open_tickets: u64,
+
+    /// Stake the epoch's update forced into cooldown to cover withdrawals
+    pub forced_cooldown_amount: u64,
+
+    /// Supported tokens paid out to stakers for claimed tickets
+    pub claimed_amount: u64,
+
+    /// The fees in effect at the end of the epoch
+    pub deposit_fee_bps: u16,
+    pub withdrawal_fee_bps: u16,
+    pub reward_fee_bps: u16,
+    pub program_fee_bps: u16,
+
+    /// VRT fees collected during the epoch
+    pub fees: FeeReport,
+
+    /// VRT held by the fee wallets, cumulative
+    pub vault_fee_wallet_vrt: u64,
+    pub program_fee_wallet_vrt: u64,
+
+    /// Actions the program would have rejected, and why
+    pub rejected: Vec<RejectedAction>,
+}
+
+/// VRT fees collected during an epoch
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct FeeReport {
+    pub deposit_fee_vrt: u64,
+    pub withdrawal_fee_vrt: u64,
+    pub program_fee_vrt: u64,
+    pub reward_fee_vrt: u64,
+}
+
+impl FeeReport {
+    pub const fn total(&self) -> u64 {
+        self.deposit_fee_vrt
+            .saturating_add(self.withdrawal_fee_vrt)
+            .saturating_add(self.program_fee_vrt)
+            .saturating_add(self.reward_fee_vrt)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct RejectedAction {
+    pub action: String,
+    pub error: String,
+}
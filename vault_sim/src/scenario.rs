@@ -0,0 +1,151 @@
+use std::path::Path;
+
+use jito_vault_core::vault::Vault;
+use serde::{Deserialize, Serialize};
+use solana_program::epoch_schedule::DEFAULT_SLOTS_PER_EPOCH;
+
+/// A simulation scenario: the vault's starting parameters, its operators and the actions taken
+/// each epoch. Scenarios are loaded from JSON or YAML.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Scenario {
+    /// The number of NCN epochs to simulate
+    pub epochs: u64,
+
+    /// The NCN epoch length in slots
+    #[serde(default = "default_epoch_length")]
+    pub epoch_length: u64,
+
+    /// The program fee taken on withdrawals
+    #[serde(default)]
+    pub program_fee_bps: u16,
+
+    #[serde(default)]
+    pub vault: VaultParams,
+
+    /// The operators the vault can delegate to, by operator index
+    #[serde(default)]
+    pub operators: Vec<String>,
+
+    /// Claim withdrawal tickets as soon as they're withdrawable
+    #[serde(default = "default_auto_claim")]
+    pub auto_claim: bool,
+
+    #[serde(default)]
+    pub events: Vec<ScenarioEvent>,
+}
+
+/// The vault's parameters at initialization
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields, default)]
+pub struct VaultParams {
+    pub deposit_fee_bps: u16,
+    pub withdrawal_fee_bps: u16,
+    pub reward_fee_bps: u16,
+    pub deposit_capacity: u64,
+
+    /// The supported tokens deposited when the vault is initialized
+    pub initialize_token_amount: u64,
+}
+
+impl Default for VaultParams {
+    fn default() -> Self {
+        Self {
+            deposit_fee_bps: 0,
+            withdrawal_fee_bps: 0,
+            reward_fee_bps: 0,
+            deposit_capacity: u64::MAX,
+            initialize_token_amount: Vault::DEFAULT_INITIALIZATION_TOKEN_AMOUNT,
+        }
+    }
+}
+
+/// An action taken during an epoch, after the vault has been updated for it
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ScenarioEvent {
+    pub epoch: u64,
+
+    #[serde(flatten)]
+    pub action: Action,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "action", rename_all = "snake_case", deny_unknown_fields)]
+pub enum Action {
+    /// A staker deposits supported tokens, receiving VRT
+    Deposit {
+        staker: String,
+        amount: u64,
+    },
+
+    /// A staker enqueues VRT for withdrawal, all of their VRT if no amount is given
+    RequestWithdrawal {
+        staker: String,
+        vrt_amount: Option<u64>,
+    },
+
+    /// A staker burns their withdrawable tickets
+    Claim {
+        staker: String,
+    },
+
+    /// The delegation admin delegates supported tokens to an operator
+    Delegate {
+        operator: String,
+        amount: u64,
+    },
+
+    /// The delegation admin cools down an operator's delegation
+    Cooldown {
+        operator: String,
+        amount: u64,
+    },
+
+    /// Supported tokens are sent to the vault, followed by `UpdateVaultBalance`
+    Reward {
+        amount: u64,
+    },
+
+    /// The fee admin changes the vault's fees, subject to the program's rate limits
+    SetFees {
+        deposit_fee_bps: Option<u16>,
+        withdrawal_fee_bps: Option<u16>,
+        reward_fee_bps: Option<u16>,
+    },
+
+    SetCapacity {
+        amount: u64,
+    },
+
+    /// The program fee, picked up by the vault on its next update
+    SetProgramFee {
+        program_fee_bps: u16,
+    },
+
+    SetEmergencyExit {
+        is_emergency_exit: bool,
+    },
+}
+
+impl Scenario {
+    /// Loads a scenario, parsing `.json` files as JSON and anything else as YAML
+    pub fn from_file(path: &Path) -> anyhow::Result<Self> {
+        let contents = std::fs::read_to_string(path)?;
+        if path
+            .extension()
+            .is_some_and(|extension| extension == "json")
+        {
+            Ok(serde_json::from_str(&contents)?)
+        } else {
+            Ok(serde_yaml::from_str(&contents)?)
+        }
+    }
+}
+
+const fn default_epoch_length() -> u64 {
+    DEFAULT_SLOTS_PER_EPOCH
+}
+
+const fn default_auto_claim() -> bool {
+    true
+}
@@ -0,0 +1,750 @@
+use std::collections::BTreeMap;
+
+use jito_jsm_core::get_epoch;
+use jito_vault_core::{
+    config::Config,
+    vault::{BurnSummary, MintSummary, Vault},
+    vault_operator_delegation::VaultOperatorDelegation,
+    vault_staker_withdrawal_ticket::VaultStakerWithdrawalTicket,
+    vault_update_state_tracker::{crank_vault_operator_delegation, VaultUpdateStateTracker},
+};
+use jito_vault_sdk::{error::VaultError, instruction::WithdrawalAllocationMethod};
+use solana_program::{account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey};
+use thiserror::Error;
+
+use crate::{
+    report::{EpochReport, FeeReport, RejectedAction},
+    scenario::{Action, Scenario},
+};
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum SimError {
+    #[error("Unknown operator {0}")]
+    UnknownOperator(String),
+    #[error("Duplicate operator {0}")]
+    DuplicateOperator(String),
+    #[error("Unknown staker {0}")]
+    UnknownStaker(String),
+    #[error("Event at epoch {0} is past the end of the simulation")]
+    EventOutOfRange(u64),
+    #[error("Staker {staker} holds {available} VRT, less than {requested}")]
+    InsufficientVrt {
+        staker: String,
+        available: u64,
+        requested: u64,
+    },
+    #[error("Staker {0} has no withdrawable tickets")]
+    NothingToClaim(String),
+    #[error(transparent)]
+    Vault(#[from] VaultError),
+    #[error(transparent)]
+    Program(#[from] ProgramError),
+}
+
+#[derive(Debug, Default)]
+struct Staker {
+    pubkey: Pubkey,
+    vrt_balance: u64,
+}
+
+/// Per-epoch totals, reset at the start of every epoch
+#[derive(Debug, Default)]
+struct EpochTotals {
+    forced_cooldown_amount: u64,
+    claimed_amount: u64,
+    fees: FeeReport,
+    rejected: Vec<RejectedAction>,
+}
+
+/// Simulates a vault, its operator delegations and its stakers with the same `vault_core` math
+/// the vault program runs.
+///
+/// Every epoch starts with a full vault update, as a cranker would run it, followed by the
+/// withdrawal claims and the scenario's actions for that epoch. Actions the program would
+/// reject are recorded in the epoch's report and otherwise ignored.
+pub struct Simulator {
+    config: Config,
+    epoch_length: u64,
+    vault: Vault,
+    vault_pubkey: Pubkey,
+
+    /// The supported token balance of the vault's token account
+    vault_token_balance: u64,
+    operators: Vec<(String, VaultOperatorDelegation)>,
+    stakers: BTreeMap<String, Staker>,
+    tickets: Vec<(String, VaultStakerWithdrawalTicket)>,
+    vault_fee_wallet_vrt: u64,
+    program_fee_wallet_vrt: u64,
+    auto_claim: bool,
+    slot: u64,
+    totals: EpochTotals,
+}
+
+impl Simulator {
+    /// Initializes the vault and its operator delegations at slot 0
+    pub fn new(scenario: &Scenario) -> Result<Self, SimError> {
+        let config = Config::new(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            scenario.program_fee_bps,
+            0,
+        );
+        let vault_pubkey = Pubkey::new_unique();
+        let base = Pubkey::new_unique();
+        let params = &scenario.vault;
+
+        let mut vault = Vault::new(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            0,
+            base,
+            params.deposit_fee_bps,
+            params.withdrawal_fee_bps,
+            params.reward_fee_bps,
+            config.program_fee_bps(),
+            0,
+            0,
+        )?;
+        {
+            // Mirrors InitializeVault, which mints the initial supply without a deposit fee
+            let (mut lamports, mut data) = (0, []);
+            let base_info = AccountInfo::new(
+                &base,
+                true,
+                false,
+                &mut lamports,
+                &mut data,
+                &vault_pubkey,
+                false,
+                0,
+            );
+            vault.initialize_vault_override_deposit_fee_bps(0, &base_info)?;
+            vault.mint_with_fee(
+                params.initialize_token_amount,
+                params.initialize_token_amount,
+            )?;
+            vault.initialize_vault_override_deposit_fee_bps(params.deposit_fee_bps, &base_info)?;
+        }
+        vault.set_capacity(params.deposit_capacity);
+
+        let mut operators: Vec<(String, VaultOperatorDelegation)> = Vec::new();
+        for name in scenario.operators.iter() {
+            if operators.iter().any(|(existing, _)| existing == name) {
+                return Err(SimError::DuplicateOperator(name.clone()));
+            }
+            operators.push((
+                name.clone(),
+                VaultOperatorDelegation::new(
+                    vault_pubkey,
+                    Pubkey::new_unique(),
+                    vault.operator_count(),
+                    0,
+                    0,
+                ),
+            ));
+            vault.increment_operator_count()?;
+        }
+
+        for event in scenario.events.iter() {
+            if event.epoch >= scenario.epochs {
+                return Err(SimError::EventOutOfRange(event.epoch));
+            }
+            if let Action::Delegate { operator, .. } | Action::Cooldown { operator, .. } =
+                &event.action
+            {
+                if !operators.iter().any(|(name, _)| name == operator) {
+                    return Err(SimError::UnknownOperator(operator.clone()));
+                }
+            }
+        }
+
+        Ok(Self {
+            config,
+            epoch_length: scenario.epoch_length,
+            vault,
+            vault_pubkey,
+            vault_token_balance: params.initialize_token_amount,
+            operators,
+            stakers: BTreeMap::new(),
+            tickets: Vec::new(),
+            vault_fee_wallet_vrt: 0,
+            program_fee_wallet_vrt: 0,
+            auto_claim: scenario.auto_claim,
+            slot: 0,
+            totals: EpochTotals::default(),
+        })
+    }
+
+    /// Runs the scenario, returning a report for every epoch
+    pub fn run(scenario: &Scenario) -> Result<Vec<EpochReport>, SimError> {
+        let mut simulator = Self::new(scenario)?;
+        let mut reports = Vec::with_capacity(scenario.epochs as usize);
+        for epoch in 0..scenario.epochs {
+            simulator.start_epoch(epoch)?;
+            for event in scenario.events.iter().filter(|event| event.epoch == epoch) {
+                simulator.apply(&event.action);
+            }
+            reports.push(simulator.report(epoch));
+        }
+        Ok(reports)
+    }
+
+    pub const fn vault(&self) -> &Vault {
+        &self.vault
+    }
+
+    /// Moves to the first slot of the epoch, updating the vault and claiming withdrawable tickets
+    /// if `auto_claim` is set
+    pub fn start_epoch(&mut self, epoch: u64) -> Result<(), SimError> {
+        self.totals = EpochTotals::default();
+        self.slot = epoch
+            .checked_mul(self.epoch_length)
+            .ok_or(VaultError::ArithmeticOverflow)?;
+
+        if self.vault.is_update_needed(self.slot, self.epoch_length)? {
+            self.update_vault()?;
+        }
+
+        if self.auto_claim {
+            let stakers: Vec<String> = self.stakers.keys().cloned().collect();
+            for staker in stakers {
+                match self.claim(&staker) {
+                    Ok(()) | Err(SimError::NothingToClaim(_)) => {}
+                    Err(e) => self.totals.rejected.push(RejectedAction {
+                        action: format!("auto claim for {}", staker),
+                        error: e.to_string(),
+                    }),
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Applies an action in the current epoch, recording it as rejected if it fails
+    pub fn apply(&mut self, action: &Action) {
+        let result = match action {
+            Action::Deposit { staker, amount } => self.deposit(staker, *amount),
+            Action::RequestWithdrawal { staker, vrt_amount } => {
+                self.request_withdrawal(staker, *vrt_amount)
+            }
+            Action::Claim { staker } => self.claim(staker),
+            Action::Delegate { operator, amount } => self.delegate(operator, *amount),
+            Action::Cooldown { operator, amount } => self.cooldown(operator, *amount),
+            Action::Reward { amount } => self.reward(*amount),
+            Action::SetFees {
+                deposit_fee_bps,
+                withdrawal_fee_bps,
+                reward_fee_bps,
+            } => self.set_fees(*deposit_fee_bps, *withdrawal_fee_bps, *reward_fee_bps),
+            Action::SetCapacity { amount } => {
+                self.vault.set_capacity(*amount);
+                Ok(())
+            }
+            Action::SetProgramFee { program_fee_bps } => self
+                .config
+                .set_program_fee_bps(*program_fee_bps)
+                .map_err(SimError::from),
+            Action::SetEmergencyExit { is_emergency_exit } => {
+                self.vault.set_is_emergency_exit(*is_emergency_exit);
+                Ok(())
+            }
+        };
+        if let Err(e) = result {
+            self.totals.rejected.push(RejectedAction {
+                action: format!("{:?}", action),
+                error: e.to_string(),
+            });
+        }
+    }
+
+    /// Runs InitializeVaultUpdateStateTracker, cranks every operator in order and closes the
+    /// tracker, as a cranker would at the start of an epoch
+    fn update_vault(&mut self) -> Result<(), SimError> {
+        let slot = self.slot;
+        let epoch_length = self.epoch_length;
+        let ncn_epoch = get_epoch(slot, epoch_length).map_err(ProgramError::from)?;
+
+        self.vault
+            .set_withdrawal_fee_bps(self.vault.next_withdrawal_fee_bps())?;
+        self.vault
+            .set_program_fee_bps(self.config.program_fee_bps())?;
+        let additional_assets_need_unstaking = self
+            .vault
+            .calculate_additional_supported_assets_needed_to_unstake(slot, epoch_length)?;
+        self.vault
+            .set_additional_assets_need_unstaking(additional_assets_need_unstaking);
+        self.vault.set_last_start_state_update_slot(slot);
+
+        let mut tracker = VaultUpdateStateTracker::new(
+            self.vault_pubkey,
+            ncn_epoch,
+            WithdrawalAllocationMethod::Greedy as u8,
+        );
+        // The program requires cranking to start at the epoch's rotating start index
+        let num_operators = self.operators.len();
+        let start_index = if num_operators == 0 {
+            0
+        } else {
+            (ncn_epoch % num_operators as u64) as usize
+        };
+        for offset in 0..num_operators {
+            let (_, vault_operator_delegation) =
+                &mut self.operators[(start_index + offset) % num_operators];
+            let staked_before = vault_operator_delegation.delegation_state.staked_amount();
+            crank_vault_operator_delegation(
+                &mut self.vault,
+                vault_operator_delegation,
+                &mut tracker,
+                slot,
+                epoch_length,
+            )?;
+            self.totals.forced_cooldown_amount =
+                self.totals
+                    .forced_cooldown_amount
+                    .saturating_add(staked_before.saturating_sub(
+                        vault_operator_delegation.delegation_state.staked_amount(),
+                    ));
+        }
+
+        // CloseVaultUpdateStateTracker
+        if self.vault.additional_assets_need_unstaking() > 0 {
+            return Err(VaultError::NonZeroAdditionalAssetsNeededForWithdrawalAtEndOfUpdate.into());
+        }
+        let last_updated_epoch = get_epoch(self.vault.last_full_state_update_slot(), epoch_length)
+            .map_err(ProgramError::from)?;
+        self.vault.delegation_state = tracker.delegation_state;
+        self.vault.set_last_full_state_update_slot(slot);
+        let epoch_diff = ncn_epoch
+            .checked_sub(last_updated_epoch)
+            .ok_or(VaultError::VaultUnderflow)?;
+        for _ in 0..epoch_diff.min(2) {
+            self.vault
+                .increment_vrt_ready_to_claim_amount(self.vault.vrt_cooling_down_amount())?;
+            self.vault
+                .set_vrt_cooling_down_amount(self.vault.vrt_enqueued_for_cooldown_amount());
+            self.vault.set_vrt_enqueued_for_cooldown_amount(0);
+        }
+        Ok(())
+    }
+
+    fn deposit(&mut self, staker: &str, amount: u64) -> Result<(), SimError> {
+        self.vault.check_is_emergency_exit()?;
+        let MintSummary {
+            vrt_to_depositor,
+            vrt_to_fee_wallet,
+        } = self.vault.mint_with_fee(amount, 0)?;
+        self.vault_token_balance = self
+            .vault_token_balance
+            .checked_add(amount)
+            .ok_or(VaultError::VaultOverflow)?;
+
+        let staker = self.stakers.entry(staker.to_string()).or_insert(Staker {
+            pubkey: Pubkey::new_unique(),
+            vrt_balance: 0,
+        });
+        staker.vrt_balance = staker.vrt_balance.saturating_add(vrt_to_depositor);
+        self.vault_fee_wallet_vrt = self.vault_fee_wallet_vrt.saturating_add(vrt_to_fee_wallet);
+        self.totals.fees.deposit_fee_vrt = self
+            .totals
+            .fees
+            .deposit_fee_vrt
+            .saturating_add(vrt_to_fee_wallet);
+        Ok(())
+    }
+
+    fn request_withdrawal(
+        &mut self,
+        staker: &str,
+        vrt_amount: Option<u64>,
+    ) -> Result<(), SimError> {
+        let Some(staker_state) = self.stakers.get_mut(staker) else {
+            return Err(SimError::UnknownStaker(staker.to_string()));
+        };
+        let vrt_amount = vrt_amount.unwrap_or(staker_state.vrt_balance);
+        if vrt_amount == 0 {
+            return Err(VaultError::VaultEnqueueWithdrawalAmountZero.into());
+        }
+        if vrt_amount > staker_state.vrt_balance {
+            return Err(SimError::InsufficientVrt {
+                staker: staker.to_string(),
+                available: staker_state.vrt_balance,
+                requested: vrt_amount,
+            });
+        }
+
+        self.vault
+            .increment_vrt_enqueued_for_cooldown_amount(vrt_amount)?;
+        staker_state.vrt_balance -= vrt_amount;
+        self.tickets.push((
+            staker.to_string(),
+            VaultStakerWithdrawalTicket::new(
+                self.vault_pubkey,
+                staker_state.pubkey,
+                Pubkey::new_unique(),
+                vrt_amount,
+                self.slot,
+                0,
+            ),
+        ));
+        Ok(())
+    }
+
+    /// Burns every withdrawable ticket the staker holds
+    fn claim(&mut self, staker: &str) -> Result<(), SimError> {
+        if !self.stakers.contains_key(staker) {
+            return Err(SimError::UnknownStaker(staker.to_string()));
+        }
+
+        let mut claimed_any = false;
+        let mut index = 0;
+        while index < self.tickets.len() {
+            let (ticket_staker, ticket) = &self.tickets[index];
+            if ticket_staker != staker || !ticket.is_withdrawable(self.slot, self.epoch_length)? {
+                index += 1;
+                continue;
+            }
+
+            let BurnSummary {
+                program_fee_amount,
+                vault_fee_amount,
+                out_amount,
+                ..
+            } = self
+                .vault
+                .burn_with_fee(false, false, ticket.vrt_amount())?;
+            self.vault
+                .decrement_vrt_ready_to_claim_amount(ticket.vrt_amount())?;
+            self.vault_token_balance = self
+                .vault_token_balance
+                .checked_sub(out_amount)
+                .ok_or(VaultError::VaultUnderflow)?;
+            self.tickets.remove(index);
+            claimed_any = true;
+
+            self.vault_fee_wallet_vrt = self.vault_fee_wallet_vrt.saturating_add(vault_fee_amount);
+            self.program_fee_wallet_vrt = self
+                .program_fee_wallet_vrt
+                .saturating_add(program_fee_amount);
+            let totals = &mut self.totals;
+            totals.claimed_amount = totals.claimed_amount.saturating_add(out_amount);
+            totals.fees.withdrawal_fee_vrt = totals
+                .fees
+                .withdrawal_fee_vrt
+                .saturating_add(vault_fee_amount);
+            totals.fees.program_fee_vrt = totals
+                .fees
+                .program_fee_vrt
+                .saturating_add(program_fee_amount);
+        }
+
+        if !claimed_any {
+            return Err(SimError::NothingToClaim(staker.to_string()));
+        }
+        Ok(())
+    }
+
+    fn operator_mut(&mut self, operator: &str) -> Result<&mut VaultOperatorDelegation, SimError> {
+        self.operators
+            .iter_mut()
+            .find(|(name, _)| name == operator)
+            .map(|(_, vault_operator_delegation)| vault_operator_delegation)
+            .ok_or_else(|| SimError::UnknownOperator(operator.to_string()))
+    }
+
+    fn delegate(&mut self, operator: &str, amount: u64) -> Result<(), SimError> {
+        self.vault.check_is_emergency_exit()?;
+        self.operator_mut(operator)?;
+        self.vault.delegate(amount)?;
+        self.operator_mut(operator)?
+            .delegation_state
+            .delegate(amount)?;
+        Ok(())
+    }
+
+    fn cooldown(&mut self, operator: &str, amount: u64) -> Result<(), SimError> {
+        self.operator_mut(operator)?
+            .delegation_state
+            .cooldown(amount)?;
+        self.vault.delegation_state.cooldown(amount)?;
+        Ok(())
+    }
+
+    /// Mirrors UpdateVaultBalance after `amount` supported tokens are sent to the vault
+    fn reward(&mut self, amount: u64) -> Result<(), SimError> {
+        self.vault_token_balance = self
+            .vault_token_balance
+            .checked_add(amount)
+            .ok_or(VaultError::VaultOverflow)?;
+
+        let mut vault = self.vault;
+        let new_st_balance = self.vault_token_balance;
+        let st_rewards = new_st_balance.saturating_sub(vault.tokens_deposited());
+        let st_reward_fee = vault.calculate_st_reward_fee(new_st_balance)?;
+        let st_balance_after_fees = new_st_balance
+            .checked_sub(st_reward_fee)
+            .ok_or(VaultError::ArithmeticUnderflow)?;
+        vault.set_tokens_deposited(st_balance_after_fees);
+        let vrt_reward_fee = vault.calculate_vrt_mint_amount(st_reward_fee)?;
+        vault.set_tokens_deposited(new_st_balance);
+        vault.increment_vrt_supply(vrt_reward_fee)?;
+        vault.check_reward_fee_effective_rate(
+            st_rewards,
+            vrt_reward_fee,
+            Vault::MAX_REWARD_DELTA_BPS,
+        )?;
+
+        // The tokens stay in the vault's token account even if the update is rejected
+        self.vault = vault;
+        self.vault_fee_wallet_vrt = self.vault_fee_wallet_vrt.saturating_add(vrt_reward_fee);
+        self.totals.fees.reward_fee_vrt = self
+            .totals
+            .fees
+            .reward_fee_vrt
+            .saturating_add(vrt_reward_fee);
+        Ok(())
+    }
+
+    fn set_fees(
+        &mut self,
+        deposit_fee_bps: Option<u16>,
+        withdrawal_fee_bps: Option<u16>,
+        reward_fee_bps: Option<u16>,
+    ) -> Result<(), SimError> {
+        let mut vault = self.vault;
+        vault.check_can_modify_fees(self.slot, self.epoch_length)?;
+        if let Some(deposit_fee_bps) = deposit_fee_bps {
+            vault.set_deposit_fee_bps(
+                deposit_fee_bps,
+                self.config.deposit_withdrawal_fee_cap_bps(),
+                self.config.fee_bump_bps(),
+                self.config.fee_rate_of_change_bps(),
+            )?;
+        }
+        if let Some(withdrawal_fee_bps) = withdrawal_fee_bps {
+            vault.set_next_withdrawal_fee_bps(
+                withdrawal_fee_bps,
+                self.config.deposit_withdrawal_fee_cap_bps(),
+                self.config.fee_bump_bps(),
+                self.config.fee_rate_of_change_bps(),
+            )?;
+        }
+        if let Some(reward_fee_bps) = reward_fee_bps {
+            vault.set_reward_fee_bps(reward_fee_bps)?;
+        }
+        vault.set_last_fee_change_slot(self.slot);
+        self.vault = vault;
+        Ok(())
+    }
+
+    fn report(&mut self, epoch: u64) -> EpochReport {
+        let vault = &self.vault;
+        let totals = std::mem::take(&mut self.totals);
+        EpochReport {
+            epoch,
+            tokens_deposited: vault.tokens_deposited(),
+            vrt_supply: vault.vrt_supply(),
+            exchange_rate: if vault.vrt_supply() == 0 {
+                0.0
+            } else {
+                vault.tokens_deposited() as f64 / vault.vrt_supply() as f64
+            },
+            staked_amount: vault.delegation_state.staked_amount(),
+            enqueued_for_cooldown_amount: vault.delegation_state.enqueued_for_cooldown_amount(),
+            cooling_down_amount: vault.delegation_state.cooling_down_amount(),
+            vrt_enqueued_for_cooldown_amount: vault.vrt_enqueued_for_cooldown_amount(),
+            vrt_cooling_down_amount: vault.vrt_cooling_down_amount(),
+            vrt_ready_to_claim_amount: vault.vrt_ready_to_claim_amount(),
+            open_tickets: self.tickets.len() as u64,
+            forced_cooldown_amount: totals.forced_cooldown_amount,
+            claimed_amount: totals.claimed_amount,
+            deposit_fee_bps: vault.deposit_fee_bps(),
+            withdrawal_fee_bps: vault.withdrawal_fee_bps(),
+            reward_fee_bps: vault.reward_fee_bps(),
+            program_fee_bps: vault.program_fee_bps(),
+            fees: totals.fees,
+            vault_fee_wallet_vrt: self.vault_fee_wallet_vrt,
+            program_fee_wallet_vrt: self.program_fee_wallet_vrt,
+            rejected: totals.rejected,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scenario::{Action, Scenario, ScenarioEvent, VaultParams};
+
+    const EPOCH_LENGTH: u64 = 100;
+
+    fn scenario(operators: &[&str], events: Vec<(u64, Action)>) -> Scenario {
+        Scenario {
+            epochs: 6,
+            epoch_length: EPOCH_LENGTH,
+            program_fee_bps: 0,
+            vault: VaultParams::default(),
+            operators: operators.iter().map(|name| name.to_string()).collect(),
+            auto_claim: true,
+            events: events
+                .into_iter()
+                .map(|(epoch, action)| ScenarioEvent { epoch, action })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn test_deposit_and_withdraw_round_trip() {
+        let reports = Simulator::run(&scenario(
+            &[],
+            vec![
+                (
+                    0,
+                    Action::Deposit {
+                        staker: "alice".to_string(),
+                        amount: 1_000_000,
+                    },
+                ),
+                (
+                    1,
+                    Action::RequestWithdrawal {
+                        staker: "alice".to_string(),
+                        vrt_amount: None,
+                    },
+                ),
+            ],
+        ))
+        .unwrap();
+
+        // Enqueued in epoch 1, cooling down in epoch 2, claimable in epoch 3
+        assert_eq!(reports[1].vrt_enqueued_for_cooldown_amount, 1_000_000);
+        assert_eq!(reports[2].vrt_cooling_down_amount, 1_000_000);
+        assert_eq!(reports[2].claimed_amount, 0);
+        assert_eq!(reports[3].claimed_amount, 1_000_000);
+        assert_eq!(reports[3].open_tickets, 0);
+        assert_eq!(
+            reports[3].tokens_deposited,
+            Vault::DEFAULT_INITIALIZATION_TOKEN_AMOUNT
+        );
+        assert!(reports.iter().all(|report| report.rejected.is_empty()));
+    }
+
+    #[test]
+    fn test_withdrawal_forces_cooldown() {
+        let reports = Simulator::run(&scenario(
+            &["operator"],
+            vec![
+                (
+                    0,
+                    Action::Deposit {
+                        staker: "alice".to_string(),
+                        amount: 1_000_000,
+                    },
+                ),
+                (
+                    0,
+                    Action::Delegate {
+                        operator: "operator".to_string(),
+                        amount: 1_000_000 + Vault::DEFAULT_INITIALIZATION_TOKEN_AMOUNT,
+                    },
+                ),
+                (
+                    1,
+                    Action::RequestWithdrawal {
+                        staker: "alice".to_string(),
+                        vrt_amount: Some(400_000),
+                    },
+                ),
+            ],
+        ))
+        .unwrap();
+
+        // Nothing is left undelegated, so the whole withdrawal comes out of the operator
+        assert_eq!(
+            reports[1].staked_amount,
+            1_000_000 + Vault::DEFAULT_INITIALIZATION_TOKEN_AMOUNT
+        );
+        assert_eq!(reports[2].forced_cooldown_amount, 400_000);
+        assert_eq!(
+            reports[2].staked_amount,
+            600_000 + Vault::DEFAULT_INITIALIZATION_TOKEN_AMOUNT
+        );
+        assert_eq!(reports[3].claimed_amount, 400_000);
+    }
+
+    #[test]
+    fn test_rejected_actions_are_reported() {
+        let reports = Simulator::run(&scenario(
+            &["operator"],
+            vec![(
+                0,
+                Action::Delegate {
+                    operator: "operator".to_string(),
+                    amount: u64::MAX,
+                },
+            )],
+        ))
+        .unwrap();
+
+        assert_eq!(reports[0].rejected.len(), 1);
+        assert_eq!(reports[0].staked_amount, 0);
+    }
+
+    #[test]
+    fn test_deposit_in_emergency_exit_is_rejected() {
+        let reports = Simulator::run(&scenario(
+            &[],
+            vec![
+                (
+                    0,
+                    Action::SetEmergencyExit {
+                        is_emergency_exit: true,
+                    },
+                ),
+                (
+                    0,
+                    Action::Deposit {
+                        staker: "alice".to_string(),
+                        amount: 1_000_000,
+                    },
+                ),
+            ],
+        ))
+        .unwrap();
+
+        assert_eq!(reports[0].rejected.len(), 1);
+        assert!(reports[0].rejected[0].action.starts_with("Deposit"));
+        assert_eq!(
+            reports[0].tokens_deposited,
+            Vault::DEFAULT_INITIALIZATION_TOKEN_AMOUNT
+        );
+    }
+
+    #[test]
+    fn test_unknown_operator() {
+        let result = Simulator::new(&scenario(
+            &["operator"],
+            vec![(
+                0,
+                Action::Cooldown {
+                    operator: "other".to_string(),
+                    amount: 1,
+                },
+            )],
+        ));
+        assert_eq!(
+            result.err(),
+            Some(SimError::UnknownOperator("other".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_example_scenario() {
+        let scenario: Scenario =
+            serde_yaml::from_str(include_str!("../scenarios/example.yaml")).unwrap();
+        let reports = Simulator::run(&scenario).unwrap();
+        assert_eq!(reports.len(), scenario.epochs as usize);
+        assert!(reports.iter().all(|report| report.rejected.is_empty()));
+        assert!(reports.iter().any(|report| report.claimed_amount > 0));
+    }
+}